@@ -53,6 +53,9 @@ echo ""
 # Create log directory
 mkdir -p "$SCRIPT_DIR/logs"
 
+# Fresh per-run Advice Bus secret (data-mining generates it, Brain reads it)
+rm -f /tmp/advice_bus.secret
+
 echo "=================================="
 echo "Starting services in order..."
 echo "=================================="
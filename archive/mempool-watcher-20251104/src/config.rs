@@ -12,6 +12,12 @@ fn get_env_u16(key: &str, default: u16) -> Result<u16> {
         .parse()?)
 }
 
+fn get_env_u32(key: &str, default: u32) -> Result<u32> {
+    Ok(env::var(key)
+        .unwrap_or_else(|_| default.to_string())
+        .parse()?)
+}
+
 fn get_env_u64(key: &str, default: u64) -> Result<u64> {
     Ok(env::var(key)
         .unwrap_or_else(|_| default.to_string())
@@ -45,6 +51,9 @@ pub struct Config {
 pub struct RpcConfig {
     pub url: String,
     pub ws_url: String,
+    pub signature_commitment: String,        // processed | confirmed | finalized (RPC-polled signatures)
+    pub signature_max_poll_attempts: u32,    // Polls below that commitment before the tx is reported failed
+    pub signature_poll_timeout_secs: u64,    // Age after which an unconfirmed tx is reported failed
 }
 
 #[derive(Debug, Clone)]
@@ -53,7 +62,11 @@ pub struct UdpConfig {
     pub watch_listen_port: u16,          // Mempool listens for watch requests (45130)
     pub brain_confirmation_port: u16,    // Brain listens for post-confirmation intelligence (45131)
     pub executor_confirmed_port: u16,    // Executor listens for TxConfirmed (45132)
+    pub sol_price_port: u16,             // Mempool listens for SolPriceUpdate from data-mining (45136)
+    pub advice_bus_port: u16,            // Brain's authenticated Advice Bus, receives ExitAdvice (45100)
     pub bind_address: String,
+    pub advice_bus_secret: Option<String>,   // HMAC secret shared with Brain / data-mining
+    pub advice_bus_secret_file: String,      // Shared per-run secret when ADVICE_BUS_SECRET is unset
 }
 
 #[derive(Debug, Clone)]
@@ -61,11 +74,16 @@ pub struct MonitoringConfig {
     pub heat_update_interval_secs: u64,
     pub hot_signal_cooldown_ms: u64,
     pub transaction_window_secs: u64,
+    pub status_port: u16,                // HTTP status endpoint (WebSocket health)
+    pub default_sol_price_usd: f64,      // Used for P&L until the first SolPriceUpdate arrives
+    pub sol_price_max_age_secs: u64,     // SolPriceUpdates broadcast longer ago than this are dropped
 }
 
 #[derive(Debug, Clone)]
 pub struct ThresholdConfig {
     pub whale_threshold_sol: f64,
+    pub mega_whale_threshold_sol: f64,   // Trades at or above this are mega-whales
+    pub mega_whale_heat_weight: f64,     // Heat multiplier for mega-whale volume (whales = 1.0)
     pub bot_repeat_threshold: usize,
     pub heat_index_threshold: u8,
 }
@@ -98,21 +116,33 @@ impl Config {
             rpc: RpcConfig {
                 url: get_env("SOLANA_RPC_URL", "https://api.mainnet-beta.solana.com")?,
                 ws_url: get_env("SOLANA_RPC_WS_URL", "wss://api.mainnet-beta.solana.com")?,
+                signature_commitment: get_env("SIGNATURE_COMMITMENT", "confirmed")?,
+                signature_max_poll_attempts: get_env_u32("SIGNATURE_MAX_POLL_ATTEMPTS", 15)?,
+                signature_poll_timeout_secs: get_env_u64("SIGNATURE_POLL_TIMEOUT_SECS", 30)?,
             },
             udp: UdpConfig {
                 brain_port: get_env_u16("BRAIN_UDP_PORT", 45120)?,
                 watch_listen_port: get_env_u16("WATCH_LISTEN_PORT", 45130)?,
                 brain_confirmation_port: get_env_u16("BRAIN_CONFIRMATION_PORT", 45131)?,
                 executor_confirmed_port: get_env_u16("EXECUTOR_CONFIRMED_PORT", 45132)?,
+                sol_price_port: get_env_u16("SOL_PRICE_PORT", 45136)?,
+                advice_bus_port: get_env_u16("ADVICE_BUS_PORT", 45100)?,
                 bind_address: get_env("UDP_BIND_ADDRESS", "127.0.0.1")?,
+                advice_bus_secret: env::var("ADVICE_BUS_SECRET").ok(),
+                advice_bus_secret_file: get_env("ADVICE_BUS_SECRET_FILE", &crate::auth::default_secret_file())?,
             },
             monitoring: MonitoringConfig {
                 heat_update_interval_secs: get_env_u64("HEAT_UPDATE_INTERVAL_SECS", 5)?,
                 hot_signal_cooldown_ms: get_env_u64("HOT_SIGNAL_COOLDOWN_MS", 1000)?,
                 transaction_window_secs: get_env_u64("TRANSACTION_WINDOW_SECS", 10)?,
+                status_port: get_env_u16("STATUS_PORT", 9092)?,
+                default_sol_price_usd: get_env_f64("DEFAULT_SOL_PRICE_USD", 200.0)?,
+                sol_price_max_age_secs: get_env_u64("SOL_PRICE_MAX_AGE_SECS", 30)?,
             },
             thresholds: ThresholdConfig {
                 whale_threshold_sol: get_env_f64("WHALE_THRESHOLD_SOL", 10.0)?,
+                mega_whale_threshold_sol: get_env_f64("MEGA_WHALE_THRESHOLD_SOL", 50.0)?,
+                mega_whale_heat_weight: get_env_f64("MEGA_WHALE_HEAT_WEIGHT", 2.0)?,
                 bot_repeat_threshold: get_env_usize("BOT_REPEAT_THRESHOLD", 3)?,
                 heat_index_threshold: get_env_u8("HEAT_INDEX_THRESHOLD", 70)?,
             },
@@ -137,10 +167,30 @@ impl Config {
     }
 
     pub fn validate(&self) -> Result<()> {
+        if !matches!(self.rpc.signature_commitment.as_str(), "processed" | "confirmed" | "finalized") {
+            anyhow::bail!("SIGNATURE_COMMITMENT must be processed, confirmed or finalized");
+        }
+
+        if self.rpc.signature_max_poll_attempts == 0 {
+            anyhow::bail!("SIGNATURE_MAX_POLL_ATTEMPTS must be > 0");
+        }
+
+        if self.rpc.signature_poll_timeout_secs == 0 {
+            anyhow::bail!("SIGNATURE_POLL_TIMEOUT_SECS must be > 0");
+        }
+
         if self.thresholds.whale_threshold_sol <= 0.0 {
             anyhow::bail!("WHALE_THRESHOLD_SOL must be > 0");
         }
 
+        if self.thresholds.mega_whale_threshold_sol <= self.thresholds.whale_threshold_sol {
+            anyhow::bail!("MEGA_WHALE_THRESHOLD_SOL must be > WHALE_THRESHOLD_SOL");
+        }
+
+        if self.thresholds.mega_whale_heat_weight < 1.0 {
+            anyhow::bail!("MEGA_WHALE_HEAT_WEIGHT must be >= 1.0");
+        }
+
         if self.thresholds.bot_repeat_threshold < 2 {
             anyhow::bail!("BOT_REPEAT_THRESHOLD must be >= 2");
         }
@@ -153,6 +203,26 @@ impl Config {
             anyhow::bail!("HEAT_UPDATE_INTERVAL_SECS must be > 0");
         }
 
+        if self.monitoring.default_sol_price_usd <= 0.0 {
+            anyhow::bail!("DEFAULT_SOL_PRICE_USD must be > 0");
+        }
+
+        if self.monitoring.sol_price_max_age_secs == 0 {
+            anyhow::bail!("SOL_PRICE_MAX_AGE_SECS must be > 0");
+        }
+
+        if [
+            self.udp.brain_port,
+            self.udp.watch_listen_port,
+            self.udp.brain_confirmation_port,
+            self.udp.executor_confirmed_port,
+            self.udp.advice_bus_port,
+        ]
+        .contains(&self.udp.sol_price_port)
+        {
+            anyhow::bail!("SOL_PRICE_PORT must differ from the other UDP ports");
+        }
+
         Ok(())
     }
 
@@ -162,12 +232,20 @@ impl Config {
         info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
         info!("📡 RPC: {}", self.rpc.url);
         info!("🌊 WebSocket: {}", self.rpc.ws_url);
+        info!("🔄 Signature polling: {} (max {} polls, {}s timeout)",
+              self.rpc.signature_commitment, self.rpc.signature_max_poll_attempts, self.rpc.signature_poll_timeout_secs);
         info!("📤 Brain UDP: port {}", self.udp.brain_port);
         info!("📤 Brain Confirmation UDP: port {}", self.udp.brain_confirmation_port);
+        info!("📤 Brain Advice Bus (ExitAdvice): port {}", self.udp.advice_bus_port);
         info!("💾 SQLite DB: {}", self.database.sqlite_path);
         info!("⏱️  Heat update: every {}s", self.monitoring.heat_update_interval_secs);
+        info!("🩺 Status endpoint: port {}", self.monitoring.status_port);
+        info!("💲 SOL price feed: port {} (default ${:.2}, max age {}s)",
+              self.udp.sol_price_port, self.monitoring.default_sol_price_usd, self.monitoring.sol_price_max_age_secs);
         info!("👥 Alpha wallet update: every {}s", self.database.alpha_wallet_update_interval_secs);
         info!("🐋 Whale threshold: {} SOL", self.thresholds.whale_threshold_sol);
+        info!("🐳 Mega-whale threshold: {} SOL (heat x{})",
+              self.thresholds.mega_whale_threshold_sol, self.thresholds.mega_whale_heat_weight);
         info!("🤖 Bot detection: {} repeat txs", self.thresholds.bot_repeat_threshold);
         info!("🔥 Heat threshold: {}", self.thresholds.heat_index_threshold);
         info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
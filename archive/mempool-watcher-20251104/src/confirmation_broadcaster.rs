@@ -35,7 +35,7 @@ pub struct ConfirmationBroadcaster {
     brain_addr: String,
     executor_addr: String,
     decoder: std::sync::Arc<TransactionDecoder>,
-    alpha_manager: std::sync::Arc<tokio::sync::Mutex<AlphaWalletManager>>,
+    alpha_manager: std::sync::Arc<AlphaWalletManager>,
     position_tracker: Arc<PositionTracker>,
     udp_publisher: Arc<UdpPublisher>,
 }
@@ -46,7 +46,7 @@ impl ConfirmationBroadcaster {
         brain_port: u16,
         executor_confirmed_port: u16,
         decoder: std::sync::Arc<TransactionDecoder>,
-        alpha_manager: std::sync::Arc<tokio::sync::Mutex<AlphaWalletManager>>,
+        alpha_manager: std::sync::Arc<AlphaWalletManager>,
         position_tracker: Arc<PositionTracker>,
         udp_publisher: Arc<UdpPublisher>,
     ) -> Result<Self> {
@@ -249,10 +249,9 @@ impl ConfirmationBroadcaster {
                     hold_time_ms,
                 );
                 
-                let exit_bytes = exit_advice.to_bytes();
-                
-                match self.brain_socket.send_to(&exit_bytes, &self.brain_addr) {
-                    Ok(_) => {
+                // Signed, on Brain's Advice Bus (the only place Brain parses ExitAdvice)
+                match self.udp_publisher.send_exit_advice(&exit_advice) {
+                    Ok(()) => {
                         info!("📤 Sent ExitAdvice to Brain (target_hit)");
                     }
                     Err(e) => {
@@ -284,10 +283,9 @@ impl ConfirmationBroadcaster {
                     hold_time_ms,
                 );
                 
-                let exit_bytes = exit_advice.to_bytes();
-                
-                match self.brain_socket.send_to(&exit_bytes, &self.brain_addr) {
-                    Ok(_) => {
+                // Signed, on Brain's Advice Bus (the only place Brain parses ExitAdvice)
+                match self.udp_publisher.send_exit_advice(&exit_advice) {
+                    Ok(()) => {
                         warn!("📤 Sent ExitAdvice to Brain (stop_loss)");
                     }
                     Err(e) => {
@@ -409,13 +407,11 @@ mod tests {
         );
         
         let decoder = std::sync::Arc::new(TransactionDecoder::new(10.0)); // 10 SOL whale threshold
-        let alpha_manager = std::sync::Arc::new(tokio::sync::Mutex::new(
-            AlphaWalletManager::new("test.db".to_string())
-        ));
+        let alpha_manager = std::sync::Arc::new(AlphaWalletManager::new("test.db".to_string()));
         
         // Create mock position tracker and udp publisher for test
         let position_tracker = Arc::new(PositionTracker::new());
-        let udp_publisher = Arc::new(UdpPublisher::new("127.0.0.1", 45115, 45131).unwrap());
+        let udp_publisher = Arc::new(UdpPublisher::new("127.0.0.1", 45115, 45131, 45100).unwrap());
         
         let broadcaster = ConfirmationBroadcaster::new(
             "127.0.0.1",
@@ -11,6 +11,18 @@ const SELL_DISCRIMINATOR: [u8; 8] = [0x33, 0xe6, 0x85, 0xa4, 0x01, 0x7f, 0x83, 0
 const BUY_INSTRUCTION_SIZE: usize = 24; // 8 (discriminator) + 8 (amount) + 8 (min_out)
 const SELL_INSTRUCTION_SIZE: usize = 24; // 8 (discriminator) + 8 (amount) + 8 (min_sol)
 
+// Buy/sell account positions within the pump instruction's own accounts
+// [global, fee_recipient, mint, bonding_curve, associated_bonding_curve, associated_user, user, ...]
+pub const PUMP_MINT_ACCOUNT: usize = 2;
+const PUMP_BONDING_CURVE_ACCOUNT: usize = 3;
+const PUMP_USER_ACCOUNT: usize = 6;
+const PUMP_MIN_ACCOUNTS: usize = PUMP_USER_ACCOUNT + 1;
+
+/// Mint among a pump buy/sell instruction's own accounts (None if too few)
+pub fn pump_mint_account(instruction_accounts: &[String]) -> Option<&str> {
+    instruction_accounts.get(PUMP_MINT_ACCOUNT).map(String::as_str)
+}
+
 /// Decoded transaction data
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DecodedTransaction {
@@ -38,6 +50,54 @@ pub enum WalletType {
     Unknown,  // Not yet classified
 }
 
+/// Trade size tier for whale detection
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[repr(u8)]
+pub enum WhaleTier {
+    #[default]
+    None = 0,
+    Whale = 1,      // >= whale threshold
+    MegaWhale = 2,  // >= mega-whale threshold
+}
+
+impl WhaleTier {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WhaleTier::None => "none",
+            WhaleTier::Whale => "whale",
+            WhaleTier::MegaWhale => "mega_whale",
+        }
+    }
+}
+
+/// SOL thresholds separating whale tiers
+#[derive(Debug, Clone, Copy)]
+pub struct WhaleTiers {
+    pub whale_sol: f64,
+    pub mega_whale_sol: f64,
+}
+
+impl WhaleTiers {
+    /// Single whale tier (mega-whale tier disabled)
+    pub fn new(whale_sol: f64) -> Self {
+        Self {
+            whale_sol,
+            mega_whale_sol: f64::INFINITY,
+        }
+    }
+
+    /// Classify a single trade by its SOL amount
+    pub fn classify(&self, amount_sol: f64) -> WhaleTier {
+        if amount_sol >= self.mega_whale_sol {
+            WhaleTier::MegaWhale
+        } else if amount_sol >= self.whale_sol {
+            WhaleTier::Whale
+        } else {
+            WhaleTier::None
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
 pub enum ProgramType {
     PumpFun,
@@ -49,7 +109,7 @@ pub enum ProgramType {
 pub struct TransactionDecoder {
     pump_program_id: Pubkey,
     raydium_program_id: Pubkey,
-    whale_threshold_sol: f64,
+    whale_tiers: WhaleTiers,
 }
 
 /// Parsed Pump.fun BUY instruction
@@ -85,10 +145,21 @@ impl TransactionDecoder {
         Self {
             pump_program_id,
             raydium_program_id,
-            whale_threshold_sol,
+            whale_tiers: WhaleTiers::new(whale_threshold_sol),
         }
     }
 
+    /// Enable the mega-whale tier for trades of at least `mega_whale_threshold_sol`
+    pub fn with_mega_whale_threshold(mut self, mega_whale_threshold_sol: f64) -> Self {
+        self.whale_tiers.mega_whale_sol = mega_whale_threshold_sol;
+        self
+    }
+
+    /// Whale tier of a trade of `amount_sol`
+    pub fn whale_tier(&self, amount_sol: f64) -> WhaleTier {
+        self.whale_tiers.classify(amount_sol)
+    }
+
     /// Decode a transaction
     pub fn decode(&self, _transaction: &[u8]) -> Result<Option<DecodedTransaction>> {
         // TODO: Implement actual transaction parsing
@@ -119,17 +190,14 @@ impl TransactionDecoder {
                 .context("Failed to parse min_tokens_out")?
         );
 
-        // Extract accounts (order as per Pump.fun program)
-        if accounts.len() < 6 {
+        // Extract accounts (the instruction's accounts, order as per Pump.fun program)
+        if accounts.len() < PUMP_MIN_ACCOUNTS {
             return Ok(None);
         }
 
-        let user = accounts[0];           // Fee payer/signer
-        let mint = accounts[1];           // Token mint
-        let bonding_curve = accounts[2];  // Bonding curve account
-        // accounts[3] = associated token account
-        // accounts[4] = system program
-        // accounts[5] = token program
+        let user = accounts[PUMP_USER_ACCOUNT];                   // Buyer/signer
+        let mint = accounts[PUMP_MINT_ACCOUNT];                   // Token mint
+        let bonding_curve = accounts[PUMP_BONDING_CURVE_ACCOUNT]; // Bonding curve account
 
         Ok(Some(PumpBuyInstruction {
             amount_lamports,
@@ -163,14 +231,14 @@ impl TransactionDecoder {
                 .context("Failed to parse min_sol_out")?
         );
 
-        // Extract accounts
-        if accounts.len() < 6 {
+        // Extract accounts (the instruction's accounts)
+        if accounts.len() < PUMP_MIN_ACCOUNTS {
             return Ok(None);
         }
 
-        let user = accounts[0];
-        let mint = accounts[1];
-        let bonding_curve = accounts[2];
+        let user = accounts[PUMP_USER_ACCOUNT];
+        let mint = accounts[PUMP_MINT_ACCOUNT];
+        let bonding_curve = accounts[PUMP_BONDING_CURVE_ACCOUNT];
 
         Ok(Some(PumpSellInstruction {
             token_amount,
@@ -181,6 +249,34 @@ impl TransactionDecoder {
         }))
     }
 
+    /// Realized SOL received by `user` from the transaction's balance delta
+    ///
+    /// `min_sol_out` on a SELL is only the slippage floor; the lamports actually
+    /// credited to the seller come from pre/post balances in the transaction
+    /// meta. The fee is added back when the seller is the fee payer (index 0).
+    /// Returns None if balances are unavailable or the seller's balance did not rise.
+    pub fn realized_sol_out(
+        &self,
+        account_keys: &[String],
+        pre_balances: &[u64],
+        post_balances: &[u64],
+        fee: u64,
+        user: &Pubkey,
+    ) -> Option<u64> {
+        let user_str = user.to_string();
+        let index = account_keys.iter().position(|key| *key == user_str)?;
+        let pre = *pre_balances.get(index)?;
+        let post = *post_balances.get(index)?;
+        let fee_paid = if index == 0 { fee } else { 0 };
+
+        let received = (post + fee_paid).checked_sub(pre)?;
+        if received == 0 {
+            None
+        } else {
+            Some(received)
+        }
+    }
+
     /// Decode Pump.fun instruction into DecodedTransaction
     pub fn decode_pump_instruction(&self, instruction_data: &[u8], accounts: &[Pubkey], timestamp: u64) -> Result<Option<DecodedTransaction>> {
         // Try to parse as BUY instruction
@@ -218,6 +314,53 @@ impl TransactionDecoder {
         Ok(None)
     }
 
+    /// Decode a fetched Pump.fun instruction (its accounts as base58 strings)
+    ///
+    /// `instruction_accounts` are the pump instruction's own accounts, already
+    /// resolved from their indices into the transaction account keys; the mint
+    /// and trader are taken from their buy/sell positions there. Returns None
+    /// unless both the instruction and a valid mint pubkey could be decoded.
+    pub fn decode_raw_pump_transaction(
+        &self,
+        signature: &str,
+        program_id: &str,
+        instruction_accounts: &[String],
+        instruction_data: &[u8],
+        timestamp: u64,
+        repeat_count: usize,
+    ) -> Option<DecodedTransaction> {
+        if program_id != self.pump_program_id.to_string() || instruction_accounts.len() < PUMP_MIN_ACCOUNTS {
+            return None;
+        }
+
+        // Only accept real pubkeys - never publish placeholder mints
+        let mint = Pubkey::from_str(pump_mint_account(instruction_accounts)?).ok()?;
+        let wallet = Pubkey::from_str(&instruction_accounts[PUMP_USER_ACCOUNT]).ok()?;
+
+        let (action, lamports) = if self.is_pump_buy(instruction_data) && instruction_data.len() >= BUY_INSTRUCTION_SIZE {
+            // BUY: amount (lamports) at [8..16]
+            (TransactionAction::Buy, u64::from_le_bytes(instruction_data[8..16].try_into().ok()?))
+        } else if self.is_pump_sell(instruction_data) && instruction_data.len() >= SELL_INSTRUCTION_SIZE {
+            // SELL: min_sol_out at [16..24] (approximation of SOL received)
+            (TransactionAction::Sell, u64::from_le_bytes(instruction_data[16..24].try_into().ok()?))
+        } else {
+            return None;
+        };
+
+        let amount_sol = lamports as f64 / 1_000_000_000.0;
+
+        Some(DecodedTransaction {
+            signature: signature.to_string(),
+            mint: mint.to_string(),
+            action,
+            amount_sol,
+            wallet: wallet.to_string(),
+            wallet_type: self.classify_wallet(amount_sol, repeat_count),
+            timestamp,
+            program: ProgramType::PumpFun,
+        })
+    }
+
     /// Check if instruction data matches Pump.fun BUY discriminator
     pub fn is_pump_buy(&self, instruction_data: &[u8]) -> bool {
         instruction_data.len() >= 8 && &instruction_data[0..8] == BUY_DISCRIMINATOR
@@ -241,7 +384,7 @@ impl TransactionDecoder {
 
     /// Classify wallet type based on transaction amount
     pub fn classify_wallet(&self, amount_sol: f64, repeat_count: usize) -> WalletType {
-        if amount_sol >= self.whale_threshold_sol {
+        if self.whale_tier(amount_sol) >= WhaleTier::Whale {
             WalletType::Whale
         } else if repeat_count >= 3 {
             WalletType::Bot
@@ -290,6 +433,19 @@ mod tests {
         assert_eq!(decoder.identify_program(&raydium_id), ProgramType::Raydium);
     }
 
+    #[test]
+    fn test_whale_tiers() {
+        let decoder = TransactionDecoder::new(5.0).with_mega_whale_threshold(50.0);
+        assert_eq!(decoder.whale_tier(1.0), WhaleTier::None);
+        assert_eq!(decoder.whale_tier(5.0), WhaleTier::Whale);
+        assert_eq!(decoder.whale_tier(49.9), WhaleTier::Whale);
+        assert_eq!(decoder.whale_tier(50.0), WhaleTier::MegaWhale);
+        assert_eq!(decoder.classify_wallet(75.0, 0), WalletType::Whale);
+
+        // Mega tier disabled by default
+        assert_eq!(TransactionDecoder::new(5.0).whale_tier(1_000.0), WhaleTier::Whale);
+    }
+
     #[test]
     fn test_wallet_classification() {
         let decoder = TransactionDecoder::new(10.0);
@@ -303,4 +459,72 @@ mod tests {
         // Retail (normal)
         assert_eq!(decoder.classify_wallet(2.0, 1), WalletType::Retail);
     }
+
+    #[test]
+    fn test_decode_raw_pump_buy() {
+        let decoder = TransactionDecoder::new(10.0);
+        let wallet = Pubkey::new_unique().to_string();
+        let mint = Pubkey::new_unique().to_string();
+        let mut accounts: Vec<String> = (0..PUMP_MIN_ACCOUNTS).map(|_| Pubkey::new_unique().to_string()).collect();
+        accounts[PUMP_MINT_ACCOUNT] = mint.clone();
+        accounts[PUMP_USER_ACCOUNT] = wallet.clone();
+
+        let mut data = BUY_DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&12_000_000_000u64.to_le_bytes()); // 12 SOL
+        data.extend_from_slice(&1_000u64.to_le_bytes());
+
+        let decoded = decoder
+            .decode_raw_pump_transaction("sig", "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P", &accounts, &data, 42, 0)
+            .unwrap();
+        assert_eq!(decoded.mint, mint);
+        assert_eq!(decoded.wallet, wallet);
+        assert_eq!(decoded.action, TransactionAction::Buy);
+        assert!((decoded.amount_sol - 12.0).abs() < 1e-9);
+        assert_eq!(decoded.wallet_type, WalletType::Whale);
+        assert_eq!(decoded.signature, "sig");
+    }
+
+    #[test]
+    fn test_decode_raw_rejects_unknown_mint() {
+        let decoder = TransactionDecoder::new(10.0);
+        let mut data = SELL_DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&[0u8; 16]);
+
+        // Too few accounts (e.g. the logs-only fallback, which has none)
+        let short = vec![Pubkey::new_unique().to_string(); PUMP_USER_ACCOUNT];
+        assert!(decoder
+            .decode_raw_pump_transaction("sig", "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P", &short, &data, 0, 0)
+            .is_none());
+        assert!(decoder
+            .decode_raw_pump_transaction("sig", "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P", &[], &data, 0, 0)
+            .is_none());
+
+        assert_eq!(pump_mint_account(&short[..PUMP_MINT_ACCOUNT]), None);
+
+        // Mint slot is not a pubkey
+        let mut bad = vec![Pubkey::new_unique().to_string(); PUMP_MIN_ACCOUNTS];
+        bad[PUMP_MINT_ACCOUNT] = "unknown".to_string();
+        assert!(decoder
+            .decode_raw_pump_transaction("sig", "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P", &bad, &data, 0, 0)
+            .is_none());
+    }
+
+    #[test]
+    fn test_realized_sol_out_from_balances() {
+        let decoder = TransactionDecoder::new(10.0);
+        let user = Pubkey::new_unique();
+        let accounts = vec![user.to_string(), Pubkey::new_unique().to_string()];
+
+        // Seller pays 5000 lamports fee and receives 1.5 SOL
+        let pre = vec![2_000_000_000, 50_000_000_000];
+        let post = vec![3_499_995_000, 48_500_000_000];
+        assert_eq!(decoder.realized_sol_out(&accounts, &pre, &post, 5_000, &user), Some(1_500_000_000));
+
+        // Missing meta or unknown seller
+        assert_eq!(decoder.realized_sol_out(&accounts, &[], &[], 5_000, &user), None);
+        assert_eq!(decoder.realized_sol_out(&accounts, &pre, &post, 5_000, &Pubkey::new_unique()), None);
+
+        // Balance dropped (not a sell proceeds credit)
+        assert_eq!(decoder.realized_sol_out(&accounts, &post, &pre, 0, &user), None);
+    }
 }
@@ -30,7 +30,8 @@ pub struct ExitAdvice {
     pub current_price_lamports: u64, // Current price
     pub hold_time_ms: u32,         // Time since entry (milliseconds)
     pub timestamp_ns: u64,         // When advice generated
-    pub _padding: [u8; 8],
+    pub exit_percent: u8,          // Advised % of position to sell (0 = full exit)
+    pub _padding: [u8; 12],
 }
 
 impl ExitAdvice {
@@ -69,10 +70,17 @@ impl ExitAdvice {
             current_price_lamports,
             hold_time_ms,
             timestamp_ns,
-            _padding: [0u8; 8],
+            exit_percent: 0,
+            _padding: [0u8; 12],
         }
     }
     
+    /// Set the advised % of the position to sell (stop-loss always exits fully)
+    pub fn with_exit_percent(mut self, exit_percent: u8) -> Self {
+        self.exit_percent = exit_percent.min(100);
+        self
+    }
+    
     /// Serialize to bytes for UDP transmission
     pub fn to_bytes(&self) -> Vec<u8> {
         let mut bytes = Vec::with_capacity(Self::SIZE);
@@ -87,7 +95,8 @@ impl ExitAdvice {
         bytes.extend_from_slice(&self.current_price_lamports.to_le_bytes());
         bytes.extend_from_slice(&self.hold_time_ms.to_le_bytes());
         bytes.extend_from_slice(&self.timestamp_ns.to_le_bytes());
-        bytes.extend_from_slice(&self._padding);
+        bytes.push(self.exit_percent);
+        bytes.resize(Self::SIZE, 0);
         
         bytes
     }
@@ -144,6 +153,9 @@ impl ExitAdvice {
         let timestamp_ns = u64::from_le_bytes(
             data[offset..offset+8].try_into().context("Invalid timestamp_ns")?
         );
+        offset += 8;
+        
+        let exit_percent = data[offset];
         
         Ok(Self {
             msg_type,
@@ -156,7 +168,8 @@ impl ExitAdvice {
             current_price_lamports,
             hold_time_ms,
             timestamp_ns,
-            _padding: [0u8; 8],
+            exit_percent,
+            _padding: [0u8; 12],
         })
     }
     
@@ -211,7 +224,7 @@ mod tests {
             1000000, // entry price
             1250000, // current price (+25%)
             5000, // 5 seconds
-        );
+        ).with_exit_percent(50);
         
         let bytes = msg.to_bytes();
         assert_eq!(bytes.len(), ExitAdvice::SIZE);
@@ -226,6 +239,7 @@ mod tests {
         assert_eq!(parsed.entry_price_lamports, 1000000);
         assert_eq!(parsed.current_price_lamports, 1250000);
         assert_eq!(parsed.hold_time_ms, 5000);
+        assert_eq!(parsed.exit_percent, 50);
     }
     
     #[test]
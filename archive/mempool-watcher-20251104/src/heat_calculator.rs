@@ -1,7 +1,7 @@
-use crate::decoder::{DecodedTransaction, WalletType};
+use crate::decoder::{DecodedTransaction, WalletType, WhaleTier, WhaleTiers};
 use dashmap::DashMap;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{HashSet, VecDeque};
 use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH, Instant};
 use tokio::sync::RwLock;
@@ -25,17 +25,80 @@ pub struct HotSignal {
     pub amount_sol: f64,
     pub action: String,
     pub urgency: u8,  // 0-100, how hot is this signal
+    pub whale_tier: WhaleTier,
     pub timestamp: u64,
 }
 
+/// Per-mint heat snapshot (EWMA-smoothed)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Heat {
+    pub mint: String,
+    pub score: u8,
+    pub tx_rate: f64,            // EWMA of transactions per second
+    pub whale_volume: f64,       // EWMA of whale SOL volume in window
+    pub tx_count: usize,         // Raw transaction count in window
+    pub unique_wallets: usize,   // Distinct wallets trading this mint in window
+    pub last_whale_wallet: Option<String>,
+    pub last_whale_amount_sol: f64,
+    pub last_whale_tier: WhaleTier,
+    pub timestamp: u64,
+}
+
+/// EWMA smoothing factor applied per observed transaction
+const EWMA_ALPHA: f64 = 0.3;
+
+/// Rolling window + EWMA state for a single mint
+#[derive(Debug, Clone, Default)]
+struct MintWindow {
+    txs: VecDeque<DecodedTransaction>,
+    tx_rate_ewma: f64,
+    whale_volume_ewma: f64,
+    last_update: u64,
+}
+
+impl MintWindow {
+    /// Drop transactions that fell out of the window
+    fn prune(&mut self, cutoff: u64) {
+        while let Some(front) = self.txs.front() {
+            if front.timestamp >= cutoff {
+                break;
+            }
+            self.txs.pop_front();
+        }
+    }
+
+    /// Fold the current window into the EWMAs (called on every new transaction)
+    ///
+    /// `instant_whale` is the tier-weighted whale volume currently in the window.
+    fn update_ewma(&mut self, now: u64, window_secs: u64, instant_whale: f64) {
+        let instant_rate = self.txs.len() as f64 / window_secs as f64;
+
+        if self.last_update == 0 {
+            self.tx_rate_ewma = instant_rate;
+            self.whale_volume_ewma = instant_whale;
+        } else {
+            self.tx_rate_ewma = EWMA_ALPHA * instant_rate + (1.0 - EWMA_ALPHA) * self.tx_rate_ewma;
+            self.whale_volume_ewma = EWMA_ALPHA * instant_whale + (1.0 - EWMA_ALPHA) * self.whale_volume_ewma;
+        }
+        self.last_update = now;
+    }
+
+    /// Decay factor for a mint that has gone quiet since its last update
+    fn idle_decay(&self, now: u64, window_secs: u64) -> f64 {
+        let idle_secs = now.saturating_sub(self.last_update) as f64;
+        (-idle_secs / window_secs as f64).exp()
+    }
+}
+
 /// Heat calculator - computes real-time mempool metrics
 pub struct HeatCalculator {
     window_secs: u64,
-    whale_threshold_sol: f64,
+    whale_tiers: WhaleTiers,
+    mega_whale_heat_weight: f64,  // Heat multiplier for mega-whale volume (whales = 1.0)
     bot_repeat_threshold: usize,
     
-    // Recent transactions (keyed by timestamp)
-    recent_txs: Arc<DashMap<u64, DecodedTransaction>>,
+    // Per-mint rolling windows (keyed by decoded mint)
+    mint_windows: Arc<DashMap<String, MintWindow>>,
     
     // Wallet activity tracking
     wallet_tx_count: Arc<DashMap<String, usize>>,
@@ -51,10 +114,11 @@ pub struct HeatCalculator {
 impl HeatCalculator {
     pub fn new(window_secs: u64, whale_threshold_sol: f64, bot_repeat_threshold: usize) -> Self {
         Self {
-            window_secs,
-            whale_threshold_sol,
+            window_secs: window_secs.max(1),
+            whale_tiers: WhaleTiers::new(whale_threshold_sol),
+            mega_whale_heat_weight: 1.0,
             bot_repeat_threshold,
-            recent_txs: Arc::new(DashMap::new()),
+            mint_windows: Arc::new(DashMap::new()),
             wallet_tx_count: Arc::new(DashMap::new()),
             signal_cache: Arc::new(RwLock::new(HashSet::new())),
             last_cleanup: Arc::new(RwLock::new(Instant::now())),
@@ -62,9 +126,32 @@ impl HeatCalculator {
         }
     }
 
-    /// Add a transaction to the tracking window
+    /// Enable the mega-whale tier: trades >= `threshold_sol` count `heat_weight`x toward heat
+    pub fn with_mega_whales(mut self, threshold_sol: f64, heat_weight: f64) -> Self {
+        self.whale_tiers.mega_whale_sol = threshold_sol;
+        self.mega_whale_heat_weight = heat_weight;
+        self
+    }
+
+    /// Whale tier of a trade of `amount_sol`
+    pub fn whale_tier(&self, amount_sol: f64) -> WhaleTier {
+        self.whale_tiers.classify(amount_sol)
+    }
+
+    /// Whale SOL volume, with mega-whale trades weighted by their heat multiplier
+    fn weighted_whale_volume<'a>(&self, txs: impl Iterator<Item = &'a DecodedTransaction>) -> f64 {
+        txs.filter(|tx| tx.wallet_type == WalletType::Whale)
+            .map(|tx| match self.whale_tier(tx.amount_sol) {
+                WhaleTier::MegaWhale => tx.amount_sol * self.mega_whale_heat_weight,
+                _ => tx.amount_sol,
+            })
+            .sum()
+    }
+
+    /// Add a transaction to the tracking window of its mint
     pub fn add_transaction(&self, tx: DecodedTransaction) {
-        let timestamp = tx.timestamp;
+        let now = tx.timestamp;
+        let cutoff = now.saturating_sub(self.window_secs);
         
         // Track wallet activity
         self.wallet_tx_count
@@ -72,24 +159,23 @@ impl HeatCalculator {
             .and_modify(|count| *count += 1)
             .or_insert(1);
 
-        // Store transaction
-        self.recent_txs.insert(timestamp, tx);
+        // Store transaction in its mint window and refresh the EWMAs
+        {
+            let mut window = self.mint_windows.entry(tx.mint.clone()).or_default();
+            window.txs.push_back(tx);
+            window.prune(cutoff);
+            let instant_whale = self.weighted_whale_volume(window.txs.iter());
+            window.update_ewma(now, self.window_secs, instant_whale);
+        }
 
         // Cleanup old transactions
         self.cleanup_old_transactions();
     }
 
-    /// Calculate current heat index
+    /// Calculate current heat index (aggregate across all mints)
     pub fn calculate_heat(&self) -> HeatIndex {
         let now = current_timestamp();
-        let window_start = now.saturating_sub(self.window_secs);
-
-        // Filter transactions in window
-        let recent: Vec<_> = self.recent_txs
-            .iter()
-            .filter(|entry| *entry.key() >= window_start)
-            .map(|entry| entry.value().clone())
-            .collect();
+        let recent = self.recent_transactions(now.saturating_sub(self.window_secs));
 
         let tx_count = recent.len() as f64;
         
@@ -100,11 +186,7 @@ impl HeatCalculator {
             0.0
         };
 
-        let whale_volume: f64 = recent
-            .iter()
-            .filter(|tx| tx.wallet_type == WalletType::Whale)
-            .map(|tx| tx.amount_sol)
-            .sum();
+        let whale_volume = self.weighted_whale_volume(recent.iter());
 
         let bot_count = recent
             .iter()
@@ -138,17 +220,106 @@ impl HeatCalculator {
         }
     }
 
+    /// Calculate EWMA-smoothed heat for a single mint
+    pub fn calculate_heat_for(&self, mint: &str) -> Heat {
+        let now = current_timestamp();
+        let cutoff = now.saturating_sub(self.window_secs);
+
+        let Some(window) = self.mint_windows.get(mint) else {
+            return Heat {
+                mint: mint.to_string(),
+                score: 0,
+                tx_rate: 0.0,
+                whale_volume: 0.0,
+                tx_count: 0,
+                unique_wallets: 0,
+                last_whale_wallet: None,
+                last_whale_amount_sol: 0.0,
+                last_whale_tier: WhaleTier::None,
+                timestamp: now,
+            };
+        };
+
+        let recent: Vec<&DecodedTransaction> = window
+            .txs
+            .iter()
+            .filter(|tx| tx.timestamp >= cutoff)
+            .collect();
+
+        let decay = window.idle_decay(now, self.window_secs);
+        let tx_rate = window.tx_rate_ewma * decay;
+        let whale_volume = window.whale_volume_ewma * decay;
+
+        let tx_count = recent.len();
+        let bot_count = recent
+            .iter()
+            .filter(|tx| tx.wallet_type == WalletType::Bot)
+            .count();
+        let bot_density = if tx_count > 0 {
+            (bot_count as f64 / tx_count as f64) * 100.0
+        } else {
+            0.0
+        };
+
+        let unique_wallets = recent
+            .iter()
+            .map(|tx| tx.wallet.as_str())
+            .collect::<HashSet<_>>()
+            .len();
+        let copy_trade_score = ((unique_wallets as f64 - 1.0) * 20.0).clamp(0.0, 100.0);
+
+        let last_whale = recent
+            .iter()
+            .rev()
+            .find(|tx| tx.wallet_type == WalletType::Whale);
+
+        Heat {
+            mint: mint.to_string(),
+            score: self.calculate_composite_score(tx_rate, whale_volume, bot_density, copy_trade_score),
+            tx_rate,
+            whale_volume,
+            tx_count,
+            unique_wallets,
+            last_whale_wallet: last_whale.map(|tx| tx.wallet.clone()),
+            last_whale_amount_sol: last_whale.map(|tx| tx.amount_sol).unwrap_or(0.0),
+            last_whale_tier: last_whale
+                .map(|tx| self.whale_tier(tx.amount_sol))
+                .unwrap_or(WhaleTier::None),
+            timestamp: now,
+        }
+    }
+
+    /// Per-mint heat breakdown, hottest first
+    pub fn heat_by_mint(&self) -> Vec<Heat> {
+        let mints: Vec<String> = self.mint_windows
+            .iter()
+            .map(|entry| entry.key().clone())
+            .collect();
+
+        let mut heats: Vec<Heat> = mints
+            .iter()
+            .map(|mint| self.calculate_heat_for(mint))
+            .filter(|heat| heat.tx_count > 0)
+            .collect();
+
+        heats.sort_by_key(|h| std::cmp::Reverse(h.score));
+        heats
+    }
+
+    /// Hottest mint in the current window, if any
+    pub fn hottest_mint(&self) -> Option<Heat> {
+        self.heat_by_mint().into_iter().next()
+    }
+
     /// Detect potential hot signals (whale movements)
     pub fn check_hot_signals(&self) -> Vec<HotSignal> {
         let now = current_timestamp();
         let window_start = now.saturating_sub(5); // Last 5 seconds
 
-        self.recent_txs
-            .iter()
-            .filter(|entry| *entry.key() >= window_start)
-            .filter(|entry| entry.value().wallet_type == WalletType::Whale)
-            .map(|entry| {
-                let tx = entry.value();
+        self.recent_transactions(window_start)
+            .into_iter()
+            .filter(|tx| tx.wallet_type == WalletType::Whale)
+            .map(|tx| {
                 let urgency = self.calculate_urgency(&tx);
                 
                 HotSignal {
@@ -157,12 +328,28 @@ impl HeatCalculator {
                     amount_sol: tx.amount_sol,
                     action: format!("{:?}", tx.action),
                     urgency,
+                    whale_tier: self.whale_tier(tx.amount_sol),
                     timestamp: tx.timestamp,
                 }
             })
             .collect()
     }
 
+    /// Collect all transactions across mint windows newer than `since`
+    fn recent_transactions(&self, since: u64) -> Vec<DecodedTransaction> {
+        self.mint_windows
+            .iter()
+            .flat_map(|entry| {
+                entry.value()
+                    .txs
+                    .iter()
+                    .filter(|tx| tx.timestamp >= since)
+                    .cloned()
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
     /// Calculate composite heat score
     fn calculate_composite_score(
         &self,
@@ -225,7 +412,10 @@ impl HeatCalculator {
         
         // Wallet score: based on wallet type classification (0-100 scale)
         let wallet_score = match tx.wallet_type {
-            WalletType::Whale => 100.0,    // Max score for whales
+            WalletType::Whale => match self.whale_tier(tx.amount_sol) {
+                WhaleTier::MegaWhale => 100.0,  // Max score for mega-whales
+                _ => 80.0,
+            },
             WalletType::Bot => 30.0,       // Lower score for bots  
             WalletType::Retail => 10.0,    // Minimal score for retail
             WalletType::Unknown => 0.0,    // No score for unknown
@@ -241,17 +431,26 @@ impl HeatCalculator {
         urgency.clamp(50.0, 255.0) as u8
     }
 
-    /// Remove transactions older than window
+    /// Remove transactions older than window and drop mints that have gone cold
     fn cleanup_old_transactions(&self) {
         let now = current_timestamp();
         let cutoff = now.saturating_sub(self.window_secs);
+        let stale_cutoff = now.saturating_sub(self.window_secs * 5);
 
-        self.recent_txs.retain(|timestamp, _| *timestamp >= cutoff);
+        self.mint_windows.retain(|_, window| {
+            window.prune(cutoff);
+            !window.txs.is_empty() || window.last_update >= stale_cutoff
+        });
     }
 
     /// Get current transaction count in window
     pub fn get_transaction_count(&self) -> usize {
-        self.recent_txs.len()
+        self.mint_windows.iter().map(|entry| entry.value().txs.len()).sum()
+    }
+
+    /// Get number of mints currently tracked
+    pub fn get_tracked_mint_count(&self) -> usize {
+        self.mint_windows.len()
     }
 
     /// Get wallet activity count
@@ -398,4 +597,112 @@ mod tests {
         assert!(!signals.is_empty());
         assert_eq!(signals[0].mint, "hot_mint");
     }
+
+    #[test]
+    fn test_mega_whale_tier_weights_heat_and_urgency() {
+        let plain = HeatCalculator::new(10, 5.0, 3);
+        let tiered = HeatCalculator::new(10, 5.0, 3).with_mega_whales(50.0, 2.0);
+        let now = current_timestamp();
+
+        for calculator in [&plain, &tiered] {
+            for (i, amount) in [60.0, 8.0].iter().enumerate() {
+                calculator.add_transaction(DecodedTransaction {
+                    signature: format!("sig{}", i),
+                    mint: format!("mint{}", i),
+                    action: TransactionAction::Buy,
+                    amount_sol: *amount,
+                    wallet: format!("whale{}", i),
+                    wallet_type: WalletType::Whale,
+                    timestamp: now,
+                    program: ProgramType::PumpFun,
+                });
+            }
+        }
+
+        assert_eq!(tiered.whale_tier(60.0), WhaleTier::MegaWhale);
+        assert_eq!(plain.whale_tier(60.0), WhaleTier::Whale);
+        assert!(tiered.calculate_heat().whale_activity > plain.calculate_heat().whale_activity);
+        assert_eq!(tiered.calculate_heat_for("mint0").last_whale_tier, WhaleTier::MegaWhale);
+
+        let signals = tiered.check_hot_signals();
+        let mega = signals.iter().find(|s| s.mint == "mint0").unwrap();
+        let whale = signals.iter().find(|s| s.mint == "mint1").unwrap();
+        assert_eq!(mega.whale_tier, WhaleTier::MegaWhale);
+        assert_eq!(whale.whale_tier, WhaleTier::Whale);
+        assert!(mega.urgency > whale.urgency);
+    }
+
+    #[test]
+    fn test_per_mint_heat_breakdown() {
+        let calculator = HeatCalculator::new(10, 10.0, 3);
+        let now = current_timestamp();
+
+        // Busy mint with a whale
+        for i in 0..6 {
+            calculator.add_transaction(DecodedTransaction {
+                signature: format!("busy{}", i),
+                mint: "busy_mint".to_string(),
+                action: TransactionAction::Buy,
+                amount_sol: if i == 5 { 25.0 } else { 1.0 },
+                wallet: format!("wallet{}", i),
+                wallet_type: if i == 5 { WalletType::Whale } else { WalletType::Retail },
+                timestamp: now,
+                program: ProgramType::PumpFun,
+            });
+        }
+
+        // Quiet mint
+        calculator.add_transaction(DecodedTransaction {
+            signature: "quiet".to_string(),
+            mint: "quiet_mint".to_string(),
+            action: TransactionAction::Buy,
+            amount_sol: 0.5,
+            wallet: "lonely".to_string(),
+            wallet_type: WalletType::Retail,
+            timestamp: now,
+            program: ProgramType::PumpFun,
+        });
+
+        let busy = calculator.calculate_heat_for("busy_mint");
+        let quiet = calculator.calculate_heat_for("quiet_mint");
+        assert_eq!(busy.tx_count, 6);
+        assert_eq!(busy.unique_wallets, 6);
+        assert!(busy.whale_volume > 0.0);
+        assert_eq!(busy.last_whale_wallet.as_deref(), Some("wallet5"));
+        assert!(busy.score > quiet.score);
+
+        let hottest = calculator.hottest_mint().unwrap();
+        assert_eq!(hottest.mint, "busy_mint");
+        assert_eq!(calculator.get_tracked_mint_count(), 2);
+        assert_eq!(calculator.get_transaction_count(), 7);
+    }
+
+    #[test]
+    fn test_unknown_mint_has_zero_heat() {
+        let calculator = HeatCalculator::new(10, 10.0, 3);
+        let heat = calculator.calculate_heat_for("never_seen");
+        assert_eq!(heat.score, 0);
+        assert_eq!(heat.tx_count, 0);
+        assert!(calculator.hottest_mint().is_none());
+    }
+
+    #[test]
+    fn test_ewma_decays_when_idle() {
+        let mut window = MintWindow::default();
+        window.txs.push_back(DecodedTransaction {
+            signature: "sig".to_string(),
+            mint: "m".to_string(),
+            action: TransactionAction::Buy,
+            amount_sol: 1.0,
+            wallet: "w".to_string(),
+            wallet_type: WalletType::Retail,
+            timestamp: 1_000,
+            program: ProgramType::PumpFun,
+        });
+        window.update_ewma(1_000, 10, 0.0);
+        assert!((window.tx_rate_ewma - 0.1).abs() < 1e-9);
+
+        assert!((window.idle_decay(1_000, 10) - 1.0).abs() < 1e-9);
+        assert!(window.idle_decay(1_010, 10) < 0.4);
+    }
 }
@@ -1,10 +1,12 @@
 use anyhow::Result;
 use log::{debug, error, info, warn};
+use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::time::{interval, Duration};
 use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_sdk::signature::Signature;
 
+mod auth;
 mod config;
 mod decoder;
 mod heat_calculator;
@@ -22,6 +24,9 @@ mod exit_advice;      // NEW: ExitAdvice message for profit target/stop-loss ale
 mod position_update;  // NEW: PositionUpdate message for real-time P&L tracking
 mod position_tracker; // NEW: Tracks active positions for P&L monitoring
 mod manual_exit;      // NEW: ManualExitNotification for user manual exits
+mod status_server;    // HTTP status endpoint (WebSocket connection health)
+mod sol_price;        // SOL/USD price feed from data-mining (manual-exit P&L)
+mod rpc_poll;         // Commitment / attempt / timeout policy for RPC signature polling
 
 use config::Config;
 use decoder::TransactionDecoder;
@@ -33,7 +38,10 @@ use watch_signature::SignatureTracker;
 use watch_sig_enhanced::SignatureTrackerEnhanced;
 use watch_listener_enhanced::WatchSignatureListenerEnhanced;
 use position_tracker::PositionTracker;
+use confirmation_broadcaster::ConfirmationBroadcaster;
 use tx_confirmed::TxConfirmed;
+use sol_price::SolPriceFeed;
+use rpc_poll::{PollOutcome, RpcPollPolicy};
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -54,21 +62,37 @@ async fn main() -> Result<()> {
     // Initialize components
     let (monitor, mut tx_receiver) = TransactionMonitor::new(config.rpc.ws_url.clone());
     
-    let decoder = Arc::new(TransactionDecoder::new(config.thresholds.whale_threshold_sol));
+    let decoder = Arc::new(
+        TransactionDecoder::new(config.thresholds.whale_threshold_sol)
+            .with_mega_whale_threshold(config.thresholds.mega_whale_threshold_sol),
+    );
     
     let alpha_wallet_manager = Arc::new(AlphaWalletManager::new(config.database.sqlite_path.clone()));
     
-    let heat_calculator = Arc::new(HeatCalculator::new(
-        config.monitoring.transaction_window_secs,
-        config.thresholds.whale_threshold_sol,
-        config.thresholds.bot_repeat_threshold,
-    ));
+    let heat_calculator = Arc::new(
+        HeatCalculator::new(
+            config.monitoring.transaction_window_secs,
+            config.thresholds.whale_threshold_sol,
+            config.thresholds.bot_repeat_threshold,
+        )
+        .with_mega_whales(
+            config.thresholds.mega_whale_threshold_sol,
+            config.thresholds.mega_whale_heat_weight,
+        ),
+    );
+    
+    // Shared HMAC secret: Brain only accepts signed manual-exit notifications and ExitAdvice
+    let advice_auth = auth::AdviceAuth::load(
+        config.udp.advice_bus_secret.as_deref(),
+        &config.udp.advice_bus_secret_file,
+    )?;
     
     let udp_publisher = Arc::new(UdpPublisher::new(
         &config.udp.bind_address,
         config.udp.brain_port,
         config.udp.brain_confirmation_port,
-    )?);
+        config.udp.advice_bus_port,
+    )?.with_auth(advice_auth.clone()));
     
     // Initialize signature trackers (basic and enhanced)
     let signature_tracker = Arc::new(SignatureTracker::new());
@@ -77,6 +101,21 @@ async fn main() -> Result<()> {
     // Initialize position tracker for P&L monitoring
     let position_tracker = Arc::new(PositionTracker::new());
     
+    // Δ-window TxConfirmedContext for enhanced watches, plus signed ExitAdvice
+    // to Brain's Advice Bus when a confirmed position hits its target / stop
+    let confirmation_broadcaster = Arc::new(ConfirmationBroadcaster::new(
+        &config.udp.bind_address,
+        45115, // Brain confirmation listener (45115)
+        config.udp.executor_confirmed_port,
+        decoder.clone(),
+        alpha_wallet_manager.clone(),
+        position_tracker.clone(),
+        udp_publisher.clone(),
+    )?);
+    
+    // SOL price from data-mining's SolPriceUpdate stream (default until first update)
+    let sol_price_feed = Arc::new(SolPriceFeed::new(config.monitoring.default_sol_price_usd));
+    
     // Initialize RPC client for signature polling backup
     let rpc_client = Arc::new(RpcClient::new(config.rpc.url.clone()));
 
@@ -120,15 +159,20 @@ async fn main() -> Result<()> {
     };
     
     // Spawn RPC polling task (CRITICAL: backup for unreliable WebSocket)
-    // Polls watched signatures every 2 seconds to detect confirmations
+    // Polls watched signatures every 2 seconds until they reach the configured
+    // commitment, or reports them FAILED once out of attempts / time
+    let rpc_poll_policy = RpcPollPolicy::from_config(&config.rpc)?;
     let rpc_polling_handle = {
         let tracker = signature_tracker_enhanced.clone();
         let rpc = rpc_client.clone();
         let position_tracker = position_tracker.clone();
+        let broadcaster = confirmation_broadcaster.clone();
         let config = config.clone();
+        let policy = rpc_poll_policy;
         
         tokio::spawn(async move {
-            info!("🔄 RPC signature polling task started (interval: 2s)");
+            info!("🔄 RPC signature polling task started (interval: 2s, commitment: {:?}, max polls: {}, timeout: {}s)",
+                  policy.commitment.commitment, policy.max_poll_attempts, policy.timeout_secs);
             
             // Create UDP sockets for sending TxConfirmed messages
             let executor_socket = tokio::net::UdpSocket::bind("0.0.0.0:0").await
@@ -139,23 +183,26 @@ async fn main() -> Result<()> {
             let executor_addr = format!("127.0.0.1:{}", config.udp.executor_confirmed_port);
             let brain_addr = format!("127.0.0.1:{}", config.udp.brain_confirmation_port);
             
+            // Polls so far per signature (pruned once a signature stops being watched)
+            let mut poll_counts: HashMap<String, u32> = HashMap::new();
             let mut tick = interval(Duration::from_secs(2));
             
             loop {
                 tick.tick().await;
                 
                 let signatures = tracker.get_all_signatures().await;
+                poll_counts.retain(|sig, _| signatures.contains(sig));
                 if signatures.is_empty() {
                     continue;
                 }
                 
                 debug!("🔍 Polling {} signatures via RPC", signatures.len());
                 
-                // Parse signatures
-                let mut sig_objects = Vec::new();
-                for sig_str in &signatures {
+                // Parse signatures (kept paired so statuses line up with their strings)
+                let mut watched = Vec::new();
+                for sig_str in signatures {
                     match sig_str.parse::<Signature>() {
-                        Ok(sig) => sig_objects.push(sig),
+                        Ok(sig) => watched.push((sig_str, sig)),
                         Err(e) => {
                             warn!("⚠️  Invalid signature format: {} - {}", &sig_str[..12], e);
                             continue;
@@ -163,70 +210,101 @@ async fn main() -> Result<()> {
                     }
                 }
                 
-                if sig_objects.is_empty() {
+                if watched.is_empty() {
                     continue;
                 }
                 
                 // Batch query signature statuses
-                match rpc.get_signature_statuses(&sig_objects).await {
-                    Ok(response) => {
-                        for (idx, status_opt) in response.value.iter().enumerate() {
-                            if let Some(status) = status_opt {
-                                let sig_str = &signatures[idx];
-                                
-                                // Check if confirmed or finalized
-                                if status.confirmation_status.is_some() {
-                                    info!("✅ RPC POLL: Signature {} confirmed via RPC backup", &sig_str[..12]);
-                                    
-                                    // Remove from tracker
-                                    if let Some(watch) = tracker.remove(sig_str).await {
-                                        let mint_str = watch.mint_str();
-                                        
-                                        // Determine status
-                                        let tx_status = if status.err.is_some() {
-                                            TxConfirmed::STATUS_FAILED
-                                        } else {
-                                            TxConfirmed::STATUS_SUCCESS
-                                        };
-                                        
-                                        // Create confirmation message
-                                        let tx_confirmed = TxConfirmed::new(
-                                            watch.signature,
-                                            watch.mint,
-                                            watch.trade_id,
-                                            watch.side,
-                                            tx_status,
-                                        );
-                                        
-                                        let bytes = tx_confirmed.to_bytes();
-                                        
-                                        // Send to Executor (port 45132)
-                                        if let Err(e) = executor_socket.send_to(&bytes, &executor_addr).await {
-                                            error!("❌ Failed to send TxConfirmed to Executor (RPC poll): {}", e);
-                                        } else {
-                                            info!("📤 Sent TxConfirmed to Executor (RPC poll): {} | {} | mint: {}", 
-                                                  &sig_str[..12], tx_confirmed.status_str(), &mint_str[..12]);
-                                        }
-                                        
-                                        // Send to Brain (port 45115)
-                                        if let Err(e) = brain_socket.send_to(&bytes, &brain_addr).await {
-                                            error!("❌ Failed to send TxConfirmed to Brain (RPC poll): {}", e);
-                                        } else {
-                                            info!("📤 Sent TxConfirmed to Brain (RPC poll): {} | {}", 
-                                                  &sig_str[..12], tx_confirmed.status_str());
-                                        }
-                                        
-                                        // Track position if BUY
-                                        if watch.side == 0 {
-                                            position_tracker.add_position(watch).await;
-                                        }
-                                    }
-                                }
+                let sig_objects: Vec<Signature> = watched.iter().map(|(_, sig)| *sig).collect();
+                let response = match rpc.get_signature_statuses(&sig_objects).await {
+                    Ok(response) => response,
+                    Err(e) => {
+                        warn!("⚠️  RPC batch status query failed: {}", e);
+                        continue;
+                    }
+                };
+                
+                let now_ns = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .unwrap()
+                    .as_nanos() as u64;
+                
+                for ((sig_str, _), status_opt) in watched.iter().zip(response.value.iter()) {
+                    let Some(watch) = tracker.get(sig_str).await else {
+                        continue;
+                    };
+                    let polls = poll_counts.entry(sig_str.clone()).or_insert(0);
+                    *polls += 1;
+                    let age_secs = now_ns.saturating_sub(watch.timestamp_ns) / 1_000_000_000;
+                    
+                    // Determine status
+                    let tx_status = match policy.evaluate(status_opt.as_ref(), *polls, age_secs) {
+                        PollOutcome::Pending => continue,
+                        PollOutcome::Landed => {
+                            info!("✅ RPC POLL: Signature {} confirmed via RPC backup", &sig_str[..12]);
+                            if status_opt.as_ref().is_some_and(|status| status.err.is_some()) {
+                                TxConfirmed::STATUS_FAILED
+                            } else {
+                                TxConfirmed::STATUS_SUCCESS
                             }
                         }
+                        PollOutcome::Expired => {
+                            warn!("⏰ RPC POLL: Signature {} not {:?} after {} polls / {}s, reporting FAILED",
+                                  &sig_str[..12], policy.commitment.commitment, polls, age_secs);
+                            TxConfirmed::STATUS_FAILED
+                        }
+                    };
+                    
+                    // Remove from tracker
+                    let Some(watch) = tracker.remove(sig_str).await else {
+                        continue;
+                    };
+                    poll_counts.remove(sig_str);
+                    let mint_str = watch.mint_str();
+                    
+                    // Create confirmation message
+                    let tx_confirmed = TxConfirmed::new(
+                        watch.signature,
+                        watch.mint,
+                        watch.trade_id,
+                        watch.side,
+                        tx_status,
+                    );
+                    
+                    let bytes = tx_confirmed.to_bytes();
+                    
+                    // Send to Executor (port 45132)
+                    if let Err(e) = executor_socket.send_to(&bytes, &executor_addr).await {
+                        error!("❌ Failed to send TxConfirmed to Executor (RPC poll): {}", e);
+                    } else {
+                        info!("📤 Sent TxConfirmed to Executor (RPC poll): {} | {} | mint: {}", 
+                              &sig_str[..12], tx_confirmed.status_str(), &mint_str[..12]);
                     }
-                    Err(e) => {
-                        warn!("⚠️  RPC batch status query failed: {}", e);
+                    
+                    // Send to Brain (port 45115)
+                    if let Err(e) = brain_socket.send_to(&bytes, &brain_addr).await {
+                        error!("❌ Failed to send TxConfirmed to Brain (RPC poll): {}", e);
+                    } else {
+                        info!("📤 Sent TxConfirmed to Brain (RPC poll): {} | {}", 
+                              &sig_str[..12], tx_confirmed.status_str());
+                    }
+                    
+                    // Δ-window context + exit target check (waits out its micro-buffer off this loop)
+                    {
+                        let broadcaster = broadcaster.clone();
+                        let watch = watch.clone();
+                        let slot = status_opt.as_ref().map_or(0, |status| status.slot);
+                        tokio::spawn(async move {
+                            let price = watch.entry_price_lamports;
+                            if let Err(e) = broadcaster.broadcast_with_context(watch, slot, tx_status, price, &mint_str).await {
+                                error!("❌ Confirmation broadcast failed: {}", e);
+                            }
+                        });
+                    }
+                    
+                    // Track position if the BUY landed
+                    if watch.side == 0 && tx_status == TxConfirmed::STATUS_SUCCESS {
+                        position_tracker.add_position(watch).await;
                     }
                 }
             }
@@ -260,7 +338,32 @@ async fn main() -> Result<()> {
         })
     };
 
-    // Spawn WebSocket monitoring task
+    // Spawn status endpoint (WebSocket connection state + reconnect count)
+    {
+        let health = monitor.health();
+        let bind_address = config.udp.bind_address.clone();
+        let port = config.monitoring.status_port;
+        tokio::spawn(async move {
+            if let Err(e) = status_server::start_status_server(&bind_address, port, health).await {
+                error!("❌ Status server failed: {}", e);
+            }
+        });
+    }
+
+    // Spawn SOL price listener (keeps manual-exit P&L on the live price)
+    {
+        let feed = sol_price_feed.clone();
+        let bind_addr = format!("{}:{}", config.udp.bind_address, config.udp.sol_price_port);
+        let auth = advice_auth.clone();
+        let max_age_secs = config.monitoring.sol_price_max_age_secs;
+        tokio::spawn(async move {
+            if let Err(e) = feed.listen(&bind_addr, auth, max_age_secs).await {
+                error!("❌ SOL price listener failed: {}", e);
+            }
+        });
+    }
+
+    // Spawn WebSocket monitoring task (reconnects internally with backoff)
     let monitor_handle = {
         let monitor = Arc::new(monitor);
         tokio::spawn(async move {
@@ -277,6 +380,7 @@ async fn main() -> Result<()> {
         let udp_publisher = udp_publisher.clone();
         let tracker = signature_tracker.clone();
         let position_tracker = position_tracker.clone();
+        let sol_price_feed = sol_price_feed.clone();
         let config = config.clone();
         
         tokio::spawn(async move {
@@ -334,16 +438,17 @@ async fn main() -> Result<()> {
                     }
                 } else {
                     // NOT a watched signature - check if it's a manual exit for a tracked position
-                    // Parse the raw transaction to extract mint address
-                    // For Pump.fun transactions, mint is typically in accounts[2]
+                    // The mint comes from the pump instruction's own accounts
                     
                     // Log ALL untracked confirmed transactions for debugging
                     debug!("🔍 Untracked transaction: {} | program: {} | accounts: {} | data_len: {}",
                            &raw_tx.signature[..12], &raw_tx.program_id[..8], 
                            raw_tx.accounts.len(), raw_tx.data.len());
                     
-                    if raw_tx.program_id == "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P" && raw_tx.accounts.len() > 2 {
-                        let mint_str = &raw_tx.accounts[2];
+                    let instruction_accounts = raw_tx.instruction_account_keys();
+                    let pump_mint = crate::decoder::pump_mint_account(&instruction_accounts)
+                        .filter(|_| raw_tx.program_id == "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P");
+                    if let Some(mint_str) = pump_mint {
                         
                         debug!("🔍 Pump.fun tx | mint: {} | checking if tracked...", &mint_str[..8]);
                         
@@ -374,36 +479,47 @@ async fn main() -> Result<()> {
                                                     let mut sig_array = [0u8; 64];
                                                     sig_array.copy_from_slice(&sig_bytes);
                                                     
-                                                    // Extract exit price from SELL instruction using decoder
-                                                    let exit_price_lamports = if let Ok(pubkey_accounts) = raw_tx.accounts.iter()
+                                                    // Extract exit price from SELL instruction using decoder,
+                                                    // and the SOL actually received from the tx meta balances
+                                                    let (exit_price_lamports, realized_sol_lamports) = if let Ok(pubkey_accounts) = instruction_accounts.iter()
                                                         .map(|a| a.parse::<solana_sdk::pubkey::Pubkey>())
                                                         .collect::<Result<Vec<_>, _>>() {
                                                         
                                                         // Decode SELL instruction
                                                         if let Ok(Some(sell_ix)) = decoder.parse_pump_sell_instruction(&raw_tx.data, &pubkey_accounts) {
-                                                            // Use min_sol_out as exit price approximation
-                                                            // This is the minimum SOL the user expects to receive
-                                                            debug!("💵 Decoded SELL: {} tokens for min {} lamports SOL",
-                                                                   sell_ix.token_amount, sell_ix.min_sol_out);
-                                                            sell_ix.min_sol_out
+                                                            let realized = decoder.realized_sol_out(
+                                                                &raw_tx.accounts,
+                                                                &raw_tx.pre_balances,
+                                                                &raw_tx.post_balances,
+                                                                raw_tx.fee,
+                                                                &sell_ix.user,
+                                                            );
+                                                            match realized {
+                                                                Some(lamports) => debug!("💵 Decoded SELL: {} tokens for {} lamports SOL (min {})",
+                                                                                         sell_ix.token_amount, lamports, sell_ix.min_sol_out),
+                                                                // min_sol_out is only the slippage floor - P&L will be understated
+                                                                None => warn!("⚠️ No balance meta for SELL, falling back to min_sol_out {} lamports",
+                                                                              sell_ix.min_sol_out),
+                                                            }
+                                                            (sell_ix.min_sol_out, realized)
                                                         } else {
                                                             warn!("⚠️ Failed to decode SELL instruction, using 0");
-                                                            0u64
+                                                            (0u64, None)
                                                         }
                                                     } else {
                                                         warn!("⚠️ Failed to parse account pubkeys, using 0");
-                                                        0u64
+                                                        (0u64, None)
                                                     };
                                                     
-                                                    // Get SOL price (TODO: integrate with oracle or data-mining)
-                                                    // For now, use a reasonable estimate
-                                                    let sol_price_usd = 200.0;  // Conservative estimate
+                                                    // Latest SOL price from data-mining (configured default until one arrives)
+                                                    let sol_price_usd = sol_price_feed.price_usd();
                                                     
                                                     // Check for manual exit and calculate P&L
                                                     if let Some(manual_exit) = position_tracker.check_manual_exit(
                                                         &mint_array,
                                                         &sig_array,
                                                         exit_price_lamports,
+                                                        realized_sol_lamports,
                                                         sol_price_usd,
                                                     ).await {
                                                         // Copy values to avoid packed struct alignment issues
@@ -444,22 +560,54 @@ async fn main() -> Result<()> {
                     continue;
                 }
                 
-                // In production, fetch full transaction details here
-                // For now, we work with what we have from logs
+                // Decode the full transaction to get the real mint and trade size
+                let repeat_count = raw_tx.accounts.first()
+                    .map(|wallet| heat_calculator.get_wallet_activity(wallet))
+                    .unwrap_or(0);
+                let instruction_accounts = raw_tx.instruction_account_keys();
+                if instruction_accounts.is_empty() {
+                    debug!("⏭️  No instruction accounts for {} (logs-only fallback) - skipping heat update", &raw_tx.signature[..12]);
+                    continue;
+                }
+                let decoded = match decoder.decode_raw_pump_transaction(
+                    &raw_tx.signature,
+                    &raw_tx.program_id,
+                    &instruction_accounts,
+                    &raw_tx.data,
+                    raw_tx.timestamp.max(0) as u64,
+                    repeat_count,
+                ) {
+                    Some(decoded) => decoded,
+                    None => {
+                        debug!("⏭️  Could not decode mint for {} - skipping heat update", &raw_tx.signature[..12]);
+                        continue;
+                    }
+                };
                 
-                // Calculate heat and publish if hot
-                let heat = heat_calculator.calculate_heat();
+                heat_calculator.add_transaction(decoded.clone());
+                
+                // Calculate heat for this mint and publish if hot
+                let heat = heat_calculator.calculate_heat_for(&decoded.mint);
                 
                 if heat.score >= 70 {
-                    info!("� HOT SIGNAL detected! Score: {}", heat.score);
+                    info!("🔥 HOT SIGNAL detected! Mint: {} | Score: {} | {:?} {:.2} SOL",
+                          &decoded.mint[..8], heat.score, decoded.action, decoded.amount_sol);
+                    
+                    // Prefer the whale that made this mint hot, fall back to the current trader
+                    let whale_wallet = heat.last_whale_wallet.clone()
+                        .unwrap_or_else(|| decoded.wallet.clone());
+                    let whale_tier = heat.last_whale_tier.max(decoder.whale_tier(decoded.amount_sol));
                     
-                    // Create hot signal
                     let hot_signal = heat_calculator::HotSignal {
-                        mint: "unknown".to_string(), // Would be extracted from full tx
-                        whale_wallet: "unknown".to_string(),
-                        amount_sol: 0.0,
-                        action: "BUY".to_string(),
+                        mint: decoded.mint.clone(),
+                        whale_wallet,
+                        amount_sol: decoded.amount_sol,
+                        action: match decoded.action {
+                            decoder::TransactionAction::Buy => "BUY".to_string(),
+                            decoder::TransactionAction::Sell => "SELL".to_string(),
+                        },
                         urgency: heat.score,
+                        whale_tier,
                         timestamp: chrono::Utc::now().timestamp() as u64,
                     };
                     
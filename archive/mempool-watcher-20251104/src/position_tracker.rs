@@ -360,11 +360,16 @@ impl PositionTracker {
     
     /// Check if a SELL transaction is a manual exit (not from our executor)
     /// Returns ManualExitNotification if manual exit detected
+    ///
+    /// `realized_sol_lamports` is the SOL actually received, decoded from the
+    /// transaction meta. When unavailable, P&L falls back to treating
+    /// `exit_price_lamports` (the instruction's min_sol_out) as the exit price.
     pub async fn check_manual_exit(
         &self,
         mint: &[u8; 32],
         exit_signature: &[u8; 64],
         exit_price_lamports: u64,
+        realized_sol_lamports: Option<u64>,
         sol_price_usd: f64,
     ) -> Option<crate::manual_exit::ManualExitNotification> {
         let positions = self.positions.read().await;
@@ -374,11 +379,22 @@ impl PositionTracker {
         let pos = positions.get(&mint_str)?;
         
         // Calculate P&L
-        let price_ratio = exit_price_lamports as f64 / pos.entry_price_lamports as f64;
-        let exit_value_sol = pos.size_sol * price_ratio;
-        
-        // Fees: 0.3% pump.fun fee on entry + exit
-        let fees_sol = pos.size_sol * (pos.fee_bps as f64 / 10000.0) * 2.0;
+        let (price_ratio, exit_value_sol, fees_sol, exit_price_lamports) = match realized_sol_lamports {
+            Some(lamports) if pos.size_sol > 0.0 => {
+                // Proceeds are already net of the exit fee; only the entry fee remains
+                let exit_value_sol = lamports as f64 / 1_000_000_000.0;
+                let price_ratio = exit_value_sol / pos.size_sol;
+                let fees_sol = pos.size_sol * (pos.fee_bps as f64 / 10000.0);
+                let exit_price = (pos.entry_price_lamports as f64 * price_ratio) as u64;
+                (price_ratio, exit_value_sol, fees_sol, exit_price)
+            }
+            _ => {
+                let price_ratio = exit_price_lamports as f64 / pos.entry_price_lamports as f64;
+                // Fees: 0.3% pump.fun fee on entry + exit
+                let fees_sol = pos.size_sol * (pos.fee_bps as f64 / 10000.0) * 2.0;
+                (price_ratio, pos.size_sol * price_ratio, fees_sol, exit_price_lamports)
+            }
+        };
         let net_profit_sol = exit_value_sol - pos.size_sol - fees_sol;
         let realized_pnl_usd = net_profit_sol * sol_price_usd;
         let pnl_percent = (price_ratio - 1.0) * 100.0;
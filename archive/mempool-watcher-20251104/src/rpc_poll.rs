@@ -0,0 +1,119 @@
+//! ⏳ RPC signature polling policy
+//!
+//! Decides, for each watched signature the RPC backup poller checks, whether
+//! it has landed at the configured commitment, is still pending, or should be
+//! declared failed after `SIGNATURE_MAX_POLL_ATTEMPTS` polls or
+//! `SIGNATURE_POLL_TIMEOUT_SECS` (a dropped transaction never lands).
+
+use anyhow::Result;
+use solana_sdk::commitment_config::{CommitmentConfig, CommitmentLevel};
+use solana_transaction_status::TransactionStatus;
+use std::str::FromStr;
+
+use crate::config::RpcConfig;
+
+/// What to do with a watched signature after one poll
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PollOutcome {
+    /// Reached the required commitment (the tx itself may still have errored)
+    Landed,
+    /// Not there yet; poll again next tick
+    Pending,
+    /// Out of attempts or time; report as failed
+    Expired,
+}
+
+/// When a polled signature counts as landed, and when to give up on it
+#[derive(Debug, Clone, Copy)]
+pub struct RpcPollPolicy {
+    /// Commitment a status must reach (`processed` is too weak for P&L)
+    pub commitment: CommitmentConfig,
+    /// Polls below the required commitment before declaring failure
+    pub max_poll_attempts: u32,
+    /// Age after which an unconfirmed signature is declared failed
+    pub timeout_secs: u64,
+}
+
+impl RpcPollPolicy {
+    /// Build from `SIGNATURE_COMMITMENT` / `SIGNATURE_MAX_POLL_ATTEMPTS` / `SIGNATURE_POLL_TIMEOUT_SECS`
+    pub fn from_config(rpc: &RpcConfig) -> Result<Self> {
+        let commitment = CommitmentLevel::from_str(&rpc.signature_commitment)
+            .map_err(|_| anyhow::anyhow!("Invalid SIGNATURE_COMMITMENT: {}", rpc.signature_commitment))?;
+        Ok(Self {
+            commitment: CommitmentConfig { commitment },
+            max_poll_attempts: rpc.signature_max_poll_attempts,
+            timeout_secs: rpc.signature_poll_timeout_secs,
+        })
+    }
+
+    /// Classify a signature given its latest status, how many times it has
+    /// been polled (including this one) and its age since it was watched
+    pub fn evaluate(&self, status: Option<&TransactionStatus>, polls: u32, age_secs: u64) -> PollOutcome {
+        if status.is_some_and(|s| s.satisfies_commitment(self.commitment)) {
+            PollOutcome::Landed
+        } else if polls >= self.max_poll_attempts || age_secs >= self.timeout_secs {
+            PollOutcome::Expired
+        } else {
+            PollOutcome::Pending
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_transaction_status::TransactionConfirmationStatus;
+
+    fn policy(commitment: CommitmentConfig) -> RpcPollPolicy {
+        RpcPollPolicy { commitment, max_poll_attempts: 3, timeout_secs: 30 }
+    }
+
+    fn status(confirmation: TransactionConfirmationStatus) -> TransactionStatus {
+        let confirmations = match confirmation {
+            TransactionConfirmationStatus::Finalized => None,
+            TransactionConfirmationStatus::Confirmed => Some(2),
+            TransactionConfirmationStatus::Processed => Some(0),
+        };
+        TransactionStatus {
+            slot: 1,
+            confirmations,
+            status: Ok(()),
+            err: None,
+            confirmation_status: Some(confirmation),
+        }
+    }
+
+    #[test]
+    fn test_processed_is_not_landed_at_confirmed() {
+        let confirmed = policy(CommitmentConfig::confirmed());
+        let processed = status(TransactionConfirmationStatus::Processed);
+        assert_eq!(confirmed.evaluate(Some(&processed), 1, 2), PollOutcome::Pending);
+        assert_eq!(
+            confirmed.evaluate(Some(&status(TransactionConfirmationStatus::Confirmed)), 1, 2),
+            PollOutcome::Landed
+        );
+        assert_eq!(policy(CommitmentConfig::processed()).evaluate(Some(&processed), 1, 2), PollOutcome::Landed);
+
+        let finalized = policy(CommitmentConfig::finalized());
+        assert_eq!(
+            finalized.evaluate(Some(&status(TransactionConfirmationStatus::Confirmed)), 1, 2),
+            PollOutcome::Pending
+        );
+        assert_eq!(
+            finalized.evaluate(Some(&status(TransactionConfirmationStatus::Finalized)), 1, 2),
+            PollOutcome::Landed
+        );
+    }
+
+    #[test]
+    fn test_expires_after_attempts_or_timeout() {
+        let confirmed = policy(CommitmentConfig::confirmed());
+        assert_eq!(confirmed.evaluate(None, 2, 10), PollOutcome::Pending);
+        assert_eq!(confirmed.evaluate(None, 3, 10), PollOutcome::Expired);
+        assert_eq!(confirmed.evaluate(None, 1, 30), PollOutcome::Expired);
+
+        // Landing wins even on the last allowed poll
+        let landed = status(TransactionConfirmationStatus::Confirmed);
+        assert_eq!(confirmed.evaluate(Some(&landed), 3, 30), PollOutcome::Landed);
+    }
+}
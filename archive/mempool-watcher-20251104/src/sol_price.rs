@@ -0,0 +1,191 @@
+//! 💲 SOL price feed from data-mining
+//!
+//! Listens for the signed 32-byte SolPriceUpdate messages data-mining
+//! broadcasts (port 45136, data-mining's `price_oracle.mempool_price_addr`)
+//! and keeps the latest SOL/USD price in an atomic so the manual-exit P&L
+//! path can read it without locking. Until the first update arrives, the
+//! configured default price is returned.
+
+use anyhow::Result;
+use log::{debug, error, info, warn};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::net::UdpSocket;
+
+use crate::auth::AdviceAuth;
+
+/// Message type byte for SolPriceUpdate
+pub const SOL_PRICE_UPDATE_MSG_TYPE: u8 = 14;
+
+/// SolPriceUpdate payload size: [msg_type(1), price_usd(4), timestamp(8), source(1), timestamp_ms(8), padding(10)]
+pub const SOL_PRICE_UPDATE_SIZE: usize = 32;
+
+/// Latest SOL/USD price, stored as f64 bits (0 = no update received yet)
+pub struct SolPriceFeed {
+    price_bits: AtomicU64,
+    default_usd: f64,
+}
+
+impl SolPriceFeed {
+    pub fn new(default_usd: f64) -> Self {
+        Self {
+            price_bits: AtomicU64::new(0),
+            default_usd,
+        }
+    }
+
+    /// Current SOL price, or the configured default if none received yet
+    pub fn price_usd(&self) -> f64 {
+        self.latest().unwrap_or(self.default_usd)
+    }
+
+    /// Last price received from the feed, if any
+    pub fn latest(&self) -> Option<f64> {
+        let bits = self.price_bits.load(Ordering::Relaxed);
+        if bits == 0 {
+            None
+        } else {
+            Some(f64::from_bits(bits))
+        }
+    }
+
+    /// Record a new price; non-finite or non-positive prices are ignored
+    pub fn update(&self, price_usd: f64) -> bool {
+        if !price_usd.is_finite() || price_usd <= 0.0 {
+            return false;
+        }
+        self.price_bits.store(price_usd.to_bits(), Ordering::Relaxed);
+        true
+    }
+
+    /// Parse a signed SolPriceUpdate packet and return its price
+    ///
+    /// Packets without a valid Advice Bus HMAC, of the wrong type or size, or
+    /// broadcast more than `max_age_secs` before `now_secs` are rejected.
+    pub fn parse_update(auth: &AdviceAuth, packet: &[u8], max_age_secs: u64, now_secs: u64) -> Option<f64> {
+        let msg = auth.verify(packet)?;
+        if msg.len() != SOL_PRICE_UPDATE_SIZE || msg[0] != SOL_PRICE_UPDATE_MSG_TYPE {
+            return None;
+        }
+        let timestamp = u64::from_le_bytes(msg[5..13].try_into().ok()?);
+        if now_secs.saturating_sub(timestamp) > max_age_secs {
+            return None;
+        }
+        Some(f32::from_le_bytes([msg[1], msg[2], msg[3], msg[4]]) as f64)
+    }
+
+    /// Listen for SolPriceUpdate messages on `bind_addr` and keep the price current
+    pub async fn listen(&self, bind_addr: &str, auth: AdviceAuth, max_age_secs: u64) -> Result<()> {
+        let socket = UdpSocket::bind(bind_addr).await?;
+        info!("✅ SOL price listener bound to {}", bind_addr);
+        self.run(socket, auth, max_age_secs).await
+    }
+
+    async fn run(&self, socket: UdpSocket, auth: AdviceAuth, max_age_secs: u64) -> Result<()> {
+        let mut buf = vec![0u8; 256];
+        loop {
+            match socket.recv_from(&mut buf).await {
+                Ok((len, addr)) => {
+                    let now_secs = SystemTime::now()
+                        .duration_since(UNIX_EPOCH)
+                        .map(|d| d.as_secs())
+                        .unwrap_or(0);
+                    match Self::parse_update(&auth, &buf[..len], max_age_secs, now_secs) {
+                        Some(price) if self.update(price) => {
+                            debug!("💲 SOL price update from {}: ${:.2}", addr, price);
+                        }
+                        Some(price) => {
+                            warn!("⚠️ Ignoring invalid SOL price ${} from {}", price, addr);
+                        }
+                        None => {
+                            warn!("⚠️ Dropped {} byte unsigned, stale or non-price packet from {}", len, addr);
+                        }
+                    }
+                }
+                Err(e) => {
+                    error!("❌ Failed to receive SOL price packet: {}", e);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: u64 = 1_700_000_000;
+
+    fn price_packet(price: f32, timestamp: u64) -> Vec<u8> {
+        let mut msg = vec![0u8; SOL_PRICE_UPDATE_SIZE];
+        msg[0] = SOL_PRICE_UPDATE_MSG_TYPE;
+        msg[1..5].copy_from_slice(&price.to_le_bytes());
+        msg[5..13].copy_from_slice(&timestamp.to_le_bytes());
+        msg[13] = 1;
+        msg[14..22].copy_from_slice(&(timestamp * 1000).to_le_bytes());
+        msg
+    }
+
+    #[test]
+    fn test_falls_back_to_default_until_update() {
+        let feed = SolPriceFeed::new(200.0);
+        assert_eq!(feed.latest(), None);
+        assert_eq!(feed.price_usd(), 200.0);
+
+        assert!(feed.update(142.5));
+        assert_eq!(feed.price_usd(), 142.5);
+
+        assert!(!feed.update(0.0));
+        assert!(!feed.update(f64::NAN));
+        assert_eq!(feed.price_usd(), 142.5);
+    }
+
+    #[test]
+    fn test_parse_requires_signed_fresh_packets() {
+        let auth = AdviceAuth::new(b"test-secret");
+        let plain = price_packet(187.25, NOW - 5);
+        assert_eq!(SolPriceFeed::parse_update(&auth, &auth.sign(&plain), 30, NOW), Some(187.25));
+
+        // Unsigned or foreign-key packets
+        assert_eq!(SolPriceFeed::parse_update(&auth, &plain, 30, NOW), None);
+        let foreign = AdviceAuth::new(b"other-secret").sign(&plain);
+        assert_eq!(SolPriceFeed::parse_update(&auth, &foreign, 30, NOW), None);
+
+        // Stale timestamp
+        let stale = auth.sign(&price_packet(187.25, NOW - 31));
+        assert_eq!(SolPriceFeed::parse_update(&auth, &stale, 30, NOW), None);
+
+        // Wrong type or truncated payload, even when signed
+        let mut wrong_type = price_packet(187.25, NOW);
+        wrong_type[0] = 13;
+        assert_eq!(SolPriceFeed::parse_update(&auth, &auth.sign(&wrong_type), 30, NOW), None);
+        assert_eq!(SolPriceFeed::parse_update(&auth, &auth.sign(&plain[..20]), 30, NOW), None);
+    }
+
+    #[tokio::test]
+    async fn test_listener_applies_only_signed_updates() {
+        let auth = AdviceAuth::new(b"test-secret");
+        let socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let addr = socket.local_addr().unwrap();
+        let feed = std::sync::Arc::new(SolPriceFeed::new(200.0));
+        {
+            let feed = feed.clone();
+            let auth = auth.clone();
+            tokio::spawn(async move { feed.run(socket, auth, 30).await });
+        }
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+        let sender = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        sender.send_to(&price_packet(999.0, now), addr).await.unwrap();
+        sender.send_to(&auth.sign(&price_packet(999.0, now - 120)), addr).await.unwrap();
+        sender.send_to(&auth.sign(&price_packet(175.5, now)), addr).await.unwrap();
+
+        for _ in 0..100 {
+            if feed.latest().is_some() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        assert_eq!(feed.latest(), Some(175.5));
+    }
+}
@@ -0,0 +1,118 @@
+//! 🩺 Status Server - Minimal HTTP health endpoint
+//!
+//! Exposes WebSocket monitor health so a dropped connection is visible:
+//! - `GET /status` → JSON `{"ws_state":"connected","ws_state_code":2,"reconnects":3}`
+//! - `GET /health` → 200 when connected, 503 otherwise
+//! - `GET /ready` → 503 until the first WebSocket subscription succeeds, 200 after
+
+use anyhow::{Context, Result};
+use log::{debug, info};
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+use crate::transaction_monitor::{ConnectionState, MonitorHealth};
+
+/// Serve status requests forever on `bind_address:port`
+pub async fn start_status_server(bind_address: &str, port: u16, health: Arc<MonitorHealth>) -> Result<()> {
+    let addr = format!("{}:{}", bind_address, port);
+    let listener = TcpListener::bind(&addr)
+        .await
+        .context(format!("Failed to bind status server on {}", addr))?;
+    info!("🩺 Status server listening on http://{}/status", addr);
+
+    loop {
+        let (mut stream, peer) = listener.accept().await?;
+        let health = health.clone();
+
+        tokio::spawn(async move {
+            let mut buf = [0u8; 1024];
+            let n = match stream.read(&mut buf).await {
+                Ok(n) => n,
+                Err(e) => {
+                    debug!("Status request from {} failed: {}", peer, e);
+                    return;
+                }
+            };
+
+            let request = String::from_utf8_lossy(&buf[..n]);
+            let path = request.split_whitespace().nth(1).unwrap_or("/");
+            let response = render_response(path, &health);
+
+            if let Err(e) = stream.write_all(response.as_bytes()).await {
+                debug!("Failed to write status response to {}: {}", peer, e);
+            }
+        });
+    }
+}
+
+fn render_response(path: &str, health: &MonitorHealth) -> String {
+    let state = health.state();
+    let (status_line, body) = match path {
+        "/health" => {
+            if state == ConnectionState::Connected {
+                ("200 OK", "ok".to_string())
+            } else {
+                ("503 Service Unavailable", state.as_str().to_string())
+            }
+        }
+        "/ready" => {
+            if health.is_ready() {
+                ("200 OK", "ready".to_string())
+            } else {
+                ("503 Service Unavailable", "not ready".to_string())
+            }
+        }
+        "/status" | "/" => (
+            "200 OK",
+            serde_json::json!({
+                "ws_state": state.as_str(),
+                "ws_state_code": state as u8,
+                "reconnects": health.reconnects(),
+            })
+            .to_string(),
+        ),
+        _ => ("404 Not Found", "not found".to_string()),
+    };
+
+    format!(
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status_line,
+        if body.starts_with('{') { "application/json" } else { "text/plain" },
+        body.len(),
+        body
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_health_reflects_connection_state() {
+        let health = MonitorHealth::default();
+        assert!(render_response("/health", &health).starts_with("HTTP/1.1 503"));
+
+        health.set_state(ConnectionState::Connected);
+        assert!(render_response("/health", &health).starts_with("HTTP/1.1 200"));
+
+        let status = render_response("/status", &health);
+        assert!(status.contains("\"ws_state\":\"connected\""));
+        assert!(status.contains("\"ws_state_code\":2"));
+    }
+
+    #[test]
+    fn test_ready_after_first_subscription() {
+        let health = MonitorHealth::default();
+        health.set_state(ConnectionState::Connecting);
+        assert!(render_response("/ready", &health).starts_with("HTTP/1.1 503"));
+
+        health.set_state(ConnectionState::Connected);
+        assert!(render_response("/ready", &health).starts_with("HTTP/1.1 200"));
+
+        // A later drop is a /health problem; the service stays ready
+        health.set_state(ConnectionState::Reconnecting);
+        assert!(render_response("/ready", &health).starts_with("HTTP/1.1 200"));
+        assert!(render_response("/health", &health).starts_with("HTTP/1.1 503"));
+    }
+}
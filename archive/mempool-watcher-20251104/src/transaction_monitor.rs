@@ -5,6 +5,9 @@
 
 use anyhow::{Context, Result};
 use log::{debug, error, info, warn};
+use rand::Rng;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicU8, Ordering};
+use std::sync::Arc;
 use tokio::sync::mpsc;
 use tokio_tungstenite::{connect_async, tungstenite::Message};
 use futures_util::{SinkExt, StreamExt};
@@ -14,21 +17,142 @@ use serde_json::json;
 const PUMP_FUN_PROGRAM: &str = "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P";
 const RAYDIUM_PROGRAM: &str = "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8";
 
+/// Reconnect backoff: 1s → 2s → 4s → ... capped at 30s, each with ±25% jitter
+const INITIAL_RECONNECT_DELAY_MS: u64 = 1_000;
+const MAX_RECONNECT_DELAY_MS: u64 = 30_000;
+const RECONNECT_JITTER: f64 = 0.25;
+
+/// WebSocket connection state (stored as `AtomicU8` for lock-free status reads)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ConnectionState {
+    Disconnected = 0,
+    Connecting = 1,
+    Connected = 2,
+    Reconnecting = 3,
+}
+
+impl ConnectionState {
+    pub fn from_u8(value: u8) -> Self {
+        match value {
+            1 => ConnectionState::Connecting,
+            2 => ConnectionState::Connected,
+            3 => ConnectionState::Reconnecting,
+            _ => ConnectionState::Disconnected,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConnectionState::Disconnected => "disconnected",
+            ConnectionState::Connecting => "connecting",
+            ConnectionState::Connected => "connected",
+            ConnectionState::Reconnecting => "reconnecting",
+        }
+    }
+}
+
+/// Shared monitor health, readable from the status endpoint
+#[derive(Debug, Default)]
+pub struct MonitorHealth {
+    state: AtomicU8,
+    reconnects: AtomicU64,
+    /// Set once the first WebSocket subscription succeeds; never cleared
+    ready: AtomicBool,
+}
+
+impl MonitorHealth {
+    pub fn state(&self) -> ConnectionState {
+        ConnectionState::from_u8(self.state.load(Ordering::Relaxed))
+    }
+
+    pub fn set_state(&self, state: ConnectionState) {
+        self.state.store(state as u8, Ordering::Relaxed);
+        if state == ConnectionState::Connected {
+            self.ready.store(true, Ordering::Relaxed);
+        }
+    }
+
+    /// Whether the monitor has subscribed successfully at least once since startup
+    pub fn is_ready(&self) -> bool {
+        self.ready.load(Ordering::Relaxed)
+    }
+
+    /// Total reconnect attempts since startup
+    pub fn reconnects(&self) -> u64 {
+        self.reconnects.load(Ordering::Relaxed)
+    }
+
+    fn record_reconnect(&self) {
+        self.reconnects.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Backoff delay for the given consecutive failure count (0-based), with jitter
+fn reconnect_delay_ms(attempt: u32) -> u64 {
+    let base = INITIAL_RECONNECT_DELAY_MS
+        .saturating_mul(1u64 << attempt.min(16))
+        .min(MAX_RECONNECT_DELAY_MS);
+    let jitter = rand::thread_rng().gen_range(-RECONNECT_JITTER..=RECONNECT_JITTER);
+    ((base as f64 * (1.0 + jitter)) as u64).min(MAX_RECONNECT_DELAY_MS)
+}
+
 /// Raw transaction data from WebSocket
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RawTransaction {
     pub signature: String,
     pub slot: u64,
     pub timestamp: i64,
+    /// Transaction account keys (static keys, then lookup-table writable/readonly)
     pub accounts: Vec<String>,
+    /// Data of the Pump.fun instruction (empty if none was found)
     pub data: Vec<u8>,
     pub program_id: String,
+    /// Indices into `accounts` of the Pump.fun instruction's own accounts
+    #[serde(default)]
+    pub instruction_accounts: Vec<u8>,
+    /// Lamport balances per account key before/after execution (empty if meta unavailable)
+    #[serde(default)]
+    pub pre_balances: Vec<u64>,
+    #[serde(default)]
+    pub post_balances: Vec<u64>,
+    /// Transaction fee in lamports, charged to account 0 (fee payer)
+    #[serde(default)]
+    pub fee: u64,
+}
+
+impl RawTransaction {
+    /// The Pump.fun instruction's accounts resolved into account keys, in
+    /// instruction order (empty for the logs-only fallback or a bad index)
+    pub fn instruction_account_keys(&self) -> Vec<String> {
+        self.instruction_accounts
+            .iter()
+            .map(|&idx| self.accounts.get(idx as usize).cloned())
+            .collect::<Option<Vec<_>>>()
+            .unwrap_or_default()
+    }
+}
+
+/// Find the Pump.fun instruction in a fetched message
+///
+/// Wallets prefix compute-budget (and ATA-create) instructions, so the pump
+/// instruction is matched by program id rather than position.
+fn find_pump_instruction<'a>(
+    account_keys: &[String],
+    instructions: &'a [solana_transaction_status::UiCompiledInstruction],
+) -> Option<&'a solana_transaction_status::UiCompiledInstruction> {
+    instructions.iter().find(|ix| {
+        account_keys
+            .get(ix.program_id_index as usize)
+            .is_some_and(|program| program == PUMP_FUN_PROGRAM)
+    })
 }
 
 /// Transaction monitor - watches mempool for new transactions
 pub struct TransactionMonitor {
     ws_url: String,
     tx_sender: mpsc::UnboundedSender<RawTransaction>,
+    health: Arc<MonitorHealth>,
 }
 
 impl TransactionMonitor {
@@ -39,37 +163,54 @@ impl TransactionMonitor {
             Self {
                 ws_url,
                 tx_sender,
+                health: Arc::new(MonitorHealth::default()),
             },
             tx_receiver,
         )
     }
 
+    /// Shared connection health (for the status endpoint)
+    pub fn health(&self) -> Arc<MonitorHealth> {
+        self.health.clone()
+    }
+
     /// Start monitoring mempool via WebSocket
+    ///
+    /// Never returns on disconnect: reconnects internally with exponential
+    /// backoff plus jitter, resetting the backoff after a successful session.
     pub async fn start_monitoring(&self) -> Result<()> {
         info!("🎯 Starting mempool monitoring...");
         info!("🌊 WebSocket: {}", self.ws_url);
 
-        let mut reconnect_delay = 2; // Start with 2 seconds
-        const MAX_RECONNECT_DELAY: u64 = 60; // Cap at 60 seconds
+        let mut failures: u32 = 0;
 
         loop {
-            match self.connect_and_monitor().await {
-                Ok(_) => {
-                    warn!("WebSocket connection closed cleanly, reconnecting...");
-                    // Reset delay on clean disconnect
-                    reconnect_delay = 2;
-                }
-                Err(e) => {
-                    error!("❌ WebSocket error: {} - Reconnecting in {}s...", e, reconnect_delay);
-                    
-                    // Exponential backoff: 2s → 4s → 8s → 16s → 32s → 60s (capped)
-                    tokio::time::sleep(tokio::time::Duration::from_secs(reconnect_delay)).await;
-                    
-                    reconnect_delay = (reconnect_delay * 2).min(MAX_RECONNECT_DELAY);
-                    
-                    info!("⚡ Attempting reconnection (next delay: {}s if failed)", reconnect_delay);
-                }
+            self.health.set_state(if failures == 0 {
+                ConnectionState::Connecting
+            } else {
+                ConnectionState::Reconnecting
+            });
+
+            let result = self.connect_and_monitor().await;
+
+            // A session that got as far as Connected resets the backoff
+            if self.health.state() == ConnectionState::Connected {
+                failures = 0;
+            }
+            self.health.set_state(ConnectionState::Disconnected);
+
+            match result {
+                Ok(_) => warn!("WebSocket connection closed cleanly, reconnecting..."),
+                Err(e) => error!("❌ WebSocket error: {}", e),
             }
+
+            let delay_ms = reconnect_delay_ms(failures);
+            failures = failures.saturating_add(1);
+            self.health.record_reconnect();
+
+            info!("⚡ Reconnecting in {}ms (attempt {}, {} total reconnects)",
+                  delay_ms, failures, self.health.reconnects());
+            tokio::time::sleep(tokio::time::Duration::from_millis(delay_ms)).await;
         }
     }
 
@@ -127,6 +268,7 @@ impl TransactionMonitor {
             .context("Failed to send Raydium subscription")?;
 
         info!("📡 Subscribed to Raydium program logs");
+        self.health.set_state(ConnectionState::Connected);
 
         // Process incoming messages
         let mut last_ping = tokio::time::Instant::now();
@@ -244,7 +386,8 @@ impl TransactionMonitor {
                 }
                 Err(e) => {
                     warn!("⚠️  Failed to fetch transaction {}: {} - using fallback", &signature[..12], e);
-                    // Fallback: send minimal transaction data
+                    // Fallback: signature only, enough to confirm watched signatures;
+                    // with no accounts it can't be decoded for heat
                     let raw_tx = RawTransaction {
                         signature: signature.to_string(),
                         slot: result.get("context").and_then(|c| c.get("slot")).and_then(|s| s.as_u64()).unwrap_or(0),
@@ -252,6 +395,10 @@ impl TransactionMonitor {
                         accounts: vec![],
                         data: vec![],
                         program_id: PUMP_FUN_PROGRAM.to_string(),
+                        instruction_accounts: vec![],
+                        pre_balances: vec![],
+                        post_balances: vec![],
+                        fee: 0,
                     };
                     let _ = self.tx_sender.send(raw_tx);
                 }
@@ -287,28 +434,39 @@ impl TransactionMonitor {
             },
         ).context("Failed to fetch transaction")?;
         
-        // Extract accounts and instruction data
+        // Extract accounts and the pump instruction
         let mut accounts = Vec::new();
         let mut data = Vec::new();
         let mut program_id = PUMP_FUN_PROGRAM.to_string();
+        let mut instruction_accounts = Vec::new();
+        
+        // Balance changes for realized SOL proceeds (SELL P&L)
+        let (pre_balances, post_balances, fee) = match &tx.transaction.meta {
+            Some(meta) => (meta.pre_balances.clone(), meta.post_balances.clone(), meta.fee),
+            None => (vec![], vec![], 0),
+        };
         
         if let solana_transaction_status::EncodedTransaction::Json(ui_tx) = tx.transaction.transaction {
-            if let solana_transaction_status::UiMessage::Parsed(parsed) = ui_tx.message {
-                // Extract account keys
-                for key in parsed.account_keys {
-                    accounts.push(key.pubkey);
+            if let solana_transaction_status::UiMessage::Raw(raw) = ui_tx.message {
+                // Static keys, then v0 lookup-table keys (writable before readonly)
+                accounts = raw.account_keys;
+                let loaded: Option<solana_transaction_status::UiLoadedAddresses> = tx.transaction.meta
+                    .and_then(|meta| meta.loaded_addresses.into());
+                if let Some(loaded) = loaded {
+                    accounts.extend(loaded.writable);
+                    accounts.extend(loaded.readonly);
                 }
                 
-                // Extract instruction data from first instruction
-                if let Some(first_ix) = parsed.instructions.first() {
-                    if let solana_transaction_status::UiInstruction::Compiled(compiled) = first_ix {
-                        if let Ok(decoded) = bs58::decode(&compiled.data).into_vec() {
-                            data = decoded;
-                        }
-                        // Get program ID from program_id_index (u8)
-                        let prog_idx = compiled.program_id_index as usize;
-                        if let Some(prog_key) = accounts.get(prog_idx) {
-                            program_id = prog_key.clone();
+                match find_pump_instruction(&accounts, &raw.instructions) {
+                    Some(ix) => {
+                        data = bs58::decode(&ix.data).into_vec().unwrap_or_default();
+                        instruction_accounts = ix.accounts.clone();
+                    }
+                    None => {
+                        // Record what was actually invoked so it isn't mistaken for pump
+                        if let Some(program) = raw.instructions.first()
+                            .and_then(|ix| accounts.get(ix.program_id_index as usize)) {
+                            program_id = program.clone();
                         }
                     }
                 }
@@ -322,6 +480,10 @@ impl TransactionMonitor {
             accounts,
             data,
             program_id,
+            instruction_accounts,
+            pre_balances,
+            post_balances,
+            fee,
         })
     }
 
@@ -346,6 +508,93 @@ mod tests {
         assert_eq!(monitor.ws_url, "wss://api.mainnet-beta.solana.com");
     }
 
+    #[test]
+    fn test_reconnect_delay_capped_with_jitter() {
+        for attempt in 0..20 {
+            let delay = reconnect_delay_ms(attempt);
+            assert!(delay <= MAX_RECONNECT_DELAY_MS);
+            assert!(delay >= (INITIAL_RECONNECT_DELAY_MS as f64 * (1.0 - RECONNECT_JITTER)) as u64);
+        }
+        // Late attempts sit at the cap (minus jitter)
+        assert!(reconnect_delay_ms(10) >= (MAX_RECONNECT_DELAY_MS as f64 * (1.0 - RECONNECT_JITTER)) as u64);
+    }
+
+    #[test]
+    fn test_connection_state_roundtrip() {
+        let health = MonitorHealth::default();
+        assert_eq!(health.state(), ConnectionState::Disconnected);
+        health.set_state(ConnectionState::Connected);
+        assert_eq!(health.state(), ConnectionState::Connected);
+        assert_eq!(health.state().as_str(), "connected");
+    }
+
+    #[test]
+    fn test_finds_pump_instruction_after_compute_budget() {
+        use crate::decoder::{TransactionAction, TransactionDecoder};
+        use solana_sdk::pubkey::Pubkey;
+        use solana_transaction_status::UiCompiledInstruction;
+
+        let payer = Pubkey::new_unique().to_string();
+        let mint = Pubkey::new_unique().to_string();
+        let compute_budget = "ComputeBudget111111111111111111111111111111".to_string();
+        // [payer, compute budget, global, fee_recipient, mint, curve, assoc curve, assoc user, pump]
+        let mut accounts = vec![payer.clone(), compute_budget];
+        accounts.push(Pubkey::new_unique().to_string());
+        accounts.push(Pubkey::new_unique().to_string());
+        accounts.push(mint.clone());
+        for _ in 0..3 {
+            accounts.push(Pubkey::new_unique().to_string());
+        }
+        accounts.push(PUMP_FUN_PROGRAM.to_string());
+
+        let mut buy = vec![0x66, 0x06, 0x3d, 0x12, 0x01, 0xda, 0xeb, 0xea];
+        buy.extend_from_slice(&2_500_000_000u64.to_le_bytes());
+        buy.extend_from_slice(&1_000u64.to_le_bytes());
+        let compiled = |program_id_index: u8, accounts: Vec<u8>, data: &[u8]| UiCompiledInstruction {
+            program_id_index,
+            accounts,
+            data: bs58::encode(data).into_string(),
+            stack_height: None,
+        };
+        let instructions = vec![
+            compiled(1, vec![], &[2, 0x40, 0x0d, 0x03, 0x00]), // SetComputeUnitLimit
+            compiled(1, vec![], &[3, 0x10, 0x27, 0, 0, 0, 0, 0, 0]), // SetComputeUnitPrice
+            compiled(8, vec![2, 3, 4, 5, 6, 7, 0], &buy),
+        ];
+
+        let ix = find_pump_instruction(&accounts, &instructions).unwrap();
+        let raw_tx = RawTransaction {
+            signature: "sig".to_string(),
+            slot: 1,
+            timestamp: 0,
+            accounts,
+            data: bs58::decode(&ix.data).into_vec().unwrap(),
+            program_id: PUMP_FUN_PROGRAM.to_string(),
+            instruction_accounts: ix.accounts.clone(),
+            pre_balances: vec![],
+            post_balances: vec![],
+            fee: 0,
+        };
+
+        let decoded = TransactionDecoder::new(10.0)
+            .decode_raw_pump_transaction(
+                &raw_tx.signature,
+                &raw_tx.program_id,
+                &raw_tx.instruction_account_keys(),
+                &raw_tx.data,
+                0,
+                0,
+            )
+            .unwrap();
+        assert_eq!(decoded.mint, mint);
+        assert_eq!(decoded.wallet, payer);
+        assert_eq!(decoded.action, TransactionAction::Buy);
+        assert!((decoded.amount_sol - 2.5).abs() < 1e-9);
+
+        // No pump instruction at all
+        assert!(find_pump_instruction(&raw_tx.accounts, &instructions[..2]).is_none());
+    }
+
     #[test]
     fn test_pump_program_constant() {
         assert_eq!(PUMP_FUN_PROGRAM, "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P");
@@ -1,3 +1,4 @@
+use crate::auth::AdviceAuth;
 use crate::heat_calculator::{HeatIndex, HotSignal};
 use crate::position_update::PositionUpdate;
 use crate::exit_advice::ExitAdvice;
@@ -14,6 +15,10 @@ pub struct UdpPublisher {
     brain_confirmation_socket: UdpSocket,
     brain_addr: String,
     brain_confirmation_addr: String,
+    /// Brain's authenticated Advice Bus (ExitAdvice)
+    advice_bus_addr: String,
+    /// Signs packets for Brain listeners that require authentication (manual exits)
+    auth: Option<AdviceAuth>,
 }
 
 /// Message sent to Brain (heat context for decisions)
@@ -34,6 +39,7 @@ pub struct HotSignalMessage {
     pub amount_sol: f64,
     pub action: String,
     pub urgency: u8,
+    pub whale_tier: u8,  // 0=none, 1=whale, 2=mega-whale
     pub timestamp: u64,
 }
 
@@ -42,6 +48,7 @@ impl UdpPublisher {
         bind_address: &str,
         brain_port: u16,
         brain_confirmation_port: u16,
+        advice_bus_port: u16,
     ) -> Result<Self> {
         // Create separate sockets for Brain (price updates) and Brain (confirmations/hot signals)
         let brain_socket = UdpSocket::bind(format!("{}:0", bind_address))?;
@@ -52,15 +59,24 @@ impl UdpPublisher {
 
         let brain_addr = format!("{}:{}", bind_address, brain_port);
         let brain_confirmation_addr = format!("{}:{}", bind_address, brain_confirmation_port);
+        let advice_bus_addr = format!("{}:{}", bind_address, advice_bus_port);
 
         Ok(Self {
             brain_socket,
             brain_confirmation_socket,
             brain_addr,
             brain_confirmation_addr,
+            advice_bus_addr,
+            auth: None,
         })
     }
 
+    /// Sign packets sent to Brain's authenticated listeners (required for ExitAdvice)
+    pub fn with_auth(mut self, auth: AdviceAuth) -> Self {
+        self.auth = Some(auth);
+        self
+    }
+
     /// Send heat index to Brain for decision context
     pub fn send_heat_to_brain(&self, heat: &HeatIndex) -> Result<()> {
         // Build binary message (24 bytes total, matching MempoolHeatAdvice::SIZE)
@@ -109,6 +125,7 @@ impl UdpPublisher {
             amount_sol: signal.amount_sol,
             action: signal.action.clone(),
             urgency: signal.urgency,
+            whale_tier: signal.whale_tier as u8,
             timestamp: signal.timestamp,
         };
 
@@ -116,8 +133,8 @@ impl UdpPublisher {
         
         match self.brain_confirmation_socket.send_to(&serialized, &self.brain_confirmation_addr) {
             Ok(bytes) => {
-                debug!("🔥 Sent hot signal to Brain (45131): {} bytes (urgency: {}, jitter: {}ms)", 
-                       bytes, signal.urgency, jitter_ms);
+                debug!("🔥 Sent hot signal to Brain (45131): {} bytes (urgency: {}, tier: {}, jitter: {}ms)", 
+                       bytes, signal.urgency, signal.whale_tier.as_str(), jitter_ms);
                 Ok(())
             }
             Err(e) => {
@@ -181,9 +198,13 @@ impl UdpPublisher {
             }
         }
         
-        // Send to Brain (port 45135) - for position cleanup
+        // Send to Brain (port 45135) - for position cleanup; Brain drops unsigned packets
         let brain_cleanup_addr = "127.0.0.1:45135";
-        match self.brain_confirmation_socket.send_to(&bytes, brain_cleanup_addr) {
+        let packet = match &self.auth {
+            Some(auth) => auth.sign(&bytes),
+            None => bytes.clone(),
+        };
+        match self.brain_confirmation_socket.send_to(&packet, brain_cleanup_addr) {
             Ok(_) => {
                 debug!("📤 Sent ManualExitNotification to Brain: mint: {} | P&L: ${:.2} ({:.1}%)",
                     &notification.mint_str()[..8], pnl_usd, pnl_pct);
@@ -197,12 +218,16 @@ impl UdpPublisher {
     }
     
     /// Send ExitAdvice to Brain (profit target or stop loss hit)
+    ///
+    /// Brain only parses ExitAdvice on its Advice Bus, which drops unsigned
+    /// packets, so this fails without `with_auth`.
     pub fn send_exit_advice(&self, advice: &ExitAdvice) -> Result<()> {
-        let bytes = advice.to_bytes();
+        let Some(auth) = &self.auth else {
+            anyhow::bail!("ExitAdvice needs the advice bus secret (Brain drops unsigned advice)");
+        };
+        let packet = auth.sign(&advice.to_bytes());
         
-        // Send to Brain (port 45115) - Brain's confirmation listener
-        let brain_advice_addr = "127.0.0.1:45115";
-        match self.brain_confirmation_socket.send_to(&bytes, brain_advice_addr) {
+        match self.brain_confirmation_socket.send_to(&packet, &self.advice_bus_addr) {
             Ok(_) => {
                 debug!("📤 Sent ExitAdvice to Brain: {} | P&L: ${:.2} | confidence: {}",
                     advice.reason_str(), advice.realized_pnl_usd(), advice.confidence);
@@ -222,7 +247,7 @@ mod tests {
 
     #[test]
     fn test_publisher_creation() {
-        let publisher = UdpPublisher::new("127.0.0.1", 45120, 45130);
+        let publisher = UdpPublisher::new("127.0.0.1", 45120, 45130, 45100);
         assert!(publisher.is_ok());
     }
 
@@ -242,4 +267,37 @@ mod tests {
         let deserialized: Result<MempoolHeatMessage, _> = bincode::deserialize(&serialized.unwrap());
         assert!(deserialized.is_ok());
     }
+
+    /// Wire bytes of a stop-loss ExitAdvice; Brain's receiver tests parse the same fixture
+    const EXIT_ADVICE_FIXTURE: &str = concat!(
+        "1e07070707070707070707070707070707", // msg_type 30, trade_id
+        "0909090909090909090909090909090909090909090909090909090909090909", // mint
+        "016457feffff40420f000000000050f80c000000000010a4000000002a36fe9c971764000000000000000000000000",
+    );
+
+    #[test]
+    fn test_exit_advice_signed_to_advice_bus() {
+        let brain = UdpSocket::bind("127.0.0.1:0").unwrap();
+        brain.set_read_timeout(Some(Duration::from_secs(2))).unwrap();
+        let advice_bus_port = brain.local_addr().unwrap().port();
+
+        let mut advice = ExitAdvice::new([7u8; 16], [9u8; 32], ExitAdvice::REASON_STOP_LOSS, 100, -4.25,
+                                         1_000_000, 850_000, 42_000)
+            .with_exit_percent(100);
+        advice.timestamp_ns = 1_700_000_000_000_000_000;
+
+        // Brain drops unsigned advice, so the publisher refuses to send it
+        let unsigned = UdpPublisher::new("127.0.0.1", 45120, 45130, advice_bus_port).unwrap();
+        assert!(unsigned.send_exit_advice(&advice).is_err());
+
+        let auth = AdviceAuth::new(b"test-secret");
+        let publisher = UdpPublisher::new("127.0.0.1", 45120, 45130, advice_bus_port).unwrap()
+            .with_auth(auth.clone());
+        publisher.send_exit_advice(&advice).unwrap();
+
+        let mut buf = [0u8; 256];
+        let len = brain.recv(&mut buf).unwrap();
+        let payload = auth.verify(&buf[..len]).expect("ExitAdvice must be signed");
+        assert_eq!(hex::encode(payload), EXIT_ADVICE_FIXTURE);
+    }
 }
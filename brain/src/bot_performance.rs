@@ -0,0 +1,195 @@
+//! 🏁 Bot Performance - our own realized P&L
+//!
+//! Every confirmed SELL from our Executor is written to the `bot_trades` table
+//! (next to data-mining's tables in the shared SQLite DB) with its entry/exit
+//! price, size, realized P&L and the trigger that opened the position. Partial
+//! exits are one row each; a position is identified by (mint, entry_timestamp).
+//!
+//! This is our bot's track record, separate from the discovery `wallet_stats`
+//! (which only covers external wallets).
+
+use anyhow::Result;
+use rusqlite::{params, Connection};
+
+use crate::decision_engine::position_tracker::ActivePosition;
+use crate::udp_bus::ExecutionConfirmation;
+
+/// A confirmed SELL of (part of) one of our positions
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClosedTrade {
+    pub mint: String,
+    /// Entry trigger (`EntryTrigger::as_str`)
+    pub trigger: String,
+    /// Unix seconds the position was opened
+    pub entry_timestamp: u64,
+    /// Unix seconds the SELL was executed
+    pub exit_timestamp: u64,
+    pub entry_price_sol: f64,
+    pub exit_price_sol: f64,
+    /// SOL cost basis of the tokens sold
+    pub size_sol: f64,
+    pub realized_pnl_sol: f64,
+    /// Executor only simulated the SELL (never submitted)
+    pub dry_run: bool,
+}
+
+impl ClosedTrade {
+    /// Price a successful SELL confirmation against its position's entry
+    ///
+    /// The Executor echoes the SELL decision size, i.e. the entry SOL of the
+    /// tokens sold, so P&L = size × (exit / entry - 1). None without usable prices.
+    pub fn from_sell(position: &ActivePosition, conf: &ExecutionConfirmation) -> Option<Self> {
+        let entry_price_sol = position.entry_price_sol;
+        let exit_price_sol = conf.executed_price_sol();
+        if !conf.is_sell() || !conf.is_success() || entry_price_sol <= 0.0 || exit_price_sol <= 0.0 {
+            return None;
+        }
+
+        let size_sol = conf.executed_size_sol();
+        Some(Self {
+            mint: position.mint.clone(),
+            trigger: position.entry_path.as_str().to_string(),
+            entry_timestamp: position.entry_timestamp,
+            exit_timestamp: conf.timestamp,
+            entry_price_sol,
+            exit_price_sol,
+            size_sol,
+            realized_pnl_sol: size_sol * (exit_price_sol / entry_price_sol - 1.0),
+            dry_run: conf.is_dry_run() || position.dry_run,
+        })
+    }
+}
+
+/// Closed positions and win rate for one entry trigger (dry-runs excluded)
+#[derive(Debug, Clone, PartialEq)]
+pub struct TriggerPerformance {
+    pub trigger: String,
+    pub positions: u64,
+    pub wins: u64,
+    pub realized_pnl_sol: f64,
+}
+
+impl TriggerPerformance {
+    pub fn win_rate(&self) -> f64 {
+        if self.positions == 0 {
+            0.0
+        } else {
+            self.wins as f64 / self.positions as f64
+        }
+    }
+}
+
+/// Create `bot_trades` if it doesn't exist yet
+pub fn ensure_schema(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS bot_trades (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            mint TEXT NOT NULL,
+            trigger TEXT NOT NULL,
+            entry_timestamp INTEGER NOT NULL,
+            exit_timestamp INTEGER NOT NULL,
+            entry_price_sol REAL NOT NULL,
+            exit_price_sol REAL NOT NULL,
+            size_sol REAL NOT NULL,
+            realized_pnl_sol REAL NOT NULL,
+            dry_run INTEGER NOT NULL DEFAULT 0
+        );
+        CREATE INDEX IF NOT EXISTS idx_bot_trades_trigger ON bot_trades(trigger, exit_timestamp);",
+    )?;
+    Ok(())
+}
+
+pub fn record_closed_trade(conn: &Connection, trade: &ClosedTrade) -> Result<()> {
+    conn.execute(
+        "INSERT INTO bot_trades (mint, trigger, entry_timestamp, exit_timestamp,
+             entry_price_sol, exit_price_sol, size_sol, realized_pnl_sol, dry_run)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+        params![
+            trade.mint,
+            trade.trigger,
+            trade.entry_timestamp as i64,
+            trade.exit_timestamp as i64,
+            trade.entry_price_sol,
+            trade.exit_price_sol,
+            trade.size_sol,
+            trade.realized_pnl_sol,
+            trade.dry_run,
+        ],
+    )?;
+    Ok(())
+}
+
+/// Win rate per trigger over real (non dry-run) positions, partial exits summed per position
+pub fn performance_by_trigger(conn: &Connection) -> Result<Vec<TriggerPerformance>> {
+    let mut stmt = conn.prepare(
+        "SELECT trigger, COUNT(*), SUM(pnl > 0), SUM(pnl)
+         FROM (
+             SELECT trigger, SUM(realized_pnl_sol) AS pnl
+             FROM bot_trades
+             WHERE dry_run = 0
+             GROUP BY mint, entry_timestamp, trigger
+         )
+         GROUP BY trigger
+         ORDER BY trigger",
+    )?;
+    let rows = stmt.query_map([], |row| {
+        Ok(TriggerPerformance {
+            trigger: row.get(0)?,
+            positions: row.get::<_, i64>(1)? as u64,
+            wins: row.get::<_, i64>(2)? as u64,
+            realized_pnl_sol: row.get(3)?,
+        })
+    })?;
+    Ok(rows.collect::<rusqlite::Result<Vec<_>>>()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trade(mint: &str, trigger: &str, entry_timestamp: u64, size_sol: f64, exit_price_sol: f64) -> ClosedTrade {
+        ClosedTrade {
+            mint: mint.to_string(),
+            trigger: trigger.to_string(),
+            entry_timestamp,
+            exit_timestamp: entry_timestamp + 30,
+            entry_price_sol: 0.001,
+            exit_price_sol,
+            size_sol,
+            realized_pnl_sol: size_sol * (exit_price_sol / 0.001 - 1.0),
+            dry_run: false,
+        }
+    }
+
+    #[test]
+    fn test_performance_by_trigger() {
+        let conn = Connection::open_in_memory().unwrap();
+        ensure_schema(&conn).unwrap();
+        ensure_schema(&conn).unwrap(); // idempotent on restart
+
+        // Momentum position A: +50% on 30%, then -20% on the rest → net +0.0052 SOL (a win)
+        record_closed_trade(&conn, &trade("A", "momentum", 100, 0.03, 0.0015)).unwrap();
+        record_closed_trade(&conn, &trade("A", "momentum", 100, 0.07, 0.0008)).unwrap();
+        // Momentum position B: stopped out
+        record_closed_trade(&conn, &trade("B", "momentum", 200, 0.1, 0.0005)).unwrap();
+        // Same mint re-entered later is a separate position
+        record_closed_trade(&conn, &trade("B", "momentum", 900, 0.1, 0.002)).unwrap();
+        // Copy trade win, plus a dry-run that must not count
+        record_closed_trade(&conn, &trade("C", "copy", 300, 0.1, 0.0012)).unwrap();
+        let mut dry = trade("D", "copy", 400, 0.1, 0.0001);
+        dry.dry_run = true;
+        record_closed_trade(&conn, &dry).unwrap();
+
+        let stats = performance_by_trigger(&conn).unwrap();
+        assert_eq!(stats.len(), 2);
+
+        let copy = &stats[0];
+        assert_eq!((copy.trigger.as_str(), copy.positions, copy.wins), ("copy", 1, 1));
+        assert!((copy.realized_pnl_sol - 0.02).abs() < 1e-9);
+
+        let momentum = &stats[1];
+        assert_eq!((momentum.trigger.as_str(), momentum.positions, momentum.wins), ("momentum", 3, 2));
+        assert!((momentum.win_rate() - 2.0 / 3.0).abs() < 1e-9);
+        assert!((momentum.realized_pnl_sol - (0.015 - 0.014 - 0.05 + 0.1)).abs() < 1e-9);
+    }
+}
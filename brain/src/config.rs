@@ -4,11 +4,15 @@
 //! validated, type-safe access to all service parameters.
 
 use anyhow::{Context, Result};
+use std::collections::HashSet;
 use std::env;
 use std::net::IpAddr;
 use std::path::PathBuf;
 use std::str::FromStr;
 
+use crate::decision_engine::position_tracker::ProfitLadder;
+use crate::decision_engine::triggers::EntryTrigger;
+
 /// Complete configuration for the Brain decision engine service
 #[derive(Debug, Clone)]
 pub struct Config {
@@ -21,6 +25,23 @@ pub struct Config {
     pub cache: CacheConfig,
     pub performance: PerformanceConfig,
     pub confirmation: ConfirmationConfig,
+    pub wallet_tiers: WalletTierConfig,
+}
+
+/// Copy-trade wallet tier boundaries (highest tier whose minimums are all met)
+#[derive(Debug, Clone)]
+pub struct WalletTierConfig {
+    /// Completed trades required before any tier above Discovery
+    pub min_trades: u32,
+    /// Tier A minimum win rate (0.0-1.0) and 7d PnL (SOL)
+    pub tier_a_min_win_rate: f64,
+    pub tier_a_min_pnl_sol: f64,
+    /// Tier B minimum win rate (0.0-1.0) and 7d PnL (SOL)
+    pub tier_b_min_win_rate: f64,
+    pub tier_b_min_pnl_sol: f64,
+    /// Tier C minimum win rate (0.0-1.0) and 7d PnL (SOL)
+    pub tier_c_min_win_rate: f64,
+    pub tier_c_min_pnl_sol: f64,
 }
 
 /// Confirmation tracking configuration
@@ -53,8 +74,63 @@ pub struct DecisionConfig {
     pub min_decision_conf: u8,
     /// Minimum confidence score (0-100) for copytrade decisions
     pub min_copytrade_confidence: u8,
+    /// Per-trigger minimum decision confidence (each defaults to MIN_DECISION_CONF)
+    pub min_conf_late: u8,
+    pub min_conf_copy: u8,
+    pub min_conf_rank: u8,
+    pub min_conf_momentum: u8,
     /// Minimum follow-through score (0-100) to proceed
     pub min_follow_through_score: u8,
+    /// Seconds a position may still be held once its bonding curve completes (0 = ignore)
+    pub curve_complete_exit_secs: u64,
+    /// Oldest SOL/USD oracle price accepted for USD sizing (seconds)
+    pub sol_price_max_age_secs: u64,
+    /// Volume-drop exit: 5s volume below this fraction of its peak since entry
+    pub volume_drop_exit_ratio: f64,
+    /// Volume-drop exit: percent of the position sold (0 = off)
+    pub volume_drop_exit_percent: u8,
+    /// Emergency exit: curve SOL reserves down this % from their peak (0 = off)
+    pub rug_reserve_drop_pct: f64,
+    /// Emergency exit: the token creator sold
+    pub rug_exit_on_creator_sell: bool,
+    /// Emergency exit: one wallet's share of the last 60s traded SOL, selling (0 = off)
+    pub rug_top_seller_share: f64,
+    /// Only these mints may be entered (empty = any mint)
+    pub allowed_mints: HashSet<String>,
+    /// Mints that are never entered
+    pub denied_mints: HashSet<String>,
+    /// Profit-taking ladders given to new positions, ascending by gain
+    pub profit_ladders: Vec<ProfitLadder>,
+    /// Trailing stop: exit once price falls this % below its peak since entry (0 = off)
+    pub trailing_stop_pct: f64,
+}
+
+impl DecisionConfig {
+    /// Minimum decision confidence for an entry path (hotlist shares the rank threshold)
+    pub fn min_conf_for(&self, trigger: EntryTrigger) -> u8 {
+        match trigger {
+            EntryTrigger::RankBased | EntryTrigger::Hotlist => self.min_conf_rank,
+            EntryTrigger::Momentum => self.min_conf_momentum,
+            EntryTrigger::CopyTrade => self.min_conf_copy,
+            EntryTrigger::LateOpportunity => self.min_conf_late,
+        }
+    }
+    
+    /// Trailing stop % for new positions (None = off)
+    pub fn trailing_stop(&self) -> Option<f64> {
+        (self.trailing_stop_pct > 0.0).then_some(self.trailing_stop_pct)
+    }
+    
+    /// Why the allowlist/denylist blocks entering `mint` (None = permitted)
+    pub fn mint_list_rejection(&self, mint: &str) -> Option<&'static str> {
+        if self.denied_mints.contains(mint) {
+            Some("on the denylist")
+        } else if !self.allowed_mints.is_empty() && !self.allowed_mints.contains(mint) {
+            Some("not on the allowlist")
+        } else {
+            None
+        }
+    }
 }
 
 /// Pre-trade validation parameters
@@ -68,6 +144,8 @@ pub struct ValidationConfig {
     pub min_liquidity_usd: f64,
     /// Maximum slippage tolerance as fraction (e.g., 0.15 = 15%)
     pub max_slippage: f64,
+    /// Slippage allowed below current market price on SELLs, in basis points
+    pub exit_slippage_bps: u16,
 }
 
 /// Anti-churn guardrail configuration
@@ -77,6 +155,8 @@ pub struct GuardrailsConfig {
     pub max_concurrent_positions: usize,
     /// Maximum positions from advisor/copytrade source
     pub max_advisor_positions: usize,
+    /// Hard cap on total open position size (SOL, 0 = no cap)
+    pub max_total_exposure_sol: f64,
     /// Rate limit for general decisions (milliseconds)
     pub rate_limit_ms: u64,
     /// Rate limit for advisor decisions (milliseconds)
@@ -89,6 +169,8 @@ pub struct GuardrailsConfig {
     pub loss_backoff_pause_secs: u64,
     /// Wallet cooling: min time between copytrading same wallet (seconds)
     pub wallet_cooling_secs: u64,
+    /// Mint cooldown: no new entry into a mint for this long after a loss on it (seconds, 0 = off)
+    pub mint_loss_cooldown_secs: u64,
 }
 
 /// Database connection configuration
@@ -127,14 +209,26 @@ impl DatabaseConfig {
 pub struct NetworkConfig {
     /// Port to receive advice messages on
     pub advice_bus_port: u16,
+    /// Receive advice on this Unix datagram socket instead of UDP (None = UDP port)
+    pub advice_bus_unix_path: Option<PathBuf>,
     /// Port to send decisions to
     pub decision_bus_port: u16,
+    /// Resend SELL decisions this often until confirmed (0 = fire-and-forget)
+    pub sell_retransmit_ms: u64,
+    /// Stop resending an unconfirmed SELL after this long
+    pub sell_retransmit_timeout_ms: u64,
+    /// Port to receive ManualExitNotifications from mempool-watcher on
+    pub manual_exit_port: u16,
     /// UDP bind address (typically 127.0.0.1 for localhost)
     pub udp_bind_address: IpAddr,
     /// UDP receive buffer size
     pub udp_recv_buffer_size: usize,
     /// UDP send buffer size
     pub udp_send_buffer_size: usize,
+    /// Shared secret for Advice Bus packet authentication (None = use secret file)
+    pub advice_bus_secret: Option<String>,
+    /// Secret file shared with data-mining (generated per run if missing)
+    pub advice_bus_secret_file: String,
     /// Yellowstone gRPC endpoint
     pub yellowstone_endpoint: String,
     /// Yellowstone x-token (optional)
@@ -167,6 +261,10 @@ pub struct CacheConfig {
     pub wallet_cache_capacity: usize,
     /// Cache refresh interval (seconds)
     pub cache_refresh_interval_secs: u64,
+    /// Mint cache incremental refresh interval - only mints with new windows (milliseconds)
+    pub mint_cache_incremental_interval_ms: u64,
+    /// Mint cache full-scan reconciliation interval (seconds)
+    pub mint_cache_full_refresh_secs: u64,
 }
 
 /// Performance tuning configuration
@@ -185,27 +283,47 @@ impl Config {
         // Load .env file if it exists (ignoring error if not found)
         let _ = dotenv::dotenv();
 
+        let min_decision_conf = get_env_u8("MIN_DECISION_CONF", 75)?;
+
         Ok(Config {
             decision: DecisionConfig {
-                min_decision_conf: get_env_u8("MIN_DECISION_CONF", 75)?,
+                min_decision_conf,
                 min_copytrade_confidence: get_env_u8("MIN_COPYTRADE_CONFIDENCE", 70)?,
+                min_conf_late: get_env_u8("MIN_CONF_LATE", min_decision_conf)?,
+                min_conf_copy: get_env_u8("MIN_CONF_COPY", min_decision_conf)?,
+                min_conf_rank: get_env_u8("MIN_CONF_RANK", min_decision_conf)?,
+                min_conf_momentum: get_env_u8("MIN_CONF_MOMENTUM", min_decision_conf)?,
                 min_follow_through_score: get_env_u8("MIN_FOLLOW_THROUGH_SCORE", 55)?,
+                curve_complete_exit_secs: get_env_u64("CURVE_COMPLETE_EXIT_SECS", 10)?,
+                sol_price_max_age_secs: get_env_u64("SOL_PRICE_MAX_AGE_SECS", 30)?,
+                volume_drop_exit_ratio: get_env_f64("VOLUME_DROP_EXIT_RATIO", 0.3)?,
+                volume_drop_exit_percent: get_env_u8("VOLUME_DROP_EXIT_PERCENT", 50)?,
+                rug_reserve_drop_pct: get_env_f64("RUG_RESERVE_DROP_PCT", 30.0)?,
+                rug_exit_on_creator_sell: get_env_bool("RUG_EXIT_ON_CREATOR_SELL", true)?,
+                rug_top_seller_share: get_env_f64("RUG_TOP_SELLER_SHARE", 0.5)?,
+                allowed_mints: get_env_mint_set("ALLOWED_MINTS", "ALLOWED_MINTS_FILE")?,
+                denied_mints: get_env_mint_set("DENIED_MINTS", "DENIED_MINTS_FILE")?,
+                profit_ladders: get_env_profit_ladders("PROFIT_LADDERS")?,
+                trailing_stop_pct: get_env_f64("TRAILING_STOP_PCT", 0.0)?,
             },
             validation: ValidationConfig {
                 fee_multiplier: get_env_f64("FEE_MULTIPLIER", 2.2)?,
                 impact_cap_multiplier: get_env_f64("IMPACT_CAP_MULTIPLIER", 0.45)?,
                 min_liquidity_usd: get_env_f64("MIN_LIQUIDITY_USD", 5000.0)?,
                 max_slippage: get_env_f64("MAX_SLIPPAGE", 0.15)?,
+                exit_slippage_bps: get_env_u16("EXIT_SLIPPAGE_BPS", 300)?,
             },
             guardrails: GuardrailsConfig {
                 max_concurrent_positions: get_env_usize("MAX_CONCURRENT_POSITIONS", 5)?,  // Increased from 3 for 1M+ MC hunting
                 max_advisor_positions: get_env_usize("MAX_ADVISOR_POSITIONS", 3)?,        // Increased from 2
+                max_total_exposure_sol: get_env_f64("MAX_TOTAL_EXPOSURE_SOL", 2.0)?,
                 rate_limit_ms: get_env_u64("RATE_LIMIT_MS", 100)?,
                 advisor_rate_limit_ms: get_env_u64("ADVISOR_RATE_LIMIT_MS", 30000)?,
                 loss_backoff_threshold: get_env_usize("LOSS_BACKOFF_THRESHOLD", 4)?,      // Increased from 3 ($100 positions)
                 loss_backoff_window_secs: get_env_u64("LOSS_BACKOFF_WINDOW_SECS", 180)?,
                 loss_backoff_pause_secs: get_env_u64("LOSS_BACKOFF_PAUSE_SECS", 120)?,
                 wallet_cooling_secs: get_env_u64("WALLET_COOLING_SECS", 60)?,             // Reduced from 90 for faster reuse
+                mint_loss_cooldown_secs: get_env_u64("MINT_LOSS_COOLDOWN_SECS", 300)?,
             },
             database: DatabaseConfig {
                 postgres_host: get_env_string("POSTGRES_HOST", "localhost")?,
@@ -217,11 +335,19 @@ impl Config {
             },
             network: NetworkConfig {
                 advice_bus_port: get_env_u16("ADVICE_BUS_PORT", 45100)?,
+                advice_bus_unix_path: env::var("ADVICE_BUS_UNIX_PATH").ok()
+                    .filter(|path| !path.is_empty())
+                    .map(PathBuf::from),
                 decision_bus_port: get_env_u16("DECISION_BUS_PORT", 45110)?,
+                sell_retransmit_ms: get_env_u64("SELL_RETRANSMIT_MS", 0)?,
+                sell_retransmit_timeout_ms: get_env_u64("SELL_RETRANSMIT_TIMEOUT_MS", 5000)?,
+                manual_exit_port: get_env_u16("MANUAL_EXIT_PORT", 45135)?,
                 udp_bind_address: IpAddr::from_str(&get_env_string("UDP_BIND_ADDRESS", "127.0.0.1")?)
                     .context("Invalid UDP_BIND_ADDRESS")?,
                 udp_recv_buffer_size: get_env_usize("UDP_RECV_BUFFER_SIZE", 8192)?,
                 udp_send_buffer_size: get_env_usize("UDP_SEND_BUFFER_SIZE", 8192)?,
+                advice_bus_secret: env::var("ADVICE_BUS_SECRET").ok(),
+                advice_bus_secret_file: get_env_string("ADVICE_BUS_SECRET_FILE", &crate::udp_bus::auth::default_secret_file())?,
                 yellowstone_endpoint: get_env_string("YELLOWSTONE_ENDPOINT", "http://127.0.0.1:10000")?,
                 yellowstone_token: env::var("YELLOWSTONE_TOKEN").ok(),
                 rpc_url: get_env_string("RPC_URL", "https://api.mainnet-beta.solana.com")?,
@@ -230,13 +356,15 @@ impl Config {
                 telegram_chat_id: get_env_string("TELEGRAM_CHAT_ID", "")?,
             },
             logging: LoggingConfig {
-                decision_log_path: PathBuf::from(get_env_string("DECISION_LOG_PATH", "./data/brain_decisions.csv")?),
+                decision_log_path: PathBuf::from(get_env_string("DECISION_LOG_PATH", "./data/brain_decisions.jsonl")?),
                 log_level: get_env_string("LOG_LEVEL", "info")?,
             },
             cache: CacheConfig {
                 mint_cache_capacity: get_env_usize("MINT_CACHE_CAPACITY", 10000)?,
                 wallet_cache_capacity: get_env_usize("WALLET_CACHE_CAPACITY", 5000)?,
                 cache_refresh_interval_secs: get_env_u64("CACHE_REFRESH_INTERVAL_SECS", 30)?,
+                mint_cache_incremental_interval_ms: get_env_u64("MINT_CACHE_INCREMENTAL_INTERVAL_MS", 1000)?,
+                mint_cache_full_refresh_secs: get_env_u64("MINT_CACHE_FULL_REFRESH_SECS", 300)?,
             },
             performance: PerformanceConfig {
                 worker_threads: get_env_usize("WORKER_THREADS", 0)?,
@@ -252,62 +380,153 @@ impl Config {
                 reconciliation_interval_sec: get_env_u64("RECONCILIATION_INTERVAL_SEC", 30)?,
                 stale_state_threshold_sec: get_env_u64("STALE_STATE_THRESHOLD_SEC", 60)?,
             },
+            wallet_tiers: WalletTierConfig {
+                min_trades: get_env_u32("WALLET_TIER_MIN_TRADES", 10)?,
+                tier_a_min_win_rate: get_env_f64("WALLET_TIER_A_MIN_WIN_RATE", 0.60)?,
+                tier_a_min_pnl_sol: get_env_f64("WALLET_TIER_A_MIN_PNL_SOL", 100.0)?,
+                tier_b_min_win_rate: get_env_f64("WALLET_TIER_B_MIN_WIN_RATE", 0.55)?,
+                tier_b_min_pnl_sol: get_env_f64("WALLET_TIER_B_MIN_PNL_SOL", 40.0)?,
+                tier_c_min_win_rate: get_env_f64("WALLET_TIER_C_MIN_WIN_RATE", 0.50)?,
+                tier_c_min_pnl_sol: get_env_f64("WALLET_TIER_C_MIN_PNL_SOL", 15.0)?,
+            },
         })
     }
 
     /// Validate configuration values are within acceptable ranges
+    ///
+    /// Collects every problem and fails with one error listing them all.
     pub fn validate(&self) -> Result<()> {
+        let mut problems: Vec<String> = Vec::new();
+
         // Decision thresholds
         if self.decision.min_decision_conf > 100 {
-            anyhow::bail!("MIN_DECISION_CONF must be ≤ 100");
+            problems.push("MIN_DECISION_CONF must be ≤ 100".to_string());
         }
         if self.decision.min_copytrade_confidence > 100 {
-            anyhow::bail!("MIN_COPYTRADE_CONFIDENCE must be ≤ 100");
+            problems.push("MIN_COPYTRADE_CONFIDENCE must be ≤ 100".to_string());
+        }
+        for (name, value) in [
+            ("MIN_CONF_LATE", self.decision.min_conf_late),
+            ("MIN_CONF_COPY", self.decision.min_conf_copy),
+            ("MIN_CONF_RANK", self.decision.min_conf_rank),
+            ("MIN_CONF_MOMENTUM", self.decision.min_conf_momentum),
+        ] {
+            if value > 100 {
+                problems.push(format!("{} must be ≤ 100", name));
+            }
         }
         if self.decision.min_follow_through_score > 100 {
-            anyhow::bail!("MIN_FOLLOW_THROUGH_SCORE must be ≤ 100");
+            problems.push("MIN_FOLLOW_THROUGH_SCORE must be ≤ 100".to_string());
+        }
+        if self.decision.sol_price_max_age_secs == 0 {
+            problems.push("SOL_PRICE_MAX_AGE_SECS must be > 0".to_string());
+        }
+        if !(0.0..=1.0).contains(&self.decision.volume_drop_exit_ratio) {
+            problems.push("VOLUME_DROP_EXIT_RATIO must be between 0.0 and 1.0".to_string());
+        }
+        if self.decision.volume_drop_exit_percent > 100 {
+            problems.push("VOLUME_DROP_EXIT_PERCENT must be ≤ 100".to_string());
+        }
+        if !(0.0..100.0).contains(&self.decision.rug_reserve_drop_pct) {
+            problems.push("RUG_RESERVE_DROP_PCT must be between 0 and 100".to_string());
+        }
+        if !(0.0..=1.0).contains(&self.decision.rug_top_seller_share) {
+            problems.push("RUG_TOP_SELLER_SHARE must be between 0.0 and 1.0".to_string());
+        }
+        for (key, mints) in [("ALLOWED_MINTS", &self.decision.allowed_mints), ("DENIED_MINTS", &self.decision.denied_mints)] {
+            for mint in mints {
+                if solana_sdk::pubkey::Pubkey::from_str(mint).is_err() {
+                    problems.push(format!("{} contains an invalid mint: {}", key, mint));
+                }
+            }
+        }
+        if let Some(mint) = self.decision.allowed_mints.intersection(&self.decision.denied_mints).next() {
+            problems.push(format!("Mint {} is on both ALLOWED_MINTS and DENIED_MINTS", mint));
+        }
+        let ladders = &self.decision.profit_ladders;
+        if ladders.is_empty() || ladders.len() > u8::MAX as usize {
+            problems.push("PROFIT_LADDERS must have between 1 and 255 ladders".to_string());
+        }
+        if ladders.iter().any(|l| l.gain_pct <= 0.0 || !(1..=100).contains(&l.exit_pct)) {
+            problems.push("PROFIT_LADDERS gains must be > 0 and exit percents between 1 and 100".to_string());
+        }
+        if ladders.windows(2).any(|pair| pair[1].gain_pct <= pair[0].gain_pct) {
+            problems.push("PROFIT_LADDERS gains must be strictly ascending".to_string());
+        }
+        // exit_pct is a share of what's left: anything under 100 on the last rung leaves a remainder
+        if ladders.last().is_some_and(|last| last.exit_pct != 100) {
+            problems.push("PROFIT_LADDERS last ladder must sell 100%".to_string());
+        }
+        if !(0.0..100.0).contains(&self.decision.trailing_stop_pct) {
+            problems.push("TRAILING_STOP_PCT must be between 0 and 100".to_string());
         }
 
         // Validation parameters
         if self.validation.fee_multiplier <= 0.0 {
-            anyhow::bail!("FEE_MULTIPLIER must be > 0");
+            problems.push("FEE_MULTIPLIER must be > 0".to_string());
         }
         if self.validation.impact_cap_multiplier < 0.0 || self.validation.impact_cap_multiplier > 1.0 {
-            anyhow::bail!("IMPACT_CAP_MULTIPLIER must be between 0.0 and 1.0");
+            problems.push("IMPACT_CAP_MULTIPLIER must be between 0.0 and 1.0".to_string());
         }
         if self.validation.min_liquidity_usd < 0.0 {
-            anyhow::bail!("MIN_LIQUIDITY_USD must be ≥ 0");
+            problems.push("MIN_LIQUIDITY_USD must be ≥ 0".to_string());
         }
         if self.validation.max_slippage < 0.0 || self.validation.max_slippage > 1.0 {
-            anyhow::bail!("MAX_SLIPPAGE must be between 0.0 and 1.0");
+            problems.push("MAX_SLIPPAGE must be between 0.0 and 1.0".to_string());
+        }
+        if self.validation.exit_slippage_bps > 10_000 {
+            problems.push("EXIT_SLIPPAGE_BPS must be ≤ 10000".to_string());
         }
 
         // Guardrails
         if self.guardrails.max_concurrent_positions == 0 {
-            anyhow::bail!("MAX_CONCURRENT_POSITIONS must be > 0");
+            problems.push("MAX_CONCURRENT_POSITIONS must be > 0".to_string());
         }
         if self.guardrails.max_advisor_positions > self.guardrails.max_concurrent_positions {
-            anyhow::bail!("MAX_ADVISOR_POSITIONS cannot exceed MAX_CONCURRENT_POSITIONS");
+            problems.push("MAX_ADVISOR_POSITIONS cannot exceed MAX_CONCURRENT_POSITIONS".to_string());
+        }
+        if self.guardrails.max_total_exposure_sol < 0.0 {
+            problems.push("MAX_TOTAL_EXPOSURE_SOL must be ≥ 0".to_string());
         }
 
         // Network
         if self.network.advice_bus_port == 0 {
-            anyhow::bail!("ADVICE_BUS_PORT must be > 0");
+            problems.push("ADVICE_BUS_PORT must be > 0".to_string());
         }
         if self.network.decision_bus_port == 0 {
-            anyhow::bail!("DECISION_BUS_PORT must be > 0");
+            problems.push("DECISION_BUS_PORT must be > 0".to_string());
         }
         if self.network.advice_bus_port == self.network.decision_bus_port {
-            anyhow::bail!("ADVICE_BUS_PORT and DECISION_BUS_PORT must be different");
+            problems.push("ADVICE_BUS_PORT and DECISION_BUS_PORT must be different".to_string());
+        }
+        if let Some(path) = &self.network.advice_bus_unix_path {
+            // sun_path holds 108 bytes including the terminating NUL
+            if !path.is_absolute() || path.as_os_str().len() >= 108 {
+                problems.push(format!("ADVICE_BUS_UNIX_PATH must be an absolute path under 108 bytes (got {})", path.display()));
+            }
+        }
+        if self.network.sell_retransmit_ms > 0
+            && self.network.sell_retransmit_timeout_ms < self.network.sell_retransmit_ms
+        {
+            problems.push("SELL_RETRANSMIT_TIMEOUT_MS must be ≥ SELL_RETRANSMIT_MS".to_string());
+        }
+        if self.network.manual_exit_port == 0 {
+            problems.push("MANUAL_EXIT_PORT must be > 0".to_string());
+        } else if self.network.manual_exit_port == self.network.advice_bus_port
+            || self.network.manual_exit_port == self.network.decision_bus_port
+        {
+            problems.push("MANUAL_EXIT_PORT must differ from ADVICE_BUS_PORT and DECISION_BUS_PORT".to_string());
         }
         if self.network.wallet_pubkey.is_empty() {
-            anyhow::bail!("WALLET_PUBKEY must be set for gRPC monitoring");
+            problems.push("WALLET_PUBKEY must be set for gRPC monitoring".to_string());
+        } else if solana_sdk::pubkey::Pubkey::from_str(&self.network.wallet_pubkey).is_err() {
+            problems.push(format!("WALLET_PUBKEY '{}' is not a valid pubkey", self.network.wallet_pubkey));
         }
         if self.network.yellowstone_endpoint.is_empty() {
-            anyhow::bail!("YELLOWSTONE_ENDPOINT must be set");
+            problems.push("YELLOWSTONE_ENDPOINT must be set".to_string());
         }
         if self.network.rpc_url.is_empty() {
-            anyhow::bail!("RPC_URL must be set");
+            problems.push("RPC_URL must be set".to_string());
         }
         if self.network.telegram_bot_token.is_empty() {
             log::warn!("TELEGRAM_BOT_TOKEN is empty - notifications will be disabled");
@@ -323,12 +542,37 @@ impl Config {
 
         // Cache
         if self.cache.mint_cache_capacity == 0 {
-            anyhow::bail!("MINT_CACHE_CAPACITY must be > 0");
+            problems.push("MINT_CACHE_CAPACITY must be > 0".to_string());
         }
         if self.cache.wallet_cache_capacity == 0 {
-            anyhow::bail!("WALLET_CACHE_CAPACITY must be > 0");
+            problems.push("WALLET_CACHE_CAPACITY must be > 0".to_string());
+        }
+        if self.cache.mint_cache_incremental_interval_ms == 0 {
+            problems.push("MINT_CACHE_INCREMENTAL_INTERVAL_MS must be > 0".to_string());
+        }
+
+        // Wallet tiers: each tier must be at least as strict as the one below it
+        let tiers = &self.wallet_tiers;
+        for (key, win_rate) in [
+            ("WALLET_TIER_A_MIN_WIN_RATE", tiers.tier_a_min_win_rate),
+            ("WALLET_TIER_B_MIN_WIN_RATE", tiers.tier_b_min_win_rate),
+            ("WALLET_TIER_C_MIN_WIN_RATE", tiers.tier_c_min_win_rate),
+        ] {
+            if !(0.0..=1.0).contains(&win_rate) {
+                problems.push(format!("{} must be between 0.0 and 1.0", key));
+            }
+        }
+        if tiers.tier_a_min_win_rate < tiers.tier_b_min_win_rate
+            || tiers.tier_b_min_win_rate < tiers.tier_c_min_win_rate
+            || tiers.tier_a_min_pnl_sol < tiers.tier_b_min_pnl_sol
+            || tiers.tier_b_min_pnl_sol < tiers.tier_c_min_pnl_sol
+        {
+            problems.push("WALLET_TIER_* minimums must satisfy A ≥ B ≥ C".to_string());
         }
 
+        if !problems.is_empty() {
+            anyhow::bail!("Invalid configuration ({} problems):\n  - {}", problems.len(), problems.join("\n  - "));
+        }
         Ok(())
     }
 }
@@ -355,6 +599,14 @@ fn get_env_u16(key: &str, default: u16) -> Result<u16> {
         .context(format!("Invalid {} value", key))
 }
 
+fn get_env_u32(key: &str, default: u32) -> Result<u32> {
+    env::var(key)
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .or(Some(default))
+        .context(format!("Invalid {} value", key))
+}
+
 fn get_env_u64(key: &str, default: u64) -> Result<u64> {
     env::var(key)
         .ok()
@@ -379,6 +631,61 @@ fn get_env_f64(key: &str, default: f64) -> Result<f64> {
         .context(format!("Invalid {} value", key))
 }
 
+fn get_env_bool(key: &str, default: bool) -> Result<bool> {
+    env::var(key)
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .or(Some(default))
+        .context(format!("Invalid {} value", key))
+}
+
+/// Mints from a comma-separated `list_key` plus the file at `file_key`
+/// (one mint per line, `#` comments)
+fn get_env_mint_set(list_key: &str, file_key: &str) -> Result<HashSet<String>> {
+    let mut mints = parse_mint_list(&env::var(list_key).unwrap_or_default());
+    if let Ok(path) = env::var(file_key) {
+        let contents = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {} ({})", file_key, path))?;
+        mints.extend(parse_mint_list(&contents));
+    }
+    Ok(mints)
+}
+
+/// Profit ladders from comma-separated `gain_pct:exit_pct` pairs
+/// (unset or empty = `ProfitLadder::defaults`)
+fn get_env_profit_ladders(key: &str) -> Result<Vec<ProfitLadder>> {
+    match env::var(key) {
+        Ok(text) if !text.trim().is_empty() => parse_profit_ladders(&text)
+            .with_context(|| format!("Invalid {} value: {}", key, text)),
+        _ => Ok(ProfitLadder::defaults()),
+    }
+}
+
+fn parse_profit_ladders(text: &str) -> Result<Vec<ProfitLadder>> {
+    text.split(',')
+        .map(str::trim)
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (gain, exit) = pair.split_once(':')
+                .with_context(|| format!("expected gain_pct:exit_pct, got '{}'", pair))?;
+            Ok(ProfitLadder::new(
+                gain.trim().parse().with_context(|| format!("bad gain in '{}'", pair))?,
+                exit.trim().parse().with_context(|| format!("bad exit percent in '{}'", pair))?,
+            ))
+        })
+        .collect()
+}
+
+fn parse_mint_list(text: &str) -> HashSet<String> {
+    text.lines()
+        .map(|line| line.split('#').next().unwrap_or(""))
+        .flat_map(|line| line.split(','))
+        .map(str::trim)
+        .filter(|mint| !mint.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -452,6 +759,84 @@ mod tests {
         assert!(config.validate().is_err());
     }
 
+    #[test]
+    fn test_config_validation_lists_all_problems() {
+        let mut config = Config::from_env().expect("Failed to load config");
+        config.decision.min_decision_conf = 150;
+        config.validation.fee_multiplier = 0.0;
+        config.network.wallet_pubkey = "not-a-pubkey".to_string();
+
+        let err = config.validate().unwrap_err().to_string();
+        assert!(err.contains("MIN_DECISION_CONF"), "{}", err);
+        assert!(err.contains("FEE_MULTIPLIER"), "{}", err);
+        assert!(err.contains("WALLET_PUBKEY"), "{}", err);
+    }
+
+    #[test]
+    fn test_min_conf_for_trigger() {
+        let mut config = Config::from_env().expect("Failed to load config");
+        config.decision.min_conf_late = 60;
+        config.decision.min_conf_copy = 65;
+        config.decision.min_conf_rank = 70;
+        config.decision.min_conf_momentum = 80;
+
+        let decision = &config.decision;
+        assert_eq!(decision.min_conf_for(EntryTrigger::LateOpportunity), 60);
+        assert_eq!(decision.min_conf_for(EntryTrigger::CopyTrade), 65);
+        assert_eq!(decision.min_conf_for(EntryTrigger::RankBased), 70);
+        assert_eq!(decision.min_conf_for(EntryTrigger::Hotlist), 70);
+        assert_eq!(decision.min_conf_for(EntryTrigger::Momentum), 80);
+
+        config.decision.min_conf_momentum = 101;
+        assert!(config.validate().unwrap_err().to_string().contains("MIN_CONF_MOMENTUM"));
+    }
+
+    #[test]
+    fn test_mint_lists() {
+        let mut config = Config::from_env().expect("Failed to load config");
+        let (pilot, other, bad) = (
+            "So11111111111111111111111111111111111111112",
+            "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
+            "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB",
+        );
+        assert_eq!(config.decision.mint_list_rejection(pilot), None);
+
+        config.decision.allowed_mints = parse_mint_list(&format!("{pilot}, {other}\n# pilot set\n"));
+        config.decision.denied_mints = parse_mint_list(&format!("{bad} # known rug"));
+        assert_eq!(config.decision.allowed_mints.len(), 2);
+        assert_eq!(config.decision.mint_list_rejection(pilot), None);
+        assert_eq!(config.decision.mint_list_rejection(bad), Some("on the denylist"));
+
+        config.decision.allowed_mints.remove(other);
+        assert_eq!(config.decision.mint_list_rejection(other), Some("not on the allowlist"));
+
+        config.decision.denied_mints.insert(pilot.to_string());
+        assert!(config.validate().unwrap_err().to_string().contains("both ALLOWED_MINTS and DENIED_MINTS"));
+    }
+
+    #[test]
+    fn test_profit_ladders() {
+        let mut config = Config::from_env().expect("Failed to load config");
+
+        config.decision.profit_ladders = parse_profit_ladders("20:25, 50:33,150:100").unwrap();
+        assert_eq!(config.decision.profit_ladders, vec![
+            ProfitLadder::new(20.0, 25),
+            ProfitLadder::new(50.0, 33),
+            ProfitLadder::new(150.0, 100),
+        ]);
+        assert!(parse_profit_ladders("20").is_err());
+        assert!(parse_profit_ladders("20:abc").is_err());
+
+        config.decision.profit_ladders = parse_profit_ladders("50:25,20:100").unwrap();
+        assert!(config.validate().unwrap_err().to_string().contains("strictly ascending"));
+        config.decision.profit_ladders = parse_profit_ladders("20:0,50:100").unwrap();
+        assert!(config.validate().unwrap_err().to_string().contains("exit percents"));
+        config.decision.profit_ladders = parse_profit_ladders("20:25,50:50").unwrap();
+        assert!(config.validate().unwrap_err().to_string().contains("last ladder must sell 100%"));
+        config.decision.profit_ladders.clear();
+        assert!(config.validate().unwrap_err().to_string().contains("between 1 and 255 ladders"));
+    }
+
     #[test]
     fn test_postgres_connection_string() {
         let db_config = DatabaseConfig {
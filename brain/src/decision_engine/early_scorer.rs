@@ -14,10 +14,25 @@
 //! 5. Buy concentration check (+1.0)
 //! 6. Volume acceleration (+1.5)
 //! 7. MC velocity (+3.0)
+//!
+//! ## Scorer by token age
+//! - **< 60s** (`EARLY_SCORE_MAX_AGE_SECS`): `EarlyScorer` - launch-shape signals
+//!   (buyer speed, concentration, volume/MC acceleration since launch)
+//! - **≥ 60s**: `FollowThroughScorer` - sustained momentum, tuned for older tokens
+//!
+//! Both produce a 0-100 decision confidence for RankOpportunity/LateOpportunity entries.
 
 use anyhow::Result;
 use log::{debug, info};
 
+use crate::feature_cache::MintFeatures;
+
+/// Tokens younger than this are scored with `EarlyScorer` instead of `FollowThroughScorer`
+pub const EARLY_SCORE_MAX_AGE_SECS: u64 = 60;
+
+/// Pump.fun market cap at launch (30 virtual SOL over ~1.073B virtual tokens, 1B supply)
+const PUMP_LAUNCH_MC_SOL: f64 = 28.0;
+
 /// Early scoring result with signal breakdown
 #[derive(Debug, Clone)]
 pub struct EarlyScore {
@@ -66,6 +81,14 @@ impl EarlyScore {
         pct as u8
     }
     
+    /// Decision confidence (0-100) on the same scale as the follow-through score
+    /// 
+    /// Anchored to the entry thresholds: 6.0 (high confidence) → 75, matching the
+    /// default MIN_DECISION_CONF; 8.0 (max position size) → 100.
+    pub fn decision_confidence(&self) -> u8 {
+        (self.total / 8.0 * 100.0).clamp(0.0, 100.0) as u8
+    }
+    
     /// Generate human-readable breakdown string
     fn generate_breakdown(&self) -> String {
         let mut parts = Vec::new();
@@ -340,6 +363,39 @@ impl EarlyScorer {
         score
     }
     
+    /// Calculate early score from cached mint features (sub-minute launches)
+    /// 
+    /// Creator reputation, liquidity and wallet overlap aren't in `MintFeatures`, so
+    /// those signals score 0. For a token this young the whole 60s window is its
+    /// history, so:
+    /// - volume acceleration compares the last 5s to the 60s per-5s average
+    /// - MC 30s ago is interpolated linearly from the launch MC
+    pub fn score_features(&self, mint_features: &MintFeatures) -> EarlyScore {
+        let age = mint_features.age_since_launch.max(1);
+        let market_cap = mint_features.mc_sol;
+        
+        let mc_30s_ago = if market_cap > PUMP_LAUNCH_MC_SOL {
+            let elapsed_before = age.saturating_sub(30) as f64 / age as f64;
+            PUMP_LAUNCH_MC_SOL + (market_cap - PUMP_LAUNCH_MC_SOL) * elapsed_before
+        } else {
+            0.0
+        };
+        
+        self.calculate_score(
+            false,
+            mint_features.buyers_60s,
+            age,
+            market_cap,
+            0.0,
+            mint_features.top3_share_60s,
+            mint_features.vol_60s_sol / 12.0,
+            mint_features.vol_5s_sol,
+            mc_30s_ago,
+            0,
+            0,
+        )
+    }
+    
     /// Quick check if token meets minimum scoring threshold
     pub fn quick_check(
         &self,
@@ -431,4 +487,37 @@ mod tests {
         // Should fail quick check
         assert!(!scorer.quick_check(false, 3, 200.0));
     }
+    
+    #[test]
+    fn test_score_features_sub_minute_launch() {
+        let scorer = EarlyScorer::new();
+        
+        // 20s old, 12 buyers, 5s volume well above the 60s average, MC ripping
+        let hot = MintFeatures {
+            age_since_launch: 20,
+            buyers_60s: 12,
+            vol_60s_sol: 24.0,
+            vol_5s_sol: 8.0,
+            mc_sol: 600.0,
+            top3_share_60s: 0.4,
+            ..Default::default()
+        };
+        let score = scorer.score_features(&hot);
+        assert!(score.is_high_confidence(6.0), "Expected high score, got {} ({})", score.total, score.breakdown);
+        assert!(score.decision_confidence() >= 75);
+        
+        // Slow launch: few buyers, flat volume, MC barely moved, concentrated
+        let cold = MintFeatures {
+            age_since_launch: 50,
+            buyers_60s: 2,
+            vol_60s_sol: 1.2,
+            vol_5s_sol: 0.1,
+            mc_sol: 30.0,
+            top3_share_60s: 0.95,
+            ..Default::default()
+        };
+        let score = scorer.score_features(&cold);
+        assert_eq!(score.total, 0.0, "Expected no signals, got {}", score.breakdown);
+        assert_eq!(score.decision_confidence(), 0);
+    }
 }
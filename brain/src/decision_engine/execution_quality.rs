@@ -0,0 +1,167 @@
+//! 📐 Execution Quality - Predicted vs realized entry price per trigger type
+//!
+//! Fed by Mempool-watcher's TxConfirmedContext (port 45115): on each confirmed BUY
+//! we compare the price the decision was made at against the confirmed fill price
+//! and keep a rolling window of the delta per entry trigger.
+//!
+//! The rolling mean feeds back into sizing: triggers whose fills consistently land
+//! worse than the tolerated slippage get smaller positions until they recover.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use log::debug;
+use crate::decision_engine::triggers::EntryTrigger;
+
+/// Samples kept per trigger type
+const DEFAULT_WINDOW: usize = 50;
+
+/// Samples required before calibration kicks in
+const MIN_SAMPLES: usize = 5;
+
+/// Mean fill delta we accept without shrinking size (matches the old fixed tolerance)
+const TOLERATED_DELTA_BPS: f64 = 150.0;
+
+/// Size cut per 100 bps of mean delta above tolerance
+const SIZE_CUT_PER_100_BPS: f64 = 0.10;
+
+/// Never shrink below half size on execution quality alone
+const MIN_SIZE_MULTIPLIER: f64 = 0.5;
+
+/// Rolling execution stats for one trigger type
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExecutionStats {
+    pub samples: usize,
+    /// Mean realized vs predicted delta (positive = paid more than predicted)
+    pub mean_delta_bps: f64,
+    /// Worst delta in the window
+    pub worst_delta_bps: f64,
+}
+
+/// Rolling per-trigger tracker of entry fill quality
+pub struct ExecutionQualityTracker {
+    window: usize,
+    samples: Mutex<HashMap<EntryTrigger, VecDeque<f64>>>,
+}
+
+impl ExecutionQualityTracker {
+    pub fn new() -> Self {
+        Self::with_window(DEFAULT_WINDOW)
+    }
+
+    pub fn with_window(window: usize) -> Self {
+        Self {
+            window: window.max(1),
+            samples: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Record a confirmed BUY fill; returns the delta in bps (None if either price is unknown)
+    pub fn record(&self, trigger: EntryTrigger, predicted_price_sol: f64, realized_price_sol: f64) -> Option<f64> {
+        let delta_bps = price_delta_bps(predicted_price_sol, realized_price_sol)?;
+
+        let mut samples = self.samples.lock().unwrap();
+        let history = samples.entry(trigger).or_default();
+        history.push_back(delta_bps);
+        while history.len() > self.window {
+            history.pop_front();
+        }
+
+        debug!("📐 Recorded {} fill delta: {:+.0} bps (history: {})",
+               trigger.as_str(), delta_bps, history.len());
+
+        Some(delta_bps)
+    }
+
+    /// Rolling stats for a trigger type (None until the first sample)
+    pub fn stats(&self, trigger: EntryTrigger) -> Option<ExecutionStats> {
+        let samples = self.samples.lock().unwrap();
+        let history = samples.get(&trigger).filter(|h| !h.is_empty())?;
+
+        Some(ExecutionStats {
+            samples: history.len(),
+            mean_delta_bps: history.iter().sum::<f64>() / history.len() as f64,
+            worst_delta_bps: history.iter().cloned().fold(f64::MIN, f64::max),
+        })
+    }
+
+    /// Sizing multiplier for a trigger type (1.0 until enough samples / within tolerance)
+    pub fn size_multiplier(&self, trigger: EntryTrigger) -> f64 {
+        match self.stats(trigger) {
+            Some(stats) if stats.samples >= MIN_SAMPLES && stats.mean_delta_bps > TOLERATED_DELTA_BPS => {
+                let excess = stats.mean_delta_bps - TOLERATED_DELTA_BPS;
+                (1.0 - excess / 100.0 * SIZE_CUT_PER_100_BPS).max(MIN_SIZE_MULTIPLIER)
+            }
+            _ => 1.0,
+        }
+    }
+}
+
+impl Default for ExecutionQualityTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Realized vs predicted price delta in bps (positive = filled above prediction)
+pub fn price_delta_bps(predicted_price_sol: f64, realized_price_sol: f64) -> Option<f64> {
+    if predicted_price_sol <= 0.0 || realized_price_sol <= 0.0 {
+        return None;
+    }
+    Some((realized_price_sol - predicted_price_sol) / predicted_price_sol * 10_000.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_price_delta_bps() {
+        assert_eq!(price_delta_bps(0.001, 0.00102).map(|d| d.round()), Some(200.0));
+        assert_eq!(price_delta_bps(0.001, 0.00099).map(|d| d.round()), Some(-100.0));
+        assert_eq!(price_delta_bps(0.0, 0.001), None);
+    }
+
+    #[test]
+    fn test_stats_are_per_trigger_and_rolling() {
+        let tracker = ExecutionQualityTracker::with_window(3);
+        for realized in [0.00101, 0.00102, 0.00103, 0.00104] {
+            tracker.record(EntryTrigger::CopyTrade, 0.001, realized);
+        }
+        tracker.record(EntryTrigger::LateOpportunity, 0.001, 0.00099);
+
+        let copy = tracker.stats(EntryTrigger::CopyTrade).unwrap();
+        assert_eq!(copy.samples, 3);
+        assert_eq!(copy.mean_delta_bps.round(), 300.0); // oldest (100 bps) rolled out
+        assert_eq!(copy.worst_delta_bps.round(), 400.0);
+
+        assert_eq!(tracker.stats(EntryTrigger::LateOpportunity).unwrap().samples, 1);
+        assert!(tracker.stats(EntryTrigger::RankBased).is_none());
+    }
+
+    #[test]
+    fn test_size_multiplier_shrinks_on_poor_fills() {
+        let tracker = ExecutionQualityTracker::new();
+
+        // Not enough samples yet
+        for _ in 0..MIN_SAMPLES - 1 {
+            tracker.record(EntryTrigger::CopyTrade, 0.001, 0.001035); // +350 bps
+        }
+        assert_eq!(tracker.size_multiplier(EntryTrigger::CopyTrade), 1.0);
+
+        // 200 bps over tolerance → 20% smaller
+        tracker.record(EntryTrigger::CopyTrade, 0.001, 0.001035);
+        assert!((tracker.size_multiplier(EntryTrigger::CopyTrade) - 0.8).abs() < 1e-6);
+
+        // Good fills never change size
+        for _ in 0..MIN_SAMPLES {
+            tracker.record(EntryTrigger::LateOpportunity, 0.001, 0.001);
+        }
+        assert_eq!(tracker.size_multiplier(EntryTrigger::LateOpportunity), 1.0);
+
+        // Floor
+        for _ in 0..DEFAULT_WINDOW {
+            tracker.record(EntryTrigger::RankBased, 0.001, 0.002);
+        }
+        assert_eq!(tracker.size_multiplier(EntryTrigger::RankBased), MIN_SIZE_MULTIPLIER);
+    }
+}
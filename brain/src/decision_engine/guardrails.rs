@@ -1,7 +1,8 @@
 //! 🛡️ Anti-Churn Guardrails
 //!
 //! Prevents excessive trading that leads to losses from fees and slippage.
-//! Enforces: loss backoff, position limits, rate limiting, wallet cooling.
+//! Enforces: loss backoff, position limits, total exposure cap, rate limiting,
+//! wallet cooling, per-mint cooldown after a loss.
 
 use std::time::{SystemTime, UNIX_EPOCH};
 use std::collections::{VecDeque, HashMap};
@@ -36,7 +37,7 @@ struct WalletCopyEntry {
 /// Entry for rate limiting
 #[derive(Debug, Clone)]
 struct RateLimitEntry {
-    trigger_type: u8, // 0=rank, 1=momentum, 2=copy, 3=late
+    trigger_type: u8, // 0=rank, 1=momentum, 2=copy, 3=late, 4=hotlist
     timestamp: u64,
 }
 
@@ -58,6 +59,7 @@ pub struct GuardrailConfig {
     // Position limits
     pub max_concurrent_positions: usize, // Default: 3
     pub max_advisor_positions: usize,    // Default: 2 (copy+late only)
+    pub max_total_exposure_sol: f64,     // Default: 2.0 (hard cap on open size_sol, 0 = off)
     
     // Rate limiting
     pub advisor_rate_limit_secs: u64,    // Default: 30 (≤1 advisor entry per 30s)
@@ -67,6 +69,9 @@ pub struct GuardrailConfig {
     pub wallet_cooling_period_secs: u64, // Default: 90 (no copy same wallet twice in 90s)
     pub tier_a_bypass_cooling: bool,     // Default: true (Tier A can bypass if last was profitable)
     
+    // Mint loss cooldown
+    pub mint_loss_cooldown_secs: u64,    // Default: 300 (no re-entry into a mint we just lost on, 0 = off)
+    
     // Creator rate limiting
     pub creator_trade_limit_window_secs: u64, // Default: 60 (1 minute window)
     pub creator_trade_limit_count: usize,     // Default: 3 (max 3 trades per minute per creator)
@@ -80,10 +85,12 @@ impl Default for GuardrailConfig {
             loss_backoff_duration_secs: 120,
             max_concurrent_positions: 3,
             max_advisor_positions: 2,
+            max_total_exposure_sol: 2.0,
             advisor_rate_limit_secs: 30,
             min_decision_interval_ms: 100,
             wallet_cooling_period_secs: 90,
             tier_a_bypass_cooling: true,
+            mint_loss_cooldown_secs: 300,
             creator_trade_limit_window_secs: 60,
             creator_trade_limit_count: 3,
         }
@@ -100,6 +107,9 @@ pub struct Guardrails {
     recent_losses: Arc<Mutex<VecDeque<LossEntry>>>,
     backoff_until: Arc<Mutex<Option<u64>>>,
     
+    // Executor circuit breaker (execution-side health pause)
+    execution_paused_until: Arc<Mutex<Option<u64>>>,
+    
     // Position tracking
     open_positions: Arc<Mutex<HashMap<[u8; 32], bool>>>, // mint -> is_advisor
     
@@ -111,6 +121,9 @@ pub struct Guardrails {
     // Wallet cooling
     wallet_copy_history: Arc<Mutex<VecDeque<WalletCopyEntry>>>,
     
+    // Mint cooldown after a loss
+    mint_loss_cooldowns: Arc<Mutex<HashMap<[u8; 32], u64>>>, // mint -> blocked until (secs)
+    
     // Creator rate limiting (with persistence)
     creator_trade_history: Arc<Mutex<VecDeque<CreatorTradeEntry>>>,
 }
@@ -131,12 +144,14 @@ impl Guardrails {
         info!("   Position limits: {} total, {} advisor", 
               config.max_concurrent_positions,
               config.max_advisor_positions);
+        info!("   Max total exposure: {:.2} SOL", config.max_total_exposure_sol);
         info!("   Rate limits: advisor {}s, general {}ms",
               config.advisor_rate_limit_secs,
               config.min_decision_interval_ms);
         info!("   Wallet cooling: {}s (Tier A bypass: {})",
               config.wallet_cooling_period_secs,
               config.tier_a_bypass_cooling);
+        info!("   Mint loss cooldown: {}s", config.mint_loss_cooldown_secs);
         info!("   Creator rate limit: {} trades per {}s",
               config.creator_trade_limit_count,
               config.creator_trade_limit_window_secs);
@@ -147,11 +162,13 @@ impl Guardrails {
             db_path: db_path.clone(),
             recent_losses: Arc::new(Mutex::new(VecDeque::new())),
             backoff_until: Arc::new(Mutex::new(None)),
+            execution_paused_until: Arc::new(Mutex::new(None)),
             open_positions: Arc::new(Mutex::new(HashMap::new())),
             last_advisor_entry: Arc::new(Mutex::new(None)),
             last_decision: Arc::new(Mutex::new(None)),
             recent_entries: Arc::new(Mutex::new(VecDeque::new())),
             wallet_copy_history: Arc::new(Mutex::new(VecDeque::new())),
+            mint_loss_cooldowns: Arc::new(Mutex::new(HashMap::new())),
             creator_trade_history: Arc::new(Mutex::new(VecDeque::new())),
         };
         
@@ -170,8 +187,8 @@ impl Guardrails {
     /// Returns Ok(()) if allowed, Err(reason) if blocked.
     pub fn check_decision_allowed(
         &self,
-        trigger_type: u8, // 0=rank, 1=momentum, 2=copy, 3=late
-        _mint: &[u8; 32],
+        trigger_type: u8, // 0=rank, 1=momentum, 2=copy, 3=late, 4=hotlist
+        mint: &[u8; 32],
         wallet: Option<&[u8; 32]>, // For copy trades
         wallet_tier: Option<u8>,   // For copy trades (0=C, 1=B, 2=A)
         creator_wallet: Option<&[u8; 32]>, // Token creator wallet for rate limiting
@@ -186,12 +203,29 @@ impl Guardrails {
             }
         }
         
+        // 1b. Check Executor circuit breaker
+        if let Some(until) = *self.execution_paused_until.lock().unwrap() {
+            if now < until {
+                let remaining = until - now;
+                return Err(format!("Executor circuit open: {}s remaining", remaining));
+            }
+        }
+        
+        // 1c. Check mint cooldown (recent loss on this mint)
+        {
+            let mut cooldowns = self.mint_loss_cooldowns.lock().unwrap();
+            cooldowns.retain(|_, until| now < *until);
+            if let Some(until) = cooldowns.get(mint) {
+                return Err(format!("Mint cooldown after loss: {}s remaining", until - now));
+            }
+        }
+        
         // 2. Check position limits
         let positions = self.open_positions.lock().unwrap();
         let total_positions = positions.len();
         let advisor_positions = positions.values().filter(|&&is_adv| is_adv).count();
         
-        let is_advisor = trigger_type == 2 || trigger_type == 3; // copy or late
+        let is_advisor = matches!(trigger_type, 2 | 3 | 4); // copy, late or hotlist
         
         if total_positions >= self.config.max_concurrent_positions {
             return Err(format!("Max positions reached: {}/{}", 
@@ -285,6 +319,17 @@ impl Guardrails {
         Ok(())
     }
     
+    /// Check that opening `new_position_sol` keeps total open exposure under the cap
+    pub fn check_exposure(&self, total_exposure_sol: f64, new_position_sol: f64) -> Result<(), String> {
+        let cap = self.config.max_total_exposure_sol;
+        let new_total = total_exposure_sol + new_position_sol;
+        if cap > 0.0 && new_total > cap {
+            return Err(format!("Max exposure reached: {:.3} + {:.3} SOL > {:.3} SOL cap",
+                               total_exposure_sol, new_position_sol, cap));
+        }
+        Ok(())
+    }
+    
     /// Record a new decision (call after check_decision_allowed succeeds)
     pub fn record_decision(
         &self,
@@ -295,7 +340,7 @@ impl Guardrails {
     ) {
         let now = Self::now_secs();
         
-        let is_advisor = trigger_type == 2 || trigger_type == 3;
+        let is_advisor = matches!(trigger_type, 2 | 3 | 4);
         
         // NOTE: Position tracking moved to ExecutionConfirmation handler
         // Do NOT add to open_positions here - only track confirmed executions!
@@ -521,6 +566,8 @@ impl Guardrails {
         
         // Track losses for backoff
         if outcome == TradeOutcome::Loss {
+            self.record_mint_loss(mint);
+            
             let mut losses = self.recent_losses.lock().unwrap();
             
             // Add new loss
@@ -556,6 +603,29 @@ impl Guardrails {
         }
     }
     
+    /// Block new entries into `mint` for `mint_loss_cooldown_secs` after a realized loss
+    pub fn record_mint_loss(&self, mint: &[u8; 32]) {
+        if self.config.mint_loss_cooldown_secs == 0 {
+            return;
+        }
+        let until = Self::now_secs() + self.config.mint_loss_cooldown_secs;
+        self.mint_loss_cooldowns.lock().unwrap().insert(*mint, until);
+        debug!("🧊 Mint cooldown: {}... blocked for {}s after loss",
+               hex::encode(&mint[..4]), self.config.mint_loss_cooldown_secs);
+    }
+    
+    /// Pause decisions while the Executor's circuit breaker is open
+    ///
+    /// Extends (never shortens) an existing pause.
+    pub fn pause_for_execution_circuit(&self, cooldown_secs: u64) {
+        let until = Self::now_secs() + cooldown_secs;
+        let mut paused_until = self.execution_paused_until.lock().unwrap();
+        if paused_until.is_none_or(|current| until > current) {
+            *paused_until = Some(until);
+            warn!("🔌 Executor circuit open: pausing decisions for {}s", cooldown_secs);
+        }
+    }
+    
     /// Get current statistics
     pub fn stats(&self) -> GuardrailStats {
         let now = Self::now_secs();
@@ -668,6 +738,61 @@ mod tests {
         assert_eq!(guardrails.stats().open_positions, 0);
     }
     
+    #[test]
+    fn test_execution_circuit_pause() {
+        let guardrails = Guardrails::with_config(GuardrailConfig::default(), ":memory:".to_string());
+        let mint = [1u8; 32];
+        
+        assert!(guardrails.check_decision_allowed(0, &mint, None, None, None).is_ok());
+        
+        guardrails.pause_for_execution_circuit(120);
+        let reason = guardrails.check_decision_allowed(0, &mint, None, None, None).unwrap_err();
+        assert!(reason.contains("circuit open"));
+        
+        // A shorter pause doesn't cut the active one short
+        guardrails.pause_for_execution_circuit(1);
+        assert!(guardrails.execution_paused_until.lock().unwrap().unwrap() >= Guardrails::now_secs() + 119);
+    }
+    
+    #[test]
+    fn test_max_total_exposure() {
+        let config = GuardrailConfig {
+            max_total_exposure_sol: 1.0,
+            ..Default::default()
+        };
+        let guardrails = Guardrails::with_config(config, ":memory:".to_string());
+        
+        assert!(guardrails.check_exposure(0.6, 0.4).is_ok());
+        assert!(guardrails.check_exposure(0.7, 0.4).unwrap_err().contains("Max exposure"));
+        
+        let uncapped = Guardrails::with_config(
+            GuardrailConfig { max_total_exposure_sol: 0.0, ..Default::default() },
+            ":memory:".to_string(),
+        );
+        assert!(uncapped.check_exposure(100.0, 1.0).is_ok());
+    }
+    
+    #[test]
+    fn test_mint_loss_cooldown() {
+        let config = GuardrailConfig {
+            min_decision_interval_ms: 0,
+            ..Default::default()
+        };
+        let guardrails = Guardrails::with_config(config, ":memory:".to_string());
+        let lost_mint = [1u8; 32];
+        let other_mint = [2u8; 32];
+        let wallet = [9u8; 32];
+        
+        guardrails.record_outcome(&lost_mint, TradeOutcome::Loss, None);
+        
+        // Immediate CopyTrade into the same mint is blocked
+        let reason = guardrails.check_decision_allowed(2, &lost_mint, Some(&wallet), Some(1), None).unwrap_err();
+        assert!(reason.contains("Mint cooldown"), "{}", reason);
+        
+        // A different mint is unaffected
+        assert!(guardrails.check_decision_allowed(2, &other_mint, Some(&wallet), Some(1), None).is_ok());
+    }
+    
     #[test]
     fn test_config_defaults() {
         let config = GuardrailConfig::default();
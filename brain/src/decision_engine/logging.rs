@@ -2,22 +2,43 @@
 //!
 //! Comprehensive logging of all trading decisions for analysis and debugging.
 //! Records: decision_id, mint, trigger type, fees, impact, TP, score, size, EV, timestamp.
+//!
+//! Format: one JSON object per line (JSON-lines), e.g.
+//! `{"schema_version":2,"decision_id":1,"timestamp":1730000000,"mint":"<hex>",
+//!   "mint_b58":"<base58>","trigger_type":"copy","side":0,...}`
+//!
+//! Schema history:
+//! - v1: CSV with `DecisionLogEntry::csv_header()` columns (no version field)
+//! - v2: JSON-lines with `schema_version` and `mint_b58`
+//!
+//! New fields must be added with `#[serde(default)]` so older lines still parse.
+//! `DecisionLogReader` reads both formats, including files that mix them.
 
 use std::fs::{File, OpenOptions};
-use std::io::Write;
+use std::io::{BufRead, BufReader, Write};
 use std::path::Path;
 use std::sync::{Arc, Mutex};
 use std::time::{SystemTime, UNIX_EPOCH};
 use log::info;
 use anyhow::{Result, Context};
+use serde::{Deserialize, Serialize};
+
+/// Current decision log schema version (v1 = legacy CSV)
+pub const DECISION_LOG_SCHEMA_VERSION: u32 = 2;
 
 /// Entry trigger type for logging
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum TriggerType {
+    #[serde(rename = "rank")]
     Rank,
+    #[serde(rename = "momentum")]
     Momentum,
+    #[serde(rename = "copy")]
     CopyTrade,
+    #[serde(rename = "late")]
     LateOpportunity,
+    #[serde(rename = "hotlist")]
+    Hotlist,
 }
 
 impl TriggerType {
@@ -27,6 +48,7 @@ impl TriggerType {
             TriggerType::Momentum => "momentum",
             TriggerType::CopyTrade => "copy",
             TriggerType::LateOpportunity => "late",
+            TriggerType::Hotlist => "hotlist",
         }
     }
     
@@ -36,16 +58,30 @@ impl TriggerType {
             1 => TriggerType::Momentum,
             2 => TriggerType::CopyTrade,
             3 => TriggerType::LateOpportunity,
+            4 => TriggerType::Hotlist,
             _ => TriggerType::Rank,
         }
     }
+    
+    /// Parse the `as_str()` form (legacy CSV column)
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "rank" => Some(TriggerType::Rank),
+            "momentum" => Some(TriggerType::Momentum),
+            "copy" => Some(TriggerType::CopyTrade),
+            "late" => Some(TriggerType::LateOpportunity),
+            "hotlist" => Some(TriggerType::Hotlist),
+            _ => None,
+        }
+    }
 }
 
 /// Trading decision log entry
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DecisionLogEntry {
     pub decision_id: u64,
     pub timestamp: u64,
+    /// Mint address (hex)
     pub mint: String,
     pub trigger_type: TriggerType,
     pub side: u8, // 0=buy, 1=sell
@@ -66,12 +102,70 @@ pub struct DecisionLogEntry {
     pub success_probability: f64,
     
     // Additional context
+    #[serde(default)]
     pub rank: Option<u8>,
+    #[serde(default)]
     pub wallet: Option<String>,
+    #[serde(default)]
     pub wallet_tier: Option<u8>,
+    /// Slippage tolerance sent with the decision (not in v1 CSV)
+    #[serde(default)]
+    pub slippage_bps: Option<u16>,
 }
 
 impl DecisionLogEntry {
+    /// Mint in base58 (greppable against explorers); falls back to the raw string
+    pub fn mint_b58(&self) -> String {
+        match hex::decode(&self.mint) {
+            Ok(bytes) if bytes.len() == 32 => bs58::encode(bytes).into_string(),
+            _ => self.mint.clone(),
+        }
+    }
+    
+    /// Convert to a versioned JSON line
+    pub fn to_json_line(&self) -> Result<String> {
+        let record = VersionedEntryRef {
+            schema_version: DECISION_LOG_SCHEMA_VERSION,
+            mint_b58: self.mint_b58(),
+            entry: self,
+        };
+        serde_json::to_string(&record).context("Failed to serialize decision log entry")
+    }
+    
+    /// Parse a legacy (schema v1) CSV row
+    pub fn from_csv_row(row: &str) -> Result<Self> {
+        let cols: Vec<&str> = row.split(',').collect();
+        if cols.len() < 17 {
+            anyhow::bail!("Expected at least 17 CSV columns, got {}", cols.len());
+        }
+        
+        fn opt<T: std::str::FromStr>(value: &str) -> Option<T> {
+            if value.is_empty() { None } else { value.parse().ok() }
+        }
+        
+        Ok(Self {
+            decision_id: cols[0].parse().context("decision_id")?,
+            timestamp: cols[1].parse().context("timestamp")?,
+            mint: cols[2].to_string(),
+            trigger_type: TriggerType::parse(cols[3])
+                .with_context(|| format!("Unknown trigger_type: {}", cols[3]))?,
+            side: cols[4].parse().context("side")?,
+            predicted_fees_usd: cols[5].parse().context("predicted_fees_usd")?,
+            predicted_impact_usd: cols[6].parse().context("predicted_impact_usd")?,
+            tp_usd: cols[7].parse().context("tp_usd")?,
+            follow_through_score: cols[8].parse().context("follow_through_score")?,
+            size_sol: cols[9].parse().context("size_sol")?,
+            size_usd: cols[10].parse().context("size_usd")?,
+            confidence: cols[11].parse().context("confidence")?,
+            expected_ev_usd: cols[12].parse().context("expected_ev_usd")?,
+            success_probability: cols[13].parse().context("success_probability")?,
+            rank: opt(cols[14]),
+            wallet: opt(cols[15]),
+            wallet_tier: opt(cols[16]),
+            slippage_bps: None,
+        })
+    }
+
     /// Convert to CSV row
     pub fn to_csv_row(&self) -> String {
         format!(
@@ -105,7 +199,74 @@ impl DecisionLogEntry {
     }
 }
 
-/// Decision logger that writes to CSV file
+/// Serialized form of a log line (borrows the entry)
+#[derive(Serialize)]
+struct VersionedEntryRef<'a> {
+    schema_version: u32,
+    mint_b58: String,
+    #[serde(flatten)]
+    entry: &'a DecisionLogEntry,
+}
+
+/// A decision log line as read back, with its schema version
+#[derive(Debug, Clone, Deserialize)]
+pub struct DecisionLogRecord {
+    pub schema_version: u32,
+    /// Base58 mint (derived from hex for v1 lines)
+    #[serde(default)]
+    pub mint_b58: String,
+    #[serde(flatten)]
+    pub entry: DecisionLogEntry,
+}
+
+/// Reader for decision logs in any schema version (v1 CSV, v2 JSON-lines)
+pub struct DecisionLogReader;
+
+impl DecisionLogReader {
+    /// Read all records from a decision log file
+    pub fn read_path<P: AsRef<Path>>(path: P) -> Result<Vec<DecisionLogRecord>> {
+        let path = path.as_ref();
+        let file = File::open(path)
+            .context(format!("Failed to open decision log: {:?}", path))?;
+        
+        let mut records = Vec::new();
+        for (line_no, line) in BufReader::new(file).lines().enumerate() {
+            let line = line.context("Failed to read decision log line")?;
+            if let Some(record) = Self::parse_line(&line)
+                .with_context(|| format!("{:?}:{}", path, line_no + 1))?
+            {
+                records.push(record);
+            }
+        }
+        Ok(records)
+    }
+    
+    /// Parse one line; returns None for blank lines and CSV headers
+    pub fn parse_line(line: &str) -> Result<Option<DecisionLogRecord>> {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with("decision_id,") {
+            return Ok(None);
+        }
+        
+        if line.starts_with('{') {
+            let mut record: DecisionLogRecord = serde_json::from_str(line)
+                .context("Failed to parse JSON decision log line")?;
+            if record.mint_b58.is_empty() {
+                record.mint_b58 = record.entry.mint_b58();
+            }
+            return Ok(Some(record));
+        }
+        
+        let entry = DecisionLogEntry::from_csv_row(line)?;
+        Ok(Some(DecisionLogRecord {
+            schema_version: 1,
+            mint_b58: entry.mint_b58(),
+            entry,
+        }))
+    }
+}
+
+/// Decision logger that writes versioned JSON-lines
 pub struct DecisionLogger {
     log_file: Arc<Mutex<File>>,
     decision_counter: Arc<Mutex<u64>>,
@@ -115,27 +276,24 @@ pub struct DecisionLogger {
 impl DecisionLogger {
     /// Create new decision logger
     /// 
-    /// If the log file doesn't exist, it will be created with a CSV header.
-    /// If it exists, new entries will be appended.
+    /// If the log file doesn't exist, it will be created.
+    /// If it exists, new entries will be appended (legacy CSV files stay readable
+    /// through `DecisionLogReader`).
     pub fn new<P: AsRef<Path>>(log_path: P) -> Result<Self> {
         let path = log_path.as_ref();
         let file_exists = path.exists();
         
         // Open file in append mode
-        let mut file = OpenOptions::new()
+        let file = OpenOptions::new()
             .create(true)
             .append(true)
             .open(path)
             .context(format!("Failed to open log file: {:?}", path))?;
         
-        // Write header if new file
         if !file_exists {
-            writeln!(file, "{}", DecisionLogEntry::csv_header())
-                .context("Failed to write CSV header")?;
-            file.flush()?;
-            info!("📝 Created new decision log: {:?}", path);
+            info!("📝 Created new decision log: {:?} (schema v{})", path, DECISION_LOG_SCHEMA_VERSION);
         } else {
-            info!("📝 Opened existing decision log: {:?}", path);
+            info!("📝 Opened existing decision log: {:?} (schema v{})", path, DECISION_LOG_SCHEMA_VERSION);
         }
         
         Ok(Self {
@@ -157,10 +315,11 @@ impl DecisionLogger {
         
         entry.decision_id = decision_id;
         
-        // Write to CSV
+        // Write JSON line
+        let line = entry.to_json_line()?;
         {
             let mut file = self.log_file.lock().unwrap();
-            writeln!(file, "{}", entry.to_csv_row())
+            writeln!(file, "{}", line)
                 .context("Failed to write log entry")?;
             file.flush()?;
         }
@@ -174,7 +333,7 @@ impl DecisionLogger {
         info!(
             "📝 Logged decision #{}: mint={}..., trigger={}, size={:.2} SOL, EV=${:.2}",
             decision_id,
-            &entry.mint[..8],
+            &entry.mint_b58()[..8],
             entry.trigger_type.as_str(),
             entry.size_sol,
             entry.expected_ev_usd
@@ -216,6 +375,7 @@ pub struct DecisionLogBuilder {
     rank: Option<u8>,
     wallet: Option<[u8; 32]>,
     wallet_tier: Option<u8>,
+    slippage_bps: Option<u16>,
 }
 
 impl DecisionLogBuilder {
@@ -243,6 +403,7 @@ impl DecisionLogBuilder {
             rank: None,
             wallet: None,
             wallet_tier: None,
+            slippage_bps: None,
         }
     }
     
@@ -288,6 +449,12 @@ impl DecisionLogBuilder {
         self
     }
     
+    /// Set slippage tolerance
+    pub fn slippage(mut self, bps: u16) -> Self {
+        self.slippage_bps = Some(bps);
+        self
+    }
+    
     /// Build the log entry
     pub fn build(self) -> DecisionLogEntry {
         DecisionLogEntry {
@@ -308,6 +475,7 @@ impl DecisionLogBuilder {
             rank: self.rank,
             wallet: self.wallet.map(hex::encode),
             wallet_tier: self.wallet_tier,
+            slippage_bps: self.slippage_bps,
         }
     }
 }
@@ -370,15 +538,15 @@ mod tests {
     
     #[test]
     fn test_logger_creation() {
-        let temp_path = "/tmp/test_decisions.csv";
+        let temp_path = "/tmp/test_decisions.jsonl";
         let _ = fs::remove_file(temp_path); // Clean up if exists
         
         let logger = DecisionLogger::new(temp_path);
         assert!(logger.is_ok());
         
-        // Verify file was created with header
+        // Verify file was created (JSON-lines has no header)
         let content = fs::read_to_string(temp_path).unwrap();
-        assert!(content.contains("decision_id,timestamp"));
+        assert!(content.is_empty());
         
         // Clean up
         let _ = fs::remove_file(temp_path);
@@ -386,7 +554,7 @@ mod tests {
     
     #[test]
     fn test_logging_entry() {
-        let temp_path = "/tmp/test_decisions_2.csv";
+        let temp_path = "/tmp/test_decisions_2.jsonl";
         let _ = fs::remove_file(temp_path);
         
         let logger = DecisionLogger::new(temp_path).unwrap();
@@ -407,8 +575,14 @@ mod tests {
         // Verify file content
         let content = fs::read_to_string(temp_path).unwrap();
         let lines: Vec<&str> = content.lines().collect();
-        assert_eq!(lines.len(), 2); // Header + 1 entry
-        assert!(lines[1].contains("copy"));
+        assert_eq!(lines.len(), 1);
+        assert!(lines[0].contains("\"schema_version\":2"));
+        assert!(lines[0].contains(&bs58::encode(mint).into_string()));
+        
+        let records = DecisionLogReader::read_path(temp_path).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].entry.trigger_type, TriggerType::CopyTrade);
+        assert_eq!(records[0].entry.wallet_tier, Some(2));
         
         // Clean up
         let _ = fs::remove_file(temp_path);
@@ -416,7 +590,7 @@ mod tests {
     
     #[test]
     fn test_multiple_entries() {
-        let temp_path = "/tmp/test_decisions_3.csv";
+        let temp_path = "/tmp/test_decisions_3.jsonl";
         let _ = fs::remove_file(temp_path);
         
         let logger = DecisionLogger::new(temp_path).unwrap();
@@ -441,4 +615,42 @@ mod tests {
         // Clean up
         let _ = fs::remove_file(temp_path);
     }
+    
+    #[test]
+    fn test_reader_handles_legacy_csv_and_json() {
+        let temp_path = "/tmp/test_decisions_mixed.log";
+        let _ = fs::remove_file(temp_path);
+        
+        let mint = [3u8; 32];
+        let legacy = DecisionLogBuilder::new(mint, TriggerType::LateOpportunity, 0)
+            .position(0.5, 100.0, 80)
+            .build();
+        let current = DecisionLogBuilder::new(mint, TriggerType::Rank, 1)
+            .rank(4)
+            .build();
+        
+        // Old CSV file that a v2 logger later appended to
+        let content = format!(
+            "{}\n{}\n{}\n",
+            DecisionLogEntry::csv_header(),
+            legacy.to_csv_row(),
+            current.to_json_line().unwrap()
+        );
+        fs::write(temp_path, content).unwrap();
+        
+        let records = DecisionLogReader::read_path(temp_path).unwrap();
+        assert_eq!(records.len(), 2);
+        
+        assert_eq!(records[0].schema_version, 1);
+        assert_eq!(records[0].entry.trigger_type, TriggerType::LateOpportunity);
+        assert_eq!(records[0].entry.size_sol, 0.5);
+        assert_eq!(records[0].mint_b58, bs58::encode(mint).into_string());
+        
+        assert_eq!(records[1].schema_version, DECISION_LOG_SCHEMA_VERSION);
+        assert_eq!(records[1].entry.side, 1);
+        assert_eq!(records[1].entry.rank, Some(4));
+        assert_eq!(records[1].mint_b58, bs58::encode(mint).into_string());
+        
+        let _ = fs::remove_file(temp_path);
+    }
 }
@@ -6,16 +6,19 @@ pub mod logging;
 pub mod position_tracker;
 pub mod position_sizer;
 pub mod early_scorer;  // 7-signal scoring for 1M+ MC detection
+pub mod slippage;
+pub mod execution_quality;  // Predicted vs realized fill tracking per trigger
 
 // Re-export main types for convenience
 pub use scoring::FollowThroughScorer;
 pub use validation::{TradeValidator, ValidatedTrade, ValidationError};
 pub use triggers::TriggerEngine;
 pub use guardrails::Guardrails;
-pub use logging::{DecisionLogger, DecisionLogEntry, TriggerType};
-pub use position_tracker::{PositionTracker, ActivePosition, ExitReason};
+pub use logging::{DecisionLogger, DecisionLogEntry, DecisionLogReader, DecisionLogRecord, TriggerType};
+pub use position_tracker::{PositionTracker, ActivePosition, ExitReason, ProfitLadder, RugExitConfig};
 pub use position_sizer::{PositionSizer, PositionSizerConfig, SizingStrategy};
-pub use early_scorer::{EarlyScorer, EarlyScore, EarlyScorerConfig};
+pub use early_scorer::{EarlyScorer, EarlyScore, EarlyScorerConfig, EARLY_SCORE_MAX_AGE_SECS};
+pub use execution_quality::ExecutionQualityTracker;
 
 // Type aliases for easier use in main.rs
 pub type Scorer = FollowThroughScorer;
@@ -62,6 +62,20 @@ pub struct PositionSizerConfig {
     pub risk_per_trade_pct: f64,
     
     /// Reduce size when near position limit
+    ///
+    /// Step curve on utilization = active / max positions (open before this entry):
+    ///
+    /// | utilization   | size  |
+    /// |---------------|-------|
+    /// | < 60%         | 100%  |
+    /// | 60% to < 80%  | 75%   |
+    /// | ≥ 80%         | 50%   |
+    ///
+    /// Applied after the strategy size (incl. adaptive boost) and heat cap, before
+    /// the `max_position_pct` cap and the `[min_position_sol, max_position_sol]` clamp,
+    /// so the floor still wins. E.g. with 3 max positions: entries 1 and 2 get
+    /// full size, the 3rd 75%. The sizer never rejects: at the limit it still
+    /// returns 50%, and the entry path rejects first (`PositionTracker::is_full`).
     pub scale_down_near_limit: bool,
     
     /// Enable adaptive scaling (boost after winning streak)
@@ -103,6 +117,9 @@ pub struct PositionSizer {
 impl PositionSizer {
     /// Create new position sizer with config
     pub fn new(config: PositionSizerConfig) -> Self {
+        debug_assert!(config.min_position_sol <= config.max_position_sol,
+                      "min_position_sol ({}) > max_position_sol ({})",
+                      config.min_position_sol, config.max_position_sol);
         info!("💰 Position Sizer initialized:");
         info!("   Strategy: {:?}", config.strategy);
         info!("   Portfolio: {} SOL", config.portfolio_sol);
@@ -129,13 +146,27 @@ impl PositionSizer {
     /// * `total_exposure_sol` - Total SOL currently in active positions
     /// 
     /// # Returns
-    /// Position size in SOL
+    /// Position size in SOL, always within `[min_position_sol, max_position_sol]`
+    /// (the floor wins over the `max_position_pct` cap and scale-downs)
     pub fn calculate_size(
         &self,
         confidence: u8,
         active_positions: usize,
         max_positions: usize,
         total_exposure_sol: f64,
+    ) -> f64 {
+        self.calculate_size_scaled(confidence, active_positions, max_positions, total_exposure_sol, 1.0)
+    }
+    
+    /// `calculate_size` with an extra `size_multiplier` (e.g. the execution-quality
+    /// fill multiplier) applied before the final `[min, max]` clamp
+    pub fn calculate_size_scaled(
+        &self,
+        confidence: u8,
+        active_positions: usize,
+        max_positions: usize,
+        total_exposure_sol: f64,
+        size_multiplier: f64,
     ) -> f64 {
         // 1. Calculate base size from strategy
         let base_size = self.calculate_base_size(confidence);
@@ -158,7 +189,7 @@ impl PositionSizer {
         let remaining_capacity = self.config.portfolio_sol - total_exposure_sol;
         let heat_adjusted = adaptive_scaled.min(remaining_capacity * 0.8); // Leave 20% buffer
         
-        // 4. Apply position limit scaling
+        // 4. Apply position limit scaling (curve documented on `scale_down_near_limit`)
         let limit_adjusted = if self.config.scale_down_near_limit && max_positions > 0 {
             let utilization = active_positions as f64 / max_positions as f64;
             if utilization >= 0.8 {
@@ -174,16 +205,26 @@ impl PositionSizer {
             heat_adjusted
         };
         
-        // 5. Apply absolute limits
-        let final_size = limit_adjusted
-            .max(self.config.min_position_sol)
-            .min(self.config.max_position_sol)
+        // 5. Apply the caller's multiplier (recent fill quality)
+        let multiplied = limit_adjusted * size_multiplier;
+        
+        // 6. Apply absolute limits, clamping to [min, max] last so strategy bounds
+        //    (e.g. ConfidenceScaled min/max) and multipliers can't push the size outside them
+        let pct_capped = multiplied
             .min(self.config.portfolio_sol * self.config.max_position_pct / 100.0);
+        let final_size = pct_capped
+            .max(self.config.min_position_sol)
+            .min(self.config.max_position_sol);
+        debug_assert!(
+            final_size >= self.config.min_position_sol && final_size <= self.config.max_position_sol,
+            "position size {} outside [{}, {}]",
+            final_size, self.config.min_position_sol, self.config.max_position_sol
+        );
         
         debug!("Position sizing: conf={}, active={}/{}, exposure={:.2} SOL",
                confidence, active_positions, max_positions, total_exposure_sol);
-        debug!("  base={:.3}, adaptive={:.3}, heat_adj={:.3}, limit_adj={:.3}, final={:.3} SOL",
-               base_size, adaptive_scaled, heat_adjusted, limit_adjusted, final_size);
+        debug!("  base={:.3}, adaptive={:.3}, heat_adj={:.3}, limit_adj={:.3}, x{:.2}, final={:.3} SOL",
+               base_size, adaptive_scaled, heat_adjusted, limit_adjusted, size_multiplier, final_size);
         
         final_size
     }
@@ -419,6 +460,59 @@ mod tests {
         assert_eq!(size_high, 0.1);
     }
     
+    #[test]
+    fn test_scale_down_curve_with_confidence_scaled() {
+        let config = PositionSizerConfig {
+            strategy: SizingStrategy::ConfidenceScaled {
+                min_size_sol: 0.05,
+                max_size_sol: 0.2,
+            },
+            scale_down_near_limit: true,
+            enable_adaptive_scaling: false,
+            ..Default::default()
+        };
+        let sizer = PositionSizer::new(config.clone());
+        let size = |confidence, active| sizer.calculate_size(confidence, active, 5, 0.0);
+        
+        // No open positions: full confidence-scaled size
+        assert_eq!(size(100, 0), 0.2);
+        assert!((size(50, 0) - 0.125).abs() < 1e-9);
+        
+        // 40% full: still full size
+        assert_eq!(size(100, 2), 0.2);
+        
+        // 60% full (boundary): 75%
+        assert!((size(100, 3) - 0.15).abs() < 1e-9);
+        
+        // 80% full (boundary): 50%, scaling the confidence-scaled size...
+        assert!((size(100, 4) - 0.1).abs() < 1e-9);
+        assert!((size(50, 4) - 0.0625).abs() < 1e-9);
+        // ...down to the min_position_sol floor at most
+        assert_eq!(size(0, 4), 0.05);
+        
+        // Disabled: utilization doesn't matter
+        let flat = PositionSizer::new(PositionSizerConfig { scale_down_near_limit: false, ..config });
+        assert_eq!(flat.calculate_size(100, 4, 5, 0.0), 0.2);
+        
+        // No limit configured: no scaling (and no divide by zero)
+        assert_eq!(sizer.calculate_size(100, 4, 0, 0.0), 0.2);
+    }
+    
+    #[test]
+    fn test_at_limit_sized_but_rejected_upstream() {
+        let config = PositionSizerConfig {
+            strategy: SizingStrategy::Fixed { size_sol: 0.2 },
+            enable_adaptive_scaling: false,
+            ..Default::default()
+        };
+        let sizer = PositionSizer::new(config);
+        
+        // At (or over) the limit the sizer still answers with the 50% tier -
+        // the entry paths reject before sizing (PositionTracker::is_full)
+        assert_eq!(sizer.calculate_size(80, 3, 3, 0.0), 0.1);
+        assert_eq!(sizer.calculate_size(80, 4, 3, 0.0), 0.1);
+    }
+    
     #[test]
     fn test_absolute_limits() {
         let config = PositionSizerConfig {
@@ -440,6 +534,75 @@ mod tests {
         assert!(size <= config.portfolio_sol * config.max_position_pct / 100.0);
     }
     
+    #[test]
+    fn test_final_clamp_to_min_max() {
+        // Strategy bounds wider than the outer limits on both sides
+        let config = PositionSizerConfig {
+            strategy: SizingStrategy::ConfidenceScaled {
+                min_size_sol: 0.01,
+                max_size_sol: 0.8,
+            },
+            min_position_sol: 0.05,
+            max_position_sol: 0.5,
+            portfolio_sol: 10.0,
+            max_position_pct: 10.0,
+            ..Default::default()
+        };
+        let sizer = PositionSizer::new(config.clone());
+        
+        assert_eq!(sizer.calculate_size(0, 0, 10, 0.0), 0.05);   // 0.01 → floor
+        assert!((sizer.calculate_size(50, 0, 10, 0.0) - 0.405).abs() < 1e-9);
+        assert_eq!(sizer.calculate_size(100, 0, 10, 0.0), 0.5);  // 0.8 → ceiling
+        
+        // Win-streak boost can't lift it past the ceiling either
+        for _ in 0..3 {
+            sizer.record_outcome(TradeResult::Win);
+        }
+        assert_eq!(sizer.calculate_size(100, 0, 10, 0.0), 0.5);
+        
+        // A max_position_pct cap below the floor still yields the floor
+        let tiny_portfolio = PositionSizer::new(PositionSizerConfig { portfolio_sol: 0.4, ..config });
+        assert_eq!(tiny_portfolio.calculate_size(100, 0, 10, 0.0), 0.05);
+    }
+    
+    #[test]
+    fn test_fill_multiplier_never_goes_below_min() {
+        let config = PositionSizerConfig {
+            strategy: SizingStrategy::Fixed { size_sol: 0.08 },
+            min_position_sol: 0.05,
+            enable_adaptive_scaling: false,
+            ..Default::default()
+        };
+        let sizer = PositionSizer::new(config);
+        
+        // x1.0 is plain calculate_size; x0.8 shrinks inside the bounds
+        assert_eq!(sizer.calculate_size_scaled(80, 0, 10, 0.0, 1.0), sizer.calculate_size(80, 0, 10, 0.0));
+        assert!((sizer.calculate_size_scaled(80, 0, 10, 0.0, 0.8) - 0.064).abs() < 1e-9);
+        
+        // Worst fill multiplier (0.5) on top of the 80%-full scale-down: held at the floor
+        for active in [0, 7, 8, 10] {
+            assert!(sizer.calculate_size_scaled(80, active, 10, 0.0, 0.5) >= 0.05, "active {}", active);
+        }
+        assert_eq!(sizer.calculate_size_scaled(80, 8, 10, 0.0, 0.5), 0.05);
+    }
+    
+    #[test]
+    fn test_scale_down_boundary_respects_floor() {
+        let config = PositionSizerConfig {
+            strategy: SizingStrategy::Fixed { size_sol: 0.08 },
+            min_position_sol: 0.05,
+            enable_adaptive_scaling: false,
+            ..Default::default()
+        };
+        let sizer = PositionSizer::new(config);
+        
+        // 7/10 (< 80%): 25% off → 0.06
+        assert!((sizer.calculate_size(80, 7, 10, 0.0) - 0.06).abs() < 1e-9);
+        // 8/10 (exactly 80%): 50% off → 0.04, held at the 0.05 floor
+        assert_eq!(sizer.calculate_size(80, 8, 10, 0.0), 0.05);
+        assert_eq!(sizer.calculate_size(80, 10, 10, 0.0), 0.05);
+    }
+    
     #[test]
     fn test_portfolio_heat_check() {
         let config = PositionSizerConfig {
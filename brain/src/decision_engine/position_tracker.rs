@@ -4,10 +4,80 @@
 //! when profit targets hit, stop losses trigger, or time decay occurs.
 
 use std::collections::HashMap;
-use std::time::{Instant, SystemTime, UNIX_EPOCH};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use log::{info, warn, debug};
 use crate::feature_cache::MintFeatures;
 use crate::decision_engine::triggers::EntryTrigger;
+use crate::udp_bus::messages::TradeDecision;
+use crate::udp_bus::ExitAdvice;
+
+/// Remaining token balance treated as fully exited
+const DUST_TOKENS: f64 = 1e-6;
+
+/// Activity that keeps a position past `max_hold_secs` (5s volume and 2s buyers)
+const STRONG_VOL_5S_SOL: f64 = 2.0;
+const STRONG_BUYERS_2S: u32 = 3;
+/// Hold multiplier while activity stays strong
+const HOLD_EXTEND_MULT: u64 = 2;
+/// 5s volume pace below this fraction of the 60s average = activity collapsed, hold halved
+const COLLAPSED_PACE_RATIO: f64 = 0.25;
+/// Peak 5s volume below this is noise, too thin to judge a drop against
+const MIN_PEAK_VOL_5S_SOL: f64 = 1.0;
+/// Emergency exits re-fire after this long without a SELL confirmation
+/// (instead of waiting out the normal in-flight timeout)
+const EMERGENCY_RESEND: Duration = Duration::from_secs(2);
+/// Fully exited positions stay available to `exit_basis` this long (SELL confirmations arrive after the exit is booked)
+const EXITED_RETENTION: Duration = Duration::from_secs(300);
+
+/// Rug indicators that trigger an immediate full (Emergency) exit
+#[derive(Debug, Clone)]
+pub struct RugExitConfig {
+    /// Curve virtual SOL reserves this % below their peak since entry (0 = off)
+    pub reserve_drop_pct: f64,
+    /// Exit as soon as the token creator sells
+    pub exit_on_creator_sell: bool,
+    /// One wallet selling at least this share of the last 60s traded SOL (0 = off)
+    pub top_seller_share: f64,
+}
+
+impl Default for RugExitConfig {
+    fn default() -> Self {
+        Self {
+            reserve_drop_pct: 30.0,
+            exit_on_creator_sell: true,
+            top_seller_share: 0.5,
+        }
+    }
+}
+
+/// One profit-taking step: sell `exit_pct`% of the remaining position at +`gain_pct`%
+///
+/// A position's ladders are sorted by ascending gain and fire one at a time in
+/// order; the last ladder must sell 100% to close out the position.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProfitLadder {
+    /// Gain over entry price that fires this ladder (%)
+    pub gain_pct: f64,
+    /// Percent of the remaining position sold (1-100)
+    pub exit_pct: u8,
+}
+
+impl ProfitLadder {
+    pub fn new(gain_pct: f64, exit_pct: u8) -> Self {
+        Self { gain_pct, exit_pct }
+    }
+    
+    /// Default ladders: 30% at +30%, 60% of the rest at +60%, everything at +100%
+    pub fn defaults() -> Vec<Self> {
+        vec![Self::new(30.0, 30), Self::new(60.0, 60), Self::new(100.0, 100)]
+    }
+}
+
+impl std::fmt::Display for ProfitLadder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}% at +{:.1}%", self.exit_pct, self.gain_pct)
+    }
+}
 
 /// Position state in 3-state confirmation system
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -72,7 +142,7 @@ pub struct ActivePosition {
     /// Entry price (SOL per token, estimated)
     pub entry_price_sol: f64,
     
-    /// Tokens received
+    /// Tokens still held (reduced by each partial exit)
     pub tokens: f64,
     
     /// Confidence score at entry (0-100)
@@ -84,15 +154,27 @@ pub struct ActivePosition {
     /// Early score from 7-signal system (0.0-15.0)
     pub early_score: f64,
     
-    /// Profit targets (tier1, tier2, tier3) in % gain
-    pub profit_targets: (f64, f64, f64),
+    /// Profit-taking ladders, ascending by gain
+    pub profit_ladders: Vec<ProfitLadder>,
+    
+    /// Ladders already sold into (the next to fire is `profit_ladders[ladders_hit]`)
+    pub ladders_hit: usize,
     
     /// Stop loss threshold in % loss
     pub stop_loss_pct: f64,
     
+    /// Trailing stop: exit once price falls this % below its peak since entry (None = off)
+    pub trailing_stop_pct: Option<f64>,
+    
+    /// Highest price (SOL per token) seen since entry
+    pub peak_price_sol: f64,
+    
     /// Maximum hold time in seconds (path-specific)
     pub max_hold_secs: u64,
     
+    /// max_hold_secs was cut short (curve complete, early exit): never extended
+    pub hold_capped: bool,
+    
     /// Source that triggered entry
     pub trigger_source: String,
     
@@ -102,11 +184,20 @@ pub struct ActivePosition {
     /// MC at entry (for velocity tracking)
     pub entry_mc_sol: f64,
     
+    /// Highest 5s volume (SOL) since entry or the last partial exit
+    pub peak_vol_5s_sol: f64,
+    
+    /// Highest bonding curve virtual SOL reserves (SOL) since entry
+    pub peak_curve_sol_reserves: f64,
+    
     /// MC 10s ago (for velocity-based exit)
     pub mc_10s_ago: Option<f64>,
     
     /// MC 20s ago (for velocity-based exit)
     pub mc_20s_ago: Option<f64>,
+
+    /// Executor confirmed this entry as a dry-run (simulated, never submitted)
+    pub dry_run: bool,
 }
 
 impl ActivePosition {
@@ -142,10 +233,75 @@ impl ActivePosition {
             EntryTrigger::LateOpportunity => {
                 // Late: Not used for 1M+ MC hunting
                 5.0
+            },
+            EntryTrigger::Hotlist => {
+                // Hotlist: $3-5, already filtered by the 7-signal scorer
+                if self.early_score >= 8.0 {
+                    5.0
+                } else {
+                    3.0
+                }
             }
         }
     }
     
+    /// Raise the rolling 5s volume, curve reserve and price peaks
+    pub fn observe(&mut self, features: &MintFeatures) {
+        self.peak_vol_5s_sol = self.peak_vol_5s_sol.max(features.vol_5s_sol);
+        self.peak_curve_sol_reserves = self.peak_curve_sol_reserves.max(features.curve_sol_reserves);
+        self.peak_price_sol = self.peak_price_sol.max(features.current_price);
+    }
+    
+    /// Trailing stop price: `trailing_stop_pct` below the peak (never below entry's trail)
+    pub fn trailing_stop_price(&self) -> Option<f64> {
+        self.trailing_stop_pct
+            .map(|pct| self.peak_price_sol.max(self.entry_price_sol) * (1.0 - pct / 100.0))
+    }
+    
+    /// Full exit on a rug indicator: liquidity pulled from the curve, creator
+    /// selling, or one wallet dumping most of the recent volume
+    pub fn rug_exit(&self, features: &MintFeatures, config: &RugExitConfig) -> Option<ExitReason> {
+        let reserves = features.curve_sol_reserves;
+        let reason = if config.reserve_drop_pct > 0.0
+            && reserves > 0.0
+            && reserves <= self.peak_curve_sol_reserves * (1.0 - config.reserve_drop_pct / 100.0)
+        {
+            format!("liquidity pulled: curve {:.2} → {:.2} SOL", self.peak_curve_sol_reserves, reserves)
+        } else if config.exit_on_creator_sell && features.creator_sold_60s {
+            "creator sold".to_string()
+        } else if config.top_seller_share > 0.0 && features.top_seller_share_60s >= config.top_seller_share {
+            format!("top holder dumping: {:.0}% of 60s volume", features.top_seller_share_60s * 100.0)
+        } else {
+            return None;
+        };
+        
+        warn!("🚨 EXIT TRIGGER: rug indicator on {} ({})", &self.mint[..8], reason);
+        Some(ExitReason::Emergency {
+            reason,
+            exit_percent: 100,
+        })
+    }
+    
+    /// Partial exit once 5s volume falls below `drop_ratio` of the rolling peak
+    /// (momentum dying before the stop-loss sees it)
+    pub fn volume_drop_exit(&self, features: &MintFeatures, drop_ratio: f64, exit_percent: u8) -> Option<ExitReason> {
+        if exit_percent == 0
+            || self.peak_vol_5s_sol < MIN_PEAK_VOL_5S_SOL
+            || features.vol_5s_sol >= self.peak_vol_5s_sol * drop_ratio
+        {
+            return None;
+        }
+        
+        info!("📉 EXIT TRIGGER: 5s volume {:.2} SOL < {:.0}% of peak {:.2} SOL",
+              features.vol_5s_sol, drop_ratio * 100.0, self.peak_vol_5s_sol);
+        let price_change_pct = ((features.current_price - self.entry_price_sol) / self.entry_price_sol.max(0.0001)) * 100.0;
+        Some(ExitReason::VolumeDrop {
+            volume_5s: features.vol_5s_sol,
+            pnl_pct: price_change_pct,
+            exit_percent,
+        })
+    }
+    
     /// Update MC velocity tracking (call every 10s)
     pub fn update_mc_velocity(&mut self, current_mc_sol: f64) {
         // Shift history
@@ -180,6 +336,25 @@ impl ActivePosition {
             EntryTrigger::Momentum => -15.0,       // Standard stop
             EntryTrigger::CopyTrade => -10.0,      // Tight stop for quick scalps
             EntryTrigger::LateOpportunity => -15.0,
+            EntryTrigger::Hotlist => -20.0,        // Fresh launches, wide stop like Rank
+        }
+    }
+    
+    /// Hold limit for the current activity: `max_hold_secs` doubled while 5s
+    /// volume and 2s buyers stay strong, halved once the 5s volume pace
+    /// collapses below a quarter of the 60s average
+    pub fn dynamic_max_hold_secs(&self, features: &MintFeatures) -> u64 {
+        let strong = features.vol_5s_sol >= STRONG_VOL_5S_SOL && features.buyers_2s >= STRONG_BUYERS_2S;
+        // 12 five-second windows per minute
+        let collapsed = features.vol_60s_sol > 0.0
+            && features.vol_5s_sol * 12.0 < features.vol_60s_sol * COLLAPSED_PACE_RATIO;
+        
+        if strong && !self.hold_capped {
+            self.max_hold_secs.saturating_mul(HOLD_EXTEND_MULT)
+        } else if collapsed {
+            (self.max_hold_secs / 2).max(1)
+        } else {
+            self.max_hold_secs
         }
     }
     
@@ -228,29 +403,16 @@ impl ActivePosition {
             }
         }
         
-        // Check percentage-based profit targets (backup, for high-percentage gains)
-        if price_change_pct >= self.profit_targets.2 {
-            return Some(ExitReason::ProfitTarget {
-                tier: 3,
-                pnl_pct: price_change_pct,
-                exit_percent: 100, // Exit all
-            });
-        }
-        
-        if price_change_pct >= self.profit_targets.1 {
-            return Some(ExitReason::ProfitTarget {
-                tier: 2,
-                pnl_pct: price_change_pct,
-                exit_percent: 60, // Exit 60%
-            });
-        }
-        
-        if price_change_pct >= self.profit_targets.0 {
-            return Some(ExitReason::ProfitTarget {
-                tier: 1,
-                pnl_pct: price_change_pct,
-                exit_percent: 30, // Exit 30%
-            });
+        // Check percentage-based profit ladders (backup, for high-percentage gains)
+        // Ladders fire in order, one per check; a gap-up sells into the rest on the next cycles
+        if let Some(ladder) = self.next_ladder() {
+            if price_change_pct >= ladder.gain_pct {
+                return Some(ExitReason::ProfitTarget {
+                    tier: (self.ladders_hit + 1) as u8,
+                    pnl_pct: price_change_pct,
+                    exit_percent: ladder.exit_pct,
+                });
+            }
         }
         
         // ✅ PATH-SPECIFIC STOP LOSS
@@ -263,6 +425,19 @@ impl ActivePosition {
             });
         }
         
+        // Trailing stop: ratchets up with each new high, locks in a runner's gains
+        if let Some(stop_price) = self.trailing_stop_price() {
+            if current_price_sol <= stop_price {
+                info!("❌ EXIT TRIGGER: Trailing stop hit ({:.10} ≤ {:.10} SOL, peak {:.10})",
+                      current_price_sol, stop_price, self.peak_price_sol);
+                return Some(ExitReason::TrailingStop {
+                    peak_price_sol: self.peak_price_sol,
+                    pnl_pct: price_change_pct,
+                    exit_percent: 100,
+                });
+            }
+        }
+        
         // ✅ PRIORITY EXIT: No mempool activity (no buying pressure)
         // User requirement: "Bot will only stay if mempool shows volume"
         if current_features.mempool_pending_buys == 0 && elapsed > 15 {
@@ -277,10 +452,12 @@ impl ActivePosition {
                   current_features.mempool_pending_buys);
         }
         
-        // Check time decay (safety backstop only)
-        if elapsed >= self.max_hold_secs {
+        // Check time decay (safety backstop, stretched or cut by current activity)
+        let max_hold_secs = self.dynamic_max_hold_secs(current_features);
+        if elapsed >= max_hold_secs {
             return Some(ExitReason::TimeDecay {
                 elapsed_secs: elapsed,
+                max_hold_secs,
                 pnl_pct: price_change_pct,
                 exit_percent: 100, // Exit all
             });
@@ -310,6 +487,57 @@ impl ActivePosition {
     pub fn unrealized_pnl_usd(&self, current_price_sol: f64, sol_price_usd: f64) -> f64 {
         self.current_value_usd(current_price_sol, sol_price_usd) - self.size_usd
     }
+    
+    /// Next profit ladder to fire (None once all have sold)
+    pub fn next_ladder(&self) -> Option<&ProfitLadder> {
+        self.profit_ladders.get(self.ladders_hit)
+    }
+    
+    /// True if profit ladder `tier` (1-based) hasn't fired yet
+    pub fn target_pending(&self, tier: u8) -> bool {
+        tier as usize > self.ladders_hit
+    }
+    
+    /// Replace the estimated entry with the Executor's bonding curve quote
+    ///
+    /// Only applies before any partial exit; returns false if ignored.
+    pub fn apply_entry_quote(&mut self, tokens: f64, effective_price_sol: f64) -> bool {
+        if self.ladders_hit > 0 || tokens <= 0.0 || effective_price_sol <= 0.0 {
+            return false;
+        }
+        self.tokens = tokens;
+        self.entry_price_sol = effective_price_sol;
+        true
+    }
+    
+    /// Apply a SELL of `exit_percent`% of the remaining position
+    ///
+    /// Marks ladders up to `tier` as hit (profit-target exits) and scales tokens
+    /// and cost basis down to what's left. Returns the remaining tokens (0 once fully exited).
+    pub fn apply_exit(&mut self, tier: Option<u8>, exit_percent: u8) -> f64 {
+        if let Some(tier) = tier {
+            self.ladders_hit = self.ladders_hit.max((tier as usize).min(self.profit_ladders.len()));
+        }
+        
+        let keep = 1.0 - (exit_percent.min(100) as f64 / 100.0);
+        self.tokens *= keep;
+        self.size_sol *= keep;
+        self.size_usd *= keep;
+        // Judge the rest of the position against volume from here on
+        self.peak_vol_5s_sol = 0.0;
+        
+        if self.tokens <= DUST_TOKENS {
+            self.tokens = 0.0;
+        }
+        self.tokens
+    }
+    
+    /// Minimum SOL out (lamports) for selling `exit_percent`% of our tokens at
+    /// `current_price_sol`, allowing `slippage_bps` below market (0 = no floor)
+    pub fn min_sol_out_lamports(&self, exit_percent: u8, current_price_sol: f64, slippage_bps: u16) -> u64 {
+        let tokens = self.tokens * (exit_percent.min(100) as f64 / 100.0);
+        TradeDecision::min_sol_out_for(tokens, current_price_sol, slippage_bps)
+    }
 }
 
 /// Reason for exiting a position
@@ -317,7 +545,7 @@ impl ActivePosition {
 pub enum ExitReason {
     /// Profit target hit
     ProfitTarget {
-        tier: u8,            // 1, 2, or 3
+        tier: u8,            // 1-based profit ladder
         pnl_pct: f64,        // Percentage gain
         exit_percent: u8,    // What % of position to exit
     },
@@ -328,9 +556,17 @@ pub enum ExitReason {
         exit_percent: u8,
     },
     
-    /// Max hold time exceeded
+    /// Price fell `trailing_stop_pct` below its peak since entry
+    TrailingStop {
+        peak_price_sol: f64,
+        pnl_pct: f64,
+        exit_percent: u8,
+    },
+    
+    /// Max hold time exceeded (`max_hold_secs` = activity-adjusted limit that fired)
     TimeDecay {
         elapsed_secs: u64,
+        max_hold_secs: u64,
         pnl_pct: f64,
         exit_percent: u8,
     },
@@ -357,6 +593,27 @@ pub enum ExitReason {
 }
 
 impl ExitReason {
+    /// Percent of the remaining position this exit sells
+    pub fn exit_percent(&self) -> u8 {
+        match self {
+            ExitReason::ProfitTarget { exit_percent, .. }
+            | ExitReason::StopLoss { exit_percent, .. }
+            | ExitReason::TrailingStop { exit_percent, .. }
+            | ExitReason::TimeDecay { exit_percent, .. }
+            | ExitReason::VolumeDrop { exit_percent, .. }
+            | ExitReason::NoMempoolActivity { exit_percent, .. }
+            | ExitReason::Emergency { exit_percent, .. } => *exit_percent,
+        }
+    }
+    
+    /// Profit target tier for ProfitTarget exits
+    pub fn profit_tier(&self) -> Option<u8> {
+        match self {
+            ExitReason::ProfitTarget { tier, .. } => Some(*tier),
+            _ => None,
+        }
+    }
+    
     pub fn to_string(&self) -> String {
         match self {
             ExitReason::ProfitTarget { tier, pnl_pct, exit_percent } => {
@@ -365,8 +622,11 @@ impl ExitReason {
             ExitReason::StopLoss { pnl_pct, .. } => {
                 format!("STOP_LOSS ({:+.1}%)", pnl_pct)
             }
-            ExitReason::TimeDecay { elapsed_secs, pnl_pct, .. } => {
-                format!("TIME_DECAY ({}s, {:+.1}%)", elapsed_secs, pnl_pct)
+            ExitReason::TrailingStop { peak_price_sol, pnl_pct, .. } => {
+                format!("TRAILING_STOP ({:+.1}%, peak {:.10})", pnl_pct, peak_price_sol)
+            }
+            ExitReason::TimeDecay { elapsed_secs, max_hold_secs, pnl_pct, .. } => {
+                format!("TIME_DECAY ({}s/{}s, {:+.1}%)", elapsed_secs, max_hold_secs, pnl_pct)
             }
             ExitReason::VolumeDrop { volume_5s, pnl_pct, .. } => {
                 format!("VOL_DROP ({:.2}SOL/5s, {:+.1}%)", volume_5s, pnl_pct)
@@ -387,7 +647,25 @@ pub struct PositionTracker {
     positions: HashMap<String, ActivePosition>,
     /// Provisional positions awaiting confirmation
     provisional_positions: HashMap<String, ProvisionalPosition>,
+    /// SELL decisions sent but not yet reflected on-chain (mint → sent at)
+    sells_in_flight: HashMap<String, Instant>,
+    /// Fully exited positions awaiting their SELL confirmation (mint → position, exited at)
+    exited: HashMap<String, (ActivePosition, Instant)>,
     max_positions: usize,
+    /// Slippage below market used for SELL min SOL out (basis points)
+    exit_slippage_bps: u16,
+    /// How long a sent SELL blocks further exit signals without a confirmation
+    exit_in_flight_timeout: Duration,
+    /// Volume-drop exit: 5s volume below this fraction of its peak
+    volume_drop_ratio: f64,
+    /// Volume-drop exit: percent of the position sold
+    volume_drop_exit_percent: u8,
+    /// Emergency exit rug indicators
+    rug_exit: RugExitConfig,
+    /// Profit ladders given to positions confirmed from provisional
+    profit_ladders: Vec<ProfitLadder>,
+    /// Trailing stop given to positions confirmed from provisional
+    trailing_stop_pct: Option<f64>,
 }
 
 impl PositionTracker {
@@ -395,10 +673,61 @@ impl PositionTracker {
         Self {
             positions: HashMap::new(),
             provisional_positions: HashMap::new(),
+            sells_in_flight: HashMap::new(),
+            exited: HashMap::new(),
             max_positions,
+            exit_slippage_bps: 300,
+            exit_in_flight_timeout: Duration::from_secs(10),
+            volume_drop_ratio: 0.3,
+            volume_drop_exit_percent: 50,
+            rug_exit: RugExitConfig::default(),
+            profit_ladders: ProfitLadder::defaults(),
+            trailing_stop_pct: None,
         }
     }
     
+    /// Override the exit slippage (default 300bps) used for SELL decisions
+    pub fn with_exit_slippage_bps(mut self, exit_slippage_bps: u16) -> Self {
+        self.exit_slippage_bps = exit_slippage_bps;
+        self
+    }
+    
+    /// Override how long an unconfirmed SELL suppresses new exits (default 10s)
+    pub fn with_exit_in_flight_timeout(mut self, timeout: Duration) -> Self {
+        self.exit_in_flight_timeout = timeout;
+        self
+    }
+    
+    /// Override the volume-drop exit (default: sell 50% below 30% of peak 5s volume)
+    pub fn with_volume_drop(mut self, drop_ratio: f64, exit_percent: u8) -> Self {
+        self.volume_drop_ratio = drop_ratio;
+        self.volume_drop_exit_percent = exit_percent;
+        self
+    }
+    
+    /// Override the emergency exit rug indicators
+    pub fn with_rug_exit(mut self, rug_exit: RugExitConfig) -> Self {
+        self.rug_exit = rug_exit;
+        self
+    }
+    
+    /// Override the profit ladders (default `ProfitLadder::defaults`)
+    pub fn with_profit_ladders(mut self, profit_ladders: Vec<ProfitLadder>) -> Self {
+        self.profit_ladders = profit_ladders;
+        self
+    }
+    
+    /// Trail confirmed positions' stop `pct`% below their peak (default off)
+    pub fn with_trailing_stop_pct(mut self, trailing_stop_pct: Option<f64>) -> Self {
+        self.trailing_stop_pct = trailing_stop_pct;
+        self
+    }
+    
+    /// Exit slippage (basis points) applied to SELL decisions
+    pub fn exit_slippage_bps(&self) -> u16 {
+        self.exit_slippage_bps
+    }
+    
     /// Add a provisional position (SUBMITTED state)
     pub fn add_provisional(&mut self, mint: String, signature: String, expected_tokens: u64, 
                           expected_sol_lamports: u64, expected_slip_bps: u16, side: u8, 
@@ -452,17 +781,24 @@ impl PositionTracker {
                 entry_price_sol,
                 tokens: actual_tokens,
                 entry_confidence,
-                profit_targets: (15.0, 30.0, 50.0), // Default targets
+                profit_ladders: self.profit_ladders.clone(),
+                ladders_hit: 0,
                 stop_loss_pct: 15.0,
+                trailing_stop_pct: self.trailing_stop_pct,
+                peak_price_sol: entry_price_sol,
                 max_hold_secs: 120,
+                hold_capped: false,
                 trigger_source,
                 sell_retry_count: 0,
                 // New fields for 1M+ MC hunting
                 entry_path: EntryTrigger::RankBased, // Default
                 early_score: 0.0,  // Will be populated from provisional
                 entry_mc_sol: 0.0, // TODO: Get from features
+                peak_vol_5s_sol: 0.0,
+                peak_curve_sol_reserves: 0.0,
                 mc_10s_ago: None,
                 mc_20s_ago: None,
+                dry_run: false,
             };
             
             self.add_position(position)?;
@@ -529,9 +865,63 @@ impl PositionTracker {
     
     /// Remove a position (after exit)
     pub fn remove_position(&mut self, mint: &str) -> Option<ActivePosition> {
+        self.sells_in_flight.remove(mint);
         self.positions.remove(mint)
     }
     
+    /// Record that a SELL decision was sent for this mint
+    /// (reconciliation won't treat a missing balance as drift while it's in flight)
+    pub fn mark_sell_sent(&mut self, mint: &str) {
+        if self.positions.contains_key(mint) {
+            self.sells_in_flight.insert(mint.to_string(), Instant::now());
+        }
+    }
+    
+    /// Record a SELL of `exit_percent`% of the remaining position
+    ///
+    /// Decrements remaining tokens and marks the profit `tier` as hit so the
+    /// monitor doesn't re-emit the same partial exit. The position is removed
+    /// once tokens reach ~0; returns true in that case.
+    pub fn record_exit(&mut self, mint: &str, tier: Option<u8>, exit_percent: u8) -> bool {
+        let remaining = match self.positions.get_mut(mint) {
+            Some(pos) => pos.apply_exit(tier, exit_percent),
+            None => return false,
+        };
+        
+        if remaining > 0.0 {
+            info!("📉 Partial exit: {} sold {}% → {:.2} tokens remaining",
+                  &mint[..8], exit_percent, remaining);
+            return false;
+        }
+        
+        info!("📊 Closing position: {} (fully exited)", &mint[..8]);
+        self.exited.retain(|_, (_, exited_at)| exited_at.elapsed() < EXITED_RETENTION);
+        if let Some(position) = self.remove_position(mint) {
+            self.exited.insert(mint.to_string(), (position, Instant::now()));
+        }
+        true
+    }
+    
+    /// Entry side of a SELL: the open position, or one fully exited within
+    /// the last few minutes (its final SELL confirmation may still be coming)
+    pub fn exit_basis(&self, mint: &str) -> Option<&ActivePosition> {
+        self.positions.get(mint)
+            .or_else(|| self.exited.get(mint).map(|(position, _)| position))
+    }
+    
+    /// Clear the in-flight SELL for this mint (executor confirmed or failed it)
+    pub fn clear_sell_in_flight(&mut self, mint: &str) -> bool {
+        self.sells_in_flight.remove(mint).is_some()
+    }
+    
+    /// Check if a SELL for this mint was sent within `window`
+    pub fn is_sell_in_flight(&self, mint: &str, window: Duration) -> bool {
+        self.sells_in_flight
+            .get(mint)
+            .map(|sent_at| sent_at.elapsed() < window)
+            .unwrap_or(false)
+    }
+    
     /// Get position count
     pub fn count(&self) -> usize {
         self.positions.len()
@@ -547,6 +937,66 @@ impl PositionTracker {
         self.positions.values().collect()
     }
     
+    /// Raise a position's rolling volume / curve reserve peaks (call each monitor tick)
+    pub fn update_peaks(&mut self, mint: &str, features: &MintFeatures) {
+        if let Some(pos) = self.positions.get_mut(mint) {
+            pos.observe(features);
+        }
+    }
+    
+    /// Get a position by mint (base58)
+    pub fn get_position(&self, mint: &str) -> Option<&ActivePosition> {
+        self.positions.get(mint)
+    }
+    
+    /// Build a SELL decision mirroring a copied wallet's exit
+    ///
+    /// Sized to our full holdings. Returns None when we don't hold the mint,
+    /// so an alpha SELL never opens or grows a position. `current_price_sol`
+    /// (from MintFeatures) sets the min SOL out; None sends no floor.
+    pub fn copy_sell_decision(&self, mint: &[u8; 32], current_price_sol: Option<f64>) -> Option<TradeDecision> {
+        let mint_str = bs58::encode(mint).into_string();
+        let position = self.positions.get(&mint_str)?;
+        let min_sol_out = current_price_sol
+            .map(|price| position.min_sol_out_lamports(100, price, self.exit_slippage_bps))
+            .unwrap_or(0);
+        
+        Some(TradeDecision::new_sell(
+            *mint,
+            (position.size_sol * 1e9) as u64,
+            self.exit_slippage_bps,
+            position.entry_confidence,
+            position.sell_retry_count,
+            2, // entry_type: 2 = CopyTrade
+            min_sol_out,
+        ))
+    }
+    
+    /// Build an immediate SELL for an ExitAdvice from Mempool-watcher
+    ///
+    /// Sells the advised percentage of the matching position (stop-loss = 100%).
+    /// Returns None if we don't hold the mint.
+    pub fn exit_advice_decision(&self, advice: &ExitAdvice, current_price_sol: Option<f64>) -> Option<TradeDecision> {
+        let position = self.positions.get(&advice.mint_str())?;
+        let exit_percent = advice.effective_exit_percent();
+        let exit_size_sol = position.size_sol * (exit_percent as f64 / 100.0);
+        let min_sol_out = current_price_sol
+            .map(|price| position.min_sol_out_lamports(exit_percent, price, self.exit_slippage_bps))
+            .unwrap_or(0);
+        
+        let entry_type = position.entry_path.entry_type();
+        
+        Some(TradeDecision::new_sell(
+            advice.mint,
+            (exit_size_sol * 1e9) as u64,
+            self.exit_slippage_bps,
+            position.entry_confidence,
+            position.sell_retry_count,
+            entry_type,
+            min_sol_out,
+        ))
+    }
+    
     /// Get mutable reference to a position (for updating MC velocity)
     pub fn get_position_mut(&mut self, mint: &str) -> Option<&mut ActivePosition> {
         self.positions.get_mut(mint)
@@ -569,26 +1019,42 @@ impl PositionTracker {
     /// Returns true if position was found and updated
     pub fn adjust_profit_targets(&mut self, mint: &str, multiplier: f64) -> bool {
         if let Some(pos) = self.positions.get_mut(mint) {
-            let old_targets = pos.profit_targets;
-            pos.profit_targets = (
-                old_targets.0 * multiplier,
-                old_targets.1 * multiplier,
-                old_targets.2 * multiplier,
-            );
-            info!("📊 Adjusted profit targets for {} by {:.2}x → ({:.1}%, {:.1}%, {:.1}%)", 
-                  &mint[..8], multiplier, pos.profit_targets.0, pos.profit_targets.1, pos.profit_targets.2);
+            for ladder in &mut pos.profit_ladders {
+                ladder.gain_pct *= multiplier;
+            }
+            let ladders: Vec<String> = pos.profit_ladders.iter().map(|l| l.to_string()).collect();
+            info!("📊 Adjusted profit targets for {} by {:.2}x → [{}]", 
+                  &mint[..8], multiplier, ladders.join(", "));
             true
         } else {
             false
         }
     }
     
+    /// Cap a position's remaining hold to `remaining_secs` from now
+    /// (bonding curve completed: exit ahead of the migration volatility window)
+    /// Returns true if position was found and its hold was shortened
+    pub fn cap_remaining_hold(&mut self, mint: &str, remaining_secs: u64) -> bool {
+        if let Some(pos) = self.positions.get_mut(mint) {
+            let capped = pos.entry_time.elapsed().as_secs().saturating_add(remaining_secs).max(1);
+            if capped < pos.max_hold_secs {
+                pos.max_hold_secs = capped;
+                pos.hold_capped = true;
+                info!("⏱️  Capped hold for {} to {}s from now → {}s total",
+                      &mint[..8], remaining_secs, pos.max_hold_secs);
+                return true;
+            }
+        }
+        false
+    }
+    
     /// Trigger early exit for a position (alpha wallet sell or negative volume spike)
     /// Returns true if position exists (caller should initiate sell)
     pub fn trigger_early_exit(&mut self, mint: &str) -> bool {
         if let Some(pos) = self.positions.get_mut(mint) {
             // Set max_hold_secs to 1 to force immediate exit on next check
             pos.max_hold_secs = 1;
+            pos.hold_capped = true;
             warn!("⚠️  Triggered early exit for {} (max_hold → 1s)", &mint[..8]);
             true
         } else {
@@ -616,11 +1082,29 @@ impl PositionTracker {
             return None;
         }
         
+        // Rug indicators come first and only wait EMERGENCY_RESEND on an in-flight SELL
+        if let Some(pos) = self.positions.get(mint) {
+            if !self.is_sell_in_flight(mint, EMERGENCY_RESEND) {
+                if let Some(reason) = pos.rug_exit(features, &self.rug_exit) {
+                    return Some((reason, pos));
+                }
+            }
+        }
+        
+        // A SELL is already in flight: wait for its confirmation (or timeout)
+        if self.is_sell_in_flight(mint, self.exit_in_flight_timeout) {
+            debug!("⏳ Skipping exit check for {}: SELL in flight", &mint[..8]);
+            return None;
+        }
+        
         // Only check confirmed active positions
         if let Some(pos) = self.positions.get(mint) {
             if let Some(reason) = pos.should_exit(features, sol_price_usd) {
                 return Some((reason, pos));
             }
+            if let Some(reason) = pos.volume_drop_exit(features, self.volume_drop_ratio, self.volume_drop_exit_percent) {
+                return Some((reason, pos));
+            }
         }
         None
     }
@@ -637,22 +1121,8 @@ mod tests {
         assert!(!tracker.is_full());
         
         // Add positions up to limit
-        for i in 0..3 {
-            let pos = ActivePosition {
-                mint: format!("mint_{}", i),
-                entry_time: Instant::now(),
-                entry_timestamp: 0,
-                size_sol: 0.1,
-                size_usd: 20.0,
-                entry_price_sol: 0.001,
-                tokens: 100.0,
-                entry_confidence: 80,
-                profit_targets: (0.30, 0.60, 1.0),
-                stop_loss_pct: 15.0,
-                max_hold_secs: 120,
-                trigger_source: "test".to_string(),
-                sell_retry_count: 0,
-            };
+        for i in 0..3u8 {
+            let pos = test_position(bs58::encode(&[i; 32]).into_string(), 0.1);
             assert!(tracker.add_position(pos).is_ok());
         }
         
@@ -660,21 +1130,407 @@ mod tests {
         assert!(tracker.is_full());
         
         // Try to add beyond limit
-        let pos = ActivePosition {
-            mint: "mint_overflow".to_string(),
+        let pos = test_position(bs58::encode(&[9u8; 32]).into_string(), 0.1);
+        assert!(tracker.add_position(pos).is_err());
+    }
+    
+    fn test_position(mint: String, size_sol: f64) -> ActivePosition {
+        ActivePosition {
+            mint,
             entry_time: Instant::now(),
             entry_timestamp: 0,
-            size_sol: 0.1,
-            size_usd: 20.0,
+            size_sol,
+            size_usd: size_sol * 200.0,
             entry_price_sol: 0.001,
-            tokens: 100.0,
-            entry_confidence: 80,
-            profit_targets: (0.30, 0.60, 1.0),
+            tokens: size_sol / 0.001,
+            entry_confidence: 75,
+            entry_path: EntryTrigger::CopyTrade,
+            early_score: 0.0,
+            profit_ladders: ProfitLadder::defaults(),
+            ladders_hit: 0,
             stop_loss_pct: 15.0,
-            max_hold_secs: 120,
-            trigger_source: "test".to_string(),
+            trailing_stop_pct: None,
+            peak_price_sol: 0.001,
+            max_hold_secs: 300,
+            hold_capped: false,
+            trigger_source: "copy_trade".to_string(),
             sell_retry_count: 0,
-        };
-        assert!(tracker.add_position(pos).is_err());
+            entry_mc_sol: 0.0,
+            peak_vol_5s_sol: 0.0,
+            peak_curve_sol_reserves: 0.0,
+            mc_10s_ago: None,
+            mc_20s_ago: None,
+            dry_run: false,
+        }
+    }
+    
+    #[test]
+    fn test_full_at_max_positions() {
+        // Entry paths check is_full before sizing; add_position is the backstop
+        let mut tracker = PositionTracker::new(2);
+        tracker.add_position(test_position(bs58::encode(&[1u8; 32]).into_string(), 0.1)).unwrap();
+        assert!(!tracker.is_full());
+        tracker.add_position(test_position(bs58::encode(&[2u8; 32]).into_string(), 0.1)).unwrap();
+        assert!(tracker.is_full());
+        assert!(tracker.add_position(test_position(bs58::encode(&[3u8; 32]).into_string(), 0.1)).is_err());
+        assert_eq!(tracker.count(), 2);
+    }
+    
+    #[test]
+    fn test_copy_sell_requires_held_position() {
+        let mut tracker = PositionTracker::new(3);
+        let held = [7u8; 32];
+        let not_held = [9u8; 32];
+        tracker.add_position(test_position(bs58::encode(&held).into_string(), 0.25)).unwrap();
+        
+        let decision = tracker.copy_sell_decision(&held, None).expect("held mint should produce SELL");
+        assert_eq!(decision.side, 1);
+        assert_eq!(decision.mint, held);
+        assert_eq!(decision.size_lamports, 250_000_000);
+        assert_eq!(decision.entry_type, 2);
+        assert_eq!(decision.min_sol_out_lamports, 0, "no price known -> no floor");
+        
+        // Alpha SELL on a mint we don't hold never produces a decision
+        assert!(tracker.copy_sell_decision(&not_held, Some(0.001)).is_none());
+    }
+    
+    #[test]
+    fn test_exit_advice_sells_advised_percentage() {
+        let mut tracker = PositionTracker::new(3);
+        let held = [5u8; 32];
+        tracker.add_position(test_position(bs58::encode(&held).into_string(), 0.4)).unwrap();
+        
+        // Profit target hit: sell the advised 50%
+        let target = ExitAdvice::new(
+            [0u8; 16], held, ExitAdvice::REASON_TARGET_HIT, 90, 10.0, 1_000, 1_300, 20_000, 50,
+        );
+        let decision = tracker.exit_advice_decision(&target, Some(0.002)).expect("held mint should produce SELL");
+        assert_eq!(decision.side, 1);
+        assert_eq!(decision.mint, held);
+        assert_eq!(decision.size_lamports, 200_000_000);
+        assert_eq!(decision.entry_type, 2);
+        // 200 tokens at 0.002 SOL less 3% exit slippage
+        assert_eq!(decision.slippage_bps, 300);
+        assert_eq!(decision.min_sol_out_lamports, 388_000_000);
+        
+        // Stop-loss hit: always close the full position
+        let stop = ExitAdvice::new(
+            [0u8; 16], held, ExitAdvice::REASON_STOP_LOSS, 100, -6.0, 1_000, 850, 20_000, 50,
+        );
+        let decision = tracker.exit_advice_decision(&stop, None).unwrap();
+        assert_eq!(decision.size_lamports, 400_000_000);
+        
+        // Advice for a mint we don't hold is ignored
+        let other = ExitAdvice::new(
+            [0u8; 16], [6u8; 32], ExitAdvice::REASON_TARGET_HIT, 90, 10.0, 1_000, 1_300, 20_000, 50,
+        );
+        assert!(tracker.exit_advice_decision(&other, None).is_none());
+    }
+    
+    fn features_at(price: f64) -> MintFeatures {
+        MintFeatures {
+            current_price: price,
+            mempool_pending_buys: 5,
+            vol_5s_sol: 10.0,
+            ..Default::default()
+        }
+    }
+    
+    #[test]
+    fn test_cap_remaining_hold() {
+        let mut tracker = PositionTracker::new(3);
+        let mint = bs58::encode(&[5u8; 32]).into_string();
+        tracker.add_position(test_position(mint.clone(), 0.2)).unwrap();
+        
+        // 300s max hold, just entered: capped to ~10s
+        assert!(tracker.cap_remaining_hold(&mint, 10));
+        assert!(tracker.get_position(&mint).unwrap().max_hold_secs <= 11);
+        
+        // Never extends a hold that's already shorter
+        assert!(!tracker.cap_remaining_hold(&mint, 60));
+        assert!(!tracker.cap_remaining_hold("unknown-mint-xx", 10));
+    }
+    
+    #[test]
+    fn test_apply_entry_quote() {
+        let mut pos = test_position(bs58::encode(&[6u8; 32]).into_string(), 0.4);
+        
+        assert!(pos.apply_entry_quote(388.5, 0.00103));
+        assert_eq!(pos.tokens, 388.5);
+        assert_eq!(pos.entry_price_sol, 0.00103);
+        
+        // After a partial exit the held amount is no longer the entry fill
+        pos.apply_exit(Some(1), 30);
+        assert!(!pos.apply_entry_quote(400.0, 0.001));
+        assert!((pos.tokens - 271.95).abs() < 1e-9);
+    }
+    
+    #[test]
+    fn test_laddered_partial_exits() {
+        let mut tracker = PositionTracker::new(3);
+        let mint = bs58::encode(&[8u8; 32]).into_string();
+        let mut pos = test_position(mint.clone(), 0.4);
+        pos.entry_path = EntryTrigger::LateOpportunity; // $5 USD target stays out of the way
+        tracker.add_position(pos).unwrap();
+        
+        // +35%: tier 1 sells 30% of the position
+        let (reason, _) = tracker.check_position(&mint, &features_at(0.00135), 0.01).unwrap();
+        assert_eq!(reason.profit_tier(), Some(1));
+        assert_eq!(reason.exit_percent(), 30);
+        assert!(!tracker.record_exit(&mint, reason.profit_tier(), reason.exit_percent()));
+        let pos = tracker.get_position(&mint).unwrap();
+        assert!((pos.tokens - 280.0).abs() < 1e-9);
+        assert!((pos.size_sol - 0.28).abs() < 1e-9);
+        
+        // Same price on the next cycle: tier 1 already sold, nothing to do
+        assert!(tracker.check_position(&mint, &features_at(0.00135), 0.01).is_none());
+        
+        // +65%: tier 2 sells 60% of what's left
+        let (reason, _) = tracker.check_position(&mint, &features_at(0.00165), 0.01).unwrap();
+        assert_eq!(reason.profit_tier(), Some(2));
+        assert_eq!(reason.exit_percent(), 60);
+        assert!(!tracker.record_exit(&mint, reason.profit_tier(), reason.exit_percent()));
+        assert!((tracker.get_position(&mint).unwrap().tokens - 112.0).abs() < 1e-9);
+        
+        // Pullback into the tier 1 band doesn't re-fire a lower tier
+        assert!(tracker.check_position(&mint, &features_at(0.00135), 0.01).is_none());
+        
+        // +100%: tier 3 closes the rest and the position is removed
+        let (reason, _) = tracker.check_position(&mint, &features_at(0.0021), 0.01).unwrap();
+        assert_eq!(reason.profit_tier(), Some(3));
+        assert_eq!(reason.exit_percent(), 100);
+        assert!(tracker.record_exit(&mint, reason.profit_tier(), reason.exit_percent()));
+        assert!(tracker.get_position(&mint).is_none());
+        assert_eq!(tracker.count(), 0);
+        
+        // The final SELL's confirmation can still price against the entry
+        assert_eq!(tracker.exit_basis(&mint).unwrap().entry_path, EntryTrigger::LateOpportunity);
+    }
+    
+    #[test]
+    fn test_four_ladder_exit_sequence() {
+        let mut tracker = PositionTracker::new(3);
+        let mint = bs58::encode(&[12u8; 32]).into_string();
+        let mut pos = test_position(mint.clone(), 0.4);
+        pos.entry_path = EntryTrigger::LateOpportunity;
+        pos.profit_ladders = vec![
+            ProfitLadder::new(20.0, 25),
+            ProfitLadder::new(50.0, 33),
+            ProfitLadder::new(100.0, 50),
+            ProfitLadder::new(150.0, 100),
+        ];
+        tracker.add_position(pos).unwrap();
+        
+        assert!(tracker.check_position(&mint, &features_at(0.0011), 0.01).is_none());
+        
+        // Gap up to +60%: ladders still fire in order, one per check
+        let expected = [(1, 25, 300.0), (2, 33, 201.0)];
+        for (tier, exit_percent, tokens_left) in expected {
+            let (reason, _) = tracker.check_position(&mint, &features_at(0.0016), 0.01).unwrap();
+            assert_eq!(reason.profit_tier(), Some(tier));
+            assert_eq!(reason.exit_percent(), exit_percent);
+            assert!(!tracker.record_exit(&mint, reason.profit_tier(), reason.exit_percent()));
+            assert!((tracker.get_position(&mint).unwrap().tokens - tokens_left).abs() < 1e-9);
+        }
+        // Ladder 3 needs +100%
+        assert!(tracker.check_position(&mint, &features_at(0.0016), 0.01).is_none());
+        
+        let (reason, _) = tracker.check_position(&mint, &features_at(0.0026), 0.01).unwrap();
+        assert_eq!((reason.profit_tier(), reason.exit_percent()), (Some(3), 50));
+        assert!(!tracker.record_exit(&mint, reason.profit_tier(), reason.exit_percent()));
+        assert!((tracker.get_position(&mint).unwrap().tokens - 100.5).abs() < 1e-9);
+        
+        // Ladder 4 closes the position
+        let (reason, _) = tracker.check_position(&mint, &features_at(0.0026), 0.01).unwrap();
+        assert_eq!((reason.profit_tier(), reason.exit_percent()), (Some(4), 100));
+        assert!(tracker.record_exit(&mint, reason.profit_tier(), reason.exit_percent()));
+        assert!(tracker.get_position(&mint).is_none());
+        assert!(tracker.exit_basis(&mint).unwrap().next_ladder().is_none());
+    }
+    
+    #[test]
+    fn test_trailing_stop_ratchets_and_fires_on_retrace() {
+        let mut tracker = PositionTracker::new(3);
+        let mint = bs58::encode(&[14u8; 32]).into_string();
+        let mut pos = test_position(mint.clone(), 0.4);
+        pos.entry_path = EntryTrigger::LateOpportunity;
+        pos.profit_ladders = vec![ProfitLadder::new(500.0, 100)]; // out of the way
+        assert_eq!(pos.trailing_stop_price(), None);
+        pos.trailing_stop_pct = Some(20.0);
+        tracker.add_position(pos).unwrap();
+        let stop = |tracker: &PositionTracker| tracker.get_position(&mint).unwrap().trailing_stop_price().unwrap();
+        
+        // Trails 20% below entry until price makes a new high
+        assert!((stop(&tracker) - 0.0008).abs() < 1e-12);
+        
+        // New highs raise the stop; a dip in between never lowers it
+        for (price, expected_stop) in [(0.0012, 0.00096), (0.0011, 0.00096), (0.0015, 0.0012)] {
+            tracker.update_peaks(&mint, &features_at(price));
+            assert!((stop(&tracker) - expected_stop).abs() < 1e-12, "price {}", price);
+        }
+        
+        // +25%, above the stop: hold
+        assert!(tracker.check_position(&mint, &features_at(0.00125), 0.01).is_none());
+        
+        // Retrace to +19% crosses the 0.0012 stop: full exit with the gain locked in
+        let (reason, _) = tracker.check_position(&mint, &features_at(0.00119), 0.01).unwrap();
+        match reason {
+            ExitReason::TrailingStop { peak_price_sol, pnl_pct, exit_percent } => {
+                assert_eq!(peak_price_sol, 0.0015);
+                assert!((pnl_pct - 19.0).abs() < 1e-6);
+                assert_eq!(exit_percent, 100);
+            }
+            other => panic!("expected TrailingStop, got {:?}", other),
+        }
+    }
+    
+    #[test]
+    fn test_adjust_profit_targets_scales_ladders() {
+        let mut tracker = PositionTracker::new(3)
+            .with_profit_ladders(vec![ProfitLadder::new(20.0, 50), ProfitLadder::new(40.0, 100)]);
+        let mint = bs58::encode(&[13u8; 32]).into_string();
+        let mut pos = test_position(mint.clone(), 0.2);
+        pos.profit_ladders = tracker.profit_ladders.clone();
+        tracker.add_position(pos).unwrap();
+        
+        assert!(tracker.adjust_profit_targets(&mint, 1.5));
+        let gains: Vec<f64> = tracker.get_position(&mint).unwrap().profit_ladders.iter().map(|l| l.gain_pct).collect();
+        assert_eq!(gains, vec![30.0, 60.0]);
+        assert!(!tracker.adjust_profit_targets("unknown-mint-xx", 1.5));
+    }
+    
+    #[test]
+    fn test_exit_in_flight_suppresses_duplicate_sells() {
+        let mut tracker = PositionTracker::new(3).with_exit_in_flight_timeout(Duration::from_millis(50));
+        let mint = bs58::encode(&[11u8; 32]).into_string();
+        tracker.add_position(test_position(mint.clone(), 0.4)).unwrap();
+        
+        // -50% trips the stop loss
+        let dump = features_at(0.0005);
+        assert!(tracker.check_position(&mint, &dump, 0.01).is_some());
+        
+        // SELL sent: the next monitor tick must not emit another one
+        tracker.mark_sell_sent(&mint);
+        assert!(tracker.check_position(&mint, &dump, 0.01).is_none());
+        
+        // Executor confirmation clears the flag
+        assert!(tracker.clear_sell_in_flight(&mint));
+        assert!(tracker.check_position(&mint, &dump, 0.01).is_some());
+        
+        // Unconfirmed SELLs stop blocking after the timeout
+        tracker.mark_sell_sent(&mint);
+        assert!(tracker.check_position(&mint, &dump, 0.01).is_none());
+        std::thread::sleep(Duration::from_millis(60));
+        assert!(tracker.check_position(&mint, &dump, 0.01).is_some());
+    }
+    
+    fn aged_position(age_secs: u64) -> ActivePosition {
+        let mut pos = test_position(bs58::encode(&[12u8; 32]).into_string(), 0.2);
+        pos.entry_time = Instant::now().checked_sub(Duration::from_secs(age_secs)).unwrap();
+        pos
+    }
+    
+    fn activity(vol_5s_sol: f64, buyers_2s: u32, vol_60s_sol: f64) -> MintFeatures {
+        MintFeatures {
+            current_price: 0.001, // flat vs entry
+            mempool_pending_buys: buyers_2s.max(1),
+            buyers_2s,
+            vol_5s_sol,
+            vol_60s_sol,
+            ..Default::default()
+        }
+    }
+    
+    #[test]
+    fn test_time_decay_extends_while_active() {
+        // Past the flat 300s, but still ripping: keep holding up to 600s
+        let pos = aged_position(310);
+        let ripping = activity(5.0, 6, 60.0);
+        assert_eq!(pos.dynamic_max_hold_secs(&ripping), 600);
+        assert!(pos.should_exit(&ripping, 200.0).is_none());
+        
+        // A capped hold (curve complete) is never stretched
+        let mut capped = aged_position(310);
+        capped.hold_capped = true;
+        assert!(matches!(
+            capped.should_exit(&ripping, 200.0),
+            Some(ExitReason::TimeDecay { max_hold_secs: 300, .. })
+        ));
+    }
+    
+    #[test]
+    fn test_time_decay_exits_early_when_activity_collapses() {
+        let pos = aged_position(160);
+        
+        // Steady pace: the flat 300s limit applies
+        assert!(pos.should_exit(&activity(5.0, 1, 60.0), 200.0).is_none());
+        
+        // 5s pace (0.6 × 12 = 7.2 SOL/min) under a quarter of the 60s volume: 150s limit
+        match pos.should_exit(&activity(0.6, 1, 60.0), 200.0) {
+            Some(ExitReason::TimeDecay { elapsed_secs, max_hold_secs, .. }) => {
+                assert_eq!(max_hold_secs, 150);
+                assert!(elapsed_secs >= 160);
+            }
+            other => panic!("expected TimeDecay, got {:?}", other),
+        }
+    }
+    
+    #[test]
+    fn test_volume_drop_from_peak() {
+        let mut tracker = PositionTracker::new(3);
+        let mint = bs58::encode(&[13u8; 32]).into_string();
+        tracker.add_position(test_position(mint.clone(), 0.4)).unwrap();
+        let at_volume = |vol_5s_sol| MintFeatures { vol_5s_sol, ..features_at(0.001) };
+        
+        // Volume builds to a 10 SOL peak, then fades to 4 SOL: still above 30%
+        for vol in [3.0, 10.0, 4.0] {
+            tracker.update_peaks(&mint, &at_volume(vol));
+        }
+        assert_eq!(tracker.get_position(&mint).unwrap().peak_vol_5s_sol, 10.0);
+        assert!(tracker.check_position(&mint, &at_volume(4.0), 200.0).is_none());
+        
+        // Collapse to 2 SOL (<30% of peak): partial exit
+        let (reason, _) = tracker.check_position(&mint, &at_volume(2.0), 200.0).unwrap();
+        assert!(matches!(reason, ExitReason::VolumeDrop { exit_percent: 50, .. }));
+        
+        // The partial exit resets the peak, the rest is judged on volume from here
+        assert!(!tracker.record_exit(&mint, reason.profit_tier(), reason.exit_percent()));
+        tracker.update_peaks(&mint, &at_volume(2.0));
+        assert!(tracker.check_position(&mint, &at_volume(2.0), 200.0).is_none());
+        
+        // Thin peaks never trigger
+        let quiet = bs58::encode(&[14u8; 32]).into_string();
+        tracker.add_position(test_position(quiet.clone(), 0.4)).unwrap();
+        tracker.update_peaks(&quiet, &at_volume(0.8));
+        assert!(tracker.check_position(&quiet, &at_volume(0.6), 200.0).is_none());
+    }
+    
+    #[test]
+    fn test_rug_indicators_force_full_exit() {
+        let mut tracker = PositionTracker::new(3);
+        let mint = bs58::encode(&[15u8; 32]).into_string();
+        tracker.add_position(test_position(mint.clone(), 0.4)).unwrap();
+        let healthy = MintFeatures { curve_sol_reserves: 60.0, ..features_at(0.001) };
+        tracker.update_peaks(&mint, &healthy);
+        assert!(tracker.check_position(&mint, &healthy, 200.0).is_none());
+        
+        let is_emergency = |features: &MintFeatures, tracker: &PositionTracker| matches!(
+            tracker.check_position(&mint, features, 200.0),
+            Some((ExitReason::Emergency { exit_percent: 100, .. }, _))
+        );
+        
+        // Reserves 20% off the peak is a normal sell-off, 40% off is liquidity pulled
+        assert!(!is_emergency(&MintFeatures { curve_sol_reserves: 48.0, ..healthy.clone() }, &tracker));
+        assert!(is_emergency(&MintFeatures { curve_sol_reserves: 36.0, ..healthy.clone() }, &tracker));
+        
+        assert!(is_emergency(&MintFeatures { creator_sold_60s: true, ..healthy.clone() }, &tracker));
+        assert!(is_emergency(&MintFeatures { top_seller_share_60s: 0.7, ..healthy.clone() }, &tracker));
+        
+        // Emergencies skip the normal in-flight wait, ordinary exits don't
+        tracker.mark_sell_sent(&mint);
+        assert!(!is_emergency(&MintFeatures { creator_sold_60s: true, ..healthy.clone() }, &tracker));
+        tracker.sells_in_flight.insert(mint.clone(), Instant::now() - EMERGENCY_RESEND);
+        assert!(is_emergency(&MintFeatures { creator_sold_60s: true, ..healthy.clone() }, &tracker));
+        assert!(tracker.check_position(&mint, &features_at(0.0005), 200.0).is_none());
     }
 }
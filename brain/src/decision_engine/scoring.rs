@@ -4,6 +4,7 @@
 //! - 40% Buyer momentum (buyers_2s normalized)
 //! - 40% Volume momentum (vol_5s_sol normalized)
 //! - 20% Wallet quality (avg tier/confidence of recent buyers)
+//! - ±10 points for 60s net SOL flow (net inflow rewarded, distribution penalized)
 //!
 //! Higher scores indicate stronger momentum and higher probability of follow-through.
 
@@ -22,6 +23,9 @@ pub struct ScoreComponents {
     /// Wallet quality score (0-100)
     pub wallet_quality_score: u8,
     
+    /// Net SOL flow adjustment applied to the total (-10..=10 by default)
+    pub net_flow_adjustment: i8,
+    
     /// Combined follow-through score (0-100)
     pub total_score: u8,
     
@@ -35,11 +39,12 @@ impl ScoreComponents {
     /// Create a breakdown string for logging
     pub fn breakdown(&self) -> String {
         format!(
-            "FT={} (buyers={}, vol={}, quality={}) | raw: {}b, {:.1}SOL, conf={:.0}",
+            "FT={} (buyers={}, vol={}, quality={}, flow={:+}) | raw: {}b, {:.1}SOL, conf={:.0}",
             self.total_score,
             self.buyer_score,
             self.volume_score,
             self.wallet_quality_score,
+            self.net_flow_adjustment,
             self.buyers_2s,
             self.vol_5s_sol,
             self.avg_wallet_confidence
@@ -63,6 +68,12 @@ pub struct FollowThroughScorer {
     
     /// Weight for wallet quality (default: 0.2)
     quality_weight: f64,
+    
+    /// Points added at 100% net inflow / removed at 100% net outflow (default: 10)
+    net_flow_points: f64,
+    
+    /// 60s volume below which net flow is too noisy to score (default: 1 SOL)
+    net_flow_min_vol_sol: f64,
 }
 
 impl Default for FollowThroughScorer {
@@ -73,6 +84,8 @@ impl Default for FollowThroughScorer {
             buyer_weight: 0.4,
             volume_weight: 0.4,
             quality_weight: 0.2,
+            net_flow_points: 10.0,
+            net_flow_min_vol_sol: 1.0,
         }
     }
 }
@@ -109,18 +122,57 @@ impl FollowThroughScorer {
     /// This is the simplified version that uses pre-computed metrics from MintFeatures.
     /// For real-time scoring with wallet data, use `calculate_with_wallets()`.
     pub fn calculate(&self, mint_features: &MintFeatures) -> ScoreComponents {
-        // Extract metrics from mint features
-        let buyers_2s = mint_features.buyers_2s;
-        let vol_5s_sol = mint_features.vol_5s_sol;
-        
         // Use the follow_through_score from mint cache as wallet quality proxy
         // (since we don't have individual wallet data in this simplified version)
-        let wallet_quality_proxy = mint_features.follow_through_score as f64 / 100.0;
-        
+        let components = self.calculate_from_inputs(
+            mint_features.buyers_2s,
+            mint_features.vol_5s_sol,
+            mint_features.follow_through_score,
+            mint_features.volatility_60s,
+        );
+        self.apply_net_flow(components, mint_features)
+    }
+    
+    /// Adjust the total by 60s net SOL flow: the share of 60s volume that was
+    /// net buying (+) or net selling (-), scaled to ±net_flow_points
+    fn apply_net_flow(&self, components: ScoreComponents, mint_features: &MintFeatures) -> ScoreComponents {
+        let adjustment = self.score_net_flow(mint_features.net_sol_flow_60s, mint_features.vol_60s_sol);
+        if adjustment < 0 {
+            debug!("📉 Distribution penalty: net flow {:.2} SOL of {:.2} SOL (60s)",
+                mint_features.net_sol_flow_60s, mint_features.vol_60s_sol);
+        }
+        ScoreComponents {
+            net_flow_adjustment: adjustment,
+            total_score: (components.total_score as i16 + adjustment as i16).clamp(0, 100) as u8,
+            ..components
+        }
+    }
+    
+    /// Net flow points: (net / volume) × net_flow_points, 0 below net_flow_min_vol_sol
+    fn score_net_flow(&self, net_sol_flow_60s: f64, vol_60s_sol: f64) -> i8 {
+        if vol_60s_sol < self.net_flow_min_vol_sol || vol_60s_sol <= 0.0 {
+            return 0;
+        }
+        let share = (net_sol_flow_60s / vol_60s_sol).clamp(-1.0, 1.0);
+        (share * self.net_flow_points).round() as i8
+    }
+    
+    /// Calculate follow-through score from primitive inputs
+    /// 
+    /// Single source of truth for weights, thresholds and the volatility penalty;
+    /// `calculate()` and the mint cache updater both go through here. Net flow
+    /// isn't an input: `calculate()` applies it on top from `MintFeatures`.
+    pub fn calculate_from_inputs(
+        &self,
+        buyers_2s: u32,
+        vol_5s_sol: f64,
+        wallet_quality_score: u8,
+        volatility_60s: f64,
+    ) -> ScoreComponents {
         // Calculate individual component scores
         let buyer_score = self.score_buyers(buyers_2s);
         let volume_score = self.score_volume(vol_5s_sol);
-        let wallet_quality_score = (wallet_quality_proxy * 100.0) as u8;
+        let wallet_quality_score = wallet_quality_score.min(100);
         
         // Compute weighted total
         let total_score = (
@@ -130,8 +182,8 @@ impl FollowThroughScorer {
         ).round() as u8;
         
         // Apply volatility penalty for whipsaw charts (reduce false positives)
-        let final_score = if mint_features.volatility_60s > 0.25 {
-            debug!("📉 Volatility penalty applied: {:.3} > 0.25", mint_features.volatility_60s);
+        let final_score = if volatility_60s > 0.25 {
+            debug!("📉 Volatility penalty applied: {:.3} > 0.25", volatility_60s);
             total_score.saturating_sub(10)
         } else {
             total_score
@@ -141,10 +193,11 @@ impl FollowThroughScorer {
             buyer_score,
             volume_score,
             wallet_quality_score,
+            net_flow_adjustment: 0,
             total_score: final_score.min(100),
             buyers_2s,
             vol_5s_sol,
-            avg_wallet_confidence: wallet_quality_proxy * 100.0,
+            avg_wallet_confidence: wallet_quality_score as f64,
         }
     }
     
@@ -157,14 +210,6 @@ impl FollowThroughScorer {
         mint_features: &MintFeatures,
         recent_wallets: &[WalletFeatures],
     ) -> ScoreComponents {
-        // Extract metrics
-        let buyers_2s = mint_features.buyers_2s;
-        let vol_5s_sol = mint_features.vol_5s_sol;
-        
-        // Calculate buyer and volume scores
-        let buyer_score = self.score_buyers(buyers_2s);
-        let volume_score = self.score_volume(vol_5s_sol);
-        
         // Calculate wallet quality from actual wallet features
         let wallet_quality_score = self.score_wallet_quality(recent_wallets);
         let avg_wallet_confidence = if !recent_wallets.is_empty() {
@@ -173,35 +218,25 @@ impl FollowThroughScorer {
             50.0
         };
         
-        // Compute weighted total
-        let total_score = (
-            (buyer_score as f64 * self.buyer_weight) +
-            (volume_score as f64 * self.volume_weight) +
-            (wallet_quality_score as f64 * self.quality_weight)
-        ).round() as u8;
-        
-        // Apply volatility penalty for whipsaw charts
-        let final_score = if mint_features.volatility_60s > 0.25 {
-            debug!("📉 Volatility penalty applied: {:.3} > 0.25", mint_features.volatility_60s);
-            total_score.saturating_sub(10)
-        } else {
-            total_score
-        };
+        let components = self.calculate_from_inputs(
+            mint_features.buyers_2s,
+            mint_features.vol_5s_sol,
+            wallet_quality_score,
+            mint_features.volatility_60s,
+        );
+        let components = self.apply_net_flow(components, mint_features);
         
         debug!(
-            "📊 Follow-through: total={} (buyers={}, vol={}, quality={}) | {}/{}b, {:.2}SOL",
-            final_score, buyer_score, volume_score, wallet_quality_score,
-            buyers_2s, self.max_buyers_2s, vol_5s_sol
+            "📊 Follow-through: total={} (buyers={}, vol={}, quality={}, flow={:+}) | {}/{}b, {:.2}SOL",
+            components.total_score, components.buyer_score, components.volume_score,
+            components.wallet_quality_score, components.net_flow_adjustment,
+            components.buyers_2s, self.max_buyers_2s,
+            components.vol_5s_sol
         );
         
         ScoreComponents {
-            buyer_score,
-            volume_score,
-            wallet_quality_score,
-            total_score: final_score.min(100),
-            buyers_2s,
-            vol_5s_sol,
             avg_wallet_confidence,
+            ..components
         }
     }
     
@@ -313,6 +348,24 @@ impl FollowThroughScorer {
     }
 }
 
+/// Wallet quality proxy from 60s buyer count (0-100 buyers → 40-90 points, 50 if unknown)
+///
+/// Used when scoring from the SQLite cache, where individual buyer wallets aren't available.
+pub fn buyers_60s_quality_proxy(buyers_60s: u32) -> u8 {
+    if buyers_60s == 0 {
+        return 50;
+    }
+    let normalized = (buyers_60s as f64 / 100.0).min(1.0);
+    (40.0 + normalized * 50.0) as u8
+}
+
+/// Follow-through score for the mint cache updater (default scorer, no volatility penalty)
+pub fn cache_follow_through_score(buyers_2s: u32, vol_5s_sol: f64, buyers_60s: u32) -> u8 {
+    FollowThroughScorer::default()
+        .calculate_from_inputs(buyers_2s, vol_5s_sol, buyers_60s_quality_proxy(buyers_60s), 0.0)
+        .total_score
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -493,6 +546,7 @@ mod tests {
             buyer_score: 75,
             volume_score: 85,
             wallet_quality_score: 80,
+            net_flow_adjustment: 3,
             total_score: 80,
             buyers_2s: 12,
             vol_5s_sol: 18.5,
@@ -501,7 +555,102 @@ mod tests {
         
         let breakdown = components.breakdown();
         assert!(breakdown.contains("FT=80"));
+        assert!(breakdown.contains("flow=+3"));
         assert!(breakdown.contains("12b"));
         assert!(breakdown.contains("18.5SOL"));
     }
+    
+    #[test]
+    fn test_calculate_matches_primitive_inputs() {
+        let scorer = FollowThroughScorer::new();
+        
+        // Sweep the input space: MintFeatures entry point == primitive entry point
+        for buyers_2s in 0..=30u32 {
+            for vol_tenths in (0..=600u32).step_by(7) {
+                let vol_5s_sol = vol_tenths as f64 / 10.0;
+                for quality in (0..=100u8).step_by(9) {
+                    for volatility_60s in [0.0, 0.25, 0.4] {
+                        let mint = MintFeatures {
+                            buyers_2s,
+                            vol_5s_sol,
+                            follow_through_score: quality,
+                            volatility_60s,
+                            ..Default::default()
+                        };
+                        let from_features = scorer.calculate(&mint);
+                        let from_inputs = scorer.calculate_from_inputs(buyers_2s, vol_5s_sol, quality, volatility_60s);
+                        assert_eq!(from_features.total_score, from_inputs.total_score,
+                            "buyers={} vol={} quality={} volatility={}", buyers_2s, vol_5s_sol, quality, volatility_60s);
+                        assert_eq!(from_features.buyer_score, from_inputs.buyer_score);
+                        assert_eq!(from_features.volume_score, from_inputs.volume_score);
+                        assert_eq!(from_features.wallet_quality_score, from_inputs.wallet_quality_score);
+                    }
+                }
+            }
+        }
+    }
+    
+    #[test]
+    fn test_cache_score_matches_scorer() {
+        let scorer = FollowThroughScorer::new();
+        
+        // Cache updater score == scorer on a MintFeatures carrying the same proxy
+        for buyers_2s in 0..=30u32 {
+            for vol_tenths in (0..=600u32).step_by(7) {
+                let vol_5s_sol = vol_tenths as f64 / 10.0;
+                for buyers_60s in (0..=150u32).step_by(5) {
+                    let mint = MintFeatures {
+                        buyers_2s,
+                        vol_5s_sol,
+                        follow_through_score: buyers_60s_quality_proxy(buyers_60s),
+                        ..Default::default()
+                    };
+                    assert_eq!(
+                        cache_follow_through_score(buyers_2s, vol_5s_sol, buyers_60s),
+                        scorer.calculate(&mint).total_score,
+                        "buyers_2s={} vol={} buyers_60s={}", buyers_2s, vol_5s_sol, buyers_60s
+                    );
+                }
+            }
+        }
+    }
+    
+    #[test]
+    fn test_net_flow_adjustment() {
+        let scorer = FollowThroughScorer::new();
+        let base = MintFeatures {
+            buyers_2s: 8,
+            vol_5s_sol: 15.0,
+            follow_through_score: 70,
+            vol_60s_sol: 20.0,
+            ..Default::default()
+        };
+        let neutral = scorer.calculate(&base).total_score;
+        
+        // 15 of 20 SOL net buying → +8 (0.75 × 10, rounded)
+        let inflow = scorer.calculate(&MintFeatures { net_sol_flow_60s: 15.0, ..base.clone() });
+        assert_eq!(inflow.net_flow_adjustment, 8);
+        assert_eq!(inflow.total_score, neutral + 8);
+        
+        // Distribution: all net selling → -10
+        let outflow = scorer.calculate(&MintFeatures { net_sol_flow_60s: -25.0, ..base.clone() });
+        assert_eq!(outflow.net_flow_adjustment, -10);
+        assert_eq!(outflow.total_score, neutral - 10);
+        
+        // Too little 60s volume to trust the sign
+        let thin = MintFeatures { vol_60s_sol: 0.5, net_sol_flow_60s: -0.5, ..base.clone() };
+        assert_eq!(scorer.calculate(&thin).net_flow_adjustment, 0);
+        
+        // Same adjustment on the wallet-aware path
+        assert_eq!(scorer.calculate_with_wallets(&MintFeatures { net_sol_flow_60s: -25.0, ..base }, &[]).net_flow_adjustment, -10);
+    }
+    
+    #[test]
+    fn test_buyers_60s_quality_proxy() {
+        assert_eq!(buyers_60s_quality_proxy(0), 50);
+        assert_eq!(buyers_60s_quality_proxy(1), 40);
+        assert_eq!(buyers_60s_quality_proxy(50), 65);
+        assert_eq!(buyers_60s_quality_proxy(100), 90);
+        assert_eq!(buyers_60s_quality_proxy(500), 90);
+    }
 }
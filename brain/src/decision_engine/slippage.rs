@@ -0,0 +1,80 @@
+//! 📉 Slippage Model - Size-aware slippage estimate for entry decisions
+//!
+//! Replaces the fixed 1.5% tolerance with an estimate that grows with position
+//! size and shrinks with curve depth. Uses the constant-product impact of buying
+//! `size_sol` into an effective SOL liquidity derived from `curve_depth_proxy`,
+//! plus headroom for other buyers landing in the same slot.
+//!
+//! (Realized slippage is measured after the fact by execution's `slippage` module.)
+
+/// Floor for any entry (fees, rounding, one-slot price drift)
+const BASE_SLIPPAGE_BPS: f64 = 100.0;
+
+/// Multiplier on raw price impact to cover concurrent flow
+const IMPACT_HEADROOM: f64 = 1.5;
+
+/// Hard cap - anything wider is not worth taking
+pub const MAX_SLIPPAGE_BPS: u16 = 1500;
+
+/// Virtual SOL reserves of a fresh pump.fun curve
+const BASE_LIQUIDITY_SOL: f64 = 30.0;
+
+/// Curve depth treated as "normal" (same reference as TradeValidator's impact model)
+const REFERENCE_DEPTH: f64 = 1_000_000.0;
+
+/// Estimate slippage tolerance (bps) for buying `size_sol` into a curve of the given depth
+///
+/// Depth scales liquidity by sqrt(depth / reference), floored at half the base
+/// liquidity; unknown depth (0) is treated as the thinnest curve.
+pub fn estimate_bps(size_sol: f64, curve_depth_proxy: u64) -> u16 {
+    if size_sol <= 0.0 {
+        return BASE_SLIPPAGE_BPS as u16;
+    }
+
+    let depth_factor = if curve_depth_proxy > 0 {
+        (curve_depth_proxy as f64 / REFERENCE_DEPTH).sqrt().max(0.5)
+    } else {
+        0.5
+    };
+    let liquidity_sol = BASE_LIQUIDITY_SOL * depth_factor;
+
+    // Constant-product price impact: Δp/p ≈ size / (reserves + size)
+    let impact_bps = size_sol / (liquidity_sol + size_sol) * 10_000.0;
+    let bps = BASE_SLIPPAGE_BPS + impact_bps * IMPACT_HEADROOM;
+
+    bps.round().min(MAX_SLIPPAGE_BPS as f64) as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_small_entry_on_normal_curve_matches_old_default() {
+        // 0.1 SOL into a reference-depth curve ≈ the old fixed 150 bps
+        let bps = estimate_bps(0.1, 1_000_000);
+        assert!((145..=155).contains(&bps), "got {}", bps);
+    }
+
+    #[test]
+    fn test_slippage_grows_with_size() {
+        let small = estimate_bps(0.1, 1_000_000);
+        let medium = estimate_bps(0.5, 1_000_000);
+        let large = estimate_bps(2.0, 1_000_000);
+        assert!(small < medium && medium < large);
+    }
+
+    #[test]
+    fn test_thin_curve_needs_more_slippage() {
+        assert!(estimate_bps(0.5, 250_000) > estimate_bps(0.5, 4_000_000));
+        // Unknown depth is priced as the thinnest curve
+        assert_eq!(estimate_bps(0.5, 0), estimate_bps(0.5, 1));
+        assert!(estimate_bps(0.5, 0) > 150);
+    }
+
+    #[test]
+    fn test_bounds() {
+        assert_eq!(estimate_bps(0.0, 1_000_000), 100);
+        assert_eq!(estimate_bps(1_000.0, 0), MAX_SLIPPAGE_BPS);
+    }
+}
@@ -14,12 +14,13 @@ use crate::decision_engine::{TradeValidator, ValidatedTrade};
 use crate::udp_bus::messages::TradeDecision;
 
 /// Entry trigger type for logging and analysis
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum EntryTrigger {
     RankBased,      // Path A: Top-ranked launch
     Momentum,       // Path B: High recent activity
     CopyTrade,      // Path C: Following wallet
     LateOpportunity, // Path D: Mature launch
+    Hotlist,        // Path E: Data-mining hotlist top entry (sized like Path A)
 }
 
 impl EntryTrigger {
@@ -29,6 +30,17 @@ impl EntryTrigger {
             EntryTrigger::Momentum => "momentum",
             EntryTrigger::CopyTrade => "copy",
             EntryTrigger::LateOpportunity => "late",
+            EntryTrigger::Hotlist => "hotlist",
+        }
+    }    
+    /// Wire code for TradeDecision.entry_type / guardrails (0=rank, 1=momentum, 2=copy, 3=late, 4=hotlist)
+    pub fn entry_type(&self) -> u8 {
+        match self {
+            EntryTrigger::RankBased => 0,
+            EntryTrigger::Momentum => 1,
+            EntryTrigger::CopyTrade => 2,
+            EntryTrigger::LateOpportunity => 3,
+            EntryTrigger::Hotlist => 4,
         }
     }
 }
@@ -188,7 +200,7 @@ impl TriggerEngine {
     /// Returns: Position size in SOL
     pub fn calculate_position_size(&self, early_score: f64, path: EntryTrigger) -> f64 {
         let size = match path {
-            EntryTrigger::RankBased => {
+            EntryTrigger::RankBased | EntryTrigger::Hotlist => {
                 // Rank-based: Scale by score
                 if early_score >= 9.0 {
                     self.config.rank_position_size_sol * 2.0  // 100 SOL
@@ -234,7 +246,7 @@ impl TriggerEngine {
     /// Get path-specific confidence threshold
     pub fn get_min_decision_conf(&self, path: EntryTrigger) -> u8 {
         match path {
-            EntryTrigger::RankBased => self.config.min_decision_conf_rank,
+            EntryTrigger::RankBased | EntryTrigger::Hotlist => self.config.min_decision_conf_rank,
             EntryTrigger::Momentum => self.config.min_decision_conf_momentum,
             EntryTrigger::CopyTrade => self.config.min_decision_conf_copy,
             EntryTrigger::LateOpportunity => 75,  // Not used
@@ -244,7 +256,7 @@ impl TriggerEngine {
     /// Get path-specific max hold time
     pub fn get_max_hold_time(&self, path: EntryTrigger) -> u64 {
         match path {
-            EntryTrigger::RankBased => self.config.rank_max_hold_secs,
+            EntryTrigger::RankBased | EntryTrigger::Hotlist => self.config.rank_max_hold_secs,
             EntryTrigger::Momentum => self.config.momentum_max_hold_secs,
             EntryTrigger::CopyTrade => self.config.copy_max_hold_secs,
             EntryTrigger::LateOpportunity => 300,  // Not used
@@ -309,13 +321,7 @@ impl TriggerEngine {
         validated: &ValidatedTrade,
         trigger: EntryTrigger,
     ) -> TradeDecision {
-        // Convert EntryTrigger to entry_type u8
-        let entry_type = match trigger {
-            EntryTrigger::RankBased => 0,
-            EntryTrigger::Momentum => 1,
-            EntryTrigger::CopyTrade => 2,
-            EntryTrigger::LateOpportunity => 3,
-        };
+        let entry_type = trigger.entry_type();
         
         // Use new_buy() constructor which automatically calculates checksum
         TradeDecision::new_buy(
@@ -700,6 +706,7 @@ mod copy_trade_tests {
         WalletFeatures {
             win_rate_7d: 0.65,
             realized_pnl_7d: 100.0,
+            realized_pnl_24h: 0.0,
             trade_count: 50,
             avg_size: 5.0,
             tier,
@@ -36,6 +36,9 @@ pub struct ValidationConfig {
     
     /// Enable/disable rug checks (default: true)
     pub enable_rug_checks: bool,
+    
+    /// Max share of 60s net buy accumulation held by one wallet (default: 0.6)
+    pub max_top1_share: f64,
 }
 
 impl Default for ValidationConfig {
@@ -48,6 +51,7 @@ impl Default for ValidationConfig {
             max_hot_launch_age_secs: 300,
             rug_creator_blacklist: HashSet::new(),
             enable_rug_checks: true,
+            max_top1_share: 0.6,
         }
     }
 }
@@ -158,6 +162,10 @@ pub enum ValidationError {
         buyers_2s: u32,
         vol_5s: f64,
     },
+    BuyerConcentration {
+        top1_share: f64,
+        max_allowed: f64,
+    },
 }
 
 impl std::fmt::Display for ValidationError {
@@ -187,6 +195,10 @@ impl std::fmt::Display for ValidationError {
             ValidationError::WeakDemand { buyers_2s, vol_5s } => {
                 write!(f, "Weak demand: {} buyers in 2s, {:.2} SOL vol in 5s", buyers_2s, vol_5s)
             }
+            ValidationError::BuyerConcentration { top1_share, max_allowed } => {
+                write!(f, "Buyer concentration: top wallet holds {:.0}% of net buys (max {:.0}%)",
+                       top1_share * 100.0, max_allowed * 100.0)
+            }
         }
     }
 }
@@ -388,7 +400,16 @@ impl TradeValidator {
             });
         }
         
-        // Pattern 3: Zero or near-zero price (likely broken token)
+        // Pattern 3: One wallet accumulated most of the 60s net buy flow (rug setup)
+        // Needs 2+ buyers - a lone buyer is always 100% and is covered by WeakDemand
+        if mint_features.buyers_60s >= 2 && mint_features.top1_share_60s > self.config.max_top1_share {
+            bail!(ValidationError::BuyerConcentration {
+                top1_share: mint_features.top1_share_60s,
+                max_allowed: self.config.max_top1_share,
+            });
+        }
+        
+        // Pattern 4: Zero or near-zero price (likely broken token)
         // Note: Pump.fun tokens can have extremely small prices (e.g., 1e-14) due to large supplies
         // Only reject if price is literally 0.0
         if mint_features.current_price <= 0.0 {
@@ -585,6 +606,35 @@ mod tests {
         assert!(result.unwrap_err().to_string().contains("Suspicious pattern"));
     }
     
+    #[test]
+    fn test_validation_buyer_concentration() {
+        let validator = TradeValidator::new();
+        
+        let mint = Pubkey::new_unique();
+        
+        // Healthy flow otherwise, but one wallet accumulated 80% of net buys
+        let mint_features = MintFeatures {
+            age_since_launch: 60,
+            current_price: 0.001,
+            vol_60s_sol: 25.0,
+            buyers_60s: 15,
+            buys_sells_ratio: 2.5,
+            curve_depth_proxy: 1_000_000,
+            buyers_2s: 8,
+            vol_5s_sol: 10.0,
+            top1_share_60s: 0.8,
+            ..Default::default()
+        };
+        
+        let result = validator.validate(mint, &mint_features, 10.0, 150, 75, None);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Buyer concentration"));
+        
+        // Same flow with a healthy top holder passes
+        let distributed = MintFeatures { top1_share_60s: 0.2, ..mint_features };
+        assert!(validator.validate(mint, &distributed, 10.0, 150, 75, None).is_ok());
+    }
+    
     #[test]
     fn test_price_impact_estimation() {
         let validator = TradeValidator::new();
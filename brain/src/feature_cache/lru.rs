@@ -0,0 +1,148 @@
+//! ♻️ Bounded LRU map shared by the feature caches
+//!
+//! DashMap keyed by pubkey where every read/write stamps the entry with a
+//! monotonically increasing access tick. When an insert pushes the map over
+//! capacity, the least recently used entries are evicted (in a small batch so
+//! a full cache doesn't rescan on every insert).
+
+use dashmap::DashMap;
+use solana_sdk::pubkey::Pubkey;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+struct LruEntry<V> {
+    value: V,
+    last_access: AtomicU64,
+}
+
+/// Lock-free map with least-recently-used eviction
+pub struct LruCache<V> {
+    map: DashMap<Pubkey, LruEntry<V>>,
+    capacity: usize,
+    tick: AtomicU64,
+}
+
+impl<V: Clone> LruCache<V> {
+    /// Create a cache holding at most `capacity` entries (minimum 1)
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            map: DashMap::new(),
+            capacity: capacity.max(1),
+            tick: AtomicU64::new(0),
+        }
+    }
+
+    fn next_tick(&self) -> u64 {
+        self.tick.fetch_add(1, Ordering::Relaxed) + 1
+    }
+
+    /// Get a copy of the value, marking it as recently used
+    pub fn get(&self, key: &Pubkey) -> Option<V> {
+        self.map.get(key).map(|entry| {
+            entry.last_access.store(self.next_tick(), Ordering::Relaxed);
+            entry.value.clone()
+        })
+    }
+
+    /// Insert or replace a value, evicting LRU entries if over capacity
+    pub fn insert(&self, key: Pubkey, value: V) {
+        let last_access = AtomicU64::new(self.next_tick());
+        self.map.insert(key, LruEntry { value, last_access });
+
+        if self.map.len() > self.capacity {
+            self.evict_lru();
+        }
+    }
+
+    /// Mutate a value in place (marks it as recently used); returns false if absent
+    pub fn update<F: FnOnce(&mut V)>(&self, key: &Pubkey, f: F) -> bool {
+        match self.map.get_mut(key) {
+            Some(mut entry) => {
+                entry.last_access.store(self.next_tick(), Ordering::Relaxed);
+                f(&mut entry.value);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Check presence without touching recency
+    pub fn contains(&self, key: &Pubkey) -> bool {
+        self.map.contains_key(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Keep only entries matching `keep`; returns how many were removed
+    pub fn retain<F: FnMut(&V) -> bool>(&self, mut keep: F) -> usize {
+        let before = self.map.len();
+        self.map.retain(|_, entry| keep(&entry.value));
+        before.saturating_sub(self.map.len())
+    }
+
+    /// Evict least recently used entries down to capacity minus 1/16 slack
+    fn evict_lru(&self) {
+        let target = self.capacity - self.capacity / 16;
+        let excess = self.map.len().saturating_sub(target);
+        if excess == 0 {
+            return;
+        }
+
+        let mut by_age: Vec<(u64, Pubkey)> = self
+            .map
+            .iter()
+            .map(|entry| (entry.last_access.load(Ordering::Relaxed), *entry.key()))
+            .collect();
+        by_age.sort_unstable_by_key(|(tick, _)| *tick);
+
+        for (_, key) in by_age.into_iter().take(excess) {
+            self.map.remove(&key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let cache = LruCache::new(3);
+        let keys: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+
+        cache.insert(keys[0], 0);
+        cache.insert(keys[1], 1);
+        cache.insert(keys[2], 2);
+
+        // Touch the oldest so keys[1] becomes LRU
+        assert_eq!(cache.get(&keys[0]), Some(0));
+
+        cache.insert(keys[3], 3);
+        assert_eq!(cache.len(), 3);
+        assert!(!cache.contains(&keys[1]));
+        assert!(cache.contains(&keys[0]));
+        assert!(cache.contains(&keys[3]));
+    }
+
+    #[test]
+    fn test_update_and_retain() {
+        let cache = LruCache::new(10);
+        let a = Pubkey::new_unique();
+        let b = Pubkey::new_unique();
+        cache.insert(a, 1);
+        cache.insert(b, 5);
+
+        assert!(cache.update(&a, |v| *v += 10));
+        assert!(!cache.update(&Pubkey::new_unique(), |v| *v += 1));
+        assert_eq!(cache.get(&a), Some(11));
+
+        assert_eq!(cache.retain(|v| *v > 10), 1);
+        assert!(cache.contains(&a));
+        assert!(!cache.contains(&b));
+    }
+}
@@ -2,8 +2,9 @@
 //!
 //! Lock-free cache of token metrics updated from LaunchTracker SQLite database.
 //! Provides <50µs read access to mint features for decision-making.
+//! Bounded by `MINT_CACHE_CAPACITY` (LRU eviction); `remove_stale` purges
+//! mints that dropped out of the refresh query.
 
-use dashmap::DashMap;
 use solana_sdk::pubkey::Pubkey;
 use rusqlite::{Connection, params};
 use std::str::FromStr;
@@ -12,6 +13,34 @@ use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use log::{info, warn, error, debug};
 use anyhow::{Result, Context};
 
+use super::lru::LruCache;
+
+/// Mints not refreshed for this long are purged by the updater
+pub const MINT_STALE_AFTER: Duration = Duration::from_secs(300);
+
+/// `windows.window_sec` values the cache queries read (buyers_2s, vol_5s_sol, 60s features)
+/// IMPORTANT: must match data-mining's `config::REQUIRED_WINDOW_INTERVALS`
+pub const REQUIRED_WINDOW_INTERVALS: [u64; 3] = [2, 5, 60];
+
+/// Required window intervals with no rows written in the last `lookback_secs`
+///
+/// An empty result means data-mining is producing every window Brain reads;
+/// anything returned would silently fill the matching features with zeros.
+pub fn missing_window_intervals(conn: &Connection, lookback_secs: u64) -> Result<Vec<u64>> {
+    let mut stmt = conn.prepare(
+        "SELECT DISTINCT window_sec FROM windows WHERE start_time > strftime('%s', 'now') - ?1"
+    )?;
+    let present = stmt
+        .query_map(params![lookback_secs as i64], |row| row.get::<_, i64>(0))?
+        .collect::<Result<Vec<_>, _>>()?;
+    
+    Ok(REQUIRED_WINDOW_INTERVALS
+        .iter()
+        .copied()
+        .filter(|sec| !present.contains(&(*sec as i64)))
+        .collect())
+}
+
 /// Features extracted for each token mint
 #[derive(Debug, Clone)]
 pub struct MintFeatures {
@@ -21,13 +50,13 @@ pub struct MintFeatures {
     /// Current token price in SOL (from last trade)
     pub current_price: f64,
     
-    /// Trading volume in last 60 seconds (SOL)
+    /// Trading volume in a 60s window (SOL, `windows.window_sec = 60`)
     pub vol_60s_sol: f64,
     
-    /// Number of unique buyers in last 60 seconds
+    /// Number of unique buyers in a 60s window (`windows.window_sec = 60`)
     pub buyers_60s: u32,
     
-    /// Ratio of buys to sells in last 60 seconds
+    /// Ratio of buys to sells in a 60s window
     pub buys_sells_ratio: f64,
     
     /// Proxy for bonding curve depth (total_supply - burned_tokens)
@@ -39,10 +68,10 @@ pub struct MintFeatures {
     /// Last update timestamp (Unix seconds)
     pub last_update: u64,
     
-    /// Number of buyers in last 2 seconds (for Path B trigger)
+    /// Unique buyers in the current 2s window (`windows.window_sec = 2`, for Path B trigger)
     pub buyers_2s: u32,
     
-    /// Trading volume in last 5 seconds (SOL, for Path B trigger)
+    /// Trading volume in the current 5s window (SOL, `windows.window_sec = 5`, for Path B trigger)
     pub vol_5s_sol: f64,
     
     /// Price volatility in 60s window (standard deviation)
@@ -59,6 +88,24 @@ pub struct MintFeatures {
     
     /// Mempool volume in SOL
     pub mempool_volume_sol: f64,
+    
+    /// Top wallet's share of net buy accumulation in 60s window (0-1)
+    pub top1_share_60s: f64,
+    
+    /// Top 3 wallets' share of net buy accumulation in 60s window (0-1)
+    pub top3_share_60s: f64,
+    
+    /// Buy SOL minus sell SOL in 60s window (`windows.net_sol_flow`; negative = distribution)
+    pub net_sol_flow_60s: f64,
+    
+    /// Bonding curve virtual SOL reserves (SOL, from the gRPC curve account; 0 = unknown)
+    pub curve_sol_reserves: f64,
+    
+    /// Token creator sold in the last 60s
+    pub creator_sold_60s: bool,
+    
+    /// Largest single seller's share of the last 60s traded SOL (0-1)
+    pub top_seller_share_60s: f64,
 }
 
 impl Default for MintFeatures {
@@ -79,6 +126,12 @@ impl Default for MintFeatures {
             mempool_pending_sells: 0,
             mc_sol: 0.0,
             mempool_volume_sol: 0.0,
+            top1_share_60s: 0.0,
+            top3_share_60s: 0.0,
+            net_sol_flow_60s: 0.0,
+            curve_sol_reserves: 0.0,
+            creator_sold_60s: false,
+            top_seller_share_60s: 0.0,
         }
     }
 }
@@ -100,28 +153,28 @@ impl MintFeatures {
     }
 }
 
-/// Lock-free cache of mint features
+/// Lock-free LRU cache of mint features
 #[derive(Clone)]
 pub struct MintCache {
-    cache: Arc<DashMap<Pubkey, MintFeatures>>,
+    cache: Arc<LruCache<MintFeatures>>,
     db_path: String,
 }
 
 impl MintCache {
-    /// Create new mint cache
-    pub fn new(db_path: String) -> Self {
+    /// Create new mint cache holding at most `capacity` mints
+    pub fn new(db_path: String, capacity: usize) -> Self {
         Self {
-            cache: Arc::new(DashMap::new()),
+            cache: Arc::new(LruCache::new(capacity)),
             db_path,
         }
     }
     
     /// Get features for a mint (returns None if not in cache)
     pub fn get(&self, mint: &Pubkey) -> Option<MintFeatures> {
-        self.cache.get(mint).map(|entry| entry.value().clone())
+        self.cache.get(mint)
     }
     
-    /// Insert or update features for a mint
+    /// Insert or update features for a mint (evicts least recently used mints when full)
     pub fn insert(&self, mint: Pubkey, features: MintFeatures) {
         // Check for potential update contention (rapid overwrites)
         if let Some(existing) = self.cache.get(&mint) {
@@ -150,7 +203,7 @@ impl MintCache {
             .as_secs();
         
         // Get existing or create new
-        if let Some(mut existing) = self.cache.get_mut(&mint) {
+        let updated = self.cache.update(&mint, |existing| {
             // Update only provided fields
             if let Some(vol) = vol_60s_sol {
                 existing.vol_60s_sol = vol;
@@ -173,7 +226,9 @@ impl MintCache {
                 existing.vol_5s_sol = vol;
             }
             existing.last_update = now;
-            
+        });
+        
+        if updated {
             debug!("♻️  Updated cache from UDP: {} (age={}s)", 
                 MintFeatures::mint_short(&mint), now);
         } else {
@@ -198,7 +253,20 @@ impl MintCache {
     
     /// Check if mint exists in cache
     pub fn contains(&self, mint: &Pubkey) -> bool {
-        self.cache.contains_key(mint)
+        self.cache.contains(mint)
+    }
+    
+    /// Purge mints whose features are older than `max_age`; returns how many were removed
+    pub fn remove_stale(&self, max_age: Duration) -> usize {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let removed = self.cache.retain(|v| now.saturating_sub(v.last_update) < max_age.as_secs());
+        if removed > 0 {
+            debug!("🧹 Purged {} stale mints from cache", removed);
+        }
+        removed
     }
     
     /// Get cache size
@@ -252,13 +320,7 @@ impl MintCache {
         // NOTE: We increased this from 30s to 300s to prevent removing cache entries
         // for active positions during low trading activity. The emergency exit logic
         // in main.rs will handle positions without cache after 30s.
-        self.cache.retain(|_, v| {
-            let now = SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .unwrap_or_default()
-                .as_secs();
-            now.saturating_sub(v.last_update) < 300  // 5 minutes
-        });
+        self.remove_stale(MINT_STALE_AFTER);
         
         Ok(count)
     }
@@ -267,6 +329,8 @@ impl MintCache {
     fn query_mint_features(db_path: &str) -> Result<Vec<(Pubkey, MintFeatures)>> {
         let conn = Connection::open(db_path)
             .context("Failed to open SQLite database")?;
+        conn.busy_timeout(Duration::from_secs(2))
+            .context("Failed to set SQLite busy timeout")?;
         
         let now = SystemTime::now()
             .duration_since(UNIX_EPOCH)
@@ -287,7 +351,10 @@ impl MintCache {
                 0 as total_supply,
                 COALESCE(w2.uniq_buyers, 0) as buyers_2s,
                 COALESCE(w5.vol_sol, 0.0) as vol_5s_sol,
-                COALESCE(w60.price_volatility, 0.0) as volatility_60s
+                COALESCE(w60.price_volatility, 0.0) as volatility_60s,
+                COALESCE(w60.top1_share, 0.0) as top1_share_60s,
+                COALESCE(w60.top3_share, 0.0) as top3_share_60s,
+                COALESCE(w60.net_sol_flow, 0.0) as net_sol_flow_60s
              FROM windows w60
              INNER JOIN tokens t ON w60.mint = t.mint
              LEFT JOIN windows w2 ON w60.mint = w2.mint AND w2.window_sec = 2
@@ -314,6 +381,9 @@ impl MintCache {
             let buyers_2s: u32 = row.get(8)?;
             let vol_5s: f64 = row.get(9)?;
             let volatility_60s: f64 = row.get(10)?;
+            let top1_share_60s: f64 = row.get(11)?;
+            let top3_share_60s: f64 = row.get(12)?;
+            let net_sol_flow_60s: f64 = row.get(13)?;
             
             Ok((
                 mint_str,
@@ -327,6 +397,9 @@ impl MintCache {
                 buyers_2s,
                 vol_5s,
                 volatility_60s,
+                top1_share_60s,
+                top3_share_60s,
+                net_sol_flow_60s,
             ))
         })?;
         
@@ -334,7 +407,8 @@ impl MintCache {
         
         for row_result in rows {
             let (mint_str, launch_ts, price, vol_60s, buyers_60s, buys_60s, sells_60s, 
-                 total_supply, buyers_2s, vol_5s, volatility_60s) = row_result?;
+                 total_supply, buyers_2s, vol_5s, volatility_60s, top1_share_60s, top3_share_60s,
+                 net_sol_flow_60s) = row_result?;
             
             // Parse mint address
             let mint = match Pubkey::from_str(&mint_str) {
@@ -381,6 +455,12 @@ impl MintCache {
                 mempool_pending_sells: 0, // TODO: Populate from mempool watcher
                 mc_sol: 0.0,              // TODO: Calculate from price * supply
                 mempool_volume_sol: 0.0,  // TODO: Populate from mempool watcher
+                top1_share_60s,
+                top3_share_60s,
+                net_sol_flow_60s,
+                curve_sol_reserves: 0.0,    // Rug signals come from main's cache refresh and gRPC
+                creator_sold_60s: false,
+                top_seller_share_60s: 0.0,
             };
             
             features.push((mint, feature));
@@ -420,6 +500,48 @@ mod tests {
         assert!(!features.is_stale());
     }
     
+    #[test]
+    fn test_remove_stale_and_capacity() {
+        let cache = MintCache::new(String::new(), 2);
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        
+        let fresh = Pubkey::new_unique();
+        let stale = Pubkey::new_unique();
+        cache.insert(fresh, MintFeatures { last_update: now, ..Default::default() });
+        cache.insert(stale, MintFeatures { last_update: now - 600, ..Default::default() });
+        
+        assert_eq!(cache.remove_stale(MINT_STALE_AFTER), 1);
+        assert!(cache.contains(&fresh));
+        assert!(!cache.contains(&stale));
+        
+        // Capacity 2: a third mint evicts the least recently used one
+        let second = Pubkey::new_unique();
+        let third = Pubkey::new_unique();
+        cache.insert(second, MintFeatures { last_update: now, ..Default::default() });
+        cache.get(&fresh);
+        cache.insert(third, MintFeatures { last_update: now, ..Default::default() });
+        assert_eq!(cache.len(), 2);
+        assert!(cache.contains(&fresh));
+        assert!(!cache.contains(&second));
+    }
+    
+    #[test]
+    fn test_missing_window_intervals() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE windows (mint TEXT, window_sec INTEGER, start_time INTEGER);
+             INSERT INTO windows VALUES ('m', 5, strftime('%s', 'now'));
+             INSERT INTO windows VALUES ('m', 60, strftime('%s', 'now'));
+             INSERT INTO windows VALUES ('m', 2, strftime('%s', 'now') - 7200);"
+        ).unwrap();
+        
+        // The only 2s window is outside the lookback
+        assert_eq!(missing_window_intervals(&conn, 3600).unwrap(), vec![2]);
+    }
+    
     #[test]
     fn test_mint_short() {
         let mint = Pubkey::new_unique();
@@ -1,5 +1,6 @@
+pub mod lru;
 pub mod mint_cache;
 pub mod wallet_cache;
 
 pub use mint_cache::{MintCache, MintFeatures};
-pub use wallet_cache::{WalletCache, WalletFeatures, WalletTier};
+pub use wallet_cache::{TierThresholds, WalletCache, WalletFeatures, WalletTier};
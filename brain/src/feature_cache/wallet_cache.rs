@@ -2,8 +2,9 @@
 //!
 //! Lock-free cache of trader statistics updated from SQLite database.
 //! Provides <50µs read access to wallet features for copy-trade decisions.
+//! Bounded by `WALLET_CACHE_CAPACITY` (LRU eviction); `remove_stale` purges
+//! wallets that dropped out of the refresh query.
 
-use dashmap::DashMap;
 use solana_sdk::pubkey::Pubkey;
 use rusqlite::{Connection, params};
 use std::str::FromStr;
@@ -12,17 +13,33 @@ use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use log::{info, warn, error, debug};
 use anyhow::{Result, Context};
 
+use super::lru::LruCache;
+
+/// Wallets not refreshed for this long are purged by the updater
+pub const WALLET_STALE_AFTER: Duration = Duration::from_secs(600);
+
 /// Wallet tier classification
+///
+/// Boundaries come from `TierThresholds` (WALLET_TIER_* env vars); the values
+/// below are the defaults. Base confidence per tier is `confidence()`:
+///
+/// | Tier      | Default criteria (≥10 trades)  | `confidence()` |
+/// |-----------|--------------------------------|----------------|
+/// | A         | Win ≥ 60%, PnL ≥ 100 SOL       | 93             |
+/// | B         | Win ≥ 55%, PnL ≥ 40 SOL        | 87             |
+/// | C         | Win ≥ 50%, PnL ≥ 15 SOL        | 80             |
+/// | Discovery | anything else                  | 50             |
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum WalletTier {
     Discovery = 0,  // Unknown/new wallet
-    C = 1,          // Win≥50%, PnL≥15 SOL, conf 75-84
-    B = 2,          // Win≥55%, PnL≥40 SOL, conf 85-89
-    A = 3,          // Win≥60%, PnL≥100 SOL, conf 92-95
+    C = 1,          // Win≥50%, PnL≥15 SOL, conf 80
+    B = 2,          // Win≥55%, PnL≥40 SOL, conf 87
+    A = 3,          // Win≥60%, PnL≥100 SOL, conf 93
 }
 
 impl WalletTier {
-    /// Get confidence score for this tier
+    /// Base confidence score for this tier (A 93, B 87, C 80, Discovery 50);
+    /// `calculate_confidence` adds win-rate and experience boosts on top
     pub fn confidence(&self) -> u8 {
         match self {
             WalletTier::Discovery => 50,
@@ -36,8 +53,50 @@ impl WalletTier {
     pub fn meets_copy_threshold(&self) -> bool {
         *self >= WalletTier::C
     }
+    
+    /// Next tier down (Discovery stays Discovery)
+    pub fn demoted(&self) -> WalletTier {
+        match self {
+            WalletTier::A => WalletTier::B,
+            WalletTier::B => WalletTier::C,
+            WalletTier::C | WalletTier::Discovery => WalletTier::Discovery,
+        }
+    }
+}
+
+/// Tier boundaries used by `WalletFeatures::classify_tier`
+///
+/// A wallet gets the highest tier whose win rate AND 7d PnL minimums it meets
+/// (both inclusive); fewer than `min_trades` trades is always Discovery.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TierThresholds {
+    /// Minimum completed trades before any tier above Discovery
+    pub min_trades: u32,
+    pub a_min_win_rate: f64,
+    pub a_min_pnl_sol: f64,
+    pub b_min_win_rate: f64,
+    pub b_min_pnl_sol: f64,
+    pub c_min_win_rate: f64,
+    pub c_min_pnl_sol: f64,
+}
+
+impl Default for TierThresholds {
+    fn default() -> Self {
+        Self {
+            min_trades: 10,
+            a_min_win_rate: 0.60,
+            a_min_pnl_sol: 100.0,
+            b_min_win_rate: 0.55,
+            b_min_pnl_sol: 40.0,
+            c_min_win_rate: 0.50,
+            c_min_pnl_sol: 15.0,
+        }
+    }
 }
 
+/// 24h loss (as a fraction of 7d PnL) at which a wallet drops two tiers instead of one
+const RECENT_LOSS_SEVERE_RATIO: f64 = 0.25;
+
 /// Last trade info for a wallet
 #[derive(Debug, Clone)]
 pub struct LastTrade {
@@ -56,6 +115,9 @@ pub struct WalletFeatures {
     /// Realized profit/loss over last 7 days (SOL)
     pub realized_pnl_7d: f64,
     
+    /// Realized profit/loss over last 24 hours (SOL)
+    pub realized_pnl_24h: f64,
+    
     /// Total number of completed trades (7 days)
     pub trade_count: u32,
     
@@ -84,6 +146,7 @@ impl Default for WalletFeatures {
         Self {
             win_rate_7d: 0.0,
             realized_pnl_7d: 0.0,
+            realized_pnl_24h: 0.0,
             trade_count: 0,
             avg_size: 0.0,
             tier: WalletTier::Discovery,
@@ -112,30 +175,44 @@ impl WalletFeatures {
     }
     
     /// Classify wallet into tier based on performance
-    pub fn classify_tier(win_rate: f64, pnl_7d: f64, trade_count: u32) -> WalletTier {
+    pub fn classify_tier(win_rate: f64, pnl_7d: f64, trade_count: u32, thresholds: &TierThresholds) -> WalletTier {
         // Require minimum trade count for tier classification
-        if trade_count < 10 {
+        if trade_count < thresholds.min_trades {
             return WalletTier::Discovery;
         }
         
-        // Tier A: Win≥60%, PnL≥100 SOL
-        if win_rate >= 0.60 && pnl_7d >= 100.0 {
+        if win_rate >= thresholds.a_min_win_rate && pnl_7d >= thresholds.a_min_pnl_sol {
             return WalletTier::A;
         }
         
-        // Tier B: Win≥55%, PnL≥40 SOL
-        if win_rate >= 0.55 && pnl_7d >= 40.0 {
+        if win_rate >= thresholds.b_min_win_rate && pnl_7d >= thresholds.b_min_pnl_sol {
             return WalletTier::B;
         }
         
-        // Tier C: Win≥50%, PnL≥15 SOL
-        if win_rate >= 0.50 && pnl_7d >= 15.0 {
+        if win_rate >= thresholds.c_min_win_rate && pnl_7d >= thresholds.c_min_pnl_sol {
             return WalletTier::C;
         }
         
         WalletTier::Discovery
     }
     
+    /// Demote a tier when the wallet is losing today despite strong 7d stats
+    /// (recent_performance_7d_vs_24h factor)
+    ///
+    /// - 24h PnL ≥ 0: tier unchanged
+    /// - 24h loss < 25% of 7d PnL: down one tier
+    /// - 24h loss ≥ 25% of 7d PnL: down two tiers
+    pub fn apply_recent_performance(tier: WalletTier, pnl_7d: f64, pnl_24h: f64) -> WalletTier {
+        if pnl_24h >= 0.0 {
+            return tier;
+        }
+        
+        let severe = pnl_7d <= 0.0 || -pnl_24h >= pnl_7d * RECENT_LOSS_SEVERE_RATIO;
+        let steps = if severe { 2 } else { 1 };
+        
+        (0..steps).fold(tier, |t, _| t.demoted())
+    }
+    
     /// Calculate confidence score (0-100)
     pub fn calculate_confidence(tier: WalletTier, win_rate: f64, trade_count: u32) -> u8 {
         let base = tier.confidence();
@@ -169,35 +246,61 @@ impl WalletFeatures {
     }
 }
 
-/// Lock-free cache of wallet features
+/// Lock-free LRU cache of wallet features
 #[derive(Clone)]
 pub struct WalletCache {
-    cache: Arc<DashMap<Pubkey, WalletFeatures>>,
+    cache: Arc<LruCache<WalletFeatures>>,
     db_path: String,
+    tier_thresholds: TierThresholds,
 }
 
 impl WalletCache {
-    /// Create new wallet cache
-    pub fn new(db_path: String) -> Self {
+    /// Create new wallet cache holding at most `capacity` wallets
+    pub fn new(db_path: String, capacity: usize) -> Self {
         Self {
-            cache: Arc::new(DashMap::new()),
+            cache: Arc::new(LruCache::new(capacity)),
             db_path,
+            tier_thresholds: TierThresholds::default(),
         }
     }
     
+    /// Classify wallets with custom tier boundaries (WALLET_TIER_* config)
+    pub fn with_tier_thresholds(mut self, tier_thresholds: TierThresholds) -> Self {
+        self.tier_thresholds = tier_thresholds;
+        self
+    }
+    
+    /// Tier boundaries applied to every wallet loaded into this cache
+    pub fn tier_thresholds(&self) -> &TierThresholds {
+        &self.tier_thresholds
+    }
+    
     /// Get features for a wallet (returns None if not in cache)
     pub fn get(&self, wallet: &Pubkey) -> Option<WalletFeatures> {
-        self.cache.get(wallet).map(|entry| entry.value().clone())
+        self.cache.get(wallet)
     }
     
-    /// Insert or update features for a wallet
+    /// Insert or update features for a wallet (evicts least recently used wallets when full)
     pub fn insert(&self, wallet: Pubkey, features: WalletFeatures) {
         self.cache.insert(wallet, features);
     }
     
     /// Check if wallet exists in cache
     pub fn contains(&self, wallet: &Pubkey) -> bool {
-        self.cache.contains_key(wallet)
+        self.cache.contains(wallet)
+    }
+    
+    /// Purge wallets whose features are older than `max_age`; returns how many were removed
+    pub fn remove_stale(&self, max_age: Duration) -> usize {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let removed = self.cache.retain(|v| now.saturating_sub(v.last_update) < max_age.as_secs());
+        if removed > 0 {
+            debug!("🧹 Purged {} stale wallets from cache", removed);
+        }
+        removed
     }
     
     /// Get cache size
@@ -235,8 +338,9 @@ impl WalletCache {
     pub async fn update_cache(&self) -> Result<usize> {
         // Run DB query in blocking task to avoid blocking async runtime
         let db_path = self.db_path.clone();
+        let thresholds = self.tier_thresholds.clone();
         let features = tokio::task::spawn_blocking(move || {
-            Self::query_wallet_features(&db_path)
+            Self::query_wallet_features(&db_path, &thresholds)
         })
         .await
         .context("Failed to join blocking task")??;
@@ -248,22 +352,46 @@ impl WalletCache {
         }
         
         // Remove stale entries (>10 minutes old)
-        self.cache.retain(|_, v| {
-            let now = SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .unwrap_or_default()
-                .as_secs();
-            now.saturating_sub(v.last_update) < 600
-        });
+        self.remove_stale(WALLET_STALE_AFTER);
+        
+        Ok(count)
+    }
+    
+    /// Update cache from data-mining's `wallet_stats` over a shared read connection
+    ///
+    /// Fallback for when PostgreSQL (WalletTracker) is unavailable, so copy-trade
+    /// decisions still see tiered wallets in single-DB deployments.
+    pub async fn update_from_sqlite(&self, sqlite: &Arc<tokio::sync::Mutex<Connection>>) -> Result<usize> {
+        let sqlite = sqlite.clone();
+        let thresholds = self.tier_thresholds.clone();
+        let features = tokio::task::spawn_blocking(move || {
+            let conn = sqlite.blocking_lock();
+            Self::load_wallet_stats(&conn, &thresholds)
+        })
+        .await
+        .context("Failed to join blocking task")??;
+        
+        let count = features.len();
+        for (wallet, feature) in features {
+            self.cache.insert(wallet, feature);
+        }
+        self.remove_stale(WALLET_STALE_AFTER);
         
         Ok(count)
     }
     
     /// Query wallet features from SQLite database
-    fn query_wallet_features(db_path: &str) -> Result<Vec<(Pubkey, WalletFeatures)>> {
+    fn query_wallet_features(db_path: &str, thresholds: &TierThresholds) -> Result<Vec<(Pubkey, WalletFeatures)>> {
         let conn = Connection::open(db_path)
             .context("Failed to open SQLite database")?;
+        conn.busy_timeout(Duration::from_secs(2))
+            .context("Failed to set SQLite busy timeout")?;
         
+        Self::load_wallet_stats(&conn, thresholds)
+    }
+    
+    /// Tracked wallets active in the last 7 days from `wallet_stats`, tiered with `classify_tier`
+    pub fn load_wallet_stats(conn: &Connection, thresholds: &TierThresholds) -> Result<Vec<(Pubkey, WalletFeatures)>> {
         let now = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .unwrap_or_default()
@@ -327,7 +455,7 @@ impl WalletCache {
             
             // Use win_rate from database (already calculated)
             // Classify tier
-            let tier = WalletFeatures::classify_tier(win_rate, pnl, trade_count);
+            let tier = WalletFeatures::classify_tier(win_rate, pnl, trade_count, thresholds);
             
             // Calculate confidence
             let confidence = WalletFeatures::calculate_confidence(tier, win_rate, trade_count);
@@ -345,6 +473,7 @@ impl WalletCache {
             let feature = WalletFeatures {
                 win_rate_7d: win_rate,
                 realized_pnl_7d: pnl,
+                realized_pnl_24h: 0.0, // No 24h PnL in SQLite wallet_stats
                 trade_count,
                 avg_size,
                 tier,
@@ -369,35 +498,114 @@ mod tests {
     fn test_wallet_tier_classification() {
         // Tier A
         assert_eq!(
-            WalletFeatures::classify_tier(0.65, 150.0, 50),
+            WalletFeatures::classify_tier(0.65, 150.0, 50, &TierThresholds::default()),
             WalletTier::A
         );
         
         // Tier B
         assert_eq!(
-            WalletFeatures::classify_tier(0.58, 50.0, 30),
+            WalletFeatures::classify_tier(0.58, 50.0, 30, &TierThresholds::default()),
             WalletTier::B
         );
         
         // Tier C
         assert_eq!(
-            WalletFeatures::classify_tier(0.52, 20.0, 25),
+            WalletFeatures::classify_tier(0.52, 20.0, 25, &TierThresholds::default()),
             WalletTier::C
         );
         
         // Discovery (low trade count)
         assert_eq!(
-            WalletFeatures::classify_tier(0.80, 200.0, 5),
+            WalletFeatures::classify_tier(0.80, 200.0, 5, &TierThresholds::default()),
             WalletTier::Discovery
         );
         
         // Discovery (below thresholds)
         assert_eq!(
-            WalletFeatures::classify_tier(0.45, 10.0, 20),
+            WalletFeatures::classify_tier(0.45, 10.0, 20, &TierThresholds::default()),
             WalletTier::Discovery
         );
     }
     
+    #[test]
+    fn test_tier_boundaries_matrix() {
+        let t = TierThresholds::default();
+        let classify = |win_rate: f64, pnl: f64, trades: u32| WalletFeatures::classify_tier(win_rate, pnl, trades, &t);
+        
+        // (win_rate, pnl_7d, trades, expected)
+        let cases = [
+            // Trade count gate: exactly min_trades qualifies, one below never does
+            (0.60, 100.0, 10, WalletTier::A),
+            (0.60, 100.0, 9, WalletTier::Discovery),
+            (0.99, 10_000.0, 0, WalletTier::Discovery),
+            // Tier A: both minimums inclusive
+            (0.60, 100.0, 50, WalletTier::A),
+            (0.5999, 100.0, 50, WalletTier::B),   // win rate just under A
+            (0.60, 99.99, 50, WalletTier::B),     // borderline pnl falls to B
+            (0.95, 99.99, 50, WalletTier::B),     // huge win rate can't buy back pnl
+            (0.5999, 1_000.0, 50, WalletTier::B), // huge pnl can't buy back win rate
+            // Tier B
+            (0.55, 40.0, 50, WalletTier::B),
+            (0.5499, 40.0, 50, WalletTier::C),
+            (0.55, 39.99, 50, WalletTier::C),
+            // Tier C
+            (0.50, 15.0, 50, WalletTier::C),
+            (0.4999, 15.0, 50, WalletTier::Discovery),
+            (0.50, 14.99, 50, WalletTier::Discovery),
+            // Losing / no-history wallets
+            (0.70, -5.0, 50, WalletTier::Discovery),
+            (0.0, 0.0, 50, WalletTier::Discovery),
+        ];
+        
+        for (win_rate, pnl, trades, expected) in cases {
+            assert_eq!(
+                classify(win_rate, pnl, trades), expected,
+                "win_rate={} pnl={} trades={}", win_rate, pnl, trades
+            );
+        }
+    }
+    
+    #[test]
+    fn test_custom_tier_thresholds() {
+        // More aggressive operator: smaller sample, lower bars
+        let aggressive = TierThresholds {
+            min_trades: 5,
+            a_min_win_rate: 0.55,
+            a_min_pnl_sol: 50.0,
+            b_min_win_rate: 0.50,
+            b_min_pnl_sol: 20.0,
+            c_min_win_rate: 0.45,
+            c_min_pnl_sol: 5.0,
+        };
+        
+        assert_eq!(WalletFeatures::classify_tier(0.56, 60.0, 5, &aggressive), WalletTier::A);
+        assert_eq!(WalletFeatures::classify_tier(0.56, 60.0, 5, &TierThresholds::default()), WalletTier::Discovery);
+        assert_eq!(WalletFeatures::classify_tier(0.45, 5.0, 8, &aggressive), WalletTier::C);
+        assert_eq!(WalletFeatures::classify_tier(0.44, 5.0, 8, &aggressive), WalletTier::Discovery);
+        
+        let cache = WalletCache::new(String::new(), 10).with_tier_thresholds(aggressive.clone());
+        assert_eq!(cache.tier_thresholds(), &aggressive);
+    }
+    
+    #[test]
+    fn test_recent_losses_demote_tier() {
+        // Profitable or flat last 24h: no change
+        assert_eq!(WalletFeatures::apply_recent_performance(WalletTier::A, 150.0, 5.0), WalletTier::A);
+        assert_eq!(WalletFeatures::apply_recent_performance(WalletTier::A, 150.0, 0.0), WalletTier::A);
+        
+        // Any 24h loss demotes one tier
+        assert_eq!(WalletFeatures::apply_recent_performance(WalletTier::A, 150.0, -0.01), WalletTier::B);
+        assert_eq!(WalletFeatures::apply_recent_performance(WalletTier::B, 50.0, -5.0), WalletTier::C);
+        
+        // Just under / at the 25% boundary (37.5 SOL of 150)
+        assert_eq!(WalletFeatures::apply_recent_performance(WalletTier::A, 150.0, -37.4), WalletTier::B);
+        assert_eq!(WalletFeatures::apply_recent_performance(WalletTier::A, 150.0, -37.5), WalletTier::C);
+        
+        // Tier C falls out of copy range; Discovery stays put
+        assert_eq!(WalletFeatures::apply_recent_performance(WalletTier::C, 20.0, -1.0), WalletTier::Discovery);
+        assert_eq!(WalletFeatures::apply_recent_performance(WalletTier::Discovery, 0.0, -10.0), WalletTier::Discovery);
+    }
+    
     #[test]
     fn test_confidence_calculation() {
         // Tier A with high win rate
@@ -432,6 +640,30 @@ mod tests {
         assert_eq!(short.len(), 12);
     }
     
+    #[test]
+    fn test_load_wallet_stats_from_sqlite() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE wallet_stats (
+                wallet TEXT PRIMARY KEY, last_seen INTEGER NOT NULL, total_trades INTEGER,
+                net_pnl_sol REAL, realized_wins INTEGER, realized_losses INTEGER,
+                win_rate REAL, is_tracked INTEGER
+            );"
+        ).unwrap();
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i64;
+        let tracked = Pubkey::new_unique().to_string();
+        let untracked = Pubkey::new_unique().to_string();
+        conn.execute(
+            "INSERT INTO wallet_stats VALUES (?1, ?2, 40, 120.0, 26, 14, 0.65, 1), (?3, ?2, 40, 120.0, 26, 14, 0.65, 0)",
+            params![tracked, now, untracked],
+        ).unwrap();
+        
+        let features = WalletCache::load_wallet_stats(&conn, &TierThresholds::default()).unwrap();
+        assert_eq!(features.len(), 1);
+        assert_eq!(features[0].0.to_string(), tracked);
+        assert_eq!(features[0].1.tier, WalletTier::A);
+    }
+    
     #[test]
     fn test_tier_confidence() {
         assert_eq!(WalletTier::A.confidence(), 93);
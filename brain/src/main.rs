@@ -21,22 +21,26 @@ mod grpc_monitor;
 mod signature_tracker;
 mod telegram;
 mod bonding_curve;
+mod position_reconciler;
+mod manual_exit_listener;
+mod bot_performance;
 
 use anyhow::{Result, Context};
 use log::{info, warn, error, debug};
 use std::sync::Arc;
-use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use solana_sdk::pubkey::Pubkey;
 use std::str::FromStr;
 
 use config::Config;
-use udp_bus::{AdviceBusReceiver, DecisionBusSender, AdviceMessage, LateOpportunityAdvice, CopyTradeAdvice};
+use udp_bus::{AdviceBusReceiver, DecisionBusSender, AdviceMessage, CopyTradeAdvice, ExitAdvice, MomentumOpportunityAdvice, SolPriceUpdate};
 use feature_cache::{MintCache, WalletCache};
 use decision_engine::{
-    FollowThroughScorer, TradeValidator, Guardrails, DecisionLogger, DecisionLogEntry,
-    TriggerEngine, TriggerType,
+    FollowThroughScorer, EarlyScorer, TradeValidator, Guardrails, DecisionLogger, DecisionLogEntry,
+    TriggerEngine, TriggerType, slippage, EARLY_SCORE_MAX_AGE_SECS,
 };
+use decision_engine::triggers::EntryTrigger;
 
 // Type aliases for shorter names
 type Scorer = FollowThroughScorer;
@@ -45,17 +49,54 @@ type Validator = TradeValidator;
 /// Global SOL price in cents (e.g., 19444 = $194.44)
 static SOL_PRICE_CENTS: AtomicU32 = AtomicU32::new(19344); // Default $193.44
 
-/// Get current SOL price in USD
+/// Broadcast time (ms) of the price in SOL_PRICE_CENTS (0 = still the default)
+static SOL_PRICE_AT_MS: AtomicU64 = AtomicU64::new(0);
+
+/// Oldest oracle price accepted / considered fresh (SOL_PRICE_MAX_AGE_SECS)
+static SOL_PRICE_MAX_AGE_MS: AtomicU64 = AtomicU64::new(30_000);
+
+/// Set once a stale price has been reported, cleared by the next fresh update
+static SOL_PRICE_STALE: AtomicBool = AtomicBool::new(false);
+
+fn now_ms() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis() as u64
+}
+
+/// Get current SOL price in USD (warns once when the oracle feed has gone stale)
 fn get_sol_price_usd() -> f64 {
-    SOL_PRICE_CENTS.load(Ordering::Relaxed) as f64 / 100.0
+    let price = SOL_PRICE_CENTS.load(Ordering::Relaxed) as f64 / 100.0;
+    let at_ms = SOL_PRICE_AT_MS.load(Ordering::Relaxed);
+    let age_ms = now_ms().saturating_sub(at_ms);
+    if age_ms > SOL_PRICE_MAX_AGE_MS.load(Ordering::Relaxed) && !SOL_PRICE_STALE.swap(true, Ordering::Relaxed) {
+        if at_ms == 0 {
+            warn!("⏰ No SOL price from oracle yet - sizing with default ${:.2}", price);
+        } else {
+            warn!("⏰ SOL price STALE: no oracle update for {:.0}s - sizing with last ${:.2}",
+                  age_ms as f64 / 1000.0, price);
+        }
+    }
+    price
 }
 
-/// Update SOL price from oracle
-fn update_sol_price(price_usd: f32) {
+/// Update SOL price from oracle, ignoring out-of-order or too-old broadcasts
+fn update_sol_price(update: &SolPriceUpdate) {
+    let price_usd = update.price_usd;
+    let now = now_ms();
+    let max_age_ms = SOL_PRICE_MAX_AGE_MS.load(Ordering::Relaxed);
+    if !update.is_fresh(SOL_PRICE_AT_MS.load(Ordering::Relaxed), now, max_age_ms) {
+        warn!("⏭️  Ignoring SOL price ${:.2}: broadcast {}ms ago (max {}ms) or out of order",
+              price_usd, now.saturating_sub(update.broadcast_ms()), max_age_ms);
+        return;
+    }
     let cents = (price_usd * 100.0) as u32;
     SOL_PRICE_CENTS.store(cents, Ordering::Relaxed);
+    SOL_PRICE_AT_MS.store(update.broadcast_ms(), Ordering::Relaxed);
     metrics::update_sol_price(price_usd);
-    info!("💵 SOL price updated: ${:.2}", price_usd);
+    if SOL_PRICE_STALE.swap(false, Ordering::Relaxed) {
+        info!("✅ SOL price fresh again: ${:.2}", price_usd);
+    } else {
+        info!("💵 SOL price updated: ${:.2}", price_usd);
+    }
 }
 
 #[tokio::main]
@@ -79,7 +120,10 @@ async fn main() -> Result<()> {
     info!("✅ Metrics: Server started on port 9090");
     
     // Load configuration
-    let config = Arc::new(Config::from_env().context("Failed to load configuration")?);;
+    let config = Config::from_env().context("Failed to load configuration")?;
+    config.validate()?;
+    let config = Arc::new(config);
+    SOL_PRICE_MAX_AGE_MS.store(config.decision.sol_price_max_age_secs * 1000, Ordering::Relaxed);
     info!("✅ Configuration: Loaded");
     
     // Print startup banner
@@ -91,12 +135,39 @@ async fn main() -> Result<()> {
     // SQLite (LaunchTracker data)
     let sqlite_path = config.database.sqlite_path.clone();
     let sqlite_conn = tokio::task::spawn_blocking(move || {
-        rusqlite::Connection::open(&sqlite_path)
+        let conn = rusqlite::Connection::open(&sqlite_path)?;
+        // Wait out data-mining's writer lock instead of failing with SQLITE_BUSY
+        conn.busy_timeout(Duration::from_secs(2))?;
+        Ok::<_, rusqlite::Error>(conn)
     })
     .await?
     .context("Failed to open SQLite database")?;
     info!("✅ SQLite: Connected ({})", config.database.sqlite_path.display());
     
+    // Data-mining must aggregate every window interval the mint cache reads,
+    // otherwise those features silently stay at zero
+    match feature_cache::mint_cache::missing_window_intervals(&sqlite_conn, 3600) {
+        Ok(missing) if !missing.is_empty() => {
+            warn!("⚠️  No recent windows for intervals {:?}s - add them to data-mining [windows] intervals", missing);
+        }
+        Ok(_) => info!("✅ Window intervals: {:?}s present",
+            feature_cache::mint_cache::REQUIRED_WINDOW_INTERVALS),
+        Err(e) => warn!("⚠️  Could not verify window intervals: {}", e),
+    }
+    
+    // Our own closed positions (bot_trades), win rate per trigger so far
+    match bot_performance::ensure_schema(&sqlite_conn)
+        .and_then(|_| bot_performance::performance_by_trigger(&sqlite_conn))
+    {
+        Ok(stats) => {
+            for s in stats {
+                info!("🏁 Bot performance [{}]: {} positions, {:.1}% win rate, {:+.4} SOL realized",
+                      s.trigger, s.positions, s.win_rate() * 100.0, s.realized_pnl_sol);
+            }
+        }
+        Err(e) => warn!("⚠️  bot_trades unavailable, closed positions won't be recorded: {}", e),
+    }
+    
     // PostgreSQL (WalletTracker data) - Optional for now
     let pg_config = config.database.postgres_connection_string();
     let pg_client_opt = match tokio_postgres::connect(&pg_config, tokio_postgres::NoTls).await {
@@ -111,39 +182,73 @@ async fn main() -> Result<()> {
             Some(pg_client)
         }
         Err(e) => {
-            warn!("⚠️  PostgreSQL not available: {}. Wallet cache will be empty.", e);
-            warn!("   (This is OK for testing - only affects copy trade decisions)");
+            warn!("⚠️  PostgreSQL not available: {}. Wallet cache will load from SQLite wallet_stats.", e);
             None
         }
     };
     
     // Initialize feature caches
     info!("🗂️  Initializing feature caches...");
-    let mint_cache = Arc::new(MintCache::new(config.database.sqlite_path.to_string_lossy().to_string()));
-    let wallet_cache = Arc::new(WalletCache::new(config.database.postgres_connection_string()));
-    info!("✅ Caches: Initialized");
+    let mint_cache = Arc::new(MintCache::new(
+        config.database.sqlite_path.to_string_lossy().to_string(),
+        config.cache.mint_cache_capacity,
+    ));
+    let tiers = &config.wallet_tiers;
+    let wallet_cache = Arc::new(WalletCache::new(
+        config.database.sqlite_path.to_string_lossy().to_string(),
+        config.cache.wallet_cache_capacity,
+    ).with_tier_thresholds(feature_cache::TierThresholds {
+        min_trades: tiers.min_trades,
+        a_min_win_rate: tiers.tier_a_min_win_rate,
+        a_min_pnl_sol: tiers.tier_a_min_pnl_sol,
+        b_min_win_rate: tiers.tier_b_min_win_rate,
+        b_min_pnl_sol: tiers.tier_b_min_pnl_sol,
+        c_min_win_rate: tiers.tier_c_min_win_rate,
+        c_min_pnl_sol: tiers.tier_c_min_pnl_sol,
+    }));
+    info!("✅ Caches: Initialized (capacity: {} mints, {} wallets)",
+        config.cache.mint_cache_capacity, config.cache.wallet_cache_capacity);
     
     // Perform initial cache load immediately
     let sqlite_conn_arc = Arc::new(tokio::sync::Mutex::new(sqlite_conn));
     info!("📊 Performing initial mint cache load...");
-    if let Err(e) = update_mint_cache(&mint_cache, &sqlite_conn_arc).await {
-        warn!("⚠️  Initial mint cache load failed: {}", e);
-    } else {
-        info!("✅ Initial mint cache loaded ({} entries)", mint_cache.len());
-    }
+    let initial_watermark = match update_mint_cache(&mint_cache, &sqlite_conn_arc, None).await {
+        Ok(watermark) => {
+            info!("✅ Initial mint cache loaded ({} entries)", mint_cache.len());
+            Some(watermark)
+        }
+        Err(e) => {
+            warn!("⚠️  Initial mint cache load failed: {}", e);
+            None
+        }
+    };
     
-    // Start cache updater tasks
+    // Start cache updater tasks: incremental merges of changed mints, plus a
+    // periodic full scan to reconcile anything the incremental path missed
     let mint_cache_updater = mint_cache.clone();
     let sqlite_for_mint = sqlite_conn_arc.clone();
+    let incremental_interval = Duration::from_millis(config.cache.mint_cache_incremental_interval_ms);
+    let full_refresh_interval = Duration::from_secs(config.cache.mint_cache_full_refresh_secs);
     
     tokio::spawn(async move {
-        let mut interval = tokio::time::interval(Duration::from_secs(30));
+        let mut interval = tokio::time::interval(incremental_interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        let mut watermark = initial_watermark;
+        let mut last_full_refresh = std::time::Instant::now();
         loop {
             interval.tick().await;
-            if let Err(e) = update_mint_cache(&mint_cache_updater, &sqlite_for_mint).await {
-                warn!("⚠️  Mint cache update failed: {}", e);
-            } else {
-                info!("♻️  Mint cache updated ({} entries)", mint_cache_updater.len());
+            
+            // No watermark yet (failed load) or reconciliation due -> full scan
+            let since = watermark.filter(|_| last_full_refresh.elapsed() < full_refresh_interval);
+            match update_mint_cache(&mint_cache_updater, &sqlite_for_mint, since).await {
+                Ok(new_watermark) => {
+                    watermark = Some(new_watermark);
+                    if since.is_none() {
+                        last_full_refresh = std::time::Instant::now();
+                        mint_cache_updater.remove_stale(feature_cache::mint_cache::MINT_STALE_AFTER);
+                    }
+                }
+                Err(e) => warn!("⚠️  Mint cache update failed: {}", e),
             }
         }
     });
@@ -161,18 +266,34 @@ async fn main() -> Result<()> {
                 } else {
                     info!("♻️  Wallet cache updated ({} entries)", wallet_cache_updater.len());
                 }
+                wallet_cache_updater.remove_stale(feature_cache::wallet_cache::WALLET_STALE_AFTER);
             }
         });
-        info!("✅ Cache updaters: Started (30s interval)");
+        info!("✅ Cache updaters: Started (mint {}ms incremental / {}s full, wallet 30s)",
+            config.cache.mint_cache_incremental_interval_ms, config.cache.mint_cache_full_refresh_secs);
     } else {
-        info!("⚠️  Wallet cache updater: Skipped (PostgreSQL not available)");
-        info!("✅ Mint cache updater: Started (30s interval)");
+        // Fallback: data-mining's wallet_stats over the shared SQLite read connection
+        let sqlite_for_wallet = sqlite_conn_arc.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(30));
+            loop {
+                interval.tick().await;
+                match wallet_cache_updater.update_from_sqlite(&sqlite_for_wallet).await {
+                    Ok(count) => info!("♻️  Wallet cache updated from SQLite ({} wallets, {} entries)",
+                        count, wallet_cache_updater.len()),
+                    Err(e) => warn!("⚠️  Wallet cache SQLite update failed: {}", e),
+                }
+            }
+        });
+        info!("✅ Cache updaters: Started (mint {}ms incremental / {}s full, wallet 30s from SQLite)",
+            config.cache.mint_cache_incremental_interval_ms, config.cache.mint_cache_full_refresh_secs);
     }
     
     // Initialize decision engine components
     info!("🧠 Initializing decision engine...");
     let trigger_engine = TriggerEngine::new();
     let scorer = Scorer::new();
+    let early_scorer = EarlyScorer::new();
     let validator = Validator::new();
     
     // Configure guardrails from config
@@ -182,9 +303,11 @@ async fn main() -> Result<()> {
         loss_backoff_duration_secs: config.guardrails.loss_backoff_pause_secs,
         max_concurrent_positions: config.guardrails.max_concurrent_positions,
         max_advisor_positions: config.guardrails.max_advisor_positions,
+        max_total_exposure_sol: config.guardrails.max_total_exposure_sol,
         advisor_rate_limit_secs: config.guardrails.advisor_rate_limit_ms / 1000, // Convert ms to seconds
         min_decision_interval_ms: config.guardrails.rate_limit_ms,
         wallet_cooling_period_secs: config.guardrails.wallet_cooling_secs,
+        mint_loss_cooldown_secs: config.guardrails.mint_loss_cooldown_secs,
         tier_a_bypass_cooling: true, // Always allow Tier A bypass if profitable
         creator_trade_limit_window_secs: 60, // 1 minute window
         creator_trade_limit_count: 3, // Max 3 trades per creator per minute
@@ -229,6 +352,16 @@ async fn main() -> Result<()> {
     // Initialize position tracker
     let position_tracker = Arc::new(tokio::sync::RwLock::new(
         decision_engine::PositionTracker::new(config.guardrails.max_concurrent_positions)
+            .with_exit_slippage_bps(config.validation.exit_slippage_bps)
+            .with_exit_in_flight_timeout(Duration::from_secs(config.confirmation.confirm_timeout_sell_sec))
+            .with_volume_drop(config.decision.volume_drop_exit_ratio, config.decision.volume_drop_exit_percent)
+            .with_rug_exit(decision_engine::RugExitConfig {
+                reserve_drop_pct: config.decision.rug_reserve_drop_pct,
+                exit_on_creator_sell: config.decision.rug_exit_on_creator_sell,
+                top_seller_share: config.decision.rug_top_seller_share,
+            })
+            .with_profit_ladders(config.decision.profit_ladders.clone())
+            .with_trailing_stop_pct(config.decision.trailing_stop())
     ));
     info!("✅ Position tracker: Initialized (max: {})", config.guardrails.max_concurrent_positions);
     
@@ -251,15 +384,29 @@ async fn main() -> Result<()> {
     let position_sizer = Arc::new(decision_engine::PositionSizer::new(sizer_config));
     info!("✅ Position sizer: Initialized");
     
+    // Initialize execution quality tracker (fed by TxConfirmedContext on port 45115)
+    let execution_quality = Arc::new(decision_engine::ExecutionQualityTracker::new());
+    
     // Initialize UDP communication
     info!("📡 Setting up UDP communication...");
-    let advice_receiver = AdviceBusReceiver::new().await
-        .context("Failed to create Advice Bus receiver")?;
+    let advice_auth = udp_bus::auth::AdviceAuth::load(
+        config.network.advice_bus_secret.as_deref(),
+        &config.network.advice_bus_secret_file,
+    ).context("Failed to load Advice Bus secret")?;
+    let advice_receiver = match &config.network.advice_bus_unix_path {
+        Some(path) => AdviceBusReceiver::new_unix(path, advice_auth.clone()).await,
+        None => AdviceBusReceiver::new(advice_auth.clone()).await,
+    }
+    .context("Failed to create Advice Bus receiver")?;
     let target_addr = format!("127.0.0.1:{}", config.network.decision_bus_port)
         .parse()
         .context("Invalid decision bus address")?;
     let decision_sender = Arc::new(DecisionBusSender::new(target_addr).await
-        .context("Failed to create Decision Bus sender")?);
+        .context("Failed to create Decision Bus sender")?
+        .with_sell_retransmit(
+            Duration::from_millis(config.network.sell_retransmit_ms),
+            Duration::from_millis(config.network.sell_retransmit_timeout_ms),
+        ));
     info!("✅ UDP: Advice Bus (port {}), Decision Bus (port {})", 
           config.network.advice_bus_port, config.network.decision_bus_port);
     
@@ -371,6 +518,7 @@ async fn main() -> Result<()> {
                                     if let Some(mut features) = handler_mint_cache.get(&mint_pk) {
                                         features.current_price = new_price;
                                         features.mc_sol = mc_sol;
+                                        features.curve_sol_reserves = curve_state.virtual_sol_reserves as f64 / 1e9;
                                         features.last_update = std::time::SystemTime::now()
                                             .duration_since(std::time::UNIX_EPOCH)
                                             .unwrap()
@@ -383,6 +531,7 @@ async fn main() -> Result<()> {
                                             // Clone position data before dropping lock
                                             let pos_clone = pos.clone();
                                             let reason_clone = reason.clone();
+                                            let exit_slippage_bps = tracker.exit_slippage_bps();
                                             drop(tracker); // Release read lock before sending decision
                                             
                                             info!("🚨 gRPC EXIT SIGNAL: {} | reason: {} | price: {:.10} SOL",
@@ -391,6 +540,7 @@ async fn main() -> Result<()> {
                                             let exit_percent = match &reason_clone {
                                                 decision_engine::ExitReason::ProfitTarget { exit_percent, .. } => *exit_percent,
                                                 decision_engine::ExitReason::StopLoss { exit_percent, .. } => *exit_percent,
+                                                decision_engine::ExitReason::TrailingStop { exit_percent, .. } => *exit_percent,
                                                 decision_engine::ExitReason::TimeDecay { exit_percent, .. } => *exit_percent,
                                                 decision_engine::ExitReason::VolumeDrop { exit_percent, .. } => *exit_percent,
                                                 decision_engine::ExitReason::Emergency { exit_percent, .. } => *exit_percent,
@@ -436,23 +586,35 @@ async fn main() -> Result<()> {
                                             }
                                             
                                             // Create and send SELL decision
+                                            // Floor the fill at the fresh curve price less exit slippage
+                                            let min_sol_out = pos_clone.min_sol_out_lamports(
+                                                exit_percent, new_price, exit_slippage_bps,
+                                            );
                                             let sell_decision = crate::udp_bus::TradeDecision::new_sell(
                                                 *mint_bytes,
                                                 exit_size_lamports,
-                                                300, // 3% slippage for exits
+                                                exit_slippage_bps,
                                                 pos_clone.entry_confidence,
                                                 0, // retry_count
                                                 0, // entry_type
+                                                min_sol_out,
                                             );
                                             
                                             let sender = handler_decision_sender.clone();
+                                            let sell_tracker = handler_position_tracker.clone();
+                                            let sell_mint = mint_str.clone();
+                                            let sell_tier = reason_clone.profit_tier();
                                             tokio::spawn(async move {
                                                 if let Err(e) = sender.send_decision(&sell_decision).await {
                                                     warn!("❌ Failed to send gRPC SELL decision: {}", e);
                                                 } else {
-                                                    info!("✅ gRPC SELL DECISION SENT: {:.3} SOL ({}%)",
-                                                        exit_size_sol, exit_percent);
+                                                    info!("✅ gRPC SELL DECISION SENT: {:.3} SOL ({}%) | id {} | trace={}",
+                                                        exit_size_sol, exit_percent, sell_decision.decision_id_str(),
+                                                        udp_bus::trace_tag(&sell_decision.trace_id));
                                                     metrics::record_decision_sent();
+                                                    let mut tracker = sell_tracker.write().await;
+                                                    tracker.mark_sell_sent(&sell_mint);
+                                                    tracker.record_exit(&sell_mint, sell_tier, exit_percent);
                                                 }
                                             });
                                         }
@@ -477,6 +639,188 @@ async fn main() -> Result<()> {
         }
     });
     
+    // Spawn TxConfirmedContext listener: learn predicted vs realized fill price per trigger
+    // (also picks up Executor dry-run ExecutionConfirmations so simulated trades are flagged)
+    let context_position_tracker = position_tracker.clone();
+    let context_execution_quality = execution_quality.clone();
+    let context_guardrails = guardrails.clone();
+    let context_decision_sender = decision_sender.clone();
+    let context_sqlite = sqlite_conn_arc.clone();
+    tokio::spawn(async move {
+        let socket = match tokio::net::UdpSocket::bind("127.0.0.1:45115").await {
+            Ok(socket) => socket,
+            Err(e) => {
+                warn!("⚠️  TxConfirmedContext listener disabled: failed to bind port 45115: {}", e);
+                return;
+            }
+        };
+        info!("📐 TxConfirmedContext listener bound to 127.0.0.1:45115");
+        
+        let mut buf = [0u8; 1024];
+        loop {
+            let len = match socket.recv_from(&mut buf).await {
+                Ok((len, _)) => len,
+                Err(e) => {
+                    error!("❌ TxConfirmedContext receive error: {}", e);
+                    continue;
+                }
+            };
+
+            // ExecutionConfirmations: release in-flight SELLs; dry-run fills keep
+            // tracking the trade, but flag it as not real; circuit-open pauses entries
+            if buf[0] == udp_bus::ExecutionConfirmation::MSG_TYPE {
+                match udp_bus::ExecutionConfirmation::from_bytes(&buf[..len]) {
+                    Ok(conf) => {
+                        let mint_str = conf.mint_bs58();
+                        info!("📬 ExecutionConfirmation: {} {} for {} | trace={}",
+                              if conf.is_buy() { "BUY" } else { "SELL" },
+                              if conf.is_success() { "OK" } else { "FAILED" },
+                              &mint_str[..8], udp_bus::trace_tag(&conf.trace_id));
+                        
+                        // SELL reached the Executor: stop retransmitting it
+                        if !conf.is_buy() && context_decision_sender.acknowledge(&conf.trace_id) {
+                            debug!("📪 SELL trace={} acknowledged", udp_bus::trace_tag(&conf.trace_id));
+                        }
+                        
+                        // Executor circuit breaker open: pause entries for its cooldown
+                        if conf.is_circuit_open() {
+                            context_guardrails.pause_for_execution_circuit(conf.circuit_cooldown_secs as u64);
+                        }
+                        
+                        let mut tracker = context_position_tracker.write().await;
+                        
+                        // BUY fill: replace the size/price estimate with the curve quote
+                        if conf.is_buy() && conf.is_success() {
+                            if let (Some((tokens, price)), Some(position)) =
+                                (conf.entry_quote(), tracker.get_position_mut(&mint_str))
+                            {
+                                if position.apply_entry_quote(tokens, price) {
+                                    debug!("🎯 Entry quote for {}: {:.0} tokens @ {:.10} SOL",
+                                           &mint_str[..8], tokens, price);
+                                }
+                            }
+                        }
+                        
+                        // Losing exit: keep the next signal from buying straight back in
+                        if !conf.is_buy() && conf.is_success() {
+                            if let Some(position) = tracker.exit_basis(&mint_str) {
+                                let exit_price = conf.executed_price_sol();
+                                if exit_price > 0.0 && exit_price < position.entry_price_sol {
+                                    context_guardrails.record_mint_loss(&conf.mint);
+                                    info!("🧊 Losing exit on {} ({:.10} → {:.10} SOL): mint cooldown started",
+                                          &mint_str[..8], position.entry_price_sol, exit_price);
+                                }
+                            }
+                        }
+                        
+                        // Book realized P&L for our own performance tracking (bot_trades)
+                        if let Some(trade) = tracker.exit_basis(&mint_str)
+                            .and_then(|position| bot_performance::ClosedTrade::from_sell(position, &conf))
+                        {
+                            info!("🏁 Closed {} [{}]: {:.4} SOL @ {:.10} → {:.10} SOL = {:+.4} SOL{}",
+                                  &mint_str[..8], trade.trigger, trade.size_sol, trade.entry_price_sol,
+                                  trade.exit_price_sol, trade.realized_pnl_sol,
+                                  if trade.dry_run { " (dry-run)" } else { "" });
+                            let sqlite = context_sqlite.clone();
+                            tokio::task::spawn_blocking(move || {
+                                let conn = sqlite.blocking_lock();
+                                if let Err(e) = bot_performance::record_closed_trade(&conn, &trade) {
+                                    warn!("⚠️  Failed to record closed trade for {}: {}", &trade.mint[..8], e);
+                                }
+                            });
+                        }
+                        
+                        // Any SELL outcome ends the in-flight exit so the monitor can act again
+                        if !conf.is_buy() && tracker.clear_sell_in_flight(&mint_str) {
+                            debug!("🔓 SELL {} for {}: exit no longer in flight",
+                                   if conf.is_success() { "confirmed" } else { "failed" }, &mint_str[..8]);
+                        }
+                        
+                        if conf.is_dry_run() && conf.is_success() {
+                            if let Some(position) = tracker.get_position_mut(&mint_str) {
+                                position.dry_run = true;
+                            }
+                            info!("🧪 Dry-run {} confirmed for {}: {:.4} SOL @ {:.10} SOL (simulated, not submitted)",
+                                  if conf.is_buy() { "BUY" } else { "SELL" }, &mint_str[..8],
+                                  conf.executed_size_sol(), conf.executed_price_sol());
+                        }
+                    }
+                    Err(e) => debug!("Ignoring malformed ExecutionConfirmation: {}", e),
+                }
+                continue;
+            }
+
+            let ctx = match udp_bus::TxConfirmedContext::from_bytes(&buf[..len]) {
+                Ok(ctx) => ctx,
+                Err(e) => {
+                    debug!("Ignoring non-context message on 45115 ({} bytes): {}", len, e);
+                    continue;
+                }
+            };
+            
+            let mint_str = ctx.mint_str();
+            debug!("📐 TxConfirmedContext: {} {} {} | Δ{}ms buyers+{} buy {:.2}/sell {:.2} SOL {:+.1}%",
+                   ctx.side_str(), ctx.status_str(), &mint_str[..8], ctx.trail_ms,
+                   ctx.uniq_buyers_delta, ctx.vol_buy_sol(), ctx.vol_sell_sol(),
+                   ctx.price_change_percent());
+            
+            if !ctx.is_buy() || !ctx.is_success() {
+                continue;
+            }
+            
+            // Predicted price = price the BUY decision was made at
+            let position = context_position_tracker.read().await.get_position(&mint_str).cloned();
+            let Some(position) = position else {
+                debug!("📐 No tracked position for confirmed BUY {}", &mint_str[..8]);
+                continue;
+            };
+            if position.dry_run {
+                continue;
+            }
+            
+            let realized_price_sol = ctx.entry_price_lamports as f64 / 1e9;
+            let trigger = position.entry_path;
+            if let Some(delta_bps) = context_execution_quality.record(
+                trigger,
+                position.entry_price_sol,
+                realized_price_sol,
+            ) {
+                let stats = context_execution_quality.stats(trigger);
+                info!("📐 Fill quality [{}] {}: predicted {:.10} → realized {:.10} SOL ({:+.0} bps) | avg {:+.0} bps over {} fills, size x{:.2}",
+                      trigger.as_str(), &mint_str[..8],
+                      position.entry_price_sol, realized_price_sol, delta_bps,
+                      stats.map(|s| s.mean_delta_bps).unwrap_or(delta_bps),
+                      stats.map(|s| s.samples).unwrap_or(1),
+                      context_execution_quality.size_multiplier(trigger));
+            }
+        }
+    });
+    
+    // Spawn ManualExit listener: mempool-watcher saw a confirmed SELL we didn't send
+    // (user exited from their wallet), so drop the position to keep the count that
+    // gates new entries accurate
+    let manual_exit_position_tracker = position_tracker.clone();
+    let manual_exit_addr = format!("{}:{}", config.network.udp_bind_address, config.network.manual_exit_port);
+    let manual_exit_auth = advice_auth.clone();
+    tokio::spawn(async move {
+        let mut manual_exits = match manual_exit_listener::ManualExitListener::listen(&manual_exit_addr, manual_exit_auth).await {
+            Ok(rx) => rx,
+            Err(e) => {
+                warn!("⚠️  ManualExit listener disabled: {}", e);
+                return;
+            }
+        };
+        
+        while let Some(mint_str) = manual_exits.recv().await {
+            let mut tracker = manual_exit_position_tracker.write().await;
+            match tracker.remove_position(&mint_str) {
+                Some(position) => info!("🧹 Manual exit: closed {} ({:.4} SOL) | {} positions open",
+                                        &mint_str[..8], position.size_sol, tracker.count()),
+                None => debug!("🧹 Manual exit for untracked position {}", &mint_str[..8]),
+            }
+        }
+    });
+    
     // Spawn position monitoring task
     let position_tracker_monitor = position_tracker.clone();
     let mint_cache_monitor = mint_cache.clone();
@@ -488,9 +832,21 @@ async fn main() -> Result<()> {
         loop {
             interval.tick().await;
             
+            // Roll each position's 5s volume / curve reserve peaks forward (volume-drop and rug exits)
+            {
+                let mut tracker = position_tracker_monitor.write().await;
+                let mints: Vec<String> = tracker.get_all().iter().map(|p| p.mint.clone()).collect();
+                for mint in mints {
+                    if let Some(features) = mint.parse::<Pubkey>().ok().and_then(|pk| mint_cache_monitor.get(&pk)) {
+                        tracker.update_peaks(&mint, &features);
+                    }
+                }
+            }
+            
             // Check all active positions
             let tracker = position_tracker_monitor.read().await;
             let positions = tracker.get_all();
+            let mut sells_sent = Vec::new();
             
             for pos in positions {
                 // Parse mint from bs58 string to Pubkey
@@ -534,6 +890,7 @@ async fn main() -> Result<()> {
                                 let exit_percent = match &reason {
                                     decision_engine::ExitReason::ProfitTarget { exit_percent, .. } => *exit_percent,
                                     decision_engine::ExitReason::StopLoss { exit_percent, .. } => *exit_percent,
+                                    decision_engine::ExitReason::TrailingStop { exit_percent, .. } => *exit_percent,
                                     decision_engine::ExitReason::TimeDecay { exit_percent, .. } => *exit_percent,
                                     decision_engine::ExitReason::VolumeDrop { exit_percent, .. } => *exit_percent,
                                     decision_engine::ExitReason::Emergency { exit_percent, .. } => *exit_percent,
@@ -581,22 +938,29 @@ async fn main() -> Result<()> {
                                 });
                                 
                                 // Create SELL decision
+                                let exit_slippage_bps = tracker.exit_slippage_bps();
+                                let min_sol_out = position.min_sol_out_lamports(
+                                    exit_percent, current_price, exit_slippage_bps,
+                                );
                                 let sell_decision = crate::udp_bus::TradeDecision::new_sell(
                                     mint_bytes,
                                     exit_size_lamports,
-                                    300, // 3% slippage for exits (wider)
+                                    exit_slippage_bps,
                                     position.entry_confidence,
                                     0, // retry_count (first attempt)
                                     0, // entry_type (0 = rank-based, will be from position in future)
+                                    min_sol_out,
                                 );
                                 
                                 // Send to executor
                                 if let Err(e) = decision_sender_monitor.send_decision(&sell_decision).await {
                                     warn!("❌ Failed to send SELL decision: {}", e);
                                 } else {
-                                    info!("✅ SELL DECISION SENT: {} ({:.3} SOL, {}%)", 
-                                          &pos.mint[..8], exit_size_sol, exit_percent);
+                                    info!("✅ SELL DECISION SENT: {} ({:.3} SOL, {}%) | id {} | trace={}", 
+                                          &pos.mint[..8], exit_size_sol, exit_percent, sell_decision.decision_id_str(),
+                                          udp_bus::trace_tag(&sell_decision.trace_id));
                                     metrics::record_decision_sent();
+                                    sells_sent.push((pos.mint.clone(), reason.profit_tier(), exit_percent));
                                     
                                     // Send Telegram notification for SELL
                                     if let Some(tg) = telegram_client_monitor.as_ref() {
@@ -636,9 +1000,48 @@ async fn main() -> Result<()> {
                     }
                 }
             }
+            drop(tracker);
+            
+            // Mark SELLs in flight so reconciliation doesn't treat them as drift,
+            // then book the exit so partial targets don't re-fire every cycle
+            if !sells_sent.is_empty() {
+                let mut tracker = position_tracker_monitor.write().await;
+                for (mint, tier, exit_percent) in &sells_sent {
+                    tracker.mark_sell_sent(mint);
+                    tracker.record_exit(mint, *tier, *exit_percent);
+                }
+            }
         }
     });
     
+    // Spawn Brain/on-chain position reconciliation task
+    if config.network.wallet_pubkey.is_empty() {
+        warn!("⚠️  WALLET_PUBKEY not set - position reconciliation disabled");
+    } else {
+        match position_reconciler::PositionReconciler::new(
+            config.network.rpc_url.clone(),
+            &config.network.wallet_pubkey,
+            config.confirmation.stale_state_threshold_sec,
+            config.confirmation.confirm_timeout_sell_sec,
+        ) {
+            Ok(mut reconciler) => {
+                let position_tracker_reconcile = position_tracker.clone();
+                let interval_secs = config.confirmation.reconciliation_interval_sec.max(1);
+                info!("🔍 Position reconciliation enabled (every {}s)", interval_secs);
+                tokio::spawn(async move {
+                    let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(interval_secs));
+                    loop {
+                        interval.tick().await;
+                        if let Err(e) = reconciler.reconcile(&position_tracker_reconcile).await {
+                            warn!("⚠️  Position reconciliation failed: {}", e);
+                        }
+                    }
+                });
+            }
+            Err(e) => warn!("⚠️  Position reconciliation disabled: {}", e),
+        }
+    }
+    
     // Main decision loop
     while let Some(advice) = advice_rx.recv().await {
         // Record that we received an advice message
@@ -650,7 +1053,7 @@ async fn main() -> Result<()> {
         // Handle different advice types
         match advice {
             AdviceMessage::SolPriceUpdate(price) => {
-                update_sol_price(price.price_usd);
+                update_sol_price(&price);
                 // Not a trading decision
             }
             
@@ -659,19 +1062,105 @@ async fn main() -> Result<()> {
                 // TODO: Implement position management logic
             }
             
+            AdviceMessage::RankOpportunity(ref rank) => {
+                info!("🏆 Rank opportunity: {} (rank #{}, score {})", hex::encode(&rank.mint[..4]), rank.rank, rank.score);
+                
+                if let Err(e) = process_launch_opportunity(
+                    rank.mint,
+                    rank.score,
+                    EntryTrigger::RankBased,
+                    &mint_cache,
+                    &scorer,
+                    &early_scorer,
+                    &validator,
+                    &mut guardrails,
+                    &logger,
+                    &decision_sender,
+                    &position_tracker,
+                    &position_sizer,
+                    &execution_quality,
+                    &lifecycle_logger,
+                    &telegram_client,
+                    &config,
+                ).await {
+                    warn!("⚠️  Failed to process rank opportunity: {}", e);
+                }
+            }
+            
+            AdviceMessage::HotlistOpportunity(ref hot) => {
+                let hot_rank = hot.rank;
+                info!("🔥 Hotlist opportunity: {} (score {:.1}/15, #{} this cycle)", hex::encode(&hot.mint[..4]), hot.score(), hot_rank);
+                
+                if let Err(e) = process_launch_opportunity(
+                    hot.mint,
+                    (hot.score() * 10.0).min(255.0) as u8, // x10 so the 0-15 fallback early score is preserved
+                    EntryTrigger::Hotlist,
+                    &mint_cache,
+                    &scorer,
+                    &early_scorer,
+                    &validator,
+                    &mut guardrails,
+                    &logger,
+                    &decision_sender,
+                    &position_tracker,
+                    &position_sizer,
+                    &execution_quality,
+                    &lifecycle_logger,
+                    &telegram_client,
+                    &config,
+                ).await {
+                    warn!("⚠️  Failed to process hotlist opportunity: {}", e);
+                }
+            }
+            
+            AdviceMessage::MomentumOpportunity(ref momentum) => {
+                // Packed struct: copy multi-byte fields out before formatting
+                let buyers_2s = momentum.buyers_2s;
+                let vol_5s_sol = momentum.vol_5s_sol();
+                info!("⚡ Momentum opportunity: {} (buyers_2s {}, vol_5s {:.2} SOL, score {})",
+                      hex::encode(&momentum.mint[..4]), buyers_2s, vol_5s_sol, momentum.score);
+                
+                apply_momentum_advice(&mint_cache, momentum);
+                
+                if let Err(e) = process_launch_opportunity(
+                    momentum.mint,
+                    momentum.score,
+                    EntryTrigger::Momentum,
+                    &mint_cache,
+                    &scorer,
+                    &early_scorer,
+                    &validator,
+                    &mut guardrails,
+                    &logger,
+                    &decision_sender,
+                    &position_tracker,
+                    &position_sizer,
+                    &execution_quality,
+                    &lifecycle_logger,
+                    &telegram_client,
+                    &config,
+                ).await {
+                    warn!("⚠️  Failed to process momentum opportunity: {}", e);
+                }
+            }
+            
             AdviceMessage::LateOpportunity(ref late) => {
                 info!("🎯 Late opportunity: {}", hex::encode(&late.mint[..4]));
                 
-                if let Err(e) = process_late_opportunity(
-                    late,
+                if let Err(e) = process_launch_opportunity(
+                    late.mint,
+                    late.follow_through_score,
+                    EntryTrigger::LateOpportunity,
                     &mint_cache,
                     &scorer,
+                    &early_scorer,
                     &validator,
                     &mut guardrails,
                     &logger,
                     &decision_sender,
                     &position_tracker,
                     &position_sizer,
+                    &execution_quality,
                     &lifecycle_logger,
                     &telegram_client,
                     &config,
@@ -694,6 +1183,7 @@ async fn main() -> Result<()> {
                     &decision_sender,
                     &position_tracker,
                     &position_sizer,
+                    &execution_quality,
                     &lifecycle_logger,
                     &telegram_client,
                     &config,
@@ -731,6 +1221,7 @@ async fn main() -> Result<()> {
                     &decision_sender,
                     &position_tracker,
                     &position_sizer,
+                    &execution_quality,
                     &lifecycle_logger,
                     &telegram_client,
                     &config,
@@ -772,6 +1263,33 @@ async fn main() -> Result<()> {
                 }
             }
             
+            AdviceMessage::CurveComplete(ref complete) => {
+                // Curve filled: migration volatility is coming, don't sit through it
+                let mint_str = bs58::encode(&complete.mint).into_string();
+                if config.decision.curve_complete_exit_secs > 0
+                    && position_tracker.write().await.cap_remaining_hold(&mint_str, config.decision.curve_complete_exit_secs)
+                {
+                    info!("🎓 Curve complete for held {}: exiting within {}s",
+                          &mint_str[..8], config.decision.curve_complete_exit_secs);
+                }
+            }
+            
+            AdviceMessage::ExitAdvice(ref exit) => {
+                info!("🚨 Exit advice: {} ({})", &exit.mint_str()[..8], exit.reason_str());
+                
+                if let Err(e) = process_exit_advice(
+                    exit,
+                    &mint_cache,
+                    &logger,
+                    &decision_sender,
+                    &position_tracker,
+                    &lifecycle_logger,
+                    &telegram_client,
+                ).await {
+                    warn!("⚠️  Failed to process exit advice: {}", e);
+                }
+            }
+            
             // Handle all other AdviceMessage variants (not yet implemented)
             _ => {
                 debug!("Received unhandled advice type");
@@ -782,17 +1300,44 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
-/// Process a late opportunity advice message
-async fn process_late_opportunity(
-    late: &LateOpportunityAdvice,
+/// Refresh a cached mint's short-window activity from momentum advice
+/// 
+/// The advisory's buyers_2s / vol_5s are fresher than the cache, so the momentum
+/// path scores on them. Uncached mints are left alone: a bare entry would skip
+/// the not-in-cache rejection.
+fn apply_momentum_advice(mint_cache: &MintCache, momentum: &MomentumOpportunityAdvice) {
+    let mint = Pubkey::new_from_array(momentum.mint);
+    if mint_cache.contains(&mint) {
+        mint_cache.update_from_signal(
+            mint,
+            None, // vol_60s_sol
+            None, // buyers_60s
+            None, // age_seconds
+            None, // follow_through_score
+            Some(momentum.buyers_2s as u32),
+            Some(momentum.vol_5s_sol()),
+        );
+    }
+}
+
+/// Process a new-launch entry (Rank, Hotlist, Momentum or Late opportunity advice)
+/// 
+/// Confidence comes from `EarlyScorer` for tokens younger than
+/// `EARLY_SCORE_MAX_AGE_SECS` and from `FollowThroughScorer` otherwise.
+async fn process_launch_opportunity(
+    mint_bytes: [u8; 32],
+    advisor_score: u8,
+    trigger: EntryTrigger,
     mint_cache: &MintCache,
     scorer: &FollowThroughScorer,
+    early_scorer: &EarlyScorer,
     validator: &TradeValidator,
     guardrails: &mut Guardrails,
     logger: &DecisionLogger,
     sender: &Arc<DecisionBusSender>,
     position_tracker: &Arc<tokio::sync::RwLock<decision_engine::PositionTracker>>,
     position_sizer: &Arc<decision_engine::PositionSizer>,
+    execution_quality: &Arc<decision_engine::ExecutionQualityTracker>,
     lifecycle_logger: &Arc<tokio::sync::Mutex<position_lifecycle_logger::PositionLifecycleLogger>>,
     telegram_client: &Option<Arc<telegram::TelegramClient>>,
     config: &Config,
@@ -801,8 +1346,21 @@ async fn process_late_opportunity(
     
     metrics::record_decision_pathway(DecisionPathway::NewLaunch);
     
+    let (trigger_type, trigger_source, trigger_label) = match trigger {
+        EntryTrigger::RankBased => (TriggerType::Rank, "rank_opportunity", "Rank Opportunity"),
+        EntryTrigger::Hotlist => (TriggerType::Hotlist, "hotlist_opportunity", "Hotlist Opportunity"),
+        EntryTrigger::Momentum => (TriggerType::Momentum, "momentum_opportunity", "Momentum Opportunity"),
+        _ => (TriggerType::LateOpportunity, "late_opportunity", "Late Opportunity"),
+    };
+    
     // Convert mint bytes to Pubkey
-    let mint = Pubkey::new_from_array(late.mint);
+    let mint = Pubkey::new_from_array(mint_bytes);
+    
+    if let Some(why) = config.decision.mint_list_rejection(&mint.to_string()) {
+        info!("🚷 {} rejected: {} {}", trigger_label, &mint.to_string()[..8], why);
+        metrics::record_decision_rejected(DecisionPathway::NewLaunch, trigger, RejectionReason::MintList);
+        return Ok(());
+    }
     
     // 1. Lookup mint features from cache
     let mint_features = match mint_cache.get(&mint) {
@@ -812,50 +1370,77 @@ async fn process_late_opportunity(
         }
         None => {
             metrics::record_cache_access(metrics::CacheType::Mint, false);
-            warn!("❌ Mint not in cache: {}", hex::encode(&late.mint[..4]));
-            metrics::record_decision_rejected(RejectionReason::Validation);
+            warn!("❌ Mint not in cache: {}", hex::encode(&mint_bytes[..4]));
+            metrics::record_decision_rejected(DecisionPathway::NewLaunch, trigger, RejectionReason::Validation);
             return Ok(());
         }
     };
     
     // Check if data is stale
     if mint_features.is_stale() {
-        warn!("⏱️  Stale data for mint: {}", hex::encode(&late.mint[..4]));
-        metrics::record_decision_rejected(RejectionReason::Validation);
+        warn!("⏱️  Stale data for mint: {}", hex::encode(&mint_bytes[..4]));
+        metrics::record_decision_rejected(DecisionPathway::NewLaunch, trigger, RejectionReason::Validation);
         return Ok(());
     }
     
-    // 2. Score the opportunity
-    let score_components = scorer.calculate(&mint_features);
-    let confidence = score_components.total_score;
+    // 2. Score the opportunity (scorer depends on token age band)
+    let (confidence, early_score) = if mint_features.age_since_launch < EARLY_SCORE_MAX_AGE_SECS {
+        let early = early_scorer.score_features(&mint_features);
+        info!("📊 Early score: {:.1}/15 → conf {} (age {}s | {})",
+              early.total,
+              early.decision_confidence(),
+              mint_features.age_since_launch,
+              early.breakdown);
+        (early.decision_confidence(), early.total)
+    } else {
+        let score_components = scorer.calculate(&mint_features);
+        info!("📊 Score: {} (buyers={}, vol={}, quality={})",
+              score_components.total_score,
+              score_components.buyer_score,
+              score_components.volume_score,
+              score_components.wallet_quality_score);
+        (score_components.total_score, advisor_score as f64 / 10.0) // advisor score on 0-15 scale
+    };
     
-    info!("📊 Score: {} (buyers={}, vol={}, quality={})",
-          confidence,
-          score_components.buyer_score,
-          score_components.volume_score,
-          score_components.wallet_quality_score);
-    
-    // Check minimum confidence threshold
-    if confidence < config.decision.min_decision_conf {
-        info!("🚫 Below confidence threshold: {} < {}", confidence, config.decision.min_decision_conf);
-        metrics::record_decision_rejected(RejectionReason::LowConfidence);
+    // Check this path's minimum confidence threshold
+    let min_conf = config.decision.min_conf_for(trigger);
+    if confidence < min_conf {
+        info!("🚫 Below {} confidence threshold: {} < {}", trigger.as_str(), confidence, min_conf);
+        metrics::record_decision_rejected(DecisionPathway::NewLaunch, trigger, RejectionReason::LowConfidence);
         return Ok(());
     }
     
     // 3. Calculate position size dynamically
     let tracker = position_tracker.read().await;
     let active_count = tracker.count();
+    let at_limit = tracker.is_full();
     let total_exposure = tracker.get_all()
         .iter()
         .map(|p| p.size_sol)
         .sum::<f64>();
     drop(tracker); // Release lock
     
-    let position_size_sol = position_sizer.calculate_size(
+    // At the position limit: reject here, the sizer only scales down
+    if at_limit {
+        info!("🛡️  Blocked by guardrails: Max positions reached: {}/{}",
+              active_count, config.guardrails.max_concurrent_positions);
+        metrics::record_guardrail_block(metrics::GuardrailType::PositionLimit);
+        metrics::record_decision_rejected(DecisionPathway::NewLaunch, trigger, RejectionReason::Guardrails);
+        return Ok(());
+    }
+    
+    // Shrink size if this trigger's recent fills landed well above predicted price
+    // (applied inside the sizer, ahead of its min/max clamp)
+    let fill_multiplier = execution_quality.size_multiplier(trigger);
+    if fill_multiplier < 1.0 {
+        debug!("📐 Execution quality size adjustment: x{:.2}", fill_multiplier);
+    }
+    let position_size_sol = position_sizer.calculate_size_scaled(
         confidence,
         active_count,
         config.guardrails.max_concurrent_positions,
         total_exposure,
+        fill_multiplier,
     );
     let position_size_usd = position_size_sol * get_sol_price_usd();
     let position_size_lamports = (position_size_sol * 1e9) as u64;
@@ -871,23 +1456,35 @@ async fn process_late_opportunity(
     // Check portfolio heat before proceeding
     if let Err(e) = position_sizer.check_portfolio_heat(total_exposure, position_size_sol) {
         warn!("🔥 {}", e);
-        metrics::record_decision_rejected(RejectionReason::Guardrails);
+        metrics::record_decision_rejected(DecisionPathway::NewLaunch, trigger, RejectionReason::Guardrails);
+        return Ok(());
+    }
+    
+    // Hard cap on total open exposure
+    if let Err(reason) = guardrails.check_exposure(total_exposure, position_size_sol) {
+        info!("🛡️  Blocked by guardrails: {}", reason);
+        metrics::record_guardrail_block(metrics::GuardrailType::PositionLimit);
+        metrics::record_decision_rejected(DecisionPathway::NewLaunch, trigger, RejectionReason::Guardrails);
         return Ok(());
     }
     
-    // 4. Validate the trade
+    // 4. Validate the trade (slippage sized to position and curve depth)
+    let slippage_bps = slippage::estimate_bps(position_size_sol, mint_features.curve_depth_proxy);
+    debug!("📉 Slippage: {} bps (size {:.3} SOL, depth {})",
+           slippage_bps, position_size_sol, mint_features.curve_depth_proxy);
+    
     let validated = match validator.validate(
         mint,
         &mint_features,
         position_size_usd,
-        150, // 1.5% slippage
+        slippage_bps,
         confidence,
         None, // No creator check for now
     ) {
         Ok(v) => v,
         Err(e) => {
             info!("❌ Validation failed: {}", e);
-            metrics::record_decision_rejected(RejectionReason::Validation);
+            metrics::record_decision_rejected(DecisionPathway::NewLaunch, trigger, RejectionReason::Validation);
             return Ok(());
         }
     };
@@ -899,28 +1496,28 @@ async fn process_late_opportunity(
     
     // 5. Check guardrails
     if let Err(reason) = guardrails.check_decision_allowed(
-        3, // late opportunity
-        &late.mint,
+        trigger.entry_type(),
+        &mint_bytes,
         None,
         None,
-        None, // No creator wallet for late opportunity
+        None, // No creator wallet for launch opportunities
     ) {
         info!("🛡️  Blocked by guardrails: {}", reason);
         metrics::record_guardrail_block(metrics::GuardrailType::RateLimit);
-        metrics::record_decision_rejected(RejectionReason::Guardrails);
+        metrics::record_decision_rejected(DecisionPathway::NewLaunch, trigger, RejectionReason::Guardrails);
         return Ok(());
     }
     
     // Record decision with guardrails for tracking
-    guardrails.record_decision(3, &late.mint, None, None);
+    guardrails.record_decision(trigger.entry_type(), &mint_bytes, None, None);
     
     // 6. Build trade decision
     let decision = udp_bus::TradeDecision::new_buy(
-        late.mint,
+        mint_bytes,
         position_size_lamports,
-        150, // 1.5% slippage
+        slippage_bps,
         confidence,
-        3, // entry_type: 3 = LateOpportunity
+        trigger.entry_type(),
     );
     
     // 7. Log decision
@@ -930,8 +1527,8 @@ async fn process_late_opportunity(
             .duration_since(UNIX_EPOCH)
             .unwrap_or_default()
             .as_secs(),
-        mint: hex::encode(&late.mint),
-        trigger_type: TriggerType::LateOpportunity,
+        mint: hex::encode(&mint_bytes),
+        trigger_type,
         side: 0, // BUY
         predicted_fees_usd: validated.estimated_fees_usd,
         predicted_impact_usd: validated.estimated_impact_pct,
@@ -942,16 +1539,17 @@ async fn process_late_opportunity(
         confidence,
         expected_ev_usd: validated.expected_value_usd,
         success_probability: 0.0, // TODO: Calculate from validator
-        rank: Some(late.follow_through_score),
+        rank: Some(advisor_score),
         wallet: None,
         wallet_tier: None,
+        slippage_bps: Some(slippage_bps),
     };
     
     logger.log_decision(log_entry)?;
     
     // Log lifecycle event: BUY decision
     let lifecycle_logger_clone = lifecycle_logger.clone();
-    let mint_str = bs58::encode(&late.mint).into_string();
+    let mint_str = bs58::encode(&mint_bytes).into_string();
     let entry_price = mint_features.current_price;
     tokio::spawn(async move {
         lifecycle_logger_clone.lock().await.log_event(
@@ -961,7 +1559,7 @@ async fn process_late_opportunity(
                 size_usd: position_size_usd,
                 confidence,
                 entry_price_sol: entry_price,
-                trigger_source: "late_opportunity".to_string(),
+                trigger_source: trigger_source.to_string(),
             }
         );
     });
@@ -969,20 +1567,21 @@ async fn process_late_opportunity(
     // 8. Send to executor
     sender.send_decision(&decision).await?;
     metrics::record_decision_sent();
-    metrics::record_decision_approved();
+    metrics::record_decision_approved(DecisionPathway::NewLaunch, trigger);
     
     // Send Telegram notification for BUY
     if let Some(tg) = telegram_client {
-        let mint_str = bs58::encode(&late.mint).into_string();
+        let mint_str = bs58::encode(&mint_bytes).into_string();
         let tg_clone = tg.clone();
         tokio::spawn(async move {
             let msg = format!(
-                "🟢 BUY DECISION\n\nMint: {}\nSize: {:.4} SOL (${:.2})\nPrice: {:.10} SOL\nConfidence: {}/100\nTrigger: Late Opportunity",
+                "🟢 BUY DECISION\n\nMint: {}\nSize: {:.4} SOL (${:.2})\nPrice: {:.10} SOL\nConfidence: {}/100\nTrigger: {}",
                 &mint_str[..12],
                 position_size_sol,
                 position_size_usd,
                 entry_price,
-                confidence
+                confidence,
+                trigger_label
             );
             if let Err(e) = tg_clone.send_message(&msg).await {
                 warn!("Failed to send Telegram notification: {}", e);
@@ -992,7 +1591,7 @@ async fn process_late_opportunity(
     
     // Log lifecycle event: BUY TX sent
     let lifecycle_logger_clone2 = lifecycle_logger.clone();
-    let mint_str2 = bs58::encode(&late.mint).into_string();
+    let mint_str2 = bs58::encode(&mint_bytes).into_string();
     tokio::spawn(async move {
         lifecycle_logger_clone2.lock().await.log_event(
             position_lifecycle_logger::LifecycleEvent::BuyTxSent {
@@ -1002,14 +1601,15 @@ async fn process_late_opportunity(
         );
     });
     
-    info!("✅ DECISION SENT: BUY {} ({} SOL, conf={})",
-          hex::encode(&late.mint[..8]),
+    info!("✅ DECISION SENT: BUY {} ({} SOL, conf={}) | trace={}",
+          hex::encode(&mint_bytes[..8]),
           position_size_sol,
-          confidence);
+          confidence,
+          udp_bus::trace_tag(&decision.trace_id));
     
     // 9. Track position for exit monitoring
     let entry_position = decision_engine::ActivePosition {
-        mint: bs58::encode(&late.mint).into_string(),
+        mint: bs58::encode(&mint_bytes).into_string(),
         entry_time: std::time::Instant::now(),
         entry_timestamp: std::time::SystemTime::now()
             .duration_since(std::time::UNIX_EPOCH)
@@ -1018,22 +1618,29 @@ async fn process_late_opportunity(
         size_sol: position_size_sol,
         size_usd: position_size_usd,
         entry_price_sol: mint_features.current_price,
-        tokens: (position_size_sol / mint_features.current_price) * 0.99, // Account for slippage
+        tokens: (position_size_sol / mint_features.current_price) * 0.99, // Estimate until Executor's curve quote arrives
         entry_confidence: confidence,
-        entry_path: decision_engine::triggers::EntryTrigger::RankBased,
-        early_score: late.follow_through_score as f64 / 10.0, // Convert to 0-15 scale
-        profit_targets: (30.0, 60.0, 100.0), // 30%, 60%, 100% profit targets
+        entry_path: trigger,
+        early_score,
+        profit_ladders: config.decision.profit_ladders.clone(),
+        ladders_hit: 0,
         stop_loss_pct: 15.0, // 15% stop loss
-        max_hold_secs: 300, // 5 minutes max hold
-        trigger_source: "late_opportunity".to_string(),
+        trailing_stop_pct: config.decision.trailing_stop(),
+        peak_price_sol: mint_features.current_price,
+        max_hold_secs: 300, // 5 minutes max hold (stretched/cut by activity)
+        hold_capped: false,
+        trigger_source: trigger_source.to_string(),
         sell_retry_count: 0,
         entry_mc_sol: mint_features.mc_sol,
+        peak_vol_5s_sol: mint_features.vol_5s_sol,
+        peak_curve_sol_reserves: mint_features.curve_sol_reserves,
         mc_10s_ago: None,
         mc_20s_ago: None,
+        dry_run: false,
     };
     
     position_tracker.write().await.add_position(entry_position)?;
-    info!("📊 Position tracked: {} for exit monitoring", hex::encode(&late.mint[..8]));
+    info!("📊 Position tracked: {} for exit monitoring", hex::encode(&mint_bytes[..8]));
     
     Ok(())
 }
@@ -1050,6 +1657,7 @@ async fn process_copy_trade(
     sender: &Arc<DecisionBusSender>,
     position_tracker: &Arc<tokio::sync::RwLock<decision_engine::PositionTracker>>,
     position_sizer: &Arc<decision_engine::PositionSizer>,
+    execution_quality: &Arc<decision_engine::ExecutionQualityTracker>,
     lifecycle_logger: &Arc<tokio::sync::Mutex<position_lifecycle_logger::PositionLifecycleLogger>>,
     telegram_client: &Option<Arc<telegram::TelegramClient>>,
     config: &Config,
@@ -1058,10 +1666,29 @@ async fn process_copy_trade(
     
     metrics::record_decision_pathway(DecisionPathway::CopyTrade);
     
+    // Alpha wallet exits: close our mirrored position instead of buying more
+    if copy.side == 1 {
+        return process_copy_sell(
+            copy,
+            mint_cache,
+            logger,
+            sender,
+            position_tracker,
+            lifecycle_logger,
+            telegram_client,
+        ).await;
+    }
+    
     // Convert to Pubkeys
     let mint = Pubkey::new_from_array(copy.mint);
     let wallet = Pubkey::new_from_array(copy.wallet);
     
+    if let Some(why) = config.decision.mint_list_rejection(&mint.to_string()) {
+        info!("🚷 Copy trade rejected: {} {}", &mint.to_string()[..8], why);
+        metrics::record_decision_rejected(DecisionPathway::CopyTrade, EntryTrigger::CopyTrade, RejectionReason::MintList);
+        return Ok(());
+    }
+    
     // 1. Lookup wallet features
     let wallet_features = match wallet_cache.get(&wallet) {
         Some(features) => {
@@ -1071,7 +1698,7 @@ async fn process_copy_trade(
         None => {
             metrics::record_cache_access(metrics::CacheType::Wallet, false);
             warn!("❌ Wallet not in cache: {}", hex::encode(&copy.wallet[..4]));
-            metrics::record_decision_rejected(RejectionReason::Validation);
+            metrics::record_decision_rejected(DecisionPathway::CopyTrade, EntryTrigger::CopyTrade, RejectionReason::Validation);
             return Ok(());
         }
     };
@@ -1081,7 +1708,7 @@ async fn process_copy_trade(
         info!("� Wallet confidence too low: {} < {}",
               wallet_features.confidence,
               config.decision.min_copytrade_confidence);
-        metrics::record_decision_rejected(RejectionReason::LowConfidence);
+        metrics::record_decision_rejected(DecisionPathway::CopyTrade, EntryTrigger::CopyTrade, RejectionReason::LowConfidence);
         return Ok(());
     }
     
@@ -1094,7 +1721,7 @@ async fn process_copy_trade(
         None => {
             metrics::record_cache_access(metrics::CacheType::Mint, false);
             warn!("❌ Mint not in cache: {}", hex::encode(&copy.mint[..4]));
-            metrics::record_decision_rejected(RejectionReason::Validation);
+            metrics::record_decision_rejected(DecisionPathway::CopyTrade, EntryTrigger::CopyTrade, RejectionReason::Validation);
             return Ok(());
         }
     };
@@ -1110,15 +1737,31 @@ async fn process_copy_trade(
     info!("📊 Copy trade score: {} (base={}, wallet_bonus=+{})",
           confidence, base_confidence, wallet_bonus);
     
+    if confidence < config.decision.min_conf_copy {
+        info!("🚫 Below copy confidence threshold: {} < {}", confidence, config.decision.min_conf_copy);
+        metrics::record_decision_rejected(DecisionPathway::CopyTrade, EntryTrigger::CopyTrade, RejectionReason::LowConfidence);
+        return Ok(());
+    }
+    
     // 4. Calculate position size (scale with wallet tier + confidence)
     let tracker = position_tracker.read().await;
     let active_count = tracker.count();
+    let at_limit = tracker.is_full();
     let total_exposure = tracker.get_all()
         .iter()
         .map(|p| p.size_sol)
         .sum::<f64>();
     drop(tracker);
     
+    // At the position limit: reject here, the sizer only scales down
+    if at_limit {
+        info!("🛡️  Blocked by guardrails: Max positions reached: {}/{}",
+              active_count, config.guardrails.max_concurrent_positions);
+        metrics::record_guardrail_block(metrics::GuardrailType::PositionLimit);
+        metrics::record_decision_rejected(DecisionPathway::CopyTrade, EntryTrigger::CopyTrade, RejectionReason::Guardrails);
+        return Ok(());
+    }
+    
     // Boost confidence slightly for higher tier wallets
     let tier_boosted_confidence = match wallet_features.tier {
         feature_cache::WalletTier::A => (base_confidence + 10).min(100),
@@ -1126,11 +1769,18 @@ async fn process_copy_trade(
         _ => base_confidence,
     };
     
-    let position_size_sol = position_sizer.calculate_size(
+    // Shrink size if this trigger's recent fills landed well above predicted price
+    // (applied inside the sizer, ahead of its min/max clamp)
+    let fill_multiplier = execution_quality.size_multiplier(decision_engine::triggers::EntryTrigger::CopyTrade);
+    if fill_multiplier < 1.0 {
+        debug!("📐 Execution quality size adjustment: x{:.2}", fill_multiplier);
+    }
+    let position_size_sol = position_sizer.calculate_size_scaled(
         tier_boosted_confidence,
         active_count,
         config.guardrails.max_concurrent_positions,
         total_exposure,
+        fill_multiplier,
     );
     let position_size_usd = position_size_sol * get_sol_price_usd();
     let position_size_lamports = (position_size_sol * 1e9) as u64;
@@ -1145,16 +1795,28 @@ async fn process_copy_trade(
     // Check portfolio heat
     if let Err(e) = position_sizer.check_portfolio_heat(total_exposure, position_size_sol) {
         warn!("🔥 {}", e);
-        metrics::record_decision_rejected(RejectionReason::Guardrails);
+        metrics::record_decision_rejected(DecisionPathway::CopyTrade, EntryTrigger::CopyTrade, RejectionReason::Guardrails);
         return Ok(());
     }
     
-    // 5. Validate
+    // Hard cap on total open exposure
+    if let Err(reason) = guardrails.check_exposure(total_exposure, position_size_sol) {
+        info!("🛡️  Blocked by guardrails: {}", reason);
+        metrics::record_guardrail_block(metrics::GuardrailType::PositionLimit);
+        metrics::record_decision_rejected(DecisionPathway::CopyTrade, EntryTrigger::CopyTrade, RejectionReason::Guardrails);
+        return Ok(());
+    }
+    
+    // 5. Validate (slippage sized to position and curve depth)
+    let slippage_bps = slippage::estimate_bps(position_size_sol, mint_features.curve_depth_proxy);
+    debug!("📉 Slippage: {} bps (size {:.3} SOL, depth {})",
+           slippage_bps, position_size_sol, mint_features.curve_depth_proxy);
+    
     let validated = match validator.validate(
         mint,
         &mint_features,
         position_size_usd,
-        150,
+        slippage_bps,
         confidence,
         None,
     ) {
@@ -1165,7 +1827,7 @@ async fn process_copy_trade(
                 e,
                 0.0, 0.0, 0.0, // Don't have validated values yet
             );
-            metrics::record_decision_rejected(RejectionReason::Validation);
+            metrics::record_decision_rejected(DecisionPathway::CopyTrade, EntryTrigger::CopyTrade, RejectionReason::Validation);
             return Ok(());
         }
     };
@@ -1174,7 +1836,7 @@ async fn process_copy_trade(
     if let Err(reason) = guardrails.check_decision_allowed(2, &copy.mint, Some(&copy.wallet), Some(wallet_features.tier as u8), None) {
         info!("🛡️  Blocked by guardrails: {}", reason);
         metrics::record_guardrail_block(metrics::GuardrailType::WalletCooling);
-        metrics::record_decision_rejected(RejectionReason::Guardrails);
+        metrics::record_decision_rejected(DecisionPathway::CopyTrade, EntryTrigger::CopyTrade, RejectionReason::Guardrails);
         return Ok(());
     }
     
@@ -1185,7 +1847,7 @@ async fn process_copy_trade(
     let decision = udp_bus::TradeDecision::new_buy(
         copy.mint,
         position_size_lamports,
-        150,
+        slippage_bps,
         confidence,
         2, // entry_type: 2 = CopyTrade
     );
@@ -1212,6 +1874,7 @@ async fn process_copy_trade(
         rank: None,
         wallet: Some(hex::encode(&copy.wallet)),
         wallet_tier: Some(wallet_features.tier as u8),
+        slippage_bps: Some(slippage_bps),
     };
     
     logger.log_decision(log_entry)?;
@@ -1219,7 +1882,7 @@ async fn process_copy_trade(
     // 9. Send
     sender.send_decision(&decision).await?;
     metrics::record_decision_sent();
-    metrics::record_decision_approved();
+    metrics::record_decision_approved(DecisionPathway::CopyTrade, EntryTrigger::CopyTrade);
     
     // Send Telegram notification for COPY BUY
     if let Some(tg) = telegram_client {
@@ -1245,10 +1908,11 @@ async fn process_copy_trade(
         });
     }
     
-    info!("✅ DECISION SENT: COPY BUY {} from wallet tier {:?} (conf={})",
+    info!("✅ DECISION SENT: COPY BUY {} from wallet tier {:?} (conf={}) | trace={}",
           hex::encode(&copy.mint[..8]),
           wallet_features.tier,
-          confidence);
+          confidence,
+          udp_bus::trace_tag(&decision.trace_id));
     
     // 10. Track position for exit monitoring
     let entry_position = decision_engine::ActivePosition {
@@ -1261,18 +1925,25 @@ async fn process_copy_trade(
         size_sol: position_size_sol,
         size_usd: position_size_usd,
         entry_price_sol: mint_features.current_price,
-        tokens: (position_size_sol / mint_features.current_price) * 0.99, // Account for slippage
+        tokens: (position_size_sol / mint_features.current_price) * 0.99, // Estimate until Executor's curve quote arrives
         entry_confidence: confidence,
         entry_path: decision_engine::triggers::EntryTrigger::CopyTrade,
         early_score: 0.0, // Copy trades don't have early score
-        profit_targets: (30.0, 60.0, 100.0), // 30%, 60%, 100% profit targets
+        profit_ladders: config.decision.profit_ladders.clone(),
+        ladders_hit: 0,
         stop_loss_pct: 15.0, // 15% stop loss
-        max_hold_secs: 300, // 5 minutes max hold
+        trailing_stop_pct: config.decision.trailing_stop(),
+        peak_price_sol: mint_features.current_price,
+        max_hold_secs: 300, // 5 minutes max hold (stretched/cut by activity)
+        hold_capped: false,
         trigger_source: "copy_trade".to_string(),
         sell_retry_count: 0,
         entry_mc_sol: mint_features.mc_sol,
+        peak_vol_5s_sol: mint_features.vol_5s_sol,
+        peak_curve_sol_reserves: mint_features.curve_sol_reserves,
         mc_10s_ago: None,
         mc_20s_ago: None,
+        dry_run: false,
     };
     
     position_tracker.write().await.add_position(entry_position)?;
@@ -1281,98 +1952,312 @@ async fn process_copy_trade(
     Ok(())
 }
 
-/// Calculate follow-through score for cache using same algorithm as FollowThroughScorer
-/// 
-/// This provides better score estimates than simple linear mapping.
-/// Returns 0-100 score based on momentum signals.
-fn calculate_cache_follow_through_score(
-    buyers_2s: u32,
-    vol_5s_sol: f64,
-    buyers_60s: u32,
-) -> u8 {
-    // Use same thresholds as FollowThroughScorer
-    const MAX_BUYERS_2S: u32 = 20;
-    const MAX_VOL_5S: f64 = 50.0;
-    const BUYER_WEIGHT: f64 = 0.4;
-    const VOLUME_WEIGHT: f64 = 0.4;
-    const WALLET_QUALITY_WEIGHT: f64 = 0.2;
-    
-    // Score buyer momentum (0-100)
-    let buyer_score = if buyers_2s == 0 {
-        0
-    } else if buyers_2s <= 5 {
-        ((buyers_2s as f64 / 5.0) * 50.0) as u8
-    } else {
-        let normalized = (buyers_2s as f64 / MAX_BUYERS_2S as f64).min(1.0);
-        let log_score = (normalized.ln() + 1.0).max(0.0);
-        (50.0 + log_score * 50.0) as u8
+/// Process a copy trade SELL: exit the mirrored position if we hold the mint
+///
+/// Skips buy-side scoring/validation/guardrails entirely.
+async fn process_copy_sell(
+    copy: &CopyTradeAdvice,
+    mint_cache: &MintCache,
+    logger: &DecisionLogger,
+    sender: &Arc<DecisionBusSender>,
+    position_tracker: &Arc<tokio::sync::RwLock<decision_engine::PositionTracker>>,
+    lifecycle_logger: &Arc<tokio::sync::Mutex<position_lifecycle_logger::PositionLifecycleLogger>>,
+    telegram_client: &Option<Arc<telegram::TelegramClient>>,
+) -> Result<()> {
+    let mint_str = bs58::encode(&copy.mint).into_string();
+    
+    let current_price = mint_cache
+        .get(&Pubkey::new_from_array(copy.mint))
+        .map(|f| f.current_price);
+    
+    let tracker = position_tracker.read().await;
+    let (decision, position) = match tracker.copy_sell_decision(&copy.mint, current_price) {
+        Some(decision) => (decision, tracker.get_position(&mint_str).cloned()),
+        None => {
+            debug!("👥 Copy SELL ignored: no position in {}", &mint_str[..12]);
+            return Ok(());
+        }
     };
+    drop(tracker);
     
-    // Score volume momentum (0-100)
-    let volume_score = if vol_5s_sol <= 0.0 {
-        0
-    } else {
-        let normalized = (vol_5s_sol / MAX_VOL_5S).min(1.0);
-        let sqrt_score = normalized.sqrt();
-        (sqrt_score * 100.0) as u8
+    let size_sol = decision.size_lamports as f64 / 1e9;
+    let entry_confidence = decision.confidence;
+    
+    // Log
+    let log_entry = DecisionLogEntry {
+        decision_id: 0,
+        timestamp: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs(),
+        mint: hex::encode(&copy.mint),
+        trigger_type: TriggerType::CopyTrade,
+        side: 1,
+        predicted_fees_usd: 0.0,
+        predicted_impact_usd: 0.0,
+        tp_usd: 0.0,
+        follow_through_score: 0,
+        size_sol,
+        size_usd: size_sol * get_sol_price_usd(),
+        confidence: entry_confidence,
+        expected_ev_usd: 0.0,
+        success_probability: 0.0,
+        rank: None,
+        wallet: Some(hex::encode(&copy.wallet)),
+        wallet_tier: Some(copy.wallet_tier),
+        slippage_bps: Some(decision.slippage_bps),
     };
+    logger.log_decision(log_entry)?;
     
-    // Wallet quality proxy: use buyers_60s as proxy for activity level
-    // More buyers in 60s suggests more quality participants
-    let wallet_quality_score = if buyers_60s == 0 {
-        50
-    } else {
-        // Normalize buyers_60s: 0-100 buyers → 40-90 points
-        let normalized = (buyers_60s as f64 / 100.0).min(1.0);
-        (40.0 + normalized * 50.0) as u8
+    let reason = format!("copy_sell:{}", &bs58::encode(&copy.wallet).into_string()[..8]);
+    let lifecycle_logger_sell = lifecycle_logger.clone();
+    let mint_sell = mint_str.clone();
+    let reason_sell = reason.clone();
+    tokio::spawn(async move {
+        lifecycle_logger_sell.lock().await.log_event(
+            position_lifecycle_logger::LifecycleEvent::SellDecision {
+                mint: mint_sell,
+                size_sol,
+                exit_percent: 100,
+                reason: reason_sell,
+            }
+        );
+    });
+    
+    // Send
+    sender.send_decision(&decision).await?;
+    metrics::record_decision_sent();
+    {
+        let mut tracker = position_tracker.write().await;
+        tracker.mark_sell_sent(&mint_str);
+        tracker.record_exit(&mint_str, None, 100);
+    }
+    
+    // Send Telegram notification for COPY SELL
+    if let Some(tg) = telegram_client {
+        let wallet_str = bs58::encode(&copy.wallet).into_string();
+        let hold_secs = position.map(|p| p.entry_time.elapsed().as_secs()).unwrap_or(0);
+        let mint_short = mint_str[..12].to_string();
+        let tg_clone = tg.clone();
+        tokio::spawn(async move {
+            let msg = format!(
+                "👥 COPY TRADE SELL\n\nMint: {}\nWallet: {}\nSize: {:.4} SOL\nHeld: {}s",
+                mint_short,
+                &wallet_str[..12],
+                size_sol,
+                hold_secs
+            );
+            if let Err(e) = tg_clone.send_message(&msg).await {
+                warn!("Failed to send Telegram notification: {}", e);
+            }
+        });
+    }
+    
+    info!("✅ DECISION SENT: COPY SELL {} ({:.3} SOL) following wallet {} | trace={}",
+          &mint_str[..8], size_sol, hex::encode(&copy.wallet[..4]),
+          udp_bus::trace_tag(&decision.trace_id));
+    
+    Ok(())
+}
+
+/// Process an ExitAdvice: Mempool-watcher saw our profit target or stop-loss hit
+///
+/// Sells the advised percentage of the matching position immediately
+/// (stop-loss always exits 100%). Ignored if we don't hold the mint.
+async fn process_exit_advice(
+    exit: &ExitAdvice,
+    mint_cache: &MintCache,
+    logger: &DecisionLogger,
+    sender: &Arc<DecisionBusSender>,
+    position_tracker: &Arc<tokio::sync::RwLock<decision_engine::PositionTracker>>,
+    lifecycle_logger: &Arc<tokio::sync::Mutex<position_lifecycle_logger::PositionLifecycleLogger>>,
+    telegram_client: &Option<Arc<telegram::TelegramClient>>,
+) -> Result<()> {
+    let mint_str = exit.mint_str();
+    
+    let current_price = mint_cache
+        .get(&Pubkey::new_from_array(exit.mint))
+        .map(|f| f.current_price);
+    
+    let tracker = position_tracker.read().await;
+    let decision_and_position = tracker.exit_advice_decision(exit, current_price)
+        .zip(tracker.get_position(&mint_str).cloned());
+    let (decision, position) = match decision_and_position {
+        Some(pair) => pair,
+        None => {
+            debug!("🚨 Exit advice ignored: no position in {}", &mint_str[..12]);
+            return Ok(());
+        }
+    };
+    drop(tracker);
+    
+    let size_sol = decision.size_lamports as f64 / 1e9;
+    let exit_percent = exit.effective_exit_percent();
+    let trigger_type = match position.entry_path {
+        EntryTrigger::RankBased => TriggerType::Rank,
+        EntryTrigger::Momentum => TriggerType::Momentum,
+        EntryTrigger::CopyTrade => TriggerType::CopyTrade,
+        EntryTrigger::LateOpportunity => TriggerType::LateOpportunity,
+        EntryTrigger::Hotlist => TriggerType::Hotlist,
+    };
+    
+    // Log
+    let log_entry = DecisionLogEntry {
+        decision_id: 0,
+        timestamp: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs(),
+        mint: hex::encode(&exit.mint),
+        trigger_type,
+        side: 1,
+        predicted_fees_usd: 0.0,
+        predicted_impact_usd: 0.0,
+        tp_usd: 0.0,
+        follow_through_score: 0,
+        size_sol,
+        size_usd: size_sol * get_sol_price_usd(),
+        confidence: decision.confidence,
+        expected_ev_usd: 0.0,
+        success_probability: 0.0,
+        rank: None,
+        wallet: None,
+        wallet_tier: None,
+        slippage_bps: Some(decision.slippage_bps),
     };
+    logger.log_decision(log_entry)?;
+    
+    let reason = format!("exit_advice:{}", exit.reason_str());
+    let lifecycle_logger_sell = lifecycle_logger.clone();
+    let mint_sell = mint_str.clone();
+    tokio::spawn(async move {
+        lifecycle_logger_sell.lock().await.log_event(
+            position_lifecycle_logger::LifecycleEvent::SellDecision {
+                mint: mint_sell,
+                size_sol,
+                exit_percent,
+                reason,
+            }
+        );
+    });
     
-    // Weighted total
-    let total_score = (
-        (buyer_score as f64 * BUYER_WEIGHT) +
-        (volume_score as f64 * VOLUME_WEIGHT) +
-        (wallet_quality_score as f64 * WALLET_QUALITY_WEIGHT)
-    ).round() as u8;
+    // Send
+    sender.send_decision(&decision).await?;
+    metrics::record_decision_sent();
+    {
+        let mut tracker = position_tracker.write().await;
+        tracker.mark_sell_sent(&mint_str);
+        tracker.record_exit(&mint_str, None, exit_percent);
+    }
     
-    total_score.min(100)
+    // Send Telegram notification for advised exit
+    if let Some(tg) = telegram_client {
+        let hold_secs = position.entry_time.elapsed().as_secs();
+        let mint_short = mint_str[..12].to_string();
+        let reason_str = exit.reason_str().to_string();
+        let pnl_usd = exit.realized_pnl_usd();
+        let tg_clone = tg.clone();
+        tokio::spawn(async move {
+            let msg = format!(
+                "🚨 EXIT ADVICE SELL\n\nMint: {}\nReason: {}\nExit: {}% ({:.4} SOL)\nP&L: ${:.2}\nHeld: {}s",
+                mint_short,
+                reason_str,
+                exit_percent,
+                size_sol,
+                pnl_usd,
+                hold_secs
+            );
+            if let Err(e) = tg_clone.send_message(&msg).await {
+                warn!("Failed to send Telegram notification: {}", e);
+            }
+        });
+    }
+    
+    info!("✅ DECISION SENT: EXIT SELL {} ({:.3} SOL, {}%) on {} | trace={}",
+          &mint_str[..8], size_sol, exit_percent, exit.reason_str(),
+          udp_bus::trace_tag(&decision.trace_id));
+    
+    Ok(())
 }
 
 /// Update mint cache from SQLite
+///
+/// `since = None` is a full scan of every mint with windows in the last 5 min
+/// (reconciliation). `since = Some(watermark)` only re-aggregates mints whose
+/// windows were written (`processed_at`) at or after the watermark, merging them
+/// into the cache. Returns the new watermark (max `processed_at` seen).
 async fn update_mint_cache(
     cache: &MintCache,
     sqlite: &Arc<tokio::sync::Mutex<rusqlite::Connection>>,
-) -> Result<()> {
+    since: Option<i64>,
+) -> Result<i64> {
     let _timer = metrics::DbQueryTimer::start();
     
     // Query recent tokens with aggregated window metrics
     let mint_cache_clone = cache.clone();
     let sqlite_clone = sqlite.clone();
     
-    let result = tokio::task::spawn_blocking(move || -> Result<usize> {
+    let (result, watermark) = tokio::task::spawn_blocking(move || -> Result<(usize, i64)> {
         let conn = sqlite_clone.blocking_lock();
         
-        let query = "
+        // Windows are upserted in place, so `processed_at` (reset on every write)
+        // marks changed mints; same-second rewrites are re-read (>=), which is harmless
+        let changed_filter = if since.is_some() {
+            "AND t.mint IN (SELECT mint FROM windows WHERE processed_at >= ?1)"
+        } else {
+            ""
+        };
+        
+        // 2s/5s come from the current (or just-closed) aligned bucket of those
+        // intervals; 60s fields take the busiest 60s bucket of the last 5 minutes
+        // (all three are in REQUIRED_WINDOW_INTERVALS, checked at startup)
+        let query = format!("
             SELECT 
                 t.mint,
                 t.launch_block_time,
-                MAX(CASE WHEN w.window_sec = 10 THEN w.uniq_buyers ELSE 0 END) as buyers_2s,
-                MAX(CASE WHEN w.window_sec = 10 THEN w.vol_sol ELSE 0 END) as vol_5s_sol,
+                MAX(CASE WHEN w.window_sec = 2 AND w.end_time >= strftime('%s', 'now') - 2
+                         THEN w.uniq_buyers ELSE 0 END) as buyers_2s,
+                MAX(CASE WHEN w.window_sec = 5 AND w.end_time >= strftime('%s', 'now') - 5
+                         THEN w.vol_sol ELSE 0 END) as vol_5s_sol,
                 MAX(CASE WHEN w.window_sec = 60 THEN w.uniq_buyers ELSE 0 END) as buyers_60s,
                 MAX(CASE WHEN w.window_sec = 60 THEN w.vol_sol ELSE 0 END) as vol_60s_sol,
                 MAX(CASE WHEN w.window_sec = 60 THEN w.num_buys ELSE 0 END) as buys_60s,
                 MAX(CASE WHEN w.window_sec = 60 THEN w.num_sells ELSE 1 END) as sells_60s,
                 MAX(CASE WHEN w.window_sec = 60 THEN w.close ELSE 0 END) as current_price,
-                MAX(w.start_time) as last_update
+                MAX(w.start_time) as last_update,
+                MAX(CASE WHEN w.window_sec = 60 THEN w.top1_share ELSE 0 END) as top1_share_60s,
+                MAX(CASE WHEN w.window_sec = 60 THEN w.top3_share ELSE 0 END) as top3_share_60s,
+                MAX(w.processed_at) as processed_at,
+                -- Signed, so the latest 60s bucket rather than MAX over buckets
+                COALESCE((SELECT w60.net_sol_flow FROM windows w60
+                          WHERE w60.mint = t.mint AND w60.window_sec = 60
+                          ORDER BY w60.start_time DESC LIMIT 1), 0.0) as net_sol_flow_60s,
+                -- Rug signals for the emergency exit: creator selling, one wallet dumping
+                EXISTS(SELECT 1 FROM trades tr
+                       WHERE tr.mint = t.mint AND tr.side = 'sell' AND tr.trader = t.creator_wallet
+                         AND tr.block_time >= strftime('%s', 'now') - 60) as creator_sold_60s,
+                COALESCE((SELECT SUM(tr.amount_sol) FROM trades tr
+                          WHERE tr.mint = t.mint AND tr.side = 'sell'
+                            AND tr.block_time >= strftime('%s', 'now') - 60
+                          GROUP BY tr.trader ORDER BY 1 DESC LIMIT 1)
+                         / NULLIF((SELECT SUM(tr.amount_sol) FROM trades tr
+                                   WHERE tr.mint = t.mint
+                                     AND tr.block_time >= strftime('%s', 'now') - 60), 0), 0.0) as top_seller_share_60s
             FROM tokens t
             LEFT JOIN windows w ON t.mint = w.mint
             WHERE w.start_time > strftime('%s', 'now') - 300
+              {}
             GROUP BY t.mint
             LIMIT 1000
-        ";
+        ", changed_filter);
         
-        let mut stmt = conn.prepare(query)?;
-        let mut rows = stmt.query([])?;
+        let mut stmt = conn.prepare(&query)?;
+        let mut rows = match since {
+            Some(watermark) => stmt.query([watermark])?,
+            None => stmt.query([])?,
+        };
         let mut count = 0;
+        let mut watermark = since.unwrap_or(0);
         
         while let Some(row) = rows.next()? {
             let mint_str: String = row.get(0)?;
@@ -1385,6 +2270,13 @@ async fn update_mint_cache(
             let sells_60s: i64 = row.get(7)?;
             let current_price: f64 = row.get(8)?;
             let last_update: i64 = row.get(9)?;
+            let top1_share_60s: f64 = row.get(10)?;
+            let top3_share_60s: f64 = row.get(11)?;
+            let processed_at: Option<i64> = row.get(12)?;
+            let net_sol_flow_60s: f64 = row.get(13)?;
+            let creator_sold_60s: bool = row.get(14)?;
+            let top_seller_share_60s: f64 = row.get(15)?;
+            watermark = watermark.max(processed_at.unwrap_or(0));
             
             // Parse mint pubkey
             let mint = match Pubkey::from_str(&mint_str) {
@@ -1411,7 +2303,7 @@ async fn update_mint_cache(
             
             // Calculate follow-through score (0-100) using scoring algorithm
             // This provides a better proxy than simple linear mapping
-            let follow_through_score = calculate_cache_follow_through_score(
+            let follow_through_score = decision_engine::scoring::cache_follow_through_score(
                 buyers_2s as u32,
                 vol_5s_sol,
                 buyers_60s as u32,
@@ -1433,17 +2325,28 @@ async fn update_mint_cache(
                 mempool_pending_sells: 0, // TODO: Get from mempool tracker
                 mc_sol: current_price * 1_000_000_000.0, // Rough estimate
                 mempool_volume_sol: 0.0, // TODO: Get from mempool tracker
+                top1_share_60s,
+                top3_share_60s,
+                net_sol_flow_60s,
+                // Live from the gRPC curve account, not in SQLite: keep the last reading
+                curve_sol_reserves: mint_cache_clone.get(&mint).map(|f| f.curve_sol_reserves).unwrap_or(0.0),
+                creator_sold_60s,
+                top_seller_share_60s,
             };
             
             mint_cache_clone.insert(mint, features);
             count += 1;
         }
         
-        Ok(count)
+        Ok((count, watermark))
     }).await??;
     
-    info!("📊 Mint cache updated: {} entries", result);
-    Ok(())
+    if since.is_none() {
+        info!("📊 Mint cache reconciled: {} entries", result);
+    } else if result > 0 {
+        debug!("📊 Mint cache incremental: {} changed mints", result);
+    }
+    Ok(watermark)
 }
 
 /// Update wallet cache from PostgreSQL
@@ -1464,7 +2367,8 @@ async fn update_wallet_cache(
             total_pnl_sol,
             num_trades_7d,
             follow_through_rate,
-            avg_entry_speed_ms
+            avg_entry_speed_ms,
+            COALESCE(pnl_24h_sol, 0.0) AS pnl_24h_sol
         FROM wallet_stats
         WHERE num_trades_7d > 5
           AND last_trade_time > NOW() - INTERVAL '7 days'
@@ -1483,6 +2387,7 @@ async fn update_wallet_cache(
         let num_trades_7d: i64 = row.get(4);
         let follow_through_rate: f64 = row.get(5);
         let avg_entry_speed_ms: i64 = row.get(6);
+        let pnl_24h_sol: f64 = row.get(7);
         
         // Parse wallet pubkey
         let wallet = match Pubkey::from_str(&address_str) {
@@ -1494,12 +2399,24 @@ async fn update_wallet_cache(
         };
         
         // Calculate wallet tier using the tier classifier
-        let tier = feature_cache::WalletFeatures::classify_tier(
+        let base_tier = feature_cache::WalletFeatures::classify_tier(
             win_rate_7d,
             total_pnl_sol,
-            num_trades_7d as u32
+            num_trades_7d as u32,
+            cache.tier_thresholds(),
         );
         
+        // Demote wallets bleeding in the last 24h despite strong 7d stats
+        let tier = feature_cache::WalletFeatures::apply_recent_performance(
+            base_tier,
+            total_pnl_sol,
+            pnl_24h_sol
+        );
+        if tier != base_tier {
+            debug!("📉 Wallet {} demoted {:?} → {:?} (24h PnL {:.2} SOL vs 7d {:.2} SOL)",
+                   &address_str[..8.min(address_str.len())], base_tier, tier, pnl_24h_sol, total_pnl_sol);
+        }
+        
         // Calculate bootstrap score
         let wins = (win_rate_7d * num_trades_7d as f64) as u32;
         let bootstrap_score = ((50 + wins * 2) as i32 + (total_pnl_sol / 5.0) as i32)
@@ -1509,6 +2426,7 @@ async fn update_wallet_cache(
         let features = feature_cache::WalletFeatures {
             win_rate_7d,
             realized_pnl_7d: total_pnl_sol,
+            realized_pnl_24h: pnl_24h_sol,
             trade_count: num_trades_7d as u32,
             avg_size: if num_trades_7d > 0 { 
                 total_pnl_sol.abs() / num_trades_7d as f64 
@@ -1545,3 +2463,114 @@ fn print_banner(config: &Config) {
     println!("🔍 Status: LISTENING FOR ADVICE...");
     println!("======================================================================\n");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use decision_engine::{PositionSizer, PositionSizerConfig, PositionTracker, ExecutionQualityTracker};
+    use decision_engine::guardrails::GuardrailConfig;
+
+    /// Young launch that scores 4.5/15 early (conf 56) on its cached activity:
+    /// fast buyers +2, decentralized +1, MC velocity +1.5, no volume surge yet
+    fn cached_launch() -> feature_cache::MintFeatures {
+        feature_cache::MintFeatures {
+            age_since_launch: 8,
+            current_price: 0.000_000_1,
+            vol_60s_sol: 24.0,
+            buyers_60s: 12,
+            buys_sells_ratio: 2.0,
+            curve_depth_proxy: 800_000_000,
+            last_update: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
+            buyers_2s: 1,
+            vol_5s_sol: 1.0,
+            mc_sol: 328.0,
+            top1_share_60s: 0.2,
+            top3_share_60s: 0.4,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_momentum_advice_drives_launch_opportunity() {
+        let mut config = Config::from_env().unwrap();
+        config.decision.min_conf_momentum = 75;
+
+        let dir = std::env::temp_dir().join(format!("brain_momentum_test_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let executor = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let sender = Arc::new(DecisionBusSender::new(executor.local_addr().unwrap()).await.unwrap());
+        let mint_cache = MintCache::new(String::new(), 16);
+        let mut guardrails = Guardrails::with_config(
+            GuardrailConfig::default(),
+            dir.join("guardrails.db").to_string_lossy().to_string(),
+        );
+        let logger = DecisionLogger::new(dir.join("decisions.jsonl")).unwrap();
+        let position_tracker = Arc::new(tokio::sync::RwLock::new(PositionTracker::new(3)));
+        let position_sizer = Arc::new(PositionSizer::new(PositionSizerConfig::default()));
+        let execution_quality = Arc::new(ExecutionQualityTracker::new());
+        let lifecycle_logger = Arc::new(tokio::sync::Mutex::new(
+            position_lifecycle_logger::PositionLifecycleLogger::new()
+        ));
+
+        let quiet = [7u8; 32];
+        let surging = [8u8; 32];
+        mint_cache.insert(Pubkey::new_from_array(quiet), cached_launch());
+        mint_cache.insert(Pubkey::new_from_array(surging), cached_launch());
+
+        // Momentum advice reports a 5s volume surge (5.0 SOL vs 2.0 SOL per 5s over 60s)
+        let momentum = MomentumOpportunityAdvice {
+            msg_type: 16,
+            mint: surging,
+            vol_5s_scaled: 500,
+            buyers_2s: 6,
+            score: 80,
+            _padding: [0; 12],
+        };
+        apply_momentum_advice(&mint_cache, &momentum);
+        let refreshed = mint_cache.get(&Pubkey::new_from_array(surging)).unwrap();
+        assert_eq!(refreshed.buyers_2s, 6);
+        assert_eq!(refreshed.vol_5s_sol, 5.0);
+
+        for mint in [quiet, surging] {
+            process_launch_opportunity(
+                mint,
+                momentum.score,
+                EntryTrigger::Momentum,
+                &mint_cache,
+                &FollowThroughScorer::new(),
+                &EarlyScorer::new(),
+                &TradeValidator::new(),
+                &mut guardrails,
+                &logger,
+                &sender,
+                &position_tracker,
+                &position_sizer,
+                &execution_quality,
+                &lifecycle_logger,
+                &None,
+                &config,
+            ).await.unwrap();
+        }
+
+        // Only the surging mint clears MIN_CONF_MOMENTUM (6.0/15 → conf 75)
+        let tracker = position_tracker.read().await;
+        assert!(tracker.get_position(&bs58::encode(&quiet).into_string()).is_none());
+        let position = tracker.get_position(&bs58::encode(&surging).into_string()).unwrap();
+        assert_eq!(position.entry_path, EntryTrigger::Momentum);
+        assert_eq!(position.entry_confidence, 75);
+        drop(tracker);
+
+        let mut buf = [0u8; 256];
+        let (len, _) = tokio::time::timeout(Duration::from_secs(1), executor.recv_from(&mut buf))
+            .await
+            .unwrap()
+            .unwrap();
+        let decision = udp_bus::TradeDecision::from_bytes(&buf[..len]).unwrap();
+        assert_eq!(decision.mint, surging);
+        assert_eq!(decision.side, 0);
+        assert_eq!(decision.entry_type, EntryTrigger::Momentum.entry_type());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -1,11 +1,14 @@
 //! 💰 Manual Exit Listener - Receives ManualExitNotification from Mempool (Port 45135)
 //!
-//! When user manually exits via Phantom wallet, mempool sends notification to clean up position
+//! When user manually exits via Phantom wallet, mempool sends notification to clean up position.
+//! Packets must carry the Advice Bus HMAC prefix; anything else could drop a live position.
 
 use anyhow::{Context, Result};
-use log::{debug, error, info};
+use log::{debug, error, info, warn};
 use tokio::net::UdpSocket;
 
+use crate::udp_bus::auth::{AdviceAuth, AUTH_HEADER_SIZE};
+
 /// MSG_TYPE 33 - Manual Exit Notification from Mempool
 /// Sent when user manually sells tracked position via wallet
 #[repr(C, packed)]
@@ -97,9 +100,16 @@ impl ManualExitNotification {
 pub struct ManualExitListener;
 
 impl ManualExitListener {
+    /// Verify the auth prefix and parse the notification behind it
+    pub fn parse_packet(auth: &AdviceAuth, packet: &[u8]) -> Result<ManualExitNotification> {
+        let payload = auth.verify(packet)
+            .context("unauthenticated ManualExitNotification")?;
+        ManualExitNotification::from_bytes(payload)
+    }
+    
     /// Start listening for manual exit notifications
     /// Returns a channel receiver for manual exit events
-    pub async fn listen(bind_addr: &str) -> Result<tokio::sync::mpsc::UnboundedReceiver<String>> {
+    pub async fn listen(bind_addr: &str, auth: AdviceAuth) -> Result<tokio::sync::mpsc::UnboundedReceiver<String>> {
         let socket = UdpSocket::bind(bind_addr)
             .await
             .context(format!("Failed to bind ManualExit listener to {}", bind_addr))?;
@@ -109,17 +119,17 @@ impl ManualExitListener {
         let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
         
         tokio::spawn(async move {
-            let mut buf = vec![0u8; ManualExitNotification::SIZE + 100];
+            let mut buf = vec![0u8; AUTH_HEADER_SIZE + ManualExitNotification::SIZE + 100];
             
             loop {
-                match socket.recv(&mut buf).await {
-                    Ok(len) => {
-                        if len < ManualExitNotification::SIZE {
+                match socket.recv_from(&mut buf).await {
+                    Ok((len, addr)) => {
+                        if len < AUTH_HEADER_SIZE + ManualExitNotification::SIZE {
                             debug!("Received undersized packet: {} bytes", len);
                             continue;
                         }
                         
-                        match ManualExitNotification::from_bytes(&buf[..ManualExitNotification::SIZE]) {
+                        match Self::parse_packet(&auth, &buf[..len]) {
                             Ok(notification) => {
                                 // Copy values to avoid packed struct alignment issues
                                 let pnl_usd = notification.realized_pnl_usd;
@@ -136,7 +146,7 @@ impl ManualExitListener {
                                 }
                             }
                             Err(e) => {
-                                error!("❌ Failed to parse ManualExitNotification: {}", e);
+                                warn!("⚠️  Dropped ManualExitNotification from {}: {:#}", addr, e);
                             }
                         }
                     }
@@ -150,3 +160,49 @@ impl ManualExitListener {
         Ok(rx)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn notification_bytes(mint: [u8; 32]) -> Vec<u8> {
+        let mut data = vec![0u8; ManualExitNotification::SIZE];
+        data[0] = ManualExitNotification::MSG_TYPE;
+        data[1..33].copy_from_slice(&mint);
+        data
+    }
+    
+    #[test]
+    fn test_parse_packet_requires_auth() {
+        let auth = AdviceAuth::new(b"test-secret");
+        let payload = notification_bytes([7u8; 32]);
+        
+        let notification = ManualExitListener::parse_packet(&auth, &auth.sign(&payload)).unwrap();
+        assert_eq!(notification.mint_str(), bs58::encode([7u8; 32]).into_string());
+        
+        // Unsigned, foreign-key and tampered packets are rejected
+        assert!(ManualExitListener::parse_packet(&auth, &payload).is_err());
+        let foreign = AdviceAuth::new(b"other-secret").sign(&payload);
+        assert!(ManualExitListener::parse_packet(&auth, &foreign).is_err());
+        let mut tampered = auth.sign(&payload);
+        tampered[AUTH_HEADER_SIZE + 1] ^= 1;
+        assert!(ManualExitListener::parse_packet(&auth, &tampered).is_err());
+    }
+    
+    #[tokio::test]
+    async fn test_listener_forwards_only_signed_notifications() {
+        let auth = AdviceAuth::new(b"test-secret");
+        let probe = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let addr = probe.local_addr().unwrap();
+        drop(probe);
+        let mut exits = ManualExitListener::listen(&addr.to_string(), auth.clone()).await.unwrap();
+        
+        let sender = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        sender.send_to(&notification_bytes([1u8; 32]), addr).await.unwrap();
+        sender.send_to(&auth.sign(&notification_bytes([2u8; 32])), addr).await.unwrap();
+        
+        let mint = tokio::time::timeout(std::time::Duration::from_secs(2), exits.recv()).await.unwrap().unwrap();
+        assert_eq!(mint, bs58::encode([2u8; 32]).into_string());
+        assert!(exits.try_recv().is_err());
+    }
+}
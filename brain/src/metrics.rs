@@ -29,6 +29,8 @@ use axum::{
 };
 use log::{info, error};
 
+use crate::decision_engine::triggers::EntryTrigger;
+
 /// Global metrics registry
 static METRICS: once_cell::sync::Lazy<Arc<BrainMetrics>> = once_cell::sync::Lazy::new(|| {
     Arc::new(BrainMetrics::new())
@@ -53,6 +55,10 @@ pub struct BrainMetrics {
     pub rejected_low_confidence: IntCounter,
     pub rejected_guardrails: IntCounter,
     pub rejected_validation: IntCounter,
+    pub rejected_mint_list: IntCounter,
+    
+    // Outcome per pathway / trigger (approved or rejection reason)
+    pub decision_outcomes: IntCounterVec,
     
     // Cache metrics
     pub mint_cache_hits: IntCounter,
@@ -74,7 +80,9 @@ pub struct BrainMetrics {
     // System metrics
     pub sol_price_usd: Gauge,
     pub active_positions: IntGauge,
+    pub position_drift_detected: IntCounterVec,
     pub advice_messages_received: IntCounter,
+    pub advice_packets_rejected: IntCounter,
     pub decision_messages_sent: IntCounter,
     
     // Database metrics
@@ -83,6 +91,8 @@ pub struct BrainMetrics {
     
     // UDP metrics
     pub udp_packets_received: IntCounter,
+    pub udp_messages_lost: IntCounter,
+    pub udp_messages_reordered: IntCounter,
     pub udp_packets_sent: IntCounter,
     pub udp_parse_errors: IntCounter,
 }
@@ -139,6 +149,17 @@ impl BrainMetrics {
         ).unwrap();
         registry.register(Box::new(rejected_validation.clone())).unwrap();
         
+        let rejected_mint_list = IntCounter::with_opts(
+            Opts::new("brain_rejected_mint_list", "Decisions rejected by the mint allowlist/denylist")
+        ).unwrap();
+        registry.register(Box::new(rejected_mint_list.clone())).unwrap();
+        
+        let decision_outcomes = IntCounterVec::new(
+            Opts::new("brain_decision_outcomes", "Decisions by pathway, trigger and outcome (approved or rejection reason)"),
+            &["pathway", "trigger", "outcome"]
+        ).unwrap();
+        registry.register(Box::new(decision_outcomes.clone())).unwrap();
+        
         // Cache metrics
         let mint_cache_hits = IntCounter::with_opts(
             Opts::new("brain_mint_cache_hits", "Mint cache hits")
@@ -211,11 +232,22 @@ impl BrainMetrics {
         ).unwrap();
         registry.register(Box::new(active_positions.clone())).unwrap();
         
+        let position_drift_detected = IntCounterVec::new(
+            Opts::new("brain_position_drift_detected", "Brain vs on-chain position drift events"),
+            &["kind"]
+        ).unwrap();
+        registry.register(Box::new(position_drift_detected.clone())).unwrap();
+        
         let advice_messages_received = IntCounter::with_opts(
             Opts::new("brain_advice_messages_received", "Total advice messages received")
         ).unwrap();
         registry.register(Box::new(advice_messages_received.clone())).unwrap();
         
+        let advice_packets_rejected = IntCounter::with_opts(
+            Opts::new("brain_advice_packets_rejected", "Advice packets dropped for failing authentication")
+        ).unwrap();
+        registry.register(Box::new(advice_packets_rejected.clone())).unwrap();
+        
         let decision_messages_sent = IntCounter::with_opts(
             Opts::new("brain_decision_messages_sent", "Total decision messages sent")
         ).unwrap();
@@ -239,6 +271,16 @@ impl BrainMetrics {
         ).unwrap();
         registry.register(Box::new(udp_packets_received.clone())).unwrap();
         
+        let udp_messages_lost = IntCounter::with_opts(
+            Opts::new("brain_udp_messages_lost", "Sequenced advice messages never received (seq gaps)")
+        ).unwrap();
+        registry.register(Box::new(udp_messages_lost.clone())).unwrap();
+        
+        let udp_messages_reordered = IntCounter::with_opts(
+            Opts::new("brain_udp_messages_reordered", "Sequenced advice messages received out of order")
+        ).unwrap();
+        registry.register(Box::new(udp_messages_reordered.clone())).unwrap();
+        
         let udp_packets_sent = IntCounter::with_opts(
             Opts::new("brain_udp_packets_sent", "UDP packets sent")
         ).unwrap();
@@ -260,6 +302,8 @@ impl BrainMetrics {
             rejected_low_confidence,
             rejected_guardrails,
             rejected_validation,
+            rejected_mint_list,
+            decision_outcomes,
             mint_cache_hits,
             mint_cache_misses,
             wallet_cache_hits,
@@ -273,11 +317,15 @@ impl BrainMetrics {
             advice_processing_latency,
             sol_price_usd,
             active_positions,
+            position_drift_detected,
             advice_messages_received,
+            advice_packets_rejected,
             decision_messages_sent,
             db_query_duration,
             db_errors,
             udp_packets_received,
+            udp_messages_lost,
+            udp_messages_reordered,
             udp_packets_sent,
             udp_parse_errors,
         }
@@ -359,30 +407,47 @@ async fn health_handler() -> Response {
 // ============================================================================
 
 /// Record a decision approval
-pub fn record_decision_approved() {
+pub fn record_decision_approved(pathway: DecisionPathway, trigger: EntryTrigger) {
     let m = metrics();
     m.decisions_total.inc();
     m.decisions_approved.inc();
+    m.decision_outcomes.with_label_values(&[pathway.as_str(), trigger.as_str(), "approved"]).inc();
 }
 
 /// Record a decision rejection
-pub fn record_decision_rejected(reason: RejectionReason) {
+pub fn record_decision_rejected(pathway: DecisionPathway, trigger: EntryTrigger, reason: RejectionReason) {
     let m = metrics();
     m.decisions_total.inc();
     m.decisions_rejected.inc();
+    m.decision_outcomes.with_label_values(&[pathway.as_str(), trigger.as_str(), reason.as_str()]).inc();
     
     match reason {
         RejectionReason::LowConfidence => m.rejected_low_confidence.inc(),
         RejectionReason::Guardrails => m.rejected_guardrails.inc(),
         RejectionReason::Validation => m.rejected_validation.inc(),
+        RejectionReason::MintList => m.rejected_mint_list.inc(),
     }
 }
 
 /// Decision rejection reasons
+#[derive(Debug, Clone, Copy)]
 pub enum RejectionReason {
     LowConfidence,
     Guardrails,
     Validation,
+    MintList,
+}
+
+impl RejectionReason {
+    /// `outcome` label value
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RejectionReason::LowConfidence => "low_confidence",
+            RejectionReason::Guardrails => "guardrails",
+            RejectionReason::Validation => "validation",
+            RejectionReason::MintList => "mint_list",
+        }
+    }
 }
 
 /// Record decision pathway trigger
@@ -396,12 +461,24 @@ pub fn record_decision_pathway(pathway: DecisionPathway) {
 }
 
 /// Decision pathway types
+#[derive(Debug, Clone, Copy)]
 pub enum DecisionPathway {
     CopyTrade,
     NewLaunch,
     WalletActivity,
 }
 
+impl DecisionPathway {
+    /// `pathway` label value
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DecisionPathway::CopyTrade => "copy_trade",
+            DecisionPathway::NewLaunch => "new_launch",
+            DecisionPathway::WalletActivity => "wallet_activity",
+        }
+    }
+}
+
 /// Record guardrail block
 pub fn record_guardrail_block(guardrail: GuardrailType) {
     let m = metrics();
@@ -457,6 +534,21 @@ pub fn record_advice_received() {
     m.udp_packets_received.inc();
 }
 
+/// Record an advice packet dropped for failing authentication
+pub fn record_advice_rejected() {
+    metrics().advice_packets_rejected.inc();
+}
+
+/// Record advice messages lost (gap in a sender's sequence numbers)
+pub fn record_udp_messages_lost(count: u64) {
+    metrics().udp_messages_lost.inc_by(count);
+}
+
+/// Record an advice message that arrived out of sequence
+pub fn record_udp_message_reordered() {
+    metrics().udp_messages_reordered.inc();
+}
+
 /// Record decision message sent
 pub fn record_decision_sent() {
     let m = metrics();
@@ -483,6 +575,13 @@ pub fn record_stale_cache_warning(cache_type: &str) {
         .inc();
 }
 
+/// Record a Brain vs on-chain position drift event (phantom / orphaned)
+pub fn record_position_drift(kind: &str) {
+    metrics().position_drift_detected
+        .with_label_values(&[kind])
+        .inc();
+}
+
 /// Record decision latency in milliseconds
 pub fn record_decision_latency(latency_ms: f64) {
     // Convert ms to seconds for the histogram
@@ -585,12 +684,26 @@ mod tests {
     
     #[test]
     fn test_helper_functions() {
-        record_decision_approved();
-        record_decision_rejected(RejectionReason::LowConfidence);
+        record_decision_approved(DecisionPathway::NewLaunch, EntryTrigger::RankBased);
+        record_decision_rejected(DecisionPathway::CopyTrade, EntryTrigger::CopyTrade, RejectionReason::LowConfidence);
         record_decision_pathway(DecisionPathway::CopyTrade);
         record_guardrail_block(GuardrailType::PositionLimit);
         record_cache_access(CacheType::Mint, true);
         update_sol_price(195.50);
         update_active_positions(3);
     }
+    
+    #[test]
+    fn test_decision_outcomes_by_pathway() {
+        let outcomes = &metrics().decision_outcomes;
+        let count = |outcome: &str| outcomes.with_label_values(&["new_launch", "momentum", outcome]).get();
+        let (approved, guardrails) = (count("approved"), count("guardrails"));
+        
+        record_decision_approved(DecisionPathway::NewLaunch, EntryTrigger::Momentum);
+        record_decision_rejected(DecisionPathway::NewLaunch, EntryTrigger::Momentum, RejectionReason::Guardrails);
+        record_decision_rejected(DecisionPathway::NewLaunch, EntryTrigger::Momentum, RejectionReason::Guardrails);
+        
+        assert_eq!(count("approved"), approved + 1);
+        assert_eq!(count("guardrails"), guardrails + 2);
+    }
 }
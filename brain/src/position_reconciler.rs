@@ -0,0 +1,224 @@
+//! 🔍 Position Reconciler - Detect Brain vs on-chain position drift
+//!
+//! Brain is the source of truth for positions, but the Executor can fail silently
+//! (lost confirmation, dropped BUY) and leave Brain tracking a position it never
+//! entered - or holding tokens Brain no longer tracks.
+//!
+//! Every `RECONCILIATION_INTERVAL_SEC` we fetch the wallet's SPL token balances via
+//! RPC and compare them to `PositionTracker`:
+//! - **Phantom**: Brain tracks a position but the wallet holds 0 tokens → removed
+//! - **Orphaned**: wallet holds tokens Brain doesn't track → flagged (not auto-sold)
+//!
+//! Positions with a SELL in flight, dry-run positions and entries younger than the
+//! grace period (BUY may still be landing) are never counted as drift.
+
+use anyhow::{Context, Result};
+use log::{debug, info, warn};
+use solana_client::rpc_client::RpcClient;
+use solana_client::rpc_request::TokenAccountsFilter;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+use crate::decision_engine::PositionTracker;
+use crate::metrics;
+
+/// SPL Token program (classic)
+const SPL_TOKEN_PROGRAM_ID: &str = "TokenkegQfeZyiNwAJbNbGqPxRBZ89jZZwkF8";
+
+/// Kind of drift between Brain and the chain
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DriftKind {
+    /// Brain tracks a position the wallet doesn't hold
+    Phantom,
+    /// Wallet holds tokens Brain doesn't track
+    Orphaned,
+}
+
+impl DriftKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DriftKind::Phantom => "phantom",
+            DriftKind::Orphaned => "orphaned",
+        }
+    }
+}
+
+/// Point-in-time view of a tracked position for drift checks
+#[derive(Debug, Clone)]
+pub struct PositionSnapshot {
+    pub mint: String,
+    pub held_secs: u64,
+    pub sell_in_flight: bool,
+    pub dry_run: bool,
+}
+
+/// Compare tracked positions against on-chain balances (mint → raw token amount)
+pub fn detect_drift(
+    positions: &[PositionSnapshot],
+    holdings: &HashMap<String, u64>,
+    entry_grace_secs: u64,
+) -> Vec<(String, DriftKind)> {
+    let mut drift = Vec::new();
+
+    for pos in positions {
+        if pos.dry_run || pos.sell_in_flight || pos.held_secs < entry_grace_secs {
+            continue;
+        }
+        if holdings.get(&pos.mint).copied().unwrap_or(0) == 0 {
+            drift.push((pos.mint.clone(), DriftKind::Phantom));
+        }
+    }
+
+    let tracked: HashSet<&str> = positions.iter().map(|p| p.mint.as_str()).collect();
+    for (mint, amount) in holdings {
+        if *amount > 0 && !tracked.contains(mint.as_str()) {
+            drift.push((mint.clone(), DriftKind::Orphaned));
+        }
+    }
+
+    drift
+}
+
+/// Periodic Brain/on-chain reconciliation
+pub struct PositionReconciler {
+    rpc_url: String,
+    wallet: Pubkey,
+    /// Don't judge positions younger than this (BUY may still be confirming)
+    entry_grace_secs: u64,
+    /// A SELL sent within this window is still in flight
+    sell_in_flight_window: Duration,
+    /// Orphans already reported (flag once, not every cycle)
+    flagged_orphans: HashSet<String>,
+}
+
+impl PositionReconciler {
+    pub fn new(rpc_url: String, wallet_pubkey: &str, entry_grace_secs: u64, sell_in_flight_secs: u64) -> Result<Self> {
+        let wallet = Pubkey::from_str(wallet_pubkey)
+            .with_context(|| format!("Invalid WALLET_PUBKEY: {}", wallet_pubkey))?;
+
+        Ok(Self {
+            rpc_url,
+            wallet,
+            entry_grace_secs,
+            sell_in_flight_window: Duration::from_secs(sell_in_flight_secs),
+            flagged_orphans: HashSet::new(),
+        })
+    }
+
+    /// Fetch wallet token balances (mint → raw amount) via RPC
+    async fn fetch_holdings(&self) -> Result<HashMap<String, u64>> {
+        let rpc_url = self.rpc_url.clone();
+        let wallet = self.wallet;
+        let program_id = Pubkey::from_str(SPL_TOKEN_PROGRAM_ID)?;
+
+        let accounts = tokio::task::spawn_blocking(move || {
+            RpcClient::new(&rpc_url)
+                .get_token_accounts_by_owner(&wallet, TokenAccountsFilter::ProgramId(program_id))
+                .map_err(|e| anyhow::anyhow!("get_token_accounts_by_owner failed: {}", e))
+        }).await??;
+
+        let mut holdings = HashMap::new();
+        for keyed in accounts {
+            // jsonParsed layout: data.parsed.info.{mint, tokenAmount.amount}
+            let account = serde_json::to_value(&keyed.account)?;
+            let info = &account["data"]["parsed"]["info"];
+            let (Some(mint), Some(amount)) = (
+                info["mint"].as_str(),
+                info["tokenAmount"]["amount"].as_str().and_then(|a| a.parse::<u64>().ok()),
+            ) else {
+                debug!("Skipping unparsed token account {}", keyed.pubkey);
+                continue;
+            };
+            *holdings.entry(mint.to_string()).or_insert(0) += amount;
+        }
+
+        Ok(holdings)
+    }
+
+    /// Run one reconciliation pass; returns the number of drift events found
+    pub async fn reconcile(&mut self, position_tracker: &Arc<RwLock<PositionTracker>>) -> Result<usize> {
+        let holdings = self.fetch_holdings().await?;
+
+        let snapshots: Vec<PositionSnapshot> = {
+            let tracker = position_tracker.read().await;
+            tracker.get_all().into_iter().map(|pos| PositionSnapshot {
+                mint: pos.mint.clone(),
+                held_secs: pos.entry_time.elapsed().as_secs(),
+                sell_in_flight: tracker.is_sell_in_flight(&pos.mint, self.sell_in_flight_window),
+                dry_run: pos.dry_run,
+            }).collect()
+        };
+
+        let drift = detect_drift(&snapshots, &holdings, self.entry_grace_secs);
+        let mut reported = 0;
+
+        for (mint, kind) in drift {
+            match kind {
+                DriftKind::Phantom => {
+                    warn!("👻 POSITION DRIFT: Brain tracks {} but wallet holds 0 tokens - removing phantom position", &mint[..8]);
+                    position_tracker.write().await.remove_position(&mint);
+                    metrics::record_position_closed();
+                }
+                DriftKind::Orphaned => {
+                    if !self.flagged_orphans.insert(mint.clone()) {
+                        continue;
+                    }
+                    warn!("🧟 POSITION DRIFT: wallet holds {} tokens of {} not tracked by Brain (orphaned holding)",
+                          holdings.get(&mint).copied().unwrap_or(0), &mint[..8]);
+                }
+            }
+            metrics::record_position_drift(kind.as_str());
+            reported += 1;
+        }
+
+        // Forget orphans that were sold or adopted since
+        self.flagged_orphans.retain(|mint| {
+            holdings.get(mint).copied().unwrap_or(0) > 0
+                && !snapshots.iter().any(|s| &s.mint == mint)
+        });
+
+        if reported == 0 {
+            debug!("🔍 Reconciliation OK: {} positions, {} token accounts", snapshots.len(), holdings.len());
+        } else {
+            info!("🔍 Reconciliation: {} drift event(s) across {} positions", reported, snapshots.len());
+        }
+
+        Ok(reported)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(mint: &str, held_secs: u64, sell_in_flight: bool) -> PositionSnapshot {
+        PositionSnapshot { mint: mint.to_string(), held_secs, sell_in_flight, dry_run: false }
+    }
+
+    #[test]
+    fn test_detect_drift() {
+        let positions = vec![
+            snapshot("HeldMint11111111", 120, false),     // held on-chain → OK
+            snapshot("PhantomMint11111", 120, false),     // no balance → phantom
+            snapshot("SellingMint11111", 120, true),      // SELL in flight → not drift
+            snapshot("FreshMint1111111", 5, false),       // within grace → not drift
+            PositionSnapshot { dry_run: true, ..snapshot("DryRunMint111111", 120, false) },
+        ];
+        let holdings = HashMap::from([
+            ("HeldMint11111111".to_string(), 1_000),
+            ("OrphanMint111111".to_string(), 500),
+            ("EmptyAccount1111".to_string(), 0),
+        ]);
+
+        let mut drift = detect_drift(&positions, &holdings, 30);
+        drift.sort();
+        assert_eq!(drift, vec![
+            ("OrphanMint111111".to_string(), DriftKind::Orphaned),
+            ("PhantomMint11111".to_string(), DriftKind::Phantom),
+        ]);
+    }
+}
@@ -0,0 +1,232 @@
+//! 🔐 Advice Bus authentication
+//!
+//! Every packet on the Advice Bus (port 45100) is prefixed with a magic byte and a
+//! truncated HMAC-SHA256 tag over the payload, so a local process can't inject
+//! fake advice (e.g. spoofed CopyTrade) without knowing the shared secret:
+//!
+//! ```text
+//! [magic 0xA5][tag 16 bytes][advice payload ...]
+//! ```
+//!
+//! The secret comes from `ADVICE_BUS_SECRET`. When unset, it is read from
+//! `ADVICE_BUS_SECRET_FILE` (default `$XDG_RUNTIME_DIR/advice_bus.secret`) - or
+//! generated there by whichever service starts first, so loopback-only deployments
+//! need no config. A file another user owns or can read is refused.
+//! Must match `data-mining/src/udp/auth.rs`.
+
+use anyhow::{Context, Result};
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use sha2::Sha256;
+use std::fs::{DirBuilder, File, OpenOptions};
+use std::io::{ErrorKind, Read, Write};
+use std::os::unix::fs::{DirBuilderExt, MetadataExt, OpenOptionsExt};
+use std::path::Path;
+use std::sync::Arc;
+
+/// First byte of every authenticated advice packet
+pub const AUTH_MAGIC: u8 = 0xA5;
+
+/// Truncated HMAC-SHA256 tag length
+pub const AUTH_TAG_SIZE: usize = 16;
+
+/// Magic + tag prefix in front of the advice payload
+pub const AUTH_HEADER_SIZE: usize = 1 + AUTH_TAG_SIZE;
+
+/// Secret file name inside the per-user runtime directory
+const SECRET_FILE_NAME: &str = "advice_bus.secret";
+
+/// Default secret file: `$XDG_RUNTIME_DIR/advice_bus.secret`, or a private
+/// per-user directory under /tmp when there is no runtime dir
+pub fn default_secret_file() -> String {
+    let dir = match std::env::var("XDG_RUNTIME_DIR") {
+        Ok(dir) if !dir.is_empty() => dir,
+        _ => current_uid()
+            .map(|uid| format!("/tmp/advice-bus-{}", uid))
+            .unwrap_or_else(|_| "/tmp/advice-bus".to_string()),
+    };
+    format!("{}/{}", dir, SECRET_FILE_NAME)
+}
+
+/// Effective uid of this process
+fn current_uid() -> std::io::Result<u32> {
+    std::fs::metadata("/proc/self").map(|m| m.uid())
+}
+
+/// The secret's directory must be ours (or root's) and not writable by others,
+/// unless sticky like /tmp, where nobody can replace our files
+fn check_secret_dir(dir: &Path, uid: u32) -> Result<()> {
+    let meta = std::fs::metadata(dir)
+        .with_context(|| format!("Failed to stat advice bus secret directory {}", dir.display()))?;
+    let shared_writable = meta.mode() & 0o022 != 0 && meta.mode() & 0o1000 == 0;
+    if (meta.uid() != uid && meta.uid() != 0) || shared_writable {
+        anyhow::bail!("Refusing advice bus secret directory {}: owned by uid {} with mode {:o}",
+                      dir.display(), meta.uid(), meta.mode() & 0o7777);
+    }
+    Ok(())
+}
+
+/// Read an existing secret, refusing one another user planted or can read
+fn read_secret_file(path: &Path, uid: u32) -> Result<String> {
+    let mut file = File::open(path)
+        .with_context(|| format!("Failed to read advice bus secret from {}", path.display()))?;
+    // Check the file actually opened, not the path (no swap between check and read)
+    let meta = file.metadata()?;
+    if !meta.is_file() || meta.uid() != uid || meta.mode() & 0o077 != 0 {
+        anyhow::bail!("Refusing advice bus secret file {}: must be a regular file owned by uid {} with mode 0600",
+                      path.display(), uid);
+    }
+
+    let mut secret = String::new();
+    file.read_to_string(&mut secret)
+        .with_context(|| format!("Failed to read advice bus secret from {}", path.display()))?;
+    let secret = secret.trim();
+    if secret.is_empty() {
+        anyhow::bail!("Advice bus secret file {} is empty", path.display());
+    }
+    Ok(secret.to_string())
+}
+
+/// Shared-secret signer/verifier for Advice Bus packets
+#[derive(Clone)]
+pub struct AdviceAuth {
+    key: Arc<[u8]>,
+}
+
+impl AdviceAuth {
+    pub fn new(secret: &[u8]) -> Self {
+        Self { key: Arc::from(secret) }
+    }
+
+    /// Use `secret` if configured, otherwise read (or create) the shared secret file
+    ///
+    /// A missing directory is created 0700. An existing file is only trusted
+    /// if it belongs to this user and nobody else can read it.
+    pub fn load(secret: Option<&str>, secret_file: &str) -> Result<Self> {
+        if let Some(secret) = secret.map(str::trim).filter(|s| !s.is_empty()) {
+            return Ok(Self::new(secret.as_bytes()));
+        }
+
+        let path = Path::new(secret_file);
+        let uid = current_uid().context("Failed to determine the current uid")?;
+        if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+            DirBuilder::new().recursive(true).mode(0o700).create(dir)
+                .with_context(|| format!("Failed to create advice bus secret directory {}", dir.display()))?;
+            check_secret_dir(dir, uid)?;
+        }
+
+        let mut bytes = [0u8; 32];
+        rand::thread_rng().fill_bytes(&mut bytes);
+        let generated = hex::encode(bytes);
+
+        // Write the whole secret to a private temp file, then hard-link it into
+        // place: atomic, so a peer never reads a half-written file, and (unlike
+        // rename) it never clobbers a secret the other side already loaded
+        let tmp = format!("{}.{}.tmp", secret_file, std::process::id());
+        let _ = std::fs::remove_file(&tmp);
+        let published = OpenOptions::new().write(true).create_new(true).mode(0o600).open(&tmp)
+            .and_then(|mut file| {
+                file.write_all(generated.as_bytes())?;
+                file.sync_all()
+            })
+            .and_then(|_| std::fs::hard_link(&tmp, path));
+        let _ = std::fs::remove_file(&tmp);
+
+        match published {
+            Ok(()) => Ok(Self::new(generated.as_bytes())),
+            Err(e) if e.kind() == ErrorKind::AlreadyExists => {
+                Ok(Self::new(read_secret_file(path, uid)?.as_bytes()))
+            }
+            Err(e) => Err(e).with_context(|| format!("Failed to create advice bus secret file {}", secret_file)),
+        }
+    }
+
+
+    fn tag(&self, payload: &[u8]) -> [u8; AUTH_TAG_SIZE] {
+        let mut mac = Hmac::<Sha256>::new_from_slice(&self.key).expect("HMAC accepts any key length");
+        mac.update(payload);
+        let full = mac.finalize().into_bytes();
+        let mut tag = [0u8; AUTH_TAG_SIZE];
+        tag.copy_from_slice(&full[..AUTH_TAG_SIZE]);
+        tag
+    }
+
+    /// Prefix `payload` with magic + tag
+    pub fn sign(&self, payload: &[u8]) -> Vec<u8> {
+        let mut packet = Vec::with_capacity(AUTH_HEADER_SIZE + payload.len());
+        packet.push(AUTH_MAGIC);
+        packet.extend_from_slice(&self.tag(payload));
+        packet.extend_from_slice(payload);
+        packet
+    }
+
+    /// Check magic + tag; returns the advice payload if the packet is authentic
+    pub fn verify<'a>(&self, packet: &'a [u8]) -> Option<&'a [u8]> {
+        if packet.len() <= AUTH_HEADER_SIZE || packet[0] != AUTH_MAGIC {
+            return None;
+        }
+
+        let (header, payload) = packet.split_at(AUTH_HEADER_SIZE);
+        let expected = self.tag(payload);
+
+        // Constant-time compare
+        let diff = header[1..].iter().zip(expected.iter()).fold(0u8, |acc, (a, b)| acc | (a ^ b));
+        (diff == 0).then_some(payload)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_verify_roundtrip() {
+        let auth = AdviceAuth::new(b"test-secret");
+        let payload = [13u8; 80];
+        let packet = auth.sign(&payload);
+
+        assert_eq!(packet.len(), AUTH_HEADER_SIZE + payload.len());
+        assert_eq!(auth.verify(&packet), Some(&payload[..]));
+
+        // Unsigned, tampered, or foreign-key packets are rejected
+        assert_eq!(auth.verify(&payload), None);
+        let mut tampered = packet.clone();
+        tampered[AUTH_HEADER_SIZE + 5] ^= 1;
+        assert_eq!(auth.verify(&tampered), None);
+        assert_eq!(AdviceAuth::new(b"other-secret").verify(&packet), None);
+        assert_eq!(auth.verify(&packet[..AUTH_HEADER_SIZE]), None);
+    }
+
+    #[test]
+    fn test_load_shares_generated_secret_file() {
+        let path = std::env::temp_dir().join(format!("advice_bus_secret_test_{}", std::process::id()));
+        let path = path.to_str().unwrap();
+        let _ = std::fs::remove_file(path);
+
+        let first = AdviceAuth::load(None, path).unwrap();
+        let second = AdviceAuth::load(Some(""), path).unwrap();
+        assert!(second.verify(&first.sign(b"payload")).is_some());
+
+        let configured = AdviceAuth::load(Some("configured"), path).unwrap();
+        assert!(configured.verify(&first.sign(b"payload")).is_none());
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_load_refuses_readable_secret_file() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = std::env::temp_dir().join(format!("advice_bus_secret_perms_test_{}", std::process::id()));
+        std::fs::write(&path, "planted").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).unwrap();
+        assert!(AdviceAuth::load(None, path.to_str().unwrap()).is_err());
+
+        // Tightened to 0600 it is ours alone and gets used
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600)).unwrap();
+        let loaded = AdviceAuth::load(None, path.to_str().unwrap()).unwrap();
+        assert!(AdviceAuth::new(b"planted").verify(&loaded.sign(b"payload")).is_some());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -30,7 +30,8 @@ pub struct ExitAdvice {
     pub current_price_lamports: u64, // Current price
     pub hold_time_ms: u32,         // Time since entry (milliseconds)
     pub timestamp_ns: u64,         // When advice generated
-    pub _padding: [u8; 8],
+    pub exit_percent: u8,          // Advised % of position to sell (0 = full exit)
+    pub _padding: [u8; 12],
 }
 
 impl ExitAdvice {
@@ -42,6 +43,59 @@ impl ExitAdvice {
     pub const REASON_STOP_LOSS: u8 = 1;
     pub const REASON_FADE_DETECTED: u8 = 2;
     
+    /// Create new ExitAdvice message
+    pub fn new(
+        trade_id: [u8; 16],
+        mint: [u8; 32],
+        reason: u8,
+        confidence: u8,
+        realized_pnl_usd: f64,
+        entry_price_lamports: u64,
+        current_price_lamports: u64,
+        hold_time_ms: u32,
+        exit_percent: u8,
+    ) -> Self {
+        let timestamp_ns = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos() as u64;
+        
+        Self {
+            msg_type: Self::MSG_TYPE,
+            trade_id,
+            mint,
+            reason,
+            confidence,
+            realized_pnl_cents: (realized_pnl_usd * 100.0) as i32,
+            entry_price_lamports,
+            current_price_lamports,
+            hold_time_ms,
+            timestamp_ns,
+            exit_percent,
+            _padding: [0u8; 12],
+        }
+    }
+    
+    /// Serialize to bytes (zero-padded to SIZE)
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(Self::SIZE);
+        
+        bytes.push(self.msg_type);
+        bytes.extend_from_slice(&self.trade_id);
+        bytes.extend_from_slice(&self.mint);
+        bytes.push(self.reason);
+        bytes.push(self.confidence);
+        bytes.extend_from_slice(&self.realized_pnl_cents.to_le_bytes());
+        bytes.extend_from_slice(&self.entry_price_lamports.to_le_bytes());
+        bytes.extend_from_slice(&self.current_price_lamports.to_le_bytes());
+        bytes.extend_from_slice(&self.hold_time_ms.to_le_bytes());
+        bytes.extend_from_slice(&self.timestamp_ns.to_le_bytes());
+        bytes.push(self.exit_percent);
+        bytes.resize(Self::SIZE, 0);
+        
+        bytes
+    }
+    
     /// Parse from UDP bytes
     pub fn from_bytes(data: &[u8]) -> Result<Self> {
         if data.len() < Self::SIZE {
@@ -94,6 +148,9 @@ impl ExitAdvice {
         let timestamp_ns = u64::from_le_bytes(
             data[offset..offset+8].try_into().context("Invalid timestamp_ns")?
         );
+        offset += 8;
+        
+        let exit_percent = data[offset];
         
         Ok(Self {
             msg_type,
@@ -106,7 +163,8 @@ impl ExitAdvice {
             current_price_lamports,
             hold_time_ms,
             timestamp_ns,
-            _padding: [0u8; 8],
+            exit_percent,
+            _padding: [0u8; 12],
         })
     }
     
@@ -132,6 +190,27 @@ impl ExitAdvice {
         }
     }
     
+    /// True when the advice is a profit-target hit
+    pub fn is_profit_target(&self) -> bool {
+        self.reason == Self::REASON_TARGET_HIT
+    }
+    
+    /// True when the advice is a stop-loss hit
+    pub fn is_stop_loss(&self) -> bool {
+        self.reason == Self::REASON_STOP_LOSS
+    }
+    
+    /// Percent of the position to sell (1-100)
+    ///
+    /// Stop-losses always close the full position; unset (0) means full exit.
+    pub fn effective_exit_percent(&self) -> u8 {
+        if self.is_stop_loss() || self.exit_percent == 0 {
+            100
+        } else {
+            self.exit_percent.min(100)
+        }
+    }
+    
     /// Get realized P&L in USD (unscaled)
     pub fn realized_pnl_usd(&self) -> f64 {
         self.realized_pnl_cents as f64 / 100.0
@@ -152,3 +231,42 @@ impl ExitAdvice {
         (price_diff / self.entry_price_lamports as f64) * 100.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_exit_advice_roundtrip() {
+        let advice = ExitAdvice::new(
+            [1u8; 16],
+            [2u8; 32],
+            ExitAdvice::REASON_TARGET_HIT,
+            90,
+            12.5,
+            1_000,
+            1_500,
+            30_000,
+            50,
+        );
+        
+        let bytes = advice.to_bytes();
+        assert_eq!(bytes.len(), ExitAdvice::SIZE);
+        
+        let decoded = ExitAdvice::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded.mint, [2u8; 32]);
+        assert!(decoded.is_profit_target());
+        assert_eq!(decoded.realized_pnl_cents, 1250);
+        assert_eq!(decoded.exit_percent, 50);
+        assert_eq!(decoded.effective_exit_percent(), 50);
+    }
+    
+    #[test]
+    fn test_stop_loss_exits_full_position() {
+        let advice = ExitAdvice::new(
+            [0u8; 16], [3u8; 32], ExitAdvice::REASON_STOP_LOSS, 100, -4.0, 1_000, 800, 10_000, 25,
+        );
+        assert!(advice.is_stop_loss());
+        assert_eq!(advice.effective_exit_percent(), 100);
+    }
+}
@@ -4,11 +4,20 @@
 //! All structs are #[repr(C)] for predictable memory layout and zero-copy serialization.
 
 use anyhow::Result;
-use crate::udp_bus::PositionUpdate;
+use crate::udp_bus::{ExitAdvice, PositionUpdate};
+
+/// Short trace tag for logs: first 8 hex chars of the trace UUID, "-" when unset
+pub fn trace_tag(trace_id: &[u8; 16]) -> String {
+    if *trace_id == [0u8; 16] {
+        "-".to_string()
+    } else {
+        hex::encode(&trace_id[..4])
+    }
+}
 
 /// 📦 TradeDecision - Brain → Executor (Port 45110)
 /// 
-/// 52-byte packet containing a validated trade decision ready for immediate execution.
+/// 92-byte packet containing a validated trade decision ready for immediate execution.
 /// The executor receives this and builds+sends the transaction without additional logic.
 #[derive(Debug, Clone, Copy)]
 #[repr(C)]
@@ -40,16 +49,32 @@ pub struct TradeDecision {
     /// Retry count for progressive slippage widening (0 = first attempt, max 3)
     pub retry_count: u8,
     
-    /// Entry strategy type: 0=Rank, 1=Momentum, 2=CopyTrade, 3=LateOpportunity
+    /// Entry strategy type: 0=Rank, 1=Momentum, 2=CopyTrade, 3=LateOpportunity, 4=Hotlist
     pub entry_type: u8,
     
-    /// Padding to align to 52 bytes
-    pub _padding: [u8; 1],
+    /// SELL only: minimum SOL to receive in lamports (0 = no floor)
+    ///
+    /// Derived by Brain from the current market price and exit slippage so a
+    /// fast dump can't fill at an arbitrarily bad price.
+    pub min_sol_out_lamports: u64,
+    
+    /// Unique decision ID (UUID v4 bytes) so the executor can drop retransmits
+    pub decision_id: [u8; 16],
+    
+    /// Padding (bytes 73..76)
+    pub _padding: [u8; 3],
+    
+    /// Pipeline correlation ID (UUID v4 bytes, bytes 76..92)
+    ///
+    /// Unlike `decision_id` (one per packet), the trace follows a trade from
+    /// Brain's decision through the executor's `ExecutionConfirmation` and the
+    /// watcher's `TxConfirmed`, and is kept across SELL retries.
+    pub trace_id: [u8; 16],
 }
 
 impl TradeDecision {
     /// Total packet size in bytes
-    pub const SIZE: usize = 52;
+    pub const SIZE: usize = 92;
     
     /// Message type constant
     pub const MSG_TYPE: u8 = 1;
@@ -59,7 +84,8 @@ impl TradeDecision {
     
     /// Calculate checksum for data integrity (XOR of all data bytes)
     fn calculate_checksum(msg_type: u8, protocol_version: u8, mint: &[u8; 32], side: u8, 
-                          size_lamports: u64, slippage_bps: u16, confidence: u8, retry_count: u8, entry_type: u8) -> u8 {
+                          size_lamports: u64, slippage_bps: u16, confidence: u8, retry_count: u8, entry_type: u8,
+                          min_sol_out_lamports: u64, decision_id: &[u8; 16], trace_id: &[u8; 16]) -> u8 {
         let mut checksum = msg_type ^ protocol_version ^ side ^ confidence ^ retry_count ^ entry_type;
         for &byte in mint {
             checksum ^= byte;
@@ -67,6 +93,15 @@ impl TradeDecision {
         for &byte in &size_lamports.to_le_bytes() {
             checksum ^= byte;
         }
+        for &byte in &min_sol_out_lamports.to_le_bytes() {
+            checksum ^= byte;
+        }
+        for &byte in decision_id {
+            checksum ^= byte;
+        }
+        for &byte in trace_id {
+            checksum ^= byte;
+        }
         for &byte in &slippage_bps.to_le_bytes() {
             checksum ^= byte;
         }
@@ -85,6 +120,9 @@ impl TradeDecision {
             self.confidence,
             self.retry_count,
             self.entry_type,
+            self.min_sol_out_lamports,
+            &self.decision_id,
+            &self.trace_id,
         );
         self.checksum == expected
     }
@@ -142,6 +180,8 @@ impl TradeDecision {
     /// Create a new BUY decision
     pub fn new_buy(mint: [u8; 32], size_lamports: u64, slippage_bps: u16, confidence: u8, entry_type: u8) -> Self {
         let retry_count = 0; // BUYs don't use retry logic
+        let decision_id = *uuid::Uuid::new_v4().as_bytes();
+        let trace_id = *uuid::Uuid::new_v4().as_bytes();
         let checksum = Self::calculate_checksum(
             Self::MSG_TYPE,
             Self::PROTOCOL_VERSION,
@@ -152,6 +192,9 @@ impl TradeDecision {
             confidence,
             retry_count,
             entry_type,
+            0, // BUYs carry no SOL-out floor
+            &decision_id,
+            &trace_id,
         );
         
         Self {
@@ -165,12 +208,21 @@ impl TradeDecision {
             checksum,
             retry_count,
             entry_type,
-            _padding: [0; 1],
+            min_sol_out_lamports: 0,
+            decision_id,
+            _padding: [0; 3],
+            trace_id,
         }
     }
     
     /// Create a new SELL decision with retry count for progressive slippage
-    pub fn new_sell(mint: [u8; 32], size_lamports: u64, slippage_bps: u16, confidence: u8, retry_count: u8, entry_type: u8) -> Self {
+    ///
+    /// `min_sol_out_lamports` is the worst acceptable fill (see `min_sol_out_for`);
+    /// pass 0 when no market price is known.
+    pub fn new_sell(mint: [u8; 32], size_lamports: u64, slippage_bps: u16, confidence: u8, retry_count: u8, entry_type: u8,
+                    min_sol_out_lamports: u64) -> Self {
+        let decision_id = *uuid::Uuid::new_v4().as_bytes();
+        let trace_id = *uuid::Uuid::new_v4().as_bytes();
         let checksum = Self::calculate_checksum(
             Self::MSG_TYPE,
             Self::PROTOCOL_VERSION,
@@ -181,6 +233,9 @@ impl TradeDecision {
             confidence,
             retry_count,
             entry_type,
+            min_sol_out_lamports,
+            &decision_id,
+            &trace_id,
         );
         
         Self {
@@ -194,8 +249,22 @@ impl TradeDecision {
             checksum,
             retry_count,
             entry_type,
-            _padding: [0; 1],
+            min_sol_out_lamports,
+            decision_id,
+            _padding: [0; 3],
+            trace_id,
+        }
+    }
+    
+    /// Minimum SOL out (lamports) for selling `tokens` at `price_sol` per token,
+    /// allowing `slippage_bps` of adverse movement. Returns 0 (no floor) when
+    /// the price or amount is unknown.
+    pub fn min_sol_out_for(tokens: f64, price_sol: f64, slippage_bps: u16) -> u64 {
+        if !(tokens > 0.0 && price_sol > 0.0) || !price_sol.is_finite() {
+            return 0;
         }
+        let slippage = (slippage_bps.min(10_000) as f64) / 10_000.0;
+        (tokens * price_sol * (1.0 - slippage) * 1e9) as u64
     }
     
     /// Serialize to bytes for UDP transmission
@@ -211,7 +280,10 @@ impl TradeDecision {
         buf[46] = self.checksum;
         buf[47] = self.retry_count;
         buf[48] = self.entry_type;
-        // Padding already zeros
+        buf[49..57].copy_from_slice(&self.min_sol_out_lamports.to_le_bytes());
+        buf[57..73].copy_from_slice(&self.decision_id);
+        // 73..76 padding already zeros
+        buf[76..92].copy_from_slice(&self.trace_id);
         buf
     }
     
@@ -240,6 +312,11 @@ impl TradeDecision {
         let checksum = buf[46];
         let retry_count = buf[47];
         let entry_type = buf[48];
+        let min_sol_out_lamports = u64::from_le_bytes(buf[49..57].try_into()?);
+        let mut decision_id = [0u8; 16];
+        decision_id.copy_from_slice(&buf[57..73]);
+        let mut trace_id = [0u8; 16];
+        trace_id.copy_from_slice(&buf[76..92]);
         
         let decision = Self {
             msg_type: Self::MSG_TYPE,
@@ -252,7 +329,10 @@ impl TradeDecision {
             checksum,
             retry_count,
             entry_type,
-            _padding: [0; 1],
+            min_sol_out_lamports,
+            decision_id,
+            _padding: [0; 3],
+            trace_id,
         };
         
         // Verify checksum for data integrity
@@ -282,6 +362,16 @@ impl TradeDecision {
     pub fn size_sol(&self) -> f64 {
         self.size_lamports as f64 / 1_000_000_000.0
     }
+    
+    /// Decision ID as a UUID string (for logging/tracing)
+    pub fn decision_id_str(&self) -> String {
+        uuid::Uuid::from_bytes(self.decision_id).to_string()
+    }
+    
+    /// Trace ID as a UUID string (for logging/tracing)
+    pub fn trace_id_str(&self) -> String {
+        uuid::Uuid::from_bytes(self.trace_id).to_string()
+    }
 }
 
 /// ✅ ExecutionConfirmation - Executor → Brain (Port 45115)
@@ -318,8 +408,26 @@ pub struct ExecutionConfirmation {
     /// Success flag: 1 = success, 0 = failed
     pub success: u8,
     
+    /// Dry-run flag: 1 = simulated only (EXECUTOR_DRY_RUN), never submitted on-chain
+    pub dry_run: u8,
+    
+    /// Circuit-open flag: 1 = Executor's circuit breaker is open, trade not attempted
+    pub circuit_open: u8,
+    
+    /// Remaining circuit-breaker cooldown in seconds (0 unless circuit_open)
+    pub circuit_cooldown_secs: u16,
+    
     /// Padding to align to 128 bytes
-    pub _padding: [u8; 7],
+    pub _padding: [u8; 3],
+    
+    /// BUY: tokens quoted from the bonding curve before sending (base units, 0 = no quote)
+    pub quoted_tokens: u64,
+    
+    /// BUY: effective entry price for the quote (SOL in / tokens out, SOL per token)
+    pub effective_price_sol: f64,
+    
+    /// Trace ID echoed from the originating `TradeDecision` (all zeros = none)
+    pub trace_id: [u8; 16],
 }
 
 impl ExecutionConfirmation {
@@ -355,10 +463,35 @@ impl ExecutionConfirmation {
             tx_signature,
             timestamp,
             success: 1,
-            _padding: [0; 7],
+            dry_run: 0,
+            circuit_open: 0,
+            circuit_cooldown_secs: 0,
+            _padding: [0; 3],
+            quoted_tokens: 0,
+            effective_price_sol: 0.0,
+            trace_id: [0; 16],
         }
     }
     
+    /// Mark this confirmation as a dry-run (simulated, not submitted)
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run as u8;
+        self
+    }
+    
+    /// Attach the pre-trade bonding curve quote (BUY)
+    pub fn with_quote(mut self, quoted_tokens: u64, effective_price_sol: f64) -> Self {
+        self.quoted_tokens = quoted_tokens;
+        self.effective_price_sol = effective_price_sol;
+        self
+    }
+    
+    /// Echo the trace ID of the `TradeDecision` this confirms
+    pub fn with_trace_id(mut self, trace_id: [u8; 16]) -> Self {
+        self.trace_id = trace_id;
+        self
+    }
+    
     /// Create a new execution confirmation for failed trade
     pub fn new_failure(mint: [u8; 32], side: u8) -> Self {
         let timestamp = std::time::SystemTime::now()
@@ -376,7 +509,23 @@ impl ExecutionConfirmation {
             tx_signature: [0; 32],
             timestamp,
             success: 0,
-            _padding: [0; 7],
+            dry_run: 0,
+            circuit_open: 0,
+            circuit_cooldown_secs: 0,
+            _padding: [0; 3],
+            quoted_tokens: 0,
+            effective_price_sol: 0.0,
+            trace_id: [0; 16],
+        }
+    }
+    
+    /// Create a "circuit open" confirmation: trade not attempted, Brain should
+    /// pause decision-making for `cooldown_secs`
+    pub fn new_circuit_open(mint: [u8; 32], side: u8, cooldown_secs: u16) -> Self {
+        Self {
+            circuit_open: 1,
+            circuit_cooldown_secs: cooldown_secs,
+            ..Self::new_failure(mint, side)
         }
     }
     
@@ -392,6 +541,12 @@ impl ExecutionConfirmation {
         buf[51..83].copy_from_slice(&self.tx_signature);
         buf[83..91].copy_from_slice(&self.timestamp.to_le_bytes());
         buf[91] = self.success;
+        buf[92] = self.dry_run;
+        buf[93] = self.circuit_open;
+        buf[94..96].copy_from_slice(&self.circuit_cooldown_secs.to_le_bytes());
+        buf[96..104].copy_from_slice(&self.quoted_tokens.to_le_bytes());
+        buf[104..112].copy_from_slice(&self.effective_price_sol.to_le_bytes());
+        buf[112..128].copy_from_slice(&self.trace_id);
         buf
     }
     
@@ -422,6 +577,13 @@ impl ExecutionConfirmation {
         
         let timestamp = u64::from_le_bytes(buf[83..91].try_into()?);
         let success = buf[91];
+        let dry_run = buf[92];
+        let circuit_open = buf[93];
+        let circuit_cooldown_secs = u16::from_le_bytes(buf[94..96].try_into()?);
+        let quoted_tokens = u64::from_le_bytes(buf[96..104].try_into()?);
+        let effective_price_sol = f64::from_le_bytes(buf[104..112].try_into()?);
+        let mut trace_id = [0u8; 16];
+        trace_id.copy_from_slice(&buf[112..128]);
         
         Ok(Self {
             msg_type: Self::MSG_TYPE,
@@ -433,7 +595,13 @@ impl ExecutionConfirmation {
             tx_signature,
             timestamp,
             success,
-            _padding: [0; 7],
+            dry_run,
+            circuit_open,
+            circuit_cooldown_secs,
+            _padding: [0; 3],
+            quoted_tokens,
+            effective_price_sol,
+            trace_id,
         })
     }
     
@@ -452,6 +620,16 @@ impl ExecutionConfirmation {
         self.success == 1
     }
     
+    /// Check if this was a dry-run (simulated only, no real position)
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run == 1
+    }
+    
+    /// Check if Executor's circuit breaker is open (trade not attempted)
+    pub fn is_circuit_open(&self) -> bool {
+        self.circuit_open == 1
+    }
+    
     /// Get executed price in SOL per token
     pub fn executed_price_sol(&self) -> f64 {
         self.executed_price_scaled as f64 / 1e9
@@ -462,6 +640,14 @@ impl ExecutionConfirmation {
         self.executed_size_lamports as f64 / 1_000_000_000.0
     }
     
+    /// Pre-trade bonding curve quote (tokens, effective SOL per token), if the Executor sent one
+    pub fn entry_quote(&self) -> Option<(f64, f64)> {
+        if self.quoted_tokens == 0 || !self.effective_price_sol.is_finite() || self.effective_price_sol <= 0.0 {
+            return None;
+        }
+        Some((self.quoted_tokens as f64 / 1_000_000.0, self.effective_price_sol))
+    }
+    
     /// Get mint address as bs58 string
     pub fn mint_bs58(&self) -> String {
         bs58::encode(&self.mint).into_string()
@@ -635,6 +821,66 @@ mod tests {
         assert_eq!(decoded.confidence, 95);
         assert_eq!(decoded.entry_type, 2);
         assert!(decoded.is_buy());
+        
+        // Every decision gets its own ID
+        let again = TradeDecision::new_buy(mint, 1_000_000_000, 150, 95, 2);
+        assert_eq!(decoded.decision_id, decision.decision_id);
+        assert_ne!(again.decision_id, decision.decision_id);
+    }
+    
+    #[test]
+    fn test_sell_decision_carries_min_sol_out() {
+        let mint = [4u8; 32];
+        // 1M tokens at 0.00001 SOL with 3% slippage -> 9.7 SOL floor
+        let min_out = TradeDecision::min_sol_out_for(1_000_000.0, 0.00001, 300);
+        assert_eq!(min_out, 9_700_000_000);
+        assert_eq!(TradeDecision::min_sol_out_for(1_000_000.0, 0.0, 300), 0);
+        
+        let decision = TradeDecision::new_sell(mint, 500_000_000, 300, 80, 1, 0, min_out);
+        let bytes = decision.to_bytes();
+        assert_eq!(bytes.len(), TradeDecision::SIZE);
+        
+        let decoded = TradeDecision::from_bytes(&bytes).unwrap();
+        assert!(decoded.is_sell());
+        assert_eq!(decoded.min_sol_out_lamports, min_out);
+        assert_eq!(decoded.decision_id, decision.decision_id);
+        
+        // Floor is covered by the checksum
+        let mut corrupted = bytes;
+        corrupted[49] ^= 0xFF;
+        assert!(TradeDecision::from_bytes(&corrupted).is_err());
+    }
+    
+    #[test]
+    fn test_trace_id_threads_through_pipeline() {
+        let mint = [6u8; 32];
+        let decision = TradeDecision::new_buy(mint, 100_000_000, 150, 90, 0);
+        assert_ne!(decision.trace_id, [0u8; 16]);
+        
+        let decoded = TradeDecision::from_bytes(&decision.to_bytes()).unwrap();
+        assert_eq!(decoded.trace_id, decision.trace_id);
+        assert_eq!(&decoded.trace_id_str()[..8], trace_tag(&decision.trace_id));
+        
+        // SELL retries keep the original trace; the trace is covered by the checksum
+        let retry = TradeDecision::new_sell(mint, 100_000_000, 500, 90, 1, 0, 0)
+            .with_trace_id(decision.trace_id);
+        let mut bytes = retry.to_bytes();
+        assert_eq!(TradeDecision::from_bytes(&bytes).unwrap().trace_id, decision.trace_id);
+        bytes[80] ^= 0xFF;
+        assert!(TradeDecision::from_bytes(&bytes).is_err());
+        
+        // Executor echoes it back in the confirmation
+        let conf = ExecutionConfirmation::new_success(mint, 0, 100_000_000, 0.00001, [1u8; 32])
+            .with_trace_id(decision.trace_id);
+        let conf = ExecutionConfirmation::from_bytes(&conf.to_bytes()).unwrap();
+        assert_eq!(conf.trace_id, decision.trace_id);
+        assert_eq!(trace_tag(&ExecutionConfirmation::new_failure(mint, 0).trace_id), "-");
+        
+        // Watcher's TxConfirmed carries it at bytes 97..113
+        let mut tx = [0u8; TxConfirmed::SIZE];
+        tx[0] = TxConfirmed::MSG_TYPE;
+        tx[97..113].copy_from_slice(&decision.trace_id);
+        assert_eq!(TxConfirmed::from_bytes(&tx).unwrap().trace_id, decision.trace_id);
     }
     
     #[test]
@@ -662,6 +908,19 @@ mod tests {
         assert_eq!(decoded.side, 0);
         assert_eq!(decoded.executed_size_lamports, 1_000_000_000);
         assert!(decoded.is_success());
+        assert!(!decoded.is_dry_run());
+
+        let dry = ExecutionConfirmation::from_bytes(&confirmation.with_dry_run(true).to_bytes()).unwrap();
+        assert!(dry.is_dry_run());
+        assert!(dry.is_success());
+        assert_eq!(decoded.entry_quote(), None);
+        
+        let quoted = ExecutionConfirmation::from_bytes(
+            &confirmation.with_quote(34_612_903_225_806, 0.0000000289).to_bytes()
+        ).unwrap();
+        let (tokens, price) = quoted.entry_quote().unwrap();
+        assert!((tokens - 34_612_903.225806).abs() < 1e-6);
+        assert_eq!(price, 0.0000000289);
     }
     
     #[test]
@@ -672,6 +931,19 @@ mod tests {
         assert!(confirmation.is_sell());
         assert!(!confirmation.is_success());
         assert_eq!(confirmation.executed_size_lamports, 0);
+        assert!(!confirmation.is_circuit_open());
+    }
+    
+    #[test]
+    fn test_execution_confirmation_circuit_open() {
+        let mint = [5u8; 32];
+        let bytes = ExecutionConfirmation::new_circuit_open(mint, 0, 120).to_bytes();
+        
+        let decoded = ExecutionConfirmation::from_bytes(&bytes).unwrap();
+        assert!(decoded.is_circuit_open());
+        assert!(!decoded.is_success());
+        assert_eq!(decoded.circuit_cooldown_secs, 120);
+        assert_eq!(decoded.mint, mint);
     }
     
     #[test]
@@ -779,7 +1051,10 @@ pub enum AdviceMessageType {
     EnterAck = 27,          // ✅ NEW: Executor acknowledges BUY command received (provides feedback)
     TradeClosed = 28,       // ✅ Executor signals trade fully finalized (audit trail)
     WindowMetrics = 29,     // ✅ Real-time market metrics (volume, buyers, price change, alpha activity)
+    ExitAdvice = 30,        // ✅ Mempool-watcher reports profit target / stop-loss hit
+    HotlistOpportunity = 31, // Hotlist 7-signal top entry (from data-mining)
     PositionUpdate = 32,    // ✅ NEW: Mempool-watcher sends real-time P&L updates
+    CurveComplete = 33,     // Bonding curve filled, migration imminent (from data-mining)
 }
 
 impl AdviceMessageType {
@@ -804,7 +1079,10 @@ impl AdviceMessageType {
             27 => Some(Self::EnterAck),
             28 => Some(Self::TradeClosed),
             29 => Some(Self::WindowMetrics),
+            30 => Some(Self::ExitAdvice),
+            31 => Some(Self::HotlistOpportunity),
             32 => Some(Self::PositionUpdate),
+            33 => Some(Self::CurveComplete),
             _ => None,
         }
     }
@@ -970,7 +1248,8 @@ pub struct SolPriceUpdate {
     pub price_usd: f32,         // SOL price in USD
     pub timestamp: u64,         // Unix timestamp
     pub source: u8,             // Price source: 1=Pyth, 2=Jupiter, 3=Birdeye
-    pub _padding: [u8; 18],
+    pub timestamp_ms: u64,      // Monotonic broadcast time in ms (0 = older sender, use timestamp)
+    pub _padding: [u8; 10],
 }
 
 impl SolPriceUpdate {
@@ -989,9 +1268,29 @@ impl SolPriceUpdate {
                 bytes[9], bytes[10], bytes[11], bytes[12]
             ]),
             source: bytes[13],
-            _padding: [0u8; 18],
+            timestamp_ms: u64::from_le_bytes([
+                bytes[14], bytes[15], bytes[16], bytes[17],
+                bytes[18], bytes[19], bytes[20], bytes[21]
+            ]),
+            _padding: [0u8; 10],
         })
     }
+    
+    /// Broadcast time in ms, falling back to the seconds field for senders
+    /// that predate `timestamp_ms`
+    pub fn broadcast_ms(&self) -> u64 {
+        match self.timestamp_ms {
+            0 => self.timestamp.saturating_mul(1000),
+            ms => ms,
+        }
+    }
+    
+    /// Whether this price should replace one broadcast at `last_ms`: it must be
+    /// newer (drops reordered/replayed packets) and at most `max_age_ms` old
+    pub fn is_fresh(&self, last_ms: u64, now_ms: u64, max_age_ms: u64) -> bool {
+        let at = self.broadcast_ms();
+        at > last_ms && now_ms.saturating_sub(at) <= max_age_ms
+    }
 }
 
 /// MomentumOpportunity advice - explosive short-term activity (Path B)
@@ -1065,6 +1364,47 @@ impl RankOpportunityAdvice {
     }
 }
 
+/// HotlistOpportunity advice - top hotlist entry from data-mining's 7-signal scorer
+#[derive(Debug, Clone)]
+#[repr(C, packed)]
+pub struct HotlistOpportunityAdvice {
+    pub msg_type: u8,           // 31
+    pub mint: [u8; 32],         // Token mint
+    pub score_scaled: u16,      // 7-signal score * 100 (0-1500)
+    pub rank: u8,               // Position in this scoring cycle (1 = best)
+    pub unique_buyers: u16,     // Unique buyers in last 60s
+    pub mc_velocity: f32,       // MC velocity (SOL/min)
+    pub _padding: [u8; 22],
+}
+
+impl HotlistOpportunityAdvice {
+    pub const SIZE: usize = 64;
+    
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < Self::SIZE {
+            return None;
+        }
+        
+        let mut mint = [0u8; 32];
+        mint.copy_from_slice(&bytes[1..33]);
+        
+        Some(Self {
+            msg_type: bytes[0],
+            mint,
+            score_scaled: u16::from_le_bytes([bytes[33], bytes[34]]),
+            rank: bytes[35],
+            unique_buyers: u16::from_le_bytes([bytes[36], bytes[37]]),
+            mc_velocity: f32::from_le_bytes([bytes[38], bytes[39], bytes[40], bytes[41]]),
+            _padding: [0u8; 22],
+        })
+    }
+    
+    /// 7-signal score (0.0 - 15.0)
+    pub fn score(&self) -> f64 {
+        self.score_scaled as f64 / 100.0
+    }
+}
+
 /// Mempool heat index from mempool-watcher
 #[derive(Debug, Clone)]
 #[repr(C, packed)]
@@ -1264,7 +1604,8 @@ pub struct MomentumDetectedAdvice {
     pub unique_buyers: u16,        // Number of unique buyers
     pub confidence: u8,            // Confidence score 0-100
     pub timestamp_ns: u64,         // Timestamp (nanoseconds)
-    pub _padding: [u8; 7],         // Padding to align to 64 bytes
+    pub seq: u32,                  // Sender sequence number (0 = unsequenced)
+    pub _padding: [u8; 10],        // Padding to align to 64 bytes
 }
 
 impl MomentumDetectedAdvice {
@@ -1287,7 +1628,44 @@ impl MomentumDetectedAdvice {
             confidence: bytes[41],
             timestamp_ns: u64::from_le_bytes([bytes[42], bytes[43], bytes[44], bytes[45],
                                                bytes[46], bytes[47], bytes[48], bytes[49]]),
-            _padding: [0u8; 7],
+            seq: u32::from_le_bytes([bytes[50], bytes[51], bytes[52], bytes[53]]),
+            _padding: [0u8; 10],
+        })
+    }
+}
+
+/// CurveComplete - Data-Mining → Brain (bonding curve filled, migration imminent)
+#[derive(Debug, Clone)]
+#[repr(C, packed)]
+pub struct CurveCompleteAdvice {
+    pub msg_type: u8,              // 33
+    pub mint: [u8; 32],            // Token mint
+    pub slot: u64,                 // Slot of the COMPLETE event
+    pub block_time: i64,           // Block time of the COMPLETE event (unix secs)
+    pub timestamp_ns: u64,         // When sent (nanoseconds)
+    pub seq: u32,                  // Sender sequence number (0 = unsequenced)
+    pub _padding: [u8; 3],         // Padding to align to 64 bytes
+}
+
+impl CurveCompleteAdvice {
+    pub const SIZE: usize = 64;
+    
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < Self::SIZE {
+            return None;
+        }
+        
+        let mut mint = [0u8; 32];
+        mint.copy_from_slice(&bytes[1..33]);
+        
+        Some(Self {
+            msg_type: bytes[0],
+            mint,
+            slot: u64::from_le_bytes(bytes[33..41].try_into().ok()?),
+            block_time: i64::from_le_bytes(bytes[41..49].try_into().ok()?),
+            timestamp_ns: u64::from_le_bytes(bytes[49..57].try_into().ok()?),
+            seq: u32::from_le_bytes(bytes[57..61].try_into().ok()?),
+            _padding: [0u8; 3],
         })
     }
 }
@@ -1303,7 +1681,8 @@ pub struct VolumeSpikeAdvice {
     pub time_window_ms: u16,       // Time window in milliseconds
     pub confidence: u8,            // Confidence score 0-100
     pub timestamp_ns: u64,         // Timestamp (nanoseconds)
-    pub _padding: [u8; 11],        // Padding to align to 64 bytes
+    pub seq: u32,                  // Sender sequence number (0 = unsequenced)
+    pub _padding: [u8; 10],        // Padding to align to 64 bytes
 }
 
 impl VolumeSpikeAdvice {
@@ -1326,7 +1705,8 @@ impl VolumeSpikeAdvice {
             confidence: bytes[41],
             timestamp_ns: u64::from_le_bytes([bytes[42], bytes[43], bytes[44], bytes[45],
                                                bytes[46], bytes[47], bytes[48], bytes[49]]),
-            _padding: [0u8; 11],
+            seq: u32::from_le_bytes([bytes[50], bytes[51], bytes[52], bytes[53]]),
+            _padding: [0u8; 10],
         })
     }
 }
@@ -1343,7 +1723,7 @@ pub struct WalletActivityAdvice {
     pub wallet_tier: u8,           // Wallet tier (0=Discovery, 1=C, 2=B, 3=A)
     pub confidence: u8,            // Confidence score 0-100
     pub timestamp_ns: u64,         // Timestamp (nanoseconds)
-    pub _padding: [u8; 12],        // Padding to align to 80 bytes
+    pub seq: u32,                  // Sender sequence number, bytes 80..84 (0 = unsequenced)
 }
 
 impl WalletActivityAdvice {
@@ -1370,7 +1750,10 @@ impl WalletActivityAdvice {
             confidence: bytes[71],
             timestamp_ns: u64::from_le_bytes([bytes[72], bytes[73], bytes[74], bytes[75],
                                                bytes[76], bytes[77], bytes[78], bytes[79]]),
-            _padding: [0u8; 12],
+            // Layout is full at 80 bytes; sequenced senders append seq
+            seq: bytes.get(80..84)
+                .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+                .unwrap_or(0),
         })
     }
 }
@@ -1539,7 +1922,7 @@ pub struct TxConfirmed {
     pub msg_type: u8,              // 26
     pub signature: [u8; 64],       // Transaction signature
     pub mint: [u8; 32],            // Token mint
-    pub trade_id: [u8; 16],        // UUID of trade (first 16 bytes)
+    pub trace_id: [u8; 16],        // Brain trace UUID (from WatchSignature)
     pub side: u8,                  // 0=BUY, 1=SELL
     pub status: u8,                // 0=SUCCESS, 1=FAILED
     pub timestamp_ns: u64,         // When confirmed (nanoseconds)
@@ -1569,8 +1952,8 @@ impl TxConfirmed {
         let mut mint = [0u8; 32];
         mint.copy_from_slice(&data[65..97]);
         
-        let mut trade_id = [0u8; 16];
-        trade_id.copy_from_slice(&data[97..113]);
+        let mut trace_id = [0u8; 16];
+        trace_id.copy_from_slice(&data[97..113]);
         
         let side = data[113];
         let status = data[114];
@@ -1584,7 +1967,7 @@ impl TxConfirmed {
             msg_type: data[0],
             signature,
             mint,
-            trade_id,
+            trace_id,
             side,
             status,
             timestamp_ns,
@@ -1713,7 +2096,8 @@ pub struct WindowMetrics {
     pub price_change_bps_2s: i16,  // Price change over 2s in basis points (100 = 1%)
     pub alpha_wallet_hits_10s: u8, // Alpha wallet buys in last 10s
     pub timestamp_ns: u64,         // When metrics calculated (nanoseconds)
-    pub _padding: [u8; 13],        // Padding to 64 bytes
+    pub seq: u32,                  // Sender sequence number (0 = unsequenced)
+    pub _padding: [u8; 10],        // Padding to 64 bytes
 }
 
 impl WindowMetrics {
@@ -1742,7 +2126,8 @@ impl WindowMetrics {
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap()
                 .as_nanos() as u64,
-            _padding: [0u8; 13],
+            seq: 0,
+            _padding: [0u8; 10],
         }
     }
     
@@ -1756,6 +2141,7 @@ impl WindowMetrics {
         bytes.extend_from_slice(&self.price_change_bps_2s.to_le_bytes());
         bytes.push(self.alpha_wallet_hits_10s);
         bytes.extend_from_slice(&self.timestamp_ns.to_le_bytes());
+        bytes.extend_from_slice(&self.seq.to_le_bytes());
         bytes.extend_from_slice(&self._padding);
         bytes
     }
@@ -1791,7 +2177,8 @@ impl WindowMetrics {
             price_change_bps_2s,
             alpha_wallet_hits_10s,
             timestamp_ns,
-            _padding: [0u8; 13],
+            seq: u32::from_le_bytes([data[50], data[51], data[52], data[53]]),
+            _padding: [0u8; 10],
         })
     }
     
@@ -1811,6 +2198,7 @@ pub enum AdviceMessage {
     SolPriceUpdate(SolPriceUpdate),
     MomentumOpportunity(MomentumOpportunityAdvice),
     RankOpportunity(RankOpportunityAdvice),
+    HotlistOpportunity(HotlistOpportunityAdvice),
     MempoolHeat(MempoolHeatAdvice),
     TradeSubmitted(TradeSubmittedAdvice),
     TradeConfirmed(TradeConfirmedAdvice),
@@ -1823,80 +2211,192 @@ pub enum AdviceMessage {
     EnterAck(EnterAck),  // ✅ NEW: Executor acknowledges BUY received
     TradeClosed(TradeClosed),  // ✅ Executor signals trade finalized
     WindowMetrics(WindowMetrics),  // ✅ Real-time market metrics from data-mining
+    ExitAdvice(ExitAdvice),  // ✅ Mempool-watcher reports profit target / stop-loss hit
     PositionUpdate(PositionUpdate),  // ✅ NEW: Mempool-watcher sends real-time P&L updates
+    CurveComplete(CurveCompleteAdvice),  // Bonding curve filled (migration imminent)
 }
 
-impl AdviceMessage {
-    /// Parse advice message from bytes
-    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
-        if bytes.is_empty() {
-            return None;
+impl AdviceMessageType {
+    /// Minimum packet length for this message type (its fixed layout size)
+    pub fn min_size(&self) -> usize {
+        match self {
+            Self::ExtendHold => ExtendHoldAdvice::SIZE,
+            Self::WidenExit => WidenExitAdvice::SIZE,
+            Self::LateOpportunity => LateOpportunityAdvice::SIZE,
+            Self::CopyTrade => CopyTradeAdvice::SIZE,
+            Self::SolPriceUpdate => SolPriceUpdate::SIZE,
+            Self::RankOpportunity => RankOpportunityAdvice::SIZE,
+            Self::MomentumOpportunity => MomentumOpportunityAdvice::SIZE,
+            Self::HotlistOpportunity => HotlistOpportunityAdvice::SIZE,
+            Self::MempoolHeat => MempoolHeatAdvice::SIZE,
+            Self::TradeSubmitted => TradeSubmittedAdvice::SIZE,
+            Self::TradeConfirmed => TradeConfirmedAdvice::SIZE,
+            Self::TradeFailed => TradeFailedAdvice::SIZE,
+            Self::MomentumDetected => MomentumDetectedAdvice::SIZE,
+            Self::VolumeSpike => VolumeSpikeAdvice::SIZE,
+            Self::WalletActivity => WalletActivityAdvice::SIZE,
+            Self::ExitAck => ExitAck::SIZE,
+            Self::TxConfirmed => TxConfirmed::SIZE,
+            Self::EnterAck => EnterAck::SIZE,
+            Self::TradeClosed => TradeClosed::SIZE,
+            Self::WindowMetrics => WindowMetrics::SIZE,
+            Self::ExitAdvice => ExitAdvice::SIZE,
+            Self::PositionUpdate => PositionUpdate::SIZE,
+            Self::CurveComplete => CurveCompleteAdvice::SIZE,
         }
-        
-        let msg_type = AdviceMessageType::from_u8(bytes[0])?;
-        
-        match msg_type {
-            AdviceMessageType::ExtendHold => {
-                ExtendHoldAdvice::from_bytes(bytes).map(Self::ExtendHold)
-            }
-            AdviceMessageType::WidenExit => {
-                WidenExitAdvice::from_bytes(bytes).map(Self::WidenExit)
-            }
-            AdviceMessageType::LateOpportunity => {
-                LateOpportunityAdvice::from_bytes(bytes).map(Self::LateOpportunity)
-            }
-            AdviceMessageType::CopyTrade => {
-                CopyTradeAdvice::from_bytes(bytes).map(Self::CopyTrade)
-            }
-            AdviceMessageType::SolPriceUpdate => {
-                SolPriceUpdate::from_bytes(bytes).map(Self::SolPriceUpdate)
-            }
-            AdviceMessageType::MomentumOpportunity => {
-                MomentumOpportunityAdvice::from_bytes(bytes).map(Self::MomentumOpportunity)
-            }
-            AdviceMessageType::RankOpportunity => {
-                RankOpportunityAdvice::from_bytes(bytes).map(Self::RankOpportunity)
-            }
-            AdviceMessageType::MempoolHeat => {
-                MempoolHeatAdvice::from_bytes(bytes).map(Self::MempoolHeat)
-            }
-            AdviceMessageType::TradeSubmitted => {
-                TradeSubmittedAdvice::from_bytes(bytes).map(Self::TradeSubmitted)
-            }
-            AdviceMessageType::TradeConfirmed => {
-                TradeConfirmedAdvice::from_bytes(bytes).map(Self::TradeConfirmed)
-            }
-            AdviceMessageType::TradeFailed => {
-                TradeFailedAdvice::from_bytes(bytes).map(Self::TradeFailed)
-            }
-            AdviceMessageType::MomentumDetected => {
-                MomentumDetectedAdvice::from_bytes(bytes).map(Self::MomentumDetected)
-            }
-            AdviceMessageType::VolumeSpike => {
-                VolumeSpikeAdvice::from_bytes(bytes).map(Self::VolumeSpike)
-            }
-            AdviceMessageType::WalletActivity => {
-                WalletActivityAdvice::from_bytes(bytes).map(Self::WalletActivity)
-            }
-            AdviceMessageType::ExitAck => {
-                ExitAck::from_bytes(bytes).map(Self::ExitAck)
-            }
-            AdviceMessageType::TxConfirmed => {
-                TxConfirmed::from_bytes(bytes).map(Self::TxConfirmed)
-            }
-            AdviceMessageType::EnterAck => {
-                EnterAck::from_bytes(bytes).map(Self::EnterAck)
-            }
-            AdviceMessageType::TradeClosed => {
-                TradeClosed::from_bytes(bytes).map(Self::TradeClosed)
-            }
-            AdviceMessageType::WindowMetrics => {
-                WindowMetrics::from_bytes(bytes).map(Self::WindowMetrics)
+    }
+}
+
+/// Largest advice packet accepted on the bus.
+///
+/// Senders zero-pad layouts (e.g. 56-byte LateOpportunity sent as 64 bytes), so
+/// packets may exceed `min_size()`, but nothing legitimate exceeds this.
+pub const MAX_ADVICE_PACKET_SIZE: usize = 256;
+
+/// Why an inbound advice packet was rejected
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// Zero-length packet
+    Empty,
+    /// First byte is not a known advice message type
+    UnknownType(u8),
+    /// Packet shorter than the layout for its type
+    Truncated { msg_type: AdviceMessageType, expected: usize, got: usize },
+    /// Packet longer than any advice layout
+    Oversized { msg_type: AdviceMessageType, max: usize, got: usize },
+    /// Length was fine but the payload failed validation
+    Malformed { msg_type: AdviceMessageType, reason: String },
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty packet"),
+            Self::UnknownType(t) => write!(f, "unknown advice type {}", t),
+            Self::Truncated { msg_type, expected, got } => {
+                write!(f, "{:?} truncated: expected >= {} bytes, got {}", msg_type, expected, got)
             }
-            AdviceMessageType::PositionUpdate => {
-                PositionUpdate::from_bytes(bytes).ok().map(Self::PositionUpdate)
+            Self::Oversized { msg_type, max, got } => {
+                write!(f, "{:?} oversized: max {} bytes, got {}", msg_type, max, got)
             }
+            Self::Malformed { msg_type, reason } => write!(f, "{:?} malformed: {}", msg_type, reason),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parse an inbound advice packet, validating its length for the declared type.
+///
+/// Every per-type `from_bytes` indexes fixed offsets, so the length check here is
+/// what guarantees a truncated or spoofed packet can't panic the receiver.
+pub fn parse_advice(bytes: &[u8]) -> std::result::Result<AdviceMessage, ParseError> {
+    let type_byte = *bytes.first().ok_or(ParseError::Empty)?;
+    let msg_type = AdviceMessageType::from_u8(type_byte).ok_or(ParseError::UnknownType(type_byte))?;
+    
+    let expected = msg_type.min_size();
+    if bytes.len() < expected {
+        return Err(ParseError::Truncated { msg_type, expected, got: bytes.len() });
+    }
+    if bytes.len() > MAX_ADVICE_PACKET_SIZE {
+        return Err(ParseError::Oversized { msg_type, max: MAX_ADVICE_PACKET_SIZE, got: bytes.len() });
+    }
+    
+    let truncated = || ParseError::Truncated { msg_type, expected, got: bytes.len() };
+    let msg = match msg_type {
+        AdviceMessageType::ExtendHold => {
+            ExtendHoldAdvice::from_bytes(bytes).map(AdviceMessage::ExtendHold).ok_or_else(truncated)?
+        }
+        AdviceMessageType::WidenExit => {
+            WidenExitAdvice::from_bytes(bytes).map(AdviceMessage::WidenExit).ok_or_else(truncated)?
+        }
+        AdviceMessageType::LateOpportunity => {
+            LateOpportunityAdvice::from_bytes(bytes).map(AdviceMessage::LateOpportunity).ok_or_else(truncated)?
+        }
+        AdviceMessageType::CopyTrade => {
+            CopyTradeAdvice::from_bytes(bytes).map(AdviceMessage::CopyTrade).ok_or_else(truncated)?
+        }
+        AdviceMessageType::SolPriceUpdate => {
+            SolPriceUpdate::from_bytes(bytes).map(AdviceMessage::SolPriceUpdate).ok_or_else(truncated)?
         }
+        AdviceMessageType::MomentumOpportunity => {
+            MomentumOpportunityAdvice::from_bytes(bytes).map(AdviceMessage::MomentumOpportunity).ok_or_else(truncated)?
+        }
+        AdviceMessageType::RankOpportunity => {
+            RankOpportunityAdvice::from_bytes(bytes).map(AdviceMessage::RankOpportunity).ok_or_else(truncated)?
+        }
+        AdviceMessageType::HotlistOpportunity => {
+            HotlistOpportunityAdvice::from_bytes(bytes).map(AdviceMessage::HotlistOpportunity).ok_or_else(truncated)?
+        }
+        AdviceMessageType::MempoolHeat => {
+            MempoolHeatAdvice::from_bytes(bytes).map(AdviceMessage::MempoolHeat).ok_or_else(truncated)?
+        }
+        AdviceMessageType::TradeSubmitted => {
+            TradeSubmittedAdvice::from_bytes(bytes).map(AdviceMessage::TradeSubmitted).ok_or_else(truncated)?
+        }
+        AdviceMessageType::TradeConfirmed => {
+            TradeConfirmedAdvice::from_bytes(bytes).map(AdviceMessage::TradeConfirmed).ok_or_else(truncated)?
+        }
+        AdviceMessageType::TradeFailed => {
+            TradeFailedAdvice::from_bytes(bytes).map(AdviceMessage::TradeFailed).ok_or_else(truncated)?
+        }
+        AdviceMessageType::MomentumDetected => {
+            MomentumDetectedAdvice::from_bytes(bytes).map(AdviceMessage::MomentumDetected).ok_or_else(truncated)?
+        }
+        AdviceMessageType::VolumeSpike => {
+            VolumeSpikeAdvice::from_bytes(bytes).map(AdviceMessage::VolumeSpike).ok_or_else(truncated)?
+        }
+        AdviceMessageType::WalletActivity => {
+            WalletActivityAdvice::from_bytes(bytes).map(AdviceMessage::WalletActivity).ok_or_else(truncated)?
+        }
+        AdviceMessageType::ExitAck => {
+            ExitAck::from_bytes(bytes).map(AdviceMessage::ExitAck).ok_or_else(truncated)?
+        }
+        AdviceMessageType::TxConfirmed => {
+            TxConfirmed::from_bytes(bytes).map(AdviceMessage::TxConfirmed).ok_or_else(truncated)?
+        }
+        AdviceMessageType::EnterAck => {
+            EnterAck::from_bytes(bytes).map(AdviceMessage::EnterAck).ok_or_else(truncated)?
+        }
+        AdviceMessageType::TradeClosed => {
+            TradeClosed::from_bytes(bytes).map(AdviceMessage::TradeClosed).ok_or_else(truncated)?
+        }
+        AdviceMessageType::WindowMetrics => {
+            WindowMetrics::from_bytes(bytes).map(AdviceMessage::WindowMetrics).ok_or_else(truncated)?
+        }
+        AdviceMessageType::ExitAdvice => {
+            ExitAdvice::from_bytes(bytes).map(AdviceMessage::ExitAdvice)
+                .map_err(|e| ParseError::Malformed { msg_type, reason: e.to_string() })?
+        }
+        AdviceMessageType::PositionUpdate => {
+            PositionUpdate::from_bytes(bytes).map(AdviceMessage::PositionUpdate)
+                .map_err(|e| ParseError::Malformed { msg_type, reason: e.to_string() })?
+        }
+        AdviceMessageType::CurveComplete => {
+            CurveCompleteAdvice::from_bytes(bytes).map(AdviceMessage::CurveComplete).ok_or_else(truncated)?
+        }
+    };
+    
+    Ok(msg)
+}
+
+impl AdviceMessage {
+    /// Parse advice message from bytes (see `parse_advice` for the error reason)
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        parse_advice(bytes).ok()
+    }
+    
+    /// Sender sequence number for sequenced data-mining signals (None if unsequenced)
+    pub fn seq(&self) -> Option<u32> {
+        let seq = match self {
+            AdviceMessage::MomentumDetected(m) => m.seq,
+            AdviceMessage::VolumeSpike(v) => v.seq,
+            AdviceMessage::WalletActivity(w) => w.seq,
+            AdviceMessage::WindowMetrics(w) => w.seq,
+            AdviceMessage::CurveComplete(c) => c.seq,
+            _ => 0,
+        };
+        (seq != 0).then_some(seq)
     }
 }
 
@@ -1911,6 +2411,29 @@ mod advice_tests {
         assert_eq!(AdviceMessageType::from_u8(99), None);
     }
     
+    #[test]
+    fn test_curve_complete_parse() {
+        let mut bytes = vec![0u8; CurveCompleteAdvice::SIZE];
+        bytes[0] = AdviceMessageType::CurveComplete as u8;
+        bytes[1..33].copy_from_slice(&[7u8; 32]);
+        bytes[33..41].copy_from_slice(&312_000_000u64.to_le_bytes());
+        bytes[41..49].copy_from_slice(&1_730_000_000i64.to_le_bytes());
+        bytes[57..61].copy_from_slice(&42u32.to_le_bytes());
+        
+        let msg = parse_advice(&bytes).unwrap();
+        assert_eq!(msg.seq(), Some(42));
+        match msg {
+            AdviceMessage::CurveComplete(c) => {
+                assert_eq!(c.mint, [7u8; 32]);
+                assert_eq!({ c.slot }, 312_000_000);
+                assert_eq!({ c.block_time }, 1_730_000_000);
+            }
+            other => panic!("expected CurveComplete, got {:?}", other),
+        }
+        
+        assert!(matches!(parse_advice(&bytes[..40]), Err(ParseError::Truncated { .. })));
+    }
+    
     #[test]
     fn test_extend_hold_size() {
         assert_eq!(ExtendHoldAdvice::SIZE, 48);
@@ -1925,4 +2448,97 @@ mod advice_tests {
     fn test_sol_price_update_size() {
         assert_eq!(SolPriceUpdate::SIZE, 32);
     }
+    
+    #[test]
+    fn test_sol_price_update_freshness() {
+        let mut bytes = [0u8; SolPriceUpdate::SIZE];
+        bytes[0] = 14;
+        bytes[1..5].copy_from_slice(&190.5f32.to_le_bytes());
+        bytes[5..13].copy_from_slice(&1_700_000_000u64.to_le_bytes());
+        bytes[13] = 1;
+        
+        // Older sender without timestamp_ms: seconds field is used
+        let legacy = SolPriceUpdate::from_bytes(&bytes).unwrap();
+        assert_eq!(legacy.broadcast_ms(), 1_700_000_000_000);
+        
+        bytes[14..22].copy_from_slice(&1_700_000_000_250u64.to_le_bytes());
+        let update = SolPriceUpdate::from_bytes(&bytes).unwrap();
+        assert_eq!(update.broadcast_ms(), 1_700_000_000_250);
+        
+        let now_ms = 1_700_000_010_000;
+        assert!(update.is_fresh(0, now_ms, 30_000));
+        assert!(!update.is_fresh(1_700_000_000_250, now_ms, 30_000)); // replayed
+        assert!(!update.is_fresh(1_700_000_001_000, now_ms, 30_000)); // out of order
+        assert!(!update.is_fresh(0, now_ms, 5_000));                  // too old
+    }
+    
+    #[test]
+    fn test_parse_advice_length_validation() {
+        assert_eq!(parse_advice(&[]).unwrap_err(), ParseError::Empty);
+        assert_eq!(parse_advice(&[99; 64]).unwrap_err(), ParseError::UnknownType(99));
+        
+        let mut copy = vec![0u8; CopyTradeAdvice::SIZE];
+        copy[0] = AdviceMessageType::CopyTrade as u8;
+        assert!(matches!(parse_advice(&copy), Ok(AdviceMessage::CopyTrade(_))));
+        assert_eq!(
+            parse_advice(&copy[..64]).unwrap_err(),
+            ParseError::Truncated { msg_type: AdviceMessageType::CopyTrade, expected: 80, got: 64 }
+        );
+        
+        // Senders pad short layouts up to 64 bytes
+        let mut late = vec![0u8; 64];
+        late[0] = AdviceMessageType::LateOpportunity as u8;
+        assert!(matches!(parse_advice(&late), Ok(AdviceMessage::LateOpportunity(_))));
+        
+        late.resize(MAX_ADVICE_PACKET_SIZE + 1, 0);
+        assert!(matches!(parse_advice(&late), Err(ParseError::Oversized { .. })));
+    }
+    
+    #[test]
+    fn test_hotlist_opportunity_layout() {
+        let mut bytes = vec![0u8; HotlistOpportunityAdvice::SIZE];
+        bytes[0] = AdviceMessageType::HotlistOpportunity as u8;
+        bytes[1..33].copy_from_slice(&[7u8; 32]);
+        bytes[33..35].copy_from_slice(&725u16.to_le_bytes());
+        bytes[35] = 2;
+        bytes[36..38].copy_from_slice(&42u16.to_le_bytes());
+        bytes[38..42].copy_from_slice(&1250.5f32.to_le_bytes());
+        
+        let Ok(AdviceMessage::HotlistOpportunity(hot)) = parse_advice(&bytes) else {
+            panic!("expected HotlistOpportunity");
+        };
+        assert_eq!(hot.mint, [7u8; 32]);
+        assert_eq!(hot.score(), 7.25);
+        assert_eq!({ hot.rank }, 2);
+        assert_eq!({ hot.unique_buyers }, 42);
+        assert_eq!({ hot.mc_velocity }, 1250.5);
+    }
+    
+    #[test]
+    fn test_parse_advice_fuzz_random_lengths() {
+        // xorshift64 - deterministic, no rand dependency
+        let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        
+        for _ in 0..20_000 {
+            let len = (next() % 300) as usize;
+            let mut packet: Vec<u8> = (0..len).map(|_| next() as u8).collect();
+            if let Some(first) = packet.first_mut() {
+                // Bias towards valid type bytes so every layout gets exercised
+                *first = 10 + (*first % 24);
+            }
+            
+            // Must never panic; successes must respect the per-type length bounds
+            if let Ok(_msg) = parse_advice(&packet) {
+                let msg_type = AdviceMessageType::from_u8(packet[0]).unwrap();
+                assert!(packet.len() >= msg_type.min_size());
+                assert!(packet.len() <= MAX_ADVICE_PACKET_SIZE);
+            }
+        }
+    }
 }
@@ -2,7 +2,7 @@
 //! 
 //! Handles all UDP-based messaging:
 //! - Decision Bus (port 45110): Brain → Executor
-//! - Advice Bus (port 45100): Collectors → Brain
+//! - Advice Bus (port 45100): Collectors → Brain (HMAC-authenticated, see `auth`)
 
 pub mod messages;
 pub mod sender;
@@ -11,12 +11,15 @@ pub mod deduplicator;
 pub mod tx_confirmed_context;
 pub mod exit_advice;
 pub mod position_update;
+pub mod auth;
+pub mod sequence;
 
 pub use messages::{
     TradeDecision, AdviceMessage, 
     LateOpportunityAdvice, CopyTradeAdvice,
-    MomentumOpportunityAdvice, RankOpportunityAdvice,
+    MomentumOpportunityAdvice, RankOpportunityAdvice, HotlistOpportunityAdvice, SolPriceUpdate,
     ExecutionConfirmation, TxConfirmed,
+    parse_advice, ParseError, trace_tag,
 };
 pub use sender::DecisionBusSender;
 pub use receiver::AdviceBusReceiver;
@@ -2,14 +2,118 @@
 //!
 //! Listens for advice messages from WalletTracker and LaunchTracker on port 45100.
 //! Processes: ExtendHold, WidenExit, LateOpportunity, CopyTrade, SolPriceUpdate
+//! Packets must carry a valid `AdviceAuth` prefix; anything else is dropped.
+//!
+//! Wire framing (see `split_datagram`): a datagram holds either one signed
+//! message - what data-mining sends - or a length-prefixed batch of them.
+//! Message layouts aren't all fixed-size (zero padding, optional seq), so
+//! messages are never simply concatenated.
+//!
+//! Single-host setups can listen on a Unix datagram socket instead
+//! (`ADVICE_BUS_UNIX_PATH`, `new_unix`): same datagrams, no loopback UDP drops.
 
-use tokio::net::UdpSocket;
+use tokio::net::{UdpSocket, UnixDatagram};
 use tokio::sync::mpsc;
+use std::io;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, AtomicBool, Ordering};
 use log::{info, warn, error, debug};
 use anyhow::{Result, Context};
-use crate::udp_bus::messages::AdviceMessage;
+use crate::udp_bus::messages::{AdviceMessage, parse_advice};
+use crate::udp_bus::auth::{AdviceAuth, AUTH_MAGIC};
+use crate::udp_bus::sequence::{SeqOutcome, SequenceTracker};
+use crate::metrics;
+
+/// First byte of a batch frame (a single signed message starts with `AUTH_MAGIC`)
+pub const BATCH_MAGIC: u8 = 0xB7;
+
+/// Split a datagram into its signed messages
+///
+/// ```text
+/// single:  [0xA5][tag 16][payload ...]
+/// batch:   [0xB7][count u8] then count × [len u16 LE][single message, len bytes]
+/// ```
+///
+/// Every message in a batch carries its own auth tag. Returns None for a
+/// malformed batch (count/lengths don't add up), which is dropped whole.
+pub fn split_datagram(datagram: &[u8]) -> Option<Vec<&[u8]>> {
+    if datagram.first() != Some(&BATCH_MAGIC) {
+        return Some(vec![datagram]);
+    }
+    
+    let count = *datagram.get(1)? as usize;
+    let mut rest = &datagram[2..];
+    let mut messages = Vec::with_capacity(count);
+    for _ in 0..count {
+        let len = u16::from_le_bytes(rest.get(..2)?.try_into().ok()?) as usize;
+        let message = rest.get(2..2 + len)?;
+        if message.first() != Some(&AUTH_MAGIC) {
+            return None;
+        }
+        messages.push(message);
+        rest = &rest[2 + len..];
+    }
+    rest.is_empty().then_some(messages)
+}
+
+/// Who sent a datagram (sequence numbers are tracked per peer)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Peer {
+    Udp(SocketAddr),
+    /// Sender's Unix address: data-mining binds a per-process abstract name;
+    /// unnamed senders all share one stream
+    Unix(Vec<u8>),
+}
+
+impl Peer {
+    fn unix(addr: &tokio::net::unix::SocketAddr) -> Self {
+        let name = addr.as_abstract_name()
+            .map(<[u8]>::to_vec)
+            .or_else(|| addr.as_pathname().map(|p| p.as_os_str().as_encoded_bytes().to_vec()))
+            .unwrap_or_default();
+        Self::Unix(name)
+    }
+}
+
+impl std::fmt::Display for Peer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Udp(addr) => write!(f, "{}", addr),
+            Self::Unix(name) if name.is_empty() => write!(f, "unix:(unnamed)"),
+            Self::Unix(name) => write!(f, "unix:{}", String::from_utf8_lossy(name)),
+        }
+    }
+}
+
+/// Socket the Advice Bus listens on
+enum AdviceSocket {
+    Udp(UdpSocket),
+    Unix { socket: UnixDatagram, path: PathBuf },
+}
+
+impl AdviceSocket {
+    async fn recv(&self, buf: &mut [u8]) -> io::Result<(usize, Peer)> {
+        match self {
+            Self::Udp(socket) => {
+                let (len, addr) = socket.recv_from(buf).await?;
+                Ok((len, Peer::Udp(addr)))
+            }
+            Self::Unix { socket, .. } => {
+                let (len, addr) = socket.recv_from(buf).await?;
+                Ok((len, Peer::unix(&addr)))
+            }
+        }
+    }
+    
+    fn describe(&self) -> String {
+        match self {
+            Self::Udp(socket) => socket.local_addr().map(|a| a.to_string()).unwrap_or_default(),
+            Self::Unix { path, .. } => path.display().to_string(),
+        }
+    }
+}
 
 /// Statistics for received messages
 #[derive(Debug, Clone, Default)]
@@ -21,11 +125,14 @@ pub struct ReceiverStats {
     pub copy_trade: u64,
     pub sol_price_update: u64,
     pub parse_errors: u64,
+    pub auth_rejected: u64,
+    pub messages_lost: u64,
 }
 
 /// UDP receiver for Advice Bus messages
 pub struct AdviceBusReceiver {
-    socket: Arc<UdpSocket>,
+    socket: Arc<AdviceSocket>,
+    auth: AdviceAuth,
     stats: Arc<ReceiverStats>,
     running: Arc<AtomicBool>,
     total_received: Arc<AtomicU64>,
@@ -35,21 +142,43 @@ pub struct AdviceBusReceiver {
     copy_trade_count: Arc<AtomicU64>,
     sol_price_update_count: Arc<AtomicU64>,
     parse_error_count: Arc<AtomicU64>,
+    auth_rejected_count: Arc<AtomicU64>,
+    messages_lost_count: Arc<AtomicU64>,
 }
 
 impl AdviceBusReceiver {
     /// Create new Advice Bus receiver
     /// 
     /// Binds to port 45100 to receive messages from WalletTracker and LaunchTracker.
-    pub async fn new() -> Result<Self> {
+    /// Only packets signed with `auth`'s shared secret are accepted.
+    pub async fn new(auth: AdviceAuth) -> Result<Self> {
         let socket = UdpSocket::bind("127.0.0.1:45100")
             .await
             .context("Failed to bind UDP socket for Advice Bus receiver on port 45100")?;
         
-        info!("📻 Advice Bus receiver bound to 127.0.0.1:45100");
+        Ok(Self::with_socket(AdviceSocket::Udp(socket), auth))
+    }
+    
+    /// Create a receiver on a Unix datagram socket at `path` (replaces a stale socket file)
+    pub async fn new_unix(path: impl AsRef<Path>, auth: AdviceAuth) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        match std::fs::remove_file(&path) {
+            Ok(()) => debug!("🧹 Removed stale Advice Bus socket {}", path.display()),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e).with_context(|| format!("Failed to remove stale socket {}", path.display())),
+        }
+        let socket = UnixDatagram::bind(&path)
+            .with_context(|| format!("Failed to bind Advice Bus Unix socket {}", path.display()))?;
+        
+        Ok(Self::with_socket(AdviceSocket::Unix { socket, path }, auth))
+    }
+    
+    fn with_socket(socket: AdviceSocket, auth: AdviceAuth) -> Self {
+        info!("📻 Advice Bus receiver bound to {}", socket.describe());
         
-        Ok(Self {
+        Self {
             socket: Arc::new(socket),
+            auth,
             stats: Arc::new(ReceiverStats::default()),
             running: Arc::new(AtomicBool::new(false)),
             total_received: Arc::new(AtomicU64::new(0)),
@@ -59,7 +188,9 @@ impl AdviceBusReceiver {
             copy_trade_count: Arc::new(AtomicU64::new(0)),
             sol_price_update_count: Arc::new(AtomicU64::new(0)),
             parse_error_count: Arc::new(AtomicU64::new(0)),
-        })
+            auth_rejected_count: Arc::new(AtomicU64::new(0)),
+            messages_lost_count: Arc::new(AtomicU64::new(0)),
+        }
     }
     
     /// Start receiving messages
@@ -80,262 +211,325 @@ impl AdviceBusReceiver {
         let copy_trade_count = self.copy_trade_count.clone();
         let sol_price_update_count = self.sol_price_update_count.clone();
         let parse_error_count = self.parse_error_count.clone();
+        let auth_rejected_count = self.auth_rejected_count.clone();
+        let messages_lost_count = self.messages_lost_count.clone();
+        let auth = self.auth.clone();
         
         tokio::spawn(async move {
-            let mut buf = [0u8; 1024]; // Large enough for any advice message
+            let mut buf = [0u8; 2048]; // Large enough for any advice message or batch frame
+            let mut sequences = SequenceTracker::new();
             
             info!("🎧 Started listening for Advice Bus messages...");
             
-            while running.load(Ordering::Relaxed) {
-                match socket.recv_from(&mut buf).await {
+            'recv: while running.load(Ordering::Relaxed) {
+                match socket.recv(&mut buf).await {
                     Ok((len, addr)) => {
                         total_received.fetch_add(1, Ordering::Relaxed);
                         
                         debug!("📨 Received {} bytes from {}", len, addr);
                         
-                        // Parse message
-                        match AdviceMessage::from_bytes(&buf[..len]) {
-                            Some(msg) => {
-                                // Update message type counters
-                                match &msg {
-                                    AdviceMessage::ExtendHold(_) => {
-                                        extend_hold_count.fetch_add(1, Ordering::Relaxed);
-                                        debug!("⏰ ExtendHold advice received");
-                                    }
-                                    AdviceMessage::WidenExit(_) => {
-                                        widen_exit_count.fetch_add(1, Ordering::Relaxed);
-                                        debug!("📊 WidenExit advice received");
-                                    }
-                                    AdviceMessage::LateOpportunity(advice) => {
-                                        late_opportunity_count.fetch_add(1, Ordering::Relaxed);
-                                        // Copy packed struct fields to avoid unaligned access
-                                        let age = advice.age_seconds;
-                                        let vol = advice.vol_60s_sol;
-                                        let buyers = advice.buyers_60s;
-                                        let score = advice.follow_through_score;
-                                        info!(
-                                            "🕐 LateOpportunity: mint={}..., age={}s, vol={:.1} SOL, buyers={}, score={}",
-                                            hex::encode(&advice.mint[..4]),
-                                            age, vol, buyers, score
-                                        );
-                                    }
-                                    AdviceMessage::CopyTrade(advice) => {
-                                        copy_trade_count.fetch_add(1, Ordering::Relaxed);
-                                        // Copy packed struct fields to avoid unaligned access
-                                        let side = advice.side;
-                                        let size = advice.size_sol;
-                                        let tier = advice.wallet_tier;
-                                        let conf = advice.wallet_confidence;
-                                        info!(
-                                            "🎭 CopyTrade: wallet={}..., mint={}..., side={}, size={:.2} SOL, tier={}, conf={}",
-                                            hex::encode(&advice.wallet[..4]),
-                                            hex::encode(&advice.mint[..4]),
-                                            side, size, tier, conf
-                                        );
-                                    }
-                                    AdviceMessage::SolPriceUpdate(update) => {
-                                        sol_price_update_count.fetch_add(1, Ordering::Relaxed);
-                                        // Copy packed struct fields to avoid unaligned access
-                                        let price = update.price_usd;
-                                        let source = update.source;
-                                        debug!("💵 SOL price update: ${:.2} from source {}", price, source);
-                                    }
-                                    AdviceMessage::MomentumOpportunity(advice) => {
-                                        let vol = advice.vol_5s_sol();
-                                        let buyers = advice.buyers_2s;
-                                        let score = advice.score;
-                                        info!(
-                                            "⚡ MomentumOpportunity: mint={}..., vol_5s={:.2} SOL, buyers_2s={}, score={}",
-                                            hex::encode(&advice.mint[..4]),
-                                            vol, buyers, score
-                                        );
-                                    }
-                                    AdviceMessage::RankOpportunity(advice) => {
-                                        let rank = advice.rank;
-                                        let score = advice.score;
-                                        info!(
-                                            "🏆 RankOpportunity: mint={}..., rank={}, score={}",
-                                            hex::encode(&advice.mint[..4]),
-                                            rank, score
-                                        );
-                                    }
-                                    AdviceMessage::MempoolHeat(heat) => {
-                                        let tx_rate = heat.tx_rate as f64 / 100.0;
-                                        let whale_activity = heat.whale_activity as f64 / 100.0;
-                                        let bot_density = heat.bot_density as f64 / 10000.0;
-                                        debug!(
-                                            "🌡️  MempoolHeat: score={}, tx_rate={:.2}/s, whale={:.2} SOL, bot={:.1}%",
-                                            heat.heat_score, tx_rate, whale_activity, bot_density * 100.0
-                                        );
-                                    }
-                                    AdviceMessage::TradeSubmitted(submitted) => {
-                                        let sig_str = bs58::encode(&submitted.signature[..]).into_string();
-                                        let mint_str = bs58::encode(&submitted.mint).into_string();
-                                        // Copy values to avoid unaligned reference in packed struct
-                                        let exp_tokens = submitted.expected_tokens;
-                                        let exp_sol = submitted.expected_sol_lamports;
-                                        info!(
-                                            "📤 TradeSubmitted: {} {} - sig: {} - expected tokens: {}, sol: {} lamports",
-                                            if submitted.side == 0 { "BUY" } else { "SELL" },
-                                            mint_str, sig_str, exp_tokens, exp_sol
-                                        );
-                                    }
-                                    AdviceMessage::TradeConfirmed(confirmed) => {
-                                        let sig_str = bs58::encode(&confirmed.signature[..]).into_string();
-                                        let mint_str = bs58::encode(&confirmed.mint).into_string();
-                                        // Copy values to avoid unaligned reference in packed struct
-                                        let act_tokens = confirmed.actual_tokens;
-                                        let act_sol = confirmed.actual_sol_lamports;
-                                        let fees = confirmed.total_fees_lamports;
-                                        let fast = confirmed.fast_confirm;
-                                        info!(
-                                            "✅ TradeConfirmed: {} {} - sig: {} - tokens: {}, sol: {} lamports, fees: {} lamports, fast: {}",
-                                            if confirmed.side == 0 { "BUY" } else { "SELL" },
-                                            mint_str, sig_str, act_tokens, act_sol, fees, fast == 1
-                                        );
-                                    }
-                                    AdviceMessage::TradeFailed(failed) => {
-                                        let mint_str = bs58::encode(&failed.mint).into_string();
-                                        let reason = String::from_utf8_lossy(&failed.reason_str)
-                                            .trim_end_matches('\0')
-                                            .to_string();
-                                        let sig_str = if failed.has_signature == 1 {
-                                            bs58::encode(&failed.signature[..]).into_string()
-                                        } else {
-                                            "N/A".to_string()
-                                        };
-                                        info!(
-                                            "❌ TradeFailed: {} {} - sig: {} - reason: {}",
-                                            if failed.side == 0 { "BUY" } else { "SELL" },
-                                            mint_str, sig_str, reason
-                                        );
-                                    }
-                                    AdviceMessage::MomentumDetected(momentum) => {
-                                        let mint_str = bs58::encode(&momentum.mint).into_string();
-                                        let buys = momentum.buys_in_last_500ms;
-                                        let volume = momentum.volume_sol;
-                                        let buyers = momentum.unique_buyers;
-                                        let conf = momentum.confidence;
-                                        info!(
-                                            "📊 MomentumDetected: {} | buys: {}, vol: {:.2} SOL, buyers: {}, conf: {}",
-                                            &mint_str[..8], buys, volume, buyers, conf
-                                        );
-                                    }
-                                    AdviceMessage::VolumeSpike(spike) => {
-                                        let mint_str = bs58::encode(&spike.mint).into_string();
-                                        let total = spike.total_sol;
-                                        let window = spike.time_window_ms;
-                                        let count = spike.tx_count;
-                                        let conf = spike.confidence;
-                                        info!(
-                                            "📈 VolumeSpike: {} | {:.2} SOL in {}ms, {} txs, conf: {}",
-                                            &mint_str[..8], total, window, count, conf
-                                        );
-                                    }
-                                    AdviceMessage::WalletActivity(wallet) => {
-                                        let mint_str = bs58::encode(&wallet.mint).into_string();
-                                        let wallet_str = bs58::encode(&wallet.wallet).into_string();
-                                        let action = wallet.action;
-                                        let size = wallet.size_sol;
-                                        let tier = wallet.wallet_tier;
-                                        let conf = wallet.confidence;
-                                        info!(
-                                            "👤 WalletActivity: {} | {} | {} | {:.2} SOL, tier: {}, conf: {}",
-                                            &mint_str[..8], &wallet_str[..8],
-                                            if action == 0 { "BUY" } else { "SELL" },
-                                            size, tier, conf
-                                        );
-                                    }
-                                    AdviceMessage::ExitAck(ack) => {
-                                        let mint_str = bs58::encode(&ack.mint).into_string();
-                                        let trade_id_str = String::from_utf8_lossy(&ack.trade_id).trim_end_matches('\0').to_string();
-                                        info!(
-                                            "✅ ExitAck received: mint={} trade_id={}",
-                                            &mint_str[..12],
-                                            &trade_id_str[..8]
-                                        );
-                                    }
-                                    AdviceMessage::EnterAck(ack) => {
-                                        let mint_str = bs58::encode(&ack.mint).into_string();
-                                        let trade_id_str = String::from_utf8_lossy(&ack.trade_id).trim_end_matches('\0').to_string();
-                                        info!(
-                                            "✅ EnterAck received: mint={} trade_id={}",
-                                            &mint_str[..12],
-                                            &trade_id_str[..8]
-                                        );
-                                    }
-                                    AdviceMessage::TxConfirmed(confirmed) => {
-                                        let mint_str = bs58::encode(&confirmed.mint).into_string();
-                                        let sig_str = bs58::encode(&confirmed.signature).into_string();
-                                        let side = if confirmed.side == 0 { "BUY" } else { "SELL" };
-                                        let status = if confirmed.is_success() { "SUCCESS" } else { "FAILED" };
-                                        info!(
-                                            "✅ TxConfirmed: {} {} | mint={} | sig={}",
-                                            side, status,
-                                            &mint_str[..12],
-                                            &sig_str[..12]
-                                        );
-                                    }
-                                    AdviceMessage::TradeClosed(closed) => {
-                                        let mint_str = bs58::encode(&closed.mint).into_string();
-                                        let trade_id_str = String::from_utf8_lossy(&closed.trade_id).trim_end_matches('\0').to_string();
-                                        let side = if closed.side == 0 { "BUY" } else { "SELL" };
-                                        let status = match closed.final_status {
-                                            0 => "CONFIRMED",
-                                            1 => "FAILED",
-                                            2 => "TIMEOUT",
-                                            _ => "UNKNOWN",
-                                        };
-                                        info!(
-                                            "🏁 TradeClosed: {} {} | mint={} trade_id={}",
-                                            side, status,
-                                            &mint_str[..12],
-                                            &trade_id_str[..8]
-                                        );
+                        let Some(packets) = split_datagram(&buf[..len]) else {
+                            parse_error_count.fetch_add(1, Ordering::Relaxed);
+                            warn!("⚠️ Rejected malformed batch frame from {} ({} bytes)", addr, len);
+                            continue;
+                        };
+                        
+                        for packet in packets {
+                            // Authenticate before parsing - drop spoofed/unsigned packets
+                            let Some(payload) = auth.verify(packet) else {
+                                auth_rejected_count.fetch_add(1, Ordering::Relaxed);
+                                metrics::record_advice_rejected();
+                                warn!("🚫 Rejected unauthenticated advice packet from {} ({} bytes)", addr, packet.len());
+                                continue;
+                            };
+                        
+                            // Parse message
+                            match parse_advice(payload) {
+                                Ok(msg) => {
+                                    // Detect loss/reordering on sequenced signals
+                                    if let Some(seq) = msg.seq() {
+                                        match sequences.observe(addr.clone(), seq) {
+                                            SeqOutcome::InOrder => {}
+                                            SeqOutcome::Gap(lost) => {
+                                                messages_lost_count.fetch_add(lost as u64, Ordering::Relaxed);
+                                                metrics::record_udp_messages_lost(lost as u64);
+                                                debug!("📉 {} advice message(s) lost from {} (seq {})", lost, addr, seq);
+                                            }
+                                            SeqOutcome::Reordered => {
+                                                metrics::record_udp_message_reordered();
+                                                debug!("🔀 Out-of-order advice message from {} (seq {})", addr, seq);
+                                            }
+                                            SeqOutcome::Restarted => {
+                                                info!("🔄 Advice sender {} restarted (seq {})", addr, seq);
+                                            }
+                                        }
                                     }
-                                    AdviceMessage::WindowMetrics(metrics) => {
-                                        let mint_str = bs58::encode(&metrics.mint).into_string();
-                                        let volume = metrics.volume_sol();
-                                        // Copy packed fields before use
-                                        let buyers = metrics.unique_buyers_1s;
-                                        let price_change = metrics.price_change_bps_2s;
-                                        let alpha_hits = metrics.alpha_wallet_hits_10s;
-                                        debug!(
-                                            "📊 WindowMetrics: {} | vol_1s: {:.2} SOL, buyers_1s: {}, Δprice_2s: {}bps, alpha_10s: {}",
-                                            &mint_str[..12],
-                                            volume,
-                                            buyers,
-                                            price_change,
-                                            alpha_hits
-                                        );
+                                
+                                    // Update message type counters
+                                    match &msg {
+                                        AdviceMessage::ExtendHold(_) => {
+                                            extend_hold_count.fetch_add(1, Ordering::Relaxed);
+                                            debug!("⏰ ExtendHold advice received");
+                                        }
+                                        AdviceMessage::WidenExit(_) => {
+                                            widen_exit_count.fetch_add(1, Ordering::Relaxed);
+                                            debug!("📊 WidenExit advice received");
+                                        }
+                                        AdviceMessage::LateOpportunity(advice) => {
+                                            late_opportunity_count.fetch_add(1, Ordering::Relaxed);
+                                            // Copy packed struct fields to avoid unaligned access
+                                            let age = advice.age_seconds;
+                                            let vol = advice.vol_60s_sol;
+                                            let buyers = advice.buyers_60s;
+                                            let score = advice.follow_through_score;
+                                            info!(
+                                                "🕐 LateOpportunity: mint={}..., age={}s, vol={:.1} SOL, buyers={}, score={}",
+                                                hex::encode(&advice.mint[..4]),
+                                                age, vol, buyers, score
+                                            );
+                                        }
+                                        AdviceMessage::CopyTrade(advice) => {
+                                            copy_trade_count.fetch_add(1, Ordering::Relaxed);
+                                            // Copy packed struct fields to avoid unaligned access
+                                            let side = advice.side;
+                                            let size = advice.size_sol;
+                                            let tier = advice.wallet_tier;
+                                            let conf = advice.wallet_confidence;
+                                            info!(
+                                                "🎭 CopyTrade: wallet={}..., mint={}..., side={}, size={:.2} SOL, tier={}, conf={}",
+                                                hex::encode(&advice.wallet[..4]),
+                                                hex::encode(&advice.mint[..4]),
+                                                side, size, tier, conf
+                                            );
+                                        }
+                                        AdviceMessage::SolPriceUpdate(update) => {
+                                            sol_price_update_count.fetch_add(1, Ordering::Relaxed);
+                                            // Copy packed struct fields to avoid unaligned access
+                                            let price = update.price_usd;
+                                            let source = update.source;
+                                            debug!("💵 SOL price update: ${:.2} from source {}", price, source);
+                                        }
+                                        AdviceMessage::MomentumOpportunity(advice) => {
+                                            let vol = advice.vol_5s_sol();
+                                            let buyers = advice.buyers_2s;
+                                            let score = advice.score;
+                                            info!(
+                                                "⚡ MomentumOpportunity: mint={}..., vol_5s={:.2} SOL, buyers_2s={}, score={}",
+                                                hex::encode(&advice.mint[..4]),
+                                                vol, buyers, score
+                                            );
+                                        }
+                                        AdviceMessage::RankOpportunity(advice) => {
+                                            let rank = advice.rank;
+                                            let score = advice.score;
+                                            info!(
+                                                "🏆 RankOpportunity: mint={}..., rank={}, score={}",
+                                                hex::encode(&advice.mint[..4]),
+                                                rank, score
+                                            );
+                                        }
+                                        AdviceMessage::HotlistOpportunity(advice) => {
+                                            let rank = advice.rank;
+                                            let buyers = advice.unique_buyers;
+                                            info!(
+                                                "🔥 HotlistOpportunity: mint={}..., score={:.1}, rank={}, buyers={}",
+                                                hex::encode(&advice.mint[..4]),
+                                                advice.score(), rank, buyers
+                                            );
+                                        }
+                                        AdviceMessage::MempoolHeat(heat) => {
+                                            let tx_rate = heat.tx_rate as f64 / 100.0;
+                                            let whale_activity = heat.whale_activity as f64 / 100.0;
+                                            let bot_density = heat.bot_density as f64 / 10000.0;
+                                            debug!(
+                                                "🌡️  MempoolHeat: score={}, tx_rate={:.2}/s, whale={:.2} SOL, bot={:.1}%",
+                                                heat.heat_score, tx_rate, whale_activity, bot_density * 100.0
+                                            );
+                                        }
+                                        AdviceMessage::TradeSubmitted(submitted) => {
+                                            let sig_str = bs58::encode(&submitted.signature[..]).into_string();
+                                            let mint_str = bs58::encode(&submitted.mint).into_string();
+                                            // Copy values to avoid unaligned reference in packed struct
+                                            let exp_tokens = submitted.expected_tokens;
+                                            let exp_sol = submitted.expected_sol_lamports;
+                                            info!(
+                                                "📤 TradeSubmitted: {} {} - sig: {} - expected tokens: {}, sol: {} lamports",
+                                                if submitted.side == 0 { "BUY" } else { "SELL" },
+                                                mint_str, sig_str, exp_tokens, exp_sol
+                                            );
+                                        }
+                                        AdviceMessage::TradeConfirmed(confirmed) => {
+                                            let sig_str = bs58::encode(&confirmed.signature[..]).into_string();
+                                            let mint_str = bs58::encode(&confirmed.mint).into_string();
+                                            // Copy values to avoid unaligned reference in packed struct
+                                            let act_tokens = confirmed.actual_tokens;
+                                            let act_sol = confirmed.actual_sol_lamports;
+                                            let fees = confirmed.total_fees_lamports;
+                                            let fast = confirmed.fast_confirm;
+                                            info!(
+                                                "✅ TradeConfirmed: {} {} - sig: {} - tokens: {}, sol: {} lamports, fees: {} lamports, fast: {}",
+                                                if confirmed.side == 0 { "BUY" } else { "SELL" },
+                                                mint_str, sig_str, act_tokens, act_sol, fees, fast == 1
+                                            );
+                                        }
+                                        AdviceMessage::TradeFailed(failed) => {
+                                            let mint_str = bs58::encode(&failed.mint).into_string();
+                                            let reason = String::from_utf8_lossy(&failed.reason_str)
+                                                .trim_end_matches('\0')
+                                                .to_string();
+                                            let sig_str = if failed.has_signature == 1 {
+                                                bs58::encode(&failed.signature[..]).into_string()
+                                            } else {
+                                                "N/A".to_string()
+                                            };
+                                            info!(
+                                                "❌ TradeFailed: {} {} - sig: {} - reason: {}",
+                                                if failed.side == 0 { "BUY" } else { "SELL" },
+                                                mint_str, sig_str, reason
+                                            );
+                                        }
+                                        AdviceMessage::MomentumDetected(momentum) => {
+                                            let mint_str = bs58::encode(&momentum.mint).into_string();
+                                            let buys = momentum.buys_in_last_500ms;
+                                            let volume = momentum.volume_sol;
+                                            let buyers = momentum.unique_buyers;
+                                            let conf = momentum.confidence;
+                                            info!(
+                                                "📊 MomentumDetected: {} | buys: {}, vol: {:.2} SOL, buyers: {}, conf: {}",
+                                                &mint_str[..8], buys, volume, buyers, conf
+                                            );
+                                        }
+                                        AdviceMessage::VolumeSpike(spike) => {
+                                            let mint_str = bs58::encode(&spike.mint).into_string();
+                                            let total = spike.total_sol;
+                                            let window = spike.time_window_ms;
+                                            let count = spike.tx_count;
+                                            let conf = spike.confidence;
+                                            info!(
+                                                "📈 VolumeSpike: {} | {:.2} SOL in {}ms, {} txs, conf: {}",
+                                                &mint_str[..8], total, window, count, conf
+                                            );
+                                        }
+                                        AdviceMessage::WalletActivity(wallet) => {
+                                            let mint_str = bs58::encode(&wallet.mint).into_string();
+                                            let wallet_str = bs58::encode(&wallet.wallet).into_string();
+                                            let action = wallet.action;
+                                            let size = wallet.size_sol;
+                                            let tier = wallet.wallet_tier;
+                                            let conf = wallet.confidence;
+                                            info!(
+                                                "👤 WalletActivity: {} | {} | {} | {:.2} SOL, tier: {}, conf: {}",
+                                                &mint_str[..8], &wallet_str[..8],
+                                                if action == 0 { "BUY" } else { "SELL" },
+                                                size, tier, conf
+                                            );
+                                        }
+                                        AdviceMessage::ExitAck(ack) => {
+                                            let mint_str = bs58::encode(&ack.mint).into_string();
+                                            let trade_id_str = String::from_utf8_lossy(&ack.trade_id).trim_end_matches('\0').to_string();
+                                            info!(
+                                                "✅ ExitAck received: mint={} trade_id={}",
+                                                &mint_str[..12],
+                                                &trade_id_str[..8]
+                                            );
+                                        }
+                                        AdviceMessage::EnterAck(ack) => {
+                                            let mint_str = bs58::encode(&ack.mint).into_string();
+                                            let trade_id_str = String::from_utf8_lossy(&ack.trade_id).trim_end_matches('\0').to_string();
+                                            info!(
+                                                "✅ EnterAck received: mint={} trade_id={}",
+                                                &mint_str[..12],
+                                                &trade_id_str[..8]
+                                            );
+                                        }
+                                        AdviceMessage::TxConfirmed(confirmed) => {
+                                            let mint_str = bs58::encode(&confirmed.mint).into_string();
+                                            let sig_str = bs58::encode(&confirmed.signature).into_string();
+                                            let side = if confirmed.side == 0 { "BUY" } else { "SELL" };
+                                            let status = if confirmed.is_success() { "SUCCESS" } else { "FAILED" };
+                                            info!(
+                                                "✅ TxConfirmed: {} {} | mint={} | sig={} | trace={}",
+                                                side, status,
+                                                &mint_str[..12],
+                                                &sig_str[..12],
+                                                super::trace_tag(&confirmed.trace_id)
+                                            );
+                                        }
+                                        AdviceMessage::TradeClosed(closed) => {
+                                            let mint_str = bs58::encode(&closed.mint).into_string();
+                                            let trade_id_str = String::from_utf8_lossy(&closed.trade_id).trim_end_matches('\0').to_string();
+                                            let side = if closed.side == 0 { "BUY" } else { "SELL" };
+                                            let status = match closed.final_status {
+                                                0 => "CONFIRMED",
+                                                1 => "FAILED",
+                                                2 => "TIMEOUT",
+                                                _ => "UNKNOWN",
+                                            };
+                                            info!(
+                                                "🏁 TradeClosed: {} {} | mint={} trade_id={}",
+                                                side, status,
+                                                &mint_str[..12],
+                                                &trade_id_str[..8]
+                                            );
+                                        }
+                                        AdviceMessage::WindowMetrics(metrics) => {
+                                            let mint_str = bs58::encode(&metrics.mint).into_string();
+                                            let volume = metrics.volume_sol();
+                                            // Copy packed fields before use
+                                            let buyers = metrics.unique_buyers_1s;
+                                            let price_change = metrics.price_change_bps_2s;
+                                            let alpha_hits = metrics.alpha_wallet_hits_10s;
+                                            debug!(
+                                                "📊 WindowMetrics: {} | vol_1s: {:.2} SOL, buyers_1s: {}, Δprice_2s: {}bps, alpha_10s: {}",
+                                                &mint_str[..12],
+                                                volume,
+                                                buyers,
+                                                price_change,
+                                                alpha_hits
+                                            );
+                                        }
+                                        AdviceMessage::PositionUpdate(update) => {
+                                            let mint_str = bs58::encode(&update.mint).into_string();
+                                            let pnl = update.realized_pnl_usd;
+                                            let pnl_pct = update.pnl_percent;
+                                            debug!(
+                                                "📊 PositionUpdate: {} | P&L: ${:.2} ({:.1}%)",
+                                                &mint_str[..8],
+                                                pnl,
+                                                pnl_pct
+                                            );
+                                        }
+                                        AdviceMessage::CurveComplete(complete) => {
+                                            let mint_str = bs58::encode(&complete.mint).into_string();
+                                            let slot = complete.slot;
+                                            info!("🎓 CurveComplete: {} | slot {} (migration imminent)",
+                                                  &mint_str[..12], slot);
+                                        }
+                                        AdviceMessage::ExitAdvice(advice) => {
+                                            let mint_str = advice.mint_str();
+                                            info!(
+                                                "🚨 ExitAdvice: {} | {} | exit {}% | P&L: ${:.2} ({:+.1}%) | conf: {}",
+                                                &mint_str[..8],
+                                                advice.reason_str(),
+                                                advice.effective_exit_percent(),
+                                                advice.realized_pnl_usd(),
+                                                advice.price_change_percent(),
+                                                advice.confidence
+                                            );
+                                        }
                                     }
-                                    AdviceMessage::PositionUpdate(update) => {
-                                        let mint_str = bs58::encode(&update.mint).into_string();
-                                        let pnl = update.realized_pnl_usd;
-                                        let pnl_pct = update.pnl_percent;
-                                        debug!(
-                                            "📊 PositionUpdate: {} | P&L: ${:.2} ({:.1}%)",
-                                            &mint_str[..8],
-                                            pnl,
-                                            pnl_pct
-                                        );
+                                
+                                    // Forward to channel
+                                    if let Err(e) = tx.send(msg).await {
+                                        warn!("⚠️ Failed to forward advice message: channel closed - {}", e);
+                                        break 'recv;
                                     }
                                 }
-                                
-                                // Forward to channel
-                                if let Err(e) = tx.send(msg).await {
-                                    warn!("⚠️ Failed to forward advice message: channel closed - {}", e);
-                                    break;
+                                Err(e) => {
+                                    parse_error_count.fetch_add(1, Ordering::Relaxed);
+                                    warn!("⚠️ Rejected advice packet from {}: {}", addr, e);
                                 }
                             }
-                            None => {
-                                parse_error_count.fetch_add(1, Ordering::Relaxed);
-                                warn!(
-                                    "⚠️ Failed to parse advice message: {} bytes, type={}",
-                                    len,
-                                    if !buf.is_empty() { buf[0] } else { 0 }
-                                );
-                            }
                         }
                     }
                     Err(e) => {
@@ -367,6 +561,8 @@ impl AdviceBusReceiver {
             copy_trade: self.copy_trade_count.load(Ordering::Relaxed),
             sol_price_update: self.sol_price_update_count.load(Ordering::Relaxed),
             parse_errors: self.parse_error_count.load(Ordering::Relaxed),
+            auth_rejected: self.auth_rejected_count.load(Ordering::Relaxed),
+            messages_lost: self.messages_lost_count.load(Ordering::Relaxed),
         }
     }
     
@@ -379,6 +575,8 @@ impl AdviceBusReceiver {
         self.copy_trade_count.store(0, Ordering::Relaxed);
         self.sol_price_update_count.store(0, Ordering::Relaxed);
         self.parse_error_count.store(0, Ordering::Relaxed);
+        self.auth_rejected_count.store(0, Ordering::Relaxed);
+        self.messages_lost_count.store(0, Ordering::Relaxed);
     }
     
     /// Print statistics summary
@@ -392,16 +590,19 @@ impl AdviceBusReceiver {
         info!("   CopyTrade: {}", stats.copy_trade);
         info!("   SolPriceUpdate: {}", stats.sol_price_update);
         info!("   Parse errors: {}", stats.parse_errors);
+        info!("   Auth rejected: {}", stats.auth_rejected);
+        info!("   Messages lost (seq gaps): {}", stats.messages_lost);
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::udp_bus::ExitAdvice;
     
     #[tokio::test]
     async fn test_receiver_stats_initialization() {
-        let receiver = AdviceBusReceiver::new().await;
+        let receiver = AdviceBusReceiver::new(AdviceAuth::new(b"test-secret")).await;
         // Note: This test may fail if port 45100 is already in use
         // In production, use a different port for testing
         if receiver.is_err() {
@@ -415,10 +616,120 @@ mod tests {
         assert_eq!(stats.copy_trade, 0);
     }
     
+    /// Batch frame around already-signed messages
+    fn batch_frame(messages: &[Vec<u8>]) -> Vec<u8> {
+        let mut frame = vec![BATCH_MAGIC, messages.len() as u8];
+        for message in messages {
+            frame.extend_from_slice(&(message.len() as u16).to_le_bytes());
+            frame.extend_from_slice(message);
+        }
+        frame
+    }
+    
+    #[test]
+    fn test_batch_frame_splits_into_messages() {
+        let auth = AdviceAuth::new(b"test-secret");
+        let mint = [7u8; 32];
+        
+        // RankOpportunity, MomentumOpportunity, SolPriceUpdate (64-byte layouts as data-mining sends them)
+        let mut rank = vec![0u8; 64];
+        rank[0] = 15;
+        rank[1..33].copy_from_slice(&mint);
+        rank[33] = 1;
+        rank[34] = 80;
+        let mut momentum = vec![0u8; 64];
+        momentum[0] = 16;
+        momentum[1..33].copy_from_slice(&mint);
+        momentum[33..35].copy_from_slice(&250u16.to_le_bytes());
+        momentum[35..37].copy_from_slice(&4u16.to_le_bytes());
+        momentum[37] = 70;
+        let mut price = vec![0u8; 64];
+        price[0] = 14;
+        price[1..5].copy_from_slice(&18_283u32.to_le_bytes());
+        
+        let frame = batch_frame(&[auth.sign(&rank), auth.sign(&momentum), auth.sign(&price)]);
+        let parsed: Vec<AdviceMessage> = split_datagram(&frame).unwrap()
+            .into_iter()
+            .map(|packet| parse_advice(auth.verify(packet).expect("each message is signed")).unwrap())
+            .collect();
+        
+        assert_eq!(parsed.len(), 3);
+        assert!(matches!(parsed[0], AdviceMessage::RankOpportunity(_)));
+        assert!(matches!(parsed[1], AdviceMessage::MomentumOpportunity(_)));
+        assert!(matches!(parsed[2], AdviceMessage::SolPriceUpdate(_)));
+        
+        // A plain signed datagram is one message
+        let single = auth.sign(&rank);
+        assert_eq!(split_datagram(&single), Some(vec![&single[..]]));
+        
+        // Lengths that don't add up drop the whole frame
+        assert!(split_datagram(&frame[..frame.len() - 1]).is_none());
+        let mut extra = frame.clone();
+        extra.push(0);
+        assert!(split_datagram(&extra).is_none());
+        assert!(split_datagram(&[BATCH_MAGIC, 2, 1, 0, AUTH_MAGIC]).is_none());
+    }
+    
+    #[tokio::test]
+    async fn test_unix_socket_receiver() {
+        let path = std::env::temp_dir().join(format!("advice_bus_rx_{}.sock", std::process::id()));
+        std::fs::write(&path, b"stale").unwrap(); // left behind by a previous run
+        let auth = AdviceAuth::new(b"test-secret");
+        let receiver = AdviceBusReceiver::new_unix(&path, auth.clone()).await.unwrap();
+        let mut rx = receiver.start().await;
+        
+        let mut price = vec![0u8; 64];
+        price[0] = 14;
+        price[1..5].copy_from_slice(&18_283u32.to_le_bytes());
+        let sender = std::os::unix::net::UnixDatagram::unbound().unwrap();
+        sender.send_to(&auth.sign(&price), &path).unwrap();
+        
+        let msg = tokio::time::timeout(std::time::Duration::from_secs(1), rx.recv()).await.unwrap().unwrap();
+        assert!(matches!(msg, AdviceMessage::SolPriceUpdate(_)));
+        assert_eq!(receiver.stats().total_received, 1);
+        receiver.stop();
+        let _ = std::fs::remove_file(&path);
+    }
+    
+    /// Stop-loss ExitAdvice exactly as mempool-watcher's `UdpPublisher::send_exit_advice`
+    /// emits it (same fixture as its `test_exit_advice_signed_to_advice_bus`)
+    const MEMPOOL_EXIT_ADVICE: &str = concat!(
+        "1e07070707070707070707070707070707", // msg_type 30, trade_id
+        "0909090909090909090909090909090909090909090909090909090909090909", // mint
+        "016457feffff40420f000000000050f80c000000000010a4000000002a36fe9c971764000000000000000000000000",
+    );
+    
+    #[tokio::test]
+    async fn test_mempool_exit_advice_roundtrip() {
+        let path = std::env::temp_dir().join(format!("advice_bus_exit_rx_{}.sock", std::process::id()));
+        let auth = AdviceAuth::new(b"test-secret");
+        let receiver = AdviceBusReceiver::new_unix(&path, auth.clone()).await.unwrap();
+        let mut rx = receiver.start().await;
+        
+        let sender = std::os::unix::net::UnixDatagram::unbound().unwrap();
+        let payload = hex::decode(MEMPOOL_EXIT_ADVICE).unwrap();
+        sender.send_to(&payload, &path).unwrap(); // unsigned: dropped
+        sender.send_to(&auth.sign(&payload), &path).unwrap();
+        
+        let msg = tokio::time::timeout(std::time::Duration::from_secs(1), rx.recv()).await.unwrap().unwrap();
+        let AdviceMessage::ExitAdvice(advice) = msg else {
+            panic!("expected ExitAdvice, got {:?}", msg);
+        };
+        assert_eq!(advice.mint, [9u8; 32]);
+        assert_eq!(advice.trade_id, [7u8; 16]);
+        assert_eq!(advice.reason, ExitAdvice::REASON_STOP_LOSS);
+        assert_eq!(advice.effective_exit_percent(), 100);
+        assert_eq!(advice.realized_pnl_usd(), -4.25);
+        assert_eq!(receiver.stats().auth_rejected, 1);
+        receiver.stop();
+        let _ = std::fs::remove_file(&path);
+    }
+    
     #[test]
     fn test_receiver_stats_struct() {
         let stats = ReceiverStats::default();
         assert_eq!(stats.total_received, 0);
         assert_eq!(stats.parse_errors, 0);
+        assert_eq!(stats.auth_rejected, 0);
     }
 }
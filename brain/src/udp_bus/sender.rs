@@ -2,10 +2,16 @@
 //!
 //! Sends TradeDecision packets to the Execution bot on port 45110.
 //! Non-blocking async sends with error logging and retry logic.
+//!
+//! SELLs can optionally be retransmitted (see `with_sell_retransmit`) until
+//! the Executor's ExecutionConfirmation for their trace_id arrives. Copies
+//! carry the same decision_id, so the Executor drops them once one got through.
 
 use tokio::net::UdpSocket;
+use std::collections::HashSet;
 use std::net::SocketAddr;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use log::{info, warn, error, debug};
 use anyhow::{Result, Context};
 use crate::udp_bus::messages::TradeDecision;
@@ -16,6 +22,13 @@ pub struct DecisionBusSender {
     target_addr: SocketAddr,
     sent_count: std::sync::atomic::AtomicU64,
     error_count: std::sync::atomic::AtomicU64,
+    /// SELL resend interval (None = fire-and-forget)
+    sell_retransmit_interval: Option<Duration>,
+    /// Give up resending this long after the first send
+    sell_retransmit_timeout: Duration,
+    /// trace_ids of SELLs still waiting for an ExecutionConfirmation
+    unconfirmed_sells: Arc<Mutex<HashSet<[u8; 16]>>>,
+    retransmit_count: Arc<std::sync::atomic::AtomicU64>,
 }
 
 impl DecisionBusSender {
@@ -37,9 +50,21 @@ impl DecisionBusSender {
             target_addr,
             sent_count: std::sync::atomic::AtomicU64::new(0),
             error_count: std::sync::atomic::AtomicU64::new(0),
+            sell_retransmit_interval: None,
+            sell_retransmit_timeout: Duration::ZERO,
+            unconfirmed_sells: Arc::new(Mutex::new(HashSet::new())),
+            retransmit_count: Arc::new(std::sync::atomic::AtomicU64::new(0)),
         })
     }
     
+    /// Resend SELL decisions every `interval` until `acknowledge` is called
+    /// with their trace_id or `timeout` elapses (zero interval = off)
+    pub fn with_sell_retransmit(mut self, interval: Duration, timeout: Duration) -> Self {
+        self.sell_retransmit_interval = (!interval.is_zero()).then_some(interval);
+        self.sell_retransmit_timeout = timeout;
+        self
+    }
+    
     /// Validate TradeDecision for executor v1 compatibility
     /// 
     /// Ensures message format, protocol version, and data integrity meet v1 spec.
@@ -84,12 +109,13 @@ impl DecisionBusSender {
         
         // DEBUG: Log raw bytes being sent
         info!(
-            "🔍 BRAIN SENDING: side={}, mint={}..., size={}, conf={} | RAW[34]={} (side byte)",
+            "🔍 BRAIN SENDING: side={}, mint={}..., size={}, conf={} | RAW[34]={} (side byte) | trace={}",
             if decision.side == 0 { "BUY" } else { "SELL" },
             hex::encode(&decision.mint[..8]),
             decision.size_lamports,
             decision.confidence,
-            bytes[34]
+            bytes[34],
+            super::trace_tag(&decision.trace_id)
         );
         
         // Send packet
@@ -115,6 +141,10 @@ impl DecisionBusSender {
                     decision.slippage_bps
                 );
                 
+                if decision.side == 1 {
+                    self.schedule_sell_retransmit(decision.trace_id, bytes);
+                }
+                
                 Ok(())
             }
             Err(e) => {
@@ -129,6 +159,57 @@ impl DecisionBusSender {
         }
     }
     
+    /// Keep resending a SELL packet in the background until it's acknowledged
+    fn schedule_sell_retransmit(&self, trace_id: [u8; 16], bytes: [u8; TradeDecision::SIZE]) {
+        let Some(interval) = self.sell_retransmit_interval else {
+            return;
+        };
+        // Already being resent (e.g. send_with_retry)
+        if !self.unconfirmed_sells.lock().unwrap().insert(trace_id) {
+            return;
+        }
+        
+        let socket = self.socket.clone();
+        let target = self.target_addr;
+        let timeout = self.sell_retransmit_timeout;
+        let unconfirmed = self.unconfirmed_sells.clone();
+        let retransmits = self.retransmit_count.clone();
+        tokio::spawn(async move {
+            let started = Instant::now();
+            loop {
+                tokio::time::sleep(interval).await;
+                if !unconfirmed.lock().unwrap().contains(&trace_id) {
+                    return;
+                }
+                if started.elapsed() >= timeout {
+                    unconfirmed.lock().unwrap().remove(&trace_id);
+                    warn!("⌛ SELL trace={} unconfirmed after {:?}, stopped retransmitting",
+                          super::trace_tag(&trace_id), timeout);
+                    return;
+                }
+                match socket.send_to(&bytes, target).await {
+                    Ok(_) => {
+                        retransmits.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                        debug!("🔁 Retransmitted SELL trace={}", super::trace_tag(&trace_id));
+                    }
+                    Err(e) => warn!("❌ SELL retransmit failed (trace={}): {}", super::trace_tag(&trace_id), e),
+                }
+            }
+        });
+    }
+    
+    /// ExecutionConfirmation received for `trace_id`: stop retransmitting it
+    /// 
+    /// Returns true if a SELL was still being retransmitted.
+    pub fn acknowledge(&self, trace_id: &[u8; 16]) -> bool {
+        self.unconfirmed_sells.lock().unwrap().remove(trace_id)
+    }
+    
+    /// Number of SELL retransmits sent so far
+    pub fn retransmit_count(&self) -> u64 {
+        self.retransmit_count.load(std::sync::atomic::Ordering::Relaxed)
+    }
+    
     /// Send decision with retry logic
     /// 
     /// Attempts to send up to `max_retries` times with exponential backoff.
@@ -272,7 +353,7 @@ mod tests {
     async fn test_decision_serialization() {
         let decision = mock_decision();
         let bytes = decision.to_bytes();
-        assert_eq!(bytes.len(), 52, "TradeDecision should be 52 bytes");
+        assert_eq!(bytes.len(), 92, "TradeDecision should be 92 bytes");
     }
     
     #[tokio::test]
@@ -328,6 +409,41 @@ mod tests {
                 "Invalid confidence should fail");
     }
     
+    /// Next packet on `socket`, None if nothing arrives within `ms`
+    async fn recv_within(socket: &UdpSocket, buf: &mut [u8], ms: u64) -> Option<usize> {
+        tokio::time::timeout(Duration::from_millis(ms), socket.recv(buf)).await.ok()?.ok()
+    }
+    
+    #[tokio::test]
+    async fn test_sell_retransmitted_until_acknowledged() {
+        let receiver = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let sender = DecisionBusSender::new(receiver.local_addr().unwrap()).await.unwrap()
+            .with_sell_retransmit(Duration::from_millis(20), Duration::from_secs(5));
+        let mut buf = [0u8; 128];
+        
+        // BUYs stay fire-and-forget
+        let buy = TradeDecision::new_buy(Pubkey::new_unique().to_bytes(), 10_000_000, 150, 75, 0);
+        sender.send_decision(&buy).await.unwrap();
+        assert!(recv_within(&receiver, &mut buf, 200).await.is_some());
+        assert!(recv_within(&receiver, &mut buf, 100).await.is_none(), "BUY must not be retransmitted");
+        
+        let sell = TradeDecision::new_sell(Pubkey::new_unique().to_bytes(), 1_000_000, 500, 75, 0, 0, 0);
+        sender.send_decision(&sell).await.unwrap();
+        
+        // First packet "dropped": read and ignore it, the copy must follow
+        assert!(recv_within(&receiver, &mut buf, 200).await.is_some());
+        let len = recv_within(&receiver, &mut buf, 200).await.expect("SELL retransmitted");
+        let resent = TradeDecision::from_bytes(&buf[..len]).unwrap();
+        assert_eq!(resent.decision_id, sell.decision_id, "copies must dedupe on the Executor");
+        assert!(sender.retransmit_count() >= 1);
+        
+        // Confirmation stops the retransmits (drain any copy already in flight)
+        assert!(sender.acknowledge(&sell.trace_id));
+        while recv_within(&receiver, &mut buf, 50).await.is_some() {}
+        assert!(recv_within(&receiver, &mut buf, 100).await.is_none(), "no retransmits after acknowledge");
+        assert!(!sender.acknowledge(&sell.trace_id));
+    }
+    
     // Note: Full send tests would require a listening socket
     // These are integration tests that should be run with a test receiver
 }
@@ -0,0 +1,127 @@
+//! 🔢 Per-sender sequence tracking for Advice Bus signals
+//!
+//! Data-mining's batched sender stamps each signal with a monotonic `seq: u32`.
+//! Tracking the last-seen value per sender shows whether UDP (or the 15ms
+//! batching window) is dropping or reordering packets under load. A sender
+//! that restarts begins again at seq 1; that resets its stream rather than
+//! counting as a flood of reordered packets.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::net::SocketAddr;
+
+/// A step backwards larger than this is a sender restart, not reordering
+const RESTART_BACKWARD_JUMP: u32 = 1024;
+
+/// Result of observing one sequenced packet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeqOutcome {
+    /// First packet from this sender, or exactly the next expected seq
+    InOrder,
+    /// `n` packets were skipped before this one
+    Gap(u32),
+    /// Older than the last-seen seq (arrived late, or a duplicate)
+    Reordered,
+    /// Sender started over (seq 1 or a large backward jump); tracking reset
+    Restarted,
+}
+
+/// Tracks last-seen sequence number per sender
+#[derive(Debug)]
+pub struct SequenceTracker<K = SocketAddr> {
+    last_seen: HashMap<K, u32>,
+}
+
+impl<K> Default for SequenceTracker<K> {
+    fn default() -> Self {
+        Self { last_seen: HashMap::new() }
+    }
+}
+
+impl<K: Hash + Eq> SequenceTracker<K> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record `seq` from `sender` (wrap-around safe)
+    pub fn observe(&mut self, sender: K, seq: u32) -> SeqOutcome {
+        let Some(last) = self.last_seen.get_mut(&sender) else {
+            self.last_seen.insert(sender, seq);
+            return SeqOutcome::InOrder;
+        };
+
+        let delta = seq.wrapping_sub(*last);
+        if delta == 0 || delta > u32::MAX / 2 {
+            // seq 1 after u32::MAX is the normal wrap, handled below
+            let backward = last.wrapping_sub(seq);
+            if (seq == 1 && *last != 1) || backward > RESTART_BACKWARD_JUMP {
+                *last = seq;
+                return SeqOutcome::Restarted;
+            }
+            return SeqOutcome::Reordered;
+        }
+
+        // seq 0 is never sent, so wrapping past it doesn't count as a loss
+        let wrapped = seq < *last;
+        *last = seq;
+        match delta - wrapped as u32 {
+            1 => SeqOutcome::InOrder,
+            step => SeqOutcome::Gap(step - 1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sequence_gaps_and_reordering() {
+        let a: SocketAddr = "127.0.0.1:50000".parse().unwrap();
+        let b: SocketAddr = "127.0.0.1:50001".parse().unwrap();
+        let mut tracker = SequenceTracker::new();
+
+        assert_eq!(tracker.observe(a, 1), SeqOutcome::InOrder);
+        assert_eq!(tracker.observe(a, 2), SeqOutcome::InOrder);
+        assert_eq!(tracker.observe(a, 5), SeqOutcome::Gap(2));
+        assert_eq!(tracker.observe(a, 4), SeqOutcome::Reordered);
+        assert_eq!(tracker.observe(a, 5), SeqOutcome::Reordered);
+        assert_eq!(tracker.observe(a, 6), SeqOutcome::InOrder);
+
+        // Senders are tracked independently
+        assert_eq!(tracker.observe(b, 100), SeqOutcome::InOrder);
+        assert_eq!(tracker.observe(b, 101), SeqOutcome::InOrder);
+
+        // Wrap-around skips 0
+        let c: SocketAddr = "127.0.0.1:50002".parse().unwrap();
+        assert_eq!(tracker.observe(c, u32::MAX - 1), SeqOutcome::InOrder);
+        assert_eq!(tracker.observe(c, u32::MAX), SeqOutcome::InOrder);
+        assert_eq!(tracker.observe(c, 1), SeqOutcome::InOrder);
+        assert_eq!(tracker.observe(c, 3), SeqOutcome::Gap(1));
+    }
+
+    #[test]
+    fn test_sender_restart_resets_stream() {
+        let a: SocketAddr = "127.0.0.1:50000".parse().unwrap();
+        let mut tracker = SequenceTracker::new();
+
+        for seq in 1..=50 {
+            tracker.observe(a, seq);
+        }
+        // Restarted sender begins at 1 again: no reorder storm, no phantom gap
+        assert_eq!(tracker.observe(a, 1), SeqOutcome::Restarted);
+        assert_eq!(tracker.observe(a, 2), SeqOutcome::InOrder);
+        assert_eq!(tracker.observe(a, 3), SeqOutcome::InOrder);
+
+        // Its seq 1 lost: a large backward jump is also a restart
+        for seq in 4..=5000 {
+            tracker.observe(a, seq);
+        }
+        assert_eq!(tracker.observe(a, 3), SeqOutcome::Restarted);
+        assert_eq!(tracker.observe(a, 4), SeqOutcome::InOrder);
+
+        // A small step back is still just reordering
+        assert_eq!(tracker.observe(a, 6), SeqOutcome::Gap(1));
+        assert_eq!(tracker.observe(a, 5), SeqOutcome::Reordered);
+    }
+}
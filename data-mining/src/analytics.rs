@@ -0,0 +1,392 @@
+//! 📈 Analytics queries over the collector database
+//!
+//! Typed answers to the questions operators keep writing SQL for: top tokens
+//! by volume, pump.fun graduation rate, per-wallet win rates. Every function
+//! takes a plain `Connection` so it can run on a read-only handle next to the
+//! live writer (WAL allows concurrent readers), and the same queries back the
+//! `/analytics/*` JSON endpoints on the metrics server when
+//! `metrics.analytics_enabled` is set.
+//!
+//! Time ranges are `block_time >= since` (unix seconds) and are served by
+//! `idx_trades_block_time` / `idx_tokens_launch_time`.
+
+use anyhow::{Context, Result};
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use rusqlite::{params, Connection, OpenFlags};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+/// Trading activity for one token over the queried range
+#[derive(Debug, Clone, Serialize)]
+pub struct TokenVolume {
+    pub mint: String,
+    pub symbol: Option<String>,
+    pub vol_sol: f64,
+    pub num_buys: u64,
+    pub num_sells: u64,
+    pub unique_traders: u64,
+    /// Price of the latest trade in the range
+    pub last_price: f64,
+    pub migrated: bool,
+}
+
+/// Tokens by SOL volume traded since `since`
+pub fn top_tokens_by_volume(conn: &Connection, since: i64, limit: usize) -> Result<Vec<TokenVolume>> {
+    let mut stmt = conn.prepare(
+        "SELECT t.mint, k.symbol, SUM(t.amount_sol),
+                SUM(t.side = 'buy'), SUM(t.side = 'sell'), COUNT(DISTINCT t.trader),
+                (SELECT l.price FROM trades l WHERE l.mint = t.mint
+                 ORDER BY l.block_time DESC, l.slot DESC LIMIT 1),
+                COALESCE(k.migrated_to_raydium, 0)
+         FROM trades t
+         LEFT JOIN tokens k ON k.mint = t.mint
+         WHERE t.block_time >= ?1
+         GROUP BY t.mint
+         ORDER BY SUM(t.amount_sol) DESC
+         LIMIT ?2",
+    )?;
+
+    let tokens = stmt
+        .query_map(params![since, limit as i64], |row| {
+            Ok(TokenVolume {
+                mint: row.get(0)?,
+                symbol: row.get(1)?,
+                vol_sol: row.get(2)?,
+                num_buys: row.get(3)?,
+                num_sells: row.get(4)?,
+                unique_traders: row.get(5)?,
+                last_price: row.get(6)?,
+                migrated: row.get::<_, i32>(7)? != 0,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to query top tokens by volume")?;
+
+    Ok(tokens)
+}
+
+/// Graduation (bonding curve → AMM migration) of tokens launched since `since`
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct GraduationStats {
+    pub launched: u64,
+    pub graduated: u64,
+    /// graduated / launched (0.0 when nothing launched)
+    pub graduation_rate: f64,
+    /// Mean launch → migration time of graduated tokens
+    pub avg_secs_to_graduate: Option<f64>,
+}
+
+pub fn graduation_stats(conn: &Connection, since: i64) -> Result<GraduationStats> {
+    let (launched, graduated, avg_secs_to_graduate): (u64, Option<u64>, Option<f64>) = conn
+        .query_row(
+            "SELECT COUNT(*), SUM(migrated_to_raydium = 1),
+                    AVG(CASE WHEN migrated_to_raydium = 1 AND migration_block_time IS NOT NULL
+                             THEN migration_block_time - launch_block_time END)
+             FROM tokens
+             WHERE launch_block_time >= ?1",
+            params![since],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .context("Failed to query graduation stats")?;
+
+    let graduated = graduated.unwrap_or(0);
+    Ok(GraduationStats {
+        launched,
+        graduated,
+        graduation_rate: if launched > 0 { graduated as f64 / launched as f64 } else { 0.0 },
+        avg_secs_to_graduate,
+    })
+}
+
+/// Realized trading results for one wallet over the queried range
+///
+/// Per mint, a wallet "wins" when it received more SOL from sells than it
+/// spent on buys; mints it never sold are open and don't count toward the
+/// win rate.
+#[derive(Debug, Clone, Serialize)]
+pub struct WalletPerformance {
+    pub wallet: String,
+    pub alias: Option<String>,
+    pub is_tracked: bool,
+    pub mints_traded: u64,
+    /// Mints with at least one sell
+    pub mints_closed: u64,
+    pub wins: u64,
+    /// wins / mints_closed
+    pub win_rate: f64,
+    pub sol_spent: f64,
+    pub sol_received: f64,
+    /// sol_received - sol_spent (open positions count as spent)
+    pub net_sol: f64,
+}
+
+/// Wallets by net SOL since `since`, skipping those with fewer than `min_closed` closed mints
+pub fn wallet_performance(
+    conn: &Connection,
+    since: i64,
+    min_closed: u32,
+    limit: usize,
+) -> Result<Vec<WalletPerformance>> {
+    let mut stmt = conn.prepare(
+        "SELECT p.trader, w.alias, COALESCE(w.is_tracked, 0),
+                COUNT(*), SUM(p.sold), SUM(p.sold AND p.sol_out > p.sol_in),
+                SUM(p.sol_in), SUM(p.sol_out)
+         FROM (
+             SELECT trader, mint,
+                    SUM(CASE WHEN side = 'buy' THEN amount_sol ELSE 0 END) AS sol_in,
+                    SUM(CASE WHEN side = 'sell' THEN amount_sol ELSE 0 END) AS sol_out,
+                    MAX(side = 'sell') AS sold
+             FROM trades
+             WHERE block_time >= ?1
+             GROUP BY trader, mint
+         ) p
+         LEFT JOIN wallet_stats w ON w.wallet = p.trader
+         GROUP BY p.trader
+         HAVING SUM(p.sold) >= ?2
+         ORDER BY SUM(p.sol_out) - SUM(p.sol_in) DESC
+         LIMIT ?3",
+    )?;
+
+    let wallets = stmt
+        .query_map(params![since, min_closed, limit as i64], |row| {
+            let mints_closed: u64 = row.get(4)?;
+            let wins: u64 = row.get(5)?;
+            let sol_spent: f64 = row.get(6)?;
+            let sol_received: f64 = row.get(7)?;
+            Ok(WalletPerformance {
+                wallet: row.get(0)?,
+                alias: row.get(1)?,
+                is_tracked: row.get::<_, i32>(2)? == 1,
+                mints_traded: row.get(3)?,
+                mints_closed,
+                wins,
+                win_rate: if mints_closed > 0 { wins as f64 / mints_closed as f64 } else { 0.0 },
+                sol_spent,
+                sol_received,
+                net_sol: sol_received - sol_spent,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()
+        .context("Failed to query wallet performance")?;
+
+    Ok(wallets)
+}
+
+// ============================================================================
+// HTTP ENDPOINTS (merged into the metrics server)
+// ============================================================================
+
+/// Query string shared by the `/analytics/*` endpoints
+#[derive(Debug, Deserialize)]
+pub struct AnalyticsParams {
+    /// Look-back from now (seconds)
+    #[serde(default = "default_window_secs")]
+    pub window_secs: i64,
+    #[serde(default = "default_limit")]
+    pub limit: usize,
+    /// `/analytics/wallets` only
+    #[serde(default = "default_min_closed")]
+    pub min_closed: u32,
+}
+
+fn default_window_secs() -> i64 {
+    3600
+}
+
+fn default_limit() -> usize {
+    20
+}
+
+fn default_min_closed() -> u32 {
+    3
+}
+
+/// Largest `limit` the endpoints accept
+const MAX_LIMIT: usize = 500;
+
+/// `/analytics/top_tokens`, `/analytics/graduation` and `/analytics/wallets`
+/// over a read-only connection to `db_path`
+pub fn router(db_path: String) -> Router {
+    Router::new()
+        .route("/analytics/top_tokens", get(top_tokens_handler))
+        .route("/analytics/graduation", get(graduation_handler))
+        .route("/analytics/wallets", get(wallets_handler))
+        .with_state(db_path)
+}
+
+/// Run `query` on a fresh read-only connection off the async runtime
+async fn run_query<T, F>(db_path: String, params: AnalyticsParams, query: F) -> Response
+where
+    T: Serialize + Send + 'static,
+    F: FnOnce(&Connection, i64, &AnalyticsParams) -> Result<T> + Send + 'static,
+{
+    let result = tokio::task::spawn_blocking(move || {
+        let conn = Connection::open_with_flags(&db_path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+            .context("Failed to open database for analytics")?;
+        let since = chrono::Utc::now().timestamp() - params.window_secs.max(0);
+        query(&conn, since, &params)
+    })
+    .await;
+
+    match result {
+        Ok(Ok(body)) => (StatusCode::OK, Json(body)).into_response(),
+        Ok(Err(e)) => {
+            warn!("⚠️  Analytics query failed: {:#}", e);
+            (StatusCode::INTERNAL_SERVER_ERROR, format!("{:#}", e)).into_response()
+        }
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+async fn top_tokens_handler(State(db_path): State<String>, Query(params): Query<AnalyticsParams>) -> Response {
+    run_query(db_path, params, |conn, since, p| top_tokens_by_volume(conn, since, p.limit.min(MAX_LIMIT))).await
+}
+
+async fn graduation_handler(State(db_path): State<String>, Query(params): Query<AnalyticsParams>) -> Response {
+    run_query(db_path, params, |conn, since, _| graduation_stats(conn, since)).await
+}
+
+async fn wallets_handler(State(db_path): State<String>, Query(params): Query<AnalyticsParams>) -> Response {
+    run_query(db_path, params, |conn, since, p| {
+        wallet_performance(conn, since, p.min_closed, p.limit.min(MAX_LIMIT))
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::Database;
+    use crate::types::{Token, Trade, TradeSide};
+    use tempfile::tempdir;
+
+    fn token(mint: &str, launch_block_time: i64, migration_block_time: Option<i64>) -> Token {
+        Token {
+            mint: mint.to_string(),
+            creator_wallet: "creator".to_string(),
+            bonding_curve_addr: None,
+            name: None,
+            symbol: Some(mint.to_uppercase()),
+            uri: None,
+            decimals: 6,
+            launch_tx_sig: format!("launch-{}", mint),
+            launch_slot: 1,
+            launch_block_time,
+            initial_price: None,
+            initial_liquidity_sol: None,
+            initial_supply: None,
+            market_cap_init: None,
+            mint_authority: None,
+            freeze_authority: None,
+            metadata_update_auth: None,
+            migrated_to_raydium: migration_block_time.is_some(),
+            migration_slot: migration_block_time.map(|t| t as u64),
+            migration_block_time,
+            raydium_pool: None,
+            observed_at: launch_block_time,
+        }
+    }
+
+    fn trade(sig: &str, mint: &str, trader: &str, side: TradeSide, amount_sol: f64, block_time: i64) -> Trade {
+        Trade {
+            sig: sig.to_string(),
+            slot: block_time as u64,
+            block_time,
+            mint: mint.to_string(),
+            side,
+            trader: trader.to_string(),
+            amount_tokens: amount_sol * 1_000.0,
+            amount_sol,
+            price: amount_sol / 1_000.0,
+            is_amm: false,
+        }
+    }
+
+    fn seeded_db() -> (tempfile::TempDir, Connection) {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("analytics.db");
+        let mut db = Database::new(&path, true).unwrap();
+
+        db.insert_token(&token("alpha", 1_000, Some(1_600))).unwrap();
+        db.insert_token(&token("beta", 1_100, None)).unwrap();
+        db.insert_token(&token("gamma", 1_200, Some(1_400))).unwrap();
+        db.insert_token(&token("stale", 10, None)).unwrap();
+
+        for t in [
+            // winner: +1 on alpha, +0.5 on beta
+            trade("w1", "alpha", "winner", TradeSide::Buy, 1.0, 2_000),
+            trade("w2", "alpha", "winner", TradeSide::Sell, 2.0, 2_010),
+            trade("w3", "beta", "winner", TradeSide::Buy, 1.0, 2_020),
+            trade("w4", "beta", "winner", TradeSide::Sell, 1.5, 2_030),
+            // loser: -0.5 on alpha, gamma still open
+            trade("l1", "alpha", "loser", TradeSide::Buy, 3.0, 2_040),
+            trade("l2", "alpha", "loser", TradeSide::Sell, 2.5, 2_050),
+            trade("l3", "gamma", "loser", TradeSide::Buy, 0.5, 2_060),
+            // before the range
+            trade("old", "stale", "winner", TradeSide::Buy, 50.0, 100),
+        ] {
+            db.insert_trade(&t).unwrap();
+        }
+        db.flush_trade_buffer().unwrap();
+
+        let conn = db.get_connection_for_writer().unwrap();
+        (dir, conn)
+    }
+
+    #[test]
+    fn test_top_tokens_by_volume() {
+        let (_dir, conn) = seeded_db();
+        let tokens = top_tokens_by_volume(&conn, 1_000, 2).unwrap();
+
+        assert_eq!(tokens.len(), 2);
+        assert_eq!(tokens[0].mint, "alpha");
+        assert_eq!(tokens[0].symbol.as_deref(), Some("ALPHA"));
+        assert_eq!((tokens[0].num_buys, tokens[0].num_sells, tokens[0].unique_traders), (2, 2, 2));
+        assert!((tokens[0].vol_sol - 8.5).abs() < 1e-9);
+        assert!((tokens[0].last_price - 0.0025).abs() < 1e-12);
+        assert!(tokens[0].migrated);
+        assert_eq!(tokens[1].mint, "beta");
+
+        let plan: String = conn
+            .query_row("EXPLAIN QUERY PLAN SELECT mint FROM trades WHERE block_time >= 1000", [], |row| row.get(3))
+            .unwrap();
+        assert!(plan.contains("idx_trades_block_time"), "{}", plan);
+    }
+
+    #[test]
+    fn test_graduation_stats() {
+        let (_dir, conn) = seeded_db();
+        let stats = graduation_stats(&conn, 1_000).unwrap();
+        assert_eq!((stats.launched, stats.graduated), (3, 2));
+        assert!((stats.graduation_rate - 2.0 / 3.0).abs() < 1e-9);
+        assert_eq!(stats.avg_secs_to_graduate, Some(400.0));
+
+        let empty = graduation_stats(&conn, 5_000).unwrap();
+        assert_eq!(empty, GraduationStats { launched: 0, graduated: 0, graduation_rate: 0.0, avg_secs_to_graduate: None });
+    }
+
+    #[test]
+    fn test_wallet_performance() {
+        let (_dir, conn) = seeded_db();
+        let wallets = wallet_performance(&conn, 1_000, 1, 10).unwrap();
+
+        assert_eq!(wallets.len(), 2);
+        let winner = &wallets[0];
+        assert_eq!(winner.wallet, "winner");
+        assert_eq!((winner.mints_traded, winner.mints_closed, winner.wins), (2, 2, 2));
+        assert!((winner.net_sol - 1.5).abs() < 1e-9);
+
+        let loser = &wallets[1];
+        assert_eq!((loser.mints_traded, loser.mints_closed, loser.wins), (2, 1, 0));
+        assert_eq!(loser.win_rate, 0.0);
+        assert!((loser.net_sol + 1.0).abs() < 1e-9);
+
+        assert_eq!(wallet_performance(&conn, 1_000, 2, 10).unwrap().len(), 1);
+    }
+}
@@ -1,13 +1,216 @@
 use anyhow::{Context, Result};
+use base64::{Engine as _, engine::general_purpose};
 use serde::{Deserialize, Serialize};
+use std::collections::{HashSet, VecDeque};
 use std::fs;
-use std::path::Path;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::mpsc;
 use tracing::{info, warn};
 
+/// Default number of recent signatures persisted alongside the slot
+pub const DEFAULT_SIGNATURE_CAPACITY: usize = 50_000;
+
+/// Checkpoint shared between the stream loop and the interval saver
+pub type SharedCheckpoint = Arc<Mutex<Checkpoint>>;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Checkpoint {
     pub last_processed_slot: u64,
     pub last_updated: i64,
+    /// Capped ring of recently processed signatures, stored as 8-byte fingerprints
+    /// (persisted in the `.sigs` sidecar; older checkpoints inlined it as base64)
+    #[serde(default, skip_serializing, deserialize_with = "fingerprint_ring::deserialize")]
+    recent_signatures: VecDeque<u64>,
+    #[serde(skip)]
+    signature_index: HashSet<u64>,
+    #[serde(default = "default_signature_capacity")]
+    signature_capacity: usize,
+    /// Slot of the last snapshot taken for saving (drives `snapshot_if_due`)
+    #[serde(skip)]
+    last_saved_slot: u64,
+    /// Changed since the last snapshot taken for saving (drives `snapshot_if_dirty`)
+    #[serde(skip)]
+    dirty: bool,
+}
+
+fn default_signature_capacity() -> usize {
+    DEFAULT_SIGNATURE_CAPACITY
+}
+
+/// Fingerprint a base58 signature by its first 8 raw bytes.
+/// Signatures are uniformly random, so 64 bits is plenty to avoid collisions
+/// in a ring of a few tens of thousands of entries.
+fn signature_fingerprint(signature: &str) -> u64 {
+    match bs58::decode(signature).into_vec() {
+        Ok(bytes) if bytes.len() >= 8 => {
+            u64::from_le_bytes(bytes[..8].try_into().unwrap())
+        }
+        // Not a valid base58 signature - fall back to hashing the string
+        _ => {
+            use std::hash::{Hash, Hasher};
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            signature.hash(&mut hasher);
+            hasher.finish()
+        }
+    }
+}
+
+/// Legacy checkpoints inlined the signature ring as one base64 string of little-endian u64s
+mod fingerprint_ring {
+    use super::*;
+    use serde::Deserializer;
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> std::result::Result<VecDeque<u64>, D::Error> {
+        let encoded = String::deserialize(deserializer)?;
+        let bytes = general_purpose::STANDARD
+            .decode(encoded)
+            .map_err(serde::de::Error::custom)?;
+        Ok(decode_fingerprints(&bytes))
+    }
+}
+
+fn decode_fingerprints(bytes: &[u8]) -> VecDeque<u64> {
+    bytes
+        .chunks_exact(8)
+        .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()))
+        .collect()
+}
+
+/// Sidecar next to the checkpoint holding the signature ring as raw little-endian u64s
+fn signatures_path(path: &Path) -> PathBuf {
+    path.with_extension("sigs")
+}
+
+/// Write `contents` to a temp file, fsync it, then rename it over `path`
+fn write_atomic(path: &Path, contents: &[u8]) -> Result<()> {
+    let mut temp_path = path.as_os_str().to_owned();
+    temp_path.push(".tmp");
+    let mut file = fs::File::create(&temp_path)
+        .context("Failed to create temp checkpoint file")?;
+    file.write_all(contents)
+        .context("Failed to write temp checkpoint file")?;
+    file.sync_all()
+        .context("Failed to fsync temp checkpoint file")?;
+    fs::rename(&temp_path, path)
+        .context("Failed to rename checkpoint file")
+}
+
+/// Owned copy of a checkpoint's persistent state, cheap to take on the stream
+/// loop and written (with fsync) elsewhere
+#[derive(Debug, Clone, Serialize)]
+pub struct CheckpointSnapshot {
+    pub last_processed_slot: u64,
+    pub last_updated: i64,
+    signature_capacity: usize,
+    #[serde(skip)]
+    recent_signatures: Vec<u64>,
+}
+
+impl CheckpointSnapshot {
+    /// Write to `path` (fsynced, so it survives power loss). Blocking: run it
+    /// on the blocking pool, e.g. via `spawn_checkpoint_writer`.
+    pub fn write<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        // Create parent directory if it doesn't exist
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .context("Failed to create checkpoint directory")?;
+        }
+
+        // Ring first: a crash between the two renames leaves a ring that is
+        // ahead of the slot, which only skips transactions already processed
+        let mut ring = Vec::with_capacity(self.recent_signatures.len() * 8);
+        for fp in &self.recent_signatures {
+            ring.extend_from_slice(&fp.to_le_bytes());
+        }
+        write_atomic(&signatures_path(path), &ring)?;
+
+        let contents = serde_json::to_vec(self)
+            .context("Failed to serialize checkpoint")?;
+        write_atomic(path, &contents)?;
+
+        // Persist the renames themselves (directory entries)
+        #[cfg(unix)]
+        if let Some(parent) = path.parent() {
+            let dir = if parent.as_os_str().is_empty() { Path::new(".") } else { parent };
+            fs::File::open(dir)
+                .and_then(|d| d.sync_all())
+                .context("Failed to fsync checkpoint directory")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Handle to the background checkpoint writer
+#[derive(Clone)]
+pub struct CheckpointWriter {
+    tx: mpsc::UnboundedSender<CheckpointSnapshot>,
+}
+
+impl CheckpointWriter {
+    /// Queue a snapshot for writing (superseded queued snapshots are skipped)
+    pub fn submit(&self, snapshot: CheckpointSnapshot) {
+        if self.tx.send(snapshot).is_err() {
+            warn!("❌ Checkpoint writer channel closed!");
+        }
+    }
+}
+
+/// Spawn the task that writes checkpoint snapshots to `path` on the blocking
+/// pool, so serialization and fsync never stall the gRPC stream
+pub fn spawn_checkpoint_writer(path: String) -> CheckpointWriter {
+    let (tx, mut rx) = mpsc::unbounded_channel::<CheckpointSnapshot>();
+
+    tokio::spawn(async move {
+        while let Some(mut snapshot) = rx.recv().await {
+            // Only the newest state matters once a write has fallen behind
+            while let Ok(newer) = rx.try_recv() {
+                snapshot = newer;
+            }
+
+            let path = path.clone();
+            let slot = snapshot.last_processed_slot;
+            match tokio::task::spawn_blocking(move || snapshot.write(&path)).await {
+                Ok(Ok(())) => info!("💾 Checkpoint saved: slot {}", slot),
+                Ok(Err(e)) => warn!("Failed to save checkpoint: {}", e),
+                Err(e) => warn!("Checkpoint write task panicked: {}", e),
+            }
+        }
+    });
+
+    CheckpointWriter { tx }
+}
+
+/// Spawn the task that saves the checkpoint every `every` if it changed since
+/// the last save. Keeps quiet periods (slots advancing slowly) from losing
+/// progress without the stream loop watching the clock; an idle stream
+/// leaves the checkpoint clean and skips the write.
+pub fn spawn_checkpoint_saver(
+    checkpoint: SharedCheckpoint,
+    writer: CheckpointWriter,
+    every: Duration,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        info!("💾 Checkpoint saver: Started (interval={}s)", every.as_secs());
+        let mut interval = tokio::time::interval(every.max(Duration::from_secs(1)));
+        interval.tick().await; // Nothing new to save yet
+
+        loop {
+            interval.tick().await;
+
+            // Copying the ring is ~400KB at full capacity; keep it off the runtime threads
+            let shared = checkpoint.clone();
+            match tokio::task::spawn_blocking(move || shared.lock().unwrap().snapshot_if_dirty()).await {
+                Ok(Some(snapshot)) => writer.submit(snapshot),
+                Ok(None) => {}
+                Err(e) => warn!("Checkpoint snapshot task panicked: {}", e),
+            }
+        }
+    })
 }
 
 impl Checkpoint {
@@ -21,34 +224,36 @@ impl Checkpoint {
         let contents = fs::read_to_string(&path)
             .context("Failed to read checkpoint file")?;
         
-        let checkpoint: Checkpoint = serde_json::from_str(&contents)
+        let mut checkpoint: Checkpoint = serde_json::from_str(&contents)
             .context("Failed to parse checkpoint file")?;
+        let signatures_path = signatures_path(path.as_ref());
+        if signatures_path.exists() {
+            let ring = fs::read(&signatures_path)
+                .context("Failed to read checkpoint signatures")?;
+            checkpoint.recent_signatures = decode_fingerprints(&ring);
+        }
+        checkpoint.rebuild_signature_index();
+        checkpoint.last_saved_slot = checkpoint.last_processed_slot;
         
-        info!("✅ Loaded checkpoint: slot {} (updated at {})", 
-            checkpoint.last_processed_slot, checkpoint.last_updated);
+        info!("✅ Loaded checkpoint: slot {} (updated at {}, {} recent signatures)", 
+            checkpoint.last_processed_slot, checkpoint.last_updated, checkpoint.signature_count());
         Ok(Some(checkpoint))
     }
 
-    /// Save checkpoint to file
+    /// Save checkpoint to file (fsynced, so it survives power loss). Blocking:
+    /// on the runtime, submit a `snapshot` to a `CheckpointWriter` instead.
     pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
-        // Create parent directory if it doesn't exist
-        if let Some(parent) = path.as_ref().parent() {
-            fs::create_dir_all(parent)
-                .context("Failed to create checkpoint directory")?;
-        }
+        self.snapshot().write(path)
+    }
 
-        let contents = serde_json::to_string_pretty(self)
-            .context("Failed to serialize checkpoint")?;
-        
-        // Write to temp file first, then rename (atomic operation)
-        let temp_path = path.as_ref().with_extension("tmp");
-        fs::write(&temp_path, contents)
-            .context("Failed to write temp checkpoint file")?;
-        
-        fs::rename(&temp_path, &path)
-            .context("Failed to rename checkpoint file")?;
-        
-        Ok(())
+    /// Copy of the persistent state for writing off the stream loop
+    pub fn snapshot(&self) -> CheckpointSnapshot {
+        CheckpointSnapshot {
+            last_processed_slot: self.last_processed_slot,
+            last_updated: self.last_updated,
+            signature_capacity: self.signature_capacity,
+            recent_signatures: self.recent_signatures.iter().copied().collect(),
+        }
     }
 
     /// Create new checkpoint
@@ -56,31 +261,101 @@ impl Checkpoint {
         Self {
             last_processed_slot: slot,
             last_updated: chrono::Utc::now().timestamp(),
+            recent_signatures: VecDeque::new(),
+            signature_index: HashSet::new(),
+            signature_capacity: DEFAULT_SIGNATURE_CAPACITY,
+            last_saved_slot: slot,
+            dirty: true,
+        }
+    }
+
+    /// Set how many recent signatures are kept (oldest are evicted first)
+    pub fn with_signature_capacity(mut self, capacity: usize) -> Self {
+        self.signature_capacity = capacity;
+        self.evict_signatures();
+        self
+    }
+
+    /// Record a processed signature so it survives restarts
+    pub fn record_signature(&mut self, signature: &str) {
+        let fp = signature_fingerprint(signature);
+        if self.signature_index.insert(fp) {
+            self.recent_signatures.push_back(fp);
+            self.evict_signatures();
+            self.dirty = true;
+        }
+    }
+
+    /// Check whether a signature was recently processed
+    pub fn contains_signature(&self, signature: &str) -> bool {
+        self.signature_index.contains(&signature_fingerprint(signature))
+    }
+
+    /// Drop signatures from the dedup ring (reorged-out transactions may be
+    /// re-included in a later slot and must not be skipped)
+    pub fn forget_signatures(&mut self, signatures: &[String]) {
+        let forgotten: HashSet<u64> = signatures.iter()
+            .map(|sig| signature_fingerprint(sig))
+            .filter(|fp| self.signature_index.remove(fp))
+            .collect();
+        if !forgotten.is_empty() {
+            self.recent_signatures.retain(|fp| !forgotten.contains(fp));
+            self.dirty = true;
         }
     }
 
+    /// Rewind to just before `slot` if the checkpoint already advanced past it
+    pub fn rewind_before(&mut self, slot: u64) {
+        let target = slot.saturating_sub(1);
+        if self.last_processed_slot > target {
+            warn!("⏪ Checkpoint rewound: slot {} → {}", self.last_processed_slot, target);
+            self.update(target);
+            self.last_saved_slot = self.last_saved_slot.min(target);
+        }
+    }
+
+    /// Number of signatures currently remembered
+    pub fn signature_count(&self) -> usize {
+        self.recent_signatures.len()
+    }
+
+    fn evict_signatures(&mut self) {
+        while self.recent_signatures.len() > self.signature_capacity {
+            if let Some(old) = self.recent_signatures.pop_front() {
+                self.signature_index.remove(&old);
+            }
+        }
+    }
+
+    fn rebuild_signature_index(&mut self) {
+        self.evict_signatures();
+        self.signature_index = self.recent_signatures.iter().copied().collect();
+    }
+
     /// Update checkpoint with new slot
     pub fn update(&mut self, slot: u64) {
         self.last_processed_slot = slot;
         self.last_updated = chrono::Utc::now().timestamp();
+        self.dirty = true;
     }
 
-    /// Save checkpoint if enough slots have passed since last save
-    pub fn save_if_needed<P: AsRef<Path>>(&self, path: P, current_slot: u64, interval: u64) -> Result<bool> {
-        if current_slot >= self.last_processed_slot + interval {
-            match self.save(&path) {
-                Ok(_) => {
-                    info!("💾 Checkpoint saved: slot {}", self.last_processed_slot);
-                    Ok(true)
-                }
-                Err(e) => {
-                    warn!("Failed to save checkpoint: {}", e);
-                    Err(e)
-                }
-            }
-        } else {
-            Ok(false)
-        }
+    /// Snapshot for saving if `slot_interval` slots have passed since the last
+    /// one (0 disables it). Time-based saves come from `spawn_checkpoint_saver`.
+    pub fn snapshot_if_due(&mut self, slot_interval: u64) -> Option<CheckpointSnapshot> {
+        let due = slot_interval > 0
+            && self.last_processed_slot >= self.last_saved_slot.saturating_add(slot_interval);
+        due.then(|| self.snapshot_for_save())
+    }
+
+    /// Snapshot for saving if anything changed since the last one
+    pub fn snapshot_if_dirty(&mut self) -> Option<CheckpointSnapshot> {
+        self.dirty.then(|| self.snapshot_for_save())
+    }
+
+    fn snapshot_for_save(&mut self) -> CheckpointSnapshot {
+        self.last_saved_slot = self.last_processed_slot;
+        self.dirty = false;
+        self.snapshot()
     }
 }
 
@@ -113,6 +388,175 @@ mod tests {
         assert_eq!(reloaded.last_processed_slot, 67890);
     }
 
+    #[test]
+    fn test_signature_ring_roundtrip() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("checkpoint.json");
+
+        let sig_a = bs58::encode([1u8; 64]).into_string();
+        let sig_b = bs58::encode([2u8; 64]).into_string();
+
+        let mut checkpoint = Checkpoint::new(100);
+        checkpoint.record_signature(&sig_a);
+        checkpoint.record_signature(&sig_b);
+        checkpoint.record_signature(&sig_a); // duplicate is ignored
+        assert_eq!(checkpoint.signature_count(), 2);
+        checkpoint.save(&path).unwrap();
+
+        let loaded = Checkpoint::load(&path).unwrap().unwrap();
+        assert!(loaded.contains_signature(&sig_a));
+        assert!(loaded.contains_signature(&sig_b));
+        assert!(!loaded.contains_signature(&bs58::encode([3u8; 64]).into_string()));
+    }
+
+    #[test]
+    fn test_signature_ring_evicts_oldest() {
+        let mut checkpoint = Checkpoint::new(0).with_signature_capacity(2);
+        let sigs: Vec<String> = (1u8..=3).map(|b| bs58::encode([b; 64]).into_string()).collect();
+        for sig in &sigs {
+            checkpoint.record_signature(sig);
+        }
+
+        assert_eq!(checkpoint.signature_count(), 2);
+        assert!(!checkpoint.contains_signature(&sigs[0]));
+        assert!(checkpoint.contains_signature(&sigs[1]));
+        assert!(checkpoint.contains_signature(&sigs[2]));
+    }
+
+    #[test]
+    fn test_snapshot_if_due_by_slots() {
+        let mut checkpoint = Checkpoint::new(100);
+        checkpoint.update(500);
+        assert!(checkpoint.snapshot_if_due(1000).is_none());
+
+        // Slot interval counts from the last snapshot, not the last update
+        checkpoint.update(1100);
+        let snapshot = checkpoint.snapshot_if_due(1000).unwrap();
+        assert_eq!(snapshot.last_processed_slot, 1100);
+        checkpoint.update(1101);
+        assert!(checkpoint.snapshot_if_due(1000).is_none());
+        assert!(checkpoint.snapshot_if_due(0).is_none());
+    }
+
+    #[test]
+    fn test_snapshot_if_dirty_skips_idle() {
+        // Fresh checkpoints have never been saved
+        let mut checkpoint = Checkpoint::new(100);
+        assert!(checkpoint.snapshot_if_dirty().is_some());
+        assert!(checkpoint.snapshot_if_dirty().is_none());
+
+        checkpoint.record_signature(&bs58::encode([5u8; 64]).into_string());
+        assert!(checkpoint.snapshot_if_dirty().is_some());
+
+        // A slot-interval snapshot also counts as saved
+        checkpoint.update(2000);
+        assert!(checkpoint.snapshot_if_due(1000).is_some());
+        assert!(checkpoint.snapshot_if_dirty().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_saver_writes_only_when_dirty() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("checkpoint.json");
+        let writer = spawn_checkpoint_writer(path.to_str().unwrap().to_string());
+        let checkpoint: SharedCheckpoint = Arc::new(Mutex::new(Checkpoint::new(10)));
+        checkpoint.lock().unwrap().snapshot_if_dirty(); // as if just loaded
+        spawn_checkpoint_saver(checkpoint.clone(), writer, Duration::from_secs(1));
+
+        // Idle: nothing written across a couple of intervals
+        tokio::time::sleep(Duration::from_millis(2500)).await;
+        assert!(!path.exists());
+
+        checkpoint.lock().unwrap().update(42);
+        for _ in 0..300 {
+            if let Ok(Some(loaded)) = Checkpoint::load(&path) {
+                assert_eq!(loaded.last_processed_slot, 42);
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("saver never wrote the dirty checkpoint");
+    }
+
+    #[tokio::test]
+    async fn test_writer_persists_latest_snapshot() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("checkpoint.json");
+        let writer = spawn_checkpoint_writer(path.to_str().unwrap().to_string());
+
+        let sig = bs58::encode([4u8; 64]).into_string();
+        let mut checkpoint = Checkpoint::new(10);
+        writer.submit(checkpoint.snapshot());
+        checkpoint.record_signature(&sig);
+        checkpoint.update(20);
+        writer.submit(checkpoint.snapshot());
+
+        for _ in 0..200 {
+            if let Ok(Some(loaded)) = Checkpoint::load(&path) {
+                if loaded.last_processed_slot == 20 {
+                    assert!(loaded.contains_signature(&sig));
+                    return;
+                }
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("writer never persisted slot 20");
+    }
+
+    #[test]
+    fn test_signature_ring_sidecar_is_raw_u64s() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("checkpoint.json");
+
+        let mut checkpoint = Checkpoint::new(5);
+        checkpoint.record_signature(&bs58::encode([1u8; 64]).into_string());
+        checkpoint.save(&path).unwrap();
+
+        assert_eq!(fs::read(dir.path().join("checkpoint.sigs")).unwrap(), [1u8; 8]);
+        assert!(!fs::read_to_string(&path).unwrap().contains("recent_signatures"));
+    }
+
+    #[test]
+    fn test_reorg_rewind_and_forget() {
+        let sigs: Vec<String> = (1u8..=3).map(|b| bs58::encode([b; 64]).into_string()).collect();
+        let mut checkpoint = Checkpoint::new(0);
+        for sig in &sigs {
+            checkpoint.record_signature(sig);
+        }
+        checkpoint.update(120);
+
+        checkpoint.forget_signatures(&sigs[..2]);
+        assert!(!checkpoint.contains_signature(&sigs[0]));
+        assert!(!checkpoint.contains_signature(&sigs[1]));
+        assert!(checkpoint.contains_signature(&sigs[2]));
+        assert_eq!(checkpoint.signature_count(), 1);
+
+        checkpoint.rewind_before(100);
+        assert_eq!(checkpoint.last_processed_slot, 99);
+        checkpoint.rewind_before(150); // never moves forward
+        assert_eq!(checkpoint.last_processed_slot, 99);
+    }
+
+    #[test]
+    fn test_legacy_inline_signature_ring() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("checkpoint.json");
+        let ring = general_purpose::STANDARD.encode([1u8; 8]);
+        fs::write(&path, format!(r#"{{"last_processed_slot": 9, "last_updated": 0, "recent_signatures": "{}"}}"#, ring)).unwrap();
+
+        let loaded = Checkpoint::load(&path).unwrap().unwrap();
+        assert!(loaded.contains_signature(&bs58::encode([1u8; 64]).into_string()));
+    }
+
+    #[test]
+    fn test_legacy_checkpoint_without_signatures() {
+        let mut checkpoint: Checkpoint =
+            serde_json::from_str(r#"{"last_processed_slot": 7, "last_updated": 0}"#).unwrap();
+        checkpoint.rebuild_signature_index();
+        assert_eq!(checkpoint.last_processed_slot, 7);
+        assert_eq!(checkpoint.signature_count(), 0);
+    }
+
     #[test]
     fn test_checkpoint_load_nonexistent() {
         let result = Checkpoint::load("nonexistent.json").unwrap();
@@ -0,0 +1,82 @@
+/// Time base abstraction for trackers
+///
+/// Live mode uses the wall clock. Replay mode drives a `ReplayClock` from the
+/// recorded trade timestamps so that rolling windows, cooldowns and token ages
+/// behave exactly as they did when the trades happened.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Source of "now" for time-windowed logic
+pub trait Clock: Send + Sync {
+    /// Current time in unix milliseconds
+    fn now_ms(&self) -> u64;
+
+    /// Current time in unix seconds
+    fn now_secs(&self) -> i64 {
+        (self.now_ms() / 1000) as i64
+    }
+}
+
+/// Wall clock (live mode)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now_ms(&self) -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64
+    }
+}
+
+/// Manually advanced clock (replay mode)
+#[derive(Debug, Default)]
+pub struct ReplayClock {
+    now_ms: AtomicU64,
+}
+
+impl ReplayClock {
+    pub fn new(start_ms: u64) -> Self {
+        Self {
+            now_ms: AtomicU64::new(start_ms),
+        }
+    }
+
+    /// Move the clock to `timestamp_ms` (never moves backwards)
+    pub fn set(&self, timestamp_ms: u64) {
+        self.now_ms.fetch_max(timestamp_ms, Ordering::Relaxed);
+    }
+}
+
+impl Clock for ReplayClock {
+    fn now_ms(&self) -> u64 {
+        self.now_ms.load(Ordering::Relaxed)
+    }
+}
+
+/// Shared wall clock handle (default for all trackers)
+pub fn system_clock() -> Arc<dyn Clock> {
+    Arc::new(SystemClock)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replay_clock_is_monotonic() {
+        let clock = ReplayClock::new(1_000);
+        assert_eq!(clock.now_ms(), 1_000);
+        assert_eq!(clock.now_secs(), 1);
+
+        clock.set(5_500);
+        assert_eq!(clock.now_ms(), 5_500);
+
+        // Out-of-order timestamps never rewind the clock
+        clock.set(2_000);
+        assert_eq!(clock.now_ms(), 5_500);
+    }
+}
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
 use std::fs;
+use std::str::FromStr;
 use anyhow::{Context, Result};
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -12,6 +14,24 @@ pub struct Config {
     pub monitoring: MonitoringConfig,
     pub rpc: RpcConfig,
     pub advice_bus: AdviceBusConfig,
+    #[serde(default)]
+    pub momentum: MomentumConfig,
+    #[serde(default)]
+    pub sybil: SybilConfig,
+    #[serde(default)]
+    pub price_oracle: PriceOracleConfig,
+    #[serde(default)]
+    pub metrics: MetricsConfig,
+    #[serde(default)]
+    pub health: HealthConfig,
+    #[serde(default)]
+    pub tracked_wallets: TrackedWalletsConfig,
+    #[serde(default)]
+    pub late_opportunity: LateOpportunityConfig,
+    #[serde(default)]
+    pub hotlist: HotlistConfig,
+    #[serde(default)]
+    pub shadow_advisory: ShadowAdvisoryConfig,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -26,6 +46,10 @@ pub struct ProgramConfig {
     pub pump_program: String,
     pub spl_token_program: String,
     pub raydium_amm_v4: String,
+    /// Additional programs in the same gRPC subscription, each routed to its
+    /// parser (pump AMM → PumpAMM, Raydium AMM v4 / CPMM → Raydium)
+    #[serde(default)]
+    pub extra_programs: Vec<String>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -33,17 +57,100 @@ pub struct DatabaseConfig {
     pub path: String,
     pub batch_size: usize,
     pub wal_mode: bool,
+    #[serde(default)]
+    pub trade_buffer: TradeBufferConfig,
+}
+
+/// When `Database::insert_trade` flushes its buffer (`[database.trade_buffer]`)
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct TradeBufferConfig {
+    /// Flush once this many trades are buffered (starting size in adaptive mode)
+    pub batch_size: usize,
+    /// Flush once this long has passed since the last flush (starting limit in adaptive mode)
+    pub time_limit_ms: u64,
+    /// Grow the batch size while bursts fill it quickly, shrink the time limit while quiet
+    pub adaptive: bool,
+    pub min_batch_size: usize,
+    pub max_batch_size: usize,
+    pub min_time_limit_ms: u64,
+    pub max_time_limit_ms: u64,
+}
+
+impl Default for TradeBufferConfig {
+    fn default() -> Self {
+        Self {
+            batch_size: 50,
+            time_limit_ms: 100,
+            adaptive: false,
+            min_batch_size: 50,
+            max_batch_size: 1_000,
+            min_time_limit_ms: 20,
+            max_time_limit_ms: 100,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct CheckpointConfig {
     pub path: String,
+    /// Save every N slots (0 = time-based only)
     pub save_interval: u64,
+    /// Also save every N seconds if anything changed (0 = slot-based only)
+    #[serde(default = "default_checkpoint_save_interval_secs")]
+    pub save_interval_secs: u64,
+}
+
+fn default_checkpoint_save_interval_secs() -> u64 {
+    10
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct WindowsConfig {
     pub intervals: Vec<u64>,
+    /// Refuse to start when `intervals` omits a required window (default: add it and warn)
+    #[serde(default)]
+    pub strict_intervals: bool,
+}
+
+/// Window intervals Brain's mint cache reads (`buyers_2s`, `vol_5s_sol`, 60s features)
+/// IMPORTANT: must match Brain's `feature_cache::mint_cache::REQUIRED_WINDOW_INTERVALS`
+pub const REQUIRED_WINDOW_INTERVALS: [u64; 3] = [2, 5, 60];
+
+impl WindowsConfig {
+    /// Required intervals not present in `intervals`
+    pub fn missing_required(&self) -> Vec<u64> {
+        REQUIRED_WINDOW_INTERVALS
+            .iter()
+            .copied()
+            .filter(|sec| !self.intervals.contains(sec))
+            .collect()
+    }
+
+    /// Startup check: the intervals to aggregate, or an error in strict mode when
+    /// required windows are missing
+    pub fn validate(&self) -> Result<Vec<u64>> {
+        let missing = self.missing_required();
+        if !missing.is_empty() && self.strict_intervals {
+            anyhow::bail!(
+                "[windows] intervals {:?} missing {:?} required by Brain's mint cache",
+                self.intervals, missing
+            );
+        }
+        Ok(self.effective_intervals())
+    }
+
+    /// Configured intervals plus any missing `REQUIRED_WINDOW_INTERVALS` (sorted, deduped)
+    pub fn effective_intervals(&self) -> Vec<u64> {
+        let mut intervals: Vec<u64> = self.intervals.iter()
+            .chain(REQUIRED_WINDOW_INTERVALS.iter())
+            .copied()
+            .filter(|&sec| sec > 0)
+            .collect();
+        intervals.sort_unstable();
+        intervals.dedup();
+        intervals
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -75,6 +182,170 @@ impl Config {
             .or_else(|_| Self::load("config.example.toml"))
             .context("Failed to load configuration")
     }
+
+    /// Startup check of values serde accepts but the collector can't run with
+    ///
+    /// Every problem is collected so a bad config is fixed in one pass instead
+    /// of surfacing one cryptic runtime error at a time.
+    pub fn validate(&self) -> Result<()> {
+        let mut problems = Vec::new();
+
+        // Program IDs
+        for (key, id) in [
+            ("programs.pump_program", &self.programs.pump_program),
+            ("programs.spl_token_program", &self.programs.spl_token_program),
+            ("programs.raydium_amm_v4", &self.programs.raydium_amm_v4),
+        ] {
+            if Pubkey::from_str(id).is_err() {
+                problems.push(format!("{} '{}' is not a valid pubkey", key, id));
+            }
+        }
+        for id in &self.programs.extra_programs {
+            if Pubkey::from_str(id).is_err() {
+                problems.push(format!("programs.extra_programs '{}' is not a valid pubkey", id));
+            } else if crate::parser::ProgramKind::of(id, &self.programs.pump_program).is_none() {
+                problems.push(format!("programs.extra_programs '{}' has no parser (pump AMM, Raydium AMM v4/CPMM)", id));
+            }
+        }
+
+        // Endpoints and ports
+        if self.grpc.endpoint.trim().is_empty() {
+            problems.push("grpc.endpoint must be set".to_string());
+        }
+        if self.rpc.endpoint.trim().is_empty() {
+            problems.push("rpc.endpoint must be set".to_string());
+        }
+        if self.advice_bus.enabled && self.advice_bus.port == 0 {
+            problems.push("advice_bus.port must be > 0".to_string());
+        }
+        if self.metrics.enabled && self.metrics.port == 0 {
+            problems.push("metrics.port must be > 0".to_string());
+        }
+        if self.advice_bus.enabled && self.metrics.enabled && self.advice_bus.port == self.metrics.port {
+            problems.push(format!("advice_bus.port and metrics.port are both {}", self.metrics.port));
+        }
+        if self.health.max_tx_age_secs == 0 {
+            problems.push("health.max_tx_age_secs must be > 0".to_string());
+        }
+
+        if !(0.0..=1.0).contains(&self.sybil.fresh_wallet_weight) {
+            problems.push(format!("sybil.fresh_wallet_weight {} must be within 0.0..=1.0", self.sybil.fresh_wallet_weight));
+        }
+        if self.sybil.enabled && self.sybil.wallet_history_secs == 0 {
+            problems.push("sybil.wallet_history_secs must be > 0 when sybil.enabled".to_string());
+        }
+
+        // Database / checkpoint
+        if self.database.batch_size == 0 {
+            problems.push("database.batch_size must be > 0".to_string());
+        }
+        let buffer = &self.database.trade_buffer;
+        if buffer.min_batch_size == 0 || buffer.min_time_limit_ms == 0 {
+            problems.push("database.trade_buffer min_batch_size and min_time_limit_ms must be > 0".to_string());
+        }
+        if !(buffer.min_batch_size..=buffer.max_batch_size).contains(&buffer.batch_size) {
+            problems.push(format!(
+                "database.trade_buffer.batch_size {} must be within min_batch_size {} ..= max_batch_size {}",
+                buffer.batch_size, buffer.min_batch_size, buffer.max_batch_size
+            ));
+        }
+        if !(buffer.min_time_limit_ms..=buffer.max_time_limit_ms).contains(&buffer.time_limit_ms) {
+            problems.push(format!(
+                "database.trade_buffer.time_limit_ms {} must be within min_time_limit_ms {} ..= max_time_limit_ms {}",
+                buffer.time_limit_ms, buffer.min_time_limit_ms, buffer.max_time_limit_ms
+            ));
+        }
+        if self.checkpoint.save_interval == 0 && self.checkpoint.save_interval_secs == 0 {
+            problems.push("checkpoint.save_interval and save_interval_secs can't both be 0".to_string());
+        }
+
+        // Windows
+        let intervals = &self.windows.intervals;
+        if intervals.is_empty() {
+            problems.push("windows.intervals must not be empty".to_string());
+        } else if intervals.contains(&0) {
+            problems.push(format!("windows.intervals {:?} contains 0", intervals));
+        } else if !intervals.windows(2).all(|pair| pair[0] < pair[1]) {
+            problems.push(format!("windows.intervals {:?} must be sorted ascending without duplicates", intervals));
+        }
+
+        // Thresholds
+        if self.advice_bus.sustained_volume_min_sol <= 0.0 {
+            problems.push("advice_bus.sustained_volume_min_sol must be > 0".to_string());
+        }
+        if self.advice_bus.sustained_volume_threshold_secs <= 0 {
+            problems.push("advice_bus.sustained_volume_threshold_secs must be > 0".to_string());
+        }
+        if self.advice_bus.confidence > 100 {
+            problems.push("advice_bus.confidence must be <= 100".to_string());
+        }
+        if self.advice_bus.min_initial_liquidity_sol < 0.0 {
+            problems.push("advice_bus.min_initial_liquidity_sol must be >= 0".to_string());
+        }
+        if self.advice_bus.max_datagram_bytes < crate::udp::batched_sender::MAX_MESSAGE_BYTES {
+            problems.push(format!("advice_bus.max_datagram_bytes must be >= {} (largest signed message)",
+                crate::udp::batched_sender::MAX_MESSAGE_BYTES));
+        }
+        if let Some(path) = &self.advice_bus.unix_socket_path {
+            // sun_path holds 108 bytes including the terminating NUL
+            if !path.starts_with('/') || path.len() >= 108 {
+                problems.push(format!("advice_bus.unix_socket_path must be an absolute path under 108 bytes (got {:?})", path));
+            }
+        }
+        let rate_limit = &self.advice_bus.rate_limit;
+        if rate_limit.enabled && (rate_limit.rate_per_sec <= 0.0 || rate_limit.burst < 1.0) {
+            problems.push("advice_bus.rate_limit needs rate_per_sec > 0 and burst >= 1".to_string());
+        }
+        if self.momentum.threshold_buys == 0 || self.momentum.window_ms == 0 {
+            problems.push("momentum.threshold_buys and window_ms must be > 0".to_string());
+        }
+        if self.momentum.spike_multiplier <= 0.0 {
+            problems.push("momentum.spike_multiplier must be > 0".to_string());
+        }
+        if !(self.price_oracle.confidence_ratio > 0.0 && self.price_oracle.confidence_ratio < 1.0) {
+            problems.push("price_oracle.confidence_ratio must be between 0 and 1".to_string());
+        }
+        if self.price_oracle.broadcast_interval_secs == 0
+            || self.price_oracle.heartbeat_secs < self.price_oracle.broadcast_interval_secs
+        {
+            problems.push("price_oracle.broadcast_interval_secs must be > 0 and <= heartbeat_secs".to_string());
+        }
+        if self.price_oracle.history_retention_days > 0 && self.price_oracle.history_cleanup_interval_secs == 0 {
+            problems.push("price_oracle.history_cleanup_interval_secs must be > 0".to_string());
+        }
+        let mempool_addr = &self.price_oracle.mempool_price_addr;
+        if !mempool_addr.is_empty() && mempool_addr.parse::<std::net::SocketAddr>().is_err() {
+            problems.push(format!("price_oracle.mempool_price_addr '{}' is not a valid host:port", mempool_addr));
+        }
+        let late = &self.late_opportunity;
+        if late.min_age_secs >= late.max_age_secs {
+            problems.push("late_opportunity.min_age_secs must be < max_age_secs".to_string());
+        }
+        if late.min_vol_60s_sol < 0.0 || late.full_score_vol_60s_sol <= 0.0 {
+            problems.push("late_opportunity volume thresholds must be positive".to_string());
+        }
+        if self.hotlist.scoring_interval_sec == 0 {
+            problems.push("hotlist.scoring_interval_sec must be > 0".to_string());
+        }
+        if self.hotlist.min_age_sec < 0 || self.hotlist.min_age_sec >= self.hotlist.max_age_sec {
+            problems.push("hotlist needs 0 <= min_age_sec < max_age_sec".to_string());
+        }
+
+        let shadow = &self.shadow_advisory;
+        if shadow.enabled {
+            if shadow.log_path.trim().is_empty() {
+                problems.push("shadow_advisory.log_path must be set".to_string());
+            }
+            if shadow.late_opportunity.min_age_secs >= shadow.late_opportunity.max_age_secs {
+                problems.push("shadow_advisory.late_opportunity.min_age_secs must be < max_age_secs".to_string());
+            }
+        }
+
+        if !problems.is_empty() {
+            anyhow::bail!("Invalid configuration ({} problems):\n  - {}", problems.len(), problems.join("\n  - "));
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -85,4 +356,452 @@ pub struct AdviceBusConfig {
     pub sustained_volume_threshold_secs: i64,
     pub sustained_volume_min_sol: f64,
     pub confidence: u8,
+    /// Shared HMAC secret for Brain-bound packets (unset = use `secret_file`)
+    #[serde(default)]
+    pub secret: Option<String>,
+    /// Secret file shared with Brain (generated per run if missing)
+    #[serde(default = "default_advice_secret_file")]
+    pub secret_file: String,
+    /// Per-(mint, advice type) rate limiting
+    #[serde(default)]
+    pub rate_limit: AdviceRateLimitConfig,
+    /// Launches with less first-trade liquidity than this are still recorded but
+    /// never advised to Brain as LateOpportunity/Rank (0 = disabled)
+    #[serde(default)]
+    pub min_initial_liquidity_sol: f64,
+    /// Larger UDP messages are dropped instead of sent as fragmenting datagrams
+    #[serde(default = "default_max_datagram_bytes")]
+    pub max_datagram_bytes: usize,
+    /// Send to Brain's Unix datagram socket at this path instead of host:port
+    /// (single-host setups; must match Brain's ADVICE_BUS_UNIX_PATH)
+    #[serde(default)]
+    pub unix_socket_path: Option<String>,
+}
+
+impl AdviceBusConfig {
+    /// Whether a token's initial liquidity clears `min_initial_liquidity_sol`
+    /// (unknown liquidity = no first trade seen yet, so not advisable)
+    pub fn admits_initial_liquidity(&self, initial_liquidity_sol: Option<f64>) -> bool {
+        self.min_initial_liquidity_sol <= 0.0
+            || initial_liquidity_sol.is_some_and(|liq| liq >= self.min_initial_liquidity_sol)
+    }
+}
+
+/// Token-bucket limits for advisories about the same mint
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct AdviceRateLimitConfig {
+    pub enabled: bool,
+    /// Sustained advisories per second per (mint, advice type)
+    pub rate_per_sec: f64,
+    /// Bucket capacity (advisories allowed back-to-back)
+    pub burst: f64,
+    /// Identical packets for the same key within this window are coalesced (milliseconds)
+    pub coalesce_window_ms: u64,
+    /// Tier-A copy-trade signals bypass the limiter
+    pub exempt_tier_a: bool,
+}
+
+impl Default for AdviceRateLimitConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            rate_per_sec: 2.0,
+            burst: 4.0,
+            coalesce_window_ms: 1000,
+            exempt_tier_a: true,
+        }
+    }
+}
+
+fn default_advice_secret_file() -> String {
+    crate::udp::auth::default_secret_file()
+}
+
+fn default_max_datagram_bytes() -> usize {
+    crate::udp::batched_sender::DEFAULT_MAX_DATAGRAM_BYTES
+}
+
+/// Real-time momentum/window detection sensitivity
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct MomentumConfig {
+    /// Buys within `window_ms` required to emit a momentum signal
+    pub threshold_buys: u16,
+    /// Momentum detection window (milliseconds)
+    pub window_ms: u64,
+    /// Volume spike multiplier vs running average (5.0 = 5x)
+    pub spike_multiplier: f32,
+    /// Minimum time between signals for the same mint (milliseconds)
+    pub cooldown_ms: u64,
+    /// Minimum interval between WindowMetrics sends per mint (milliseconds)
+    pub metrics_interval_ms: u64,
+    /// Minimum trades in the last 2s before WindowMetrics are sent
+    pub min_activity_trades: usize,
+}
+
+impl Default for MomentumConfig {
+    fn default() -> Self {
+        Self {
+            threshold_buys: 3,
+            window_ms: 500,
+            spike_multiplier: 5.0,
+            cooldown_ms: 5000,
+            metrics_interval_ms: 500,
+            min_activity_trades: 3,
+        }
+    }
+}
+
+/// Sybil dampening for unique-buyer counts (WindowTracker and WindowAggregator)
+///
+/// A wallet is "fresh" on a token when that token is the first one it was
+/// seen trading; one entity spraying buys from new wallets inflates raw
+/// unique buyers, so fresh wallets count for `fresh_wallet_weight` in the
+/// filtered count. Raw counts are always kept alongside.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct SybilConfig {
+    pub enabled: bool,
+    /// Weight of a fresh wallet in filtered counts (0.0 = ignore, 1.0 = same as raw)
+    pub fresh_wallet_weight: f64,
+    /// How long WindowTracker remembers a wallet's first token (seconds)
+    pub wallet_history_secs: u64,
+}
+
+impl Default for SybilConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            fresh_wallet_weight: 0.25,
+            wallet_history_secs: 6 * 3600,
+        }
+    }
+}
+
+impl SybilConfig {
+    /// Unique buyers with fresh wallets down-weighted (the raw count when disabled)
+    pub fn filtered_buyers(&self, established: usize, fresh: usize) -> u64 {
+        if !self.enabled {
+            return (established + fresh) as u64;
+        }
+        established as u64 + (fresh as f64 * self.fresh_wallet_weight).round() as u64
+    }
+}
+
+/// SOL/USD oracle quality and fallback settings
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct PriceOracleConfig {
+    /// Max Pyth confidence interval as a fraction of price (0.03 = 3%)
+    pub confidence_ratio: f64,
+    /// Fall back to Jupiter when Pyth confidence is too wide
+    pub jupiter_fallback: bool,
+    /// Jupiter price API endpoint (queried with `?ids=<SOL mint>`)
+    pub jupiter_price_url: String,
+    /// Warn when Pyth and Jupiter diverge by more than this percentage
+    pub divergence_warn_pct: f64,
+    /// Seconds between price fetches (±2s jitter)
+    pub broadcast_interval_secs: u64,
+    /// Re-broadcast an unchanged price at least this often so consumers'
+    /// staleness guards (Brain/Executor SOL_PRICE_MAX_AGE_SECS) don't trip
+    pub heartbeat_secs: u64,
+    /// Days of pyth_prices history kept in SQLite (0 = keep forever)
+    pub history_retention_days: u64,
+    /// Seconds between pyth_prices retention cleanups
+    pub history_cleanup_interval_secs: u64,
+    /// mempool-watcher's SolPriceUpdate listener (its SOL_PRICE_PORT); empty = don't send
+    pub mempool_price_addr: String,
+}
+
+impl Default for PriceOracleConfig {
+    fn default() -> Self {
+        Self {
+            confidence_ratio: 0.03,
+            jupiter_fallback: true,
+            jupiter_price_url: "https://lite-api.jup.ag/price/v3".to_string(),
+            divergence_warn_pct: 1.0,
+            broadcast_interval_secs: 5,
+            heartbeat_secs: 15,
+            history_retention_days: 7,
+            history_cleanup_interval_secs: 3600,
+            mempool_price_addr: "127.0.0.1:45136".to_string(),
+        }
+    }
+}
+
+/// Prometheus metrics endpoint
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct MetricsConfig {
+    pub enabled: bool,
+    /// HTTP port for /metrics, /health and /ready (Brain uses 9090)
+    pub port: u16,
+    /// Also serve `/analytics/*` JSON queries over the database (opt-in: the
+    /// port binds on all interfaces)
+    pub analytics_enabled: bool,
+}
+
+impl Default for MetricsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            port: 9091,
+            analytics_enabled: false,
+        }
+    }
+}
+
+/// Liveness thresholds for `/health` (served on the metrics port)
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct HealthConfig {
+    /// Report unhealthy (503) when no transaction was processed for this long
+    pub max_tx_age_secs: u64,
+}
+
+impl Default for HealthConfig {
+    fn default() -> Self {
+        Self { max_tx_age_secs: 60 }
+    }
+}
+
+/// Tracked wallet hot reload
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct TrackedWalletsConfig {
+    /// Re-query `wallet_stats WHERE is_tracked = 1` every N seconds (0 = load once at startup)
+    pub reload_interval_secs: u64,
+}
+
+impl Default for TrackedWalletsConfig {
+    fn default() -> Self {
+        Self {
+            reload_interval_secs: 60,
+        }
+    }
+}
+
+/// Path D (late opportunity) criteria and scoring, shared by the real-time
+/// window-tracker check and `check_and_send_opportunities`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct LateOpportunityConfig {
+    /// Minimum token age (seconds, exclusive)
+    pub min_age_secs: u64,
+    /// Maximum token age (seconds, exclusive)
+    pub max_age_secs: u64,
+    /// Minimum SOL volume over 60s
+    pub min_vol_60s_sol: f64,
+    /// Minimum buyers over 60s
+    pub min_buyers_60s: u32,
+    /// 60s volume that earns the full volume score (50 pts)
+    pub full_score_vol_60s_sol: f64,
+    /// 60s buyers that earn the full buyer score (30 pts)
+    pub full_score_buyers_60s: u32,
+    /// Age that earns the full age score (20 pts)
+    pub full_score_age_secs: u64,
+    /// Opportunity horizon sent to Brain (seconds)
+    pub horizon_secs: u16,
+}
+
+impl Default for LateOpportunityConfig {
+    fn default() -> Self {
+        Self {
+            min_age_secs: 1200,
+            max_age_secs: 7200,
+            min_vol_60s_sol: 10.0,
+            min_buyers_60s: 10,
+            full_score_vol_60s_sol: 35.0,
+            full_score_buyers_60s: 40,
+            full_score_age_secs: 3600,
+            horizon_secs: 300,
+        }
+    }
+}
+
+impl LateOpportunityConfig {
+    /// Age window and activity thresholds met
+    pub fn qualifies(&self, age_secs: u64, vol_60s_sol: f64, buyers_60s: u32) -> bool {
+        age_secs > self.min_age_secs
+            && age_secs < self.max_age_secs
+            && vol_60s_sol >= self.min_vol_60s_sol
+            && buyers_60s >= self.min_buyers_60s
+    }
+
+    /// Late opportunity score (0-100): volume 50 + buyers 30 + age 20
+    pub fn score(&self, age_secs: u64, vol_60s_sol: f64, buyers_60s: u32) -> u8 {
+        let vol_score = (vol_60s_sol / self.full_score_vol_60s_sol.max(f64::EPSILON) * 50.0).clamp(0.0, 50.0);
+        let buyer_score = (buyers_60s as f64 / self.full_score_buyers_60s.max(1) as f64 * 30.0).clamp(0.0, 30.0);
+        let age_score = (age_secs as f64 / self.full_score_age_secs.max(1) as f64 * 20.0).clamp(0.0, 20.0);
+        (vol_score + buyer_score + age_score) as u8
+    }
+}
+
+/// Path A (rank) and Path B (momentum) thresholds for `check_and_send_opportunities`
+///
+/// The live collector uses the defaults; `[shadow_advisory.opportunity]` overrides
+/// them for the shadow set.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct OpportunityConfig {
+    /// Minimum SOL volume over 5s for a momentum opportunity
+    pub momentum_min_vol_5s_sol: f64,
+    /// Minimum buyers over 2s for a momentum opportunity
+    pub momentum_min_buyers_2s: u32,
+    /// Only rank launches younger than this (seconds)
+    pub rank_max_age_secs: i64,
+    /// Worst rank (1 = best) still advised
+    pub max_rank: u8,
+}
+
+impl Default for OpportunityConfig {
+    fn default() -> Self {
+        Self {
+            momentum_min_vol_5s_sol: 2.0,
+            momentum_min_buyers_2s: 2,
+            rank_max_age_secs: 300,
+            max_rank: 30,
+        }
+    }
+}
+
+/// Shadow advisory: a second opportunity threshold set evaluated next to the
+/// live one, logging shadow-only decisions instead of sending them
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ShadowAdvisoryConfig {
+    pub enabled: bool,
+    /// JSON-lines log of shadow-only decisions (Brain `DecisionLogEntry` shape)
+    pub log_path: String,
+    /// Shadow Path A/B thresholds
+    pub opportunity: OpportunityConfig,
+    /// Shadow Path D criteria and scoring
+    pub late_opportunity: LateOpportunityConfig,
+}
+
+impl Default for ShadowAdvisoryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            log_path: "./data/shadow_decisions.jsonl".to_string(),
+            opportunity: OpportunityConfig::default(),
+            late_opportunity: LateOpportunityConfig::default(),
+        }
+    }
+}
+
+/// Hotlist 7-signal scorer cadence and HotlistOpportunity broadcast limits
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct HotlistConfig {
+    /// How often to run scoring (seconds)
+    pub scoring_interval_sec: u64,
+    /// Minimum age before scoring (seconds, to accumulate data)
+    pub min_age_sec: i64,
+    /// Maximum age to score (seconds, focus on fresh launches)
+    pub max_age_sec: i64,
+    /// Minimum score (0-15) to broadcast to Brain
+    pub min_broadcast_score: f64,
+    /// Top-N qualifying tokens broadcast per scoring cycle
+    pub max_broadcast_per_cycle: usize,
+}
+
+impl Default for HotlistConfig {
+    fn default() -> Self {
+        Self {
+            scoring_interval_sec: 5,
+            min_age_sec: 10,
+            max_age_sec: 300,
+            min_broadcast_score: 6.0,
+            max_broadcast_per_cycle: 3,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_effective_intervals_adds_required() {
+        let mut windows = WindowsConfig { intervals: vec![300, 10, 60, 30], strict_intervals: false };
+        assert_eq!(windows.missing_required(), vec![2, 5]);
+        assert_eq!(windows.validate().unwrap(), vec![2, 5, 10, 30, 60, 300]);
+
+        windows.strict_intervals = true;
+        assert!(windows.validate().is_err());
+
+        windows.intervals = vec![2, 5, 60];
+        assert_eq!(windows.validate().unwrap(), vec![2, 5, 60]);
+    }
+
+    #[test]
+    fn test_validate_reports_every_problem() {
+        let mut config: Config = toml::from_str(include_str!("../config.example.toml")).unwrap();
+        assert!(config.validate().is_ok());
+
+        config.programs.pump_program = "not-a-pubkey".to_string();
+        config.windows.intervals = vec![60, 5];
+        config.momentum.spike_multiplier = 0.0;
+
+        let err = config.validate().unwrap_err().to_string();
+        assert!(err.contains("3 problems"), "{}", err);
+        assert!(err.contains("programs.pump_program"));
+        assert!(err.contains("windows.intervals"));
+        assert!(err.contains("momentum.spike_multiplier"));
+    }
+
+    #[test]
+    fn test_mempool_price_addr() {
+        let mut config: Config = toml::from_str(include_str!("../config.example.toml")).unwrap();
+        assert_eq!(config.price_oracle.mempool_price_addr, "127.0.0.1:45136");
+
+        config.price_oracle.mempool_price_addr = String::new();
+        assert!(config.validate().is_ok());
+
+        config.price_oracle.mempool_price_addr = "localhost".to_string();
+        let err = config.validate().unwrap_err().to_string();
+        assert!(err.contains("price_oracle.mempool_price_addr"), "{}", err);
+    }
+
+    #[test]
+    fn test_min_initial_liquidity_filter() {
+        let mut advice_bus: AdviceBusConfig = toml::from_str(
+            r#"
+            enabled = true
+            host = "127.0.0.1"
+            port = 45100
+            sustained_volume_threshold_secs = 60
+            sustained_volume_min_sol = 10.0
+            confidence = 80
+            "#,
+        )
+        .unwrap();
+
+        // Disabled by default: everything is advisable
+        assert_eq!(advice_bus.min_initial_liquidity_sol, 0.0);
+        assert_eq!(advice_bus.max_datagram_bytes, 1232);
+        assert_eq!(advice_bus.unix_socket_path, None);
+        assert!(advice_bus.admits_initial_liquidity(None));
+
+        advice_bus.min_initial_liquidity_sol = 30.0;
+        assert!(advice_bus.admits_initial_liquidity(Some(30.0)));
+        assert!(!advice_bus.admits_initial_liquidity(Some(12.5)));
+        assert!(!advice_bus.admits_initial_liquidity(None));
+    }
+
+    #[test]
+    fn test_late_opportunity_defaults() {
+        let late = LateOpportunityConfig::default();
+
+        assert!(late.qualifies(1800, 10.0, 10));
+        assert!(!late.qualifies(1200, 50.0, 50)); // too young (exclusive)
+        assert!(!late.qualifies(7200, 50.0, 50)); // too old (exclusive)
+        assert!(!late.qualifies(1800, 9.9, 50));
+        assert!(!late.qualifies(1800, 50.0, 9));
+
+        assert_eq!(late.score(3600, 35.0, 40), 100);
+        assert_eq!(late.score(1800, 17.5, 20), 50);
+    }
 }
@@ -1,17 +1,25 @@
 use anyhow::Result;
 use std::collections::{HashMap, HashSet};
-use tracing::debug;
+use tracing::{debug, info};
 
+use crate::config::SybilConfig;
 use crate::db::Database;
 use crate::types::{Trade, TradeSide, Window};
 
 pub struct WindowAggregator {
     intervals: Vec<u64>,
+    sybil: SybilConfig,
 }
 
 impl WindowAggregator {
     pub fn new(intervals: Vec<u64>) -> Self {
-        Self { intervals }
+        Self { intervals, sybil: SybilConfig::default() }
+    }
+
+    /// Down-weight fresh wallets in `uniq_buyers_filtered` (default: disabled, equal to raw)
+    pub fn with_sybil(mut self, sybil: SybilConfig) -> Self {
+        self.sybil = sybil;
+        self
     }
 
     pub fn update_windows(
@@ -34,7 +42,7 @@ impl WindowAggregator {
                 continue;
             }
 
-            let window = self.compute_window_stats(
+            let mut window = self.compute_window_stats(
                 mint,
                 window_sec,
                 window_start,
@@ -42,6 +50,7 @@ impl WindowAggregator {
                 current_slot,
                 &trades,
             );
+            window.uniq_buyers_filtered = self.filtered_buyers(db, mint, window_start, &trades)?;
 
             db.upsert_window(&window)?;
             windows_updated += 1;
@@ -59,6 +68,83 @@ impl WindowAggregator {
         Ok(())
     }
 
+    /// Recompute every window interval for `mint` over `[from_time, to_time)` from raw trades.
+    ///
+    /// Used to fill `windows` gaps left by collector downtime. Idempotent: rows are
+    /// written with `INSERT OR REPLACE` on the (mint, window_sec, start_time) PK.
+    /// Returns the number of windows written.
+    pub fn backfill(
+        &self,
+        db: &mut Database,
+        mint: &str,
+        from_time: i64,
+        to_time: i64,
+    ) -> Result<usize> {
+        if to_time <= from_time {
+            anyhow::bail!("Invalid backfill range: from {} must be before to {}", from_time, to_time);
+        }
+
+        db.begin_transaction()?;
+        let result = self.backfill_windows(db, mint, from_time, to_time);
+        match result {
+            Ok(_) => db.commit_transaction()?,
+            Err(_) => db.rollback_transaction()?,
+        }
+        let windows_written = result?;
+
+        info!(
+            "📊 Backfilled {} windows for {} over [{}, {}) (intervals {:?})",
+            windows_written, mint, from_time, to_time, self.intervals
+        );
+        Ok(windows_written)
+    }
+
+    fn backfill_windows(&self, db: &mut Database, mint: &str, from_time: i64, to_time: i64) -> Result<usize> {
+        let mut windows_written = 0;
+
+        for &window_sec in &self.intervals {
+            let sec = window_sec as i64;
+            // Widen to whole windows so edge windows are recomputed in full
+            let range_start = from_time.div_euclid(sec) * sec;
+            let range_end = (to_time + sec - 1).div_euclid(sec) * sec;
+
+            let trades = db.get_trades_for_window(mint, range_start, range_end)?;
+
+            // Trades are ordered by block_time, so each window is a contiguous run
+            for bucket in trades.chunk_by(|a, b| a.block_time.div_euclid(sec) == b.block_time.div_euclid(sec)) {
+                let window_start = bucket[0].block_time.div_euclid(sec) * sec;
+                let mut window = self.compute_window_stats(
+                    mint,
+                    window_sec,
+                    window_start,
+                    window_start + sec,
+                    bucket[0].slot,
+                    bucket,
+                );
+                window.uniq_buyers_filtered = self.filtered_buyers(db, mint, window_start, bucket)?;
+                db.upsert_window(&window)?;
+                windows_written += 1;
+            }
+        }
+
+        Ok(windows_written)
+    }
+
+    /// Unique buyers with wallets that hadn't traded another mint before the
+    /// window down-weighted (see `SybilConfig`)
+    fn filtered_buyers(&self, db: &Database, mint: &str, window_start: i64, trades: &[Trade]) -> Result<u64> {
+        let buyers: HashSet<&str> = trades.iter()
+            .filter(|t| t.side == TradeSide::Buy)
+            .map(|t| t.trader.as_str())
+            .collect();
+        if !self.sybil.enabled {
+            return Ok(buyers.len() as u64);
+        }
+
+        let established = db.traders_with_history(mint, buyers.iter().copied(), window_start)?.len();
+        Ok(self.sybil.filtered_buyers(established, buyers.len() - established))
+    }
+
     fn compute_window_stats(
         &self,
         mint: &str,
@@ -72,6 +158,7 @@ impl WindowAggregator {
         let mut num_sells = 0u64;
         let mut unique_buyers = HashSet::new();
         let mut vol_sol = 0.0;
+        let mut net_sol_flow = 0.0;
         let mut vol_tokens = 0.0;
         let mut high = 0.0;
         let mut low = f64::MAX;
@@ -80,17 +167,22 @@ impl WindowAggregator {
         let mut total_sol_weighted = 0.0;
         let mut prices: Vec<f64> = Vec::new();
         
-        // For concentration metrics
-        let mut buyer_volumes: HashMap<String, f64> = HashMap::new();
+        // For concentration metrics: net tokens accumulated per trader within the window
+        let mut net_tokens: HashMap<String, f64> = HashMap::new();
 
         for (i, trade) in trades.iter().enumerate() {
             match trade.side {
                 TradeSide::Buy => {
                     num_buys += 1;
+                    net_sol_flow += trade.amount_sol;
                     unique_buyers.insert(trade.trader.clone());
-                    *buyer_volumes.entry(trade.trader.clone()).or_insert(0.0) += trade.amount_sol;
+                    *net_tokens.entry(trade.trader.clone()).or_insert(0.0) += trade.amount_tokens;
+                }
+                TradeSide::Sell => {
+                    num_sells += 1;
+                    net_sol_flow -= trade.amount_sol;
+                    *net_tokens.entry(trade.trader.clone()).or_insert(0.0) -= trade.amount_tokens;
                 }
-                TradeSide::Sell => num_sells += 1,
             }
 
             vol_sol += trade.amount_sol;
@@ -103,7 +195,7 @@ impl WindowAggregator {
                 low = trade.price;
             }
             
-            // First trade = open, last trade = close
+            // First trade = open, last trade = close (trades are in block_time, slot order)
             if i == 0 {
                 open = trade.price;
             }
@@ -125,7 +217,7 @@ impl WindowAggregator {
             low = 0.0;
         }
         
-        // Calculate price volatility (standard deviation)
+        // Calculate price volatility (population stddev of trade prices, unweighted)
         let price_volatility = if prices.len() > 1 {
             let mean = prices.iter().sum::<f64>() / prices.len() as f64;
             let variance = prices.iter()
@@ -136,34 +228,8 @@ impl WindowAggregator {
             0.0
         };
 
-        // Compute top buyer concentration with normalization for <3 buyers
-        let (top1_share, top3_share, top5_share) = if !buyer_volumes.is_empty() {
-            let mut volumes: Vec<f64> = buyer_volumes.values().copied().collect();
-            volumes.sort_by(|a, b| b.partial_cmp(a).unwrap());
-            
-            let total_buy_vol: f64 = volumes.iter().sum();
-            let unique_buyer_count = volumes.len();
-            
-            // Normalize based on number of unique buyers
-            let top1 = volumes.get(0).copied().unwrap_or(0.0) / total_buy_vol.max(1e-9);
-            let top3 = if unique_buyer_count >= 3 {
-                volumes.iter().take(3).sum::<f64>() / total_buy_vol.max(1e-9)
-            } else {
-                // For <3 buyers, normalize to actual count
-                volumes.iter().sum::<f64>() / total_buy_vol.max(1e-9)
-            };
-            let top5 = if unique_buyer_count >= 5 {
-                volumes.iter().take(5).sum::<f64>() / total_buy_vol.max(1e-9)
-            } else {
-                // For <5 buyers, normalize to actual count
-                volumes.iter().sum::<f64>() / total_buy_vol.max(1e-9)
-            };
-            
-            (top1, top3, top5)
-        } else {
-            (0.0, 0.0, 0.0)
-        };
-        
+        let (top1_share, top3_share, top5_share) = buyer_concentration(&net_tokens);
+
         debug!(
             "Window stats: buyers={}, volatility={:.10}, open={:.10}, close={:.10}",
             unique_buyers.len(), price_volatility, open, close
@@ -178,8 +244,10 @@ impl WindowAggregator {
             num_buys,
             num_sells,
             uniq_buyers: unique_buyers.len() as u64,
+            uniq_buyers_filtered: unique_buyers.len() as u64,
             vol_tokens,
             vol_sol,
+            net_sol_flow,
             high,
             low,
             close,
@@ -192,3 +260,231 @@ impl WindowAggregator {
         }
     }
 }
+
+/// Top-1/3/5 shares of net buy volume from per-trader net token accumulation.
+///
+/// Only net accumulators count: a wallet that bought and sold back out (or is a net
+/// seller) holds nothing from this window. One wallet taking most of the net flow is
+/// a rug signal.
+fn buyer_concentration(net_tokens: &HashMap<String, f64>) -> (f64, f64, f64) {
+    let mut accumulated: Vec<f64> = net_tokens.values().copied().filter(|&n| n > 0.0).collect();
+    let total: f64 = accumulated.iter().sum();
+    if total <= 0.0 {
+        return (0.0, 0.0, 0.0);
+    }
+
+    accumulated.sort_by(|a, b| b.partial_cmp(a).unwrap());
+    let share = |n: usize| accumulated.iter().take(n).sum::<f64>() / total;
+    (share(1), share(3), share(5))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Token;
+    use tempfile::tempdir;
+
+    fn trade(sig: &str, block_time: i64, side: TradeSide, amount_sol: f64) -> Trade {
+        Trade {
+            sig: sig.to_string(),
+            slot: block_time as u64 * 2,
+            block_time,
+            mint: "BackfillMint".to_string(),
+            side,
+            trader: format!("trader-{}", sig),
+            amount_tokens: amount_sol * 1_000.0,
+            amount_sol,
+            price: 0.001,
+            is_amm: false,
+        }
+    }
+
+    fn test_token() -> Token {
+        Token {
+            mint: "BackfillMint".to_string(),
+            creator_wallet: "creator".to_string(),
+            bonding_curve_addr: None,
+            name: None,
+            symbol: None,
+            uri: None,
+            decimals: 6,
+            launch_tx_sig: "launch".to_string(),
+            launch_slot: 1_000,
+            launch_block_time: 900,
+            initial_price: None,
+            initial_liquidity_sol: None,
+            initial_supply: None,
+            market_cap_init: None,
+            mint_authority: None,
+            freeze_authority: None,
+            metadata_update_auth: None,
+            migrated_to_raydium: false,
+            migration_slot: None,
+            migration_block_time: None,
+            raydium_pool: None,
+            observed_at: 900,
+        }
+    }
+
+    fn window_count(db: &Database) -> i64 {
+        db.get_connection_for_writer().unwrap()
+            .query_row("SELECT COUNT(*) FROM windows", [], |row| row.get(0))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_backfill_recomputes_gap_idempotently() {
+        let dir = tempdir().unwrap();
+        let mut db = Database::new(dir.path().join("test.db"), false).unwrap();
+        db.insert_token(&test_token()).unwrap();
+        for t in [
+            trade("a", 1_000, TradeSide::Buy, 1.0),
+            trade("b", 1_010, TradeSide::Sell, 0.5),
+            trade("c", 1_065, TradeSide::Buy, 2.0),
+            trade("d", 5_000, TradeSide::Buy, 9.0), // outside range
+        ] {
+            db.insert_trade(&t).unwrap();
+        }
+        db.flush_trade_buffer().unwrap();
+
+        let aggregator = WindowAggregator::new(vec![10, 60]);
+        // 10s: [1000), [1010), [1060) | 60s: [960), [1020)
+        assert_eq!(aggregator.backfill(&mut db, "BackfillMint", 1_000, 1_070).unwrap(), 5);
+        assert_eq!(window_count(&db), 5);
+
+        // Re-running replaces rows instead of duplicating them
+        assert_eq!(aggregator.backfill(&mut db, "BackfillMint", 1_000, 1_070).unwrap(), 5);
+        assert_eq!(window_count(&db), 5);
+
+        let (num_buys, num_sells, vol_sol): (i64, i64, f64) = db.get_connection_for_writer().unwrap()
+            .query_row(
+                "SELECT num_buys, num_sells, vol_sol FROM windows WHERE window_sec = 60 AND start_time = 960",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .unwrap();
+        assert_eq!((num_buys, num_sells, vol_sol), (1, 1, 1.5));
+
+        assert!(aggregator.backfill(&mut db, "BackfillMint", 1_070, 1_000).is_err());
+    }
+
+    fn priced(sig: &str, block_time: i64, side: TradeSide, amount_sol: f64, price: f64) -> Trade {
+        Trade { price, ..trade(sig, block_time, side, amount_sol) }
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() < 1e-12, "expected {}, got {}", expected, actual);
+    }
+
+    #[test]
+    fn test_window_price_stats() {
+        let trades = vec![
+            priced("a", 1_000, TradeSide::Buy, 1.0, 2.0),
+            priced("b", 1_001, TradeSide::Buy, 3.0, 4.0),
+            priced("c", 1_002, TradeSide::Sell, 2.0, 1.0),
+            priced("d", 1_003, TradeSide::Buy, 2.0, 3.0),
+        ];
+        let window = WindowAggregator::new(vec![10])
+            .compute_window_stats("BackfillMint", 10, 1_000, 1_010, 2_000, &trades);
+
+        assert_close(window.open, 2.0);
+        assert_close(window.high, 4.0);
+        assert_close(window.low, 1.0);
+        assert_close(window.close, 3.0);
+        assert_close(window.vol_sol, 8.0);
+        // Buys 1 + 3 + 2 SOL, sell 2 SOL
+        assert_close(window.net_sol_flow, 4.0);
+        // VWAP weighted by SOL volume: (1*2 + 3*4 + 2*1 + 2*3) / 8
+        assert_close(window.vwap, 22.0 / 8.0);
+        // Unweighted population stddev of [2, 4, 1, 3]: mean 2.5, variance 1.25
+        assert_close(window.price_volatility, 1.25f64.sqrt());
+
+        // Single trade: no volatility, OHLC collapse to one price
+        let window = WindowAggregator::new(vec![10])
+            .compute_window_stats("BackfillMint", 10, 1_000, 1_010, 2_000, &trades[..1]);
+        assert_close(window.price_volatility, 0.0);
+        assert_close(window.vwap, 2.0);
+        assert_eq!((window.open, window.high, window.low, window.close), (2.0, 2.0, 2.0, 2.0));
+    }
+
+    #[test]
+    fn test_close_is_latest_trade_within_same_second() {
+        let dir = tempdir().unwrap();
+        let mut db = Database::new(dir.path().join("test.db"), false).unwrap();
+        db.insert_token(&test_token()).unwrap();
+
+        // Same block_time, inserted out of slot order
+        let later = Trade { slot: 2_005, ..priced("z-later", 1_000, TradeSide::Buy, 1.0, 5.0) };
+        let earlier = Trade { slot: 2_001, ..priced("a-earlier", 1_000, TradeSide::Buy, 1.0, 3.0) };
+        db.insert_trade(&later).unwrap();
+        db.insert_trade(&earlier).unwrap();
+        db.flush_trade_buffer().unwrap();
+
+        WindowAggregator::new(vec![10]).update_windows(&mut db, "BackfillMint", 1_000, 2_005).unwrap();
+
+        let (open, close): (f64, f64) = db.get_connection_for_writer().unwrap()
+            .query_row("SELECT open, close FROM windows WHERE window_sec = 10", [], |row| Ok((row.get(0)?, row.get(1)?)))
+            .unwrap();
+        assert_eq!((open, close), (3.0, 5.0));
+    }
+
+    #[test]
+    fn test_sybil_filter_down_weights_fresh_buyers() {
+        let dir = tempdir().unwrap();
+        let mut db = Database::new(dir.path().join("test.db"), false).unwrap();
+        db.insert_token(&test_token()).unwrap();
+        db.insert_token(&Token { mint: "OtherMint".to_string(), ..test_token() }).unwrap();
+
+        // "veteran" traded another mint before the window; the four others are fresh
+        db.insert_trade(&Trade { mint: "OtherMint".to_string(), trader: "veteran".to_string(), ..trade("old", 900, TradeSide::Buy, 1.0) }).unwrap();
+        db.insert_trade(&Trade { trader: "veteran".to_string(), ..trade("v", 1_000, TradeSide::Buy, 1.0) }).unwrap();
+        for i in 0..4 {
+            db.insert_trade(&trade(&format!("f{}", i), 1_001, TradeSide::Buy, 0.1)).unwrap();
+        }
+        db.flush_trade_buffer().unwrap();
+
+        let uniq_buyers = |db: &Database| -> (i64, i64) {
+            db.get_connection_for_writer().unwrap()
+                .query_row("SELECT uniq_buyers, uniq_buyers_filtered FROM windows WHERE mint = 'BackfillMint'", [], |row| Ok((row.get(0)?, row.get(1)?)))
+                .unwrap()
+        };
+
+        WindowAggregator::new(vec![10]).update_windows(&mut db, "BackfillMint", 1_000, 2_000).unwrap();
+        assert_eq!(uniq_buyers(&db), (5, 5));
+
+        let sybil = SybilConfig { enabled: true, ..SybilConfig::default() };
+        WindowAggregator::new(vec![10]).with_sybil(sybil).update_windows(&mut db, "BackfillMint", 1_000, 2_000).unwrap();
+        // 1 established + 4 fresh × 0.25
+        assert_eq!(uniq_buyers(&db), (5, 2));
+    }
+
+    #[test]
+    fn test_buyer_concentration_uses_net_accumulation() {
+        let mut trades = vec![
+            Trade { trader: "whale".to_string(), amount_tokens: 600.0, ..trade("a", 1_000, TradeSide::Buy, 6.0) },
+            Trade { trader: "flipper".to_string(), amount_tokens: 500.0, ..trade("b", 1_001, TradeSide::Buy, 5.0) },
+            Trade { trader: "flipper".to_string(), amount_tokens: 500.0, ..trade("c", 1_002, TradeSide::Sell, 5.0) },
+            Trade { trader: "seller".to_string(), amount_tokens: 50.0, ..trade("d", 1_003, TradeSide::Sell, 0.5) },
+        ];
+        for (i, amount) in [200.0, 100.0, 50.0, 50.0].into_iter().enumerate() {
+            trades.push(Trade {
+                trader: format!("small-{}", i),
+                amount_tokens: amount,
+                ..trade(&format!("s{}", i), 1_004, TradeSide::Buy, amount / 100.0)
+            });
+        }
+
+        let window = WindowAggregator::new(vec![10])
+            .compute_window_stats("BackfillMint", 10, 1_000, 1_010, 2_000, &trades);
+
+        // Net accumulators: whale 600, small 200/100/50/50 → total 1000 (flipper nets 0, seller negative)
+        assert_close(window.top1_share, 0.6);
+        assert_close(window.top3_share, 0.9);
+        assert_close(window.top5_share, 1.0);
+
+        // Nobody accumulating → no concentration
+        let window = WindowAggregator::new(vec![10])
+            .compute_window_stats("BackfillMint", 10, 1_000, 1_010, 2_000, &trades[1..4]);
+        assert_eq!((window.top1_share, window.top3_share, window.top5_share), (0.0, 0.0, 0.0));
+    }
+}
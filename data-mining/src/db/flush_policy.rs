@@ -0,0 +1,142 @@
+//! When the `Database` trade buffer is flushed
+//!
+//! Fixed mode flushes at `batch_size` trades or `time_limit_ms` since the last
+//! flush. Adaptive mode moves both limits after every flush, within the
+//! configured min/max:
+//! - Burst (the batch filled in under half the time limit): double the batch
+//!   size so a viral token doesn't turn into one transaction per millisecond,
+//!   and restore the full time limit
+//! - Quiet (time-triggered flush at most a quarter full): halve the time limit
+//!   and the batch size, flushes are cheap and trades reach the Brain sooner
+//! - Steady (time-triggered flush at least half full): double the time limit
+
+use std::time::Duration;
+
+use crate::config::TradeBufferConfig;
+
+/// Weight of the newest batch in the running average
+const AVG_ALPHA: f64 = 0.2;
+
+#[derive(Debug, Clone)]
+pub struct FlushPolicy {
+    config: TradeBufferConfig,
+    batch_size: usize,
+    time_limit_ms: u64,
+    avg_batch_size: f64,
+}
+
+impl FlushPolicy {
+    pub fn new(config: TradeBufferConfig) -> Self {
+        Self {
+            batch_size: config.batch_size.max(1),
+            time_limit_ms: config.time_limit_ms,
+            avg_batch_size: 0.0,
+            config,
+        }
+    }
+
+    /// Current size limit (trades)
+    pub fn batch_size(&self) -> usize {
+        self.batch_size
+    }
+
+    /// Current time limit
+    pub fn time_limit(&self) -> Duration {
+        Duration::from_millis(self.time_limit_ms)
+    }
+
+    /// Running average of flushed batch sizes
+    pub fn avg_batch_size(&self) -> f64 {
+        self.avg_batch_size
+    }
+
+    pub fn should_flush(&self, buffered: usize, since_last_flush: Duration) -> bool {
+        buffered >= self.batch_size || since_last_flush >= self.time_limit()
+    }
+
+    /// Record a flush of `count` trades that accumulated over `since_last_flush`
+    pub fn on_flush(&mut self, count: usize, since_last_flush: Duration) {
+        self.avg_batch_size = if self.avg_batch_size == 0.0 {
+            count as f64
+        } else {
+            AVG_ALPHA * count as f64 + (1.0 - AVG_ALPHA) * self.avg_batch_size
+        };
+
+        if !self.config.adaptive {
+            return;
+        }
+
+        let cfg = &self.config;
+        if count >= self.batch_size {
+            if since_last_flush < self.time_limit() / 2 {
+                self.batch_size = (self.batch_size * 2).min(cfg.max_batch_size);
+                self.time_limit_ms = cfg.max_time_limit_ms;
+            }
+        } else if count * 4 <= self.batch_size {
+            self.time_limit_ms = (self.time_limit_ms / 2).max(cfg.min_time_limit_ms);
+            self.batch_size = (self.batch_size / 2).max(cfg.min_batch_size);
+        } else if count * 2 >= self.batch_size {
+            self.time_limit_ms = (self.time_limit_ms * 2).min(cfg.max_time_limit_ms);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn adaptive() -> TradeBufferConfig {
+        TradeBufferConfig { adaptive: true, ..TradeBufferConfig::default() }
+    }
+
+    #[test]
+    fn test_fixed_policy_never_moves() {
+        let mut policy = FlushPolicy::new(TradeBufferConfig::default());
+        policy.on_flush(50, Duration::from_millis(1));
+        policy.on_flush(1, Duration::from_millis(100));
+
+        assert_eq!(policy.batch_size(), 50);
+        assert_eq!(policy.time_limit(), Duration::from_millis(100));
+        assert!(policy.should_flush(50, Duration::ZERO));
+        assert!(policy.should_flush(1, Duration::from_millis(100)));
+        assert!(!policy.should_flush(49, Duration::from_millis(99)));
+    }
+
+    #[test]
+    fn test_adaptive_policy_bounds() {
+        let mut policy = FlushPolicy::new(adaptive());
+
+        // Burst: batches fill in 1ms, size doubles up to the max
+        for _ in 0..10 {
+            let size = policy.batch_size();
+            policy.on_flush(size, Duration::from_millis(1));
+        }
+        assert_eq!(policy.batch_size(), 1_000);
+        assert_eq!(policy.time_limit(), Duration::from_millis(100));
+
+        // Quiet: a few trades per time limit, both limits shrink to the min
+        for _ in 0..10 {
+            let limit = policy.time_limit();
+            policy.on_flush(2, limit);
+        }
+        assert_eq!(policy.batch_size(), 50);
+        assert_eq!(policy.time_limit(), Duration::from_millis(20));
+
+        // Steady: time-triggered but more than half full, time limit recovers
+        for _ in 0..5 {
+            let limit = policy.time_limit();
+            policy.on_flush(30, limit);
+        }
+        assert_eq!(policy.batch_size(), 50);
+        assert_eq!(policy.time_limit(), Duration::from_millis(100));
+    }
+
+    #[test]
+    fn test_avg_batch_size() {
+        let mut policy = FlushPolicy::new(TradeBufferConfig::default());
+        policy.on_flush(10, Duration::from_millis(100));
+        assert_eq!(policy.avg_batch_size(), 10.0);
+        policy.on_flush(60, Duration::from_millis(5));
+        assert!((policy.avg_batch_size() - 20.0).abs() < 1e-9);
+    }
+}
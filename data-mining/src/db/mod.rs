@@ -1,27 +1,33 @@
 pub mod checkpoint;
 pub mod aggregator;
+pub mod flush_policy;
 pub mod writer;
 
 use anyhow::{Context, Result};
 use rusqlite::{params, Connection, OptionalExtension};
-use std::collections::HashMap;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use tracing::{debug, info};
 
+use crate::config::TradeBufferConfig;
 use crate::types::{Token, Trade, TradeSide, Window};
 
 pub use checkpoint::Checkpoint;
 pub use aggregator::WindowAggregator;
-pub use writer::{DbWriter, DbWriteCommand, spawn_db_writer};
+pub use flush_policy::FlushPolicy;
+pub use writer::{DbWriter, DbWriteCommand, DbWriterHandle, WritePriority, spawn_db_writer};
+
+/// How long a connection waits on another connection's lock before SQLITE_BUSY (ms)
+pub const BUSY_TIMEOUT_MS: u64 = 2_000;
 
 pub struct Database {
     conn: Connection,
     db_path: String,
     trade_buffer: Vec<Trade>,
     buffer_last_flush: Instant,
-    buffer_size_limit: usize,
-    buffer_time_limit_ms: u128,
+    flush_policy: FlushPolicy,
 }
 
 impl Database {
@@ -34,6 +40,13 @@ impl Database {
 
         let conn = Connection::open(&path)
             .context("Failed to open database connection")?;
+        conn.busy_timeout(Duration::from_millis(BUSY_TIMEOUT_MS))
+            .context("Failed to set busy timeout")?;
+
+        // Lets retention cleanups hand freed pages back to the OS; only takes
+        // effect on a new file (existing ones keep their mode until a VACUUM)
+        conn.execute_batch("PRAGMA auto_vacuum=INCREMENTAL;")
+            .context("Failed to set auto_vacuum")?;
 
         if wal_mode {
             conn.execute_batch(
@@ -47,20 +60,27 @@ impl Database {
             db_path: path.as_ref().to_string_lossy().to_string(),
             trade_buffer: Vec::with_capacity(100),
             buffer_last_flush: Instant::now(),
-            buffer_size_limit: 50,     // Flush every 50 trades - balanced performance
-            buffer_time_limit_ms: 100,  // Flush every 100ms - safe and efficient
+            flush_policy: FlushPolicy::new(TradeBufferConfig::default()),
         };
         db.initialize_schema()?;
         
         info!("✅ Database initialized successfully");
         Ok(db)
     }
+
+    /// Trade buffer flush limits (defaults: 50 trades / 100ms, fixed)
+    pub fn with_trade_buffer(mut self, config: TradeBufferConfig) -> Self {
+        self.flush_policy = FlushPolicy::new(config);
+        self
+    }
     
     /// Create a new connection for the async DB writer
     /// This allows writes to happen in parallel without blocking reads
     pub fn get_connection_for_writer(&self) -> Result<Connection> {
         let conn = Connection::open(&self.db_path)
             .context("Failed to open writer connection")?;
+        conn.busy_timeout(Duration::from_millis(BUSY_TIMEOUT_MS))
+            .context("Failed to set busy timeout")?;
         
         // Enable WAL mode for writer connection
         conn.execute_batch(
@@ -129,8 +149,10 @@ impl Database {
                 num_buys INTEGER DEFAULT 0,
                 num_sells INTEGER DEFAULT 0,
                 uniq_buyers INTEGER DEFAULT 0,
+                uniq_buyers_filtered INTEGER DEFAULT 0,
                 vol_tokens REAL DEFAULT 0.0,
                 vol_sol REAL DEFAULT 0.0,
+                net_sol_flow REAL DEFAULT 0.0,
                 high REAL DEFAULT 0.0,
                 low REAL DEFAULT 0.0,
                 close REAL DEFAULT 0.0,
@@ -199,12 +221,15 @@ impl Database {
             CREATE INDEX IF NOT EXISTS idx_trades_mint_time ON trades(mint, block_time, slot);
             CREATE INDEX IF NOT EXISTS idx_trades_trader_time ON trades(trader, block_time, slot);
             CREATE INDEX IF NOT EXISTS idx_trades_slot ON trades(slot);
+            CREATE INDEX IF NOT EXISTS idx_trades_block_time ON trades(block_time);
             CREATE INDEX IF NOT EXISTS idx_tokens_launch_time ON tokens(launch_block_time);
             CREATE INDEX IF NOT EXISTS idx_windows_mint_start ON windows(mint, start_time);
+            CREATE INDEX IF NOT EXISTS idx_windows_processed ON windows(processed_at);
             CREATE INDEX IF NOT EXISTS idx_pyth_prices_timestamp ON pyth_prices(timestamp);
             CREATE INDEX IF NOT EXISTS idx_wallet_stats_profit ON wallet_stats(profit_score DESC);
             CREATE INDEX IF NOT EXISTS idx_wallet_stats_tracked ON wallet_stats(is_tracked);
             CREATE INDEX IF NOT EXISTS idx_positions_wallet_mint ON positions(wallet, mint, is_closed);
+            CREATE INDEX IF NOT EXISTS idx_positions_wallet_hold ON positions(wallet, is_closed, bought_at, sold_at);
             
             -- Hotlist table for real-time explosive token scoring
             CREATE TABLE IF NOT EXISTS hotlist (
@@ -230,10 +255,28 @@ impl Database {
             "#
         ).context("Failed to initialize database schema")?;
 
+        // Columns added after tables were first created (CREATE IF NOT EXISTS skips them)
+        self.add_column_if_missing("windows", "uniq_buyers_filtered", "INTEGER DEFAULT 0")?;
+        self.add_column_if_missing("windows", "net_sol_flow", "REAL DEFAULT 0.0")?;
+
         info!("📊 Database schema initialized");
         Ok(())
     }
 
+    fn add_column_if_missing(&self, table: &str, column: &str, decl: &str) -> Result<()> {
+        let exists: bool = self.conn.query_row(
+            &format!("SELECT EXISTS(SELECT 1 FROM pragma_table_info('{}') WHERE name = ?1)", table),
+            params![column],
+            |row| row.get(0),
+        )?;
+        if !exists {
+            self.conn.execute_batch(&format!("ALTER TABLE {} ADD COLUMN {} {};", table, column, decl))
+                .with_context(|| format!("Failed to add {}.{}", table, column))?;
+            info!("📊 Added column {}.{}", table, column);
+        }
+        Ok(())
+    }
+
     pub fn insert_token(&mut self, token: &Token) -> Result<()> {
         self.conn.execute(
             r#"
@@ -323,9 +366,9 @@ impl Database {
     }
 
     /// Insert trade with buffering for better performance
-    /// Trades are buffered and flushed in batch when:
-    /// - Buffer reaches 50 trades, OR
-    /// - 100ms has passed since last flush
+    /// Trades are buffered and flushed in batch when (see `FlushPolicy`):
+    /// - Buffer reaches the batch size (default 50 trades), OR
+    /// - The time limit (default 100ms) has passed since last flush
     /// 
     /// This provides optimal balance:
     /// - Responsive enough for Brain cache updates (well under 10s staleness threshold)
@@ -334,10 +377,7 @@ impl Database {
     pub fn insert_trade(&mut self, trade: &Trade) -> Result<()> {
         self.trade_buffer.push(trade.clone());
         
-        let should_flush = self.trade_buffer.len() >= self.buffer_size_limit
-            || self.buffer_last_flush.elapsed().as_millis() >= self.buffer_time_limit_ms;
-        
-        if should_flush {
+        if self.flush_policy.should_flush(self.trade_buffer.len(), self.buffer_last_flush.elapsed()) {
             self.flush_trade_buffer()?;
         }
         
@@ -392,6 +432,9 @@ impl Database {
             count as f64 / elapsed.as_millis().max(1) as f64
         );
         
+        self.flush_policy.on_flush(count, self.buffer_last_flush.elapsed());
+        crate::metrics::set_trade_batch_sizes(self.flush_policy.avg_batch_size(), self.flush_policy.batch_size());
+
         self.trade_buffer.clear();
         self.buffer_last_flush = Instant::now();
         
@@ -459,6 +502,19 @@ impl Database {
         Ok(windows)
     }
     
+    /// Get (top1_share, top3_share) of net buy accumulation from the latest window of `window_sec`
+    pub fn get_window_concentration(&self, mint: &str, window_sec: u32) -> Result<Option<(f64, f64)>> {
+        let shares = self.conn.query_row(
+            "SELECT top1_share, top3_share FROM windows
+             WHERE mint = ?1 AND window_sec = ?2
+             ORDER BY start_time DESC LIMIT 1",
+            params![mint, window_sec],
+            |row| Ok((row.get::<_, f64>(0)?, row.get::<_, f64>(1)?))
+        ).optional()?;
+
+        Ok(shares)
+    }
+
     /// Get token launch time for age calculation
     pub fn get_token_launch_time(&self, mint: &str) -> Result<Option<i64>> {
         let launch_time = self.conn.query_row(
@@ -511,8 +567,8 @@ impl Database {
                 mint, window_sec, start_slot, start_time, end_time,
                 num_buys, num_sells, uniq_buyers, vol_tokens, vol_sol,
                 high, low, close, vwap, top1_share, top3_share, top5_share,
-                price_volatility, open
-            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19)
+                price_volatility, open, uniq_buyers_filtered, net_sol_flow
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21)
             "#,
             params![
                 window.mint,
@@ -534,12 +590,34 @@ impl Database {
                 window.top5_share,
                 window.price_volatility,
                 window.open,
+                window.uniq_buyers_filtered,
+                window.net_sol_flow,
             ],
         ).context("Failed to upsert window")?;
 
         Ok(())
     }
 
+    /// Of `traders`, those with a trade on another mint before `before` (sybil
+    /// dampening: the rest are fresh wallets whose first token is `mint`)
+    pub fn traders_with_history<'a>(
+        &self,
+        mint: &str,
+        traders: impl IntoIterator<Item = &'a str>,
+        before: i64,
+    ) -> Result<HashSet<String>> {
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT EXISTS(SELECT 1 FROM trades WHERE trader = ?1 AND block_time < ?2 AND mint != ?3)"
+        )?;
+        let mut established = HashSet::new();
+        for trader in traders {
+            if stmt.query_row(params![trader, before, mint], |row| row.get::<_, bool>(0))? {
+                established.insert(trader.to_string());
+            }
+        }
+        Ok(established)
+    }
+
     pub fn get_trades_for_window(
         &self,
         mint: &str,
@@ -551,7 +629,7 @@ impl Database {
             SELECT sig, slot, block_time, mint, side, trader, amount_tokens, amount_sol, price, is_amm
             FROM trades
             WHERE mint = ?1 AND block_time >= ?2 AND block_time < ?3
-            ORDER BY block_time ASC
+            ORDER BY block_time ASC, slot ASC, sig ASC
             "#
         )?;
 
@@ -682,6 +760,29 @@ impl Database {
         Ok(result)
     }
 
+    /// Delete pyth_prices rows older than the retention window
+    ///
+    /// The cutoff scan uses idx_pyth_prices_timestamp. Freed pages are reused
+    /// by later inserts, so the file stops growing at roughly one retention
+    /// window of rows; on incremental auto_vacuum files they are also released
+    /// to the OS (no full VACUUM, which would block the writer).
+    pub fn cleanup_old_pyth_prices(&mut self, retention_secs: i64) -> Result<usize> {
+        let cutoff = chrono::Utc::now().timestamp() - retention_secs;
+        let deleted = self.conn.execute(
+            "DELETE FROM pyth_prices WHERE timestamp < ?1",
+            params![cutoff],
+        )?;
+
+        if deleted > 0 {
+            let auto_vacuum: i64 = self.conn.query_row("PRAGMA auto_vacuum", [], |row| row.get(0))?;
+            if auto_vacuum == 2 {
+                self.conn.execute_batch("PRAGMA incremental_vacuum;")?;
+            }
+            debug!("🧹 Cleaned up {} old pyth_prices rows (>{}s old)", deleted, retention_secs);
+        }
+        Ok(deleted)
+    }
+
     /// Clean up old hotlist entries (older than 5 minutes)
     pub fn cleanup_old_hotlist(&mut self, age_seconds: i64) -> Result<usize> {
         let cutoff = chrono::Utc::now().timestamp() - age_seconds;
@@ -723,18 +824,19 @@ impl Database {
     pub fn get_recent_trades_for_scoring(
         &self,
         mint: &str,
+        now: i64,
         lookback_sec: i64,
     ) -> Result<Vec<(String, String, f64)>> {
-        let cutoff = chrono::Utc::now().timestamp() - lookback_sec;
+        let cutoff = now - lookback_sec;
         
         let mut stmt = self.conn.prepare(
             "SELECT trader, side, amount_sol 
              FROM trades 
-             WHERE mint = ?1 AND block_time >= ?2
+             WHERE mint = ?1 AND block_time >= ?2 AND block_time <= ?3
              ORDER BY block_time ASC"
         )?;
         
-        let trades = stmt.query_map(params![mint, cutoff], |row| {
+        let trades = stmt.query_map(params![mint, cutoff, now], |row| {
             Ok((
                 row.get::<_, String>(0)?,
                 row.get::<_, String>(1)?,
@@ -1025,44 +1127,38 @@ impl Database {
     pub fn get_wallet_stats(&self, wallet: &str) -> Result<Option<WalletStats>> {
         let stats = self.conn
             .query_row(
-                "SELECT wallet, total_trades, net_pnl_sol, win_rate, profit_score,
-                        realized_wins, realized_losses, is_tracked
-                 FROM wallet_stats WHERE wallet = ?1",
+                &format!("SELECT {} FROM wallet_stats w WHERE w.wallet = ?1", WALLET_STATS_COLUMNS),
                 params![wallet],
-                |row| {
-                    Ok(WalletStats {
-                        wallet: row.get(0)?,
-                        total_trades: row.get(1)?,
-                        net_pnl_sol: row.get(2)?,
-                        win_rate: row.get(3)?,
-                        profit_score: row.get(4)?,
-                        realized_wins: row.get(5)?,
-                        realized_losses: row.get(6)?,
-                        is_tracked: row.get::<_, i32>(7)? == 1,
-                    })
-                },
+                WalletStats::from_row,
             )
             .optional()?;
         Ok(stats)
     }
+
+    /// Wallet PnL leaderboard for reviewing discovered wallets before promoting
+    /// them to `is_tracked = 1`
+    ///
+    /// Ordered by `profit_score` (served by `idx_wallet_stats_profit`); wallets
+    /// with fewer than `min_trades` trades are skipped.
+    pub fn get_wallet_leaderboard(&self, limit: usize, min_trades: u32) -> Result<Vec<WalletStats>> {
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT {} FROM wallet_stats w
+             WHERE w.total_trades >= ?1
+             ORDER BY w.profit_score DESC, w.net_pnl_sol DESC
+             LIMIT ?2",
+            WALLET_STATS_COLUMNS
+        ))?;
+
+        let wallets = stmt
+            .query_map(params![min_trades, limit as i64], WalletStats::from_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(wallets)
+    }
     
     /// Get all tracked wallets with their aliases
     pub fn get_tracked_wallets(&self) -> Result<HashMap<String, Option<String>>> {
-        let mut tracked = HashMap::new();
-        let mut stmt = self.conn.prepare("SELECT wallet, alias FROM wallet_stats WHERE is_tracked = 1")?;
-        let rows = stmt.query_map([], |row| {
-            Ok((
-                row.get::<_, String>(0)?,
-                row.get::<_, Option<String>>(1)?,
-            ))
-        })?;
-        
-        for row in rows {
-            let (wallet, alias) = row?;
-            tracked.insert(wallet, alias);
-        }
-        
-        Ok(tracked)
+        query_tracked_wallets(&self.conn)
     }
 
     /// Update time-series windows for a specific mint
@@ -1082,14 +1178,146 @@ impl Database {
 // WALLET TRACKING DATA STRUCTURES
 // ============================================================================
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct WalletStats {
     pub wallet: String,
+    pub alias: Option<String>,
     pub total_trades: i32,
     pub net_pnl_sol: f64,
     pub win_rate: f64,
     pub profit_score: f64,
     pub realized_wins: i32,
     pub realized_losses: i32,
+    /// Average seconds between entry and exit over closed positions (None = no closed positions)
+    pub avg_hold_secs: Option<f64>,
     pub is_tracked: bool,
 }
+
+/// Column list matching `WalletStats::from_row` (`wallet_stats` aliased as `w`)
+const WALLET_STATS_COLUMNS: &str = "w.wallet, w.alias, w.total_trades, w.net_pnl_sol, w.win_rate, w.profit_score,
+     w.realized_wins, w.realized_losses,
+     (SELECT AVG(p.sold_at - p.bought_at) FROM positions p
+      WHERE p.wallet = w.wallet AND p.is_closed = 1 AND p.sold_at IS NOT NULL),
+     w.is_tracked";
+
+impl WalletStats {
+    fn from_row(row: &rusqlite::Row) -> rusqlite::Result<Self> {
+        Ok(Self {
+            wallet: row.get(0)?,
+            alias: row.get(1)?,
+            total_trades: row.get(2)?,
+            net_pnl_sol: row.get(3)?,
+            win_rate: row.get(4)?,
+            profit_score: row.get(5)?,
+            realized_wins: row.get(6)?,
+            realized_losses: row.get(7)?,
+            avg_hold_secs: row.get(8)?,
+            is_tracked: row.get::<_, i32>(9)? == 1,
+        })
+    }
+
+    /// CSV header matching `to_csv_row`
+    pub const CSV_HEADER: &'static str =
+        "wallet,alias,total_trades,net_pnl_sol,win_rate,profit_score,realized_wins,realized_losses,avg_hold_secs,is_tracked";
+
+    /// One CSV row (alias quoted; empty fields for missing values)
+    pub fn to_csv_row(&self) -> String {
+        format!(
+            "{},{},{},{:.4},{:.4},{:.2},{},{},{},{}",
+            self.wallet,
+            self.alias.as_deref().map(|a| format!("\"{}\"", a.replace('"', "\"\""))).unwrap_or_default(),
+            self.total_trades,
+            self.net_pnl_sol,
+            self.win_rate,
+            self.profit_score,
+            self.realized_wins,
+            self.realized_losses,
+            self.avg_hold_secs.map(|s| format!("{:.1}", s)).unwrap_or_default(),
+            self.is_tracked as i32,
+        )
+    }
+}
+
+/// Query tracked wallets with their aliases (shared by startup load and hot reload)
+pub fn query_tracked_wallets(conn: &Connection) -> Result<HashMap<String, Option<String>>> {
+    let mut tracked = HashMap::new();
+    let mut stmt = conn.prepare("SELECT wallet, alias FROM wallet_stats WHERE is_tracked = 1")?;
+    let rows = stmt.query_map([], |row| {
+        Ok((
+            row.get::<_, String>(0)?,
+            row.get::<_, Option<String>>(1)?,
+        ))
+    })?;
+    
+    for row in rows {
+        let (wallet, alias) = row?;
+        tracked.insert(wallet, alias);
+    }
+    
+    Ok(tracked)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_wallet_leaderboard() {
+        let dir = tempdir().unwrap();
+        let db = Database::new(dir.path().join("leaderboard.db"), false).unwrap();
+        let conn = db.get_connection_for_writer().unwrap();
+        conn.execute_batch(
+            "INSERT INTO tokens (mint, creator_wallet, launch_tx_sig, launch_slot, launch_block_time, decimals, observed_at)
+                 VALUES ('mint', 'creator', 'sig', 1, 1000, 6, 1000);
+             INSERT INTO wallet_stats (wallet, alias, first_seen, last_seen, total_trades, net_pnl_sol,
+                                       realized_wins, realized_losses, win_rate, profit_score, is_tracked)
+                 VALUES ('alpha', 'Al \"A\"', 0, 0, 12, 8.0, 3, 1, 0.75, 117.5, 0),
+                        ('beta', NULL, 0, 0, 20, 2.0, 1, 1, 0.5, 45.0, 1),
+                        ('noob', NULL, 0, 0, 2, 50.0, 1, 0, 1.0, 550.0, 0);
+             INSERT INTO positions (wallet, mint, bought_at, sold_at, is_closed)
+                 VALUES ('alpha', 'mint', 1000, 1030, 1),
+                        ('alpha', 'mint', 2000, 2090, 1),
+                        ('alpha', 'mint', 3000, NULL, 0);",
+        ).unwrap();
+
+        let board = db.get_wallet_leaderboard(10, 5).unwrap();
+        let wallets: Vec<&str> = board.iter().map(|w| w.wallet.as_str()).collect();
+        assert_eq!(wallets, vec!["alpha", "beta"]); // noob filtered by min_trades
+
+        let alpha = &board[0];
+        assert_eq!(alpha.avg_hold_secs, Some(60.0));
+        assert_eq!((alpha.realized_wins, alpha.realized_losses), (3, 1));
+        assert_eq!(board[1].avg_hold_secs, None);
+        assert!(board[1].is_tracked);
+
+        assert_eq!(
+            alpha.to_csv_row(),
+            "alpha,\"Al \"\"A\"\"\",12,8.0000,0.7500,117.50,3,1,60.0,0"
+        );
+        assert_eq!(db.get_wallet_leaderboard(1, 0).unwrap()[0].wallet, "noob");
+    }
+
+    #[test]
+    fn test_cleanup_old_pyth_prices() {
+        let dir = tempdir().unwrap();
+        let mut db = Database::new(dir.path().join("pyth.db"), true).unwrap();
+        let now = chrono::Utc::now().timestamp();
+        for age_days in [10, 8, 6, 0] {
+            db.log_pyth_price(now - age_days * 86_400, 190.0, 0.1, 0.0005, "pyth_http").unwrap();
+        }
+
+        assert_eq!(db.cleanup_old_pyth_prices(7 * 86_400).unwrap(), 2);
+        assert_eq!(db.cleanup_old_pyth_prices(7 * 86_400).unwrap(), 0);
+        let remaining: i64 = db.conn.query_row("SELECT COUNT(*) FROM pyth_prices", [], |row| row.get(0)).unwrap();
+        assert_eq!(remaining, 2);
+
+        // New files can release pruned pages; the cutoff scan is indexed
+        let auto_vacuum: i64 = db.conn.query_row("PRAGMA auto_vacuum", [], |row| row.get(0)).unwrap();
+        assert_eq!(auto_vacuum, 2);
+        let plan: String = db.conn
+            .query_row("EXPLAIN QUERY PLAN DELETE FROM pyth_prices WHERE timestamp < 0", [], |row| row.get(3))
+            .unwrap();
+        assert!(plan.contains("idx_pyth_prices_timestamp"), "plan: {}", plan);
+    }
+}
@@ -4,10 +4,10 @@
 //! Receives write commands via channel and processes them in background.
 
 use anyhow::Result;
-use rusqlite::Connection;
+use rusqlite::{Connection, ErrorCode};
 use tokio::sync::mpsc;
 use tracing::{debug, info, warn};
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use crate::types::{Token, Trade};
 
@@ -17,12 +17,80 @@ const BATCH_MAX_SIZE: usize = 500;
 /// Maximum time to hold items in batch before flushing (ms)
 const BATCH_MAX_LATENCY_MS: u64 = 50;
 
+/// Flush attempts retried after SQLITE_BUSY/LOCKED (on top of `busy_timeout`)
+const BUSY_MAX_RETRIES: u32 = 5;
+
+/// First retry backoff, doubled on each attempt (ms)
+const BUSY_BACKOFF_BASE_MS: u64 = 10;
+
 /// DB write commands sent from hot path
 #[derive(Debug, Clone)]
 pub enum DbWriteCommand {
     InsertTrade(Trade),
     InsertToken(Token),
     UpdateInitialLiquidity { mint: String, liquidity_sol: f64 },
+    /// Reorg rollback: delete trades recorded from orphaned slots
+    DeleteTradesInSlots(Vec<u64>),
+}
+
+/// Capacity of the low-priority channel before placeholder/liquidity writes are shed
+const LOW_PRIORITY_CAPACITY: usize = 50_000;
+
+/// Which tier of the writer channel a command travels on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WritePriority {
+    /// Trades and CREATE tokens - never dropped
+    High,
+    /// Placeholder tokens and liquidity updates - shed under back-pressure
+    Low,
+}
+
+impl WritePriority {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WritePriority::High => "high",
+            WritePriority::Low => "low",
+        }
+    }
+}
+
+/// Sending side of the two-tier DB writer channel
+///
+/// The high tier is unbounded so trades are never lost under load; the low
+/// tier is bounded and drops when full.
+#[derive(Clone)]
+pub struct DbWriterHandle {
+    high: mpsc::UnboundedSender<DbWriteCommand>,
+    low: mpsc::Sender<DbWriteCommand>,
+}
+
+impl DbWriterHandle {
+    /// Create a handle plus the receivers the writer loop drains
+    pub fn channel(
+        low_capacity: usize,
+    ) -> (Self, mpsc::UnboundedReceiver<DbWriteCommand>, mpsc::Receiver<DbWriteCommand>) {
+        let (high, high_rx) = mpsc::unbounded_channel();
+        let (low, low_rx) = mpsc::channel(low_capacity);
+        (Self { high, low }, high_rx, low_rx)
+    }
+
+    /// Queue a high-priority write (only fails if the writer has exited)
+    pub fn send(&self, cmd: DbWriteCommand) -> Result<(), mpsc::error::SendError<DbWriteCommand>> {
+        let result = self.high.send(cmd);
+        if result.is_err() {
+            crate::metrics::record_db_writer_dropped(WritePriority::High);
+        }
+        result
+    }
+
+    /// Queue a low-priority write, dropping it if the low tier is full
+    pub fn try_send_low(&self, cmd: DbWriteCommand) -> Result<(), mpsc::error::TrySendError<DbWriteCommand>> {
+        let result = self.low.try_send(cmd);
+        if result.is_err() {
+            crate::metrics::record_db_writer_dropped(WritePriority::Low);
+        }
+        result
+    }
 }
 
 /// Async DB Writer - runs in separate task
@@ -31,6 +99,7 @@ pub struct DbWriter {
     trade_batch: Vec<Trade>,
     token_batch: Vec<Token>,
     liquidity_updates: Vec<(String, f64)>,
+    orphaned_slots: Vec<u64>,
     batch_start: Instant,
 }
 
@@ -42,6 +111,7 @@ impl DbWriter {
             trade_batch: Vec::with_capacity(BATCH_MAX_SIZE),
             token_batch: Vec::with_capacity(100),
             liquidity_updates: Vec::with_capacity(100),
+            orphaned_slots: Vec::new(),
             batch_start: Instant::now(),
         }
     }
@@ -87,45 +157,63 @@ impl DbWriter {
         }
     }
 
-    /// Main writer loop with bounded channel (back-pressure support)
-    pub fn run_blocking_bounded(mut self, mut rx: mpsc::Receiver<DbWriteCommand>) {
-        info!("📝 DB Writer task started (blocking thread, bounded channel)");
+    /// Main writer loop over the two-tier channel
+    ///
+    /// High-priority commands are always drained first; low-priority commands
+    /// fill the remainder of each batch.
+    pub fn run_blocking_tiered(
+        mut self,
+        mut high_rx: mpsc::UnboundedReceiver<DbWriteCommand>,
+        mut low_rx: mpsc::Receiver<DbWriteCommand>,
+        rt: tokio::runtime::Handle,
+    ) {
+        info!("📝 DB Writer task started (blocking thread, two-tier channel)");
         
         use std::time::{Duration, Instant};
         let mut last_flush = Instant::now();
         let flush_interval = Duration::from_millis(BATCH_MAX_LATENCY_MS);
-        let mut dropped_count = 0u64;
-        let mut last_drop_log = Instant::now();
 
         loop {
-            // Try to receive commands with timeout for periodic flush
-            match rx.blocking_recv() {
-                Some(cmd) => {
-                    self.handle_command(cmd);
-                    
-                    // Drain additional messages if available (batch efficiently)
-                    while let Ok(cmd) = rx.try_recv() {
-                        self.handle_command(cmd);
-                        
-                        // Check if batch size threshold reached
-                        if self.should_flush_size() {
-                            break;
-                        }
-                    }
-                    
-                    // Flush if size threshold reached OR time elapsed
-                    if self.should_flush_size() || last_flush.elapsed() >= flush_interval {
-                        if let Err(e) = self.flush_all() {
-                            warn!("❌ DB flush failed: {}", e);
-                        }
-                        last_flush = Instant::now();
-                    }
+            // Wait for the next command, preferring the high-priority tier
+            let next = rt.block_on(async {
+                tokio::select! {
+                    biased;
+                    Some(cmd) = high_rx.recv() => Some(cmd),
+                    Some(cmd) = low_rx.recv() => Some(cmd),
+                    else => None,
                 }
-                None => {
-                    warn!("📝 DB Writer channel closed, exiting");
-                    break;
+            });
+
+            let Some(cmd) = next else {
+                warn!("📝 DB Writer channels closed, exiting");
+                break;
+            };
+            self.handle_command(cmd);
+
+            // Drain high priority first, then top up with low priority
+            while !self.should_flush_size() {
+                match high_rx.try_recv() {
+                    Ok(cmd) => self.handle_command(cmd),
+                    Err(_) => break,
                 }
             }
+            while !self.should_flush_size() {
+                match low_rx.try_recv() {
+                    Ok(cmd) => self.handle_command(cmd),
+                    Err(_) => break,
+                }
+            }
+
+            crate::metrics::set_db_writer_queue_depth(WritePriority::High, high_rx.len());
+            crate::metrics::set_db_writer_queue_depth(WritePriority::Low, low_rx.len());
+
+            // Flush if size threshold reached OR time elapsed
+            if self.should_flush_size() || last_flush.elapsed() >= flush_interval {
+                if let Err(e) = self.flush_all() {
+                    warn!("❌ DB flush failed: {}", e);
+                }
+                last_flush = Instant::now();
+            }
         }
     }
 
@@ -141,6 +229,9 @@ impl DbWriter {
             DbWriteCommand::UpdateInitialLiquidity { mint, liquidity_sol } => {
                 self.liquidity_updates.push((mint, liquidity_sol));
             }
+            DbWriteCommand::DeleteTradesInSlots(slots) => {
+                self.orphaned_slots.extend(slots);
+            }
         }
     }
 
@@ -149,6 +240,7 @@ impl DbWriter {
         self.trade_batch.len() >= BATCH_MAX_SIZE
             || self.token_batch.len() >= 100
             || self.liquidity_updates.len() >= 100
+            || !self.orphaned_slots.is_empty()
     }
 
     /// Check if time threshold reached
@@ -156,17 +248,67 @@ impl DbWriter {
         !self.trade_batch.is_empty() 
             || !self.token_batch.is_empty()
             || !self.liquidity_updates.is_empty()
+            || !self.orphaned_slots.is_empty()
     }
 
     /// Flush all batches to database in a SINGLE transaction
+    ///
+    /// Busy/locked errors are retried with exponential backoff. Batches are only
+    /// cleared once the transaction commits, so a lock that outlasts the retries
+    /// keeps the data buffered for the next flush instead of dropping it.
     fn flush_all(&mut self) -> Result<()> {
         let start = Instant::now();
-        let total_items = self.trade_batch.len() + self.token_batch.len() + self.liquidity_updates.len();
+        let total_items = self.trade_batch.len() + self.token_batch.len()
+            + self.liquidity_updates.len() + self.orphaned_slots.len();
 
         if total_items == 0 {
             return Ok(());
         }
 
+        let mut attempt = 0;
+        loop {
+            match self.write_batches() {
+                Ok(()) => break,
+                Err(e) if is_busy(&e) && attempt < BUSY_MAX_RETRIES => {
+                    let backoff_ms = BUSY_BACKOFF_BASE_MS << attempt;
+                    attempt += 1;
+                    crate::metrics::record_db_writer_busy_retry();
+                    debug!("🔒 DB busy, retry {}/{} in {}ms: {}", attempt, BUSY_MAX_RETRIES, backoff_ms, e);
+                    std::thread::sleep(Duration::from_millis(backoff_ms));
+                }
+                Err(e) if is_busy(&e) => {
+                    warn!("🔒 DB still busy after {} retries - keeping {} items buffered", attempt, total_items);
+                    return Err(e.into());
+                }
+                Err(e) => {
+                    // Not transient - drop the batch rather than fail every flush
+                    self.clear_batches();
+                    return Err(e.into());
+                }
+            }
+        }
+
+        crate::metrics::record_trades_inserted(self.trade_batch.len());
+        self.clear_batches();
+
+        let elapsed = start.elapsed();
+        if total_items > 50 || elapsed.as_millis() > 10 {
+            debug!("💾 DB flush: {} items in {:?}", total_items, elapsed);
+        }
+        
+        self.batch_start = Instant::now();
+        Ok(())
+    }
+
+    fn clear_batches(&mut self) {
+        self.token_batch.clear();
+        self.trade_batch.clear();
+        self.liquidity_updates.clear();
+        self.orphaned_slots.clear();
+    }
+
+    /// Write all buffered items in one transaction (batches are left untouched)
+    fn write_batches(&mut self) -> rusqlite::Result<()> {
         // Use a SINGLE transaction for all inserts (much faster!)
         let tx = self.conn.transaction()?;
 
@@ -211,7 +353,6 @@ impl DbWriter {
                     token.observed_at,
                 ])?;
             }
-            self.token_batch.clear();
         }
 
         // THEN flush trades (now tokens exist)
@@ -239,7 +380,6 @@ impl DbWriter {
                     trade.is_amm as i32,
                 ])?;
             }
-            self.trade_batch.clear();
         }
 
         // Finally flush liquidity updates
@@ -255,34 +395,145 @@ impl DbWriter {
             for (mint, liquidity_sol) in &self.liquidity_updates {
                 stmt.execute(rusqlite::params![mint, liquidity_sol])?;
             }
-            self.liquidity_updates.clear();
         }
 
-        // Commit everything at once
-        tx.commit()?;
-
-        let elapsed = start.elapsed();
-        if total_items > 50 || elapsed.as_millis() > 10 {
-            debug!("💾 DB flush: {} items in {:?}", total_items, elapsed);
+        // Reorg rollback LAST, so trades from an orphaned slot still sitting in
+        // this batch are removed too
+        if !self.orphaned_slots.is_empty() {
+            let mut stmt = tx.prepare_cached("DELETE FROM trades WHERE slot = ?1")?;
+            let mut deleted = 0;
+            for slot in &self.orphaned_slots {
+                deleted += stmt.execute(rusqlite::params![slot])?;
+            }
+            info!("🔀 Reorg rollback: deleted {} trades from {} orphaned slots", deleted, self.orphaned_slots.len());
         }
-        
-        self.batch_start = Instant::now();
-        Ok(())
+
+        // Commit everything at once
+        tx.commit()
     }
 }
 
-/// Spawn DB writer task and return channel for sending commands
-pub fn spawn_db_writer(conn: Connection) -> mpsc::Sender<DbWriteCommand> {
-    // Use bounded channel with 50k capacity for back-pressure
-    const CHANNEL_CAPACITY: usize = 50_000;
-    let (tx, rx) = mpsc::channel(CHANNEL_CAPACITY);
+/// SQLITE_BUSY / SQLITE_LOCKED - another connection holds the lock
+fn is_busy(e: &rusqlite::Error) -> bool {
+    matches!(
+        e.sqlite_error_code(),
+        Some(ErrorCode::DatabaseBusy) | Some(ErrorCode::DatabaseLocked)
+    )
+}
+
+/// Spawn DB writer task and return the two-tier handle for sending commands
+///
+/// Must be called from within a tokio runtime.
+pub fn spawn_db_writer(conn: Connection) -> DbWriterHandle {
+    let (handle, high_rx, low_rx) = DbWriterHandle::channel(LOW_PRIORITY_CAPACITY);
+    let rt = tokio::runtime::Handle::current();
     
     // Spawn writer in dedicated blocking thread (SQLite operations are blocking)
     std::thread::spawn(move || {
         let writer = DbWriter::new(conn);
-        writer.run_blocking_bounded(rx);
+        writer.run_blocking_tiered(high_rx, low_rx, rt);
     });
     
-    info!("✅ DB Writer channel created (blocking thread, capacity={})", CHANNEL_CAPACITY);
-    tx
+    info!(
+        "✅ DB Writer channels created (blocking thread, high=unbounded, low capacity={})",
+        LOW_PRIORITY_CAPACITY
+    );
+    handle
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::Database;
+    use tempfile::tempdir;
+
+    fn liquidity(mint: &str) -> DbWriteCommand {
+        DbWriteCommand::UpdateInitialLiquidity { mint: mint.to_string(), liquidity_sol: 30.0 }
+    }
+
+    #[test]
+    fn test_low_priority_sheds_while_high_priority_queues() {
+        let (handle, mut high_rx, mut low_rx) = DbWriterHandle::channel(1);
+
+        assert!(handle.try_send_low(liquidity("a")).is_ok());
+        assert!(matches!(
+            handle.try_send_low(liquidity("b")),
+            Err(mpsc::error::TrySendError::Full(_))
+        ));
+
+        // High tier keeps accepting even though the low tier is full
+        for i in 0..10 {
+            assert!(handle.send(liquidity(&i.to_string())).is_ok());
+        }
+        assert_eq!(high_rx.len(), 10);
+        assert_eq!(low_rx.len(), 1);
+
+        high_rx.close();
+        low_rx.close();
+        assert!(handle.send(liquidity("c")).is_err());
+    }
+
+    #[test]
+    fn test_orphaned_slot_trades_are_deleted() {
+        let dir = tempdir().unwrap();
+        let db = Database::new(dir.path().join("reorg.db"), true).unwrap();
+        let mut writer = DbWriter::new(db.get_connection_for_writer().unwrap());
+        writer.conn.execute_batch(
+            "INSERT INTO tokens (mint, creator_wallet, decimals, launch_tx_sig, launch_slot, launch_block_time, observed_at)
+             VALUES ('mint', 'creator', 6, 'launch', 1, 1730000000, 1730000000)"
+        ).unwrap();
+
+        let trade = |sig: &str, slot: u64| DbWriteCommand::InsertTrade(Trade {
+            sig: sig.to_string(),
+            slot,
+            block_time: 1_730_000_000,
+            mint: "mint".to_string(),
+            side: crate::types::TradeSide::Buy,
+            trader: "trader".to_string(),
+            amount_tokens: 1.0,
+            amount_sol: 0.1,
+            price: 0.1,
+            is_amm: false,
+        });
+
+        writer.handle_command(trade("a", 5));
+        writer.flush_all().unwrap();
+
+        // Orphaned slot 5 covers both the committed trade and one still batched
+        writer.handle_command(trade("b", 5));
+        writer.handle_command(trade("c", 6));
+        writer.handle_command(DbWriteCommand::DeleteTradesInSlots(vec![5]));
+        writer.flush_all().unwrap();
+
+        let sigs: Vec<String> = writer.conn
+            .prepare("SELECT sig FROM trades ORDER BY sig").unwrap()
+            .query_map([], |row| row.get(0)).unwrap()
+            .collect::<rusqlite::Result<_>>().unwrap();
+        assert_eq!(sigs, vec!["c".to_string()]);
+        assert!(writer.orphaned_slots.is_empty());
+    }
+
+    #[test]
+    fn test_busy_flush_keeps_batch_until_lock_released() {
+        let dir = tempdir().unwrap();
+        let db = Database::new(dir.path().join("busy.db"), true).unwrap();
+
+        let conn = db.get_connection_for_writer().unwrap();
+        conn.busy_timeout(Duration::ZERO).unwrap();
+        let mut writer = DbWriter::new(conn);
+        writer.handle_command(liquidity("mint"));
+
+        // Another connection holds the write lock
+        let locker = db.get_connection_for_writer().unwrap();
+        locker.execute_batch("BEGIN IMMEDIATE").unwrap();
+
+        let retries_before = crate::metrics::metrics().db_writer_busy_retries.get();
+        assert!(writer.flush_all().is_err());
+        assert_eq!(writer.liquidity_updates.len(), 1);
+        assert!(crate::metrics::metrics().db_writer_busy_retries.get() >= retries_before + BUSY_MAX_RETRIES as u64);
+
+        locker.execute_batch("COMMIT").unwrap();
+        assert!(writer.flush_all().is_ok());
+        assert!(writer.liquidity_updates.is_empty());
+    }
 }
@@ -0,0 +1,112 @@
+//! 🩺 Liveness / readiness probes for orchestration
+//!
+//! Served on the metrics port next to `/metrics`:
+//! - `/health`: 200 while a transaction was processed within
+//!   `health.max_tx_age_secs` (counted from startup until the first one),
+//!   503 once the stream has gone quiet, so a supervisor can restart a hung
+//!   process instead of it logging reconnects forever
+//! - `/ready`: 200 once the first transaction has been processed
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::Serialize;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+static STARTED: once_cell::sync::Lazy<Instant> = once_cell::sync::Lazy::new(Instant::now);
+
+/// Milliseconds after `STARTED` of the last processed transaction (`u64::MAX` = none yet)
+static LAST_TX_MS: AtomicU64 = AtomicU64::new(u64::MAX);
+
+static MAX_TX_AGE_SECS: AtomicU64 = AtomicU64::new(60);
+
+/// Start the uptime clock and set the staleness threshold (called at startup)
+pub fn init(max_tx_age_secs: u64) {
+    once_cell::sync::Lazy::force(&STARTED);
+    MAX_TX_AGE_SECS.store(max_tx_age_secs, Ordering::Relaxed);
+}
+
+/// Record a transaction taken off the stream
+pub fn record_tx() {
+    LAST_TX_MS.store(STARTED.elapsed().as_millis() as u64, Ordering::Relaxed);
+}
+
+/// Probe result returned by `/health` and `/ready`
+#[derive(Debug, Clone, Serialize)]
+pub struct HealthStatus {
+    pub service: &'static str,
+    pub healthy: bool,
+    pub ready: bool,
+    pub secs_since_last_tx: Option<f64>,
+    pub max_tx_age_secs: u64,
+}
+
+impl HealthStatus {
+    /// `since_last_tx` is None until the first transaction; until then the
+    /// uptime counts against the threshold (startup grace period)
+    pub fn evaluate(uptime: Duration, since_last_tx: Option<Duration>, max_tx_age: Duration) -> Self {
+        let quiet_for = since_last_tx.unwrap_or(uptime);
+        Self {
+            service: "data-mining",
+            healthy: quiet_for <= max_tx_age,
+            ready: since_last_tx.is_some(),
+            secs_since_last_tx: since_last_tx.map(|d| d.as_secs_f64()),
+            max_tx_age_secs: max_tx_age.as_secs(),
+        }
+    }
+}
+
+/// Current probe state
+pub fn status() -> HealthStatus {
+    let uptime = STARTED.elapsed();
+    let since_last_tx = match LAST_TX_MS.load(Ordering::Relaxed) {
+        u64::MAX => None,
+        at_ms => Some(uptime.saturating_sub(Duration::from_millis(at_ms))),
+    };
+    HealthStatus::evaluate(uptime, since_last_tx, Duration::from_secs(MAX_TX_AGE_SECS.load(Ordering::Relaxed)))
+}
+
+fn respond(ok: bool, status: HealthStatus) -> Response {
+    let code = if ok { StatusCode::OK } else { StatusCode::SERVICE_UNAVAILABLE };
+    (code, Json(status)).into_response()
+}
+
+/// Liveness: 503 once the transaction stream is stale
+pub async fn health_handler() -> Response {
+    let status = status();
+    respond(status.healthy, status)
+}
+
+/// Readiness: 503 until the first transaction has been processed
+pub async fn ready_handler() -> Response {
+    let status = status();
+    respond(status.ready, status)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_health_status_staleness() {
+        let max_age = Duration::from_secs(60);
+
+        // Startup grace period: healthy but not ready until the first tx
+        let starting = HealthStatus::evaluate(Duration::from_secs(10), None, max_age);
+        assert!(starting.healthy && !starting.ready);
+
+        // Never saw a tx past the threshold: hung at startup
+        let stuck = HealthStatus::evaluate(Duration::from_secs(61), None, max_age);
+        assert!(!stuck.healthy && !stuck.ready);
+
+        let flowing = HealthStatus::evaluate(Duration::from_secs(3600), Some(Duration::from_secs(2)), max_age);
+        assert!(flowing.healthy && flowing.ready);
+        assert_eq!(flowing.secs_since_last_tx, Some(2.0));
+
+        let stale = HealthStatus::evaluate(Duration::from_secs(3600), Some(Duration::from_secs(90)), max_age);
+        assert!(!stale.healthy && stale.ready);
+    }
+}
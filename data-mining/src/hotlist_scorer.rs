@@ -5,7 +5,7 @@
 /// - Signal 2: Speed of first 10 buyers (+2.0)
 /// - Signal 3: Liquidity vs MC ratio <4 (+1.5)
 /// - Signal 4: Wallet overlap with winners (+2.0)
-/// - Signal 5: Buy concentration <70% (+1.0)
+/// - Signal 5: Buy concentration <70% (+1.0, -1.0 if one wallet holds >50% of net buys)
 /// - Signal 6: Volume acceleration 2x (+1.5)
 /// - Signal 7: MC velocity >1000 SOL/min (+3.0)
 
@@ -14,50 +14,33 @@ use std::time::Duration;
 use anyhow::Result;
 use tracing::{debug, info, warn};
 
+use crate::clock::Clock;
+use crate::config::HotlistConfig;
 use crate::db::Database;
 use crate::udp::AdvisorySender;
 use crate::window_tracker::WindowTracker;
 
-/// Configuration for hotlist scoring
-pub struct HotlistScorerConfig {
-    /// How often to run scoring (seconds)
-    pub scoring_interval_sec: u64,
-    /// Minimum age before scoring (seconds, to accumulate data)
-    pub min_age_sec: i64,
-    /// Maximum age to score (seconds, focus on fresh launches)
-    pub max_age_sec: i64,
-    /// Minimum score to broadcast to Brain
-    pub min_broadcast_score: f64,
-}
-
-impl Default for HotlistScorerConfig {
-    fn default() -> Self {
-        Self {
-            scoring_interval_sec: 5,    // Score every 5 seconds
-            min_age_sec: 10,             // Wait 10s for data accumulation
-            max_age_sec: 300,            // Only score tokens <5min old
-            min_broadcast_score: 6.0,    // Broadcast score ≥6.0
-        }
-    }
-}
+/// Top-1 share of net buy accumulation above which Signal 5 penalizes (rug signal)
+const MAX_TOP1_SHARE: f64 = 0.5;
 
 /// Spawn background hotlist scorer task
 pub fn spawn_hotlist_scorer(
     db: Arc<Mutex<Database>>,
     advisory_sender: Option<AdvisorySender>,
     window_tracker: Arc<Mutex<WindowTracker>>,
-    config: HotlistScorerConfig,
+    config: HotlistConfig,
+    clock: Arc<dyn Clock>,
 ) -> tokio::task::JoinHandle<()> {
     tokio::spawn(async move {
-        info!("🎯 Hotlist Scorer: Started (interval={}s, min_score={:.1})", 
-              config.scoring_interval_sec, config.min_broadcast_score);
+        info!("🎯 Hotlist Scorer: Started (interval={}s, min_score={:.1}, max_per_cycle={})", 
+              config.scoring_interval_sec, config.min_broadcast_score, config.max_broadcast_per_cycle);
         
         let mut interval = tokio::time::interval(Duration::from_secs(config.scoring_interval_sec));
         
         loop {
             interval.tick().await;
             
-            if let Err(e) = run_scoring_cycle(&db, &advisory_sender, &window_tracker, &config).await {
+            if let Err(e) = run_scoring_cycle(&db, &advisory_sender, &window_tracker, &config, clock.as_ref()).await {
                 warn!("⚠️  Hotlist scoring cycle failed: {}", e);
             }
         }
@@ -69,9 +52,10 @@ async fn run_scoring_cycle(
     db: &Arc<Mutex<Database>>,
     advisory_sender: &Option<AdvisorySender>,
     window_tracker: &Arc<Mutex<WindowTracker>>,
-    config: &HotlistScorerConfig,
+    config: &HotlistConfig,
+    clock: &dyn Clock,
 ) -> Result<()> {
-    let now = chrono::Utc::now().timestamp();
+    let now = clock.now_secs();
     let min_launch_time = now - config.max_age_sec;
     let max_launch_time = now - config.min_age_sec;
     
@@ -87,6 +71,8 @@ async fn run_scoring_cycle(
     
     debug!("🎯 Scoring {} recent tokens...", tokens.len());
     
+    let mut qualifying: Vec<(String, ScoreBreakdown)> = Vec::new();
+    
     for (mint, launch_time) in tokens {
         // Calculate 7-signal score
         let score_result = calculate_token_score(&db, &window_tracker, &mint, launch_time, now).await;
@@ -111,17 +97,8 @@ async fn run_scoring_cycle(
                     )?;
                 }
                 
-                // Broadcast to Brain if score is high enough
                 if score_data.total >= config.min_broadcast_score {
-                    if let Some(ref sender) = advisory_sender {
-                        let confidence = ((score_data.total / 15.0) * 100.0).min(100.0) as u8;
-                        let horizon_sec = 60; // Valid for 60 seconds
-                        
-                        let _ = sender.send_rank_opportunity(&mint, horizon_sec, confidence);
-                        
-                        info!("🔥 HIGH-SCORE TOKEN: {} | score: {:.1}/15.0 | MC velocity: {:.0} SOL/min | conf: {}%",
-                              &mint[..8], score_data.total, score_data.mc_vel_value, confidence);
-                    }
+                    qualifying.push((mint, score_data));
                 }
             }
             Err(e) => {
@@ -130,6 +107,29 @@ async fn run_scoring_cycle(
         }
     }
     
+    // Broadcast the top N qualifying tokens to Brain, best first
+    if let Some(ref sender) = advisory_sender {
+        qualifying.sort_by(|a, b| b.1.total.total_cmp(&a.1.total));
+        
+        for (i, (mint, score_data)) in qualifying.iter().take(config.max_broadcast_per_cycle).enumerate() {
+            let rank = (i + 1) as u8;
+            
+            if let Err(e) = sender.send_hotlist_opportunity(
+                mint,
+                score_data.total,
+                rank,
+                score_data.unique_buyers,
+                score_data.mc_vel_value,
+            ) {
+                warn!("⚠️  Failed to send HotlistOpportunity for {}: {}", &mint[..8], e);
+                continue;
+            }
+            
+            info!("🔥 HIGH-SCORE TOKEN #{}: {} | score: {:.1}/15.0 | MC velocity: {:.0} SOL/min | buyers: {}",
+                  rank, &mint[..8], score_data.total, score_data.mc_vel_value, score_data.unique_buyers);
+        }
+    }
+    
     // Cleanup old entries (>5 minutes)
     {
         let mut db_guard = db.lock().unwrap();
@@ -165,9 +165,9 @@ async fn calculate_token_score(
     let age_sec = now - launch_time;
     
     // Get recent trades, creator stats, initial liquidity, and MC velocity
-    let (trades, creator_stats, initial_liquidity, mc_velocity_opt) = {
+    let (trades, creator_stats, initial_liquidity, window_concentration, mc_velocity_opt) = {
         let db_guard = db.lock().unwrap();
-        let trades = get_recent_trades(&db_guard, mint, 60)?;
+        let trades = get_recent_trades(&db_guard, mint, now, 60)?;
         
         // Get creator wallet and stats for Signal 1
         let creator_stats = match db_guard.get_creator_wallet(mint) {
@@ -178,6 +178,9 @@ async fn calculate_token_score(
         // Get initial liquidity for Signal 3
         let initial_liquidity = db_guard.get_initial_liquidity(mint).ok().flatten();
         
+        // Net-accumulation concentration from the latest 60s window for Signal 5
+        let window_concentration = db_guard.get_window_concentration(mint, 60).ok().flatten();
+        
         // Release db lock before acquiring window_tracker lock
         drop(db_guard);
         
@@ -192,7 +195,7 @@ async fn calculate_token_score(
         let mc_velocity = tracker_guard.get_metrics_if_ready(mint, estimated_mc)
             .map(|metrics| metrics.mc_velocity_sol_per_min);
         
-        (trades, creator_stats, initial_liquidity, mc_velocity)
+        (trades, creator_stats, initial_liquidity, window_concentration, mc_velocity)
     };
     
     if trades.is_empty() {
@@ -309,8 +312,15 @@ async fn calculate_token_score(
     }
     
     // Signal 5: Buy concentration (lower = better distribution)
-    let concentration_pct = calculate_buy_concentration(&trades);
-    if concentration_pct < 70.0 {
+    // Prefer the aggregator's net-accumulation shares; fall back to gross buy SOL
+    let (top1_share, concentration_pct) = match window_concentration {
+        Some((top1, top3)) if top1 > 0.0 => (top1, top3 * 100.0),
+        _ => (0.0, calculate_buy_concentration(&trades)),
+    };
+    if top1_share > MAX_TOP1_SHARE {
+        score.concentration = -1.0; // One wallet accumulating most of the flow (rug signal)
+        debug!("🚨 Signal 5: {} | top holder accumulated {:.1}% of net buys (rug risk)", mint, top1_share * 100.0);
+    } else if concentration_pct < 70.0 {
         score.concentration = 1.0; // Healthy distribution
         debug!("✅ Signal 5: {} | concentration {:.1}% (healthy)", mint, concentration_pct);
     } else if concentration_pct < 80.0 {
@@ -385,9 +395,10 @@ fn get_recent_tokens(
 fn get_recent_trades(
     db: &Database,
     mint: &str,
+    now: i64,
     lookback_sec: i64,
 ) -> Result<Vec<(String, String, f64)>> {
-    db.get_recent_trades_for_scoring(mint, lookback_sec)
+    db.get_recent_trades_for_scoring(mint, now, lookback_sec)
 }
 
 /// Calculate volume acceleration by comparing recent vs baseline period
@@ -1,7 +1,10 @@
 //! 📊 Latency Tracker - 4-timestamp instrumentation for bottleneck detection
 //!
-//! Tracks: created_ns → enqueued_ns → flushed_ns → brain_recv_ns
-//! Reports: p50/p90/p99 histograms every 5s
+//! Tracks: created_ns → enqueued_ns → flushed_ns → brain_recv_ns, plus the
+//! per-stage breakdown of `process_transaction` (parse, momentum, window and
+//! their lock waits, advisory sends) to pin down which stage a spike comes from
+//! Reports: p50/p90/p99 histograms every 5s (logged and exported to /metrics
+//! as `datamining_latency_ms{stage, quantile}`)
 
 use std::time::{SystemTime, UNIX_EPOCH};
 use tokio::time::{interval, Duration};
@@ -15,70 +18,162 @@ pub fn now_ns() -> u64 {
         .as_nanos() as u64
 }
 
-/// Simple histogram for latency tracking
+/// Sub-buckets per power of two (relative bucket width ≤ 1/16 ≈ 6%)
+const SUB_BUCKET_BITS: u32 = 4;
+const SUB_BUCKETS: usize = 1 << SUB_BUCKET_BITS;
+/// Enough buckets to cover every u64 value
+const NUM_BUCKETS: usize = (64 - SUB_BUCKET_BITS as usize + 1) * SUB_BUCKETS;
+
+/// Bucket index for a value: exact below 16, then 16 log-linear buckets per power of two
+fn bucket_index(value: u64) -> usize {
+    if value < SUB_BUCKETS as u64 {
+        return value as usize;
+    }
+    let magnitude = 63 - value.leading_zeros(); // >= SUB_BUCKET_BITS
+    let shift = magnitude - SUB_BUCKET_BITS;
+    let sub = ((value >> shift) as usize) & (SUB_BUCKETS - 1);
+    (shift as usize + 1) * SUB_BUCKETS + sub
+}
+
+/// Highest value that lands in a bucket (percentiles report this upper edge)
+fn bucket_upper_bound(index: usize) -> u64 {
+    if index < SUB_BUCKETS {
+        return index as u64;
+    }
+    let shift = (index / SUB_BUCKETS - 1) as u32;
+    let sub = (index % SUB_BUCKETS) as u64;
+    let low = (SUB_BUCKETS as u64 + sub) << shift;
+    low.saturating_add((1u64 << shift) - 1)
+}
+
+/// Per-interval latency percentiles (nanoseconds)
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct LatencySnapshot {
+    pub count: u64,
+    pub p50: u64,
+    pub p90: u64,
+    pub p99: u64,
+    pub max: u64,
+}
+
+/// Bucketed (HDR-style, log-linear) latency histogram
+///
+/// Fixed memory regardless of sample count; percentiles are accurate to the
+/// bucket width (~6%). Cleared each report interval via `reset`.
 pub struct LatencyHistogram {
-    samples: Vec<u64>,
+    buckets: Vec<u64>,
+    count: u64,
+    max: u64,
     name: String,
 }
 
 impl LatencyHistogram {
     pub fn new(name: &str) -> Self {
         Self {
-            samples: Vec::with_capacity(10000),
+            buckets: vec![0; NUM_BUCKETS],
+            count: 0,
+            max: 0,
             name: name.to_string(),
         }
     }
 
     /// Record a latency sample (in nanoseconds)
     pub fn record(&mut self, latency_ns: u64) {
-        self.samples.push(latency_ns);
+        self.buckets[bucket_index(latency_ns)] += 1;
+        self.count += 1;
+        self.max = self.max.max(latency_ns);
     }
 
-    /// Get percentile (0.0 to 1.0)
-    fn percentile(&mut self, p: f64) -> u64 {
-        if self.samples.is_empty() {
+    /// Get percentile (0.0 to 1.0); never above the exact max
+    pub fn percentile(&self, p: f64) -> u64 {
+        if self.count == 0 {
             return 0;
         }
-        self.samples.sort_unstable();
-        let idx = ((self.samples.len() as f64 * p) as usize).min(self.samples.len() - 1);
-        self.samples[idx]
+        let rank = ((self.count as f64 * p).ceil() as u64).clamp(1, self.count);
+        let mut seen = 0;
+        for (index, &n) in self.buckets.iter().enumerate() {
+            seen += n;
+            if seen >= rank {
+                return bucket_upper_bound(index).min(self.max);
+            }
+        }
+        self.max
     }
 
-    /// Report statistics and clear
+    pub fn snapshot(&self) -> LatencySnapshot {
+        LatencySnapshot {
+            count: self.count,
+            p50: self.percentile(0.5),
+            p90: self.percentile(0.9),
+            p99: self.percentile(0.99),
+            max: self.max,
+        }
+    }
+
+    /// Clear all samples (start a new interval)
+    pub fn reset(&mut self) {
+        self.buckets.iter_mut().for_each(|n| *n = 0);
+        self.count = 0;
+        self.max = 0;
+    }
+
+    /// Metrics label, e.g. "End-to-End" → "end_to_end"
+    fn stage(&self) -> String {
+        self.name.to_lowercase().replace('-', "_")
+    }
+
+    /// Report statistics (log + metrics endpoint) and clear
     pub fn report_and_clear(&mut self) {
-        if self.samples.is_empty() {
+        let snapshot = self.snapshot();
+        let to_ms = |ns: u64| ns as f64 / 1_000_000.0;
+
+        crate::metrics::set_latency_percentiles(
+            &self.stage(),
+            snapshot.count,
+            to_ms(snapshot.p50),
+            to_ms(snapshot.p90),
+            to_ms(snapshot.p99),
+            to_ms(snapshot.max),
+        );
+
+        if snapshot.count == 0 {
             return;
         }
 
-        let count = self.samples.len();
-        let p50 = self.percentile(0.5);
-        let p90 = self.percentile(0.9);
-        let p99 = self.percentile(0.99);
-        let max = *self.samples.iter().max().unwrap();
-
         info!(
             "📊 {} Latency: count={} | p50={:.2}ms | p90={:.2}ms | p99={:.2}ms | max={:.2}ms",
             self.name,
-            count,
-            p50 as f64 / 1_000_000.0,
-            p90 as f64 / 1_000_000.0,
-            p99 as f64 / 1_000_000.0,
-            max as f64 / 1_000_000.0
+            snapshot.count,
+            to_ms(snapshot.p50),
+            to_ms(snapshot.p90),
+            to_ms(snapshot.p99),
+            to_ms(snapshot.max)
         );
 
-        if p99 > 100_000_000 {
+        if snapshot.p99 > 100_000_000 {
             // > 100ms
             warn!(
                 "⚠️  {} p99 latency is high: {:.2}ms - possible bottleneck!",
                 self.name,
-                p99 as f64 / 1_000_000.0
+                to_ms(snapshot.p99)
             );
         }
 
-        self.samples.clear();
+        self.reset();
     }
 }
 
+/// Per-stage durations of one trade through `process_trade_signals` (nanoseconds)
+///
+/// Lock waits are split out so contention shows up separately from the work.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TradeStageTimings {
+    pub momentum_lock_ns: u64,
+    pub momentum_ns: u64,
+    pub window_lock_ns: u64,
+    pub window_ns: u64,
+}
+
 /// Global latency tracker
 pub struct LatencyTracker {
     pub db_enqueue: LatencyHistogram,    // created_ns → enqueued_ns
@@ -86,6 +181,12 @@ pub struct LatencyTracker {
     pub udp_enqueue: LatencyHistogram,   // created_ns → udp_enqueued_ns
     pub udp_flush: LatencyHistogram,     // udp_enqueued_ns → flushed_ns
     pub end_to_end: LatencyHistogram,    // created_ns → processing done
+    pub parse: LatencyHistogram,         // created_ns → events parsed
+    pub momentum_lock: LatencyHistogram, // wait for momentum_tracker lock
+    pub momentum: LatencyHistogram,      // momentum stage incl. lock wait + signal sends
+    pub window_lock: LatencyHistogram,   // wait for window_tracker lock
+    pub window: LatencyHistogram,        // window stage incl. lock wait + late-opportunity check
+    pub advisory: LatencyHistogram,      // tracked-wallet advisory sends
 }
 
 impl LatencyTracker {
@@ -96,15 +197,50 @@ impl LatencyTracker {
             udp_enqueue: LatencyHistogram::new("UDP_Enqueue"),
             udp_flush: LatencyHistogram::new("UDP_Flush"),
             end_to_end: LatencyHistogram::new("End-to-End"),
+            parse: LatencyHistogram::new("Parse"),
+            momentum_lock: LatencyHistogram::new("Momentum_Lock"),
+            momentum: LatencyHistogram::new("Momentum"),
+            window_lock: LatencyHistogram::new("Window_Lock"),
+            window: LatencyHistogram::new("Window"),
+            advisory: LatencyHistogram::new("Advisory"),
+        }
+    }
+
+    /// Record one trade's signal-pipeline stage durations
+    pub fn record_trade_stages(&mut self, timings: &TradeStageTimings) {
+        self.momentum_lock.record(timings.momentum_lock_ns);
+        self.momentum.record(timings.momentum_ns);
+        self.window_lock.record(timings.window_lock_ns);
+        self.window.record(timings.window_ns);
+    }
+
+    fn histograms_mut(&mut self) -> [&mut LatencyHistogram; 11] {
+        [
+            &mut self.db_enqueue,
+            &mut self.db_flush,
+            &mut self.udp_enqueue,
+            &mut self.udp_flush,
+            &mut self.end_to_end,
+            &mut self.parse,
+            &mut self.momentum_lock,
+            &mut self.momentum,
+            &mut self.window_lock,
+            &mut self.window,
+            &mut self.advisory,
+        ]
+    }
+
+    /// Clear every histogram (start a new interval without reporting)
+    pub fn reset_all(&mut self) {
+        for histogram in self.histograms_mut() {
+            histogram.reset();
         }
     }
 
     pub fn report_all(&mut self) {
-        self.db_enqueue.report_and_clear();
-        self.db_flush.report_and_clear();
-        self.udp_enqueue.report_and_clear();
-        self.udp_flush.report_and_clear();
-        self.end_to_end.report_and_clear();
+        for histogram in self.histograms_mut() {
+            histogram.report_and_clear();
+        }
     }
 }
 
@@ -122,3 +258,62 @@ pub fn spawn_latency_reporter(tracker: std::sync::Arc<std::sync::Mutex<LatencyTr
     });
     info!("📊 Latency reporter spawned (reporting every 5s)");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bucket_bounds_cover_values() {
+        for value in [0, 1, 15, 16, 17, 100, 1_000, 123_456, 5_000_000, u64::MAX / 3, u64::MAX] {
+            let index = bucket_index(value);
+            assert!(index < NUM_BUCKETS);
+            let upper = bucket_upper_bound(index);
+            assert!(upper >= value, "{} > upper {}", value, upper);
+            // Relative bucket width stays within 1/16
+            assert!((upper - value) as f64 <= value as f64 / 16.0 + 1.0);
+        }
+    }
+
+    #[test]
+    fn test_percentiles_and_reset() {
+        let mut hist = LatencyHistogram::new("End-to-End");
+        assert_eq!(hist.snapshot(), LatencySnapshot::default());
+
+        // 1..=1000 ms
+        for ms in 1..=1000u64 {
+            hist.record(ms * 1_000_000);
+        }
+        let snapshot = hist.snapshot();
+        assert_eq!(snapshot.count, 1000);
+        assert_eq!(snapshot.max, 1_000_000_000);
+        for (actual, expected_ms) in [(snapshot.p50, 500.0), (snapshot.p90, 900.0), (snapshot.p99, 990.0)] {
+            let actual_ms = actual as f64 / 1_000_000.0;
+            assert!(actual_ms >= expected_ms && actual_ms <= expected_ms * 1.07,
+                "expected ~{}ms, got {}ms", expected_ms, actual_ms);
+        }
+        assert_eq!(hist.stage(), "end_to_end");
+
+        hist.reset();
+        assert_eq!(hist.snapshot().count, 0);
+        assert_eq!(hist.percentile(0.99), 0);
+    }
+
+    #[test]
+    fn test_record_trade_stages() {
+        let mut tracker = LatencyTracker::new();
+        tracker.record_trade_stages(&TradeStageTimings {
+            momentum_lock_ns: 2_000,
+            momentum_ns: 50_000,
+            window_lock_ns: 3_000_000,
+            window_ns: 4_000_000,
+        });
+
+        assert_eq!(tracker.momentum.snapshot().max, 50_000);
+        assert_eq!(tracker.window_lock.snapshot().max, 3_000_000);
+        assert_eq!(tracker.window_lock.stage(), "window_lock");
+
+        tracker.reset_all();
+        assert_eq!(tracker.window.snapshot().count, 0);
+    }
+}
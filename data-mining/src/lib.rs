@@ -16,5 +16,14 @@ pub mod momentum_tracker;
 pub mod window_tracker;
 pub mod hotlist_scorer;
 pub mod latency_tracker;
+pub mod clock;
+pub mod replay;
+pub mod metrics;
+pub mod health;
+pub mod tracked_wallets;
+pub mod token_cache;
+pub mod reorg;
+pub mod opportunity;
+pub mod analytics;
 
 pub use db::Database;
@@ -3,18 +3,23 @@
 // Processes all Pump.fun transactions in one stream
 
 use anyhow::{Context, Result};
-use data_mining::{config::Config, Database};
-use data_mining::checkpoint::Checkpoint;
+use data_mining::{config::{AdviceBusConfig, Config, LateOpportunityConfig, OpportunityConfig}, Database};
+use data_mining::checkpoint::{Checkpoint, CheckpointWriter, SharedCheckpoint};
+use data_mining::clock::{self, Clock, ReplayClock, SystemClock};
 use data_mining::db::aggregator::WindowAggregator;
+use data_mining::metrics::{self, DropKind};
 use data_mining::momentum_tracker::MomentumTracker;
-use data_mining::parser::PumpParser;
-use data_mining::parser::raydium::RaydiumParser;
+use data_mining::opportunity::{self, Opportunity, ShadowAdvisor};
+use data_mining::parser::ProgramRouter;
+use data_mining::reorg::{ReorgTracker, SlotState};
+use data_mining::replay::{ReplayOptions, ReplayPacer, ReplaySource};
+use data_mining::latency_tracker::TradeStageTimings;
+use data_mining::token_cache::{LaunchTimeCache, TokenCache};
+use data_mining::tracked_wallets::{self, TrackedWallets};
 use data_mining::types::{PumpEvent, Token, Trade, TradeSide};
-use data_mining::udp::{AdvisorySender, BatchedBrainSignalSender};
-use std::collections::{HashMap, HashSet};
+use data_mining::udp::{AdviceAuth, AdvisorySender, BatchedBrainSignalSender};
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
-use std::str::FromStr;
-use std::time::{SystemTime, UNIX_EPOCH};
 use tokio_stream::StreamExt;
 use tracing::{info, warn, error, debug};
 use tracing_subscriber::{fmt, EnvFilter};
@@ -22,13 +27,20 @@ use tracing_subscriber::{fmt, EnvFilter};
 use yellowstone_grpc_client::GeyserGrpcClient;
 use yellowstone_grpc_proto::prelude::{
     subscribe_update::UpdateOneof,
+    SlotStatus,
     SubscribeRequest,
+    SubscribeRequestFilterSlots,
     SubscribeRequestFilterTransactions,
     SubscribeUpdateTransaction,
     CommitmentLevel,
 };
 use solana_sdk::pubkey::Pubkey;
 
+/// How often idle mints are evicted from the momentum/window trackers
+const TRACKER_PRUNE_INTERVAL_SECS: u64 = 30;
+/// Mints with no trades for this long are evicted (5 minutes)
+const TRACKER_MAX_IDLE_MS: u64 = 300_000;
+
 #[tokio::main]
 async fn main() -> Result<()> {
     // Initialize logging
@@ -41,10 +53,59 @@ async fn main() -> Result<()> {
 
     // Load configuration
     let config = Config::load_or_default()?;
+    config.validate()?;
     info!("⚙️  Configuration loaded");
 
+    // Backfill mode (backfill-windows <mint> <from> <to>): recompute windows over a gap, then exit
+    if let Some((mint, from_time, to_time)) = parse_backfill_args(std::env::args())? {
+        let mut db = Database::new(&config.database.path, config.database.wal_mode)?;
+        let window_aggregator = WindowAggregator::new(config.windows.validate()?).with_sybil(config.sybil.clone());
+        info!("🔁 BACKFILL MODE: recomputing windows for {} over [{}, {})", mint, from_time, to_time);
+        let written = window_aggregator.backfill(&mut db, &mint, from_time, to_time)?;
+        info!("✅ Backfill complete: {} windows written", written);
+        return Ok(());
+    }
+
+    // Leaderboard export (wallet-leaderboard <out.csv|out.json> [limit] [min_trades]): write file, then exit
+    if let Some((out_path, limit, min_trades)) = parse_leaderboard_args(std::env::args())? {
+        let db = Database::new(&config.database.path, config.database.wal_mode)?;
+        let board = db.get_wallet_leaderboard(limit, min_trades)?;
+        export_wallet_leaderboard(&board, &out_path)?;
+        info!("🏆 Exported {} wallets (min_trades={}) to {}", board.len(), min_trades, out_path);
+        info!("   Promote with: UPDATE wallet_stats SET is_tracked = 1 WHERE wallet = '<wallet>'");
+        return Ok(());
+    }
+
+    // Replay mode (--replay): re-run recorded trades instead of connecting to gRPC
+    let replay_options = ReplayOptions::from_args(std::env::args())?;
+    let replay_clock = replay_options.as_ref().map(|_| Arc::new(ReplayClock::new(0)));
+    let clock: Arc<dyn Clock> = match &replay_clock {
+        Some(replay_clock) => {
+            info!("⏪ REPLAY MODE: trackers use replayed trade timestamps");
+            replay_clock.clone()
+        }
+        None => clock::system_clock(),
+    };
+
+    // 📊 Prometheus metrics (/metrics) + health probes (/health, /ready)
+    data_mining::metrics::init_metrics();
+    data_mining::health::init(config.health.max_tx_age_secs);
+    if config.metrics.enabled {
+        let port = config.metrics.port;
+        let analytics_db = config.metrics.analytics_enabled.then(|| config.database.path.clone());
+        tokio::spawn(async move {
+            if let Err(e) = data_mining::metrics::start_metrics_server(port, analytics_db).await {
+                error!("❌ Metrics server failed: {}", e);
+            }
+        });
+        info!("✅ Metrics: Server started on port {}", port);
+    }
+
     // Initialize unified database and async writer
-    let db = Arc::new(Mutex::new(Database::new(&config.database.path, config.database.wal_mode)?));
+    let db = Arc::new(Mutex::new(
+        Database::new(&config.database.path, config.database.wal_mode)?
+            .with_trade_buffer(config.database.trade_buffer.clone()),
+    ));
     info!("✅ Database initialized: {}", config.database.path);
     
     // Create async DB writer (separate task, non-blocking)
@@ -56,18 +117,24 @@ async fn main() -> Result<()> {
     info!("✅ Async DB Writer: Started (non-blocking mode)");
 
     // Create in-memory token cache to avoid DB reads in hot path
-    let token_cache = Arc::new(Mutex::new(std::collections::HashSet::<String>::new()));
-    info!("🗂️  Token cache initialized (in-memory HashSet)");
+    // (bounded: exact ring of recent mints + bloom filters for the long tail)
+    let token_cache = Arc::new(Mutex::new(TokenCache::default()));
+    // Launch times for the hot path (DB lock only on misses)
+    let launch_times = Arc::new(LaunchTimeCache::default());
+    info!("🗂️  Token cache initialized (recent={}, bloom={}/generation)",
+        data_mining::token_cache::DEFAULT_RECENT_CAPACITY, data_mining::token_cache::DEFAULT_BLOOM_CAPACITY);
 
     // Initialize batched UDP sender (event-driven, adaptive flushing)
-    let udp_batch_tx = data_mining::udp::spawn_batched_sender();
-    info!("✅ Batched UDP Sender: Started (max_batch=256, max_latency=15ms)");
+    let udp_batch_tx = data_mining::udp::spawn_batched_sender(config.advice_bus.max_datagram_bytes);
+    info!("✅ Batched UDP Sender: Started (max_batch=256, max_latency=15ms, max_datagram={}B)",
+        config.advice_bus.max_datagram_bytes);
 
     // Load or create checkpoint
-    let checkpoint_path = "data/checkpoint.json";
-    let mut checkpoint = match Checkpoint::load(checkpoint_path)? {
+    let checkpoint_path = config.checkpoint.path.as_str();
+    let checkpoint = match Checkpoint::load(checkpoint_path)? {
         Some(cp) => {
-            info!("✅ Loaded checkpoint: slot {}", cp.last_processed_slot);
+            info!("✅ Loaded checkpoint: slot {} | seeding dedup cache with {} signatures",
+                cp.last_processed_slot, cp.signature_count());
             cp
         }
         None => {
@@ -75,22 +142,67 @@ async fn main() -> Result<()> {
             Checkpoint::new(0)
         }
     };
+    let checkpoint: SharedCheckpoint = Arc::new(Mutex::new(checkpoint));
+    let checkpoint_writer = data_mining::checkpoint::spawn_checkpoint_writer(checkpoint_path.to_string());
+    if config.checkpoint.save_interval_secs > 0 {
+        let _saver_handle = data_mining::checkpoint::spawn_checkpoint_saver(
+            checkpoint.clone(),
+            checkpoint_writer.clone(),
+            std::time::Duration::from_secs(config.checkpoint.save_interval_secs),
+        );
+    }
 
     // Load tracked wallets from config or database
-    let tracked_wallets = load_tracked_wallets(&db).await?;
-    info!("👥 Loaded {} tracked wallets", tracked_wallets.len());
+    let tracked_wallets: TrackedWallets = Arc::new(Mutex::new(load_tracked_wallets(&db).await?));
+    info!("👥 Loaded {} tracked wallets", tracked_wallets.lock().unwrap().len());
+
+    // 👥 Hot-reload tracked wallets (not in replay - keep the set fixed for reproducibility)
+    if replay_options.is_none() && config.tracked_wallets.reload_interval_secs > 0 {
+        let _reload_handle = tracked_wallets::spawn_tracked_wallets_reloader(
+            config.database.path.clone(),
+            tracked_wallets.clone(),
+            config.tracked_wallets.reload_interval_secs,
+        );
+    }
+
+    let programs = ProgramRouter::new(&config.programs.pump_program, &config.programs.extra_programs)
+        .context("Invalid programs config")?;
+    info!("🎯 Monitoring Pump.fun: {}", config.programs.pump_program);
+    for program in &programs.program_ids()[1..] {
+        info!("🎯 Also monitoring: {}", program);
+    }
 
-    let pump_program = Pubkey::from_str(&config.programs.pump_program)
-        .context("Invalid pump program ID")?;
-    info!("🎯 Monitoring Pump.fun: {}", pump_program);
+    // Shared secret for signing Brain-bound packets (Brain drops unsigned advice)
+    let advice_auth = AdviceAuth::load(config.advice_bus.secret.as_deref(), &config.advice_bus.secret_file)
+        .context("Failed to load advice bus secret")?;
 
     // Initialize advisory sender (optional - gracefully handles if execution bot is offline)
     let advisory_sender = if config.advice_bus.enabled {
-        match AdvisorySender::new(&config.advice_bus.host, config.advice_bus.port) {
+        let sender = match &config.advice_bus.unix_socket_path {
+            Some(path) => AdvisorySender::new_unix(path, advice_auth.clone()),
+            None => AdvisorySender::new(&config.advice_bus.host, config.advice_bus.port, advice_auth.clone()),
+        };
+        match sender {
             Ok(sender) => {
-                info!("✅ Advisory Sender: Connected to {}:{}", 
-                    config.advice_bus.host, config.advice_bus.port);
-                Some(sender)
+                match &config.advice_bus.unix_socket_path {
+                    Some(path) => info!("✅ Advisory Sender: Connected to {} (Unix socket)", path),
+                    None => info!("✅ Advisory Sender: Connected to {}:{}",
+                        config.advice_bus.host, config.advice_bus.port),
+                }
+                let rate_limit = &config.advice_bus.rate_limit;
+                if rate_limit.enabled {
+                    info!("🚦 Advisory rate limit: {:.1}/s per mint (burst {:.0}, coalesce {}ms, tier-A exempt: {})",
+                        rate_limit.rate_per_sec, rate_limit.burst, rate_limit.coalesce_window_ms, rate_limit.exempt_tier_a);
+                }
+                let sender = sender.with_rate_limit(rate_limit).with_clock(clock.clone());
+                let shadow = &config.shadow_advisory;
+                if shadow.enabled {
+                    let shadow_advisor = ShadowAdvisor::open(shadow)?;
+                    info!("👥 Shadow advisory: ON (shadow-only decisions → {}, never sent)", shadow.log_path);
+                    Some(sender.with_shadow(Arc::new(shadow_advisor)))
+                } else {
+                    Some(sender)
+                }
             }
             Err(e) => {
                 warn!("⚠️  Advisory Sender: Failed to initialize: {}", e);
@@ -104,64 +216,146 @@ async fn main() -> Result<()> {
     };
 
     // Initialize WindowAggregator for time-series window computation
-    let window_aggregator = WindowAggregator::new(config.windows.intervals.clone());
-    info!("📊 Window Aggregator: Intervals {:?}", config.windows.intervals);
+    let window_intervals = config.windows.validate()?;
+    let missing_intervals = config.windows.missing_required();
+    if !missing_intervals.is_empty() {
+        warn!("⚠️  [windows] intervals {:?} missing {:?} (read by Brain's mint cache) - using {:?}",
+            config.windows.intervals, missing_intervals, window_intervals);
+    }
+    let window_aggregator = WindowAggregator::new(window_intervals.clone()).with_sybil(config.sybil.clone());
+    info!("📊 Window Aggregator: Intervals {:?}", window_intervals);
+    if config.sybil.enabled {
+        info!("🕵️  Sybil dampening: fresh wallets weigh {} in filtered unique buyers (history {}s)",
+            config.sybil.fresh_wallet_weight, config.sybil.wallet_history_secs);
+    }
 
     // Initialize BrainSignalSender for market intelligence (optional - gracefully handles if brain is offline)
     let brain_signal_sender = if config.advice_bus.enabled {
-        let sender = data_mining::udp::BatchedBrainSignalSender::new(
-            udp_batch_tx.clone(),
-            &config.advice_bus.host,
-            45100  // Brain listens on 45100 for all advice messages including WindowMetrics
-        );
-        info!("✅ Brain Signal Sender: Connected to {}:45100 (batched)", config.advice_bus.host);
+        let sender = match &config.advice_bus.unix_socket_path {
+            Some(path) => {
+                info!("✅ Brain Signal Sender: Connected to {} (Unix socket, batched)", path);
+                BatchedBrainSignalSender::new_unix(udp_batch_tx.clone(), path, advice_auth.clone())
+            }
+            None => {
+                info!("✅ Brain Signal Sender: Connected to {}:45100 (batched)", config.advice_bus.host);
+                BatchedBrainSignalSender::new(
+                    udp_batch_tx.clone(),
+                    &config.advice_bus.host,
+                    45100,  // Brain listens on 45100 for all advice messages including WindowMetrics
+                    advice_auth.clone(),
+                )
+            }
+        };
         Some(sender)
     } else {
         info!("ℹ️  Brain Signal Sender: DISABLED in config");
         None
     };
 
-    // Initialize MomentumTracker for real-time pattern detection
-    // Parameters: momentum_threshold (3 buys in 500ms), spike_multiplier (5x volume), cooldown_ms (5000ms)
+    // Initialize MomentumTracker for real-time pattern detection ([momentum] config section)
+    let momentum_config = &config.momentum;
     let momentum_tracker = Arc::new(Mutex::new(
-        MomentumTracker::new(3, 5.0, 5000)
+        MomentumTracker::from_config(momentum_config).with_clock(clock.clone())
     ));
-    info!("📈 Momentum Tracker: Initialized (threshold=3 buys/500ms, spike=5x, cooldown=5s)");
+    info!("📈 Momentum Tracker: Initialized (threshold={} buys/{}ms, spike={}x, cooldown={}ms)",
+        momentum_config.threshold_buys, momentum_config.window_ms,
+        momentum_config.spike_multiplier, momentum_config.cooldown_ms);
 
     // Initialize WindowTracker for sliding window analytics
-    // Parameters: send_interval_ms (500ms), min_activity_threshold (3 trades)
     let window_tracker = Arc::new(Mutex::new(
-        data_mining::window_tracker::WindowTracker::new(500, 3)
+        data_mining::window_tracker::WindowTracker::new(
+            momentum_config.metrics_interval_ms,
+            momentum_config.min_activity_trades,
+        ).with_clock(clock.clone()).with_sybil(config.sybil.clone())
     ));
-    info!("📊 Window Tracker: Initialized (interval=500ms, min_activity=3 trades)");
+    info!("📊 Window Tracker: Initialized (interval={}ms, min_activity={} trades)",
+        momentum_config.metrics_interval_ms, momentum_config.min_activity_trades);
+
+    // 🧹 Periodically evict idle mints so tracker memory stays bounded on multi-day runs
+    {
+        let momentum_tracker = momentum_tracker.clone();
+        let window_tracker = window_tracker.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(TRACKER_PRUNE_INTERVAL_SECS));
+            loop {
+                interval.tick().await;
+                let (momentum_pruned, momentum_live) = {
+                    let mut tracker = momentum_tracker.lock().unwrap();
+                    (tracker.prune_inactive(TRACKER_MAX_IDLE_MS), tracker.mint_count())
+                };
+                let (window_pruned, window_live) = {
+                    let mut tracker = window_tracker.lock().unwrap();
+                    (tracker.prune_inactive(TRACKER_MAX_IDLE_MS), tracker.window_count())
+                };
+                info!("🧹 Tracker prune: momentum {} live (-{}), window {} live (-{})",
+                    momentum_live, momentum_pruned, window_live, window_pruned);
+            }
+        });
+    }
 
     // � Initialize Latency Tracker for performance monitoring
     let latency_tracker = Arc::new(Mutex::new(data_mining::latency_tracker::LatencyTracker::new()));
     data_mining::latency_tracker::spawn_latency_reporter(latency_tracker.clone());
 
     // �🔮 Spawn Pyth SOL/USD Price Fetcher (HTTP API with SQLite logging)
-    let _pyth_handle = data_mining::pyth_http::spawn_pyth_http(Some(db.clone()));
-    info!("🔮 Pyth HTTP fetcher spawned - broadcasting to ports 45100 & 45110");
+    // Skipped in replay mode - live prices would not match replayed trades
+    if replay_options.is_none() {
+        let _pyth_handle = data_mining::pyth_http::spawn_pyth_http(
+            Some(db.clone()),
+            config.price_oracle.clone(),
+            advice_auth.clone(),
+        );
+        info!("🔮 Pyth HTTP fetcher spawned - broadcasting to ports 45100 & 45110");
+        let _pyth_cleanup_handle = data_mining::pyth_http::spawn_pyth_price_cleanup(
+            db.clone(),
+            &config.price_oracle,
+        );
+    }
 
     // 🎯 Spawn Hotlist Scorer for 1M+ MC hunting
-    let hotlist_config = data_mining::hotlist_scorer::HotlistScorerConfig::default();
+    let hotlist_config = config.hotlist.clone();
+    info!(
+        "🎯 Hotlist Scorer: Spawning (scoring every {}s, broadcasting top {} with score ≥{:.1})",
+        hotlist_config.scoring_interval_sec,
+        hotlist_config.max_broadcast_per_cycle,
+        hotlist_config.min_broadcast_score
+    );
     let _hotlist_handle = data_mining::hotlist_scorer::spawn_hotlist_scorer(
         db.clone(),
         advisory_sender.clone(),
         window_tracker.clone(),
         hotlist_config,
+        clock.clone(),
     );
-    info!("🎯 Hotlist Scorer: Spawned (scoring every 5s, broadcasting score ≥6.0)");
+
+    if let (Some(options), Some(replay_clock)) = (replay_options, replay_clock) {
+        return run_replay(
+            options,
+            &config.database.path,
+            replay_clock,
+            &db,
+            &tracked_wallets,
+            &advisory_sender,
+            &brain_signal_sender,
+            &momentum_tracker,
+            &window_tracker,
+            &config.late_opportunity,
+            &config.advice_bus,
+            &launch_times,
+        )
+        .await;
+    }
 
     // Main processing loop with auto-reconnect
     loop {
         info!("🔌 Connecting to gRPC: {}", config.grpc.endpoint);
         
         match run_unified_collector(
-            &mut checkpoint,
-            checkpoint_path,
+            &checkpoint,
+            &checkpoint_writer,
+            config.checkpoint.save_interval,
             &config.grpc.endpoint,
-            &pump_program,
+            &programs,
             db.clone(),
             &db_writer_tx,
             &udp_batch_tx,
@@ -170,9 +364,12 @@ async fn main() -> Result<()> {
             brain_signal_sender.clone(),
             momentum_tracker.clone(),
             window_tracker.clone(),
+            &config.late_opportunity,
+            &config.advice_bus,
             &window_aggregator,
             latency_tracker.clone(),
             token_cache.clone(),
+            &launch_times,
         )
         .await
         {
@@ -181,39 +378,165 @@ async fn main() -> Result<()> {
             }
             Err(e) => {
                 error!("Stream error: {}, reconnecting in 5s...", e);
+                // Don't lose progress since the last periodic save if we go down while reconnecting
+                let snapshot = checkpoint.lock().unwrap().snapshot();
+                checkpoint_writer.submit(snapshot);
                 tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
             }
         }
     }
 }
-/// Get display name for wallet (alias if available, otherwise short address)
-fn get_wallet_display_name(wallet: &str, tracked_wallets: &HashMap<String, Option<String>>) -> String {
-    if let Some(Some(alias)) = tracked_wallets.get(wallet) {
-        alias.clone()
+
+/// Parse `backfill-windows <mint> <from_unix> <to_unix>` from the command line
+fn parse_backfill_args<I: IntoIterator<Item = String>>(args: I) -> Result<Option<(String, i64, i64)>> {
+    let mut args = args.into_iter().skip(1);
+    if args.next().as_deref() != Some("backfill-windows") {
+        return Ok(None);
+    }
+
+    let usage = "usage: data-mining backfill-windows <mint> <from_unix> <to_unix>";
+    let mint = args.next().context(usage)?;
+    let from_time = args.next().context(usage)?;
+    let to_time = args.next().context(usage)?;
+
+    Ok(Some((
+        mint,
+        from_time.parse().with_context(|| format!("Invalid backfill from time: {}", from_time))?,
+        to_time.parse().with_context(|| format!("Invalid backfill to time: {}", to_time))?,
+    )))
+}
+
+/// Parse `wallet-leaderboard <out.csv|out.json> [limit] [min_trades]` from the command line
+fn parse_leaderboard_args<I: IntoIterator<Item = String>>(args: I) -> Result<Option<(String, usize, u32)>> {
+    let mut args = args.into_iter().skip(1);
+    if args.next().as_deref() != Some("wallet-leaderboard") {
+        return Ok(None);
+    }
+
+    let out_path = args.next()
+        .context("usage: data-mining wallet-leaderboard <out.csv|out.json> [limit] [min_trades]")?;
+    let limit = match args.next() {
+        Some(v) => v.parse().with_context(|| format!("Invalid leaderboard limit: {}", v))?,
+        None => 100,
+    };
+    let min_trades = match args.next() {
+        Some(v) => v.parse().with_context(|| format!("Invalid leaderboard min_trades: {}", v))?,
+        None => 10,
+    };
+
+    Ok(Some((out_path, limit, min_trades)))
+}
+
+/// Write the leaderboard as JSON (`.json` path) or CSV (anything else)
+fn export_wallet_leaderboard(board: &[data_mining::db::WalletStats], out_path: &str) -> Result<()> {
+    let body = if out_path.ends_with(".json") {
+        serde_json::to_string_pretty(board)?
     } else {
-        format!("{}", &wallet[..8])
+        let mut csv = String::from(data_mining::db::WalletStats::CSV_HEADER);
+        csv.push('\n');
+        for stats in board {
+            csv.push_str(&stats.to_csv_row());
+            csv.push('\n');
+        }
+        csv
+    };
+
+    std::fs::write(out_path, body)
+        .with_context(|| format!("Failed to write leaderboard to {}", out_path))
+}
+
+/// Get display name for a tracked wallet (alias if available, otherwise short address).
+/// Returns None if the wallet is not tracked; the map lock is released before returning.
+fn get_wallet_display_name(wallet: &str, tracked_wallets: &TrackedWallets) -> Option<String> {
+    match tracked_wallets.lock().unwrap().get(wallet) {
+        Some(Some(alias)) => Some(alias.clone()),
+        Some(None) => Some(format!("{}", &wallet[..8])),
+        None => None,
     }
 }
 
 
+/// Replay recorded trades from SQLite through the momentum/window pipeline
+async fn run_replay(
+    options: ReplayOptions,
+    db_path: &str,
+    replay_clock: Arc<ReplayClock>,
+    db: &Arc<Mutex<Database>>,
+    tracked_wallets: &TrackedWallets,
+    advisory_sender: &Option<AdvisorySender>,
+    brain_signal_sender: &Option<BatchedBrainSignalSender>,
+    momentum_tracker: &Arc<Mutex<MomentumTracker>>,
+    window_tracker: &Arc<Mutex<data_mining::window_tracker::WindowTracker>>,
+    late_opportunity: &LateOpportunityConfig,
+    advice_bus: &AdviceBusConfig,
+    launch_times: &LaunchTimeCache,
+) -> Result<()> {
+    let source = ReplaySource::open(db_path, options.clone())?;
+    let total = source.trade_count()?;
+    info!("⏪ Replaying {} trades from {} at {}x (from={:?}, to={:?})",
+        total, db_path,
+        if options.speed > 0.0 { options.speed.to_string() } else { "max".to_string() },
+        options.from_time, options.to_time);
+
+    let mut pacer = ReplayPacer::new(replay_clock, options.speed);
+    let mut replayed = 0u64;
+
+    for item in source {
+        let replay_event = item?;
+        pacer.advance_to(replay_event.timestamp_ms).await;
+
+        if let PumpEvent::Trade { mint, side, trader, amount_sol, price, .. } = replay_event.event {
+            process_trade_signals(
+                &mint,
+                side == TradeSide::Buy,
+                amount_sol as f64 / 1_000_000_000.0,
+                price,
+                &trader,
+                replay_event.timestamp_ms,
+                db,
+                tracked_wallets,
+                advisory_sender,
+                brain_signal_sender,
+                momentum_tracker,
+                window_tracker,
+                late_opportunity,
+                advice_bus,
+                launch_times,
+            );
+        }
+
+        replayed += 1;
+        if replayed % 10_000 == 0 {
+            info!("⏪ Replay progress: {}/{} trades", replayed, total);
+        }
+    }
+
+    info!("✅ Replay complete: {} trades", replayed);
+    Ok(())
+}
+
 /// Main unified collector - processes ALL pump.fun transactions
 /// Handles both launch tracking AND wallet tracking in the same stream
 async fn run_unified_collector(
-    checkpoint: &mut Checkpoint,
-    checkpoint_path: &str,
+    checkpoint: &SharedCheckpoint,
+    checkpoint_writer: &CheckpointWriter,
+    checkpoint_slot_interval: u64,
     endpoint: &str,
-    pump_program: &Pubkey,
+    programs: &ProgramRouter,
     db: Arc<Mutex<Database>>,
-    db_writer_tx: &tokio::sync::mpsc::Sender<data_mining::db::DbWriteCommand>,
+    db_writer_tx: &data_mining::db::DbWriterHandle,
     udp_batch_tx: &tokio::sync::mpsc::UnboundedSender<data_mining::udp::UdpMessage>,
-    tracked_wallets: &HashMap<String, Option<String>>,
+    tracked_wallets: &TrackedWallets,
     advisory_sender: Option<AdvisorySender>,
     brain_signal_sender: Option<BatchedBrainSignalSender>,
     momentum_tracker: Arc<Mutex<MomentumTracker>>,
     window_tracker: Arc<Mutex<data_mining::window_tracker::WindowTracker>>,
+    late_opportunity: &LateOpportunityConfig,
+    advice_bus: &AdviceBusConfig,
     window_aggregator: &WindowAggregator,
     latency_tracker: Arc<Mutex<data_mining::latency_tracker::LatencyTracker>>,
-    token_cache: Arc<Mutex<std::collections::HashSet<String>>>,
+    token_cache: Arc<Mutex<TokenCache>>,
+    launch_times: &LaunchTimeCache,
 ) -> Result<()> {
     // Connect to Yellowstone gRPC
     let mut client = GeyserGrpcClient::build_from_shared(endpoint.to_string())?
@@ -223,7 +546,7 @@ async fn run_unified_collector(
 
     info!("✅ Connected to Yellowstone gRPC");
 
-    // Subscribe to ALL Pump.fun transactions
+    // Subscribe to ALL transactions of every tracked program (pump.fun + extra_programs)
     let mut transactions: HashMap<String, SubscribeRequestFilterTransactions> = HashMap::new();
     transactions.insert(
         "pump_transactions".to_string(),
@@ -231,15 +554,25 @@ async fn run_unified_collector(
             vote: Some(false),
             failed: Some(false),
             signature: None,
-            account_include: vec![pump_program.to_string()],
+            account_include: programs.program_ids().to_vec(),
             account_exclude: vec![],
             account_required: vec![],
         },
     );
 
+    // Slot status updates (all commitments) for reorg detection
+    let mut slots: HashMap<String, SubscribeRequestFilterSlots> = HashMap::new();
+    slots.insert(
+        "slot_status".to_string(),
+        SubscribeRequestFilterSlots {
+            filter_by_commitment: Some(false),
+            interslot_updates: Some(false),
+        },
+    );
+
     let request = SubscribeRequest {
         accounts: HashMap::new(),
-        slots: HashMap::new(),
+        slots,
         transactions,
         transactions_status: HashMap::new(),
         blocks: HashMap::new(),
@@ -259,10 +592,11 @@ async fn run_unified_collector(
     let mut launch_count = 0u64;
     let mut wallet_tx_count = 0u64;
     
-    // Dedup cache: Track recently seen transaction signatures
-    // Prevents double-processing on reorgs or confirmed/finalized duplicates
-    let mut seen_signatures: HashSet<String> = HashSet::with_capacity(10000);
-    const MAX_CACHE_SIZE: usize = 50000; // Keep last 50K signatures
+    // Dedup cache: recently seen transaction signatures live in the checkpoint
+    // so they survive restarts (prevents re-emitting signals for re-streamed slots)
+
+    // Processed slots awaiting finalization (per connection; a reconnect starts fresh)
+    let mut reorg_tracker = ReorgTracker::default();
 
     // Process all transactions
     loop {
@@ -276,32 +610,36 @@ async fn run_unified_collector(
                                 if let Some(tx_data) = &transaction.transaction {
                                     let sig = bs58::encode(&tx_data.signatures[0]).into_string();
                                     
-                                    // Skip if already processed (reorg/duplicate)
-                                    if seen_signatures.contains(&sig) {
+                                    // Skip if already processed (reorg/duplicate/restart),
+                                    // otherwise add to seen cache (oldest entries are evicted automatically)
+                                    let duplicate = {
+                                        let mut checkpoint = checkpoint.lock().unwrap();
+                                        let seen = checkpoint.contains_signature(&sig);
+                                        if !seen {
+                                            checkpoint.record_signature(&sig);
+                                        }
+                                        seen
+                                    };
+                                    if duplicate {
                                         debug!("⏭️  Skipping duplicate transaction: {}", &sig[..12]);
                                         continue;
                                     }
-                                    
-                                    // Add to seen cache
-                                    seen_signatures.insert(sig.clone());
-                                    
-                                    // Prune cache if it gets too large
-                                    if seen_signatures.len() > MAX_CACHE_SIZE {
-                                        // Remove oldest 10K entries (simple approach: clear and rebuild)
-                                        debug!("🗑️  Pruning signature cache ({} entries)", seen_signatures.len());
-                                        seen_signatures.clear();
-                                    }
+                                    reorg_tracker.record(tx_update.slot, &sig);
                                 }
                             }
                             
                             tx_count += 1;
-
-                            // Update checkpoint
-                            checkpoint.update(tx_update.slot);
-
-                            // Save checkpoint periodically (every 1000 slots)
-                            if let Err(e) = checkpoint.save_if_needed(checkpoint_path, tx_update.slot, 1000) {
-                                warn!("Failed to save checkpoint: {}", e);
+                            metrics::record_tx_processed();
+                            data_mining::health::record_tx();
+
+                            // Update checkpoint, saving every N slots (the saver task covers every N seconds)
+                            let snapshot = {
+                                let mut checkpoint = checkpoint.lock().unwrap();
+                                checkpoint.update(tx_update.slot);
+                                checkpoint.snapshot_if_due(checkpoint_slot_interval)
+                            };
+                            if let Some(snapshot) = snapshot {
+                                checkpoint_writer.submit(snapshot);
                             }
 
                             // Process transaction for BOTH systems
@@ -310,17 +648,20 @@ async fn run_unified_collector(
                                 &db,
                                 &db_writer_tx,
                                 &udp_batch_tx,
-                                pump_program,
+                                programs,
                                 tracked_wallets,
                                 &advisory_sender,
                                 &brain_signal_sender,
                                 &momentum_tracker,
                                 &window_tracker,
+                                late_opportunity,
+                                advice_bus,
                                 &mut launch_count,
                                 &mut wallet_tx_count,
                                 window_aggregator,
                                 &latency_tracker,
                                 &token_cache,
+                                launch_times,
                             )
                             .await
                             {
@@ -330,6 +671,12 @@ async fn run_unified_collector(
                                 }
                             }
 
+                            // Cache gauges (writer tier depths are set by the DB writer)
+                            if tx_count % 100 == 0 {
+                                metrics::set_dedup_cache_size(checkpoint.lock().unwrap().signature_count());
+                                metrics::set_token_cache_size(token_cache.lock().unwrap().len());
+                            }
+
                             // Progress logging
                             if tx_count % 100 == 0 {
                                 info!(
@@ -338,6 +685,35 @@ async fn run_unified_collector(
                                 );
                             }
                         }
+                        UpdateOneof::Slot(slot_update) => {
+                            // 🔀 REORG DETECTION: roll back trades from slots that left the canonical chain
+                            let state = if slot_update.status == SlotStatus::SlotFinalized as i32 {
+                                SlotState::Finalized
+                            } else if slot_update.status == SlotStatus::SlotDead as i32 {
+                                SlotState::Dead
+                            } else {
+                                SlotState::Other
+                            };
+
+                            if let Some(rollback) = reorg_tracker.on_slot_status(slot_update.slot, state) {
+                                warn!("🔀 REORG: {} orphaned slot(s) {:?} ({} txs) - rolling back",
+                                    rollback.slots.len(), rollback.slots, rollback.signatures.len());
+
+                                let first_slot = rollback.slots[0];
+                                if db_writer_tx.send(data_mining::db::DbWriteCommand::DeleteTradesInSlots(rollback.slots)).is_err() {
+                                    warn!("❌ DB writer channel closed!");
+                                }
+                                let snapshot = {
+                                    let mut checkpoint = checkpoint.lock().unwrap();
+                                    checkpoint.forget_signatures(&rollback.signatures);
+                                    checkpoint.rewind_before(first_slot);
+                                    checkpoint.snapshot_if_dirty()
+                                };
+                                if let Some(snapshot) = snapshot {
+                                    checkpoint_writer.submit(snapshot);
+                                }
+                            }
+                        }
                         _ => {
                             // Ignore other update types
                         }
@@ -360,19 +736,22 @@ async fn run_unified_collector(
 async fn process_transaction(
     tx: &SubscribeUpdateTransaction,
     db: &Arc<Mutex<Database>>,
-    db_writer: &tokio::sync::mpsc::Sender<data_mining::db::DbWriteCommand>,
+    db_writer: &data_mining::db::DbWriterHandle,
     udp_batch_tx: &tokio::sync::mpsc::UnboundedSender<data_mining::udp::UdpMessage>,
-    pump_program: &Pubkey,
-    tracked_wallets: &HashMap<String, Option<String>>,
+    programs: &ProgramRouter,
+    tracked_wallets: &TrackedWallets,
     advisory_sender: &Option<AdvisorySender>,
     brain_signal_sender: &Option<BatchedBrainSignalSender>,
     momentum_tracker: &Arc<Mutex<MomentumTracker>>,
     window_tracker: &Arc<Mutex<data_mining::window_tracker::WindowTracker>>,
+    late_opportunity: &LateOpportunityConfig,
+    advice_bus: &AdviceBusConfig,
     launch_count: &mut u64,
     wallet_tx_count: &mut u64,
     window_aggregator: &WindowAggregator,
     latency_tracker: &Arc<Mutex<data_mining::latency_tracker::LatencyTracker>>,
-    token_cache: &Arc<Mutex<std::collections::HashSet<String>>>,
+    token_cache: &Arc<Mutex<TokenCache>>,
+    launch_times: &LaunchTimeCache,
 ) -> Result<()> {
     // 📊 TIMESTAMP 1: Transaction created (from gRPC)
     let created_ns = data_mining::latency_tracker::now_ns();
@@ -394,29 +773,23 @@ async fn process_transaction(
     // Get fee payer (first account = actual trader)
     let fee_payer = account_keys.get(0).map(|s| s.as_str());
 
-    // Parse Pump.fun events
-    let parser = PumpParser::new(&pump_program.to_string())?;
-
-    // Raydium CPMM program for graduated tokens
-    let raydium_program = Pubkey::from_str("CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C")?;
-    let raydium_parser = RaydiumParser::new(&raydium_program.to_string())?;
-    
     // Use current UTC timestamp as block_time
     // Note: Yellowstone gRPC transactions don't include block_time (only blocks do)
     // This is accurate to within ~400ms on a well-synced server
     let block_time = chrono::Utc::now().timestamp();
     
-    let pump_events = parser.parse_transaction(transaction, tx.slot, block_time)?;
-
-        // Also check for Raydium swaps (graduated tokens)
-        let raydium_events = raydium_parser.parse_transaction(transaction, tx.slot, block_time)?;
-        let mut all_events = pump_events;
-        all_events.extend(raydium_events);
+    // Each tracked program's instructions go to its own parser (Pump, PumpAMM, Raydium)
+    let all_events = programs.parse_transaction(transaction, tx.slot, block_time)?;
     
     if all_events.is_empty() {
-        return Ok(()); // No pump.fun events
+        return Ok(()); // No events from tracked programs
     }
 
+    // 📊 Stage timings, recorded together with end-to-end (one tracker lock)
+    let parse_ns = data_mining::latency_tracker::now_ns() - created_ns;
+    let mut trade_stages: Vec<TradeStageTimings> = Vec::new();
+    let mut advisory_ns: Vec<u64> = Vec::new();
+
     // Get balance changes for SOL amount calculations
     let pre_balances = &meta.pre_balances;
     let post_balances = &meta.post_balances;
@@ -452,15 +825,14 @@ async fn process_transaction(
             PumpEvent::Launch { mint, creator, bonding_curve, name, symbol, uri, slot, block_time, signature } => {
                 // 🚀 LAUNCH TRACKING: New token created
                 *launch_count += 1;
+                metrics::record_launch();
+                launch_times.insert(&mint, block_time);
 
-                // Check in-memory cache first (avoid DB read)
-                let token_exists = token_cache.lock().unwrap().contains(&mint);
-                if !token_exists {
+                // Check in-memory cache first (avoid DB read); added immediately if new
+                let is_new_token = token_cache.lock().unwrap().insert(&mint);
+                if is_new_token {
                     info!("🆕 NEW LAUNCH: {} by {}", &mint[..12], &creator[..8]);
 
-                    // Add to cache immediately (avoid duplicate checks)
-                    token_cache.lock().unwrap().insert(mint.clone());
-
                     // Insert token into database
                     let token = Token {
                         mint: mint.clone(),
@@ -487,16 +859,9 @@ async fn process_transaction(
                         observed_at: chrono::Utc::now().timestamp(),
                     };
 
-                    // Send to async writer with back-pressure handling
-                    if let Err(e) = db_writer.try_send(data_mining::db::DbWriteCommand::InsertToken(token)) {
-                        match e {
-                            tokio::sync::mpsc::error::TrySendError::Full(_) => {
-                                warn!("⚠️  DB writer channel full, dropping CREATE token (back-pressure)");
-                            }
-                            tokio::sync::mpsc::error::TrySendError::Closed(_) => {
-                                warn!("❌ DB writer channel closed!");
-                            }
-                        }
+                    // Send to async writer (high priority - CREATE tokens are never shed)
+                    if db_writer.send(data_mining::db::DbWriteCommand::InsertToken(token)).is_err() {
+                        warn!("❌ DB writer channel closed!");
                     } else {
                         debug!("✅ Queued CREATE token {}", &mint[..12]);
                     }
@@ -507,15 +872,23 @@ async fn process_transaction(
                 info!("🆕 New wallet discovered (creator): {}", &creator[..8]);
 
                 // Check if creator is tracked wallet
-                if tracked_wallets.contains_key(&creator) {
-                    let display_name = get_wallet_display_name(&creator, tracked_wallets);
+                if let Some(display_name) = get_wallet_display_name(&creator, tracked_wallets) {
                     info!("🔥 TRACKED WALLET CREATED TOKEN: {} by {}", &mint[..12], display_name);
                     
-                    // Send CopyTrade advisory (99% confidence - creator knows what they're doing)
-                    // side=0 (BUY), size=0.0 (unknown at launch), tier=3 (assume A-tier creator)
+                    // Send CopyTrade advisory scaled by the creator's launch history
+                    // side=0 (BUY), size=0.0 (unknown at launch)
                     if let Some(sender) = advisory_sender {
-                        if let Err(e) = sender.send_copy_trade(&mint, &creator, 0, 0.0, 3, 99) {
-                            warn!("Failed to send CopyTrade advisory: {}", e);
+                        let creator_stats = db.lock().unwrap().get_creator_stats(&creator).ok().flatten();
+                        match tracked_wallets::creator_copy_signal(creator_stats) {
+                            Some((tier, confidence)) => {
+                                if let Err(e) = sender.send_copy_trade(&mint, &creator, 0, 0.0, tier, confidence) {
+                                    warn!("Failed to send CopyTrade advisory: {}", e);
+                                }
+                            }
+                            None => {
+                                info!("🚫 Skipping launch CopyTrade for {}: creator history {:?} (net_pnl, launches)",
+                                    display_name, creator_stats);
+                            }
                         }
                     }
                 }
@@ -541,11 +914,10 @@ async fn process_transaction(
 
                 // Ensure token exists before inserting trade (FK constraint)
                 // Check in-memory cache instead of DB
-                let token_exists = token_cache.lock().unwrap().contains(&mint);
+                let is_new_token = token_cache.lock().unwrap().insert(&mint);
                 
-                if !token_exists {
-                    // Token doesn't exist - create placeholder and add to cache
-                    token_cache.lock().unwrap().insert(mint.clone());
+                if is_new_token {
+                    // Token doesn't exist - create placeholder (already added to cache)
                     
                     let token = Token {
                         mint: mint.clone(),
@@ -573,10 +945,11 @@ async fn process_transaction(
                     };
                     
                     // Send to async writer with back-pressure (placeholder token is low priority)
-                    if let Err(e) = db_writer.try_send(data_mining::db::DbWriteCommand::InsertToken(token)) {
+                    if let Err(e) = db_writer.try_send_low(data_mining::db::DbWriteCommand::InsertToken(token)) {
                         match e {
                             tokio::sync::mpsc::error::TrySendError::Full(_) => {
-                                debug!("⚠️  DB writer channel full, dropping placeholder token (back-pressure)");
+                                debug!("⚠️  DB writer low-priority channel full, dropping placeholder token (back-pressure)");
+                                metrics::record_dropped(DropKind::PlaceholderToken);
                             }
                             _ => {}
                         }
@@ -585,14 +958,9 @@ async fn process_transaction(
                     }
                 }
 
-                // Send trade to async writer (CRITICAL - never drop trades!)
-                if let Err(e) = db_writer.try_send(data_mining::db::DbWriteCommand::InsertTrade(trade.clone())) {
-                    match e {
-                        tokio::sync::mpsc::error::TrySendError::Full(_) => {
-                            warn!("⚠️  DB writer channel FULL! Dropping TRADE (back-pressure) - this should not happen");
-                        }
-                        _ => {}
-                    }
+                // Send trade to async writer (CRITICAL - high priority, never shed)
+                if db_writer.send(data_mining::db::DbWriteCommand::InsertTrade(trade.clone())).is_err() {
+                    warn!("❌ DB writer channel closed, TRADE lost!");
                 }
                 
                 // � TIMESTAMP 2: DB enqueued
@@ -607,13 +975,14 @@ async fn process_transaction(
                 if virtual_sol_reserves > 0 {
                     let initial_liq_sol = (virtual_sol_reserves as f64) / 1e9;
                     // Send to async writer (low priority - can drop on back-pressure)
-                    if let Err(e) = db_writer.try_send(data_mining::db::DbWriteCommand::UpdateInitialLiquidity {
+                    if let Err(e) = db_writer.try_send_low(data_mining::db::DbWriteCommand::UpdateInitialLiquidity {
                         mint: mint.clone(),
                         liquidity_sol: initial_liq_sol,
                     }) {
                         match e {
                             tokio::sync::mpsc::error::TrySendError::Full(_) => {
-                                debug!("⚠️  DB writer channel full, dropping liquidity update (back-pressure)");
+                                debug!("⚠️  DB writer low-priority channel full, dropping liquidity update (back-pressure)");
+                                metrics::record_dropped(DropKind::Liquidity);
                             }
                             _ => {}
                         }
@@ -622,136 +991,25 @@ async fn process_transaction(
                     }
                 }
                 
-                // 📈 MOMENTUM TRACKING: Record trade and check for signals
-                {
-                    let side_enum = if is_buy { TradeSide::Buy } else { TradeSide::Sell };
-                    let mut tracker = momentum_tracker.lock().unwrap();
-                    
-                    // Record trade in rolling window
-                    tracker.record_trade(&mint, side_enum, amount_sol as f64, &trader);
-                    
-                    // Check for momentum signal (≥3 buys in 500ms)
-                    if let Some(signal) = tracker.check_momentum(&mint) {
-                        if let Some(ref sender) = brain_signal_sender {
-                            let _ = sender.send_momentum_detected(
-                                &mint,
-                                signal.buys_in_last_500ms,
-                                signal.volume_sol as f32,
-                                signal.unique_buyers,
-                                signal.confidence,
-                            );
-                            debug!("📈 Momentum signal sent: {} ({} buys, {:.2} SOL, {} buyers, conf={})",
-                                &mint[..12], signal.buys_in_last_500ms, signal.volume_sol,
-                                signal.unique_buyers, signal.confidence);
-                        }
-                    }
-                    
-                    // Check for volume spike (current > 5x average)
-                    if let Some(signal) = tracker.check_volume_spike(&mint) {
-                        if let Some(ref sender) = brain_signal_sender {
-                            let _ = sender.send_volume_spike(
-                                &mint,
-                                signal.total_sol,
-                                signal.tx_count,
-                                signal.time_window_ms,
-                                signal.confidence,
-                            );
-                            debug!("🔥 Volume spike sent: {} ({:.2} SOL in {}ms, {} txs, conf={})",
-                                &mint[..12], signal.total_sol, signal.time_window_ms,
-                                signal.tx_count, signal.confidence);
-                        }
-                    }
-                } // Drop momentum_tracker lock
-
-                // 📊 WINDOW TRACKING: Record trade and check for metrics to send
-                {
-                    let current_time_ms = std::time::SystemTime::now()
-                        .duration_since(std::time::UNIX_EPOCH)
-                        .unwrap()
-                        .as_millis() as u64;
-                    
-                    let is_alpha_wallet = tracked_wallets.contains_key(&trader);
-                    
-                    // Calculate market cap (approximate using 1B supply for now)
-                    // TODO: Get actual supply from token metadata
-                    let supply_estimate = 1_000_000_000.0; // 1 billion tokens
-                    let mc_sol = price * supply_estimate;
-                    
-                    let mut tracker = window_tracker.lock().unwrap();
-                    
-                    // Update MC history for velocity tracking
-                    tracker.update_mc(&mint, current_time_ms, mc_sol);
-                    
-                    tracker.add_trade(
-                        &mint,
-                        current_time_ms,
-                        amount_sol as f64 / 1_000_000_000.0,
-                        price,
-                        &trader,
-                        is_alpha_wallet,
-                    );
-                    
-                    // Check if metrics should be sent (throttled to avoid spam)
-                    if let Some(metrics) = tracker.get_metrics_if_ready(&mint, mc_sol) {
-                        if let Some(ref sender) = brain_signal_sender {
-                            let _ = sender.send_window_metrics(
-                                &mint,
-                                metrics.volume_sol_1s,
-                                metrics.unique_buyers_1s,
-                                metrics.price_change_bps_2s,
-                                metrics.alpha_wallet_hits_10s,
-                            );
-                            
-                            // Log MC velocity for high-velocity tokens
-                            if metrics.mc_velocity_sol_per_min > 1000.0 {
-                                info!("🚀 High MC velocity: {} | {:.0} SOL/min | MC: {:.0} SOL",
-                                    &mint[..12],
-                                    metrics.mc_velocity_sol_per_min,
-                                    metrics.mc_sol);
-                            }
-                        }
-                        
-                        // Check for late opportunity using real-time metrics
-                        // Estimate 60s metrics from 1s data (conservative)
-                        let vol_60s_estimate = metrics.volume_sol_1s * 20.0; // Assume sustained
-                        let buyers_60s_estimate = metrics.unique_buyers_1s as u32 * 10; // Conservative
-                        
-                        // Get token age from launch tracking
-                        let current_time = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i64;
-                        if let Ok(launch_ts) = db.lock().unwrap().get_token_launch_time(&mint) {
-                            if let Some(launch_time) = launch_ts {
-                                let age_seconds = (current_time - launch_time).max(0) as u64;
-                                
-                                // Late opportunity criteria:
-                                // - Age: 20 min to 2 hours
-                                // - Volume: >= 0.5 SOL/s sustained (10 SOL/60s estimate)
-                                // - Buyers: >= 1 buyer/s sustained (10 buyers/60s estimate)
-                                // - Recent activity: Metrics updated in last 2s
-                                if age_seconds > 1200 && age_seconds < 7200 // 20 min to 2 hours
-                                    && vol_60s_estimate >= 10.0
-                                    && buyers_60s_estimate >= 10
-                                {
-                                    // Calculate late opportunity score
-                                    let vol_score = (vol_60s_estimate / 35.0 * 50.0).clamp(0.0, 50.0);
-                                    let buyer_score = ((buyers_60s_estimate as f64 / 40.0) * 30.0).clamp(0.0, 30.0);
-                                    let age_factor = ((age_seconds as f64 / 3600.0) * 20.0).clamp(0.0, 20.0);
-                                    let late_score = (vol_score + buyer_score + age_factor) as u8;
-                                    
-                                    let horizon_sec = 300; // 5 minute opportunity window
-                                    
-                                    if let Some(ref advisory) = advisory_sender {
-                                        if let Err(e) = advisory.send_late_opportunity(&mint, horizon_sec, late_score) {
-                                            warn!("Failed to send LateOpportunity for {}: {}", &mint[..12], e);
-                                        } else {
-                                            info!("🎯 Late opportunity detected: {} | age: {}s | vol: {:.1} SOL/60s | buyers: {} | score: {}",
-                                                &mint[..12], age_seconds, vol_60s_estimate, buyers_60s_estimate, late_score);
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
-                } // Drop window_tracker lock
+                // 📈 MOMENTUM + 📊 WINDOW TRACKING (shared with --replay)
+                let stages = process_trade_signals(
+                    &mint,
+                    is_buy,
+                    amount_sol as f64 / 1_000_000_000.0,
+                    price,
+                    &trader,
+                    SystemClock.now_ms(),
+                    db,
+                    tracked_wallets,
+                    advisory_sender,
+                    brain_signal_sender,
+                    momentum_tracker,
+                    window_tracker,
+                    late_opportunity,
+                    advice_bus,
+                    launch_times,
+                );
+                trade_stages.push(stages);
 
                 // Compute and update windows for this token after trade is recorded
                 // DISABLED: Causes mutex contention in hot path - TODO: move to async task
@@ -776,6 +1034,8 @@ async fn process_transaction(
                     //     &db,
                     //     &advisory_sender,
                     //     block_time,
+                    //     late_opportunity,
+                    //     advice_bus,
                     // );
                 }
                 */
@@ -797,10 +1057,9 @@ async fn process_transaction(
                 info!("🆕 New wallet discovered: {}", &trader[..8]);
 
                 // Check if tracked wallet
-                        if tracked_wallets.contains_key(&trader) {
+                        if let Some(display_name) = get_wallet_display_name(&trader, tracked_wallets) {
                             *wallet_tx_count += 1;
-
-                            let display_name = get_wallet_display_name(&trader, tracked_wallets);
+                            let advisory_start = std::time::Instant::now();
                             
                             // 👤 WALLET ACTIVITY SIGNAL: Send to brain for strategic decisions
                             if let Some(ref sender) = brain_signal_sender {
@@ -851,9 +1110,20 @@ async fn process_transaction(
                                     }
                                 }
                             }
+                            advisory_ns.push(advisory_start.elapsed().as_nanos() as u64);
                         }
             }
 
+            PumpEvent::CurveComplete { mint, slot, block_time, .. } => {
+                // 🎓 Bonding curve filled: migration (and its volatility) is next
+                info!("🎓 CURVE COMPLETE: {} (slot {})", &mint[..12], slot);
+                if let Some(ref sender) = brain_signal_sender {
+                    if let Err(e) = sender.send_curve_complete(&mint, slot, block_time) {
+                        warn!("Failed to send CurveComplete signal: {}", e);
+                    }
+                }
+            }
+
             PumpEvent::Migrated { mint, pool, slot, block_time, signature } => {
                 // Handle migration to Raydium
                 info!("🚀 MIGRATION: {} → Raydium pool {}", &mint[..12], &pool[..12]);
@@ -867,17 +1137,203 @@ async fn process_transaction(
     {
         let mut tracker = latency_tracker.lock().unwrap();
         tracker.end_to_end.record(done_ns - created_ns);
+        tracker.parse.record(parse_ns);
+        for stages in &trade_stages {
+            tracker.record_trade_stages(stages);
+        }
+        for ns in advisory_ns {
+            tracker.advisory.record(ns);
+        }
     }
 
     Ok(())
 }
 
+/// Momentum + window + late-opportunity pipeline for a single trade.
+/// Shared by the live gRPC path and `--replay`; `now_ms` is the trade time
+/// (wall clock live, reconstructed trade timestamp in replay). Returns the
+/// per-stage timings (the live path records them in the LatencyTracker).
+fn process_trade_signals(
+    mint: &str,
+    is_buy: bool,
+    amount_sol: f64,
+    price: f64,
+    trader: &str,
+    now_ms: u64,
+    db: &Arc<Mutex<Database>>,
+    tracked_wallets: &TrackedWallets,
+    advisory_sender: &Option<AdvisorySender>,
+    brain_signal_sender: &Option<BatchedBrainSignalSender>,
+    momentum_tracker: &Arc<Mutex<MomentumTracker>>,
+    window_tracker: &Arc<Mutex<data_mining::window_tracker::WindowTracker>>,
+    late_opportunity: &LateOpportunityConfig,
+    advice_bus: &AdviceBusConfig,
+    launch_times: &LaunchTimeCache,
+) -> TradeStageTimings {
+    let mut timings = TradeStageTimings::default();
+    // Opportunities the live thresholds fired for this trade (shadow mode logs the rest)
+    let mut fired = Vec::new();
+
+    // 📈 MOMENTUM TRACKING: Record trade and check for signals
+    let momentum_start = std::time::Instant::now();
+    {
+        let side_enum = if is_buy { TradeSide::Buy } else { TradeSide::Sell };
+        let mut tracker = momentum_tracker.lock().unwrap();
+        timings.momentum_lock_ns = momentum_start.elapsed().as_nanos() as u64;
+
+        // Record trade in rolling window
+        tracker.record_trade(mint, side_enum, amount_sol, trader);
+
+        // Check for momentum signal (≥threshold buys in the momentum window)
+        if let Some(signal) = tracker.check_momentum(mint) {
+            fired.push(Opportunity::Momentum {
+                vol_5s_sol: signal.volume_sol,
+                buyers_2s: signal.unique_buyers as u32,
+                score: signal.confidence,
+            });
+            if let Some(ref sender) = brain_signal_sender {
+                let _ = sender.send_momentum_detected(
+                    mint,
+                    signal.buys_in_last_500ms,
+                    signal.volume_sol as f32,
+                    signal.unique_buyers,
+                    signal.confidence,
+                );
+                debug!("📈 Momentum signal sent: {} ({} buys, {:.2} SOL, {} buyers, conf={})",
+                    &mint[..12], signal.buys_in_last_500ms, signal.volume_sol,
+                    signal.unique_buyers, signal.confidence);
+            }
+        }
+
+        // Check for volume spike (current > spike_multiplier x average)
+        if let Some(signal) = tracker.check_volume_spike(mint) {
+            if let Some(ref sender) = brain_signal_sender {
+                let _ = sender.send_volume_spike(
+                    mint,
+                    signal.total_sol,
+                    signal.tx_count,
+                    signal.time_window_ms,
+                    signal.confidence,
+                );
+                debug!("🔥 Volume spike sent: {} ({:.2} SOL in {}ms, {} txs, conf={})",
+                    &mint[..12], signal.total_sol, signal.time_window_ms,
+                    signal.tx_count, signal.confidence);
+            }
+        }
+    } // Drop momentum_tracker lock
+    timings.momentum_ns = momentum_start.elapsed().as_nanos() as u64;
+
+    // 📊 WINDOW TRACKING: Record trade and check for metrics to send
+    let window_start = std::time::Instant::now();
+    {
+        let is_alpha_wallet = tracked_wallets.lock().unwrap().contains_key(trader);
+
+        // Calculate market cap (approximate using 1B supply for now)
+        // TODO: Get actual supply from token metadata
+        let supply_estimate = 1_000_000_000.0; // 1 billion tokens
+        let mc_sol = price * supply_estimate;
+
+        let window_lock_start = std::time::Instant::now();
+        let mut tracker = window_tracker.lock().unwrap();
+        timings.window_lock_ns = window_lock_start.elapsed().as_nanos() as u64;
+
+        // Update MC history for velocity tracking
+        tracker.update_mc(mint, now_ms, mc_sol);
+
+        tracker.add_trade(
+            mint,
+            now_ms,
+            amount_sol,
+            price,
+            trader,
+            is_alpha_wallet,
+        );
+
+        // Check if metrics should be sent (throttled to avoid spam)
+        // Scores get the sybil-filtered buyer count (the raw count when [sybil] is off)
+        if let Some(metrics) = tracker.get_metrics_if_ready(mint, mc_sol) {
+            if let Some(ref sender) = brain_signal_sender {
+                let _ = sender.send_window_metrics(
+                    mint,
+                    metrics.volume_sol_1s,
+                    metrics.unique_buyers_1s_filtered,
+                    metrics.price_change_bps_2s,
+                    metrics.alpha_wallet_hits_10s,
+                );
+
+                // Log MC velocity for high-velocity tokens
+                if metrics.mc_velocity_sol_per_min > 1000.0 {
+                    info!("🚀 High MC velocity: {} | {:.0} SOL/min | MC: {:.0} SOL",
+                        &mint[..12],
+                        metrics.mc_velocity_sol_per_min,
+                        metrics.mc_sol);
+                }
+            }
+
+            // Check for late opportunity using real-time metrics
+            // Estimate 60s metrics from 1s data (conservative)
+            let vol_60s_estimate = metrics.volume_sol_1s * opportunity::LIVE_VOL_60S_PER_1S; // Assume sustained
+            let buyers_60s_estimate = metrics.unique_buyers_1s_filtered as u32 * opportunity::LIVE_BUYERS_60S_PER_1S; // Conservative
+
+            // Get token age from launch tracking
+            let current_time = (now_ms / 1000) as i64;
+            // Launch time from memory; DB lock only on a cache miss
+            let launch_time = launch_times
+                .get_or_load(mint, || db.lock().unwrap().get_token_launch_time(mint))
+                .ok()
+                .flatten();
+            if let Some(launch_time) = launch_time {
+                let age_seconds = (current_time - launch_time).max(0) as u64;
+
+                // Late opportunity criteria ([late_opportunity] config); metrics are
+                // only produced for recently active mints
+                if late_opportunity.qualifies(age_seconds, vol_60s_estimate, buyers_60s_estimate) {
+                    let late_score = late_opportunity.score(age_seconds, vol_60s_estimate, buyers_60s_estimate);
+                    let initial_liquidity = db.lock().unwrap().get_initial_liquidity(mint).ok().flatten();
+
+                    if !advice_bus.admits_initial_liquidity(initial_liquidity) {
+                        debug!("🚫 Low-liquidity launch {} ({:?} SOL): LateOpportunity suppressed",
+                            &mint[..12], initial_liquidity);
+                    } else {
+                        fired.push(Opportunity::Late { horizon_secs: late_opportunity.horizon_secs, score: late_score });
+                        if let Some(ref advisory) = advisory_sender {
+                            if let Err(e) = advisory.send_late_opportunity(mint, late_opportunity.horizon_secs, late_score) {
+                                warn!("Failed to send LateOpportunity for {}: {}", &mint[..12], e);
+                            } else {
+                                info!("🎯 Late opportunity detected: {} | age: {}s | vol: {:.1} SOL/60s | buyers: {} | score: {}",
+                                    &mint[..12], age_seconds, vol_60s_estimate, buyers_60s_estimate, late_score);
+                            }
+                        }
+                    }
+                }
+            }
+
+            // 👥 Shadow thresholds on the same live metrics: log-only, never sent
+            if let Some(shadow) = advisory_sender.as_ref().and_then(|sender| sender.shadow()) {
+                let liquidity_ok = advice_bus.admits_initial_liquidity(db.lock().unwrap().get_initial_liquidity(mint).ok().flatten());
+                log_shadow_decisions(
+                    mint,
+                    shadow.record_live(mint, &metrics, launch_time, liquidity_ok, current_time, &fired),
+                );
+            }
+        }
+    } // Drop window_tracker lock
+    timings.window_ns = window_start.elapsed().as_nanos() as u64;
+
+    timings
+}
+
 /// Check if windows meet trigger thresholds and send UDP advice
+///
+/// With shadow advisory enabled the same windows are also scored against the
+/// shadow thresholds; decisions only the shadow set makes are logged, not sent.
 fn check_and_send_opportunities(
     mint: &str,
     db: &Arc<Mutex<Database>>,
     advisory_sender: &Option<AdvisorySender>,
     current_time: i64,
+    late_opportunity: &LateOpportunityConfig,
+    advice_bus: &AdviceBusConfig,
 ) {
     let Some(sender) = advisory_sender else { return };
     
@@ -886,125 +1342,48 @@ fn check_and_send_opportunities(
     // Query recent windows for this mint
     let Ok(windows) = db_guard.get_recent_windows(mint, current_time - 120) else { return };
     let launch_time = db_guard.get_token_launch_time(mint).ok().flatten();
+    // Low-liquidity launches stay in the DB but aren't advised as Late/Rank
+    let liquidity_ok = advice_bus.admits_initial_liquidity(db_guard.get_initial_liquidity(mint).ok().flatten());
     
     drop(db_guard);
     
-    // Skip if data is too old (stale) - windows older than 2 minutes
-    let has_recent_data = windows.iter().any(|(_, _, _, start_time, _)| {
-        (current_time - start_time) < 120
-    });
-    
-    if !has_recent_data {
-        return; // Don't send opportunities for stale/inactive tokens
-    }
-    
-    // Find relevant window sizes
-    let w2s = windows.iter().find(|(sec, _, _, _, _)| *sec == 2);
-    let w5s = windows.iter().find(|(sec, _, _, _, _)| *sec == 5);
-    let w60s = windows.iter().find(|(sec, _, _, _, _)| *sec == 60);
+    let live = opportunity::evaluate(
+        &windows,
+        launch_time,
+        liquidity_ok,
+        current_time,
+        &OpportunityConfig::default(),
+        late_opportunity,
+    );
     
-    // Path B: Momentum (high 5s volume + 2s buyers)
-    if let (Some((_, vol_5s, _, _, _)), Some((_, _, buyers_2s, _, _))) = (w5s, w2s) {
-        // Thresholds: 2 SOL/5s, 2 buyers/2s (testing mode)
-        if *vol_5s >= 2.0 && *buyers_2s >= 2 {
-            // Calculate momentum score based on activity intensity
-            let vol_score = (vol_5s / 8.0 * 50.0).clamp(0.0, 50.0);
-            let buyer_score = ((*buyers_2s as f64 / 5.0) * 50.0).clamp(0.0, 50.0);
-            let momentum_score = (vol_score + buyer_score) as u8;
-            
-            if let Err(e) = sender.send_momentum_opportunity(mint, *vol_5s, *buyers_2s, momentum_score) {
-                warn!("Failed to send MomentumOpportunity for {}: {}", &mint[..12], e);
+    for found in &live {
+        let result = match *found {
+            Opportunity::Momentum { vol_5s_sol, buyers_2s, score } => {
+                sender.send_momentum_opportunity(mint, vol_5s_sol, buyers_2s, score)
             }
-        }
-    }
-    
-    // Path D: Late Opportunity (mature token, high 60s volume)
-    if let Some((_, vol_60s, buyers_60s, start_time, _price)) = w60s {
-        if let Some(launch_ts) = launch_time {
-            let age_seconds = current_time - launch_ts;
-            
-            // Thresholds: >20 min old, 10 SOL/60s, 10 buyers (testing mode)
-            // Also check: not TOO old (max 2 hours), and has recent activity
-            if age_seconds > 1200 && age_seconds < 7200 // 20 min to 2 hours
-                && *vol_60s >= 10.0 && *buyers_60s >= 10
-                && (current_time - start_time) < 120 // Window is recent
-            {
-                // Calculate late opportunity score
-                let vol_score = (vol_60s / 35.0 * 50.0).clamp(0.0, 50.0);
-                let buyer_score = ((*buyers_60s as f64 / 40.0) * 30.0).clamp(0.0, 30.0);
-                let age_factor = ((age_seconds as f64 / 3600.0) * 20.0).clamp(0.0, 20.0);
-                let late_score = (vol_score + buyer_score + age_factor) as u8;
-                
-                let horizon_sec = 300; // 5 minute opportunity window
-                
-                if let Err(e) = sender.send_late_opportunity(mint, horizon_sec, late_score) {
-                    warn!("Failed to send LateOpportunity for {}: {}", &mint[..12], e);
-                }
+            Opportunity::Late { horizon_secs, score } => {
+                sender.send_late_opportunity(mint, horizon_secs, score)
+            }
+            Opportunity::Rank { rank, followthrough } => {
+                sender.send_rank_opportunity(mint, rank, followthrough)
             }
+        };
+        if let Err(e) = result {
+            warn!("Failed to send {} opportunity for {}: {}", found.trigger_type(), &mint[..12], e);
         }
     }
     
-    // Path A: Rank-based (new launch with strong initial metrics)
-    // This checks tokens that just launched (< 5 minutes old) and ranks them
-    if let Some(launch_ts) = launch_time {
-        let age_seconds = current_time - launch_ts;
-        
-        // Only consider very new tokens (< 5 minutes since launch)
-        if age_seconds < 300 {
-            // Calculate rank based on initial metrics
-            // Lower rank = better opportunity (1 is best)
-            
-            let mut rank_score: f64 = 100.0;
-            
-            // Factor 1: Early volume (2s window)
-            if let Some((_, vol_2s, buyers_2s, _, _)) = w2s {
-                if *vol_2s >= 1.0 {
-                    rank_score -= (*vol_2s / 3.0 * 20.0).clamp(0.0, 20.0);
-                }
-                if *buyers_2s >= 2 {
-                    rank_score -= ((*buyers_2s as f64 / 5.0) * 15.0).clamp(0.0, 15.0);
-                }
-            }
-            
-            // Factor 2: Sustained 5s activity
-            if let Some((_, vol_5s, buyers_5s, _, _)) = w5s {
-                if *vol_5s >= 2.0 {
-                    rank_score -= (*vol_5s / 8.0 * 25.0).clamp(0.0, 25.0);
-                }
-                if *buyers_5s >= 3 {
-                    rank_score -= ((*buyers_5s as f64 / 8.0) * 15.0).clamp(0.0, 15.0);
-                }
-            }
-            
-            // Factor 3: Early momentum building
-            if let Some((_, vol_60s, _buyers_60s, _, _)) = w60s {
-                if *vol_60s >= 5.0 {
-                    rank_score -= (*vol_60s / 15.0 * 25.0).clamp(0.0, 25.0);
-                }
-            }
-            
-            // Convert to rank (1-100, lower is better)
-            // Score 0-50 = Rank 1-10 (excellent)
-            // Score 50-70 = Rank 11-30 (good)
-            // Score 70-100 = Rank 31-100 (moderate)
-            let rank = if rank_score < 50.0 {
-                1 + ((rank_score / 50.0) * 9.0) as u8
-            } else if rank_score < 70.0 {
-                11 + (((rank_score - 50.0) / 20.0) * 19.0) as u8
-            } else {
-                31 + (((rank_score - 70.0) / 30.0) * 69.0) as u8
-            }.clamp(1, 100);
-            
-            // Only send if rank is decent (top 30)
-            if rank <= 30 {
-                // Calculate follow-through score (how likely it is to maintain momentum)
-                let followthrough = (100.0 - rank_score).clamp(0.0, 100.0) as u8;
-                
-                if let Err(e) = sender.send_rank_opportunity(mint, rank, followthrough) {
-                    warn!("Failed to send RankOpportunity for {}: {}", &mint[..12], e);
-                }
-            }
-        }
+    // 👥 Shadow thresholds: log-only, never sent
+    if let Some(shadow) = sender.shadow() {
+        log_shadow_decisions(mint, shadow.record(mint, &windows, launch_time, liquidity_ok, current_time, &live));
+    }
+}
+
+fn log_shadow_decisions(mint: &str, result: Result<usize>) {
+    match result {
+        Ok(0) => {}
+        Ok(logged) => debug!("👥 Shadow advisory: {} shadow-only decision(s) for {}", logged, &mint[..12]),
+        Err(e) => warn!("Failed to log shadow decisions for {}: {}", &mint[..12], e),
     }
 }
 
@@ -0,0 +1,378 @@
+//! 📊 Prometheus Metrics for Data-Mining
+//!
+//! Mirrors Brain's metrics module: a global registry, helper functions for the
+//! hot path, and an HTTP server exposing `/metrics` plus the `health` probes
+//! (`/health`, `/ready`) and, optionally, the `analytics` JSON queries.
+//!
+//! Key signals:
+//! - Throughput: txs processed, launches, trades inserted, trade buffer batch sizes
+//! - Back-pressure: DB writer channel depth and drops per tier, dropped writes by kind, busy retries
+//! - Memory: dedup cache size, token cache size
+//! - Advisories: suppressed by the per-mint rate limiter, failed UDP sends per sender
+//! - Latency: per-stage p50/p90/p99/max from the LatencyTracker (per report interval)
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+    Router,
+};
+use prometheus::{Gauge, GaugeVec, IntCounter, IntCounterVec, IntGauge, IntGaugeVec, Opts, Registry};
+use std::sync::Arc;
+use tokio::net::TcpListener;
+use tracing::{error, info};
+
+use crate::db::WritePriority;
+
+/// Global metrics registry
+static METRICS: once_cell::sync::Lazy<Arc<DataMiningMetrics>> = once_cell::sync::Lazy::new(|| {
+    Arc::new(DataMiningMetrics::new())
+});
+
+/// Data-mining service metrics
+pub struct DataMiningMetrics {
+    // Registry for Prometheus
+    registry: Registry,
+
+    // Throughput counters
+    pub txs_processed: IntCounter,
+    pub launches: IntCounter,
+    pub trades_inserted: IntCounter,
+    pub trade_batch_size_avg: Gauge,
+    pub trade_batch_size_limit: IntGauge,
+
+    // Back-pressure
+    pub db_writer_queue_depth: IntGaugeVec,
+    pub db_writer_dropped: IntCounterVec,
+    pub db_writer_busy_retries: IntCounter,
+    pub dropped_messages: IntCounterVec,
+
+    // Cache sizes
+    pub dedup_cache_size: IntGauge,
+    pub token_cache_size: IntGauge,
+
+    // Advisory rate limiting
+    pub advisories_suppressed: IntCounterVec,
+    pub udp_oversized_dropped: IntCounter,
+    pub udp_send_failures: IntCounterVec,
+
+    // Latency percentiles (last report interval)
+    pub latency_ms: GaugeVec,
+    pub latency_samples: IntGaugeVec,
+}
+
+impl DataMiningMetrics {
+    pub fn new() -> Self {
+        let registry = Registry::new();
+
+        let txs_processed = IntCounter::with_opts(
+            Opts::new("datamining_txs_processed_total", "Transactions processed from the gRPC stream")
+        ).unwrap();
+        registry.register(Box::new(txs_processed.clone())).unwrap();
+
+        let launches = IntCounter::with_opts(
+            Opts::new("datamining_launches_total", "Token launches (CREATE events) observed")
+        ).unwrap();
+        registry.register(Box::new(launches.clone())).unwrap();
+
+        let trades_inserted = IntCounter::with_opts(
+            Opts::new("datamining_trades_inserted_total", "Trades committed to SQLite by the DB writer")
+        ).unwrap();
+        registry.register(Box::new(trades_inserted.clone())).unwrap();
+
+        let trade_batch_size_avg = Gauge::with_opts(
+            Opts::new("datamining_trade_batch_size_avg", "Running average of trades per Database trade buffer flush")
+        ).unwrap();
+        registry.register(Box::new(trade_batch_size_avg.clone())).unwrap();
+
+        let trade_batch_size_limit = IntGauge::with_opts(
+            Opts::new("datamining_trade_batch_size_limit", "Current trade buffer batch size (moves in adaptive mode)")
+        ).unwrap();
+        registry.register(Box::new(trade_batch_size_limit.clone())).unwrap();
+
+        let db_writer_queue_depth = IntGaugeVec::new(
+            Opts::new("datamining_db_writer_queue_depth", "Commands waiting in each DB writer channel tier"),
+            &["tier"]
+        ).unwrap();
+        registry.register(Box::new(db_writer_queue_depth.clone())).unwrap();
+
+        let db_writer_dropped = IntCounterVec::new(
+            Opts::new("datamining_db_writer_dropped_total", "Commands rejected by each DB writer channel tier"),
+            &["tier"]
+        ).unwrap();
+        registry.register(Box::new(db_writer_dropped.clone())).unwrap();
+
+        let db_writer_busy_retries = IntCounter::with_opts(
+            Opts::new("datamining_db_writer_busy_retries_total", "DB writer flushes retried after SQLITE_BUSY/LOCKED")
+        ).unwrap();
+        registry.register(Box::new(db_writer_busy_retries.clone())).unwrap();
+
+        let dropped_messages = IntCounterVec::new(
+            Opts::new("datamining_dropped_messages_total", "Writes dropped due to DB writer back-pressure"),
+            &["kind"]
+        ).unwrap();
+        registry.register(Box::new(dropped_messages.clone())).unwrap();
+
+        let dedup_cache_size = IntGauge::with_opts(
+            Opts::new("datamining_dedup_cache_size", "Signatures held in the dedup cache")
+        ).unwrap();
+        registry.register(Box::new(dedup_cache_size.clone())).unwrap();
+
+        let token_cache_size = IntGauge::with_opts(
+            Opts::new("datamining_token_cache_size", "Mints held in the in-memory token cache")
+        ).unwrap();
+        registry.register(Box::new(token_cache_size.clone())).unwrap();
+
+        let advisories_suppressed = IntCounterVec::new(
+            Opts::new("datamining_advisories_suppressed_total", "Advisories suppressed by the per-mint rate limiter"),
+            &["advice_type", "reason"]
+        ).unwrap();
+        registry.register(Box::new(advisories_suppressed.clone())).unwrap();
+
+        let udp_oversized_dropped = IntCounter::with_opts(
+            Opts::new("datamining_udp_oversized_dropped_total", "UDP messages dropped for exceeding advice_bus.max_datagram_bytes")
+        ).unwrap();
+        registry.register(Box::new(udp_oversized_dropped.clone())).unwrap();
+
+        let udp_send_failures = IntCounterVec::new(
+            Opts::new("datamining_udp_send_failures_total", "Advisory / brain signal UDP sends that failed at the socket"),
+            &["sender"]
+        ).unwrap();
+        registry.register(Box::new(udp_send_failures.clone())).unwrap();
+
+        let latency_ms = GaugeVec::new(
+            Opts::new("datamining_latency_ms", "Pipeline stage latency percentiles over the last report interval (ms)"),
+            &["stage", "quantile"]
+        ).unwrap();
+        registry.register(Box::new(latency_ms.clone())).unwrap();
+
+        let latency_samples = IntGaugeVec::new(
+            Opts::new("datamining_latency_samples", "Latency samples recorded per stage in the last report interval"),
+            &["stage"]
+        ).unwrap();
+        registry.register(Box::new(latency_samples.clone())).unwrap();
+
+        Self {
+            registry,
+            txs_processed,
+            launches,
+            trades_inserted,
+            trade_batch_size_avg,
+            trade_batch_size_limit,
+            db_writer_queue_depth,
+            db_writer_dropped,
+            db_writer_busy_retries,
+            dropped_messages,
+            dedup_cache_size,
+            token_cache_size,
+            advisories_suppressed,
+            udp_oversized_dropped,
+            udp_send_failures,
+            latency_ms,
+            latency_samples,
+        }
+    }
+
+    /// Get the metrics registry
+    pub fn registry(&self) -> &Registry {
+        &self.registry
+    }
+}
+
+impl Default for DataMiningMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Get global metrics instance
+pub fn metrics() -> Arc<DataMiningMetrics> {
+    METRICS.clone()
+}
+
+/// Initialize metrics (called at startup)
+pub fn init_metrics() {
+    // Force initialization of lazy static
+    let _ = METRICS.clone();
+    info!("📊 Metrics system initialized");
+}
+
+/// Start Prometheus metrics HTTP server
+/// `analytics_db`: database path to serve `/analytics/*` from (None = disabled)
+pub async fn start_metrics_server(port: u16, analytics_db: Option<String>) -> anyhow::Result<()> {
+    let addr = format!("0.0.0.0:{}", port);
+
+    info!("📊 Starting metrics server on {}", addr);
+
+    let mut app = Router::new()
+        .route("/metrics", get(metrics_handler))
+        .route("/health", get(crate::health::health_handler))
+        .route("/ready", get(crate::health::ready_handler));
+    let analytics_enabled = analytics_db.is_some();
+    if let Some(db_path) = analytics_db {
+        app = app.merge(crate::analytics::router(db_path));
+    }
+
+    let listener = TcpListener::bind(&addr).await?;
+
+    info!("✓ Metrics server listening on http://{}", addr);
+    info!("  • Metrics endpoint: http://{}/metrics", addr);
+    info!("  • Health endpoint: http://{}/health", addr);
+    info!("  • Ready endpoint: http://{}/ready", addr);
+    if analytics_enabled {
+        info!("  • Analytics: http://{}/analytics/{{top_tokens,graduation,wallets}}", addr);
+    }
+
+    axum::serve(listener, app).await?;
+
+    Ok(())
+}
+
+/// Metrics endpoint handler
+async fn metrics_handler() -> Response {
+    let metrics = METRICS.clone();
+    let encoder = prometheus::TextEncoder::new();
+
+    match encoder.encode_to_string(&metrics.registry().gather()) {
+        Ok(body) => {
+            (
+                StatusCode::OK,
+                [("content-type", "text/plain; version=0.0.4")],
+                body,
+            ).into_response()
+        }
+        Err(e) => {
+            error!("Failed to encode metrics: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Failed to encode metrics: {}", e),
+            ).into_response()
+        }
+    }
+}
+
+// ============================================================================
+// Helper Functions for Recording Metrics
+// ============================================================================
+
+/// Low-priority write kinds that can be dropped under back-pressure
+#[derive(Debug, Clone, Copy)]
+pub enum DropKind {
+    PlaceholderToken,
+    Liquidity,
+}
+
+impl DropKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DropKind::PlaceholderToken => "placeholder_token",
+            DropKind::Liquidity => "liquidity",
+        }
+    }
+}
+
+/// Record a transaction taken off the stream
+pub fn record_tx_processed() {
+    metrics().txs_processed.inc();
+}
+
+/// Record a token launch
+pub fn record_launch() {
+    metrics().launches.inc();
+}
+
+/// Record trades committed by the DB writer
+pub fn record_trades_inserted(count: usize) {
+    metrics().trades_inserted.inc_by(count as u64);
+}
+
+/// Update the trade buffer's average flushed batch and current size limit
+pub fn set_trade_batch_sizes(avg: f64, limit: usize) {
+    let m = metrics();
+    m.trade_batch_size_avg.set(avg);
+    m.trade_batch_size_limit.set(limit as i64);
+}
+
+/// Record a write dropped due to back-pressure
+pub fn record_dropped(kind: DropKind) {
+    metrics().dropped_messages.with_label_values(&[kind.as_str()]).inc();
+}
+
+/// Update DB writer channel depth for one tier
+pub fn set_db_writer_queue_depth(tier: WritePriority, depth: usize) {
+    metrics().db_writer_queue_depth.with_label_values(&[tier.as_str()]).set(depth as i64);
+}
+
+/// Record a command rejected by a DB writer channel tier
+pub fn record_db_writer_dropped(tier: WritePriority) {
+    metrics().db_writer_dropped.with_label_values(&[tier.as_str()]).inc();
+}
+
+/// Record a DB writer flush retried because the database was busy/locked
+pub fn record_db_writer_busy_retry() {
+    metrics().db_writer_busy_retries.inc();
+}
+
+/// Update dedup cache size
+pub fn set_dedup_cache_size(size: usize) {
+    metrics().dedup_cache_size.set(size as i64);
+}
+
+/// Update token cache size
+pub fn set_token_cache_size(size: usize) {
+    metrics().token_cache_size.set(size as i64);
+}
+
+/// Record an advisory suppressed by the rate limiter (`reason`: duplicate / rate_limited)
+pub fn record_advisory_suppressed(advice_type: &str, reason: &str) {
+    metrics().advisories_suppressed.with_label_values(&[advice_type, reason]).inc();
+}
+
+/// Record a UDP message dropped instead of sent as a fragmenting datagram
+pub fn record_udp_oversized_dropped() {
+    metrics().udp_oversized_dropped.inc();
+}
+
+/// Record a UDP send that failed at the socket (`sender`: advisory / brain_signal)
+pub fn record_udp_send_failed(sender: &str) {
+    metrics().udp_send_failures.with_label_values(&[sender]).inc();
+}
+
+/// Publish one stage's latency percentiles for the last report interval
+pub fn set_latency_percentiles(stage: &str, count: u64, p50_ms: f64, p90_ms: f64, p99_ms: f64, max_ms: f64) {
+    let m = metrics();
+    m.latency_samples.with_label_values(&[stage]).set(count as i64);
+    for (quantile, value) in [("0.5", p50_ms), ("0.9", p90_ms), ("0.99", p99_ms), ("max", max_ms)] {
+        m.latency_ms.with_label_values(&[stage, quantile]).set(value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_helper_functions() {
+        init_metrics();
+        record_tx_processed();
+        record_launch();
+        record_trades_inserted(3);
+        set_trade_batch_sizes(12.5, 100);
+        record_dropped(DropKind::Liquidity);
+        set_db_writer_queue_depth(WritePriority::Low, 42);
+        record_db_writer_dropped(WritePriority::Low);
+        set_dedup_cache_size(10);
+        set_token_cache_size(5);
+        record_advisory_suppressed("widen_exit", "rate_limited");
+        set_latency_percentiles("end_to_end", 7, 1.5, 4.0, 12.0, 20.0);
+
+        let m = metrics();
+        assert!(m.trades_inserted.get() >= 3);
+        assert!(m.dropped_messages.with_label_values(&["liquidity"]).get() >= 1);
+        assert_eq!(m.db_writer_queue_depth.with_label_values(&["low"]).get(), 42);
+        assert!(m.db_writer_dropped.with_label_values(&["low"]).get() >= 1);
+        assert!(m.advisories_suppressed.with_label_values(&["widen_exit", "rate_limited"]).get() >= 1);
+        assert_eq!(m.latency_ms.with_label_values(&["end_to_end", "0.99"]).get(), 12.0);
+        assert_eq!(m.latency_samples.with_label_values(&["end_to_end"]).get(), 7);
+    }
+}
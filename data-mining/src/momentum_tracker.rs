@@ -4,10 +4,12 @@
 /// and volume spikes as they happen.
 
 use std::collections::{HashMap, VecDeque};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::sync::Arc;
 use anyhow::Result;
 use tracing::debug;
 
+use crate::clock::{self, Clock};
+use crate::config::MomentumConfig;
 use crate::types::TradeSide;
 
 /// Single transaction event for rolling window tracking
@@ -101,12 +103,16 @@ impl MintWindow {
 pub struct MomentumTracker {
     /// Per-mint windows
     mints: HashMap<String, MintWindow>,
-    /// Momentum detection threshold (buys in 500ms)
+    /// Momentum detection threshold (buys in `momentum_window_ms`)
     momentum_threshold: u16,
+    /// Momentum detection window (ms)
+    momentum_window_ms: u64,
     /// Volume spike multiplier (5x = 5.0)
     spike_multiplier: f32,
     /// Minimum time between signals (ms)
     signal_cooldown_ms: u64,
+    /// Time base (wall clock live, trade timestamps in replay)
+    clock: Arc<dyn Clock>,
 }
 
 impl MomentumTracker {
@@ -114,11 +120,26 @@ impl MomentumTracker {
         Self {
             mints: HashMap::new(),
             momentum_threshold,
+            momentum_window_ms: 500,
             spike_multiplier,
             signal_cooldown_ms,
+            clock: clock::system_clock(),
         }
     }
     
+    /// Build from the `[momentum]` config section
+    pub fn from_config(config: &MomentumConfig) -> Self {
+        let mut tracker = Self::new(config.threshold_buys, config.spike_multiplier, config.cooldown_ms);
+        tracker.momentum_window_ms = config.window_ms;
+        tracker
+    }
+    
+    /// Use a custom time base (e.g. `ReplayClock` for historical replay)
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+    
     /// Record a new transaction
     pub fn record_trade(
         &mut self,
@@ -127,38 +148,32 @@ impl MomentumTracker {
         amount_sol: f64,
         trader: &str,
     ) {
-        let now_ms = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_millis() as u64;
+        let now_ms = self.clock.now_ms();
         
         let window = self.mints.entry(mint.to_string())
             .or_insert_with(MintWindow::new);
         
         window.add_event(now_ms, side, amount_sol, trader.to_string());
         
-        // Cleanup events older than 2 seconds
-        let cutoff_ms = now_ms.saturating_sub(2000);
+        // Cleanup events older than 2 seconds (or the momentum window if longer)
+        let cutoff_ms = now_ms.saturating_sub(self.momentum_window_ms.max(2000));
         window.cleanup_old_events(cutoff_ms);
     }
     
     /// Check for momentum signal (returns Some if momentum detected and cooldown passed)
     pub fn check_momentum(&mut self, mint: &str) -> Option<MomentumSignal> {
-        let now_ms = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_millis() as u64;
+        let now_ms = self.clock.now_ms();
         
         let window = self.mints.get_mut(mint)?;
         
         // Check cooldown
-        if now_ms - window.last_momentum_signal_ms < self.signal_cooldown_ms {
+        if now_ms.saturating_sub(window.last_momentum_signal_ms) < self.signal_cooldown_ms {
             return None;
         }
         
-        // Calculate buys in last 500ms
-        let window_start_500ms = now_ms.saturating_sub(500);
-        let (buys, volume_sol, unique_buyers) = window.buys_in_window(window_start_500ms);
+        // Calculate buys in the momentum window
+        let window_start = now_ms.saturating_sub(self.momentum_window_ms);
+        let (buys, volume_sol, unique_buyers) = window.buys_in_window(window_start);
         
         if buys >= self.momentum_threshold {
             window.last_momentum_signal_ms = now_ms;
@@ -185,15 +200,12 @@ impl MomentumTracker {
     
     /// Check for volume spike (returns Some if spike detected and cooldown passed)
     pub fn check_volume_spike(&mut self, mint: &str) -> Option<VolumeSpikeSignal> {
-        let now_ms = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_millis() as u64;
+        let now_ms = self.clock.now_ms();
         
         let window = self.mints.get_mut(mint)?;
         
         // Check cooldown
-        if now_ms - window.last_spike_signal_ms < self.signal_cooldown_ms {
+        if now_ms.saturating_sub(window.last_spike_signal_ms) < self.signal_cooldown_ms {
             return None;
         }
         
@@ -229,19 +241,26 @@ impl MomentumTracker {
         None
     }
     
-    /// Periodic cleanup of inactive mints
-    pub fn cleanup_inactive_mints(&mut self, max_age_ms: u64) {
-        let now_ms = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_millis() as u64;
+    /// Drop mints with no trades in the last `max_age_ms`
+    ///
+    /// Returns the number of mints evicted. Call periodically to keep memory bounded.
+    pub fn prune_inactive(&mut self, max_age_ms: u64) -> usize {
+        let now_ms = self.clock.now_ms();
+        let before = self.mints.len();
         
         self.mints.retain(|_mint, window| {
             // Keep if has recent events
             window.events.back()
-                .map(|e| now_ms - e.timestamp_ms < max_age_ms)
+                .map(|e| now_ms.saturating_sub(e.timestamp_ms) < max_age_ms)
                 .unwrap_or(false)
         });
+        
+        before - self.mints.len()
+    }
+    
+    /// Number of mints currently tracked (for monitoring)
+    pub fn mint_count(&self) -> usize {
+        self.mints.len()
     }
 }
 
@@ -286,4 +305,64 @@ mod tests {
         assert_eq!(sig.buys_in_last_500ms, 4);
         assert_eq!(sig.unique_buyers, 4);
     }
+    
+    #[test]
+    fn test_momentum_from_config() {
+        let clock = Arc::new(crate::clock::ReplayClock::new(1_700_000_000_000));
+        let config = MomentumConfig {
+            threshold_buys: 2,
+            window_ms: 3000,
+            ..MomentumConfig::default()
+        };
+        let mut tracker = MomentumTracker::from_config(&config).with_clock(clock.clone());
+        
+        tracker.record_trade("mint1", TradeSide::Buy, 1.0, "wallet1");
+        clock.set(1_700_000_002_500);
+        tracker.record_trade("mint1", TradeSide::Buy, 1.0, "wallet2");
+        
+        // Two buys 2.5s apart fit in a 3s window but not the default 500ms one
+        let signal = tracker.check_momentum("mint1").unwrap();
+        assert_eq!(signal.buys_in_last_500ms, 2);
+    }
+    
+    #[test]
+    fn test_momentum_uses_replay_clock() {
+        let clock = Arc::new(crate::clock::ReplayClock::new(1_700_000_000_000));
+        let mut tracker = MomentumTracker::new(3, 5.0, 5000).with_clock(clock.clone());
+        
+        for i in 0..3 {
+            tracker.record_trade("mint1", TradeSide::Buy, 1.0, &format!("wallet{}", i));
+        }
+        assert!(tracker.check_momentum("mint1").is_some());
+        
+        // Still inside cooldown in replayed time
+        clock.set(1_700_000_001_000);
+        for i in 3..6 {
+            tracker.record_trade("mint1", TradeSide::Buy, 1.0, &format!("wallet{}", i));
+        }
+        assert!(tracker.check_momentum("mint1").is_none());
+        
+        // Cooldown elapsed in replayed time (no wall-clock wait)
+        clock.set(1_700_000_006_000);
+        for i in 6..9 {
+            tracker.record_trade("mint1", TradeSide::Buy, 1.0, &format!("wallet{}", i));
+        }
+        assert!(tracker.check_momentum("mint1").is_some());
+    }
+    
+    #[test]
+    fn test_prune_inactive_evicts_idle_mints() {
+        let clock = Arc::new(crate::clock::ReplayClock::new(1_700_000_000_000));
+        let mut tracker = MomentumTracker::new(3, 5.0, 5000).with_clock(clock.clone());
+        
+        tracker.record_trade("old_mint", TradeSide::Buy, 1.0, "wallet1");
+        clock.set(1_700_000_050_000);
+        tracker.record_trade("new_mint", TradeSide::Buy, 1.0, "wallet2");
+        assert_eq!(tracker.mint_count(), 2);
+        
+        clock.set(1_700_000_070_000);
+        assert_eq!(tracker.prune_inactive(60_000), 1);
+        assert_eq!(tracker.mint_count(), 1);
+        assert!(tracker.mints.contains_key("new_mint"));
+    }
 }
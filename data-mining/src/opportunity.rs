@@ -0,0 +1,437 @@
+//! 🎯 Opportunity evaluation - Path A/B/D thresholds over a mint's recent windows
+//!
+//! `evaluate` is the pure half of `check_and_send_opportunities`: it decides which
+//! opportunities a threshold set produces, and the caller sends them. Keeping it
+//! pure lets the same windows be scored twice - once with the live thresholds and
+//! once with a shadow set.
+//!
+//! Shadow advisory mode (`[shadow_advisory]`) logs the decisions only the shadow
+//! thresholds would have made to a separate JSON-lines file in the shape of
+//! Brain's `DecisionLogEntry` (schema v2), so both threshold sets can be compared
+//! offline with the same tooling. Shadow decisions are never sent over UDP. The
+//! live trade path scores its 1s window metrics via `ShadowAdvisor::record_live`.
+
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+use crate::config::{LateOpportunityConfig, OpportunityConfig, ShadowAdvisoryConfig};
+use crate::window_tracker::WindowMetrics;
+
+/// Recent window row: (window_sec, vol_sol, uniq_buyers, start_time, close)
+pub type WindowRow = (u32, f64, u32, i64, f64);
+
+/// Windows older than this (seconds) mean the token went quiet
+const STALE_WINDOW_SECS: i64 = 120;
+
+/// Live path's 60s extrapolation from 1s window metrics: volume assumed
+/// sustained, buyers counted conservatively
+pub const LIVE_VOL_60S_PER_1S: f64 = 20.0;
+pub const LIVE_BUYERS_60S_PER_1S: u32 = 10;
+
+/// Brain decision log schema the shadow log is written in
+/// IMPORTANT: must match Brain's `DECISION_LOG_SCHEMA_VERSION`
+pub const SHADOW_LOG_SCHEMA_VERSION: u32 = 2;
+
+/// An opportunity a threshold set decided to advise
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Opportunity {
+    /// Path A: top-ranked new launch (rank 1 = best)
+    Rank { rank: u8, followthrough: u8 },
+    /// Path B: high 5s volume with 2s buyers
+    Momentum { vol_5s_sol: f64, buyers_2s: u32, score: u8 },
+    /// Path D: mature token with sustained 60s activity
+    Late { horizon_secs: u16, score: u8 },
+}
+
+impl Opportunity {
+    /// Brain's `TriggerType` serde name
+    pub fn trigger_type(&self) -> &'static str {
+        match self {
+            Opportunity::Rank { .. } => "rank",
+            Opportunity::Momentum { .. } => "momentum",
+            Opportunity::Late { .. } => "late",
+        }
+    }
+
+    /// Score carried in the advisory (0-100)
+    fn confidence(&self) -> u8 {
+        match *self {
+            Opportunity::Rank { followthrough, .. } => followthrough,
+            Opportunity::Momentum { score, .. } | Opportunity::Late { score, .. } => score,
+        }
+    }
+}
+
+/// Opportunities `windows` qualify for under one threshold set
+///
+/// `liquidity_ok` is the `[advice_bus] min_initial_liquidity_sol` gate (applies to
+/// Late and Rank only).
+pub fn evaluate(
+    windows: &[WindowRow],
+    launch_time: Option<i64>,
+    liquidity_ok: bool,
+    current_time: i64,
+    opportunity: &OpportunityConfig,
+    late_opportunity: &LateOpportunityConfig,
+) -> Vec<Opportunity> {
+    let mut found = Vec::new();
+
+    // Skip stale/inactive tokens
+    if !windows.iter().any(|(_, _, _, start_time, _)| current_time - start_time < STALE_WINDOW_SECS) {
+        return found;
+    }
+
+    let w2s = windows.iter().find(|(sec, _, _, _, _)| *sec == 2);
+    let w5s = windows.iter().find(|(sec, _, _, _, _)| *sec == 5);
+    let w60s = windows.iter().find(|(sec, _, _, _, _)| *sec == 60);
+
+    // Path B: Momentum (high 5s volume + 2s buyers)
+    if let (Some((_, vol_5s, _, _, _)), Some((_, _, buyers_2s, _, _))) = (w5s, w2s) {
+        if *vol_5s >= opportunity.momentum_min_vol_5s_sol && *buyers_2s >= opportunity.momentum_min_buyers_2s {
+            // Calculate momentum score based on activity intensity
+            let vol_score = (vol_5s / 8.0 * 50.0).clamp(0.0, 50.0);
+            let buyer_score = ((*buyers_2s as f64 / 5.0) * 50.0).clamp(0.0, 50.0);
+            found.push(Opportunity::Momentum {
+                vol_5s_sol: *vol_5s,
+                buyers_2s: *buyers_2s,
+                score: (vol_score + buyer_score) as u8,
+            });
+        }
+    }
+
+    // Path D: Late Opportunity (mature token, high 60s volume)
+    if let (Some((_, vol_60s, buyers_60s, start_time, _)), Some(launch_ts)) = (w60s.filter(|_| liquidity_ok), launch_time) {
+        let age_seconds = (current_time - launch_ts).max(0) as u64;
+        if late_opportunity.qualifies(age_seconds, *vol_60s, *buyers_60s)
+            && (current_time - start_time) < STALE_WINDOW_SECS // Window is recent
+        {
+            found.push(Opportunity::Late {
+                horizon_secs: late_opportunity.horizon_secs,
+                score: late_opportunity.score(age_seconds, *vol_60s, *buyers_60s),
+            });
+        }
+    }
+
+    // Path A: Rank-based (new launch with strong initial metrics)
+    if let Some(launch_ts) = launch_time.filter(|_| liquidity_ok) {
+        if current_time - launch_ts < opportunity.rank_max_age_secs {
+            let rank_score = rank_score(w2s, w5s, w60s);
+
+            // Convert to rank (1-100, lower is better)
+            // Score 0-50 = Rank 1-10 (excellent)
+            // Score 50-70 = Rank 11-30 (good)
+            // Score 70-100 = Rank 31-100 (moderate)
+            let rank = if rank_score < 50.0 {
+                1 + ((rank_score / 50.0) * 9.0) as u8
+            } else if rank_score < 70.0 {
+                11 + (((rank_score - 50.0) / 20.0) * 19.0) as u8
+            } else {
+                31 + (((rank_score - 70.0) / 30.0) * 69.0) as u8
+            }.clamp(1, 100);
+
+            if rank <= opportunity.max_rank {
+                // Follow-through score (how likely it is to maintain momentum)
+                let followthrough = (100.0 - rank_score).clamp(0.0, 100.0) as u8;
+                found.push(Opportunity::Rank { rank, followthrough });
+            }
+        }
+    }
+
+    found
+}
+
+/// Window rows estimated from the live path's 1s metrics, for scoring live
+/// trades with `evaluate`
+///
+/// 60s uses the live LateOpportunity extrapolation; 2s/5s assume the 1s volume
+/// is sustained and count only the 1s buyers. `close` isn't scored, so it's 0.
+pub fn live_windows(metrics: &WindowMetrics, current_time: i64) -> Vec<WindowRow> {
+    let buyers_1s = metrics.unique_buyers_1s_filtered as u32;
+    vec![
+        (2, metrics.volume_sol_1s * 2.0, buyers_1s, current_time, 0.0),
+        (5, metrics.volume_sol_1s * 5.0, buyers_1s, current_time, 0.0),
+        (60, metrics.volume_sol_1s * LIVE_VOL_60S_PER_1S, buyers_1s * LIVE_BUYERS_60S_PER_1S, current_time, 0.0),
+    ]
+}
+
+/// Rank score from early windows (100 = no activity, lower is better)
+fn rank_score(w2s: Option<&WindowRow>, w5s: Option<&WindowRow>, w60s: Option<&WindowRow>) -> f64 {
+    let mut rank_score: f64 = 100.0;
+
+    // Factor 1: Early volume (2s window)
+    if let Some((_, vol_2s, buyers_2s, _, _)) = w2s {
+        if *vol_2s >= 1.0 {
+            rank_score -= (*vol_2s / 3.0 * 20.0).clamp(0.0, 20.0);
+        }
+        if *buyers_2s >= 2 {
+            rank_score -= ((*buyers_2s as f64 / 5.0) * 15.0).clamp(0.0, 15.0);
+        }
+    }
+
+    // Factor 2: Sustained 5s activity
+    if let Some((_, vol_5s, buyers_5s, _, _)) = w5s {
+        if *vol_5s >= 2.0 {
+            rank_score -= (*vol_5s / 8.0 * 25.0).clamp(0.0, 25.0);
+        }
+        if *buyers_5s >= 3 {
+            rank_score -= ((*buyers_5s as f64 / 8.0) * 15.0).clamp(0.0, 15.0);
+        }
+    }
+
+    // Factor 3: Early momentum building
+    if let Some((_, vol_60s, _, _, _)) = w60s {
+        if *vol_60s >= 5.0 {
+            rank_score -= (*vol_60s / 15.0 * 25.0).clamp(0.0, 25.0);
+        }
+    }
+
+    rank_score
+}
+
+/// One shadow log line, field-for-field Brain's versioned `DecisionLogEntry`
+///
+/// Sizing/EV fields are Brain's to compute, so they're zero here.
+#[derive(Debug, Serialize)]
+struct ShadowDecisionLine<'a> {
+    schema_version: u32,
+    mint_b58: &'a str,
+    decision_id: u64,
+    timestamp: u64,
+    /// Mint address (hex)
+    mint: String,
+    trigger_type: &'static str,
+    side: u8,
+    predicted_fees_usd: f64,
+    predicted_impact_usd: f64,
+    tp_usd: f64,
+    follow_through_score: u8,
+    size_sol: f64,
+    size_usd: f64,
+    confidence: u8,
+    expected_ev_usd: f64,
+    success_probability: f64,
+    rank: Option<u8>,
+    wallet: Option<String>,
+    wallet_tier: Option<u8>,
+    slippage_bps: Option<u16>,
+}
+
+/// Evaluates the `[shadow_advisory]` thresholds and logs shadow-only decisions
+pub struct ShadowAdvisor {
+    opportunity: OpportunityConfig,
+    late_opportunity: LateOpportunityConfig,
+    log: Mutex<File>,
+    next_decision_id: AtomicU64,
+}
+
+impl ShadowAdvisor {
+    /// Open (append) the shadow decision log
+    pub fn open(config: &ShadowAdvisoryConfig) -> Result<Self> {
+        if let Some(dir) = Path::new(&config.log_path).parent().filter(|d| !d.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("Failed to create shadow log directory: {:?}", dir))?;
+        }
+        let log = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&config.log_path)
+            .with_context(|| format!("Failed to open shadow decision log: {}", config.log_path))?;
+
+        Ok(Self {
+            opportunity: config.opportunity.clone(),
+            late_opportunity: config.late_opportunity.clone(),
+            log: Mutex::new(log),
+            next_decision_id: AtomicU64::new(1),
+        })
+    }
+
+    /// Score `windows` with the shadow thresholds and log every opportunity whose
+    /// trigger the live set (`live`) didn't fire; returns how many were logged
+    pub fn record(
+        &self,
+        mint: &str,
+        windows: &[WindowRow],
+        launch_time: Option<i64>,
+        liquidity_ok: bool,
+        current_time: i64,
+        live: &[Opportunity],
+    ) -> Result<usize> {
+        let shadow_only: Vec<Opportunity> = evaluate(
+            windows, launch_time, liquidity_ok, current_time, &self.opportunity, &self.late_opportunity,
+        )
+        .into_iter()
+        .filter(|shadow| !live.iter().any(|l| l.trigger_type() == shadow.trigger_type()))
+        .collect();
+
+        if shadow_only.is_empty() {
+            return Ok(0);
+        }
+
+        let mint_hex = match bs58::decode(mint).into_vec() {
+            Ok(bytes) => bytes.iter().map(|b| format!("{:02x}", b)).collect(),
+            Err(_) => mint.to_string(),
+        };
+
+        let mut lines = String::new();
+        for opportunity in &shadow_only {
+            let line = ShadowDecisionLine {
+                schema_version: SHADOW_LOG_SCHEMA_VERSION,
+                mint_b58: mint,
+                decision_id: self.next_decision_id.fetch_add(1, Ordering::Relaxed),
+                timestamp: current_time.max(0) as u64,
+                mint: mint_hex.clone(),
+                trigger_type: opportunity.trigger_type(),
+                side: 0,
+                predicted_fees_usd: 0.0,
+                predicted_impact_usd: 0.0,
+                tp_usd: 0.0,
+                follow_through_score: opportunity.confidence(),
+                size_sol: 0.0,
+                size_usd: 0.0,
+                confidence: opportunity.confidence(),
+                expected_ev_usd: 0.0,
+                success_probability: 0.0,
+                rank: match opportunity {
+                    Opportunity::Rank { rank, .. } => Some(*rank),
+                    _ => None,
+                },
+                wallet: None,
+                wallet_tier: None,
+                slippage_bps: None,
+            };
+            lines.push_str(&serde_json::to_string(&line).context("Failed to serialize shadow decision")?);
+            lines.push('\n');
+        }
+
+        let mut log = self.log.lock().unwrap();
+        log.write_all(lines.as_bytes()).context("Failed to write shadow decision log")?;
+        log.flush()?;
+
+        Ok(shadow_only.len())
+    }
+
+    /// `record` for a live trade: scores the window tracker's 1s metrics (see
+    /// `live_windows`) against the opportunities the live path fired for it
+    pub fn record_live(
+        &self,
+        mint: &str,
+        metrics: &WindowMetrics,
+        launch_time: Option<i64>,
+        liquidity_ok: bool,
+        current_time: i64,
+        fired: &[Opportunity],
+    ) -> Result<usize> {
+        let windows = live_windows(metrics, current_time);
+        self.record(mint, &windows, launch_time, liquidity_ok, current_time, fired)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: i64 = 1_700_000_000;
+    const MINT: &str = "So11111111111111111111111111111111111111112";
+
+    fn windows(vol_5s: f64, buyers_2s: u32) -> Vec<WindowRow> {
+        vec![
+            (2, 0.5, buyers_2s, NOW - 2, 0.0),
+            (5, vol_5s, 3, NOW - 5, 0.0),
+            (60, 6.0, 8, NOW - 60, 0.0),
+        ]
+    }
+
+    #[test]
+    fn test_evaluate_live_thresholds() {
+        let live = OpportunityConfig::default();
+        let late = LateOpportunityConfig::default();
+
+        let found = evaluate(&windows(2.5, 2), None, true, NOW, &live, &late);
+        assert!(matches!(found.as_slice(), [Opportunity::Momentum { buyers_2s: 2, .. }]));
+
+        assert!(evaluate(&windows(1.5, 2), None, true, NOW, &live, &late).is_empty());
+        // Stale windows never qualify
+        assert!(evaluate(&windows(2.5, 2), None, true, NOW + 600, &live, &late).is_empty());
+    }
+
+    #[test]
+    fn test_shadow_logs_only_decisions_live_missed() {
+        let path = std::env::temp_dir().join(format!("shadow_decisions_{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let config = ShadowAdvisoryConfig {
+            enabled: true,
+            log_path: path.to_string_lossy().into_owned(),
+            opportunity: OpportunityConfig { momentum_min_vol_5s_sol: 1.0, ..OpportunityConfig::default() },
+            late_opportunity: LateOpportunityConfig::default(),
+        };
+        let shadow = ShadowAdvisor::open(&config).unwrap();
+        let live_config = OpportunityConfig::default();
+        let late = LateOpportunityConfig::default();
+
+        // Both sets fire momentum: nothing shadow-only
+        let w = windows(2.5, 2);
+        let live = evaluate(&w, None, true, NOW, &live_config, &late);
+        assert_eq!(shadow.record(MINT, &w, None, true, NOW, &live).unwrap(), 0);
+
+        // Only the looser shadow threshold fires
+        let w = windows(1.5, 2);
+        let live = evaluate(&w, None, true, NOW, &live_config, &late);
+        assert_eq!(shadow.record(MINT, &w, None, true, NOW, &live).unwrap(), 1);
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let line: serde_json::Value = serde_json::from_str(contents.trim()).unwrap();
+        assert_eq!(line["schema_version"], 2);
+        assert_eq!(line["trigger_type"], "momentum");
+        assert_eq!(line["mint_b58"], MINT);
+        assert_eq!(line["mint"].as_str().unwrap().len(), 64);
+        assert_eq!(line["decision_id"], 1);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_live_trade_logs_shadow_only_late() {
+        use crate::clock::ReplayClock;
+        use crate::window_tracker::WindowTracker;
+        use std::sync::Arc;
+
+        let path = std::env::temp_dir().join(format!("shadow_live_{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let config = ShadowAdvisoryConfig {
+            enabled: true,
+            log_path: path.to_string_lossy().into_owned(),
+            opportunity: OpportunityConfig::default(),
+            late_opportunity: LateOpportunityConfig { min_vol_60s_sol: 5.0, ..LateOpportunityConfig::default() },
+        };
+        let shadow = ShadowAdvisor::open(&config).unwrap();
+        let launch_time = Some(NOW - 1800);
+
+        // Two live buys, 0.35 SOL in the last second → ~7 SOL/60s estimated:
+        // under the live 10 SOL Late bar, over the shadow 5 (and short of momentum)
+        let now_ms = NOW as u64 * 1000;
+        let mut tracker = WindowTracker::new(0, 1).with_clock(Arc::new(ReplayClock::new(now_ms)));
+        tracker.add_trade(MINT, now_ms - 500, 0.1, 1e-7, "buyer-a", false);
+        tracker.add_trade(MINT, now_ms, 0.25, 1e-7, "buyer-b", false);
+        let metrics = tracker.get_metrics_if_ready(MINT, 100.0).expect("metrics ready");
+
+        let live = LateOpportunityConfig::default();
+        assert!(!live.qualifies(1800, metrics.volume_sol_1s * LIVE_VOL_60S_PER_1S, 2 * LIVE_BUYERS_60S_PER_1S));
+        assert_eq!(shadow.record_live(MINT, &metrics, launch_time, true, NOW, &[]).unwrap(), 1);
+
+        // Once the live path fires Late too, nothing is shadow-only
+        let fired = [Opportunity::Late { horizon_secs: 300, score: 50 }];
+        assert_eq!(shadow.record_live(MINT, &metrics, launch_time, true, NOW, &fired).unwrap(), 0);
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let line: serde_json::Value = serde_json::from_str(contents.trim()).unwrap();
+        assert_eq!(line["trigger_type"], "late");
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
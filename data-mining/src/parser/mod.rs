@@ -18,6 +18,48 @@ const COMPLETE_EVENT_DISCRIMINATOR: [u8; 8] = [95, 114, 97, 156, 212, 46, 152, 8
 const COMPLETE_PUMP_AMM_MIGRATION_EVENT_DISCRIMINATOR: [u8; 8] =
     [189, 233, 93, 185, 92, 148, 234, 148];
 
+// AMM programs a graduated pump.fun token trades through
+const PUMP_AMM_PROGRAM: &str = "pAMMBay6oceH9fJKBRHGP5D4bD4sWpmSwMn52FMfXEA";
+const RAYDIUM_AMM_V4_PROGRAM: &str = "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8";
+const RAYDIUM_CPMM_PROGRAM: &str = "CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C";
+
+/// Whether a program is an AMM a graduated pump.fun token trades through
+fn is_amm_program(program_id: &str) -> bool {
+    program_id == PUMP_AMM_PROGRAM || program_id == RAYDIUM_AMM_V4_PROGRAM || program_id == RAYDIUM_CPMM_PROGRAM
+}
+
+/// Programs executing at each point of a transaction's logs, innermost last
+///
+/// Follows the runtime's `Program <id> invoke [n]` / `Program <id> success` /
+/// `Program <id> failed: ...` lines; everything a program logs in between
+/// (including "Program data:" events) was emitted by the innermost frame.
+#[derive(Default)]
+struct InvokeStack<'a> {
+    frames: Vec<&'a str>,
+}
+
+impl<'a> InvokeStack<'a> {
+    fn observe(&mut self, log: &'a str) {
+        let Some(rest) = log.strip_prefix("Program ") else {
+            return;
+        };
+        let (program_id, status) = rest.split_once(' ').unwrap_or((rest, ""));
+        // "Program log:", "Program data:", "Program return:" ... are payloads, not frames
+        if program_id.ends_with(':') {
+            return;
+        }
+        if status.starts_with("invoke [") {
+            self.frames.push(program_id);
+        } else if status == "success" || status.starts_with("failed") {
+            self.frames.pop();
+        }
+    }
+
+    fn current(&self) -> Option<&'a str> {
+        self.frames.last().copied()
+    }
+}
+
 pub struct PumpParser {
     pump_program_id: Pubkey,
 }
@@ -59,27 +101,19 @@ impl PumpParser {
         // STEP 1: Parse event logs (existing method - most reliable)
         if let Some(tx_info) = &tx.transaction {
             if let Some(meta) = &tx.meta {
-                debug!("🔍 Checking {} log messages", meta.log_messages.len());
-                for (i, log) in meta.log_messages.iter().enumerate() {
-                    if log.contains("Program data: ") {
-                        debug!("🎯 Found 'Program data:' in log {}", i);
-                        if let Some(event_data) = self.extract_event_data(log) {
-                            debug!("📦 Extracted {} bytes of event data", event_data.len());
-                            if let Some(event) = self.parse_event(&event_data, &signature, slot, block_time)? {
-                                debug!("✅ Successfully parsed event from logs!");
-                                events.push(event);
-                            }
-                        }
-                    }
-                }
+                // Static keys followed by v0 lookup-table addresses (same order as instruction indices)
+                let account_keys: Vec<String> = tx_info.message.iter()
+                    .flat_map(|msg| msg.account_keys.iter())
+                    .chain(meta.loaded_writable_addresses.iter())
+                    .chain(meta.loaded_readonly_addresses.iter())
+                    .map(|k| bs58::encode(k).into_string())
+                    .collect();
+
+                events.extend(self.parse_log_events(&meta.log_messages, &signature, slot, block_time)?);
 
                 // STEP 2: Check inner instructions (NEW - catches missed BUYs/SELLs)
                 // This is where many transactions hide!
                 if let Some(tx_msg) = &tx_info.message {
-                    let account_keys: Vec<String> = tx_msg.account_keys.iter()
-                        .map(|k| bs58::encode(k).into_string())
-                        .collect();
-                    
                     debug!("🔍 Checking {} inner instruction sets", meta.inner_instructions.len());
                     for inner_ix_set in &meta.inner_instructions {
                         for inner_ix in &inner_ix_set.instructions {
@@ -327,6 +361,40 @@ impl PumpParser {
         Ok(None)
     }
 
+    /// Parse the events a transaction logged as "Program data:"
+    ///
+    /// Each event is attributed to the program that emitted it (innermost frame
+    /// of the invoke stack), so a router mixing a bonding-curve leg with an AMM
+    /// leg still reports the curve trade as non-AMM.
+    fn parse_log_events(
+        &self,
+        log_messages: &[String],
+        signature: &Signature,
+        slot: u64,
+        block_time: i64,
+    ) -> Result<Vec<PumpEvent>> {
+        let mut events = Vec::new();
+        let mut invoke_stack = InvokeStack::default();
+
+        debug!("🔍 Checking {} log messages", log_messages.len());
+        for (i, log) in log_messages.iter().enumerate() {
+            invoke_stack.observe(log);
+            if log.contains("Program data: ") {
+                debug!("🎯 Found 'Program data:' in log {}", i);
+                if let Some(event_data) = self.extract_event_data(log) {
+                    debug!("📦 Extracted {} bytes of event data", event_data.len());
+                    let is_amm = invoke_stack.current().is_some_and(is_amm_program);
+                    if let Some(event) = self.parse_event(&event_data, signature, slot, block_time, is_amm)? {
+                        debug!("✅ Successfully parsed event from logs!");
+                        events.push(event);
+                    }
+                }
+            }
+        }
+
+        Ok(events)
+    }
+
     fn extract_event_data(&self, log: &str) -> Option<Vec<u8>> {
         // Anchor logs events as "Program data: <base64>"
         if let Some(start) = log.find("Program data: ") {
@@ -349,6 +417,7 @@ impl PumpParser {
         signature: &Signature,
         slot: u64,
         block_time: i64,
+        is_amm: bool,
     ) -> Result<Option<PumpEvent>> {
         if event_data.len() < 8 {
             debug!("Event data too short: {} bytes", event_data.len());
@@ -366,7 +435,11 @@ impl PumpParser {
             }
             disc if disc == TRADE_EVENT_DISCRIMINATOR => {
                 debug!("✨ Parsing TRADE event");
-                self.parse_trade_event_data(data, signature, slot, block_time)
+                self.parse_trade_event_data(data, signature, slot, block_time, is_amm)
+            }
+            disc if disc == COMPLETE_EVENT_DISCRIMINATOR => {
+                debug!("✨ Parsing COMPLETE event");
+                self.parse_complete_event_data(data, signature, slot, block_time)
             }
             disc if disc == COMPLETE_PUMP_AMM_MIGRATION_EVENT_DISCRIMINATOR => {
                 debug!("✨ Parsing MIGRATION event");
@@ -420,6 +493,7 @@ impl PumpParser {
         signature: &Signature,
         slot: u64,
         block_time: i64,
+        is_amm: bool,
     ) -> Result<Option<PumpEvent>> {
         let mut offset = 0;
 
@@ -443,12 +517,13 @@ impl PumpParser {
         };
 
         debug!(
-            "Parsed trade event: mint={}, side={:?}, amount_sol={}, price={}, virt_sol={}",
+            "Parsed trade event: mint={}, side={:?}, amount_sol={}, price={}, virt_sol={}, amm={}",
             mint, 
             if is_buy { TradeSide::Buy } else { TradeSide::Sell }, 
             (sol_amount as f64) / 1e9, 
             price,
-            (virtual_sol_reserves as f64) / 1e9
+            (virtual_sol_reserves as f64) / 1e9,
+            is_amm
         );
 
         Ok(Some(PumpEvent::Trade {
@@ -465,12 +540,40 @@ impl PumpParser {
             amount_tokens: token_amount,
             amount_sol: sol_amount,
             price,
-            is_amm: false,
+            is_amm,
             virtual_sol_reserves,
             virtual_token_reserves,
         }))
     }
 
+    fn parse_complete_event_data(
+        &self,
+        data: &[u8],
+        signature: &Signature,
+        slot: u64,
+        block_time: i64,
+    ) -> Result<Option<PumpEvent>> {
+        let mut offset = 0;
+
+        let _user = self.read_pubkey(data, &mut offset)?;
+        let mint = self.read_pubkey(data, &mut offset)?;
+        let bonding_curve = self.read_pubkey(data, &mut offset)?;
+        let _timestamp = self.read_i64(data, &mut offset)?;
+
+        debug!(
+            "✨ Parsed COMPLETE (curve full) event: mint={}, bonding_curve={}, sig={}",
+            mint, bonding_curve, signature
+        );
+
+        Ok(Some(PumpEvent::CurveComplete {
+            mint: mint.to_string(),
+            bonding_curve: bonding_curve.to_string(),
+            slot,
+            block_time,
+            signature: signature.to_string(),
+        }))
+    }
+
     fn parse_migrate_event_data(
         &self,
         data: &[u8],
@@ -576,3 +679,277 @@ impl PumpParser {
     }
 }
 pub mod raydium;
+pub mod pump_amm;
+
+/// Parser a subscribed program's instructions are routed to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProgramKind {
+    Pump,
+    PumpAmm,
+    Raydium,
+}
+
+impl ProgramKind {
+    /// Parser for a program ID (`pump_program` is the configured bonding curve);
+    /// None when no parser understands the program
+    pub fn of(program_id: &str, pump_program: &str) -> Option<Self> {
+        match program_id {
+            id if id == pump_program => Some(Self::Pump),
+            PUMP_AMM_PROGRAM => Some(Self::PumpAmm),
+            RAYDIUM_AMM_V4_PROGRAM | RAYDIUM_CPMM_PROGRAM => Some(Self::Raydium),
+            _ => None,
+        }
+    }
+}
+
+/// One gRPC subscription covering several programs
+///
+/// `program_ids()` feeds the stream's `account_include`; each parser only
+/// decodes instructions whose program matches its own ID, so a transaction
+/// touching several programs yields events from each of them.
+pub struct ProgramRouter {
+    program_ids: Vec<String>,
+    pump: PumpParser,
+    pump_amm: Option<pump_amm::PumpAmmParser>,
+    raydium: Vec<raydium::RaydiumParser>,
+}
+
+impl ProgramRouter {
+    pub fn new(pump_program: &str, extra_programs: &[String]) -> Result<Self> {
+        let mut program_ids = vec![pump_program.to_string()];
+        let mut pump_amm = None;
+        // Raydium CPMM swaps of graduated tokens are checked even when not subscribed
+        let mut raydium_ids = vec![RAYDIUM_CPMM_PROGRAM.to_string()];
+
+        for id in extra_programs {
+            if program_ids.contains(id) {
+                continue;
+            }
+            match ProgramKind::of(id, pump_program) {
+                Some(ProgramKind::PumpAmm) => pump_amm = Some(pump_amm::PumpAmmParser::new(id)?),
+                Some(ProgramKind::Raydium) if !raydium_ids.contains(id) => raydium_ids.push(id.clone()),
+                Some(_) => {}
+                None => anyhow::bail!("No parser for program {}", id),
+            }
+            program_ids.push(id.clone());
+        }
+
+        Ok(Self {
+            program_ids,
+            pump: PumpParser::new(pump_program)?,
+            pump_amm,
+            raydium: raydium_ids.iter()
+                .map(|id| raydium::RaydiumParser::new(id))
+                .collect::<Result<_>>()?,
+        })
+    }
+
+    /// Programs to subscribe to (pump.fun first)
+    pub fn program_ids(&self) -> &[String] {
+        &self.program_ids
+    }
+
+    pub fn parse_transaction(
+        &self,
+        tx: &yellowstone_grpc_proto::prelude::SubscribeUpdateTransactionInfo,
+        slot: u64,
+        block_time: i64,
+    ) -> Result<Vec<PumpEvent>> {
+        let mut events = self.pump.parse_transaction(tx, slot, block_time)?;
+        if let Some(pump_amm) = &self.pump_amm {
+            events.extend(pump_amm.parse_transaction(tx, slot, block_time)?);
+        }
+        for raydium in &self.raydium {
+            events.extend(raydium.parse_transaction(tx, slot, block_time)?);
+        }
+        Ok(events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUMP_PROGRAM: &str = "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P";
+    const MINT: &str = "7GCihgDB8fe6KNjn2MYtkzZcRjQy3t9GHdC8uHYmW2hr";
+    const TRADER: &str = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM";
+
+    /// TRADE event payload in on-chain layout, framed as the program logs it
+    fn trade_event_log(is_buy: bool, sol_amount: u64, token_amount: u64) -> String {
+        let mut data = TRADE_EVENT_DISCRIMINATOR.to_vec();
+        data.extend_from_slice(Pubkey::from_str(MINT).unwrap().as_ref());
+        data.extend_from_slice(&sol_amount.to_le_bytes());
+        data.extend_from_slice(&token_amount.to_le_bytes());
+        data.push(is_buy as u8);
+        data.extend_from_slice(Pubkey::from_str(TRADER).unwrap().as_ref());
+        data.extend_from_slice(&1_730_000_000i64.to_le_bytes());
+        data.extend_from_slice(&32_000_000_000u64.to_le_bytes());
+        data.extend_from_slice(&1_000_000_000_000_000u64.to_le_bytes());
+        format!("Program data: {}", general_purpose::STANDARD.encode(data))
+    }
+
+    fn migration_event_log() -> String {
+        let mut data = COMPLETE_PUMP_AMM_MIGRATION_EVENT_DISCRIMINATOR.to_vec();
+        data.extend_from_slice(Pubkey::from_str(TRADER).unwrap().as_ref());
+        data.extend_from_slice(Pubkey::from_str(MINT).unwrap().as_ref());
+        data.extend_from_slice(Pubkey::new_unique().as_ref());
+        data.extend_from_slice(&1_730_000_000i64.to_le_bytes());
+        format!("Program data: {}", general_purpose::STANDARD.encode(data))
+    }
+
+    fn complete_event_log() -> String {
+        let mut data = COMPLETE_EVENT_DISCRIMINATOR.to_vec();
+        data.extend_from_slice(Pubkey::from_str(TRADER).unwrap().as_ref());
+        data.extend_from_slice(Pubkey::from_str(MINT).unwrap().as_ref());
+        data.extend_from_slice(Pubkey::new_unique().as_ref());
+        data.extend_from_slice(&1_730_000_000i64.to_le_bytes());
+        format!("Program data: {}", general_purpose::STANDARD.encode(data))
+    }
+
+    fn parse_trade_log(parser: &PumpParser, log: &str, is_amm: bool) -> PumpEvent {
+        let data = parser.extract_event_data(log).unwrap();
+        parser
+            .parse_event(&data, &Signature::default(), 1, 1_730_000_000, is_amm)
+            .unwrap()
+            .unwrap()
+    }
+
+    /// Logs of `program` invoked at `depth`, emitting `event_log`
+    fn invocation(program: &str, depth: usize, event_log: String) -> Vec<String> {
+        vec![
+            format!("Program {} invoke [{}]", program, depth),
+            "Program log: Instruction: Buy".to_string(),
+            event_log,
+            format!("Program {} consumed 31337 of 200000 compute units", program),
+            format!("Program {} success", program),
+        ]
+    }
+
+    fn parse_logs(parser: &PumpParser, logs: &[String]) -> Vec<PumpEvent> {
+        parser.parse_log_events(logs, &Signature::default(), 1, 1_730_000_000).unwrap()
+    }
+
+    #[test]
+    fn test_bonding_curve_trade_is_not_amm() {
+        let parser = PumpParser::new(PUMP_PROGRAM).unwrap();
+        let logs = invocation(PUMP_PROGRAM, 1, trade_event_log(true, 500_000_000, 17_000_000_000));
+
+        match parse_logs(&parser, &logs).as_slice() {
+            [PumpEvent::Trade { is_amm, side, mint, .. }] => {
+                assert!(!is_amm);
+                assert_eq!(*side, TradeSide::Buy);
+                assert_eq!(mint, MINT);
+            }
+            other => panic!("expected one trade, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_event_emitted_by_amm_program_is_amm() {
+        let parser = PumpParser::new(PUMP_PROGRAM).unwrap();
+
+        for amm_program in [PUMP_AMM_PROGRAM, RAYDIUM_AMM_V4_PROGRAM, RAYDIUM_CPMM_PROGRAM] {
+            let logs = invocation(amm_program, 1, trade_event_log(false, 2_000_000_000, 40_000_000_000));
+            match parse_logs(&parser, &logs).as_slice() {
+                [PumpEvent::Trade { is_amm, side, .. }] => {
+                    assert!(is_amm, "{} should mark the trade as AMM", amm_program);
+                    assert_eq!(*side, TradeSide::Sell);
+                }
+                other => panic!("expected one trade, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_mixed_router_transaction_attributes_each_event() {
+        let parser = PumpParser::new(PUMP_PROGRAM).unwrap();
+        // Router (depth 1) buys on the curve, then sells through an AMM (depth 2 each)
+        let router = TRADER;
+        let mut logs = vec![format!("Program {} invoke [1]", router)];
+        logs.extend(invocation(PUMP_PROGRAM, 2, trade_event_log(true, 500_000_000, 17_000_000_000)));
+        logs.extend(invocation(PUMP_AMM_PROGRAM, 2, trade_event_log(false, 2_000_000_000, 40_000_000_000)));
+        logs.push("Program log: success".to_string()); // payload, not a frame exit
+        logs.push(format!("Program {} success", router));
+
+        let flags: Vec<(TradeSide, bool)> = parse_logs(&parser, &logs).into_iter()
+            .map(|event| match event {
+                PumpEvent::Trade { side, is_amm, .. } => (side, is_amm),
+                other => panic!("expected trade, got {:?}", other),
+            })
+            .collect();
+        assert_eq!(flags, vec![(TradeSide::Buy, false), (TradeSide::Sell, true)]);
+    }
+
+    #[test]
+    fn test_invoke_stack_follows_frames() {
+        let logs = [
+            format!("Program {} invoke [1]", PUMP_AMM_PROGRAM),
+            format!("Program {} invoke [2]", PUMP_PROGRAM),
+            "Program return: abc AAAA".to_string(),
+            format!("Program {} failed: custom program error: 0x1772", PUMP_PROGRAM),
+        ];
+        let mut stack = InvokeStack::default();
+        let seen: Vec<Option<&str>> = logs.iter()
+            .map(|log| {
+                stack.observe(log);
+                stack.current()
+            })
+            .collect();
+        assert_eq!(seen, vec![Some(PUMP_AMM_PROGRAM), Some(PUMP_PROGRAM), Some(PUMP_PROGRAM), Some(PUMP_AMM_PROGRAM)]);
+    }
+
+    #[test]
+    fn test_trade_event_carries_virtual_reserves() {
+        let parser = PumpParser::new(PUMP_PROGRAM).unwrap();
+        let log = trade_event_log(true, 500_000_000, 17_000_000_000);
+
+        match parse_trade_log(&parser, &log, false) {
+            PumpEvent::Trade { virtual_sol_reserves, virtual_token_reserves, amount_sol, amount_tokens, .. } => {
+                assert_eq!(virtual_sol_reserves, 32_000_000_000);
+                assert_eq!(virtual_token_reserves, 1_000_000_000_000_000);
+                assert_eq!(amount_sol, 500_000_000);
+                assert_eq!(amount_tokens, 17_000_000_000);
+            }
+            other => panic!("expected trade, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_complete_event_is_distinct_from_migration() {
+        let parser = PumpParser::new(PUMP_PROGRAM).unwrap();
+
+        match parse_trade_log(&parser, &complete_event_log(), false) {
+            PumpEvent::CurveComplete { mint, slot, block_time, .. } => {
+                assert_eq!(mint, MINT);
+                assert_eq!(slot, 1);
+                assert_eq!(block_time, 1_730_000_000);
+            }
+            other => panic!("expected curve complete, got {:?}", other),
+        }
+
+        match parse_trade_log(&parser, &migration_event_log(), false) {
+            PumpEvent::Migrated { mint, .. } => assert_eq!(mint, MINT),
+            other => panic!("expected migration, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_program_router_routes_known_programs() {
+        assert_eq!(ProgramKind::of(PUMP_PROGRAM, PUMP_PROGRAM), Some(ProgramKind::Pump));
+        assert_eq!(ProgramKind::of(PUMP_AMM_PROGRAM, PUMP_PROGRAM), Some(ProgramKind::PumpAmm));
+        assert_eq!(ProgramKind::of(RAYDIUM_CPMM_PROGRAM, PUMP_PROGRAM), Some(ProgramKind::Raydium));
+        assert_eq!(ProgramKind::of(TRADER, PUMP_PROGRAM), None);
+
+        let extra = vec![
+            PUMP_AMM_PROGRAM.to_string(),
+            RAYDIUM_AMM_V4_PROGRAM.to_string(),
+            PUMP_PROGRAM.to_string(), // duplicate of pump_program: subscribed once
+        ];
+        let router = ProgramRouter::new(PUMP_PROGRAM, &extra).unwrap();
+        assert_eq!(router.program_ids(), &[PUMP_PROGRAM, PUMP_AMM_PROGRAM, RAYDIUM_AMM_V4_PROGRAM]);
+        assert!(router.pump_amm.is_some());
+        assert_eq!(router.raydium.len(), 2); // CPMM + AMM v4
+
+        assert!(ProgramRouter::new(PUMP_PROGRAM, &[TRADER.to_string()]).is_err());
+    }
+}
@@ -0,0 +1,260 @@
+use crate::types::{PumpEvent, TradeSide};
+use anyhow::{anyhow, Context, Result};
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
+use std::str::FromStr;
+use tracing::{debug, warn};
+
+// Pump AMM (PumpSwap) uses the same Anchor names as the bonding curve: buy/sell
+use super::{BUY_DISCRIMINATOR, SELL_DISCRIMINATOR};
+
+/// Wrapped SOL: the quote side of every pool migrated from pump.fun
+const WSOL_MINT: &str = "So11111111111111111111111111111111111111112";
+
+// buy/sell accounts from the pump AMM IDL (same order for both):
+// 0: pool, 1: user (signer), 2: globalConfig, 3: baseMint, 4: quoteMint,
+// 5: userBaseTokenAccount, 6: userQuoteTokenAccount,
+// 7: poolBaseTokenAccount, 8: poolQuoteTokenAccount, ...
+const USER_IDX: usize = 1;
+const BASE_MINT_IDX: usize = 3;
+const QUOTE_MINT_IDX: usize = 4;
+const POOL_QUOTE_VAULT_IDX: usize = 8;
+
+/// Trades on pump AMM pools (graduated pump.fun tokens)
+///
+/// The instruction only carries a slippage bound on the SOL side, so the SOL
+/// actually swapped is taken from the pool's WSOL vault balance change.
+pub struct PumpAmmParser {
+    pump_amm_program_id: Pubkey,
+}
+
+impl PumpAmmParser {
+    pub fn new(pump_amm_program_id: &str) -> Result<Self> {
+        let pubkey = Pubkey::from_str(pump_amm_program_id)
+            .map_err(|e| anyhow!("Invalid pump AMM program ID: {}", e))?;
+
+        Ok(Self {
+            pump_amm_program_id: pubkey,
+        })
+    }
+
+    pub fn parse_transaction(
+        &self,
+        tx: &yellowstone_grpc_proto::prelude::SubscribeUpdateTransactionInfo,
+        slot: u64,
+        block_time: i64,
+    ) -> Result<Vec<PumpEvent>> {
+        let mut events = Vec::new();
+
+        let (Some(tx_info), Some(meta)) = (&tx.transaction, &tx.meta) else {
+            return Ok(events);
+        };
+        let Some(tx_msg) = &tx_info.message else {
+            return Ok(events);
+        };
+        let signature = match tx_info.signatures.first() {
+            Some(sig) if sig.len() >= 64 => {
+                Signature::try_from(&sig[0..64]).context("Failed to parse signature")?
+            }
+            _ => return Ok(events),
+        };
+
+        // Static keys followed by v0 lookup-table addresses (same order as instruction indices)
+        let account_keys: Vec<String> = tx_msg.account_keys.iter()
+            .chain(meta.loaded_writable_addresses.iter())
+            .chain(meta.loaded_readonly_addresses.iter())
+            .map(|k| bs58::encode(k).into_string())
+            .collect();
+        let program_id = self.pump_amm_program_id.to_string();
+
+        // Routers/aggregators CPI into the pool, so inner instructions first
+        let inner = meta.inner_instructions.iter()
+            .flat_map(|set| set.instructions.iter())
+            .map(|ix| (ix.program_id_index, &ix.accounts, &ix.data));
+        let top_level = tx_msg.instructions.iter()
+            .map(|ix| (ix.program_id_index, &ix.accounts, &ix.data));
+
+        for (program_idx, accounts, data) in inner.chain(top_level) {
+            if account_keys.get(program_idx as usize) != Some(&program_id) {
+                continue;
+            }
+            let accounts: Vec<usize> = accounts.iter().map(|a| *a as usize).collect();
+            if let Some(event) = self.parse_swap_instruction(
+                data,
+                &account_keys,
+                &accounts,
+                |vault_idx| quote_vault_delta(meta, vault_idx),
+                &signature,
+                slot,
+                block_time,
+            )? {
+                events.push(event);
+            }
+        }
+
+        Ok(events)
+    }
+
+    /// Build a Trade from a buy/sell instruction; `vault_delta` returns the
+    /// pool quote vault's signed lamport change for the given account index
+    fn parse_swap_instruction(
+        &self,
+        instruction_data: &[u8],
+        account_keys: &[String],
+        accounts: &[usize],
+        vault_delta: impl Fn(usize) -> Option<i128>,
+        signature: &Signature,
+        slot: u64,
+        block_time: i64,
+    ) -> Result<Option<PumpEvent>> {
+        if instruction_data.len() < 24 {
+            return Ok(None);
+        }
+
+        // buy(base_amount_out, max_quote_amount_in) / sell(base_amount_in, min_quote_amount_out)
+        let side = match &instruction_data[0..8] {
+            disc if disc == BUY_DISCRIMINATOR => TradeSide::Buy,
+            disc if disc == SELL_DISCRIMINATOR => TradeSide::Sell,
+            _ => return Ok(None),
+        };
+        let base_amount = u64::from_le_bytes(instruction_data[8..16].try_into()?);
+
+        if accounts.len() <= POOL_QUOTE_VAULT_IDX
+            || accounts.iter().any(|&idx| idx >= account_keys.len())
+        {
+            warn!("Pump AMM {:?}: account indices out of bounds", side);
+            return Ok(None);
+        }
+
+        let quote_mint = &account_keys[accounts[QUOTE_MINT_IDX]];
+        if quote_mint != WSOL_MINT {
+            debug!("Pump AMM pool quoted in {}, skipping (not a SOL pair)", quote_mint);
+            return Ok(None);
+        }
+        let mint = &account_keys[accounts[BASE_MINT_IDX]];
+        let user = &account_keys[accounts[USER_IDX]];
+
+        // Buys grow the WSOL vault, sells shrink it
+        let sol_amount = match vault_delta(accounts[POOL_QUOTE_VAULT_IDX]) {
+            Some(delta) if delta != 0 => delta.unsigned_abs() as u64,
+            _ => {
+                debug!("Pump AMM {:?} for {}: no quote vault balance change, skipping", side, mint);
+                return Ok(None);
+            }
+        };
+
+        // Same units as bonding-curve trades: lamports / raw 6-decimal tokens
+        let price = if base_amount > 0 {
+            ((sol_amount as f64) / 1e9) / ((base_amount as f64) / 1e6)
+        } else {
+            0.0
+        };
+
+        debug!(
+            "Parsed pump AMM trade: mint={}, side={:?}, amount_sol={}, price={}",
+            mint, side, (sol_amount as f64) / 1e9, price
+        );
+
+        Ok(Some(PumpEvent::Trade {
+            signature: signature.to_string(),
+            slot,
+            block_time,
+            mint: mint.to_string(),
+            side,
+            trader: user.to_string(),
+            amount_tokens: base_amount,
+            amount_sol: sol_amount,
+            price,
+            is_amm: true,
+            virtual_sol_reserves: 0,
+            virtual_token_reserves: 0,
+        }))
+    }
+}
+
+/// Signed change of a token account's raw balance across the transaction
+fn quote_vault_delta(
+    meta: &yellowstone_grpc_proto::prelude::TransactionStatusMeta,
+    account_index: usize,
+) -> Option<i128> {
+    let amount = |balances: &[yellowstone_grpc_proto::prelude::TokenBalance]| {
+        balances.iter()
+            .find(|b| b.account_index as usize == account_index)
+            .and_then(|b| b.ui_token_amount.as_ref())
+            .and_then(|a| a.amount.parse::<i128>().ok())
+    };
+    Some(amount(&meta.post_token_balances)? - amount(&meta.pre_token_balances)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUMP_AMM: &str = "pAMMBay6oceH9fJKBRHGP5D4bD4sWpmSwMn52FMfXEA";
+    const MINT: &str = "7GCihgDB8fe6KNjn2MYtkzZcRjQy3t9GHdC8uHYmW2hr";
+
+    fn swap_data(disc: [u8; 8], base_amount: u64, quote_bound: u64) -> Vec<u8> {
+        let mut data = disc.to_vec();
+        data.extend_from_slice(&base_amount.to_le_bytes());
+        data.extend_from_slice(&quote_bound.to_le_bytes());
+        data
+    }
+
+    /// Account keys laid out so instruction account i is key i
+    fn pool_keys(quote_mint: &str) -> (Vec<String>, Vec<usize>) {
+        let mut keys: Vec<String> = (0..10).map(|_| Pubkey::new_unique().to_string()).collect();
+        keys[BASE_MINT_IDX] = MINT.to_string();
+        keys[QUOTE_MINT_IDX] = quote_mint.to_string();
+        (keys, (0..10).collect())
+    }
+
+    #[test]
+    fn test_pump_amm_buy_and_sell() {
+        let parser = PumpAmmParser::new(PUMP_AMM).unwrap();
+        let (keys, accounts) = pool_keys(WSOL_MINT);
+        let sig = Signature::default();
+
+        // Buy 1,000 tokens; 0.5 SOL went into the vault (bound was 0.6)
+        let buy = parser
+            .parse_swap_instruction(&swap_data(BUY_DISCRIMINATOR, 1_000_000_000, 600_000_000),
+                &keys, &accounts, |_| Some(500_000_000), &sig, 1, 1_730_000_000)
+            .unwrap()
+            .expect("buy parsed");
+        match buy {
+            PumpEvent::Trade { side, mint, amount_sol, amount_tokens, price, is_amm, .. } => {
+                assert_eq!(side, TradeSide::Buy);
+                assert_eq!(mint, MINT);
+                assert_eq!(amount_sol, 500_000_000);
+                assert_eq!(amount_tokens, 1_000_000_000);
+                assert!((price - 0.0005).abs() < 1e-12);
+                assert!(is_amm);
+            }
+            other => panic!("expected Trade, got {:?}", other),
+        }
+
+        let sell = parser
+            .parse_swap_instruction(&swap_data(SELL_DISCRIMINATOR, 1_000_000_000, 400_000_000),
+                &keys, &accounts, |_| Some(-450_000_000), &sig, 1, 1_730_000_000)
+            .unwrap()
+            .expect("sell parsed");
+        assert!(matches!(sell, PumpEvent::Trade { side: TradeSide::Sell, amount_sol: 450_000_000, .. }));
+    }
+
+    #[test]
+    fn test_pump_amm_skips_unpriced_swaps() {
+        let parser = PumpAmmParser::new(PUMP_AMM).unwrap();
+        let sig = Signature::default();
+        let data = swap_data(BUY_DISCRIMINATOR, 1_000_000_000, 600_000_000);
+
+        // Not a SOL pair
+        let (keys, accounts) = pool_keys(MINT);
+        assert!(parser.parse_swap_instruction(&data, &keys, &accounts, |_| Some(1), &sig, 1, 0).unwrap().is_none());
+
+        // No vault balance change to price it from
+        let (keys, accounts) = pool_keys(WSOL_MINT);
+        assert!(parser.parse_swap_instruction(&data, &keys, &accounts, |_| None, &sig, 1, 0).unwrap().is_none());
+
+        // Other pump AMM instructions (deposit, withdraw, ...)
+        let other = swap_data([1, 2, 3, 4, 5, 6, 7, 8], 1, 1);
+        assert!(parser.parse_swap_instruction(&other, &keys, &accounts, |_| Some(1), &sig, 1, 0).unwrap().is_none());
+    }
+}
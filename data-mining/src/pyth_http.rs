@@ -1,50 +1,56 @@
 //! 🔮 Pyth Oracle SOL/USD Price via HTTP API
 //! 
 //! Fetches Pyth price via Hermes HTTP API and broadcasts
-//! real-time SOL/USD price updates via UDP to Brain (45100) and Executor (45110).
+//! signed real-time SOL/USD price updates via UDP to Brain (45100) and
+//! mempool-watcher (`price_oracle.mempool_price_addr`, 45136).
 //!
 //! Features:
 //! - Exponential backoff retry for network resilience
 //! - Confidence interval filtering for price quality
+//! - Jupiter fallback when Pyth confidence blows out (with drift logging)
+//! - Heartbeat re-broadcast with a monotonic ms timestamp so consumers can
+//!   drop stale or out-of-order prices
 //! - SQLite logging for analytics and debugging
 //! - WebSocket-ready architecture for future upgrades
 
 use anyhow::{Context, Result};
 use serde::Deserialize;
+use std::collections::HashMap;
 use std::net::UdpSocket;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
-use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use tokio::time::sleep;
 use tracing::{error, info, warn, debug};
 use rand::{Rng, SeedableRng};
 use rand::rngs::StdRng;
 
+use crate::config::PriceOracleConfig;
+use crate::udp::AdviceAuth;
+
 /// Pyth Hermes API endpoint
 const PYTH_HERMES_API: &str = "https://hermes.pyth.network/v2/updates/price/latest";
 
 /// Pyth SOL/USD Price Feed ID
 const SOL_USD_FEED_ID: &str = "0xef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d";
 
-/// Poll interval (5 seconds)
-const POLL_INTERVAL_SECS: u64 = 5;
-
-/// Maximum confidence interval (3% of price = high quality)
-const MAX_CONFIDENCE_RATIO: f64 = 0.03;
+/// Wrapped SOL mint (Jupiter price lookup key)
+const SOL_MINT: &str = "So11111111111111111111111111111111111111112";
 
 /// Exponential backoff parameters
 const INITIAL_RETRY_DELAY_MS: u64 = 100;
 const MAX_RETRY_DELAY_MS: u64 = 5000;
 const MAX_RETRIES: u32 = 5;
 
-/// UDP ports for broadcasting price updates
+/// UDP port for broadcasting price updates to Brain
 const BRAIN_UDP_PORT: u16 = 45100;
-const EXECUTOR_UDP_PORT: u16 = 45110;
 
 /// Message type for SolPriceUpdate
 const SOL_PRICE_UPDATE_MSG_TYPE: u8 = 14;
 
-/// Pyth price source identifier
+/// Price source identifiers (SolPriceUpdate.source)
 const PYTH_SOURCE: u8 = 1;
+const JUPITER_SOURCE: u8 = 2;
 
 #[derive(Debug, Deserialize)]
 struct PythResponse {
@@ -64,6 +70,13 @@ struct PythPrice {
     publish_time: i64,
 }
 
+/// Jupiter price API response: { "<mint>": { "usdPrice": 187.12, ... } }
+#[derive(Debug, Deserialize)]
+struct JupiterPrice {
+    #[serde(rename = "usdPrice")]
+    usd_price: f64,
+}
+
 /// Price data with confidence
 #[derive(Debug, Clone)]
 pub struct PriceData {
@@ -77,13 +90,15 @@ pub struct PythHttp {
     client: reqwest::Client,
     udp_socket: UdpSocket,
     brain_addr: String,
-    executor_addr: String,
     db: Option<Arc<Mutex<crate::Database>>>,
     price_buffer: Arc<Mutex<Vec<f32>>>, // Rolling buffer of last 3 prices for median filtering
+    oracle: PriceOracleConfig,
+    auth: AdviceAuth, // Signs Brain-bound packets
+    last_timestamp_ms: AtomicU64, // Last broadcast timestamp (kept strictly increasing)
 }
 
 impl PythHttp {
-    pub fn new(db: Option<Arc<Mutex<crate::Database>>>) -> Result<Self> {
+    pub fn new(db: Option<Arc<Mutex<crate::Database>>>, oracle: PriceOracleConfig, auth: AdviceAuth) -> Result<Self> {
         let client = reqwest::Client::builder()
             .timeout(Duration::from_secs(10))
             .build()
@@ -99,46 +114,56 @@ impl PythHttp {
             client,
             udp_socket,
             brain_addr: format!("127.0.0.1:{}", BRAIN_UDP_PORT),
-            executor_addr: format!("127.0.0.1:{}", EXECUTOR_UDP_PORT),
             db,
             price_buffer: Arc::new(Mutex::new(Vec::with_capacity(3))),
+            oracle,
+            auth,
+            last_timestamp_ms: AtomicU64::new(0),
         })
     }
 
     pub async fn run(&self) -> Result<()> {
         info!("🔮 Starting Pyth SOL/USD Price Fetcher (HTTP API)");
         info!("   📡 Feed ID: {}", SOL_USD_FEED_ID);
-        info!("   🎯 Broadcast to: Brain ({}), Executor ({})", 
-            self.brain_addr, self.executor_addr
+        info!("   🎯 Broadcast to: Brain ({}), mempool-watcher ({})",
+            self.brain_addr,
+            if self.oracle.mempool_price_addr.is_empty() { "off" } else { &self.oracle.mempool_price_addr }
+        );
+        info!("   ✅ Confidence filtering enabled (max {:.1}%)", self.oracle.confidence_ratio * 100.0);
+        if self.oracle.jupiter_fallback {
+            info!("   🪐 Jupiter fallback enabled ({})", self.oracle.jupiter_price_url);
+        }
+        info!("   🎲 Poll every {}s (±2s jitter), heartbeat every {}s",
+            self.oracle.broadcast_interval_secs, self.oracle.heartbeat_secs
         );
-        info!("   ✅ Confidence filtering enabled (max {:.1}%)", MAX_CONFIDENCE_RATIO * 100.0);
-        info!("   🎲 Jitter enabled (±2s randomization to avoid collisions)");
         if self.db.is_some() {
             info!("   💾 SQLite price logging enabled");
         }
 
         let mut latest_price: Option<f32> = None;
+        let mut last_broadcast: Option<Instant> = None;
+        let heartbeat = Duration::from_secs(self.oracle.heartbeat_secs);
         let mut rng = StdRng::from_entropy();
 
         loop {
-            // Add jitter: 5 seconds ±2s = 3-7 seconds
+            // Add jitter: interval ±2s
             let jitter_ms = rng.gen_range(-2000..=2000);
-            let interval_ms = (POLL_INTERVAL_SECS * 1000) as i64 + jitter_ms;
+            let interval_ms = (self.oracle.broadcast_interval_secs * 1000) as i64 + jitter_ms;
             let interval_ms = interval_ms.max(1000) as u64; // Minimum 1 second
             
             sleep(Duration::from_millis(interval_ms)).await;
 
             match self.fetch_price_with_retry().await {
-                Ok(price_data) => {
-                    // Check confidence ratio
-                    if price_data.confidence_ratio > MAX_CONFIDENCE_RATIO {
-                        warn!(
-                            "⚠️  Skipping low-confidence price: ${:.4} (conf: {:.1}%)",
-                            price_data.price,
-                            price_data.confidence_ratio * 100.0
-                        );
-                        continue;
-                    }
+                Ok(pyth_data) => {
+                    // Check confidence ratio, falling back to Jupiter when too wide
+                    let (price_data, source) = if pyth_data.confidence_ratio > self.oracle.confidence_ratio {
+                        match self.fallback_price(&pyth_data).await {
+                            Some(fallback) => (fallback, JUPITER_SOURCE),
+                            None => continue,
+                        }
+                    } else {
+                        (pyth_data, PYTH_SOURCE)
+                    };
 
                     // Add to rolling buffer and compute median
                     let mut buffer = self.price_buffer.lock().unwrap();
@@ -158,27 +183,33 @@ impl PythHttp {
                     drop(buffer);
 
                     // Detect significant price change (>$0.10) using filtered price
-                    let should_broadcast = latest_price.map_or(true, |old| {
+                    let changed = latest_price.map_or(true, |old| {
                         (filtered_price - old).abs() > 0.10
                     });
+                    let heartbeat_due = last_broadcast.map_or(true, |at| at.elapsed() >= heartbeat);
 
-                    if should_broadcast {
+                    if changed || heartbeat_due {
                         // Broadcast filtered price to Brain & Executor
-                        if let Err(e) = self.broadcast_price(filtered_price) {
+                        if let Err(e) = self.broadcast_price(filtered_price, source) {
                             warn!("Failed to broadcast price: {}", e);
+                        } else if !changed {
+                            debug!("💓 SOL/USD heartbeat: ${:.4} ({})", filtered_price, source_name(source));
+                            last_broadcast = Some(Instant::now());
                         } else {
                             info!(
-                                "📊 SOL/USD: ${:.4} (median-filtered) ±${:.4} ({:.2}% conf)",
+                                "📊 SOL/USD: ${:.4} (median-filtered, {}) ±${:.4} ({:.2}% conf)",
                                 filtered_price,
+                                source_name(source),
                                 price_data.confidence,
                                 price_data.confidence_ratio * 100.0
                             );
                             latest_price = Some(filtered_price);
+                            last_broadcast = Some(Instant::now());
                         }
 
-                        // Log to SQLite
-                        if let Some(ref db) = self.db {
-                            if let Err(e) = self.log_price_to_db(db, &price_data) {
+                        // Log to SQLite (price changes only, not heartbeats)
+                        if let (true, Some(db)) = (changed, &self.db) {
+                            if let Err(e) = self.log_price_to_db(db, &price_data, source) {
                                 debug!("Failed to log price to SQLite: {}", e);
                             }
                         }
@@ -191,6 +222,83 @@ impl PythHttp {
         }
     }
 
+    /// Replace a low-confidence Pyth price with Jupiter's, logging oracle drift
+    ///
+    /// Returns None (skip this cycle) when the fallback is disabled or unavailable.
+    async fn fallback_price(&self, pyth_data: &PriceData) -> Option<PriceData> {
+        if !self.oracle.jupiter_fallback {
+            warn!(
+                "⚠️  Skipping low-confidence price: ${:.4} (conf: {:.1}%)",
+                pyth_data.price,
+                pyth_data.confidence_ratio * 100.0
+            );
+            return None;
+        }
+
+        match self.fetch_jupiter_price().await {
+            Ok(jupiter_price) => {
+                let divergence_pct = divergence_pct(pyth_data.price, jupiter_price);
+                if divergence_pct > self.oracle.divergence_warn_pct {
+                    warn!(
+                        "🚨 Oracle drift: Pyth ${:.4} vs Jupiter ${:.4} ({:.2}% apart)",
+                        pyth_data.price, jupiter_price, divergence_pct
+                    );
+                }
+                warn!(
+                    "⚠️  Pyth confidence too wide ({:.1}%), using Jupiter: ${:.4}",
+                    pyth_data.confidence_ratio * 100.0,
+                    jupiter_price
+                );
+                let timestamp = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap()
+                    .as_secs() as i64;
+                Some(PriceData {
+                    price: jupiter_price,
+                    confidence: 0.0,
+                    confidence_ratio: 0.0,
+                    timestamp,
+                })
+            }
+            Err(e) => {
+                warn!(
+                    "⚠️  Skipping low-confidence price: ${:.4} (conf: {:.1}%), Jupiter fallback failed: {}",
+                    pyth_data.price,
+                    pyth_data.confidence_ratio * 100.0,
+                    e
+                );
+                None
+            }
+        }
+    }
+
+    /// Fetch SOL/USD from the Jupiter price API
+    async fn fetch_jupiter_price(&self) -> Result<f32> {
+        let url = format!("{}?ids={}", self.oracle.jupiter_price_url, SOL_MINT);
+
+        let prices: HashMap<String, JupiterPrice> = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .context("Failed to send request to Jupiter API")?
+            .json()
+            .await
+            .context("Failed to parse Jupiter response")?;
+
+        let price = prices
+            .get(SOL_MINT)
+            .context("No SOL price in Jupiter response")?
+            .usd_price;
+
+        // Same sanity range as Pyth
+        if price < 1.0 || price > 10_000.0 {
+            anyhow::bail!("Jupiter price out of range: ${:.2}", price);
+        }
+
+        Ok(price as f32)
+    }
+
     /// Fetch price with exponential backoff retry
     async fn fetch_price_with_retry(&self) -> Result<PriceData> {
         let mut retry_count = 0;
@@ -285,6 +393,7 @@ impl PythHttp {
         &self,
         db: &Arc<Mutex<crate::Database>>,
         price_data: &PriceData,
+        source: u8,
     ) -> Result<()> {
         let mut db = db.lock().unwrap();
         db.log_pyth_price(
@@ -292,42 +401,109 @@ impl PythHttp {
             price_data.price,
             price_data.confidence,
             price_data.confidence_ratio,
-            "pyth_http",
+            if source == JUPITER_SOURCE { "jupiter_http" } else { "pyth_http" },
         )?;
         Ok(())
     }
 
-    fn broadcast_price(&self, price: f32) -> Result<()> {
-        let timestamp = SystemTime::now()
+    fn broadcast_price(&self, price: f32, source: u8) -> Result<()> {
+        let now_ms = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .unwrap()
-            .as_secs();
+            .as_millis() as u64;
+        let prev_ms = self.last_timestamp_ms.load(Ordering::Relaxed);
+        let timestamp_ms = next_timestamp_ms(now_ms, prev_ms);
+        self.last_timestamp_ms.store(timestamp_ms, Ordering::Relaxed);
 
         // Message format matching Brain's SolPriceUpdate struct:
-        // [msg_type(1), price_usd(4), timestamp(8), source(1), padding(18)] = 32 bytes
+        // [msg_type(1), price_usd(4), timestamp(8), source(1), timestamp_ms(8), padding(10)] = 32 bytes
         let mut msg = vec![0u8; 32];
         msg[0] = SOL_PRICE_UPDATE_MSG_TYPE;
         msg[1..5].copy_from_slice(&price.to_le_bytes());
-        msg[5..13].copy_from_slice(&timestamp.to_le_bytes());
-        msg[13] = PYTH_SOURCE;
-        // bytes 14-31 are already zero (padding)
+        msg[5..13].copy_from_slice(&(timestamp_ms / 1000).to_le_bytes());
+        msg[13] = source;
+        msg[14..22].copy_from_slice(&timestamp_ms.to_le_bytes());
+        // bytes 22-31 are already zero (padding)
 
-        // Send to Brain only (Executor doesn't need price updates)
+        // Brain and mempool-watcher (manual-exit P&L); Executor doesn't need price updates
+        let packet = self.auth.sign(&msg);
         self.udp_socket
-            .send_to(&msg, &self.brain_addr)
+            .send_to(&packet, &self.brain_addr)
             .context("Failed to send price to Brain")?;
+        if !self.oracle.mempool_price_addr.is_empty() {
+            self.udp_socket
+                .send_to(&packet, &self.oracle.mempool_price_addr)
+                .context("Failed to send price to mempool-watcher")?;
+        }
 
         Ok(())
     }
 }
 
+/// Broadcast timestamp in ms, strictly increasing even if the wall clock steps
+/// back, so consumers can reject replayed or reordered prices
+fn next_timestamp_ms(now_ms: u64, prev_ms: u64) -> u64 {
+    now_ms.max(prev_ms + 1)
+}
+
+/// Percentage difference between two prices, relative to the first
+fn divergence_pct(reference: f32, other: f32) -> f64 {
+    if reference <= 0.0 {
+        return 0.0;
+    }
+    ((other - reference).abs() / reference) as f64 * 100.0
+}
+
+fn source_name(source: u8) -> &'static str {
+    match source {
+        PYTH_SOURCE => "pyth",
+        JUPITER_SOURCE => "jupiter",
+        _ => "unknown",
+    }
+}
+
+/// Spawn the pyth_prices retention job: every `history_cleanup_interval_secs`,
+/// delete rows older than `history_retention_days` (no-op when retention is 0)
+pub fn spawn_pyth_price_cleanup(
+    db: Arc<Mutex<crate::Database>>,
+    oracle: &PriceOracleConfig,
+) -> Option<tokio::task::JoinHandle<()>> {
+    if oracle.history_retention_days == 0 {
+        info!("🧹 pyth_prices retention: disabled (keeping all history)");
+        return None;
+    }
+    let retention_secs = (oracle.history_retention_days * 86_400) as i64;
+    let interval_secs = oracle.history_cleanup_interval_secs;
+
+    Some(tokio::spawn(async move {
+        info!("🧹 pyth_prices retention: keeping {}d, cleanup every {}s",
+            retention_secs / 86_400, interval_secs);
+
+        let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+        loop {
+            interval.tick().await;
+
+            let result = db.lock().unwrap().cleanup_old_pyth_prices(retention_secs);
+            match result {
+                Ok(deleted) if deleted > 0 => info!("🧹 Pruned {} pyth_prices rows", deleted),
+                Ok(_) => {}
+                Err(e) => warn!("⚠️  pyth_prices cleanup failed: {}", e),
+            }
+        }
+    }))
+}
+
 /// Spawn Pyth HTTP fetcher in background with optional DB logging
-pub fn spawn_pyth_http(db: Option<Arc<Mutex<crate::Database>>>) -> tokio::task::JoinHandle<()> {
+pub fn spawn_pyth_http(
+    db: Option<Arc<Mutex<crate::Database>>>,
+    oracle: PriceOracleConfig,
+    auth: AdviceAuth,
+) -> tokio::task::JoinHandle<()> {
     tokio::spawn(async move {
         info!("🚀 Spawning Pyth HTTP fetcher task");
 
         loop {
-            match PythHttp::new(db.clone()) {
+            match PythHttp::new(db.clone(), oracle.clone(), auth.clone()) {
                 Ok(fetcher) => {
                     info!("✅ Pyth HTTP fetcher initialized");
 
@@ -346,3 +522,17 @@ pub fn spawn_pyth_http(db: Option<Arc<Mutex<crate::Database>>>) -> tokio::task::
         }
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_timestamp_ms_is_monotonic() {
+        assert_eq!(next_timestamp_ms(1_000, 0), 1_000);
+        assert_eq!(next_timestamp_ms(2_000, 1_000), 2_000);
+        // Same millisecond or clock stepped back: still strictly increasing
+        assert_eq!(next_timestamp_ms(2_000, 2_000), 2_001);
+        assert_eq!(next_timestamp_ms(1_500, 2_001), 2_002);
+    }
+}
@@ -14,6 +14,8 @@ use std::time::{SystemTime, UNIX_EPOCH};
 use tokio::time::{interval, Duration};
 use tokio_stream::StreamExt;
 use tracing::{error, info, warn};
+
+use crate::udp::AdviceAuth;
 use yellowstone_grpc_client::GeyserGrpcClient;
 use yellowstone_grpc_proto::geyser::{
     subscribe_update::UpdateOneof, CommitmentLevel, SubscribeRequest,
@@ -43,11 +45,12 @@ pub struct PythSubscriber {
     pyth_feed_pubkey: Pubkey,
     brain_addr: String,
     executor_addr: String,
+    auth: AdviceAuth, // Signs Brain-bound packets
 }
 
 impl PythSubscriber {
     /// Create new Pyth subscriber
-    pub fn new(grpc_endpoint: String) -> Result<Self> {
+    pub fn new(grpc_endpoint: String, auth: AdviceAuth) -> Result<Self> {
         // Non-blocking UDP socket for broadcasting
         let udp_socket = UdpSocket::bind("0.0.0.0:0")
             .context("Failed to bind UDP socket for Pyth price broadcast")?;
@@ -63,6 +66,7 @@ impl PythSubscriber {
             pyth_feed_pubkey,
             brain_addr: format!("127.0.0.1:{}", BRAIN_UDP_PORT),
             executor_addr: format!("127.0.0.1:{}", EXECUTOR_UDP_PORT),
+            auth,
         })
     }
 
@@ -221,7 +225,7 @@ impl PythSubscriber {
 
         // Broadcast to Brain
         self.udp_socket
-            .send_to(&msg, &self.brain_addr)
+            .send_to(&self.auth.sign(&msg), &self.brain_addr)
             .context("Failed to send price to Brain")?;
 
         // Broadcast to Executor
@@ -234,12 +238,12 @@ impl PythSubscriber {
 }
 
 /// Spawn Pyth subscriber as background task
-pub fn spawn_pyth_subscriber(grpc_endpoint: String) -> tokio::task::JoinHandle<()> {
+pub fn spawn_pyth_subscriber(grpc_endpoint: String, auth: AdviceAuth) -> tokio::task::JoinHandle<()> {
     tokio::spawn(async move {
         info!("🚀 Spawning Pyth subscriber task");
         
         loop {
-            match PythSubscriber::new(grpc_endpoint.clone()) {
+            match PythSubscriber::new(grpc_endpoint.clone(), auth.clone()) {
                 Ok(subscriber) => {
                     info!("✅ Pyth subscriber initialized");
                     
@@ -266,7 +270,7 @@ mod tests {
 
     #[test]
     fn test_parse_pyth_price() {
-        let subscriber = PythSubscriber::new("http://localhost:10000".to_string()).unwrap();
+        let subscriber = PythSubscriber::new("http://localhost:10000".to_string(), AdviceAuth::new(b"test-secret")).unwrap();
         
         // Mock Pyth account data with price=24523456, exponent=-6
         let mut data = vec![0u8; 240];
@@ -14,6 +14,8 @@ use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tokio::time::interval;
 use tracing::{error, info, warn};
 
+use crate::udp::AdviceAuth;
+
 /// Pyth SOL/USD Price Feed Account (Mainnet)
 const PYTH_SOL_USD_FEED: &str = "H6ARHf6YoNAfHp2rGQTqSXRfxiAqoFvkVZoxMdVpZGgr";
 
@@ -39,11 +41,12 @@ pub struct PythSubscriberRpc {
     pyth_feed_pubkey: Pubkey,
     brain_addr: String,
     executor_addr: String,
+    auth: AdviceAuth, // Signs Brain-bound packets
 }
 
 impl PythSubscriberRpc {
     /// Create new Pyth subscriber with RPC polling
-    pub fn new(rpc_endpoint: String) -> Result<Self> {
+    pub fn new(rpc_endpoint: String, auth: AdviceAuth) -> Result<Self> {
         // Non-blocking UDP socket for broadcasting
         let udp_socket = UdpSocket::bind("0.0.0.0:0")
             .context("Failed to bind UDP socket for Pyth price broadcast")?;
@@ -62,6 +65,7 @@ impl PythSubscriberRpc {
             pyth_feed_pubkey,
             brain_addr: format!("127.0.0.1:{}", BRAIN_UDP_PORT),
             executor_addr: format!("127.0.0.1:{}", EXECUTOR_UDP_PORT),
+            auth,
         })
     }
 
@@ -190,7 +194,7 @@ impl PythSubscriberRpc {
 
         // Send to Brain
         self.udp_socket
-            .send_to(&msg, &self.brain_addr)
+            .send_to(&self.auth.sign(&msg), &self.brain_addr)
             .context("Failed to send price to Brain")?;
 
         // Send to Executor
@@ -203,12 +207,12 @@ impl PythSubscriberRpc {
 }
 
 /// Spawn Pyth subscriber task in background with auto-retry
-pub fn spawn_pyth_subscriber_rpc(rpc_endpoint: String) -> tokio::task::JoinHandle<()> {
+pub fn spawn_pyth_subscriber_rpc(rpc_endpoint: String, auth: AdviceAuth) -> tokio::task::JoinHandle<()> {
     tokio::spawn(async move {
         info!("🚀 Spawning Pyth RPC subscriber task");
         
         loop {
-            match PythSubscriberRpc::new(rpc_endpoint.clone()) {
+            match PythSubscriberRpc::new(rpc_endpoint.clone(), auth.clone()) {
                 Ok(subscriber) => {
                     info!("✅ Pyth RPC subscriber initialized");
                     
@@ -0,0 +1,154 @@
+//! 🔀 Reorg Tracker - detect orphaned slots so their trades can be rolled back
+//!
+//! The stream runs at `Confirmed` commitment, so a slot we already recorded
+//! trades from can still be reorged out before it's finalized. Every slot we
+//! processed transactions from is held here (with its signatures) until a slot
+//! status update finalizes it:
+//! - `Dead` orphans a held slot immediately
+//! - once the finalized tip is `FINALITY_MARGIN_SLOTS` past a held slot that
+//!   was never finalized itself, that slot is off the canonical chain (the
+//!   margin tolerates rooted-slot notifications arriving out of order)
+//!
+//! Orphaned slots come back as a `Rollback`: the caller deletes their trades,
+//! rewinds the checkpoint and forgets their signatures so transactions that get
+//! re-included in a later slot aren't skipped as duplicates.
+
+use std::collections::BTreeMap;
+
+/// Finalized-tip distance after which an unfinalized held slot is orphaned
+pub const FINALITY_MARGIN_SLOTS: u64 = 64;
+
+/// Held slots before the oldest is dropped unverified (slot stream stalled)
+pub const DEFAULT_MAX_PENDING_SLOTS: usize = 1024;
+
+/// Slot status as far as reorg detection cares
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlotState {
+    Finalized,
+    Dead,
+    Other,
+}
+
+/// Slots reorged out, and the signatures we processed from them
+#[derive(Debug, Default, PartialEq)]
+pub struct Rollback {
+    pub slots: Vec<u64>,
+    pub signatures: Vec<String>,
+}
+
+pub struct ReorgTracker {
+    /// Processed-but-unfinalized slot → signatures processed from it
+    pending: BTreeMap<u64, Vec<String>>,
+    max_pending_slots: usize,
+}
+
+impl Default for ReorgTracker {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_PENDING_SLOTS)
+    }
+}
+
+impl ReorgTracker {
+    pub fn new(max_pending_slots: usize) -> Self {
+        Self {
+            pending: BTreeMap::new(),
+            max_pending_slots,
+        }
+    }
+
+    /// Remember a processed transaction until its slot is finalized
+    pub fn record(&mut self, slot: u64, signature: &str) {
+        self.pending.entry(slot).or_default().push(signature.to_string());
+        while self.pending.len() > self.max_pending_slots {
+            self.pending.pop_first();
+        }
+    }
+
+    /// Apply a slot status update; returns the slots to roll back, if any
+    pub fn on_slot_status(&mut self, slot: u64, state: SlotState) -> Option<Rollback> {
+        let orphaned: Vec<u64> = match state {
+            SlotState::Finalized => {
+                self.pending.remove(&slot);
+                let cutoff = slot.saturating_sub(FINALITY_MARGIN_SLOTS);
+                self.pending.range(..cutoff).map(|(s, _)| *s).collect()
+            }
+            SlotState::Dead if self.pending.contains_key(&slot) => vec![slot],
+            _ => return None,
+        };
+
+        if orphaned.is_empty() {
+            return None;
+        }
+
+        let mut rollback = Rollback::default();
+        for slot in orphaned {
+            if let Some(signatures) = self.pending.remove(&slot) {
+                rollback.slots.push(slot);
+                rollback.signatures.extend(signatures);
+            }
+        }
+        Some(rollback)
+    }
+
+    /// Slots still waiting to be finalized
+    pub fn pending_slots(&self) -> usize {
+        self.pending.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_finalized_slots_are_released() {
+        let mut tracker = ReorgTracker::default();
+        tracker.record(100, "sig_a");
+        tracker.record(101, "sig_b");
+
+        assert_eq!(tracker.on_slot_status(100, SlotState::Finalized), None);
+        assert_eq!(tracker.on_slot_status(101, SlotState::Finalized), None);
+        assert_eq!(tracker.pending_slots(), 0);
+
+        // Confirmed/processed updates never roll anything back
+        tracker.record(102, "sig_c");
+        assert_eq!(tracker.on_slot_status(102, SlotState::Other), None);
+    }
+
+    #[test]
+    fn test_skipped_over_slot_is_rolled_back() {
+        let mut tracker = ReorgTracker::default();
+        tracker.record(100, "sig_a");
+        tracker.record(100, "sig_b");
+        tracker.record(101, "sig_c");
+
+        // 101 finalized but 100 wasn't: within the margin it may still arrive
+        assert_eq!(tracker.on_slot_status(101, SlotState::Finalized), None);
+        assert_eq!(tracker.pending_slots(), 1);
+
+        // Finalized tip moves past the margin without 100 ever finalizing
+        let rollback = tracker.on_slot_status(100 + FINALITY_MARGIN_SLOTS + 1, SlotState::Finalized).unwrap();
+        assert_eq!(rollback.slots, vec![100]);
+        assert_eq!(rollback.signatures, vec!["sig_a".to_string(), "sig_b".to_string()]);
+        assert_eq!(tracker.pending_slots(), 0);
+    }
+
+    #[test]
+    fn test_dead_slot_rolls_back_immediately() {
+        let mut tracker = ReorgTracker::default();
+        tracker.record(200, "sig_a");
+
+        assert_eq!(tracker.on_slot_status(201, SlotState::Dead), None);
+        let rollback = tracker.on_slot_status(200, SlotState::Dead).unwrap();
+        assert_eq!(rollback.slots, vec![200]);
+    }
+
+    #[test]
+    fn test_pending_is_bounded() {
+        let mut tracker = ReorgTracker::new(2);
+        for slot in 0..5 {
+            tracker.record(slot, "sig");
+        }
+        assert_eq!(tracker.pending_slots(), 2);
+    }
+}
@@ -0,0 +1,380 @@
+/// Historical replay of recorded trades
+///
+/// Reads the `trades` table in timestamp order and yields the same
+/// `PumpEvent::Trade` events the live gRPC parser produces, so the
+/// momentum/window/hotlist pipeline can be re-run deterministically
+/// against recorded data (e.g. for tuning Brain thresholds).
+///
+/// Usage: `data-mining --replay [--replay-speed 10] [--replay-from <unix>] [--replay-to <unix>]`
+/// A speed of 0 replays as fast as possible.
+
+use std::collections::VecDeque;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+use rusqlite::{params, Connection, OpenFlags};
+use tracing::debug;
+
+use crate::clock::ReplayClock;
+use crate::types::{PumpEvent, TradeSide};
+
+/// Approximate slot duration used to spread trades within a second
+const SLOT_MS: u64 = 400;
+
+/// Replay command-line options
+#[derive(Debug, Clone)]
+pub struct ReplayOptions {
+    /// Playback speed multiplier (10.0 = 10x real time, 0 = unthrottled)
+    pub speed: f64,
+    /// Only replay trades with block_time >= from_time
+    pub from_time: Option<i64>,
+    /// Only replay trades with block_time <= to_time
+    pub to_time: Option<i64>,
+    /// Rows fetched per query
+    pub batch_size: usize,
+}
+
+impl Default for ReplayOptions {
+    fn default() -> Self {
+        Self {
+            speed: 10.0,
+            from_time: None,
+            to_time: None,
+            batch_size: 5_000,
+        }
+    }
+}
+
+impl ReplayOptions {
+    /// Parse replay flags from process arguments.
+    /// Returns None when `--replay` is not present (live mode).
+    pub fn from_args<I: IntoIterator<Item = String>>(args: I) -> Result<Option<Self>> {
+        let mut args = args.into_iter().skip(1);
+        let mut replay = false;
+        let mut opts = Self::default();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--replay" => replay = true,
+                "--replay-speed" => {
+                    let value = args.next().context("--replay-speed requires a value")?;
+                    opts.speed = value.parse().with_context(|| format!("Invalid --replay-speed: {}", value))?;
+                    if opts.speed < 0.0 {
+                        bail!("--replay-speed must be >= 0");
+                    }
+                }
+                "--replay-from" => {
+                    let value = args.next().context("--replay-from requires a unix timestamp")?;
+                    opts.from_time = Some(value.parse().with_context(|| format!("Invalid --replay-from: {}", value))?);
+                }
+                "--replay-to" => {
+                    let value = args.next().context("--replay-to requires a unix timestamp")?;
+                    opts.to_time = Some(value.parse().with_context(|| format!("Invalid --replay-to: {}", value))?);
+                }
+                _ => {}
+            }
+        }
+
+        Ok(if replay { Some(opts) } else { None })
+    }
+}
+
+/// A replayed event with its reconstructed millisecond timestamp
+#[derive(Debug, Clone)]
+pub struct ReplayEvent {
+    pub timestamp_ms: u64,
+    pub event: PumpEvent,
+}
+
+/// Streams recorded trades from SQLite in (block_time, slot, sig) order
+pub struct ReplaySource {
+    conn: Connection,
+    options: ReplayOptions,
+    buffer: VecDeque<ReplayEvent>,
+    /// Keyset cursor: last (block_time, slot, sig) returned
+    cursor: Option<(i64, u64, String)>,
+    exhausted: bool,
+    /// (block_time, first slot seen in that second) for sub-second spreading
+    second_base: Option<(i64, u64)>,
+}
+
+impl ReplaySource {
+    /// Open the database read-only so replay can run next to a live collector
+    pub fn open<P: AsRef<Path>>(path: P, options: ReplayOptions) -> Result<Self> {
+        let conn = Connection::open_with_flags(&path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+            .with_context(|| format!("Failed to open replay database: {:?}", path.as_ref()))?;
+
+        Ok(Self::from_connection(conn, options))
+    }
+
+    pub fn from_connection(conn: Connection, options: ReplayOptions) -> Self {
+        Self {
+            conn,
+            options,
+            buffer: VecDeque::new(),
+            cursor: None,
+            exhausted: false,
+            second_base: None,
+        }
+    }
+
+    /// Number of trades in the selected range
+    pub fn trade_count(&self) -> Result<u64> {
+        let count: i64 = self.conn.query_row(
+            "SELECT COUNT(*) FROM trades WHERE block_time >= ?1 AND block_time <= ?2",
+            params![self.options.from_time.unwrap_or(i64::MIN), self.options.to_time.unwrap_or(i64::MAX)],
+            |row| row.get(0),
+        )?;
+        Ok(count as u64)
+    }
+
+    fn fill_buffer(&mut self) -> Result<()> {
+        let (cur_time, cur_slot, cur_sig) = self
+            .cursor
+            .clone()
+            .unwrap_or((i64::MIN, 0, String::new()));
+
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT sig, slot, block_time, mint, side, trader, amount_tokens, amount_sol, price, is_amm
+             FROM trades
+             WHERE (block_time, slot, sig) > (?1, ?2, ?3)
+               AND block_time >= ?4 AND block_time <= ?5
+             ORDER BY block_time ASC, slot ASC, sig ASC
+             LIMIT ?6",
+        )?;
+
+        let rows = stmt
+            .query_map(
+                params![
+                    cur_time,
+                    cur_slot as i64,
+                    cur_sig,
+                    self.options.from_time.unwrap_or(i64::MIN),
+                    self.options.to_time.unwrap_or(i64::MAX),
+                    self.options.batch_size as i64,
+                ],
+                |row| {
+                    Ok((
+                        row.get::<_, String>(0)?,
+                        row.get::<_, i64>(1)? as u64,
+                        row.get::<_, i64>(2)?,
+                        row.get::<_, String>(3)?,
+                        row.get::<_, String>(4)?,
+                        row.get::<_, String>(5)?,
+                        row.get::<_, f64>(6)?,
+                        row.get::<_, f64>(7)?,
+                        row.get::<_, f64>(8)?,
+                        row.get::<_, Option<i64>>(9)?.unwrap_or(0) != 0,
+                    ))
+                },
+            )?
+            .collect::<Result<Vec<_>, _>>()?;
+        drop(stmt);
+
+        if rows.len() < self.options.batch_size {
+            self.exhausted = true;
+        }
+
+        for (sig, slot, block_time, mint, side, trader, amount_tokens, amount_sol, price, is_amm) in rows {
+            self.cursor = Some((block_time, slot, sig.clone()));
+            let timestamp_ms = self.timestamp_ms(block_time, slot);
+
+            self.buffer.push_back(ReplayEvent {
+                timestamp_ms,
+                event: PumpEvent::Trade {
+                    signature: sig,
+                    slot,
+                    block_time,
+                    mint,
+                    side: if side == "buy" { TradeSide::Buy } else { TradeSide::Sell },
+                    trader,
+                    amount_tokens: amount_tokens.max(0.0) as u64,
+                    amount_sol: (amount_sol.max(0.0) * 1_000_000_000.0).round() as u64,
+                    price,
+                    is_amm,
+                    // Reserves are not persisted in the trades table
+                    virtual_sol_reserves: 0,
+                    virtual_token_reserves: 0,
+                },
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Reconstruct a millisecond timestamp from second-resolution block_time.
+    /// Trades within the same second are spread by slot distance (~400ms/slot)
+    /// so sub-second windows (e.g. the 500ms momentum window) stay meaningful.
+    fn timestamp_ms(&mut self, block_time: i64, slot: u64) -> u64 {
+        let base_slot = match self.second_base {
+            Some((second, base)) if second == block_time => base,
+            _ => {
+                self.second_base = Some((block_time, slot));
+                slot
+            }
+        };
+        let offset = (slot.saturating_sub(base_slot) * SLOT_MS).min(999);
+        (block_time.max(0) as u64) * 1000 + offset
+    }
+}
+
+impl Iterator for ReplaySource {
+    type Item = Result<ReplayEvent>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.buffer.is_empty() && !self.exhausted {
+            if let Err(e) = self.fill_buffer() {
+                self.exhausted = true;
+                return Some(Err(e));
+            }
+        }
+        self.buffer.pop_front().map(Ok)
+    }
+}
+
+/// Paces replayed events against the wall clock and advances the replay clock
+pub struct ReplayPacer {
+    clock: Arc<ReplayClock>,
+    speed: f64,
+    last_event_ms: Option<u64>,
+}
+
+impl ReplayPacer {
+    pub fn new(clock: Arc<ReplayClock>, speed: f64) -> Self {
+        Self {
+            clock,
+            speed,
+            last_event_ms: None,
+        }
+    }
+
+    /// Wait for the scaled gap since the previous event, then move the clock to `event_ms`
+    pub async fn advance_to(&mut self, event_ms: u64) {
+        if let Some(wait) = self.wait_for(event_ms) {
+            debug!("⏩ Replay sleeping {:?}", wait);
+            tokio::time::sleep(wait).await;
+        }
+        self.last_event_ms = Some(event_ms.max(self.last_event_ms.unwrap_or(0)));
+        self.clock.set(event_ms);
+    }
+
+    /// Wall-clock delay before an event at `event_ms` (None = no wait)
+    fn wait_for(&self, event_ms: u64) -> Option<Duration> {
+        if self.speed <= 0.0 {
+            return None;
+        }
+        let gap_ms = event_ms.saturating_sub(self.last_event_ms?);
+        if gap_ms == 0 {
+            return None;
+        }
+        Some(Duration::from_secs_f64(gap_ms as f64 / 1000.0 / self.speed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::Clock;
+
+    fn test_db(rows: &[(&str, u64, i64, &str)]) -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE trades (
+                sig TEXT PRIMARY KEY, slot INTEGER, block_time INTEGER, mint TEXT, side TEXT,
+                trader TEXT, amount_tokens REAL, amount_sol REAL, price REAL, is_amm INTEGER DEFAULT 0
+            );",
+        )
+        .unwrap();
+        for (sig, slot, block_time, side) in rows {
+            conn.execute(
+                "INSERT INTO trades VALUES (?1, ?2, ?3, 'mint', ?4, 'trader', 1000.0, 0.5, 0.0001, 0)",
+                params![sig, *slot as i64, block_time, side],
+            )
+            .unwrap();
+        }
+        conn
+    }
+
+    #[test]
+    fn test_replay_orders_and_pages() {
+        let conn = test_db(&[
+            ("c", 12, 101, "buy"),
+            ("a", 10, 100, "buy"),
+            ("b", 11, 100, "sell"),
+            ("d", 13, 102, "buy"),
+        ]);
+        let options = ReplayOptions { batch_size: 2, ..Default::default() };
+        let events: Vec<ReplayEvent> = ReplaySource::from_connection(conn, options)
+            .collect::<Result<_>>()
+            .unwrap();
+
+        let sigs: Vec<&str> = events
+            .iter()
+            .map(|e| match &e.event {
+                PumpEvent::Trade { signature, .. } => signature.as_str(),
+                _ => unreachable!(),
+            })
+            .collect();
+        assert_eq!(sigs, vec!["a", "b", "c", "d"]);
+
+        // Same second: spread by slot distance
+        assert_eq!(events[0].timestamp_ms, 100_000);
+        assert_eq!(events[1].timestamp_ms, 100_400);
+        assert_eq!(events[2].timestamp_ms, 101_000);
+
+        match &events[1].event {
+            PumpEvent::Trade { side, amount_sol, .. } => {
+                assert_eq!(*side, TradeSide::Sell);
+                assert_eq!(*amount_sol, 500_000_000);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_replay_time_range() {
+        let conn = test_db(&[("a", 1, 100, "buy"), ("b", 2, 200, "buy"), ("c", 3, 300, "buy")]);
+        let options = ReplayOptions { from_time: Some(150), to_time: Some(250), ..Default::default() };
+        let source = ReplaySource::from_connection(conn, options);
+        assert_eq!(source.trade_count().unwrap(), 1);
+
+        let events: Vec<ReplayEvent> = source.collect::<Result<_>>().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].timestamp_ms, 200_000);
+    }
+
+    #[test]
+    fn test_replay_options_from_args() {
+        let args = |v: &[&str]| v.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        assert!(ReplayOptions::from_args(args(&["data-mining"])).unwrap().is_none());
+
+        let opts = ReplayOptions::from_args(args(&["data-mining", "--replay", "--replay-speed", "0", "--replay-from", "42"]))
+            .unwrap()
+            .unwrap();
+        assert_eq!(opts.speed, 0.0);
+        assert_eq!(opts.from_time, Some(42));
+        assert_eq!(opts.to_time, None);
+
+        assert!(ReplayOptions::from_args(args(&["data-mining", "--replay", "--replay-speed", "fast"])).is_err());
+    }
+
+    #[test]
+    fn test_pacer_scales_gaps() {
+        let clock = Arc::new(ReplayClock::new(0));
+        let mut pacer = ReplayPacer::new(clock.clone(), 10.0);
+
+        // First event never waits
+        assert_eq!(pacer.wait_for(1_000), None);
+        pacer.last_event_ms = Some(1_000);
+
+        // 2s of replayed time at 10x = 200ms wall clock
+        assert_eq!(pacer.wait_for(3_000), Some(Duration::from_millis(200)));
+
+        let unthrottled = ReplayPacer { clock: clock.clone(), speed: 0.0, last_event_ms: Some(0) };
+        assert_eq!(unthrottled.wait_for(60_000), None);
+        assert_eq!(clock.now_ms(), 0);
+    }
+}
@@ -0,0 +1,310 @@
+//! 🗂️ Token Cache - bounded "already queued this mint?" set for the hot path
+//!
+//! Its only job is avoiding redundant token inserts, so it doesn't need to be
+//! exact forever. Recent mints live in an exact FIFO ring (same shape as the
+//! checkpoint's signature ring); mints evicted from the ring fall back to two
+//! rotating bloom filters. When the current filter reaches capacity it becomes
+//! the previous one and the oldest generation is dropped, so memory stays fixed
+//! no matter how many pump.fun tokens a long run sees.
+//!
+//! A bloom false positive makes a new mint look already inserted and its row
+//! is skipped; at the default rate (0.1%) that's an accepted loss.
+//!
+//! `LaunchTimeCache` sits alongside it so the hot path reads launch times from
+//! memory instead of taking the shared `Database` lock on every trade.
+
+use std::collections::{HashSet, VecDeque};
+use std::hash::{Hash, Hasher};
+use std::sync::Mutex;
+use anyhow::Result;
+use dashmap::DashMap;
+
+/// Mints remembered exactly (most recent first to be checked)
+pub const DEFAULT_RECENT_CAPACITY: usize = 100_000;
+/// Evicted mints per bloom generation (two generations are kept)
+pub const DEFAULT_BLOOM_CAPACITY: usize = 1_000_000;
+/// Target false-positive rate of each bloom generation
+pub const DEFAULT_FALSE_POSITIVE_RATE: f64 = 0.001;
+
+/// Two independent 64-bit hashes of a mint (double hashing for the bloom)
+///
+/// Mints are uniformly random pubkeys, so their raw bytes are used directly.
+fn mint_hashes(mint: &str) -> (u64, u64) {
+    match bs58::decode(mint).into_vec() {
+        Ok(bytes) if bytes.len() >= 16 => (
+            u64::from_le_bytes(bytes[..8].try_into().unwrap()),
+            u64::from_le_bytes(bytes[8..16].try_into().unwrap()) | 1,
+        ),
+        // Not a valid base58 pubkey - fall back to hashing the string
+        _ => {
+            let mut h1 = std::collections::hash_map::DefaultHasher::new();
+            mint.hash(&mut h1);
+            let mut h2 = std::collections::hash_map::DefaultHasher::new();
+            (mint, 0x9e37_79b9u32).hash(&mut h2);
+            (h1.finish(), h2.finish() | 1)
+        }
+    }
+}
+
+struct BloomFilter {
+    bits: Vec<u64>,
+    num_bits: u64,
+    num_hashes: u64,
+    inserted: usize,
+}
+
+impl BloomFilter {
+    /// Size for `capacity` items at `fp_rate`: m = -n·ln(p)/ln(2)², k = m/n·ln(2)
+    fn new(capacity: usize, fp_rate: f64) -> Self {
+        let n = capacity.max(1) as f64;
+        let ln2 = std::f64::consts::LN_2;
+        let num_bits = (-n * fp_rate.clamp(1e-9, 0.5).ln() / (ln2 * ln2)).ceil().max(64.0) as u64;
+        let num_hashes = (num_bits as f64 / n * ln2).round().clamp(1.0, 16.0) as u64;
+
+        Self {
+            bits: vec![0; num_bits.div_ceil(64) as usize],
+            num_bits,
+            num_hashes,
+            inserted: 0,
+        }
+    }
+
+    /// i-th probe position (double hashing: h1 + i·h2)
+    fn bit(&self, (h1, h2): (u64, u64), i: u64) -> u64 {
+        h1.wrapping_add(i.wrapping_mul(h2)) % self.num_bits
+    }
+
+    fn insert(&mut self, hashes: (u64, u64)) {
+        for i in 0..self.num_hashes {
+            let bit = self.bit(hashes, i);
+            self.bits[(bit / 64) as usize] |= 1 << (bit % 64);
+        }
+        self.inserted += 1;
+    }
+
+    fn contains(&self, hashes: (u64, u64)) -> bool {
+        (0..self.num_hashes).all(|i| {
+            let bit = self.bit(hashes, i);
+            self.bits[(bit / 64) as usize] & (1 << (bit % 64)) != 0
+        })
+    }
+}
+
+pub struct TokenCache {
+    /// Exact FIFO ring of recent mints (hashes kept for the bloom on eviction)
+    recent: VecDeque<(u64, u64)>,
+    recent_index: HashSet<u64>,
+    recent_capacity: usize,
+    /// Bloom generations holding mints evicted from the ring
+    current: BloomFilter,
+    previous: BloomFilter,
+    bloom_capacity: usize,
+    fp_rate: f64,
+}
+
+impl Default for TokenCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_RECENT_CAPACITY, DEFAULT_BLOOM_CAPACITY, DEFAULT_FALSE_POSITIVE_RATE)
+    }
+}
+
+impl TokenCache {
+    pub fn new(recent_capacity: usize, bloom_capacity: usize, fp_rate: f64) -> Self {
+        Self {
+            recent: VecDeque::with_capacity(recent_capacity),
+            recent_index: HashSet::with_capacity(recent_capacity),
+            recent_capacity,
+            current: BloomFilter::new(bloom_capacity, fp_rate),
+            previous: BloomFilter::new(bloom_capacity, fp_rate),
+            bloom_capacity,
+            fp_rate,
+        }
+    }
+
+    /// Whether the mint was (probably) already seen
+    pub fn contains(&self, mint: &str) -> bool {
+        let hashes = mint_hashes(mint);
+        self.recent_index.contains(&hashes.0)
+            || self.current.contains(hashes)
+            || self.previous.contains(hashes)
+    }
+
+    /// Remember a mint; returns true if it wasn't seen before (caller should insert it)
+    pub fn insert(&mut self, mint: &str) -> bool {
+        if self.contains(mint) {
+            return false;
+        }
+
+        let hashes = mint_hashes(mint);
+        self.recent_index.insert(hashes.0);
+        self.recent.push_back(hashes);
+        self.evict_recent();
+        true
+    }
+
+    /// Mints held exactly in the recent ring
+    pub fn len(&self) -> usize {
+        self.recent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.recent.is_empty()
+    }
+
+    /// Move the oldest ring entries into the current bloom generation,
+    /// rotating generations once it's full
+    fn evict_recent(&mut self) {
+        while self.recent.len() > self.recent_capacity {
+            let Some(old) = self.recent.pop_front() else { break };
+            self.recent_index.remove(&old.0);
+            self.current.insert(old);
+            if self.current.inserted >= self.bloom_capacity {
+                let fresh = BloomFilter::new(self.bloom_capacity, self.fp_rate);
+                self.previous = std::mem::replace(&mut self.current, fresh);
+            }
+        }
+    }
+}
+
+/// Launch times (unix secs) of recently seen mints, read without the DB lock
+///
+/// Launch time never changes once known, so entries are never refreshed; the
+/// oldest are evicted past `capacity` (same bound as the token cache's ring).
+/// Reads only take a DashMap shard lock; the eviction order is only touched on
+/// insert (launch events and DB misses).
+pub struct LaunchTimeCache {
+    times: DashMap<String, i64>,
+    order: Mutex<VecDeque<String>>,
+    capacity: usize,
+}
+
+impl Default for LaunchTimeCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_RECENT_CAPACITY)
+    }
+}
+
+impl LaunchTimeCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            times: DashMap::with_capacity(capacity),
+            order: Mutex::new(VecDeque::with_capacity(capacity)),
+            capacity,
+        }
+    }
+
+    pub fn get(&self, mint: &str) -> Option<i64> {
+        self.times.get(mint).map(|t| *t)
+    }
+
+    /// Record a mint's launch time (a CREATE event overrides a placeholder's)
+    pub fn insert(&self, mint: &str, launch_time: i64) {
+        if self.times.insert(mint.to_string(), launch_time).is_some() {
+            return;
+        }
+
+        let mut order = self.order.lock().unwrap();
+        order.push_back(mint.to_string());
+        while order.len() > self.capacity {
+            if let Some(old) = order.pop_front() {
+                self.times.remove(&old);
+            }
+        }
+    }
+
+    /// Cached launch time, or `load` it (DB lookup) on a miss and cache the hit
+    pub fn get_or_load(&self, mint: &str, load: impl FnOnce() -> Result<Option<i64>>) -> Result<Option<i64>> {
+        if let Some(launch_time) = self.get(mint) {
+            return Ok(Some(launch_time));
+        }
+
+        let loaded = load()?;
+        if let Some(launch_time) = loaded {
+            self.insert(mint, launch_time);
+        }
+        Ok(loaded)
+    }
+
+    pub fn len(&self) -> usize {
+        self.times.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.times.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mint(i: u64) -> String {
+        let mut bytes = [0u8; 32];
+        bytes[..8].copy_from_slice(&i.wrapping_mul(0x9e37_79b9_7f4a_7c15).to_le_bytes());
+        bytes[8..16].copy_from_slice(&i.wrapping_mul(0xc2b2_ae3d_27d4_eb4f).to_le_bytes());
+        bytes[16..24].copy_from_slice(&i.to_le_bytes());
+        bs58::encode(bytes).into_string()
+    }
+
+    #[test]
+    fn test_insert_dedups_across_ring_and_bloom() {
+        let mut cache = TokenCache::new(4, 100, 0.001);
+
+        assert!(cache.insert(&mint(1)));
+        assert!(!cache.insert(&mint(1)));
+
+        for i in 2..=20 {
+            assert!(cache.insert(&mint(i)));
+        }
+        // Ring stays bounded; evicted mints are still recognized via the bloom
+        assert_eq!(cache.len(), 4);
+        for i in 1..=20 {
+            assert!(cache.contains(&mint(i)), "mint {} forgotten", i);
+        }
+    }
+
+    #[test]
+    fn test_old_bloom_generations_are_dropped() {
+        let mut cache = TokenCache::new(1, 10, 0.001);
+        for i in 0..35 {
+            cache.insert(&mint(i));
+        }
+        // 34 evicted: generations rotated at 10/20/30, so the first 20 are gone
+        assert!(!cache.contains(&mint(0)));
+        assert!(cache.contains(&mint(25)));
+        assert!(cache.contains(&mint(34)));
+    }
+
+    #[test]
+    fn test_bloom_false_positive_rate() {
+        let mut cache = TokenCache::new(0, 10_000, 0.01);
+        for i in 0..10_000 {
+            cache.insert(&mint(i));
+        }
+        let false_positives = (10_000..20_000).filter(|&i| cache.contains(&mint(i))).count();
+        assert!(false_positives < 300, "{} false positives", false_positives);
+    }
+
+    #[test]
+    fn test_launch_time_cache_loads_misses_once() {
+        let cache = LaunchTimeCache::new(2);
+        let mut loads = 0;
+
+        for _ in 0..3 {
+            let launch_time = cache.get_or_load("mint_a", || { loads += 1; Ok(Some(1_700_000_000)) }).unwrap();
+            assert_eq!(launch_time, Some(1_700_000_000));
+        }
+        assert_eq!(loads, 1);
+
+        // Unknown mints aren't cached (the token row may not be written yet)
+        assert_eq!(cache.get_or_load("mint_b", || Ok(None)).unwrap(), None);
+        assert!(cache.get("mint_b").is_none());
+
+        // Bounded: oldest entry evicted
+        cache.insert("mint_b", 1);
+        cache.insert("mint_c", 2);
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get("mint_a").is_none());
+        assert_eq!(cache.get("mint_c"), Some(2));
+    }
+}
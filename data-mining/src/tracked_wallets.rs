@@ -0,0 +1,207 @@
+//! 👥 Tracked Wallets - hot-reloadable set of wallets to watch
+//!
+//! Loaded from `wallet_stats WHERE is_tracked = 1` at startup, then re-queried
+//! every `reload_interval_secs` so wallets can be added/removed without a restart.
+//!
+//! The reloader uses its own read-only SQLite connection (WAL allows concurrent
+//! readers), so it never takes the shared `Database` lock the hot path uses. The
+//! wallet map lock is only held for the final swap.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use anyhow::{Context, Result};
+use rusqlite::{Connection, OpenFlags};
+use tracing::{debug, info, warn};
+
+use crate::db::query_tracked_wallets;
+
+/// Shared wallet → alias map read by the hot path
+pub type TrackedWallets = Arc<Mutex<HashMap<String, Option<String>>>>;
+
+/// Changes between two tracked-wallet snapshots
+#[derive(Debug, Default, PartialEq)]
+pub struct WalletDiff {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    /// Still tracked, alias changed
+    pub renamed: Vec<String>,
+}
+
+impl WalletDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.renamed.is_empty()
+    }
+}
+
+/// Diff the current tracked set against a freshly loaded one
+pub fn diff(
+    current: &HashMap<String, Option<String>>,
+    next: &HashMap<String, Option<String>>,
+) -> WalletDiff {
+    let mut result = WalletDiff::default();
+
+    for (wallet, alias) in next {
+        match current.get(wallet) {
+            None => result.added.push(wallet.clone()),
+            Some(old_alias) if old_alias != alias => result.renamed.push(wallet.clone()),
+            Some(_) => {}
+        }
+    }
+    for wallet in current.keys() {
+        if !next.contains_key(wallet) {
+            result.removed.push(wallet.clone());
+        }
+    }
+
+    result.added.sort();
+    result.removed.sort();
+    result.renamed.sort();
+    result
+}
+
+/// Re-query tracked wallets and swap them into the shared map; returns the applied diff
+pub fn reload_once(db_path: &str, wallets: &TrackedWallets) -> Result<WalletDiff> {
+    let conn = Connection::open_with_flags(db_path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+        .context("Failed to open database for tracked wallet reload")?;
+    let next = query_tracked_wallets(&conn)?;
+    drop(conn);
+
+    let mut current = wallets.lock().unwrap();
+    let changes = diff(&current, &next);
+    if !changes.is_empty() {
+        *current = next;
+    }
+
+    Ok(changes)
+}
+
+/// CopyTrade (tier, confidence) for a tracked wallet's own launch, scaled by its
+/// launch history `(net_pnl_sol, create_count)` from `Database::get_creator_stats`.
+///
+/// Tiers mirror the hotlist creator-reputation signal. None = don't copy: the
+/// wallet has launched repeatedly and is net negative (serial rugger).
+pub fn creator_copy_signal(creator_stats: Option<(f64, i32)>) -> Option<(u8, u8)> {
+    match creator_stats {
+        Some((net_pnl, create_count)) if net_pnl >= 500.0 && create_count >= 5 => Some((3, 99)),
+        Some((net_pnl, create_count)) if net_pnl >= 200.0 && create_count >= 3 => Some((3, 90)),
+        Some((net_pnl, _)) if net_pnl >= 50.0 => Some((2, 80)),
+        Some((net_pnl, create_count)) if net_pnl < 0.0 && create_count >= 3 => None,
+        // First launches / no history yet: tracked, but unproven as a creator
+        _ => Some((1, 60)),
+    }
+}
+
+fn wallet_label(wallet: &str, wallets: &HashMap<String, Option<String>>) -> String {
+    match wallets.get(wallet) {
+        Some(Some(alias)) => format!("{} ({})", alias, &wallet[..8.min(wallet.len())]),
+        _ => wallet[..8.min(wallet.len())].to_string(),
+    }
+}
+
+/// Spawn background task that reloads tracked wallets every `interval_secs`
+pub fn spawn_tracked_wallets_reloader(
+    db_path: String,
+    wallets: TrackedWallets,
+    interval_secs: u64,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        info!("👥 Tracked wallet reloader: Started (interval={}s)", interval_secs);
+        let mut interval = tokio::time::interval(Duration::from_secs(interval_secs.max(1)));
+        interval.tick().await; // Startup load already happened
+
+        loop {
+            interval.tick().await;
+
+            let path = db_path.clone();
+            let shared = wallets.clone();
+            let result = tokio::task::spawn_blocking(move || reload_once(&path, &shared)).await;
+
+            match result {
+                Ok(Ok(changes)) if changes.is_empty() => {
+                    debug!("👥 Tracked wallets unchanged");
+                }
+                Ok(Ok(changes)) => {
+                    let snapshot = wallets.lock().unwrap().clone();
+                    for wallet in &changes.added {
+                        info!("➕ Tracked wallet added: {}", wallet_label(wallet, &snapshot));
+                    }
+                    for wallet in &changes.removed {
+                        info!("➖ Tracked wallet removed: {}", &wallet[..8.min(wallet.len())]);
+                    }
+                    for wallet in &changes.renamed {
+                        info!("✏️  Tracked wallet renamed: {}", wallet_label(wallet, &snapshot));
+                    }
+                    info!("👥 Tracked wallets reloaded: {} total (+{} / -{})",
+                        snapshot.len(), changes.added.len(), changes.removed.len());
+                }
+                Ok(Err(e)) => warn!("⚠️  Tracked wallet reload failed: {}", e),
+                Err(e) => warn!("⚠️  Tracked wallet reload task panicked: {}", e),
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn map(entries: &[(&str, Option<&str>)]) -> HashMap<String, Option<String>> {
+        entries.iter()
+            .map(|(w, a)| (w.to_string(), a.map(|s| s.to_string())))
+            .collect()
+    }
+
+    #[test]
+    fn test_diff() {
+        let current = map(&[("wallet_a", Some("alpha")), ("wallet_b", None), ("wallet_c", None)]);
+        let next = map(&[("wallet_a", Some("alpha2")), ("wallet_b", None), ("wallet_d", None)]);
+
+        let changes = diff(&current, &next);
+        assert_eq!(changes.added, vec!["wallet_d".to_string()]);
+        assert_eq!(changes.removed, vec!["wallet_c".to_string()]);
+        assert_eq!(changes.renamed, vec!["wallet_a".to_string()]);
+        assert!(diff(&next, &next).is_empty());
+    }
+
+    #[test]
+    fn test_creator_copy_signal() {
+        assert_eq!(creator_copy_signal(Some((800.0, 6))), Some((3, 99)));
+        assert_eq!(creator_copy_signal(Some((250.0, 3))), Some((3, 90)));
+        assert_eq!(creator_copy_signal(Some((60.0, 1))), Some((2, 80)));
+        assert_eq!(creator_copy_signal(None), Some((1, 60)));
+        assert_eq!(creator_copy_signal(Some((-5.0, 1))), Some((1, 60)));
+
+        // Serial rugger: many launches, net negative
+        assert_eq!(creator_copy_signal(Some((-40.0, 12))), None);
+    }
+
+    #[test]
+    fn test_reload_once_picks_up_db_changes() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("wallets.db");
+        let conn = Connection::open(&path).unwrap();
+        conn.execute_batch(
+            "CREATE TABLE wallet_stats (wallet TEXT PRIMARY KEY, alias TEXT, is_tracked INTEGER);
+             INSERT INTO wallet_stats VALUES ('wallet_a', 'alpha', 1), ('wallet_b', NULL, 0);"
+        ).unwrap();
+
+        let wallets: TrackedWallets = Arc::new(Mutex::new(HashMap::new()));
+        let changes = reload_once(path.to_str().unwrap(), &wallets).unwrap();
+        assert_eq!(changes.added, vec!["wallet_a".to_string()]);
+
+        conn.execute_batch(
+            "UPDATE wallet_stats SET is_tracked = 0 WHERE wallet = 'wallet_a';
+             UPDATE wallet_stats SET is_tracked = 1 WHERE wallet = 'wallet_b';"
+        ).unwrap();
+
+        let changes = reload_once(path.to_str().unwrap(), &wallets).unwrap();
+        assert_eq!(changes.added, vec!["wallet_b".to_string()]);
+        assert_eq!(changes.removed, vec!["wallet_a".to_string()]);
+
+        let current = wallets.lock().unwrap();
+        assert!(current.contains_key("wallet_b"));
+        assert!(!current.contains_key("wallet_a"));
+    }
+}
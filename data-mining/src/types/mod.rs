@@ -65,8 +65,12 @@ pub struct Window {
     pub num_buys: u64,
     pub num_sells: u64,
     pub uniq_buyers: u64,
+    /// uniq_buyers with sybil dampening applied (equal to it when disabled)
+    pub uniq_buyers_filtered: u64,
     pub vol_tokens: f64,
     pub vol_sol: f64,
+    /// Buy SOL volume minus sell SOL volume (net buy pressure)
+    pub net_sol_flow: f64,
     pub high: f64,
     pub low: f64,
     pub close: f64,
@@ -105,6 +109,14 @@ pub enum PumpEvent {
         virtual_sol_reserves: u64,
         virtual_token_reserves: u64,
     },
+    /// Bonding curve filled (COMPLETE event); migration follows shortly
+    CurveComplete {
+        mint: String,
+        bonding_curve: String,
+        slot: u64,
+        block_time: i64,
+        signature: String,
+    },
     Migrated {
         mint: String,
         pool: String,
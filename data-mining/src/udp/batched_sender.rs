@@ -1,20 +1,46 @@
 //! 📦 Batched UDP Sender - Event-driven with adaptive flushing
 //!
 //! Eliminates latency spikes by:
-//! - Batching up to BATCH_MAX messages (256)
-//! - Flushing after BATCH_MAX_LATENCY_MS (15ms) if batch not full
+//! - Batching up to BATCH_MAX messages (256) per destination, flushed as soon
+//!   as that destination's batch fills
+//! - Flushing a partial batch BATCH_MAX_LATENCY_MS (15ms) after its oldest
+//!   message was queued, so no message waits longer than that
 //! - Using event-driven tokio::select! (no busy waiting)
+//!
+//! Each destination (Brain advisories, brain signals, ...) is batched and
+//! flushed on its own, and a send error on one destination can't drop
+//! messages queued for another.
+//!
+//! Datagram layout: exactly one whole (signed) message per datagram, never
+//! concatenated, so receivers parse each datagram by its message type/size.
+//! (Brain's receiver also accepts length-prefixed batch frames, see
+//! `split_datagram` there; this sender doesn't emit them.)
+//!
+//! A target that is an absolute path is a Unix datagram socket (single-host
+//! setups, `advice_bus.unix_socket_path`); anything else is `host:port` UDP.
+//! A message larger than `max_datagram_bytes` is dropped (and counted) rather
+//! than sent as a datagram that would fragment or be dropped across hosts.
 
-use anyhow::{Result, Context};
+use anyhow::Result;
 use tokio::sync::mpsc;
 use tokio::net::UdpSocket;
-use tokio::time::{interval, Duration, MissedTickBehavior};
+use tokio::time::{sleep_until, Duration, Instant};
 use tracing::{debug, info, warn};
+use std::collections::HashMap;
+use std::fmt;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
 use std::time::{SystemTime, UNIX_EPOCH};
 use std::os::unix::io::AsRawFd;
+use std::os::unix::net::UnixDatagram;
 use socket2::SockAddr;
 
+use super::auth::{AdviceAuth, AUTH_HEADER_SIZE};
+use super::error::{decode_mint, decode_wallet, SendError};
+use crate::metrics;
+
 
 /// Maximum batch size before forcing flush
 const BATCH_MAX: usize = 256;
@@ -22,17 +48,84 @@ const BATCH_MAX: usize = 256;
 /// Maximum latency to hold messages before flushing (ms)
 const BATCH_MAX_LATENCY_MS: u64 = 15;
 
+/// Default datagram cap: the IPv6 minimum MTU (1280) less IPv6/UDP headers,
+/// so a datagram never fragments on any path
+pub const DEFAULT_MAX_DATAGRAM_BYTES: usize = 1232;
+
+/// Largest message we send: 84-byte WalletActivity signal plus the auth header
+pub const MAX_MESSAGE_BYTES: usize = 84 + AUTH_HEADER_SIZE;
+
 /// UDP message to send
 #[derive(Debug, Clone)]
 pub struct UdpMessage {
     pub data: Vec<u8>,
+    /// `host:port`, or the path of a Unix datagram socket
     pub target: String,
 }
 
+/// Parsed `UdpMessage::target`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Destination {
+    Udp(SocketAddr),
+    Unix(PathBuf),
+}
+
+impl Destination {
+    fn parse(target: &str) -> Result<Self, std::net::AddrParseError> {
+        if target.starts_with('/') {
+            return Ok(Self::Unix(PathBuf::from(target)));
+        }
+        target.parse().map(Self::Udp)
+    }
+}
+
+impl fmt::Display for Destination {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Udp(addr) => write!(f, "{}", addr),
+            Self::Unix(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Messages queued for one destination
+struct PendingBatch {
+    messages: Vec<Vec<u8>>,
+    /// Oldest message's queue time + max latency
+    deadline: Instant,
+}
+
+/// Unix datagram socket for sending to Brain
+///
+/// On Linux it is bound to a per-sender abstract address so Brain can tell
+/// senders apart (and a restarted sender from its predecessor) when tracking
+/// sequence numbers; elsewhere it stays unbound.
+fn unix_sender_socket() -> std::io::Result<UnixDatagram> {
+    #[cfg(target_os = "linux")]
+    {
+        use std::os::linux::net::SocketAddrExt;
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        static NEXT_SENDER: AtomicU32 = AtomicU32::new(0);
+        let name = format!("advice-bus-sender-{}-{}", std::process::id(),
+                           NEXT_SENDER.fetch_add(1, Ordering::Relaxed));
+        let addr = std::os::unix::net::SocketAddr::from_abstract_name(name)?;
+        UnixDatagram::bind_addr(&addr)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        UnixDatagram::unbound()
+    }
+}
+
 /// Batched UDP sender - runs in dedicated task
 pub struct BatchedUdpSender {
     socket: Arc<UdpSocket>,
-    batch: Vec<UdpMessage>,
+    /// Non-blocking Unix datagram socket, created on the first Unix destination
+    unix_socket: Option<UnixDatagram>,
+    batches: HashMap<Destination, PendingBatch>,
+    max_latency: Duration,
+    max_datagram_bytes: usize,
     messages_sent: u64,
     batches_sent: u64,
 }
@@ -45,166 +138,238 @@ impl BatchedUdpSender {
         
         Ok(Self {
             socket: Arc::new(socket),
-            batch: Vec::with_capacity(BATCH_MAX),
+            unix_socket: None,
+            batches: HashMap::new(),
+            max_latency: Duration::from_millis(BATCH_MAX_LATENCY_MS),
+            max_datagram_bytes: DEFAULT_MAX_DATAGRAM_BYTES,
             messages_sent: 0,
             batches_sent: 0,
         })
     }
 
+    /// Override the longest a queued message waits for its batch to fill
+    pub fn with_max_latency(mut self, max_latency: Duration) -> Self {
+        self.max_latency = max_latency;
+        self
+    }
+
+    /// Drop (instead of sending) messages larger than this many bytes
+    pub fn with_max_datagram_bytes(mut self, max_datagram_bytes: usize) -> Self {
+        self.max_datagram_bytes = max_datagram_bytes;
+        self
+    }
+
     /// Main event loop - receives messages and flushes adaptively
+    ///
+    /// Returns once every sender handle is dropped (after a final flush).
     pub async fn run(mut self, mut rx: mpsc::UnboundedReceiver<UdpMessage>) {
-        info!("📦 Batched UDP Sender started (max_batch={}, max_latency={}ms)", 
-              BATCH_MAX, BATCH_MAX_LATENCY_MS);
-        
-        let mut flush_timer = interval(Duration::from_millis(BATCH_MAX_LATENCY_MS));
-        flush_timer.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        info!("📦 Batched UDP Sender started (max_batch={}, max_latency={}ms, max_datagram={}B)", 
+              BATCH_MAX, self.max_latency.as_millis(), self.max_datagram_bytes);
 
         loop {
+            // Earliest flush deadline across destinations (None = nothing queued)
+            let next_deadline = self.batches.values().map(|b| b.deadline).min();
+            
             tokio::select! {
                 // Receive message from channel
-                Some(msg) = rx.recv() => {
-                    self.batch.push(msg);
-                    
-                    // Flush if batch is full (size-based trigger)
-                    if self.batch.len() >= BATCH_MAX {
-                        self.flush_batch();
-                    }
+                msg = rx.recv() => {
+                    let Some(msg) = msg else {
+                        let targets: Vec<Destination> = self.batches.keys().cloned().collect();
+                        for target in targets {
+                            self.flush_batch(target);
+                        }
+                        info!("📦 Batched UDP Sender stopped ({} msgs in {} batches)",
+                              self.messages_sent, self.batches_sent);
+                        return;
+                    };
+                    self.enqueue(msg);
                 }
                 
-                // Periodic flush timer (time-based trigger)
-                _ = flush_timer.tick() => {
-                    if !self.batch.is_empty() {
-                        self.flush_batch();
+                // Oldest queued message of some destination reached max latency (time-based trigger)
+                _ = sleep_until(next_deadline.unwrap_or_else(Instant::now)), if next_deadline.is_some() => {
+                    let now = Instant::now();
+                    let due: Vec<Destination> = self.batches.iter()
+                        .filter(|(_, batch)| batch.deadline <= now)
+                        .map(|(target, _)| target.clone())
+                        .collect();
+                    for target in due {
+                        self.flush_batch(target);
                     }
                 }
             }
         }
     }
 
-    /// Flush the current batch using sendmmsg (Linux-specific optimization)
-    fn flush_batch(&mut self) {
-        if self.batch.is_empty() {
+    /// Queue a message on its destination's batch, flushing that batch once full
+    fn enqueue(&mut self, msg: UdpMessage) {
+        let target = match Destination::parse(&msg.target) {
+            Ok(target) => target,
+            Err(e) => {
+                warn!("⚠️  Invalid target address '{}': {}", msg.target, e);
+                return;
+            }
+        };
+        if msg.data.len() > self.max_datagram_bytes {
+            metrics::record_udp_oversized_dropped();
+            warn!("⚠️  Dropping {}-byte message to {}: over max_datagram_bytes ({})",
+                  msg.data.len(), target, self.max_datagram_bytes);
             return;
         }
+        
+        let max_latency = self.max_latency;
+        let batch = self.batches.entry(target.clone()).or_insert_with(|| PendingBatch {
+            messages: Vec::new(),
+            deadline: Instant::now() + max_latency,
+        });
+        batch.messages.push(msg.data);
+        
+        // Flush if batch is full (size-based trigger)
+        if batch.messages.len() >= BATCH_MAX {
+            self.flush_batch(target);
+        }
+    }
 
-        let batch_size = self.batch.len();
+    /// Flush one destination's batch using sendmmsg (Linux-specific optimization)
+    fn flush_batch(&mut self, target: Destination) {
+        let Some(batch) = self.batches.remove(&target) else {
+            return;
+        };
+
+        let batch_size = batch.messages.len();
+        let target = match target {
+            Destination::Udp(addr) => addr,
+            Destination::Unix(path) => {
+                let success_count = self.flush_batch_unix(&path, &batch.messages);
+                self.batches_sent += 1;
+                if batch_size > 10 {
+                    debug!("📦 Flushed batch to {}: {} msgs ({} ok, {} total batches)",
+                           path.display(), batch_size, success_count, self.batches_sent);
+                }
+                return;
+            }
+        };
         
         // Try to use sendmmsg for batched send (Linux-only)
         #[cfg(target_os = "linux")]
         {
-            let success_count = self.flush_batch_sendmmsg();
+            let success_count = self.flush_batch_sendmmsg(target, &batch.messages);
             self.batches_sent += 1;
             
             if batch_size > 10 {
-                debug!("📦 Flushed batch (sendmmsg): {} msgs ({} ok, {} total batches)",
-                       batch_size, success_count, self.batches_sent);
+                debug!("📦 Flushed batch to {} (sendmmsg): {} msgs ({} ok, {} total batches)",
+                       target, batch_size, success_count, self.batches_sent);
             }
         }
         
         // Fallback for non-Linux systems
         #[cfg(not(target_os = "linux"))]
         {
-            let success_count = self.flush_batch_fallback();
+            let success_count = self.flush_batch_fallback(target, &batch.messages);
             self.batches_sent += 1;
             
             if batch_size > 10 {
-                debug!("📦 Flushed batch (fallback): {} msgs ({} ok, {} total batches)",
-                       batch_size, success_count, self.batches_sent);
+                debug!("📦 Flushed batch to {} (fallback): {} msgs ({} ok, {} total batches)",
+                       target, batch_size, success_count, self.batches_sent);
             }
         }
     }
 
     #[cfg(target_os = "linux")]
-    fn flush_batch_sendmmsg(&mut self) -> usize {
+    fn flush_batch_sendmmsg(&mut self, target: SocketAddr, messages: &[Vec<u8>]) -> usize {
         use std::mem::MaybeUninit;
         
-        if self.batch.is_empty() {
+        if messages.is_empty() {
             return 0;
         }
 
         let fd = self.socket.as_raw_fd();
+        let addr = SockAddr::from(target);
         let mut success_count = 0;
 
-        // Prepare mmsghdr structures
-        let mut msgvec: Vec<libc::mmsghdr> = Vec::with_capacity(self.batch.len());
-        let mut iovecs: Vec<libc::iovec> = Vec::with_capacity(self.batch.len());
-        let mut addrs: Vec<SockAddr> = Vec::with_capacity(self.batch.len());
-
-        // Build message structures
-        for msg in &self.batch {
-            // Parse target address
-            let addr = match msg.target.parse::<std::net::SocketAddr>() {
-                Ok(a) => SockAddr::from(a),
-                Err(e) => {
-                    warn!("⚠️  Invalid target address '{}': {}", msg.target, e);
-                    continue;
-                }
-            };
-
-            // Create iovec for message data
-            let iov = libc::iovec {
-                iov_base: msg.data.as_ptr() as *mut libc::c_void,
-                iov_len: msg.data.len(),
-            };
-            iovecs.push(iov);
-            addrs.push(addr);
-        }
+        // One iovec per message: each is sent as its own datagram
+        let mut iovecs: Vec<libc::iovec> = messages.iter()
+            .map(|data| libc::iovec {
+                iov_base: data.as_ptr() as *mut libc::c_void,
+                iov_len: data.len(),
+            })
+            .collect();
 
-        // Build mmsghdr array
-        for i in 0..iovecs.len() {
+        // Build mmsghdr array (all to the same destination)
+        let mut msgvec: Vec<libc::mmsghdr> = Vec::with_capacity(iovecs.len());
+        for iov in iovecs.iter_mut() {
             let mut msghdr: libc::msghdr = unsafe { MaybeUninit::zeroed().assume_init() };
-            msghdr.msg_name = addrs[i].as_ptr() as *mut libc::c_void;
-            msghdr.msg_namelen = addrs[i].len();
-            msghdr.msg_iov = &mut iovecs[i] as *mut libc::iovec;
+            msghdr.msg_name = addr.as_ptr() as *mut libc::c_void;
+            msghdr.msg_namelen = addr.len();
+            msghdr.msg_iov = iov as *mut libc::iovec;
             msghdr.msg_iovlen = 1;
 
-            let mmsg = libc::mmsghdr {
+            msgvec.push(libc::mmsghdr {
                 msg_hdr: msghdr,
                 msg_len: 0,
-            };
-            msgvec.push(mmsg);
+            });
         }
 
-        // Send batch with sendmmsg
-        if !msgvec.is_empty() {
+        // Send batch with sendmmsg; it may stop early, so resume after the last sent message
+        while success_count < msgvec.len() {
             let result = unsafe {
                 libc::sendmmsg(
                     fd,
-                    msgvec.as_mut_ptr(),
-                    msgvec.len() as libc::c_uint,
+                    msgvec[success_count..].as_mut_ptr(),
+                    (msgvec.len() - success_count) as libc::c_uint,
                     0, // flags
                 )
             };
 
-            if result >= 0 {
-                success_count = result as usize;
-                self.messages_sent += success_count as u64;
+            if result > 0 {
+                success_count += result as usize;
+                self.messages_sent += result as u64;
             } else {
                 let err = std::io::Error::last_os_error();
-                warn!("❌ sendmmsg failed: {}", err);
+                warn!("❌ sendmmsg to {} failed after {}/{} msgs, dropping the rest: {}",
+                      target, success_count, msgvec.len(), err);
+                break;
+            }
+        }
+
+        success_count
+    }
+
+    /// Send each message as its own datagram on the (lazily created) Unix socket
+    fn flush_batch_unix(&mut self, path: &Path, messages: &[Vec<u8>]) -> usize {
+        if self.unix_socket.is_none() {
+            match unix_sender_socket().and_then(|s| s.set_nonblocking(true).map(|_| s)) {
+                Ok(socket) => self.unix_socket = Some(socket),
+                Err(e) => {
+                    warn!("❌ Failed to create Unix datagram socket, dropping {} msgs: {}", messages.len(), e);
+                    return 0;
+                }
             }
         }
+        let Some(socket) = self.unix_socket.as_ref() else { return 0 };
 
-        self.batch.clear();
+        let mut success_count = 0;
+        for data in messages {
+            match socket.send_to(data, path) {
+                Ok(_) => success_count += 1,
+                Err(e) => {
+                    // Receiver's queue full or Brain not listening: drop the rest of this batch
+                    warn!("❌ Send to {} failed after {}/{} msgs, dropping the rest: {}",
+                          path.display(), success_count, messages.len(), e);
+                    break;
+                }
+            }
+        }
+        self.messages_sent += success_count as u64;
         success_count
     }
 
     #[cfg(not(target_os = "linux"))]
-    fn flush_batch_fallback(&mut self) -> usize {
+    fn flush_batch_fallback(&mut self, target: SocketAddr, messages: &[Vec<u8>]) -> usize {
         let mut success_count = 0;
 
         // Send all messages individually
-        for msg in self.batch.drain(..) {
-            // Parse target string to SocketAddr
-            let addr = match msg.target.parse() {
-                Ok(a) => a,
-                Err(e) => {
-                    warn!("⚠️  Invalid target address '{}': {}", msg.target, e);
-                    continue;
-                }
-            };
-            
-            match self.socket.try_send_to(&msg.data, addr) {
+        for data in messages {
+            match self.socket.try_send_to(data, target) {
                 Ok(_) => {
                     success_count += 1;
                     self.messages_sent += 1;
@@ -224,13 +389,13 @@ impl BatchedUdpSender {
 }
 
 /// Create batched UDP sender and return channel for sending messages
-pub fn spawn_batched_sender() -> mpsc::UnboundedSender<UdpMessage> {
+pub fn spawn_batched_sender(max_datagram_bytes: usize) -> mpsc::UnboundedSender<UdpMessage> {
     let (tx, rx) = mpsc::unbounded_channel();
     
     tokio::spawn(async move {
         match BatchedUdpSender::new().await {
             Ok(sender) => {
-                sender.run(rx).await;
+                sender.with_max_datagram_bytes(max_datagram_bytes).run(rx).await;
             }
             Err(e) => {
                 warn!("❌ Failed to create batched UDP sender: {}", e);
@@ -247,20 +412,22 @@ pub fn spawn_batched_sender() -> mpsc::UnboundedSender<UdpMessage> {
 pub struct BatchedAdvisorySender {
     tx: mpsc::UnboundedSender<UdpMessage>,
     target_addr: String,
+    auth: AdviceAuth,
 }
 
 impl BatchedAdvisorySender {
-    pub fn new(tx: mpsc::UnboundedSender<UdpMessage>, host: &str, port: u16) -> Self {
+    pub fn new(tx: mpsc::UnboundedSender<UdpMessage>, host: &str, port: u16, auth: AdviceAuth) -> Self {
         Self {
             tx,
             target_addr: format!("{}:{}", host, port),
+            auth,
         }
     }
     
     /// Send advisory packet (non-blocking, queued for batching)
     pub fn send(&self, data: Vec<u8>) -> Result<()> {
         self.tx.send(UdpMessage {
-            data,
+            data: self.auth.sign(&data),
             target: self.target_addr.clone(),
         })?;
         Ok(())
@@ -268,27 +435,53 @@ impl BatchedAdvisorySender {
 }
 
 /// Wrapper for brain signal sender using batched backend
+///
+/// Every signal carries a monotonic `seq: u32` (shared across clones) so Brain can
+/// detect packets lost or reordered by UDP / the batching window. 0 = unsequenced.
 #[derive(Clone)]
 pub struct BatchedBrainSignalSender {
     tx: mpsc::UnboundedSender<UdpMessage>,
     target_addr: String,
+    auth: AdviceAuth,
+    seq: Arc<AtomicU32>,
 }
 
 impl BatchedBrainSignalSender {
-    pub fn new(tx: mpsc::UnboundedSender<UdpMessage>, host: &str, port: u16) -> Self {
+    pub fn new(tx: mpsc::UnboundedSender<UdpMessage>, host: &str, port: u16, auth: AdviceAuth) -> Self {
         Self {
             tx,
             target_addr: format!("{}:{}", host, port),
+            auth,
+            seq: Arc::new(AtomicU32::new(0)),
         }
     }
     
-    /// Send brain signal packet (non-blocking, queued for batching)
-    pub fn send(&self, data: Vec<u8>) -> Result<()> {
+    /// Send to Brain's Unix datagram socket at `path` (absolute) instead of UDP
+    pub fn new_unix(tx: mpsc::UnboundedSender<UdpMessage>, path: &str, auth: AdviceAuth) -> Self {
+        Self {
+            tx,
+            target_addr: path.to_string(),
+            auth,
+            seq: Arc::new(AtomicU32::new(0)),
+        }
+    }
+    
+    /// Next sequence number (skips 0 on wrap - reserved for "unsequenced")
+    fn next_seq(&self) -> u32 {
+        let seq = self.seq.fetch_add(1, Ordering::Relaxed).wrapping_add(1);
+        if seq == 0 {
+            self.seq.fetch_add(1, Ordering::Relaxed).wrapping_add(1)
+        } else {
+            seq
+        }
+    }
+    
+    /// Send brain signal packet (non-blocking, queued for batching, signed)
+    pub fn send(&self, data: Vec<u8>) -> Result<(), SendError> {
         self.tx.send(UdpMessage {
-            data,
+            data: self.auth.sign(&data),
             target: self.target_addr.clone(),
-        })?;
-        Ok(())
+        }).map_err(|_| SendError::ChannelClosed)
     }
     
     /// Send momentum detected signal
@@ -299,20 +492,15 @@ impl BatchedBrainSignalSender {
         volume_sol: f32,
         unique_buyers: u16,
         confidence: u8,
-    ) -> Result<()> {
-        let mint_bytes = bs58::decode(mint_b58).into_vec()
-            .context("Invalid mint base58")?;
-        
-        if mint_bytes.len() != 32 {
-            anyhow::bail!("Mint must be 32 bytes, got {}", mint_bytes.len());
-        }
+    ) -> Result<(), SendError> {
+        let mint_bytes = decode_mint(mint_b58)?;
         
         let timestamp_ns = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .unwrap()
             .as_nanos() as u64;
         
-        // Packet: [type(1) | mint(32) | buys(2) | volume(4) | buyers(2) | conf(1) | timestamp(8) | padding(14)]
+        // Packet: [type(1) | mint(32) | buys(2) | volume(4) | buyers(2) | conf(1) | timestamp(8) | seq(4) | padding(10)]
         let mut msg = vec![0u8; 64];
         msg[0] = 21; // MomentumDetected type
         msg[1..33].copy_from_slice(&mint_bytes);
@@ -321,6 +509,33 @@ impl BatchedBrainSignalSender {
         msg[39..41].copy_from_slice(&unique_buyers.to_le_bytes());
         msg[41] = confidence.clamp(0, 100);
         msg[42..50].copy_from_slice(&timestamp_ns.to_le_bytes());
+        msg[50..54].copy_from_slice(&self.next_seq().to_le_bytes());
+        
+        self.send(msg)
+    }
+    
+    /// Send curve complete signal (bonding curve filled, migration imminent)
+    pub fn send_curve_complete(
+        &self,
+        mint_b58: &str,
+        slot: u64,
+        block_time: i64,
+    ) -> Result<(), SendError> {
+        let mint_bytes = decode_mint(mint_b58)?;
+        
+        let timestamp_ns = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos() as u64;
+        
+        // Packet: [type(1) | mint(32) | slot(8) | block_time(8) | timestamp(8) | seq(4) | padding(3)]
+        let mut msg = vec![0u8; 64];
+        msg[0] = 33; // CurveComplete type
+        msg[1..33].copy_from_slice(&mint_bytes);
+        msg[33..41].copy_from_slice(&slot.to_le_bytes());
+        msg[41..49].copy_from_slice(&block_time.to_le_bytes());
+        msg[49..57].copy_from_slice(&timestamp_ns.to_le_bytes());
+        msg[57..61].copy_from_slice(&self.next_seq().to_le_bytes());
         
         self.send(msg)
     }
@@ -333,20 +548,15 @@ impl BatchedBrainSignalSender {
         tx_count: u16,
         time_window_ms: u16,
         confidence: u8,
-    ) -> Result<()> {
-        let mint_bytes = bs58::decode(mint_b58).into_vec()
-            .context("Invalid mint base58")?;
-        
-        if mint_bytes.len() != 32 {
-            anyhow::bail!("Mint must be 32 bytes, got {}", mint_bytes.len());
-        }
+    ) -> Result<(), SendError> {
+        let mint_bytes = decode_mint(mint_b58)?;
         
         let timestamp_ns = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .unwrap()
             .as_nanos() as u64;
         
-        // Packet: [type(1) | mint(32) | total_sol(4) | tx_count(2) | window(2) | conf(1) | timestamp(8) | padding(14)]
+        // Packet: [type(1) | mint(32) | total_sol(4) | tx_count(2) | window(2) | conf(1) | timestamp(8) | seq(4) | padding(10)]
         let mut msg = vec![0u8; 64];
         msg[0] = 22; // VolumeSpike type
         msg[1..33].copy_from_slice(&mint_bytes);
@@ -355,6 +565,7 @@ impl BatchedBrainSignalSender {
         msg[39..41].copy_from_slice(&time_window_ms.to_le_bytes());
         msg[41] = confidence.clamp(0, 100);
         msg[42..50].copy_from_slice(&timestamp_ns.to_le_bytes());
+        msg[50..54].copy_from_slice(&self.next_seq().to_le_bytes());
         
         self.send(msg)
     }
@@ -368,26 +579,18 @@ impl BatchedBrainSignalSender {
         size_sol: f32,
         wallet_tier: u8,
         confidence: u8,
-    ) -> Result<()> {
-        let mint_bytes = bs58::decode(mint_b58).into_vec()
-            .context("Invalid mint base58")?;
-        let wallet_bytes = bs58::decode(wallet_b58).into_vec()
-            .context("Invalid wallet base58")?;
-        
-        if mint_bytes.len() != 32 {
-            anyhow::bail!("Mint must be 32 bytes, got {}", mint_bytes.len());
-        }
-        if wallet_bytes.len() != 32 {
-            anyhow::bail!("Wallet must be 32 bytes, got {}", wallet_bytes.len());
-        }
+    ) -> Result<(), SendError> {
+        let mint_bytes = decode_mint(mint_b58)?;
+        let wallet_bytes = decode_wallet(wallet_b58)?;
         
         let timestamp_ns = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .unwrap()
             .as_nanos() as u64;
         
-        // Packet: [type(1) | mint(32) | wallet(32) | action(1) | size(4) | tier(1) | conf(1) | timestamp(8)]
-        let mut msg = vec![0u8; 80];
+        // Packet: [type(1) | mint(32) | wallet(32) | action(1) | size(4) | tier(1) | conf(1) | timestamp(8) | seq(4)]
+        // (80-byte layout is full, so seq extends it to 84)
+        let mut msg = vec![0u8; 84];
         msg[0] = 23; // WalletActivity type
         msg[1..33].copy_from_slice(&mint_bytes);
         msg[33..65].copy_from_slice(&wallet_bytes);
@@ -396,6 +599,7 @@ impl BatchedBrainSignalSender {
         msg[70] = wallet_tier;
         msg[71] = confidence.clamp(0, 100);
         msg[72..80].copy_from_slice(&timestamp_ns.to_le_bytes());
+        msg[80..84].copy_from_slice(&self.next_seq().to_le_bytes());
         
         self.send(msg)
     }
@@ -408,13 +612,8 @@ impl BatchedBrainSignalSender {
         unique_buyers_1s: u16,
         price_change_bps_2s: i16,
         alpha_wallet_hits_10s: u8,
-    ) -> Result<()> {
-        let mint_bytes = bs58::decode(mint_b58).into_vec()
-            .context("Invalid mint base58")?;
-        
-        if mint_bytes.len() != 32 {
-            anyhow::bail!("Mint must be 32 bytes, got {}", mint_bytes.len());
-        }
+    ) -> Result<(), SendError> {
+        let mint_bytes = decode_mint(mint_b58)?;
         
         // Scale volume by 1000 to fit in u32
         let volume_scaled = (volume_sol_1s * 1000.0).min(u32::MAX as f64) as u32;
@@ -424,7 +623,7 @@ impl BatchedBrainSignalSender {
             .unwrap()
             .as_nanos() as u64;
         
-        // Packet: [type(1) | mint(32) | volume(4) | buyers(2) | price_change(2) | alpha(1) | timestamp(8) | padding(13)]
+        // Packet: [type(1) | mint(32) | volume(4) | buyers(2) | price_change(2) | alpha(1) | timestamp(8) | seq(4) | padding(10)]
         let mut msg = vec![0u8; 64];
         msg[0] = 29; // WindowMetrics type
         msg[1..33].copy_from_slice(&mint_bytes);
@@ -433,7 +632,156 @@ impl BatchedBrainSignalSender {
         msg[39..41].copy_from_slice(&price_change_bps_2s.to_le_bytes());
         msg[41] = alpha_wallet_hits_10s;
         msg[42..50].copy_from_slice(&timestamp_ns.to_le_bytes());
+        msg[50..54].copy_from_slice(&self.next_seq().to_le_bytes());
         
         self.send(msg)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(target: &UdpSocket, payload: u8) -> UdpMessage {
+        UdpMessage {
+            data: vec![payload; 8],
+            target: target.local_addr().unwrap().to_string(),
+        }
+    }
+
+    async fn spawn_sender(max_latency: Duration) -> mpsc::UnboundedSender<UdpMessage> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let sender = BatchedUdpSender::new().await.unwrap().with_max_latency(max_latency);
+        tokio::spawn(sender.run(rx));
+        tx
+    }
+
+    /// Next datagram on `socket`, None if nothing arrives within `ms`
+    async fn recv_within(socket: &UdpSocket, ms: u64) -> Option<Vec<u8>> {
+        let mut buf = [0u8; 128];
+        let len = tokio::time::timeout(Duration::from_millis(ms), socket.recv(&mut buf)).await.ok()?.ok()?;
+        Some(buf[..len].to_vec())
+    }
+
+    #[tokio::test]
+    async fn test_full_batch_flushes_immediately() {
+        let receiver = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        // Latency far beyond the test: only the size trigger can flush
+        let tx = spawn_sender(Duration::from_secs(60)).await;
+
+        for i in 0..BATCH_MAX {
+            tx.send(message(&receiver, i as u8)).unwrap();
+        }
+        for _ in 0..BATCH_MAX {
+            assert!(recv_within(&receiver, 1_000).await.is_some(), "full batch must flush at once");
+        }
+
+        // One short of a batch stays queued until its deadline
+        tx.send(message(&receiver, 0)).unwrap();
+        assert!(recv_within(&receiver, 200).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_lone_message_flushes_by_deadline() {
+        let receiver = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let tx = spawn_sender(Duration::from_millis(BATCH_MAX_LATENCY_MS)).await;
+
+        for _ in 0..3 {
+            let queued = Instant::now();
+            tx.send(message(&receiver, 7)).unwrap();
+            assert_eq!(recv_within(&receiver, 500).await, Some(vec![7; 8]));
+            let waited = queued.elapsed();
+            // Deadline plus scheduling slack
+            assert!(waited < Duration::from_millis(BATCH_MAX_LATENCY_MS + 35), "waited {:?}", waited);
+            tokio::time::sleep(Duration::from_millis(40)).await;
+        }
+
+        // Dropping the last handle flushes what's left
+        let closing_tx = spawn_sender(Duration::from_secs(60)).await;
+        closing_tx.send(message(&receiver, 9)).unwrap();
+        drop(closing_tx);
+        assert_eq!(recv_within(&receiver, 500).await, Some(vec![9; 8]));
+    }
+
+    #[tokio::test]
+    async fn test_interleaved_targets_get_their_own_payloads() {
+        let advice_bus = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let brain_signals = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let tx = spawn_sender(Duration::from_millis(BATCH_MAX_LATENCY_MS)).await;
+
+        // 64-byte advisories and 84-byte signals, interleaved
+        let packet = |socket: &UdpSocket, len: usize, tag: u8| UdpMessage {
+            data: vec![tag; len],
+            target: socket.local_addr().unwrap().to_string(),
+        };
+        for tag in 0..20u8 {
+            tx.send(packet(&advice_bus, 64, tag)).unwrap();
+            tx.send(packet(&brain_signals, 84, 100 + tag)).unwrap();
+        }
+
+        for (socket, len, first) in [(&advice_bus, 64, 0u8), (&brain_signals, 84, 100u8)] {
+            for tag in first..first + 20 {
+                assert_eq!(recv_within(socket, 500).await, Some(vec![tag; len]));
+            }
+            assert!(recv_within(socket, 50).await.is_none());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_targets_flush_independently() {
+        let busy = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let quiet = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let tx = spawn_sender(Duration::from_secs(60)).await;
+
+        // The busy destination fills its batch; the quiet one's lone message keeps waiting
+        tx.send(message(&quiet, 1)).unwrap();
+        for i in 0..BATCH_MAX {
+            tx.send(message(&busy, i as u8)).unwrap();
+        }
+        for _ in 0..BATCH_MAX {
+            assert!(recv_within(&busy, 1_000).await.is_some());
+        }
+        assert!(recv_within(&quiet, 200).await.is_none());
+
+        drop(tx);
+        assert_eq!(recv_within(&quiet, 500).await, Some(vec![1; 8]));
+    }
+
+    #[tokio::test]
+    async fn test_oversized_message_dropped_not_fragmented() {
+        let receiver = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let (tx, rx) = mpsc::unbounded_channel();
+        let sender = BatchedUdpSender::new().await.unwrap().with_max_datagram_bytes(MAX_MESSAGE_BYTES);
+        tokio::spawn(sender.run(rx));
+        let target = receiver.local_addr().unwrap().to_string();
+
+        let dropped_before = metrics::metrics().udp_oversized_dropped.get();
+        tx.send(UdpMessage { data: vec![1; MAX_MESSAGE_BYTES + 1], target: target.clone() }).unwrap();
+        tx.send(UdpMessage { data: vec![2; MAX_MESSAGE_BYTES], target }).unwrap();
+
+        assert_eq!(recv_within(&receiver, 500).await, Some(vec![2; MAX_MESSAGE_BYTES]));
+        assert!(recv_within(&receiver, 50).await.is_none());
+        assert!(metrics::metrics().udp_oversized_dropped.get() > dropped_before);
+    }
+
+    #[tokio::test]
+    async fn test_unix_socket_target() {
+        let path = std::env::temp_dir().join(format!("batched_sender_{}.sock", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let receiver = tokio::net::UnixDatagram::bind(&path).unwrap();
+        let tx = spawn_sender(Duration::from_millis(5)).await;
+
+        let target = path.to_str().unwrap().to_string();
+        tx.send(UdpMessage { data: vec![7; 64], target: target.clone() }).unwrap();
+        tx.send(UdpMessage { data: vec![8; 84], target }).unwrap();
+
+        // Same one-message-per-datagram layout as UDP
+        let mut buf = [0u8; 256];
+        for expected in [vec![7u8; 64], vec![8u8; 84]] {
+            let len = tokio::time::timeout(Duration::from_millis(500), receiver.recv(&mut buf))
+                .await.unwrap().unwrap();
+            assert_eq!(&buf[..len], &expected[..]);
+        }
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,133 @@
+//! Errors returned by the advisory / brain signal senders
+//!
+//! Encoding errors (`InvalidMint`, `InvalidWallet`) are caller bugs and
+//! retrying won't help; socket errors are transient. `SendError` converts
+//! into `anyhow::Error` for callers that don't care.
+//!
+//! Callers often drop the result (`let _ = ...`), so the senders also count
+//! socket failures themselves (`SendFailures`): a metric per failed send and
+//! a rate-limited warning while the target stays unreachable.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+use tracing::{info, warn};
+
+use crate::metrics;
+
+/// Minimum time between "target unreachable" warnings for one sender
+const FAILURE_WARN_INTERVAL: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Error)]
+pub enum SendError {
+    #[error("Invalid mint {0:?}: expected a 32-byte base58 pubkey")]
+    InvalidMint(String),
+
+    #[error("Invalid wallet {0:?}: expected a 32-byte base58 pubkey")]
+    InvalidWallet(String),
+
+    #[error("UDP send failed: {0}")]
+    SocketError(#[from] std::io::Error),
+
+    /// The batched sender task has stopped (its queue is closed)
+    #[error("Batched UDP sender is no longer running")]
+    ChannelClosed,
+}
+
+impl SendError {
+    /// Worth retrying (encoding errors never are)
+    pub fn is_transient(&self) -> bool {
+        matches!(self, SendError::SocketError(_))
+    }
+}
+
+/// Socket send failures for one sender/target since its last successful send
+pub(crate) struct SendFailures {
+    /// Metric label and log prefix ("advisory", "brain_signal")
+    sender: &'static str,
+    target: String,
+    failed: AtomicU64,
+    last_warn: Mutex<Option<Instant>>,
+    warn_interval: Duration,
+}
+
+impl SendFailures {
+    pub(crate) fn new(sender: &'static str, target: &str) -> Self {
+        Self {
+            sender,
+            target: target.to_string(),
+            failed: AtomicU64::new(0),
+            last_warn: Mutex::new(None),
+            warn_interval: FAILURE_WARN_INTERVAL,
+        }
+    }
+
+    #[cfg(test)]
+    fn with_warn_interval(mut self, warn_interval: Duration) -> Self {
+        self.warn_interval = warn_interval;
+        self
+    }
+
+    /// Count a failed send; warns (and returns the failure count) at most once per interval
+    pub(crate) fn record_failure(&self, err: &std::io::Error) -> Option<u64> {
+        metrics::record_udp_send_failed(self.sender);
+        let failed = self.failed.fetch_add(1, Ordering::Relaxed) + 1;
+
+        let mut last_warn = self.last_warn.lock().unwrap();
+        if last_warn.is_some_and(|at| at.elapsed() < self.warn_interval) {
+            return None;
+        }
+        *last_warn = Some(Instant::now());
+        warn!("⚠️  {} target {} unreachable, {} sends failed (last error: {})",
+              self.sender, self.target, failed, err);
+        Some(failed)
+    }
+
+    /// A send went through: log the recovery once and reset the count
+    pub(crate) fn record_success(&self) {
+        if self.failed.load(Ordering::Relaxed) == 0 {
+            return;
+        }
+        let failed = self.failed.swap(0, Ordering::Relaxed);
+        *self.last_warn.lock().unwrap() = None;
+        if failed > 0 {
+            info!("✅ {} target {} reachable again after {} failed sends", self.sender, self.target, failed);
+        }
+    }
+}
+
+fn decode_pubkey(b58: &str) -> Option<[u8; 32]> {
+    bs58::decode(b58).into_vec().ok()?.try_into().ok()
+}
+
+pub(crate) fn decode_mint(mint_b58: &str) -> Result<[u8; 32], SendError> {
+    decode_pubkey(mint_b58).ok_or_else(|| SendError::InvalidMint(mint_b58.to_string()))
+}
+
+pub(crate) fn decode_wallet(wallet_b58: &str) -> Result<[u8; 32], SendError> {
+    decode_pubkey(wallet_b58).ok_or_else(|| SendError::InvalidWallet(wallet_b58.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_send_failures_warn_once_per_interval() {
+        let failures = SendFailures::new("advisory", "127.0.0.1:45100")
+            .with_warn_interval(Duration::from_secs(3600));
+        let err = std::io::Error::from(std::io::ErrorKind::ConnectionRefused);
+        let before = metrics::metrics().udp_send_failures.with_label_values(&["advisory"]).get();
+
+        // First failure warns, the rest of the outage is only counted
+        assert_eq!(failures.record_failure(&err), Some(1));
+        assert_eq!(failures.record_failure(&err), None);
+        assert_eq!(failures.record_failure(&err), None);
+        assert!(metrics::metrics().udp_send_failures.with_label_values(&["advisory"]).get() >= before + 3);
+
+        // Recovery resets, so the next outage warns straight away
+        failures.record_success();
+        assert_eq!(failures.record_failure(&err), Some(1));
+    }
+}
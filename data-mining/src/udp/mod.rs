@@ -7,16 +7,36 @@
 /// - Type 3: LateOpportunity - New token with strong momentum
 /// - Type 4: CopyTrade - Alpha wallet activity detected
 /// - Type 5: SolPriceUpdate - SOL price update from oracle
+///
+/// Mint-scoped advisories pass through a per-(mint, type) token bucket
+/// (see `rate_limiter`) so one hot token cannot flood Brain.
+///
+/// Both senders can use a Unix datagram socket instead of UDP (`new_unix`,
+/// see `transport`) with the same message layouts.
 
+pub mod auth;
 pub mod batched_sender;
+pub mod error;
+pub mod rate_limiter;
+pub mod transport;
 
-use std::net::UdpSocket;
-use std::sync::Arc;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
 use std::time::{SystemTime, UNIX_EPOCH};
 use anyhow::{Context, Result};
 use tracing::{debug, info};
 
+use crate::clock::{self, Clock};
+use crate::config::AdviceRateLimitConfig;
+use crate::metrics;
+use crate::opportunity::ShadowAdvisor;
+use error::{decode_mint, decode_wallet, SendFailures};
+
+pub use auth::AdviceAuth;
 pub use batched_sender::{spawn_batched_sender, BatchedAdvisorySender, BatchedBrainSignalSender, UdpMessage};
+pub use error::SendError;
+pub use rate_limiter::{AdviceRateLimiter, RateDecision};
+pub use transport::AdviceTransport;
 
 /// Default target for execution bot UDP listener
 const DEFAULT_ADVICE_HOST: &str = "127.0.0.1";
@@ -28,7 +48,7 @@ const ADVISORY_SIZE: usize = 64;
 /// Advisory message types
 /// IMPORTANT: These must match Brain's AdviceMessageType enum exactly!
 #[repr(u8)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum AdviceType {
     ExtendHold = 10,         // Brain expects 10
     WidenExit = 11,          // Brain expects 11
@@ -37,13 +57,36 @@ pub enum AdviceType {
     SolPriceUpdate = 14,     // Brain expects 14
     RankOpportunity = 15,    // Path A: Top-ranked new launch
     MomentumOpportunity = 16, // Path B: High momentum token
+    HotlistOpportunity = 31, // Path E: Hotlist 7-signal score
+}
+
+impl AdviceType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AdviceType::ExtendHold => "extend_hold",
+            AdviceType::WidenExit => "widen_exit",
+            AdviceType::LateOpportunity => "late_opportunity",
+            AdviceType::CopyTrade => "copy_trade",
+            AdviceType::SolPriceUpdate => "sol_price_update",
+            AdviceType::RankOpportunity => "rank_opportunity",
+            AdviceType::MomentumOpportunity => "momentum_opportunity",
+            AdviceType::HotlistOpportunity => "hotlist_opportunity",
+        }
+    }
 }
 
 /// UDP Advisory Sender (cloneable via Arc)
 #[derive(Clone)]
 pub struct AdvisorySender {
-    socket: Arc<UdpSocket>,
-    target_addr: String,
+    transport: Arc<AdviceTransport>,
+    auth: AdviceAuth,
+    /// Per-(mint, advice type) limiter (None = unlimited)
+    rate_limiter: Option<Arc<Mutex<AdviceRateLimiter>>>,
+    clock: Arc<dyn Clock>,
+    /// Shadow threshold set evaluated (and logged, never sent) alongside the live one
+    shadow: Option<Arc<ShadowAdvisor>>,
+    /// Socket failures since the last successful send (metric + rate-limited warning)
+    failures: Arc<SendFailures>,
 }
 
 impl AdvisorySender {
@@ -52,36 +95,91 @@ impl AdvisorySender {
     /// # Arguments
     /// * `host` - Target host (e.g., "127.0.0.1")
     /// * `port` - Target port (e.g., 45100)
-    pub fn new(host: &str, port: u16) -> Result<Self> {
-        let socket = UdpSocket::bind("0.0.0.0:0")
-            .context("Failed to bind UDP socket")?;
-        
-        // Set non-blocking to avoid delays if execution bot is offline
-        socket.set_nonblocking(true)
-            .context("Failed to set non-blocking mode")?;
-        
-        let target_addr = format!("{}:{}", host, port);
-        
-        info!("✅ AdvisorySender initialized → {}", target_addr);
-        
-        Ok(Self {
-            socket: Arc::new(socket),
-            target_addr,
-        })
+    /// * `auth` - Shared-secret signer (Brain drops unsigned packets)
+    pub fn new(host: &str, port: u16, auth: AdviceAuth) -> Result<Self> {
+        Ok(Self::with_transport(AdviceTransport::udp(host, port)?, auth))
+    }
+    
+    /// Create a sender to Brain's Unix datagram socket at `path` (single-host setups)
+    pub fn new_unix(path: impl AsRef<Path>, auth: AdviceAuth) -> Result<Self> {
+        Ok(Self::with_transport(AdviceTransport::unix(path)?, auth))
+    }
+    
+    fn with_transport(transport: AdviceTransport, auth: AdviceAuth) -> Self {
+        let target = transport.target();
+        info!("✅ AdvisorySender initialized → {}", target);
+        
+        Self {
+            transport: Arc::new(transport),
+            failures: Arc::new(SendFailures::new("advisory", &target)),
+            auth,
+            rate_limiter: None,
+            clock: clock::system_clock(),
+            shadow: None,
+        }
     }
     
     /// Create with default host/port
-    pub fn new_default() -> Result<Self> {
-        Self::new(DEFAULT_ADVICE_HOST, DEFAULT_ADVICE_PORT)
+    pub fn new_default(auth: AdviceAuth) -> Result<Self> {
+        Self::new(DEFAULT_ADVICE_HOST, DEFAULT_ADVICE_PORT, auth)
+    }
+    
+    /// Rate limit advisories per (mint, advice type) from the `[advice_bus.rate_limit]` config
+    pub fn with_rate_limit(mut self, config: &AdviceRateLimitConfig) -> Self {
+        self.rate_limiter = config
+            .enabled
+            .then(|| Arc::new(Mutex::new(AdviceRateLimiter::new(config.clone()))));
+        self
+    }
+    
+    /// Use a custom time base for rate limiting (e.g. `ReplayClock` for historical replay)
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+    
+    /// Also evaluate a shadow threshold set for every opportunity check
+    pub fn with_shadow(mut self, shadow: Arc<ShadowAdvisor>) -> Self {
+        self.shadow = Some(shadow);
+        self
+    }
+    
+    /// Shadow advisory mode, if enabled
+    pub fn shadow(&self) -> Option<&ShadowAdvisor> {
+        self.shadow.as_deref()
+    }
+    
+    /// Check the rate limiter; records a metric and returns false if the packet is suppressed
+    fn admit(&self, advice_type: AdviceType, mint_bytes: &[u8], packet: &[u8], tier_a: bool) -> bool {
+        let Some(ref limiter) = self.rate_limiter else { return true };
+        let Ok(mint) = <[u8; 32]>::try_from(mint_bytes) else { return true };
+        
+        let decision = limiter.lock().unwrap()
+            .check(advice_type, &mint, packet, tier_a, self.clock.now_ms());
+        
+        if decision == RateDecision::Allow {
+            return true;
+        }
+        
+        metrics::record_advisory_suppressed(advice_type.as_str(), decision.as_str());
+        debug!("🚦 {:?} suppressed ({}) for {}...",
+               advice_type, decision.as_str(), &bs58::encode(mint).into_string()[..12]);
+        false
     }
     
     /// Send a raw advisory packet (internal helper)
-    fn send_advice(&self, packet: &[u8]) -> Result<()> {
-        match self.socket.send_to(packet, &self.target_addr) {
-            Ok(_) => Ok(()),
+    fn send_advice(&self, packet: &[u8]) -> Result<(), SendError> {
+        match self.transport.send(&self.auth.sign(packet)) {
+            Ok(_) => {
+                self.failures.record_success();
+                Ok(())
+            }
             // Gracefully handle if execution bot is offline
             Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => Ok(()),
-            Err(e) => Err(e.into()),
+            Err(e) => {
+                self.failures.record_failure(&e);
+                Err(e.into())
+            }
         }
     }
     
@@ -93,13 +191,8 @@ impl AdvisorySender {
     /// * `mint_b58` - Token mint address (base58, 32 bytes)
     /// * `horizon_sec` - Time horizon in seconds (how long opportunity is valid)
     /// * `score` - Opportunity score 0-100 (higher = stronger signal)
-    pub fn send_late_opportunity(&self, mint_b58: &str, horizon_sec: u16, score: u8) -> Result<()> {
-        let mint_bytes = bs58::decode(mint_b58).into_vec()
-            .context("Invalid mint base58")?;
-        
-        if mint_bytes.len() != 32 {
-            anyhow::bail!("Mint must be 32 bytes, got {}", mint_bytes.len());
-        }
+    pub fn send_late_opportunity(&self, mint_b58: &str, horizon_sec: u16, score: u8) -> Result<(), SendError> {
+        let mint_bytes = decode_mint(mint_b58)?;
         
         let mut msg = vec![0u8; ADVISORY_SIZE];
         msg[0] = AdviceType::LateOpportunity as u8;
@@ -107,6 +200,10 @@ impl AdvisorySender {
         msg[33..35].copy_from_slice(&horizon_sec.to_le_bytes());
         msg[35] = score.clamp(0, 100);
         
+        if !self.admit(AdviceType::LateOpportunity, &mint_bytes, &msg, false) {
+            return Ok(());
+        }
+        
         self.send_advice(&msg)?;
         
         debug!(
@@ -144,18 +241,9 @@ impl AdvisorySender {
         size_sol: f32,
         wallet_tier: u8,
         confidence: u8
-    ) -> Result<()> {
-        let mint_bytes = bs58::decode(mint_b58).into_vec()
-            .context("Invalid mint base58")?;
-        let wallet_bytes = bs58::decode(wallet_b58).into_vec()
-            .context("Invalid wallet base58")?;
-        
-        if mint_bytes.len() != 32 {
-            anyhow::bail!("Mint must be 32 bytes, got {}", mint_bytes.len());
-        }
-        if wallet_bytes.len() != 32 {
-            anyhow::bail!("Wallet must be 32 bytes, got {}", wallet_bytes.len());
-        }
+    ) -> Result<(), SendError> {
+        let mint_bytes = decode_mint(mint_b58)?;
+        let wallet_bytes = decode_wallet(wallet_b58)?;
         
         // Brain expects 80 bytes: [type(1) | wallet(32) | mint(32) | side(1) | size(4) | tier(1) | conf(1) | padding(8)]
         let mut msg = vec![0u8; 80];
@@ -168,6 +256,10 @@ impl AdvisorySender {
         msg[71] = confidence.clamp(0, 100);
         // bytes 72-79 are padding (already zero)
         
+        if !self.admit(AdviceType::CopyTrade, &mint_bytes, &msg, wallet_tier >= 3) {
+            return Ok(());
+        }
+        
         self.send_advice(&msg)?;
         
         debug!(
@@ -187,13 +279,8 @@ impl AdvisorySender {
     /// * `mint_b58` - Token mint address (base58, 32 bytes)
     /// * `extra_secs` - Extra seconds to hold beyond normal exit
     /// * `confidence` - Confidence score 0-100
-    pub fn send_extend_hold(&self, mint_b58: &str, extra_secs: u16, confidence: u8) -> Result<()> {
-        let mint_bytes = bs58::decode(mint_b58).into_vec()
-            .context("Invalid mint base58")?;
-        
-        if mint_bytes.len() != 32 {
-            anyhow::bail!("Mint must be 32 bytes, got {}", mint_bytes.len());
-        }
+    pub fn send_extend_hold(&self, mint_b58: &str, extra_secs: u16, confidence: u8) -> Result<(), SendError> {
+        let mint_bytes = decode_mint(mint_b58)?;
         
         let mut msg = vec![0u8; ADVISORY_SIZE];
         msg[0] = AdviceType::ExtendHold as u8;
@@ -201,6 +288,10 @@ impl AdvisorySender {
         msg[33..35].copy_from_slice(&extra_secs.to_le_bytes());
         msg[35] = confidence.clamp(0, 100);
         
+        if !self.admit(AdviceType::ExtendHold, &mint_bytes, &msg, false) {
+            return Ok(());
+        }
+        
         self.send_advice(&msg)?;
         
         debug!(
@@ -221,13 +312,8 @@ impl AdvisorySender {
     /// * `slip_bps` - Max slippage in basis points (e.g., 500 = 5%)
     /// * `ttl_ms` - Time-to-live in milliseconds (urgency)
     /// * `confidence` - Confidence score 0-100
-    pub fn send_widen_exit(&self, mint_b58: &str, slip_bps: u32, ttl_ms: u32, confidence: u8) -> Result<()> {
-        let mint_bytes = bs58::decode(mint_b58).into_vec()
-            .context("Invalid mint base58")?;
-        
-        if mint_bytes.len() != 32 {
-            anyhow::bail!("Mint must be 32 bytes, got {}", mint_bytes.len());
-        }
+    pub fn send_widen_exit(&self, mint_b58: &str, slip_bps: u32, ttl_ms: u32, confidence: u8) -> Result<(), SendError> {
+        let mint_bytes = decode_mint(mint_b58)?;
         
         let mut msg = vec![0u8; ADVISORY_SIZE];
         msg[0] = AdviceType::WidenExit as u8;
@@ -236,6 +322,10 @@ impl AdvisorySender {
         msg[37..41].copy_from_slice(&ttl_ms.to_le_bytes());
         msg[41] = confidence.clamp(0, 100);
         
+        if !self.admit(AdviceType::WidenExit, &mint_bytes, &msg, false) {
+            return Ok(());
+        }
+        
         self.send_advice(&msg)?;
         
         debug!(
@@ -253,7 +343,7 @@ impl AdvisorySender {
     /// # Arguments
     /// * `price_usd` - SOL price in USD (e.g., 182.83)
     /// * `source` - Price source: 1=Helius, 2=Jupiter, 3=Other
-    pub fn send_sol_price_update(&self, price_usd: f64, source: u8) -> Result<()> {
+    pub fn send_sol_price_update(&self, price_usd: f64, source: u8) -> Result<(), SendError> {
         let price_cents = (price_usd * 100.0) as u32;
         let timestamp_secs = SystemTime::now()
             .duration_since(UNIX_EPOCH)
@@ -289,13 +379,8 @@ impl AdvisorySender {
     /// * `mint_b58` - Token mint address (base58, 32 bytes)
     /// * `rank` - Token's rank (1-255, lower is better)
     /// * `score` - Follow-through score 0-100
-    pub fn send_rank_opportunity(&self, mint_b58: &str, rank: u8, score: u8) -> Result<()> {
-        let mint_bytes = bs58::decode(mint_b58).into_vec()
-            .context("Invalid mint base58")?;
-        
-        if mint_bytes.len() != 32 {
-            anyhow::bail!("Mint must be 32 bytes, got {}", mint_bytes.len());
-        }
+    pub fn send_rank_opportunity(&self, mint_b58: &str, rank: u8, score: u8) -> Result<(), SendError> {
+        let mint_bytes = decode_mint(mint_b58)?;
         
         let mut msg = vec![0u8; ADVISORY_SIZE];
         msg[0] = AdviceType::RankOpportunity as u8;
@@ -303,6 +388,10 @@ impl AdvisorySender {
         msg[33] = rank;
         msg[34] = score.clamp(0, 100);
         
+        if !self.admit(AdviceType::RankOpportunity, &mint_bytes, &msg, false) {
+            return Ok(());
+        }
+        
         self.send_advice(&msg)?;
         
         debug!(
@@ -322,13 +411,8 @@ impl AdvisorySender {
     /// * `vol_5s_sol` - Volume in last 5 seconds (SOL, scaled x100 for transmission)
     /// * `buyers_2s` - Unique buyers in last 2 seconds
     /// * `score` - Momentum score 0-100
-    pub fn send_momentum_opportunity(&self, mint_b58: &str, vol_5s_sol: f64, buyers_2s: u32, score: u8) -> Result<()> {
-        let mint_bytes = bs58::decode(mint_b58).into_vec()
-            .context("Invalid mint base58")?;
-        
-        if mint_bytes.len() != 32 {
-            anyhow::bail!("Mint must be 32 bytes, got {}", mint_bytes.len());
-        }
+    pub fn send_momentum_opportunity(&self, mint_b58: &str, vol_5s_sol: f64, buyers_2s: u32, score: u8) -> Result<(), SendError> {
+        let mint_bytes = decode_mint(mint_b58)?;
         
         let mut msg = vec![0u8; ADVISORY_SIZE];
         msg[0] = AdviceType::MomentumOpportunity as u8;
@@ -344,6 +428,10 @@ impl AdvisorySender {
         
         msg[37] = score.clamp(0, 100);
         
+        if !self.admit(AdviceType::MomentumOpportunity, &mint_bytes, &msg, false) {
+            return Ok(());
+        }
+        
         self.send_advice(&msg)?;
         
         debug!(
@@ -353,6 +441,52 @@ impl AdvisorySender {
         
         Ok(())
     }
+    
+    /// Send HotlistOpportunity advisory (Type 31)
+    /// 
+    /// Sent for the top-scoring fresh launches of each hotlist scoring cycle.
+    /// 
+    /// # Arguments
+    /// * `mint_b58` - Token mint address (base58, 32 bytes)
+    /// * `score` - Hotlist score 0-15 (scaled x100 for transmission)
+    /// * `rank` - Position within this cycle's broadcast (1 = best)
+    /// * `unique_buyers` - Unique buyers since launch
+    /// * `mc_velocity` - Market cap velocity (SOL/min)
+    pub fn send_hotlist_opportunity(
+        &self,
+        mint_b58: &str,
+        score: f64,
+        rank: u8,
+        unique_buyers: u32,
+        mc_velocity: f64,
+    ) -> Result<(), SendError> {
+        let mint_bytes = decode_mint(mint_b58)?;
+        
+        let mut msg = vec![0u8; ADVISORY_SIZE];
+        msg[0] = AdviceType::HotlistOpportunity as u8;
+        msg[1..33].copy_from_slice(&mint_bytes);
+        
+        let score_scaled = (score * 100.0).clamp(0.0, 65535.0) as u16;
+        msg[33..35].copy_from_slice(&score_scaled.to_le_bytes());
+        msg[35] = rank;
+        
+        let buyers = unique_buyers.min(65535) as u16;
+        msg[36..38].copy_from_slice(&buyers.to_le_bytes());
+        msg[38..42].copy_from_slice(&(mc_velocity as f32).to_le_bytes());
+        
+        if !self.admit(AdviceType::HotlistOpportunity, &mint_bytes, &msg, false) {
+            return Ok(());
+        }
+        
+        self.send_advice(&msg)?;
+        
+        debug!(
+            "📤 HotlistOpportunity: {}... | score: {:.1} | rank: {} | buyers: {} | mc_vel: {:.0} SOL/min",
+            &mint_b58[..12], score, rank, unique_buyers, mc_velocity
+        );
+        
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -361,15 +495,15 @@ mod tests {
     
     #[test]
     fn test_advisory_sender_creation() {
-        let sender = AdvisorySender::new("127.0.0.1", 45100);
+        let sender = AdvisorySender::new("127.0.0.1", 45100, AdviceAuth::new(b"test-secret"));
         assert!(sender.is_ok());
     }
     
     #[test]
     fn test_clone() {
-        let sender = AdvisorySender::new("127.0.0.1", 45100).unwrap();
+        let sender = AdvisorySender::new("127.0.0.1", 45100, AdviceAuth::new(b"test-secret")).unwrap();
         let cloned = sender.clone();
-        assert_eq!(sender.target_addr, cloned.target_addr);
+        assert_eq!(sender.transport.target(), cloned.transport.target());
     }
     
     #[test]
@@ -382,6 +516,27 @@ mod tests {
         let mint_bytes = bs58::decode(test_mint).into_vec().unwrap();
         assert_eq!(mint_bytes.len(), 32);
     }
+    
+    #[test]
+    fn test_encoding_errors_are_not_transient() {
+        let sender = AdvisorySender::new("127.0.0.1", 45100, AdviceAuth::new(b"test-secret")).unwrap();
+        let mint = "So11111111111111111111111111111111111111112";
+        
+        let err = sender.send_rank_opportunity("not-base58!", 1, 80).unwrap_err();
+        assert!(matches!(err, SendError::InvalidMint(_)));
+        assert!(!err.is_transient());
+        
+        // Valid base58, wrong length
+        let err = sender.send_copy_trade(mint, "3yZe7d", 0, 1.0, 3, 90).unwrap_err();
+        assert!(matches!(err, SendError::InvalidWallet(_)));
+        
+        let io = std::io::Error::new(std::io::ErrorKind::ConnectionRefused, "refused");
+        assert!(SendError::from(io).is_transient());
+        
+        // Still usable with `?` in anyhow code, and recoverable by downcast
+        let any: anyhow::Error = SendError::InvalidMint("x".to_string()).into();
+        assert!(matches!(any.downcast_ref::<SendError>(), Some(SendError::InvalidMint(_))));
+    }
 }
 
 /// BrainSignalSender - Send real-time signals to Brain bot
@@ -395,8 +550,10 @@ mod tests {
 /// - Type 23: WalletActivity - Alpha wallet activity detected
 #[derive(Clone)]
 pub struct BrainSignalSender {
-    socket: Arc<UdpSocket>,
-    target_addr: String,
+    transport: Arc<AdviceTransport>,
+    auth: AdviceAuth,
+    /// Socket failures since the last successful send (metric + rate-limited warning)
+    failures: Arc<SendFailures>,
 }
 
 impl BrainSignalSender {
@@ -405,31 +562,44 @@ impl BrainSignalSender {
     /// # Arguments
     /// * `host` - Target host (e.g., "127.0.0.1")
     /// * `port` - Target port (e.g., 45120 for brain)
-    pub fn new(host: &str, port: u16) -> Result<Self> {
-        let socket = UdpSocket::bind("0.0.0.0:0")
-            .context("Failed to bind UDP socket for brain signals")?;
-        
-        // Set non-blocking to avoid delays if brain is offline
-        socket.set_nonblocking(true)
-            .context("Failed to set non-blocking mode")?;
-        
-        let target_addr = format!("{}:{}", host, port);
-        
-        info!("✅ BrainSignalSender initialized → {}", target_addr);
-        
-        Ok(Self {
-            socket: Arc::new(socket),
-            target_addr,
-        })
+    /// * `auth` - Shared-secret signer (Brain drops unsigned packets)
+    pub fn new(host: &str, port: u16, auth: AdviceAuth) -> Result<Self> {
+        let transport = AdviceTransport::udp(host, port)
+            .context("Failed to create brain signal socket")?;
+        Ok(Self::with_transport(transport, auth))
+    }
+    
+    /// Create a sender to Brain's Unix datagram socket at `path` (single-host setups)
+    pub fn new_unix(path: impl AsRef<Path>, auth: AdviceAuth) -> Result<Self> {
+        let transport = AdviceTransport::unix(path)
+            .context("Failed to create brain signal socket")?;
+        Ok(Self::with_transport(transport, auth))
+    }
+    
+    fn with_transport(transport: AdviceTransport, auth: AdviceAuth) -> Self {
+        let target = transport.target();
+        info!("✅ BrainSignalSender initialized → {}", target);
+        
+        Self {
+            transport: Arc::new(transport),
+            failures: Arc::new(SendFailures::new("brain_signal", &target)),
+            auth,
+        }
     }
     
     /// Send a raw signal packet (internal helper)
-    fn send_signal(&self, packet: &[u8]) -> Result<()> {
-        match self.socket.send_to(packet, &self.target_addr) {
-            Ok(_) => Ok(()),
+    fn send_signal(&self, packet: &[u8]) -> Result<(), SendError> {
+        match self.transport.send(&self.auth.sign(packet)) {
+            Ok(_) => {
+                self.failures.record_success();
+                Ok(())
+            }
             // Gracefully handle if brain is offline
             Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => Ok(()),
-            Err(e) => Err(e.into()),
+            Err(e) => {
+                self.failures.record_failure(&e);
+                Err(e.into())
+            }
         }
     }
     
@@ -450,13 +620,8 @@ impl BrainSignalSender {
         volume_sol: f32,
         unique_buyers: u16,
         confidence: u8,
-    ) -> Result<()> {
-        let mint_bytes = bs58::decode(mint_b58).into_vec()
-            .context("Invalid mint base58")?;
-        
-        if mint_bytes.len() != 32 {
-            anyhow::bail!("Mint must be 32 bytes, got {}", mint_bytes.len());
-        }
+    ) -> Result<(), SendError> {
+        let mint_bytes = decode_mint(mint_b58)?;
         
         let timestamp_ns = SystemTime::now()
             .duration_since(UNIX_EPOCH)
@@ -501,13 +666,8 @@ impl BrainSignalSender {
         tx_count: u16,
         time_window_ms: u16,
         confidence: u8,
-    ) -> Result<()> {
-        let mint_bytes = bs58::decode(mint_b58).into_vec()
-            .context("Invalid mint base58")?;
-        
-        if mint_bytes.len() != 32 {
-            anyhow::bail!("Mint must be 32 bytes, got {}", mint_bytes.len());
-        }
+    ) -> Result<(), SendError> {
+        let mint_bytes = decode_mint(mint_b58)?;
         
         let timestamp_ns = SystemTime::now()
             .duration_since(UNIX_EPOCH)
@@ -554,18 +714,9 @@ impl BrainSignalSender {
         size_sol: f32,
         wallet_tier: u8,
         confidence: u8,
-    ) -> Result<()> {
-        let mint_bytes = bs58::decode(mint_b58).into_vec()
-            .context("Invalid mint base58")?;
-        let wallet_bytes = bs58::decode(wallet_b58).into_vec()
-            .context("Invalid wallet base58")?;
-        
-        if mint_bytes.len() != 32 {
-            anyhow::bail!("Mint must be 32 bytes, got {}", mint_bytes.len());
-        }
-        if wallet_bytes.len() != 32 {
-            anyhow::bail!("Wallet must be 32 bytes, got {}", wallet_bytes.len());
-        }
+    ) -> Result<(), SendError> {
+        let mint_bytes = decode_mint(mint_b58)?;
+        let wallet_bytes = decode_wallet(wallet_b58)?;
         
         let timestamp_ns = SystemTime::now()
             .duration_since(UNIX_EPOCH)
@@ -612,13 +763,8 @@ impl BrainSignalSender {
         unique_buyers_1s: u16,
         price_change_bps_2s: i16,
         alpha_wallet_hits_10s: u8,
-    ) -> Result<()> {
-        let mint_bytes = bs58::decode(mint_b58).into_vec()
-            .context("Invalid mint base58")?;
-        
-        if mint_bytes.len() != 32 {
-            anyhow::bail!("Mint must be 32 bytes, got {}", mint_bytes.len());
-        }
+    ) -> Result<(), SendError> {
+        let mint_bytes = decode_mint(mint_b58)?;
         
         // Scale volume by 1000 to fit in u32
         let volume_scaled = (volume_sol_1s * 1000.0).min(u32::MAX as f64) as u32;
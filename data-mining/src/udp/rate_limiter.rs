@@ -0,0 +1,196 @@
+//! 🚦 Per-(mint, advice type) rate limiter for advisories
+//!
+//! A single very active token can trigger the same advisory dozens of times per
+//! second. Each (mint, advice type) pair gets a token bucket refilled at
+//! `rate_per_sec` up to `burst`; byte-identical repeats within
+//! `coalesce_window_ms` are coalesced (dropped) even when tokens remain.
+//! Tier-A copy-trade signals can bypass the limiter entirely.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+use crate::config::AdviceRateLimitConfig;
+
+use super::AdviceType;
+
+/// Evict idle buckets once this many (mint, type) pairs are tracked
+const MAX_TRACKED_KEYS: usize = 4096;
+
+/// Buckets idle for longer than this are considered full and can be evicted
+const IDLE_EVICT_MS: u64 = 60_000;
+
+/// Outcome of a rate-limit check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateDecision {
+    Allow,
+    /// Same packet already sent for this key within the coalesce window
+    Duplicate,
+    /// Token bucket empty
+    RateLimited,
+}
+
+impl RateDecision {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RateDecision::Allow => "allow",
+            RateDecision::Duplicate => "duplicate",
+            RateDecision::RateLimited => "rate_limited",
+        }
+    }
+}
+
+struct Bucket {
+    tokens: f64,
+    last_refill_ms: u64,
+    last_packet_hash: u64,
+    last_sent_ms: u64,
+}
+
+/// Token-bucket limiter keyed by (advice type, mint)
+pub struct AdviceRateLimiter {
+    config: AdviceRateLimitConfig,
+    buckets: HashMap<(AdviceType, [u8; 32]), Bucket>,
+}
+
+impl AdviceRateLimiter {
+    pub fn new(config: AdviceRateLimitConfig) -> Self {
+        Self {
+            config,
+            buckets: HashMap::new(),
+        }
+    }
+
+    /// Decide whether `packet` for (`advice_type`, `mint`) may be sent at `now_ms`
+    ///
+    /// `tier_a` marks Tier-A copy-trade signals, which skip the limiter when
+    /// `exempt_tier_a` is set.
+    pub fn check(
+        &mut self,
+        advice_type: AdviceType,
+        mint: &[u8; 32],
+        packet: &[u8],
+        tier_a: bool,
+        now_ms: u64,
+    ) -> RateDecision {
+        if tier_a && self.config.exempt_tier_a {
+            return RateDecision::Allow;
+        }
+
+        if self.buckets.len() >= MAX_TRACKED_KEYS {
+            self.buckets
+                .retain(|_, b| now_ms.saturating_sub(b.last_refill_ms) < IDLE_EVICT_MS);
+        }
+
+        let packet_hash = {
+            let mut hasher = DefaultHasher::new();
+            packet.hash(&mut hasher);
+            hasher.finish()
+        };
+
+        let burst = self.config.burst.max(1.0);
+        let bucket = self.buckets.entry((advice_type, *mint)).or_insert(Bucket {
+            tokens: burst,
+            last_refill_ms: now_ms,
+            last_packet_hash: 0,
+            last_sent_ms: 0,
+        });
+
+        // Refill
+        let elapsed_secs = now_ms.saturating_sub(bucket.last_refill_ms) as f64 / 1000.0;
+        bucket.tokens = (bucket.tokens + elapsed_secs * self.config.rate_per_sec).min(burst);
+        bucket.last_refill_ms = now_ms;
+
+        if bucket.last_sent_ms > 0
+            && bucket.last_packet_hash == packet_hash
+            && now_ms.saturating_sub(bucket.last_sent_ms) < self.config.coalesce_window_ms
+        {
+            return RateDecision::Duplicate;
+        }
+
+        if bucket.tokens < 1.0 {
+            return RateDecision::RateLimited;
+        }
+
+        bucket.tokens -= 1.0;
+        bucket.last_packet_hash = packet_hash;
+        bucket.last_sent_ms = now_ms;
+        RateDecision::Allow
+    }
+
+    /// Number of (mint, type) pairs currently tracked
+    pub fn tracked_keys(&self) -> usize {
+        self.buckets.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limiter() -> AdviceRateLimiter {
+        AdviceRateLimiter::new(AdviceRateLimitConfig {
+            enabled: true,
+            rate_per_sec: 2.0,
+            burst: 3.0,
+            coalesce_window_ms: 1000,
+            exempt_tier_a: true,
+        })
+    }
+
+    fn packet(n: u8) -> Vec<u8> {
+        vec![AdviceType::WidenExit as u8, n]
+    }
+
+    #[test]
+    fn test_burst_then_refill() {
+        let mut rl = limiter();
+        let mint = [1u8; 32];
+        let t0 = 1_000_000;
+
+        for i in 0..3 {
+            assert_eq!(rl.check(AdviceType::WidenExit, &mint, &packet(i), false, t0), RateDecision::Allow);
+        }
+        assert_eq!(rl.check(AdviceType::WidenExit, &mint, &packet(3), false, t0), RateDecision::RateLimited);
+
+        // 2/s refill -> one token after 500ms
+        assert_eq!(rl.check(AdviceType::WidenExit, &mint, &packet(4), false, t0 + 500), RateDecision::Allow);
+        assert_eq!(rl.check(AdviceType::WidenExit, &mint, &packet(5), false, t0 + 500), RateDecision::RateLimited);
+    }
+
+    #[test]
+    fn test_duplicates_coalesced_within_window() {
+        let mut rl = limiter();
+        let mint = [2u8; 32];
+        let t0 = 1_000_000;
+
+        assert_eq!(rl.check(AdviceType::ExtendHold, &mint, &packet(7), false, t0), RateDecision::Allow);
+        assert_eq!(rl.check(AdviceType::ExtendHold, &mint, &packet(7), false, t0 + 200), RateDecision::Duplicate);
+        assert_eq!(rl.check(AdviceType::ExtendHold, &mint, &packet(7), false, t0 + 1000), RateDecision::Allow);
+    }
+
+    #[test]
+    fn test_keys_are_independent() {
+        let mut rl = limiter();
+        let t0 = 1_000_000;
+
+        for i in 0..3 {
+            rl.check(AdviceType::CopyTrade, &[3u8; 32], &packet(i), false, t0);
+        }
+        assert_eq!(rl.check(AdviceType::CopyTrade, &[3u8; 32], &packet(9), false, t0), RateDecision::RateLimited);
+        assert_eq!(rl.check(AdviceType::CopyTrade, &[4u8; 32], &packet(9), false, t0), RateDecision::Allow);
+        assert_eq!(rl.check(AdviceType::WidenExit, &[3u8; 32], &packet(9), false, t0), RateDecision::Allow);
+        assert_eq!(rl.tracked_keys(), 3);
+    }
+
+    #[test]
+    fn test_tier_a_exempt() {
+        let mut rl = limiter();
+        let mint = [5u8; 32];
+
+        for _ in 0..10 {
+            assert_eq!(rl.check(AdviceType::CopyTrade, &mint, &packet(1), true, 1_000_000), RateDecision::Allow);
+        }
+        assert_eq!(rl.tracked_keys(), 0);
+    }
+}
@@ -0,0 +1,84 @@
+//! Datagram transport for the advisory / brain signal senders
+//!
+//! UDP by default; single-host deployments can point `advice_bus.unix_socket_path`
+//! at Brain's Unix datagram socket instead, which skips the loopback network
+//! stack (and its drops under load). Message layouts are identical either way.
+
+use std::io;
+use std::net::UdpSocket;
+use std::os::unix::net::UnixDatagram;
+use std::path::{Path, PathBuf};
+use anyhow::{Context, Result};
+
+/// Where and how a sender delivers its datagrams
+pub enum AdviceTransport {
+    Udp { socket: UdpSocket, target: String },
+    Unix { socket: UnixDatagram, path: PathBuf },
+}
+
+impl AdviceTransport {
+    /// Non-blocking UDP socket sending to `host:port`
+    pub fn udp(host: &str, port: u16) -> Result<Self> {
+        let socket = UdpSocket::bind("0.0.0.0:0")
+            .context("Failed to bind UDP socket")?;
+        // Set non-blocking to avoid delays if the receiver is offline
+        socket.set_nonblocking(true)
+            .context("Failed to set non-blocking mode")?;
+
+        Ok(Self::Udp { socket, target: format!("{}:{}", host, port) })
+    }
+
+    /// Non-blocking unbound Unix datagram socket sending to the socket at `path`
+    pub fn unix(path: impl AsRef<Path>) -> Result<Self> {
+        let socket = UnixDatagram::unbound()
+            .context("Failed to create Unix datagram socket")?;
+        socket.set_nonblocking(true)
+            .context("Failed to set non-blocking mode")?;
+
+        Ok(Self::Unix { socket, path: path.as_ref().to_path_buf() })
+    }
+
+    /// Send one datagram (`WouldBlock` is returned like any other error)
+    pub fn send(&self, datagram: &[u8]) -> io::Result<usize> {
+        match self {
+            Self::Udp { socket, target } => socket.send_to(datagram, target),
+            Self::Unix { socket, path } => socket.send_to(datagram, path),
+        }
+    }
+
+    /// Destination for logs: `host:port` or the socket path
+    pub fn target(&self) -> String {
+        match self {
+            Self::Udp { target, .. } => target.clone(),
+            Self::Unix { path, .. } => path.display().to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unix_transport_delivers_datagrams() {
+        let path = std::env::temp_dir().join(format!("advice_transport_{}.sock", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let receiver = UnixDatagram::bind(&path).unwrap();
+
+        let transport = AdviceTransport::unix(&path).unwrap();
+        assert_eq!(transport.target(), path.display().to_string());
+        transport.send(&[14; 64]).unwrap();
+        transport.send(&[23; 84]).unwrap();
+
+        // One datagram per send, boundaries kept
+        let mut buf = [0u8; 256];
+        assert_eq!(receiver.recv(&mut buf).unwrap(), 64);
+        assert_eq!(buf[0], 14);
+        assert_eq!(receiver.recv(&mut buf).unwrap(), 84);
+        assert_eq!(buf[0], 23);
+
+        // Brain not running: the send fails instead of silently vanishing
+        std::fs::remove_file(&path).unwrap();
+        assert!(transport.send(&[14; 64]).is_err());
+    }
+}
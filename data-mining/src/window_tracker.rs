@@ -2,14 +2,17 @@
 /// 
 /// Tracks rolling windows of trading activity to calculate:
 /// - volume_sol_1s: SOL volume in last 1 second
-/// - unique_buyers_1s: Unique buyers in last 1 second
+/// - unique_buyers_1s: Unique buyers in last 1 second (raw and sybil-filtered)
 /// - price_change_bps_2s: Price change over 2 seconds (basis points)
 /// - alpha_wallet_hits_10s: Alpha wallet buys in last 10 seconds
 
 use std::collections::{HashMap, HashSet, VecDeque};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::sync::Arc;
 use tracing::debug;
 
+use crate::clock::{self, Clock};
+use crate::config::SybilConfig;
+
 /// Single trade event for window tracking
 #[derive(Clone, Debug)]
 struct TradeEvent {
@@ -26,6 +29,8 @@ pub struct WindowMetrics {
     pub mint: String,
     pub volume_sol_1s: f64,
     pub unique_buyers_1s: u16,
+    /// unique_buyers_1s with fresh wallets down-weighted (equal to it when sybil dampening is off)
+    pub unique_buyers_1s_filtered: u16,
     pub price_change_bps_2s: i16,
     pub alpha_wallet_hits_10s: u8,
     pub timestamp_ms: u64,
@@ -49,6 +54,8 @@ struct MintWindow {
     last_metrics_sent_ms: u64,
     /// MC history for velocity calculation (timestamp_ms, mc_sol)
     mc_history: VecDeque<(u64, f64)>,
+    /// Traders whose first observed token is this one (sybil dampening)
+    fresh_traders: HashSet<String>,
 }
 
 impl MintWindow {
@@ -58,6 +65,7 @@ impl MintWindow {
             last_price: 0.0,
             last_metrics_sent_ms: 0,
             mc_history: VecDeque::new(),
+            fresh_traders: HashSet::new(),
         }
     }
     
@@ -100,7 +108,7 @@ impl MintWindow {
     }
     
     /// Calculate metrics for current window
-    fn calculate_metrics(&self, mint: &str, current_time_ms: u64, current_mc_sol: f64) -> WindowMetrics {
+    fn calculate_metrics(&self, mint: &str, current_time_ms: u64, current_mc_sol: f64, sybil: &SybilConfig) -> WindowMetrics {
         let cutoff_1s = current_time_ms.saturating_sub(1_000);
         let cutoff_2s = current_time_ms.saturating_sub(2_000);
         let cutoff_10s = current_time_ms.saturating_sub(10_000);
@@ -114,7 +122,7 @@ impl MintWindow {
             // 1s metrics
             if event.timestamp_ms >= cutoff_1s {
                 volume_1s += event.amount_sol;
-                buyers_1s.insert(event.trader.clone());
+                buyers_1s.insert(event.trader.as_str());
             }
             
             // 2s price tracking (find oldest price in 2s window)
@@ -128,6 +136,9 @@ impl MintWindow {
             }
         }
         
+        let fresh_1s = buyers_1s.iter().filter(|t| self.fresh_traders.contains(**t)).count();
+        let filtered_1s = sybil.filtered_buyers(buyers_1s.len() - fresh_1s, fresh_1s);
+
         // Calculate price change in basis points
         let price_change_bps = if let Some(old_price) = price_2s_ago {
             if old_price > 0.0 {
@@ -169,6 +180,7 @@ impl MintWindow {
             mint: mint.to_string(),
             volume_sol_1s: volume_1s,
             unique_buyers_1s: buyers_1s.len().min(u16::MAX as usize) as u16,
+            unique_buyers_1s_filtered: filtered_1s.min(u16::MAX as u64) as u16,
             price_change_bps_2s: price_change_bps,
             alpha_wallet_hits_10s: alpha_hits_10s.min(255),
             timestamp_ms: current_time_ms,
@@ -181,7 +193,7 @@ impl MintWindow {
     
     /// Check if metrics should be sent (throttle to avoid spam)
     fn should_send_metrics(&self, current_time_ms: u64, min_interval_ms: u64) -> bool {
-        current_time_ms - self.last_metrics_sent_ms >= min_interval_ms
+        current_time_ms.saturating_sub(self.last_metrics_sent_ms) >= min_interval_ms
     }
     
     /// Mark metrics as sent
@@ -190,6 +202,12 @@ impl MintWindow {
     }
 }
 
+/// First token a wallet was seen trading (sybil dampening)
+struct WalletHistory {
+    first_mint: String,
+    last_seen_ms: u64,
+}
+
 /// Global window tracker for all mints
 pub struct WindowTracker {
     /// Per-mint windows
@@ -198,6 +216,12 @@ pub struct WindowTracker {
     send_interval_ms: u64,
     /// Minimum activity threshold (trades in last 2s) to send metrics
     min_activity_threshold: usize,
+    /// Time base (wall clock live, trade timestamps in replay)
+    clock: Arc<dyn Clock>,
+    /// Fresh-wallet down-weighting for unique_buyers_1s_filtered
+    sybil: SybilConfig,
+    /// Per-wallet first token, only kept while sybil dampening is enabled
+    wallets: HashMap<String, WalletHistory>,
 }
 
 impl WindowTracker {
@@ -211,9 +235,27 @@ impl WindowTracker {
             windows: HashMap::new(),
             send_interval_ms,
             min_activity_threshold,
+            clock: clock::system_clock(),
+            sybil: SybilConfig::default(),
+            wallets: HashMap::new(),
         }
     }
     
+    /// Use a custom time base (e.g. `ReplayClock` for historical replay)
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+    
+    /// Down-weight fresh wallets in unique_buyers_1s_filtered (default: disabled)
+    ///
+    /// Wallet history starts empty, so every wallet is fresh on the first
+    /// token it's seen trading after startup.
+    pub fn with_sybil(mut self, sybil: SybilConfig) -> Self {
+        self.sybil = sybil;
+        self
+    }
+    
     /// Create with default settings
     pub fn new_default() -> Self {
         Self::new(500, 3) // Send every 500ms, require 3+ trades
@@ -229,10 +271,22 @@ impl WindowTracker {
         trader: &str,
         is_alpha: bool,
     ) {
+        let is_fresh = self.sybil.enabled && {
+            let history = self.wallets.entry(trader.to_string()).or_insert_with(|| WalletHistory {
+                first_mint: mint.to_string(),
+                last_seen_ms: timestamp_ms,
+            });
+            history.last_seen_ms = history.last_seen_ms.max(timestamp_ms);
+            history.first_mint == mint
+        };
+        
         let window = self.windows
             .entry(mint.to_string())
             .or_insert_with(MintWindow::new);
         
+        if is_fresh {
+            window.fresh_traders.insert(trader.to_string());
+        }
         window.add_event(timestamp_ms, amount_sol, price, trader.to_string(), is_alpha);
     }
     
@@ -262,10 +316,7 @@ impl WindowTracker {
     pub fn get_metrics_if_ready(&mut self, mint: &str, current_mc_sol: f64) -> Option<WindowMetrics> {
         let window = self.windows.get_mut(mint)?;
         
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .ok()?
-            .as_millis() as u64;
+        let now = self.clock.now_ms();
         
         // Check if enough time has passed
         if !window.should_send_metrics(now, self.send_interval_ms) {
@@ -283,14 +334,15 @@ impl WindowTracker {
         }
         
         // Calculate and return metrics
-        let metrics = window.calculate_metrics(mint, now, current_mc_sol);
+        let metrics = window.calculate_metrics(mint, now, current_mc_sol, &self.sybil);
         window.mark_metrics_sent(now);
         
         debug!(
-            "📊 WindowMetrics: {} | vol_1s: {:.2} SOL, buyers_1s: {}, Δprice_2s: {}bps, alpha_10s: {}, MC: {:.0} SOL, velocity: {:.0} SOL/min",
+            "📊 WindowMetrics: {} | vol_1s: {:.2} SOL, buyers_1s: {} ({} filtered), Δprice_2s: {}bps, alpha_10s: {}, MC: {:.0} SOL, velocity: {:.0} SOL/min",
             &mint[..12.min(mint.len())],
             metrics.volume_sol_1s,
             metrics.unique_buyers_1s,
+            metrics.unique_buyers_1s_filtered,
             metrics.price_change_bps_2s,
             metrics.alpha_wallet_hits_10s,
             metrics.mc_sol,
@@ -300,25 +352,30 @@ impl WindowTracker {
         Some(metrics)
     }
     
-    /// Clean up old windows to prevent memory leaks
-    /// Call periodically (e.g., every minute)
-    pub fn cleanup_old_windows(&mut self, max_idle_sec: u64) {
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .map(|d| d.as_secs())
-            .unwrap_or(0);
+    /// Drop mints with no trades in the last `max_age_ms` to prevent memory leaks
+    ///
+    /// Returns the number of windows evicted. Call periodically (e.g., every 30s).
+    /// Wallet history older than `sybil.wallet_history_secs` is dropped too.
+    pub fn prune_inactive(&mut self, max_age_ms: u64) -> usize {
+        let now_ms = self.clock.now_ms();
+        let history_cutoff_ms = now_ms.saturating_sub(self.sybil.wallet_history_secs.saturating_mul(1_000));
+        self.wallets.retain(|_, history| history.last_seen_ms >= history_cutoff_ms);
         
-        let cutoff_ms = now.saturating_sub(max_idle_sec) * 1000;
+        let cutoff_ms = now_ms.saturating_sub(max_age_ms);
+        let before = self.windows.len();
         
         self.windows.retain(|mint, window| {
-            if let Some(last_event) = window.events.back() {
-                if last_event.timestamp_ms < cutoff_ms {
-                    debug!("🧹 Cleaned up idle window for {}", &mint[..12.min(mint.len())]);
-                    return false;
-                }
+            // Windows that never saw a trade (MC updates only) are dropped too
+            let active = window.events.back()
+                .map(|e| e.timestamp_ms >= cutoff_ms)
+                .unwrap_or(false);
+            if !active {
+                debug!("🧹 Cleaned up idle window for {}", &mint[..12.min(mint.len())]);
             }
-            true
+            active
         });
+        
+        before - self.windows.len()
     }
     
     /// Get current window count (for monitoring)
@@ -330,6 +387,7 @@ impl WindowTracker {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::time::{SystemTime, UNIX_EPOCH};
     
     #[test]
     fn test_window_tracker_basic() {
@@ -359,7 +417,7 @@ mod tests {
         window.add_event(now, 1.0, 0.001, "t1".to_string(), false);
         window.add_event(now + 1000, 1.0, 0.0011, "t2".to_string(), false);
         
-        let metrics = window.calculate_metrics("test", now + 2000, 100.0);
+        let metrics = window.calculate_metrics("test", now + 2000, 100.0, &SybilConfig::default());
         
         // Price went from 0.001 to 0.0011 = 10% increase = 1000bps
         assert_eq!(metrics.price_change_bps_2s, 1000);
@@ -376,9 +434,61 @@ mod tests {
         window.add_event(now + 200, 1.0, 0.001, "buyer2".to_string(), false);
         window.add_event(now + 300, 1.0, 0.001, "buyer3".to_string(), false);
         
-        let metrics = window.calculate_metrics("test", now + 500, 100.0);
+        let metrics = window.calculate_metrics("test", now + 500, 100.0, &SybilConfig::default());
         
         // Should count 3 unique buyers in 1s window
         assert_eq!(metrics.unique_buyers_1s, 3);
     }
-}
\ No newline at end of file
+    
+    #[test]
+    fn test_metrics_use_replay_clock() {
+        let start = 1_700_000_000_000;
+        let clock = Arc::new(crate::clock::ReplayClock::new(start));
+        let mut tracker = WindowTracker::new(500, 3).with_clock(clock.clone());
+        let mint = "replay_mint";
+        
+        tracker.add_trade(mint, start, 1.0, 0.001, "t1", false);
+        tracker.add_trade(mint, start + 100, 1.0, 0.001, "t2", false);
+        tracker.add_trade(mint, start + 200, 1.0, 0.001, "t3", false);
+        
+        clock.set(start + 600);
+        let metrics = tracker.get_metrics_if_ready(mint, 100.0).unwrap();
+        assert_eq!(metrics.timestamp_ms, start + 600);
+        assert_eq!(metrics.unique_buyers_1s, 3);
+        
+        // Throttled until send_interval elapses in replayed time
+        assert!(tracker.get_metrics_if_ready(mint, 100.0).is_none());
+        
+        // Idle cleanup is also measured in replayed time
+        clock.set(start + 120_000);
+        assert_eq!(tracker.prune_inactive(60_000), 1);
+        assert_eq!(tracker.window_count(), 0);
+    }
+    
+    #[test]
+    fn test_sybil_filtered_buyers() {
+        let start = 1_700_000_000_000;
+        let clock = Arc::new(crate::clock::ReplayClock::new(start));
+        let sybil = SybilConfig { enabled: true, fresh_wallet_weight: 0.25, wallet_history_secs: 3600 };
+        let mut tracker = WindowTracker::new(500, 3).with_clock(clock.clone()).with_sybil(sybil);
+        
+        // Two wallets with history on another token, then a burst of four new ones
+        tracker.add_trade("older_mint", start, 1.0, 0.001, "vet1", false);
+        tracker.add_trade("older_mint", start, 1.0, 0.001, "vet2", false);
+        tracker.add_trade("hot_mint", start + 100, 1.0, 0.001, "vet1", false);
+        tracker.add_trade("hot_mint", start + 100, 1.0, 0.001, "vet2", false);
+        for i in 0..4 {
+            tracker.add_trade("hot_mint", start + 200, 0.1, 0.001, &format!("fresh{}", i), false);
+        }
+        
+        clock.set(start + 600);
+        let metrics = tracker.get_metrics_if_ready("hot_mint", 100.0).unwrap();
+        assert_eq!(metrics.unique_buyers_1s, 6);
+        assert_eq!(metrics.unique_buyers_1s_filtered, 3);
+        
+        // Wallet history expires with sybil.wallet_history_secs
+        clock.set(start + 3_600_000 + 1_000);
+        tracker.prune_inactive(60_000);
+        assert!(tracker.wallets.is_empty());
+    }
+}
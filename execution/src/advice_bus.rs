@@ -7,6 +7,9 @@ use std::time::Duration;
 use anyhow::{Result, Context};
 use log::{debug, warn, info};
 
+/// Smallest message on the bus (64-byte Advisory; TradeDecision is 92)
+const MIN_MESSAGE_SIZE: usize = 64;
+
 /// Unified message type for routing
 #[derive(Debug)]
 pub enum MessageType {
@@ -14,7 +17,7 @@ pub enum MessageType {
     Advisory(Advisory),
 }
 
-/// TradeDecision from Brain (52 bytes)
+/// TradeDecision from Brain (92 bytes)
 #[derive(Debug, Clone, Copy)]
 #[repr(C)]
 pub struct TradeDecision {
@@ -25,17 +28,20 @@ pub struct TradeDecision {
     pub slippage_bps: u16,      // Slippage tolerance in basis points
     pub confidence: u8,         // Confidence score 0-100
     pub retry_count: u8,        // Retry count for progressive slippage (SELL only)
-    pub entry_type: u8,         // Entry strategy: 0=Rank, 1=Momentum, 2=CopyTrade, 3=LateOpportunity
-    pub _padding: [u8; 3],      // Padding to 52 bytes
+    pub entry_type: u8,         // Entry strategy: 0=Rank, 1=Momentum, 2=CopyTrade, 3=LateOpportunity, 4=Hotlist
+    pub min_sol_out_lamports: u64, // SELL only: minimum SOL to receive (0 = no floor)
+    pub decision_id: [u8; 16],  // Brain's decision UUID (dedup key for retransmits)
+    pub _padding: [u8; 3],      // Padding (bytes 73..76)
+    pub trace_id: [u8; 16],     // Brain's pipeline trace UUID (echoed in confirmations)
 }
 
 impl TradeDecision {
-    pub const SIZE: usize = 52;
+    pub const SIZE: usize = 92;
     pub const MSG_TYPE: u8 = 1;
     
-    /// Deserialize from 52-byte UDP packet
+    /// Deserialize from 92-byte UDP packet
     pub fn from_bytes(buf: &[u8]) -> Result<Self> {
-        if buf.len() < 52 {
+        if buf.len() < Self::SIZE {
             anyhow::bail!("TradeDecision message too short: {} bytes", buf.len());
         }
         
@@ -54,6 +60,14 @@ impl TradeDecision {
         let confidence = buf[45];            // Fixed: was 44, now 45
         let retry_count = buf[47];           // Retry count for progressive slippage
         let entry_type = buf[48];            // Entry strategy type
+        let min_sol_out_lamports = u64::from_le_bytes([
+            buf[49], buf[50], buf[51], buf[52],  // Min SOL out floor (SELL)
+            buf[53], buf[54], buf[55], buf[56],
+        ]);
+        let mut decision_id = [0u8; 16];
+        decision_id.copy_from_slice(&buf[57..73]);  // Decision UUID
+        let mut trace_id = [0u8; 16];
+        trace_id.copy_from_slice(&buf[76..92]);     // Trace UUID
         
         Ok(TradeDecision {
             msg_type,
@@ -64,7 +78,10 @@ impl TradeDecision {
             confidence,
             retry_count,
             entry_type,
+            min_sol_out_lamports,
+            decision_id,
             _padding: [0; 3],
+            trace_id,
         })
     }
     
@@ -76,6 +93,29 @@ impl TradeDecision {
         self.side == 1
     }
     
+    /// Brain's decision UUID as a string (None for an all-zero ID)
+    pub fn decision_id_str(&self) -> Option<String> {
+        if self.decision_id == [0u8; 16] {
+            None
+        } else {
+            Some(uuid::Uuid::from_bytes(self.decision_id).to_string())
+        }
+    }
+    
+    /// Brain's trace UUID as a string (None for an all-zero ID)
+    pub fn trace_id_str(&self) -> Option<String> {
+        if self.trace_id == [0u8; 16] {
+            None
+        } else {
+            Some(uuid::Uuid::from_bytes(self.trace_id).to_string())
+        }
+    }
+    
+    /// Short trace tag for logs (first 8 hex chars, "-" when Brain sent none)
+    pub fn trace_tag(&self) -> String {
+        crate::execution_confirmation::trace_tag(&self.trace_id)
+    }
+    
     /// Get human-readable entry strategy name
     pub fn entry_strategy_name(&self) -> &'static str {
         match self.entry_type {
@@ -83,6 +123,7 @@ impl TradeDecision {
             1 => "Momentum",
             2 => "Copy Trade",
             3 => "Late Opportunity",
+            4 => "Hotlist",
             _ => "Unknown",
         }
     }
@@ -587,13 +628,13 @@ impl AdviceBusListener {
         
         match self.socket.recv_from(&mut buf) {
             Ok((len, _addr)) => {
-                if len < 52 {
-                    warn!("Received undersized message: {} bytes (need >= 52)", len);
+                if len < MIN_MESSAGE_SIZE {
+                    warn!("Received undersized message: {} bytes (need >= {})", len, MIN_MESSAGE_SIZE);
                     return None;
                 }
                 
                 // Route by message size
-                if len == 52 {
+                if len == TradeDecision::SIZE {
                     // TradeDecision
                     match TradeDecision::from_bytes(&buf) {
                         Ok(decision) => Some(MessageType::TradeDecision(decision)),
@@ -640,15 +681,15 @@ impl AdviceBusListener {
         
         match self.socket.recv_from(&mut buf) {
             Ok((len, _addr)) => {
-                // Accept both TradeDecision (52 bytes) and Advisory (64+ bytes) formats
-                if len < 52 {
-                    warn!("Received undersized message: {} bytes (need >= 52)", len);
+                // Accept both TradeDecision (92 bytes) and Advisory (64+ bytes) formats
+                if len < MIN_MESSAGE_SIZE {
+                    warn!("Received undersized message: {} bytes (need >= {})", len, MIN_MESSAGE_SIZE);
                     return None;
                 }
                 
-                // If 52 bytes, it's likely a TradeDecision - skip Advisory parsing
-                if len == 52 {
-                    debug!("Received 52-byte message (likely TradeDecision), skipping Advisory parse");
+                // If 92 bytes, it's likely a TradeDecision - skip Advisory parsing
+                if len == TradeDecision::SIZE {
+                    debug!("Received {}-byte message (likely TradeDecision), skipping Advisory parse", len);
                     return None;
                 }
                 
@@ -690,8 +731,8 @@ impl AdviceBusListener {
         
         match self.socket.recv_from(&mut buf) {
             Ok((len, _addr)) => {
-                // TradeDecisions are exactly 52 bytes
-                if len == 52 {
+                // TradeDecisions are exactly 92 bytes
+                if len == TradeDecision::SIZE {
                     // DEBUG: Log raw bytes received BEFORE parsing
                     let mint_hex = format!("{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}", 
                                           buf[2], buf[3], buf[4], buf[5], buf[6], buf[7], buf[8], buf[9]);
@@ -703,11 +744,12 @@ impl AdviceBusListener {
                             let mint_hex_parsed = format!("{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}", 
                                                          decision.mint[0], decision.mint[1], decision.mint[2], decision.mint[3],
                                                          decision.mint[4], decision.mint[5], decision.mint[6], decision.mint[7]);
-                            info!("📥 RECEIVED TradeDecision: {} {} lamports (conf={}) | mint={}",
+                            info!("📥 RECEIVED TradeDecision: {} {} lamports (conf={}) | mint={} | trace={}",
                                   if decision.is_buy() { "BUY" } else { "SELL" },
                                   decision.size_lamports,
                                   decision.confidence,
-                                  mint_hex_parsed);
+                                  mint_hex_parsed,
+                                  decision.trace_tag());
                             Some(decision)
                         }
                         Err(e) => {
@@ -774,4 +816,29 @@ mod tests {
             panic!("Wrong advisory type decoded");
         }
     }
+    
+    #[test]
+    fn test_trade_decision_parses_min_out_and_decision_id() {
+        let mut buf = [0u8; TradeDecision::SIZE];
+        buf[0] = TradeDecision::MSG_TYPE;
+        buf[1] = 1; // protocol_version
+        buf[2..34].copy_from_slice(&[7u8; 32]);
+        buf[34] = 1; // SELL
+        buf[35..43].copy_from_slice(&250_000_000u64.to_le_bytes());
+        buf[49..57].copy_from_slice(&240_000_000u64.to_le_bytes());
+        buf[57..73].copy_from_slice(&[9u8; 16]);
+        buf[76..92].copy_from_slice(&[0xabu8; 16]);
+        
+        let decision = TradeDecision::from_bytes(&buf).unwrap();
+        assert!(decision.is_sell());
+        assert_eq!(decision.size_lamports, 250_000_000);
+        assert_eq!(decision.min_sol_out_lamports, 240_000_000);
+        assert_eq!(decision.decision_id, [9u8; 16]);
+        assert!(decision.decision_id_str().is_some());
+        assert_eq!(decision.trace_id, [0xabu8; 16]);
+        assert_eq!(decision.trace_tag(), "abababab");
+        
+        buf[57..73].copy_from_slice(&[0u8; 16]);
+        assert!(TradeDecision::from_bytes(&buf).unwrap().decision_id_str().is_none());
+    }
 }
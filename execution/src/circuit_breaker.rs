@@ -0,0 +1,135 @@
+//! 🔌 Execution Circuit Breaker
+//!
+//! Execution-side health breaker: after K consecutive send failures within a
+//! window (e.g. RPC/TPU provider outage), stop attempting BUYs for a cooldown
+//! instead of failing every decision. Brain is told via a "circuit open"
+//! ExecutionConfirmation so it pauses decision-making for the same cooldown.
+//!
+//! SELLs are never gated (exits must still be attempted), but their failures
+//! count toward tripping the breaker.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+pub struct CircuitBreaker {
+    failure_threshold: usize,  // K consecutive failures (0 = disabled)
+    window: Duration,          // Failures older than this don't count
+    cooldown: Duration,        // How long the circuit stays open
+    failures: VecDeque<Instant>,
+    open_until: Option<Instant>,
+}
+
+impl CircuitBreaker {
+    pub fn new(failure_threshold: usize, window: Duration, cooldown: Duration) -> Self {
+        Self {
+            failure_threshold,
+            window,
+            cooldown,
+            failures: VecDeque::new(),
+            open_until: None,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.failure_threshold > 0
+    }
+
+    /// Remaining cooldown if the circuit is open, None if sends are allowed
+    ///
+    /// Once the cooldown has passed the circuit closes again and the next send
+    /// is attempted normally.
+    pub fn open_remaining(&mut self, now: Instant) -> Option<Duration> {
+        match self.open_until {
+            Some(until) if now < until => Some(until - now),
+            Some(_) => {
+                self.open_until = None;
+                self.failures.clear();
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// A send succeeded: the failure streak is broken
+    pub fn record_success(&mut self) {
+        self.failures.clear();
+    }
+
+    /// A send failed; returns true if this failure tripped the circuit open
+    pub fn record_failure(&mut self, now: Instant) -> bool {
+        if !self.is_enabled() || self.open_until.is_some_and(|until| now < until) {
+            return false;
+        }
+
+        self.failures.push_back(now);
+        while self
+            .failures
+            .front()
+            .is_some_and(|&t| now.duration_since(t) > self.window)
+        {
+            self.failures.pop_front();
+        }
+
+        if self.failures.len() >= self.failure_threshold {
+            self.open_until = Some(now + self.cooldown);
+            self.failures.clear();
+            return true;
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn breaker() -> CircuitBreaker {
+        CircuitBreaker::new(3, Duration::from_secs(60), Duration::from_secs(120))
+    }
+
+    #[test]
+    fn test_trips_after_consecutive_failures_and_recovers() {
+        let mut cb = breaker();
+        let t0 = Instant::now();
+
+        assert!(!cb.record_failure(t0));
+        assert!(!cb.record_failure(t0 + Duration::from_secs(1)));
+        assert_eq!(cb.open_remaining(t0 + Duration::from_secs(1)), None);
+        assert!(cb.record_failure(t0 + Duration::from_secs(2)));
+
+        let remaining = cb.open_remaining(t0 + Duration::from_secs(12)).unwrap();
+        assert_eq!(remaining, Duration::from_secs(110));
+
+        // Cooldown over: circuit closes and a fresh streak is needed to re-trip
+        assert_eq!(cb.open_remaining(t0 + Duration::from_secs(122)), None);
+        assert!(!cb.record_failure(t0 + Duration::from_secs(123)));
+    }
+
+    #[test]
+    fn test_success_and_window_reset_streak() {
+        let mut cb = breaker();
+        let t0 = Instant::now();
+
+        cb.record_failure(t0);
+        cb.record_failure(t0);
+        cb.record_success();
+        assert!(!cb.record_failure(t0));
+
+        // Failures outside the window don't count toward the streak
+        let mut cb = breaker();
+        cb.record_failure(t0);
+        cb.record_failure(t0);
+        assert!(!cb.record_failure(t0 + Duration::from_secs(61)));
+        assert_eq!(cb.open_remaining(t0 + Duration::from_secs(61)), None);
+    }
+
+    #[test]
+    fn test_disabled_never_trips() {
+        let mut cb = CircuitBreaker::new(0, Duration::from_secs(60), Duration::from_secs(120));
+        let t0 = Instant::now();
+        for _ in 0..10 {
+            assert!(!cb.record_failure(t0));
+        }
+        assert_eq!(cb.open_remaining(t0), None);
+    }
+}
@@ -2,6 +2,38 @@
 // All strategy parameters moved to Brain service
 
 use std::env;
+use std::str::FromStr;
+
+/// How BUY transactions are submitted (EXECUTION_ROUTE=rpc|tpu|jito)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExecutionRoute {
+    Rpc,   // Plain sendTransaction
+    Tpu,   // Direct validator submission
+    Jito,  // Jito bundle with tip, falls back to TPU on rejection
+}
+
+impl ExecutionRoute {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExecutionRoute::Rpc => "RPC",
+            ExecutionRoute::Tpu => "TPU",
+            ExecutionRoute::Jito => "JITO",
+        }
+    }
+}
+
+impl FromStr for ExecutionRoute {
+    type Err = String;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "rpc" => Ok(ExecutionRoute::Rpc),
+            "tpu" => Ok(ExecutionRoute::Tpu),
+            "jito" => Ok(ExecutionRoute::Jito),
+            other => Err(format!("Invalid EXECUTION_ROUTE '{}' (expected rpc, tpu or jito)", other)),
+        }
+    }
+}
 
 #[derive(Clone)]
 pub struct Config {
@@ -23,6 +55,8 @@ pub struct Config {
     pub use_tpu: bool,
     pub use_jito: bool,
     pub use_jito_race: bool,  // NEW: Race TPU vs Jito, take first confirmation
+    pub execution_route: ExecutionRoute,  // BUY route (defaults from USE_TPU/USE_JITO)
+    pub dry_run: bool,        // EXECUTOR_DRY_RUN: simulate transactions, never submit
     
     // ============================================================================
     // JITO CONFIGURATION
@@ -34,6 +68,17 @@ pub struct Config {
     pub jito_entry_percentile: f64,
     pub jito_exit_percentile: f64,
     
+    // ============================================================================
+    // PUMP.FUN FEE RECIPIENTS
+    // ============================================================================
+    pub pump_fee_recipients: Vec<String>,  // Rotated per tx; empty = canonical recipient only
+    
+    // ============================================================================
+    // PRIORITY FEES (compute-unit price from recent network fees)
+    // ============================================================================
+    pub priority_fee_percentile: f64,          // Percentile of recent fees to bid at (e.g. 75.0)
+    pub max_priority_fee_microlamports: u64,   // Ceiling for BUY compute-unit price
+    
     // ============================================================================
     // ADVICE BUS (receives TradeDecisions from Brain)
     // ============================================================================
@@ -46,6 +91,19 @@ pub struct Config {
     pub advice_min_confidence: u8,
     pub advice_max_hold_extension_secs: u64,
     pub advice_max_exit_slippage_bps: u16,
+    pub sol_price_max_age_secs: u64,  // SolPriceUpdate broadcasts older than this are ignored / stale
+    
+    // ============================================================================
+    // STRATEGY OVERRIDES (backtested strategies tune BUY slippage/size)
+    // ============================================================================
+    pub strategy_overrides_enabled: bool,
+    pub strategy_db_path: String,
+    pub strategy_reload_secs: u64,
+    pub strategy_min_confidence: f64,
+    pub strategy_target_landing_rate: f64,       // Below this, BUY size shrinks proportionally
+    pub strategy_min_size_multiplier: f64,       // Floor for the strategy size multiplier
+    pub strategy_off_peak_hours_utc: Option<(u8, u8)>,  // "start-end" UTC hours, e.g. "22-6"
+    pub strategy_off_peak_size_multiplier: f64,
     
     // ============================================================================
     // BRAIN TELEMETRY (send execution results back to Brain)
@@ -53,6 +111,21 @@ pub struct Config {
     pub brain_telemetry_enabled: bool,
     pub brain_telemetry_host: String,
     pub brain_telemetry_port: u16,
+    pub landing_window_secs: u64,    // BUY counts as dropped if not confirmed within this
+    pub landing_rollup_secs: u64,    // Per-route / CU-price landing rollup interval
+    
+    // ============================================================================
+    // CIRCUIT BREAKER (pause BUYs on repeated execution failures)
+    // ============================================================================
+    pub circuit_breaker_failures: usize,      // Consecutive failures to trip (0 = disabled)
+    pub circuit_breaker_window_secs: u64,     // Failures must fall within this window
+    pub circuit_breaker_cooldown_secs: u64,   // BUYs skipped (and Brain paused) this long
+    
+    // ============================================================================
+    // METRICS & HEALTH (/metrics, /health, /ready)
+    // ============================================================================
+    pub metrics_port: u16,
+    pub health_max_listener_age_secs: u64,    // /health 503 if the Advice Bus loop stalls this long
     
     // ============================================================================
     // DATABASE (log executed trades & realized PnL)
@@ -71,6 +144,7 @@ pub struct Config {
     pub retry_on_fail: bool,
     pub max_retries: u32,
     pub price_check_interval: u64,
+    pub max_slippage_bps: u16,        // Cap on inbound TradeDecision slippage (last line of defense)
     
     // ============================================================================
     // CONFIRMATION TRACKING (3-state confirmation system)
@@ -80,6 +154,118 @@ pub struct Config {
 }
 
 impl Config {
+    /// Strategy loader settings (validated when the reloader starts)
+    pub fn strategy_config(&self) -> crate::data::strategy_loader::StrategyConfig {
+        crate::data::strategy_loader::StrategyConfig {
+            path: self.strategy_db_path.clone(),
+            reload_secs: self.strategy_reload_secs,
+            min_confidence: self.strategy_min_confidence,
+            target_landing_rate: self.strategy_target_landing_rate,
+            min_size_multiplier: self.strategy_min_size_multiplier,
+            off_peak_hours_utc: self.strategy_off_peak_hours_utc,
+            off_peak_size_multiplier: self.strategy_off_peak_size_multiplier,
+        }
+    }
+
+    /// Check values that parse but can't work, reporting every problem at once
+    pub fn validate(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let mut problems: Vec<String> = Vec::new();
+        
+        // Wallet & program IDs
+        let key = self.wallet_private_key.trim();
+        let key_ok = key.starts_with('[')
+            || bs58::decode(key).into_vec().map(|bytes| bytes.len() == 64).unwrap_or(false);
+        if !key_ok {
+            problems.push("WALLET_PRIVATE_KEY must be a base58 or JSON-array 64-byte keypair".to_string());
+        }
+        if solana_sdk::pubkey::Pubkey::from_str(&self.jito_tip_account).is_err() {
+            problems.push(format!("JITO_TIP_ACCOUNT '{}' is not a valid pubkey", self.jito_tip_account));
+        }
+        for recipient in &self.pump_fee_recipients {
+            if !crate::pump_instructions::is_known_fee_recipient(recipient) {
+                problems.push(format!("PUMP_FEE_RECIPIENTS entry '{}' is not a known pump.fun fee recipient", recipient));
+            }
+        }
+        
+        // Endpoints & ports
+        if self.rpc_endpoint.trim().is_empty() {
+            problems.push("RPC_ENDPOINT must be set".to_string());
+        }
+        if self.advice_bus_port == 0 {
+            problems.push("ADVICE_BUS_PORT must be > 0".to_string());
+        }
+        if self.brain_telemetry_enabled && self.brain_telemetry_port == 0 {
+            problems.push("BRAIN_TELEMETRY_PORT must be > 0".to_string());
+        }
+        if self.db_port == 0 {
+            problems.push("DB_PORT must be > 0".to_string());
+        }
+        if self.metrics_port == 0 || self.metrics_port == self.advice_bus_port {
+            problems.push(format!("METRICS_PORT ({}) must be > 0 and differ from ADVICE_BUS_PORT", self.metrics_port));
+        }
+        
+        // Fee percentiles & slippage
+        for (key, value) in [
+            ("JITO_ENTRY_PERCENTILE", self.jito_entry_percentile),
+            ("JITO_EXIT_PERCENTILE", self.jito_exit_percentile),
+            ("PRIORITY_FEE_PERCENTILE", self.priority_fee_percentile),
+        ] {
+            if !(value > 0.0 && value <= 100.0) {
+                problems.push(format!("{} must be in (0, 100], got {}", key, value));
+            }
+        }
+        if self.max_slippage_bps == 0 || self.max_slippage_bps > 10_000 {
+            problems.push("MAX_SLIPPAGE_BPS must be in 1..=10000".to_string());
+        }
+        if self.advice_max_exit_slippage_bps > 10_000 {
+            problems.push("ADVICE_MAX_EXIT_SLIPPAGE_BPS must be ≤ 10000".to_string());
+        }
+        if self.advice_min_confidence > 100 {
+            problems.push("ADVICE_MIN_CONFIDENCE must be ≤ 100".to_string());
+        }
+        
+        // Strategy overrides
+        if !(self.strategy_min_size_multiplier > 0.0 && self.strategy_min_size_multiplier <= 1.0) {
+            problems.push("STRATEGY_MIN_SIZE_MULTIPLIER must be in (0, 1]".to_string());
+        }
+        if !(self.strategy_off_peak_size_multiplier > 0.0 && self.strategy_off_peak_size_multiplier <= 1.0) {
+            problems.push("STRATEGY_OFF_PEAK_SIZE_MULTIPLIER must be in (0, 1]".to_string());
+        }
+        if let Some((start, end)) = self.strategy_off_peak_hours_utc {
+            if start > 23 || end > 23 {
+                problems.push("STRATEGY_OFF_PEAK_HOURS_UTC hours must be 0-23".to_string());
+            }
+        }
+        
+        // Intervals & timeouts
+        if self.landing_window_secs == 0 || self.landing_rollup_secs == 0 {
+            problems.push("LANDING_WINDOW_SECS and LANDING_ROLLUP_SECS must be > 0".to_string());
+        }
+        if self.network_timeout_ms == 0 {
+            problems.push("NETWORK_TIMEOUT_MS must be > 0".to_string());
+        }
+        let intervals = &self.confirmation_poll_intervals_ms;
+        if intervals.is_empty() || intervals.contains(&0) {
+            problems.push("CONFIRMATION_POLL_INTERVALS_MS must list positive intervals".to_string());
+        } else if !intervals.windows(2).all(|pair| pair[0] <= pair[1]) {
+            problems.push(format!("CONFIRMATION_POLL_INTERVALS_MS {:?} must be sorted ascending", intervals));
+        }
+        if self.max_confirmation_wait_ms == 0 {
+            problems.push("MAX_CONFIRMATION_WAIT_MS must be > 0".to_string());
+        }
+        if self.sol_price_max_age_secs == 0 {
+            problems.push("SOL_PRICE_MAX_AGE_SECS must be > 0".to_string());
+        }
+        if self.health_max_listener_age_secs == 0 {
+            problems.push("HEALTH_MAX_LISTENER_AGE_SECS must be > 0".to_string());
+        }
+        
+        if !problems.is_empty() {
+            return Err(format!("Invalid configuration ({} problems):\n  - {}", problems.len(), problems.join("\n  - ")).into());
+        }
+        Ok(())
+    }
+
     pub fn from_env() -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
         // Check required vars first
         let _wallet_key = env::var("WALLET_PRIVATE_KEY")
@@ -95,6 +281,30 @@ impl Config {
         let _db_password = env::var("DB_PASSWORD")
             .map_err(|_| "Missing DB_PASSWORD in .env")?;
         
+        // Execution route: explicit EXECUTION_ROUTE wins, otherwise derive from legacy flags
+        let use_tpu: bool = env::var("USE_TPU")
+            .unwrap_or_else(|_| "true".to_string())
+            .parse()?;
+        let use_jito: bool = env::var("USE_JITO")
+            .unwrap_or_else(|_| "false".to_string())
+            .parse()?;
+        let execution_route = match env::var("EXECUTION_ROUTE") {
+            Ok(route) => route.parse::<ExecutionRoute>()?,
+            Err(_) if use_tpu => ExecutionRoute::Tpu,
+            Err(_) if use_jito => ExecutionRoute::Jito,
+            Err(_) => ExecutionRoute::Rpc,
+        };
+        
+        // Off-peak window "start-end" in UTC hours (empty = none)
+        let strategy_off_peak_hours_utc = match env::var("STRATEGY_OFF_PEAK_HOURS_UTC") {
+            Ok(range) if !range.trim().is_empty() => {
+                let (start, end) = range.split_once('-')
+                    .ok_or_else(|| format!("Invalid STRATEGY_OFF_PEAK_HOURS_UTC '{}' (expected start-end)", range))?;
+                Some((start.trim().parse::<u8>()?, end.trim().parse::<u8>()?))
+            }
+            _ => None,
+        };
+        
         Ok(Config {
             // GRPC & RPC
             grpc_endpoint: env::var("GRPC_ENDPOINT")
@@ -108,13 +318,13 @@ impl Config {
             wallet_private_key: env::var("WALLET_PRIVATE_KEY")?,
             
             // Execution Mode
-            use_tpu: env::var("USE_TPU")
-                .unwrap_or_else(|_| "true".to_string())
-                .parse()?,
-            use_jito: env::var("USE_JITO")
+            use_tpu,
+            use_jito,
+            use_jito_race: env::var("USE_JITO_RACE")
                 .unwrap_or_else(|_| "false".to_string())
                 .parse()?,
-            use_jito_race: env::var("USE_JITO_RACE")
+            execution_route,
+            dry_run: env::var("EXECUTOR_DRY_RUN")
                 .unwrap_or_else(|_| "false".to_string())
                 .parse()?,
             
@@ -136,6 +346,22 @@ impl Config {
                 .unwrap_or_else(|_| "50.0".to_string())
                 .parse()?,
             
+            // Pump.fun fee recipients
+            pump_fee_recipients: env::var("PUMP_FEE_RECIPIENTS")
+                .unwrap_or_default()
+                .split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect(),
+            
+            // Priority Fees
+            priority_fee_percentile: env::var("PRIORITY_FEE_PERCENTILE")
+                .unwrap_or_else(|_| "75.0".to_string())
+                .parse()?,
+            max_priority_fee_microlamports: env::var("MAX_PRIORITY_FEE_MICROLAMPORTS")
+                .unwrap_or_else(|_| "50000".to_string())
+                .parse()?,
+            
             // Advice Bus
             advice_bus_port: env::var("ADVICE_BUS_PORT")
                 .unwrap_or_else(|_| "45110".to_string())
@@ -158,6 +384,32 @@ impl Config {
             advice_max_exit_slippage_bps: env::var("ADVICE_MAX_EXIT_SLIPPAGE_BPS")
                 .unwrap_or_else(|_| "500".to_string())
                 .parse()?,
+            sol_price_max_age_secs: env::var("SOL_PRICE_MAX_AGE_SECS")
+                .unwrap_or_else(|_| "30".to_string())
+                .parse()?,
+            
+            // Strategy Overrides
+            strategy_overrides_enabled: env::var("STRATEGY_OVERRIDES_ENABLED")
+                .unwrap_or_else(|_| "false".to_string())
+                .parse()?,
+            strategy_db_path: env::var("STRATEGY_DB_PATH")
+                .unwrap_or_else(|_| "data/strategies.db".to_string()),
+            strategy_reload_secs: env::var("STRATEGY_RELOAD_SECS")
+                .unwrap_or_else(|_| "600".to_string())
+                .parse()?,
+            strategy_min_confidence: env::var("STRATEGY_MIN_CONFIDENCE")
+                .unwrap_or_else(|_| "0.45".to_string())
+                .parse()?,
+            strategy_target_landing_rate: env::var("STRATEGY_TARGET_LANDING_RATE")
+                .unwrap_or_else(|_| "0.7".to_string())
+                .parse()?,
+            strategy_min_size_multiplier: env::var("STRATEGY_MIN_SIZE_MULTIPLIER")
+                .unwrap_or_else(|_| "0.25".to_string())
+                .parse()?,
+            strategy_off_peak_hours_utc,
+            strategy_off_peak_size_multiplier: env::var("STRATEGY_OFF_PEAK_SIZE_MULTIPLIER")
+                .unwrap_or_else(|_| "0.5".to_string())
+                .parse()?,
             
             // Brain Telemetry
             brain_telemetry_enabled: env::var("BRAIN_TELEMETRY_ENABLED")
@@ -168,6 +420,31 @@ impl Config {
             brain_telemetry_port: env::var("BRAIN_TELEMETRY_PORT")
                 .unwrap_or_else(|_| "45110".to_string())
                 .parse()?,
+            landing_window_secs: env::var("LANDING_WINDOW_SECS")
+                .unwrap_or_else(|_| "10".to_string())
+                .parse()?,
+            landing_rollup_secs: env::var("LANDING_ROLLUP_SECS")
+                .unwrap_or_else(|_| "60".to_string())
+                .parse()?,
+            
+            // Circuit Breaker
+            circuit_breaker_failures: env::var("CIRCUIT_BREAKER_FAILURES")
+                .unwrap_or_else(|_| "5".to_string())
+                .parse()?,
+            circuit_breaker_window_secs: env::var("CIRCUIT_BREAKER_WINDOW_SECS")
+                .unwrap_or_else(|_| "60".to_string())
+                .parse()?,
+            circuit_breaker_cooldown_secs: env::var("CIRCUIT_BREAKER_COOLDOWN_SECS")
+                .unwrap_or_else(|_| "120".to_string())
+                .parse()?,
+            
+            // Metrics & Health
+            metrics_port: env::var("METRICS_PORT")
+                .unwrap_or_else(|_| "9092".to_string())
+                .parse()?,
+            health_max_listener_age_secs: env::var("HEALTH_MAX_LISTENER_AGE_SECS")
+                .unwrap_or_else(|_| "5".to_string())
+                .parse()?,
             
             // Database
             db_host: env::var("DB_HOST")?,
@@ -192,6 +469,9 @@ impl Config {
             price_check_interval: env::var("PRICE_CHECK_INTERVAL")
                 .unwrap_or_else(|_| "200".to_string())
                 .parse()?,
+            max_slippage_bps: env::var("MAX_SLIPPAGE_BPS")
+                .unwrap_or_else(|_| "2000".to_string())
+                .parse()?,
             
             // Confirmation Tracking
             confirmation_poll_intervals_ms: env::var("CONFIRMATION_POLL_INTERVALS_MS")
@@ -1,5 +1,8 @@
 use anyhow::{Result, bail};
 use rusqlite::Connection;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashSet, VecDeque};
+use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use std::time::Duration;
@@ -10,6 +13,44 @@ pub struct StrategyConfig {
     pub path: String,
     pub reload_secs: u64,
     pub min_confidence: f64,
+    /// Landing rate at/above which BUY size is not reduced
+    pub target_landing_rate: f64,
+    /// Floor for the combined size multiplier
+    pub min_size_multiplier: f64,
+    /// UTC hour range [start, end) treated as off-peak (wraps past midnight)
+    pub off_peak_hours_utc: Option<(u8, u8)>,
+    /// Size multiplier applied during off-peak hours
+    pub off_peak_size_multiplier: f64,
+}
+
+impl StrategyConfig {
+    /// Validate loader settings (a zero reload interval would spin the reloader)
+    pub fn validate(&self) -> Result<()> {
+        if self.path.trim().is_empty() {
+            bail!("strategy path must not be empty");
+        }
+        if self.reload_secs == 0 {
+            bail!("strategy reload_secs must be > 0");
+        }
+        if !(0.0..=1.0).contains(&self.min_confidence) {
+            bail!("strategy min_confidence must be within [0, 1], got {}", self.min_confidence);
+        }
+        if !(0.0..=1.0).contains(&self.target_landing_rate) {
+            bail!("target_landing_rate must be within [0, 1], got {}", self.target_landing_rate);
+        }
+        if !(self.min_size_multiplier > 0.0 && self.min_size_multiplier <= 1.0) {
+            bail!("min_size_multiplier must be within (0, 1], got {}", self.min_size_multiplier);
+        }
+        if !(self.off_peak_size_multiplier > 0.0 && self.off_peak_size_multiplier <= 1.0) {
+            bail!("off_peak_size_multiplier must be within (0, 1], got {}", self.off_peak_size_multiplier);
+        }
+        if let Some((start, end)) = self.off_peak_hours_utc {
+            if start > 23 || end > 24 || start == end {
+                bail!("off_peak_hours_utc must be a non-empty range of hours 0-24, got {}-{}", start, end);
+            }
+        }
+        Ok(())
+    }
 }
 
 impl Default for StrategyConfig {
@@ -18,6 +59,10 @@ impl Default for StrategyConfig {
             path: "data/strategies.db".to_string(),
             reload_secs: 600, // 10 minutes
             min_confidence: 0.45,
+            target_landing_rate: 0.7,
+            min_size_multiplier: 0.25,
+            off_peak_hours_utc: None,
+            off_peak_size_multiplier: 0.5,
         }
     }
 }
@@ -51,6 +96,31 @@ pub struct ParsedRules {
     pub max_hold_sec: Option<u64>,
 }
 
+impl ParsedRules {
+    /// Check parsed thresholds are usable by `pick_strategy`
+    pub fn validate(&self) -> Result<()> {
+        if let Some(vol) = self.min_volume_sol {
+            if !vol.is_finite() || vol <= 0.0 {
+                bail!("min_volume_sol must be a positive number, got {}", vol);
+            }
+        }
+        if self.min_unique_buyers == Some(0) {
+            bail!("min_unique_buyers must be > 0");
+        }
+        if let Some(target) = self.profit_target_usd {
+            if !target.is_finite() || target < 0.0 {
+                bail!("profit_target_usd must be a non-negative number, got {}", target);
+            }
+        }
+        if let Some(hold) = self.max_hold_sec {
+            if hold == 0 || hold > 3600 {
+                bail!("max_hold_sec must be within 1-3600s, got {}", hold);
+            }
+        }
+        Ok(())
+    }
+}
+
 impl LiveStrategy {
     /// Parse entry and exit rules into structured data
     pub fn parse_rules(&self) -> ParsedRules {
@@ -94,6 +164,40 @@ impl LiveStrategy {
         rules
     }
     
+    /// Schema check applied before a reloaded strategy can go live
+    ///
+    /// Stricter than `is_valid`: also rejects missing ids and rule text, non-finite
+    /// backtest stats, unparseable rule thresholds, and pattern strategies that
+    /// `pick_strategy` could never match.
+    pub fn validate(&self) -> Result<()> {
+        if self.id.trim().is_empty() {
+            bail!("strategy id must not be empty");
+        }
+        if self.strategy_type.trim().is_empty() {
+            bail!("strategy {} has no type", self.id);
+        }
+        if self.entry_rule.trim().is_empty() || self.exit_rule.trim().is_empty() {
+            bail!("strategy {} has an empty entry or exit rule", self.id);
+        }
+        let stats = [self.win_rate, self.avg_profit_usd, self.profit_factor, self.score];
+        if stats.iter().any(|v| !v.is_finite()) {
+            bail!("strategy {} has non-finite backtest stats", self.id);
+        }
+        if !self.is_valid() {
+            bail!("strategy {} parameters out of safe bounds", self.id);
+        }
+
+        let rules = self.parse_rules();
+        rules.validate().map_err(|e| anyhow::anyhow!("strategy {}: {}", self.id, e))?;
+        if self.strategy_type == "pattern_based"
+            && rules.min_volume_sol.is_none()
+            && rules.min_unique_buyers.is_none()
+        {
+            bail!("pattern_based strategy {} has no volume or buyer threshold", self.id);
+        }
+        Ok(())
+    }
+
     /// Validate strategy parameters are within safe bounds
     pub fn is_valid(&self) -> bool {
         self.slippage_percent >= 0.5 && self.slippage_percent <= 50.0 &&
@@ -169,38 +273,130 @@ pub fn load_live_strategies(db_path: &str, min_conf: f64) -> Result<Vec<LiveStra
     Ok(valid_strategies)
 }
 
+/// Validate a full strategy set; any bad entry rejects the whole set
+pub fn validate_strategies(strategies: &[LiveStrategy]) -> Result<()> {
+    if strategies.is_empty() {
+        bail!("strategy set is empty");
+    }
+    let mut ids = HashSet::new();
+    for strategy in strategies {
+        strategy.validate()?;
+        if !ids.insert(strategy.id.as_str()) {
+            bail!("duplicate strategy id {}", strategy.id);
+        }
+    }
+    Ok(())
+}
+
+/// Content hash of a strategy set (hex), logged to identify the live set
+pub fn strategies_hash(strategies: &[LiveStrategy]) -> String {
+    let mut hasher = DefaultHasher::new();
+    for s in strategies {
+        s.id.hash(&mut hasher);
+        s.strategy_type.hash(&mut hasher);
+        s.entry_rule.hash(&mut hasher);
+        s.exit_rule.hash(&mut hasher);
+        for v in [
+            s.profit_target_usd, s.holding_time_sec, s.latency_ms_used, s.slippage_percent,
+            s.win_rate, s.avg_profit_usd, s.profit_factor, s.execution_confidence, s.score,
+        ] {
+            v.to_bits().hash(&mut hasher);
+        }
+        s.rank.hash(&mut hasher);
+        s.enabled.hash(&mut hasher);
+    }
+    format!("{:016x}", hasher.finish())
+}
+
+/// Live strategy set with its version (bumped per accepted reload) and content hash
+#[derive(Debug, Clone)]
+pub struct ActiveStrategies {
+    pub version: u64,
+    pub hash: String,
+    pub strategies: Vec<LiveStrategy>,
+}
+
+impl ActiveStrategies {
+    /// Validate and wrap the initial strategy set as version 1
+    pub fn new(strategies: Vec<LiveStrategy>) -> Result<Self> {
+        validate_strategies(&strategies)?;
+        Ok(Self {
+            version: 1,
+            hash: strategies_hash(&strategies),
+            strategies,
+        })
+    }
+
+    /// Swap in a reloaded set only if it validates; otherwise keep the current one
+    ///
+    /// Returns false (without bumping the version) if the content is unchanged.
+    pub fn try_replace(&mut self, strategies: Vec<LiveStrategy>) -> Result<bool> {
+        validate_strategies(&strategies)?;
+        let hash = strategies_hash(&strategies);
+        if hash == self.hash {
+            return Ok(false);
+        }
+        *self = Self {
+            version: self.version + 1,
+            hash,
+            strategies,
+        };
+        Ok(true)
+    }
+}
+
 /// Thread-safe strategy store
-pub type StrategyStore = Arc<RwLock<Vec<LiveStrategy>>>;
+pub type StrategyStore = Arc<RwLock<ActiveStrategies>>;
 
-/// Initialize strategy store with initial strategies
-pub fn strategy_store_init(initial: Vec<LiveStrategy>) -> StrategyStore {
-    Arc::new(RwLock::new(initial))
+/// Initialize strategy store with initial strategies (rejected if they fail validation)
+pub fn strategy_store_init(initial: Vec<LiveStrategy>) -> Result<StrategyStore> {
+    let active = ActiveStrategies::new(initial)?;
+    log::info!("📚 Strategies loaded: v{} hash={} ({} strategies)",
+        active.version, active.hash, active.strategies.len());
+    Ok(Arc::new(RwLock::new(active)))
 }
 
 /// Background task to periodically reload strategies
+///
+/// Reloads are validated before going live; a bad file or DB keeps the
+/// last-good set in place.
 pub async fn strategy_reloader(store: StrategyStore, cfg: StrategyConfig) {
+    if let Err(e) = cfg.validate() {
+        log::error!("❌ Strategy reloader disabled: {}", e);
+        return;
+    }
+
     loop {
         tokio::time::sleep(Duration::from_secs(cfg.reload_secs)).await;
         
-        match load_live_strategies(&cfg.path, cfg.min_confidence) {
-            Ok(new_list) if !new_list.is_empty() => {
-                let old_count = store.read().await.len();
-                *store.write().await = new_list.clone();
-                log::info!("🔄 Strategies reloaded from {} ({} -> {} strategies)", 
-                    cfg.path, old_count, new_list.len());
+        let new_list = match load_live_strategies(&cfg.path, cfg.min_confidence) {
+            Ok(list) => list,
+            Err(e) => {
+                log::error!("Strategy reload failed: {} - keeping previous set", e);
+                continue;
+            }
+        };
+
+        let mut active = store.write().await;
+        let old_count = active.strategies.len();
+        match active.try_replace(new_list) {
+            Ok(true) => {
+                log::info!("🔄 Strategies reloaded from {} ({} -> {} strategies) | v{} hash={}", 
+                    cfg.path, old_count, active.strategies.len(), active.version, active.hash);
                 
                 // Log top 3 strategies
-                for (i, s) in new_list.iter().take(3).enumerate() {
+                for (i, s) in active.strategies.iter().take(3).enumerate() {
                     log::info!("  {}. [{}] {} | entry='{}' | tp=${:.2} | hold={}s | conf={:.1}%",
                         i + 1, s.strategy_type, s.id, s.entry_rule, 
                         s.profit_target_usd, s.holding_time_sec, s.execution_confidence * 100.0);
                 }
             }
-            Ok(_) => {
-                log::warn!("Strategy reload returned 0 rows; keeping previous set");
+            Ok(false) => {
+                log::debug!("Strategies unchanged (v{} hash={})", active.version, active.hash);
             }
             Err(e) => {
-                log::error!("Strategy reload failed: {} - keeping previous set", e);
+                log::error!("❌ Rejected strategy reload from {}: {} - keeping v{} hash={}",
+                    cfg.path, e, active.version, active.hash);
             }
         }
     }
@@ -213,6 +409,92 @@ pub struct LiveContext {
     pub unique_buyers_last_2s: u32,
     pub token_age_seconds: u64,
     pub price_surge_detected: bool,
+    pub hour_utc: u8,
+    /// Share of recent BUYs that executed (None until enough samples)
+    pub landing_rate: Option<f64>,
+}
+
+/// Rolling window of recent BUY outcomes, feeding `LiveContext::landing_rate`
+#[derive(Debug, Clone)]
+pub struct LandingTracker {
+    outcomes: VecDeque<bool>,
+    capacity: usize,
+    min_samples: usize,
+}
+
+impl LandingTracker {
+    pub fn new(capacity: usize, min_samples: usize) -> Self {
+        Self {
+            outcomes: VecDeque::with_capacity(capacity.max(1)),
+            capacity: capacity.max(1),
+            min_samples: min_samples.max(1),
+        }
+    }
+
+    pub fn record(&mut self, landed: bool) {
+        if self.outcomes.len() == self.capacity {
+            self.outcomes.pop_front();
+        }
+        self.outcomes.push_back(landed);
+    }
+
+    /// Landed / attempted over the window, once `min_samples` outcomes are in
+    pub fn rate(&self) -> Option<f64> {
+        if self.outcomes.len() < self.min_samples {
+            return None;
+        }
+        let landed = self.outcomes.iter().filter(|&&l| l).count();
+        Some(landed as f64 / self.outcomes.len() as f64)
+    }
+}
+
+/// Execution overrides from the selected strategy
+///
+/// Precedence: Brain decides whether to BUY and the base size. The strategy may
+/// only scale that size down (multiplier in `[min_size_multiplier, 1.0]`) and
+/// replaces the executor's dynamic slippage with its own `slippage_bps`.
+/// With no strategy selected, Brain's size and the dynamic slippage are used as-is.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExecutionOverride {
+    pub strategy_slippage_bps: u16,
+    pub size_multiplier: f64,
+}
+
+impl ExecutionOverride {
+    /// Scale Brain's BUY size by the strategy multiplier
+    pub fn apply_size(&self, brain_size_lamports: u64) -> u64 {
+        (brain_size_lamports as f64 * self.size_multiplier) as u64
+    }
+}
+
+impl LiveStrategy {
+    /// Slippage and size overrides for this strategy under the current context
+    ///
+    /// Slippage comes from the strategy's backtested `slippage_percent`. Size is
+    /// reduced proportionally when the landing rate is below target and by the
+    /// off-peak multiplier during off-peak hours.
+    pub fn execution_override(&self, ctx: &LiveContext, cfg: &StrategyConfig) -> ExecutionOverride {
+        let landing_factor = match ctx.landing_rate {
+            Some(rate) if cfg.target_landing_rate > 0.0 && rate < cfg.target_landing_rate => {
+                rate / cfg.target_landing_rate
+            }
+            _ => 1.0,
+        };
+
+        let off_peak = cfg.off_peak_hours_utc.is_some_and(|(start, end)| {
+            if start < end {
+                ctx.hour_utc >= start && ctx.hour_utc < end
+            } else {
+                ctx.hour_utc >= start || ctx.hour_utc < end
+            }
+        });
+        let time_factor = if off_peak { cfg.off_peak_size_multiplier } else { 1.0 };
+
+        ExecutionOverride {
+            strategy_slippage_bps: (self.slippage_percent * 100.0).round() as u16,
+            size_multiplier: (landing_factor * time_factor).clamp(cfg.min_size_multiplier, 1.0),
+        }
+    }
 }
 
 /// Select best strategy based on current market context
@@ -318,4 +600,97 @@ mod tests {
         invalid.slippage_percent = 60.0;
         assert!(!invalid.is_valid());
     }
+
+    fn pattern_strategy(id: &str, min_volume: &str) -> LiveStrategy {
+        LiveStrategy {
+            id: id.to_string(),
+            strategy_type: "pattern_based".to_string(),
+            entry_rule: format!("Enter when volume > {} SOL detected", min_volume),
+            exit_rule: "Exit at $2 profit or 120s".to_string(),
+            profit_target_usd: 2.0,
+            holding_time_sec: 120.0,
+            latency_ms_used: 85.0,
+            slippage_percent: 10.0,
+            win_rate: 0.65,
+            avg_profit_usd: 2.2,
+            profit_factor: 15.0,
+            execution_confidence: 0.67,
+            rank: 1,
+            score: 0.82,
+            enabled: true,
+        }
+    }
+
+    #[test]
+    fn test_strategy_schema_validation() {
+        assert!(pattern_strategy("a", "50").validate().is_ok());
+        assert!(pattern_strategy("a", "-5").validate().is_err());
+        assert!(pattern_strategy("a", "lots").validate().is_err());
+        assert!(pattern_strategy("", "50").validate().is_err());
+
+        let mut nan_score = pattern_strategy("a", "50");
+        nan_score.score = f64::NAN;
+        assert!(nan_score.validate().is_err());
+
+        let dupes = vec![pattern_strategy("a", "50"), pattern_strategy("a", "60")];
+        assert!(validate_strategies(&dupes).is_err());
+        assert!(validate_strategies(&[]).is_err());
+
+        let cfg = StrategyConfig { reload_secs: 0, ..StrategyConfig::default() };
+        assert!(cfg.validate().is_err());
+        assert!(StrategyConfig::default().validate().is_ok());
+    }
+
+    #[test]
+    fn test_execution_override_from_context() {
+        let strategy = pattern_strategy("a", "50");
+        let cfg = StrategyConfig {
+            off_peak_hours_utc: Some((22, 6)),
+            ..StrategyConfig::default()
+        };
+
+        // Healthy landing, peak hours: Brain's size untouched, strategy slippage
+        let ctx = LiveContext { hour_utc: 14, landing_rate: Some(0.9), ..LiveContext::default() };
+        let ov = strategy.execution_override(&ctx, &cfg);
+        assert_eq!(ov.strategy_slippage_bps, 1000);
+        assert_eq!(ov.size_multiplier, 1.0);
+        assert_eq!(ov.apply_size(100_000_000), 100_000_000);
+
+        // Landing at half the target halves size; off-peak (wrapping range) halves again
+        let ctx = LiveContext { hour_utc: 3, landing_rate: Some(0.35), ..LiveContext::default() };
+        let ov = strategy.execution_override(&ctx, &cfg);
+        assert!((ov.size_multiplier - 0.25).abs() < 1e-9);
+
+        // Floor applies
+        let ctx = LiveContext { hour_utc: 23, landing_rate: Some(0.0), ..LiveContext::default() };
+        assert_eq!(strategy.execution_override(&ctx, &cfg).size_multiplier, cfg.min_size_multiplier);
+
+        let mut tracker = LandingTracker::new(4, 2);
+        tracker.record(true);
+        assert_eq!(tracker.rate(), None);
+        for landed in [false, true, true, false] {
+            tracker.record(landed);
+        }
+        assert_eq!(tracker.rate(), Some(0.5));
+    }
+
+    #[test]
+    fn test_bad_reload_keeps_previous_strategy() {
+        let mut active = ActiveStrategies::new(vec![pattern_strategy("good", "50")]).unwrap();
+        let good_hash = active.hash.clone();
+
+        // Malformed threshold rejects the whole update
+        let bad = vec![pattern_strategy("new", "50"), pattern_strategy("broken", "NaN")];
+        assert!(active.try_replace(bad).is_err());
+        assert_eq!(active.version, 1);
+        assert_eq!(active.hash, good_hash);
+        assert_eq!(active.strategies[0].id, "good");
+        assert_eq!(pick_strategy(&active.strategies, &LiveContext::default()).unwrap().id, "good");
+
+        // Same content is a no-op; a valid change bumps the version
+        assert!(!active.try_replace(vec![pattern_strategy("good", "50")]).unwrap());
+        assert!(active.try_replace(vec![pattern_strategy("good", "75")]).unwrap());
+        assert_eq!(active.version, 2);
+        assert_ne!(active.hash, good_hash);
+    }
 }
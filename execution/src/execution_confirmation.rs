@@ -5,6 +5,15 @@
 
 use anyhow::{Result, Context};
 
+/// Short trace tag for logs: first 8 hex chars of the trace UUID, "-" when unset
+pub fn trace_tag(trace_id: &[u8; 16]) -> String {
+    if *trace_id == [0u8; 16] {
+        "-".to_string()
+    } else {
+        hex::encode(&trace_id[..4])
+    }
+}
+
 /// ✅ ExecutionConfirmation - Executor → Brain (Port 45115)
 /// 
 /// 128-byte packet confirming a trade was successfully executed.
@@ -39,8 +48,26 @@ pub struct ExecutionConfirmation {
     /// Success flag: 1 = success, 0 = failed
     pub success: u8,
     
+    /// Dry-run flag: 1 = simulated only (EXECUTOR_DRY_RUN), never submitted on-chain
+    pub dry_run: u8,
+    
+    /// Circuit-open flag: 1 = Executor's circuit breaker is open, trade not attempted
+    pub circuit_open: u8,
+    
+    /// Remaining circuit-breaker cooldown in seconds (0 unless circuit_open)
+    pub circuit_cooldown_secs: u16,
+    
     /// Padding to align to 128 bytes
-    pub _padding: [u8; 7],
+    pub _padding: [u8; 3],
+    
+    /// BUY: tokens quoted from the bonding curve before sending (base units, 0 = no quote)
+    pub quoted_tokens: u64,
+    
+    /// BUY: effective entry price for the quote (SOL in / tokens out, SOL per token)
+    pub effective_price_sol: f64,
+    
+    /// Trace ID echoed from the originating `TradeDecision` (all zeros = none)
+    pub trace_id: [u8; 16],
 }
 
 impl ExecutionConfirmation {
@@ -76,10 +103,35 @@ impl ExecutionConfirmation {
             tx_signature,
             timestamp,
             success: 1,
-            _padding: [0; 7],
+            dry_run: 0,
+            circuit_open: 0,
+            circuit_cooldown_secs: 0,
+            _padding: [0; 3],
+            quoted_tokens: 0,
+            effective_price_sol: 0.0,
+            trace_id: [0; 16],
         }
     }
     
+    /// Mark this confirmation as a dry-run (simulated, not submitted)
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run as u8;
+        self
+    }
+    
+    /// Attach the pre-trade bonding curve quote (BUY)
+    pub fn with_quote(mut self, quoted_tokens: u64, effective_price_sol: f64) -> Self {
+        self.quoted_tokens = quoted_tokens;
+        self.effective_price_sol = effective_price_sol;
+        self
+    }
+    
+    /// Echo the trace ID of the `TradeDecision` this confirms
+    pub fn with_trace_id(mut self, trace_id: [u8; 16]) -> Self {
+        self.trace_id = trace_id;
+        self
+    }
+    
     /// Create a new execution confirmation for failed trade
     pub fn new_failure(mint: [u8; 32], side: u8) -> Self {
         let timestamp = std::time::SystemTime::now()
@@ -97,7 +149,23 @@ impl ExecutionConfirmation {
             tx_signature: [0; 32],
             timestamp,
             success: 0,
-            _padding: [0; 7],
+            dry_run: 0,
+            circuit_open: 0,
+            circuit_cooldown_secs: 0,
+            _padding: [0; 3],
+            quoted_tokens: 0,
+            effective_price_sol: 0.0,
+            trace_id: [0; 16],
+        }
+    }
+    
+    /// Create a "circuit open" confirmation: trade not attempted, Brain should
+    /// pause decision-making for `cooldown_secs`
+    pub fn new_circuit_open(mint: [u8; 32], side: u8, cooldown_secs: u16) -> Self {
+        Self {
+            circuit_open: 1,
+            circuit_cooldown_secs: cooldown_secs,
+            ..Self::new_failure(mint, side)
         }
     }
     
@@ -113,6 +181,12 @@ impl ExecutionConfirmation {
         buf[51..83].copy_from_slice(&self.tx_signature);
         buf[83..91].copy_from_slice(&self.timestamp.to_le_bytes());
         buf[91] = self.success;
+        buf[92] = self.dry_run;
+        buf[93] = self.circuit_open;
+        buf[94..96].copy_from_slice(&self.circuit_cooldown_secs.to_le_bytes());
+        buf[96..104].copy_from_slice(&self.quoted_tokens.to_le_bytes());
+        buf[104..112].copy_from_slice(&self.effective_price_sol.to_le_bytes());
+        buf[112..128].copy_from_slice(&self.trace_id);
         buf
     }
 }
@@ -234,7 +308,7 @@ pub struct WatchSignature {
     pub msg_type: u8,              // 25
     pub signature: [u8; 64],       // Transaction signature
     pub mint: [u8; 32],            // Token mint
-    pub trade_id: [u8; 16],        // UUID of trade (first 16 bytes)
+    pub trace_id: [u8; 16],        // Brain's trace UUID (echoed back in TxConfirmed)
     pub side: u8,                  // 0=BUY, 1=SELL
     pub timestamp_ns: u64,         // When registered (nanoseconds)
     pub _padding: [u8; 6],         // Padding to align to 128 bytes
@@ -245,7 +319,7 @@ impl WatchSignature {
     pub const MSG_TYPE: u8 = 25;
     
     /// Create new WatchSignature
-    pub fn new(signature: &str, mint: [u8; 32], trade_id: &str, side: u8) -> Result<Self> {
+    pub fn new(signature: &str, mint: [u8; 32], trace_id: [u8; 16], side: u8) -> Result<Self> {
         // Decode base58 signature to bytes
         let sig_bytes = bs58::decode(signature)
             .into_vec()
@@ -258,16 +332,11 @@ impl WatchSignature {
         let mut sig_array = [0u8; 64];
         sig_array.copy_from_slice(&sig_bytes);
         
-        let mut trade_id_bytes = [0u8; 16];
-        let uuid_bytes = trade_id.as_bytes();
-        let copy_len = uuid_bytes.len().min(16);
-        trade_id_bytes[..copy_len].copy_from_slice(&uuid_bytes[..copy_len]);
-        
         Ok(Self {
             msg_type: Self::MSG_TYPE,
             signature: sig_array,
             mint,
-            trade_id: trade_id_bytes,
+            trace_id,
             side,
             timestamp_ns: std::time::SystemTime::now()
                 .duration_since(std::time::UNIX_EPOCH)
@@ -283,7 +352,7 @@ impl WatchSignature {
         bytes.push(self.msg_type);
         bytes.extend_from_slice(&self.signature);
         bytes.extend_from_slice(&self.mint);
-        bytes.extend_from_slice(&self.trade_id);
+        bytes.extend_from_slice(&self.trace_id);
         bytes.push(self.side);
         bytes.extend_from_slice(&self.timestamp_ns.to_le_bytes());
         bytes.extend_from_slice(&self._padding);
@@ -79,9 +79,15 @@ impl PriorityFeeTracker {
     /// Calculate p95 (95th percentile) of recent priority fees
     /// Returns None if insufficient data (< 10 samples)
     pub fn get_p95(&self) -> Option<u64> {
+        self.get_percentile(95.0)
+    }
+    
+    /// Calculate an arbitrary percentile (0-100) of recent priority fees
+    /// Returns None if insufficient data (< 10 samples)
+    pub fn get_percentile(&self, percentile: f64) -> Option<u64> {
         let fees = self.fees.read().unwrap();
         
-        // Need at least 10 samples for meaningful p95
+        // Need at least 10 samples for a meaningful percentile
         if fees.len() < 10 {
             return None;
         }
@@ -97,14 +103,13 @@ impl PriorityFeeTracker {
             return None;
         }
         
-        // Sort to find p95
+        // Sort to find the percentile
         recent_fees.sort_unstable();
         
-        // Calculate 95th percentile index
-        let p95_idx = (recent_fees.len() as f64 * 0.95).floor() as usize;
-        let p95_idx = p95_idx.min(recent_fees.len() - 1);
+        let idx = (recent_fees.len() as f64 * percentile.clamp(0.0, 100.0) / 100.0).floor() as usize;
+        let idx = idx.min(recent_fees.len() - 1);
         
-        Some(recent_fees[p95_idx])
+        Some(recent_fees[idx])
     }
     
     /// Get current sample count for diagnostics
@@ -796,4 +801,23 @@ impl GrpcClient {
         }
         None
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_priority_fee_percentiles() {
+        let tracker = PriorityFeeTracker::new();
+        assert_eq!(tracker.get_percentile(75.0), None);
+        
+        for fee in 1..=20u64 {
+            tracker.add_fee(fee * 1_000);
+        }
+        
+        assert_eq!(tracker.get_percentile(50.0), Some(11_000));
+        assert_eq!(tracker.get_percentile(75.0), Some(16_000));
+        assert_eq!(tracker.get_p95(), Some(20_000));
+        assert_eq!(tracker.get_percentile(100.0), Some(20_000));
+    }
+}
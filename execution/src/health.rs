@@ -0,0 +1,108 @@
+//! Liveness / readiness probes (served on METRICS_PORT next to /metrics)
+//!
+//! The Advice Bus listener loop ticks a heartbeat every iteration (~50ms):
+//! - /health: 503 once the loop hasn't ticked within HEALTH_MAX_LISTENER_AGE_SECS
+//!   (or never started within that long after boot), so a supervisor restarts
+//!   a hung executor
+//! - /ready: 200 once the listener is bound and looping
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::Serialize;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+static STARTED: once_cell::sync::Lazy<Instant> = once_cell::sync::Lazy::new(Instant::now);
+
+/// Milliseconds after STARTED of the last listener tick (u64::MAX = not running yet)
+static LAST_TICK_MS: AtomicU64 = AtomicU64::new(u64::MAX);
+
+static MAX_LISTENER_AGE_SECS: AtomicU64 = AtomicU64::new(5);
+
+/// Start the uptime clock and set the staleness threshold (called at startup)
+pub fn init(max_listener_age_secs: u64) {
+    once_cell::sync::Lazy::force(&STARTED);
+    MAX_LISTENER_AGE_SECS.store(max_listener_age_secs, Ordering::Relaxed);
+}
+
+/// Heartbeat from the Advice Bus listener loop
+pub fn listener_tick() {
+    LAST_TICK_MS.store(STARTED.elapsed().as_millis() as u64, Ordering::Relaxed);
+}
+
+/// Probe result returned by /health and /ready
+#[derive(Debug, Clone, Serialize)]
+pub struct HealthStatus {
+    pub service: &'static str,
+    pub healthy: bool,
+    pub ready: bool,
+    pub secs_since_listener_tick: Option<f64>,
+    pub max_listener_age_secs: u64,
+}
+
+impl HealthStatus {
+    /// since_tick is None until the listener starts; until then uptime
+    /// counts against the threshold (startup grace period)
+    pub fn evaluate(uptime: Duration, since_tick: Option<Duration>, max_age: Duration) -> Self {
+        Self {
+            service: "executor",
+            healthy: since_tick.unwrap_or(uptime) <= max_age,
+            ready: since_tick.is_some(),
+            secs_since_listener_tick: since_tick.map(|d| d.as_secs_f64()),
+            max_listener_age_secs: max_age.as_secs(),
+        }
+    }
+}
+
+/// Current probe state
+pub fn status() -> HealthStatus {
+    let uptime = STARTED.elapsed();
+    let since_tick = match LAST_TICK_MS.load(Ordering::Relaxed) {
+        u64::MAX => None,
+        at_ms => Some(uptime.saturating_sub(Duration::from_millis(at_ms))),
+    };
+    HealthStatus::evaluate(uptime, since_tick, Duration::from_secs(MAX_LISTENER_AGE_SECS.load(Ordering::Relaxed)))
+}
+
+fn respond(ok: bool, status: HealthStatus) -> Response {
+    let code = if ok { StatusCode::OK } else { StatusCode::SERVICE_UNAVAILABLE };
+    (code, Json(status)).into_response()
+}
+
+/// Liveness: 503 once the Advice Bus listener stalls
+pub async fn health_handler() -> Response {
+    let status = status();
+    respond(status.healthy, status)
+}
+
+/// Readiness: 503 until the Advice Bus listener is running
+pub async fn ready_handler() -> Response {
+    let status = status();
+    respond(status.ready, status)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_health_status_listener_staleness() {
+        let max_age = Duration::from_secs(5);
+
+        let booting = HealthStatus::evaluate(Duration::from_secs(2), None, max_age);
+        assert!(booting.healthy && !booting.ready);
+
+        // Listener never came up (e.g. bind failed)
+        let never_started = HealthStatus::evaluate(Duration::from_secs(6), None, max_age);
+        assert!(!never_started.healthy && !never_started.ready);
+
+        let looping = HealthStatus::evaluate(Duration::from_secs(600), Some(Duration::from_millis(50)), max_age);
+        assert!(looping.healthy && looping.ready);
+
+        let hung = HealthStatus::evaluate(Duration::from_secs(600), Some(Duration::from_secs(30)), max_age);
+        assert!(!hung.healthy && hung.ready);
+    }
+}
@@ -23,12 +23,15 @@ mod pump_instructions;
 mod tpu_client;
 mod emoji;
 mod data;
+mod circuit_breaker;
+mod health;
 
 use std::sync::Arc;
 use std::time::Instant;
 use log::{info, error, warn, debug};
 use tokio::sync::RwLock;
 use execution_confirmation::ExecutionConfirmation;
+use data::strategy_loader::{self, LandingTracker, LiveContext};
 
 // Minimal deduplication tracking (prevents duplicate submissions within 5s)
 struct RecentTrade {
@@ -37,6 +40,13 @@ struct RecentTrade {
     timestamp: std::time::Instant,
 }
 
+// Decision IDs remembered for exact-duplicate rejection
+const MAX_SEEN_DECISIONS: usize = 1024;
+
+// Recent BUY outcomes used for the strategy landing rate
+const LANDING_WINDOW: usize = 50;
+const LANDING_MIN_SAMPLES: usize = 10;
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     // Initialize logger
@@ -54,7 +64,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     dotenv::dotenv().ok();
     
     // Load configuration
-    let config = Arc::new(config::Config::from_env()?);
+    let config = config::Config::from_env()?;
+    config.validate()?;
+    let config = Arc::new(config);
     info!("✅ Configuration: Loaded from .env");
     info!("   Decision-making: Brain Service (UDP:{} → Executor)", config.advice_bus_port);
     info!("   Execution: This service");
@@ -63,6 +75,20 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     info!("   Brain Telemetry: {}:{} (enabled: {})", 
           config.brain_telemetry_host, config.brain_telemetry_port, config.brain_telemetry_enabled);
     
+    // Metrics + health probes (/metrics, /health, /ready)
+    health::init(config.health_max_listener_age_secs);
+    let metrics_port = config.metrics_port;
+    tokio::spawn(async move {
+        if let Err(e) = metrics::start_metrics_server(metrics_port).await {
+            error!("❌ Metrics server failed: {}", e);
+        }
+    });
+    info!("✅ Metrics: http://0.0.0.0:{}/metrics (/health, /ready)", config.metrics_port);
+    
+    if config.dry_run {
+        warn!("🧪 DRY-RUN MODE (EXECUTOR_DRY_RUN=true): transactions are simulated, NOT submitted");
+    }
+    
     // Initialize telemetry sender
     let telemetry = if config.brain_telemetry_enabled {
         match telemetry::TelemetrySender::new(
@@ -84,6 +110,28 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         None
     };
     
+    // Landing telemetry: did submitted BUYs confirm within the window, per route / CU price
+    let landing_telemetry = Arc::new(telemetry::LandingTelemetry::new());
+    if config.landing_rollup_secs > 0 {
+        landing_telemetry.clone().spawn_rollup_logger(config.landing_rollup_secs);
+    }
+    info!("✅ Landing telemetry: {}s window, rollup every {}s",
+          config.landing_window_secs, config.landing_rollup_secs);
+    
+    // Circuit breaker: stop BUYs after repeated execution failures
+    let circuit_breaker = Arc::new(RwLock::new(circuit_breaker::CircuitBreaker::new(
+        config.circuit_breaker_failures,
+        std::time::Duration::from_secs(config.circuit_breaker_window_secs),
+        std::time::Duration::from_secs(config.circuit_breaker_cooldown_secs),
+    )));
+    if config.circuit_breaker_failures > 0 {
+        info!("✅ Circuit breaker: {} failures in {}s → pause BUYs {}s",
+              config.circuit_breaker_failures, config.circuit_breaker_window_secs,
+              config.circuit_breaker_cooldown_secs);
+    } else {
+        info!("ℹ️  Circuit breaker: Disabled");
+    }
+    
     // Initialize database
     let db = Arc::new(database::Database::new(&config).await?);
     info!("✅ Database: Connected ({}:{}/{})", config.db_host, config.db_port, config.db_name);
@@ -94,7 +142,34 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     
     // Recent trades tracker (for deduplication - prevents double submissions within 5s)
     let recent_trades: Arc<RwLock<Vec<RecentTrade>>> = Arc::new(RwLock::new(Vec::new()));
-    info!("✅ Deduplication: Active (5s window, max 100 trades)");
+    // Brain decision UUIDs already handled (drops retransmits regardless of age)
+    let seen_decisions: Arc<RwLock<std::collections::VecDeque<[u8; 16]>>> =
+        Arc::new(RwLock::new(std::collections::VecDeque::new()));
+    info!("✅ Deduplication: Active (5s window, max 100 trades; last {} decision IDs)", MAX_SEEN_DECISIONS);
+    
+    // Strategy overrides: the selected backtested strategy tunes BUY slippage/size
+    // (see STRATEGY_* in .env.example for precedence over Brain's values)
+    let strategy_cfg = config.strategy_config();
+    let strategy_store = if config.strategy_overrides_enabled {
+        let loaded = strategy_cfg.validate()
+            .and_then(|_| strategy_loader::load_live_strategies(&strategy_cfg.path, strategy_cfg.min_confidence))
+            .and_then(strategy_loader::strategy_store_init);
+        match loaded {
+            Ok(store) => {
+                tokio::spawn(strategy_loader::strategy_reloader(store.clone(), strategy_cfg.clone()));
+                info!("✅ Strategy overrides: Active ({}, reload every {}s)", strategy_cfg.path, strategy_cfg.reload_secs);
+                Some(store)
+            }
+            Err(e) => {
+                warn!("⚠️  Strategy overrides disabled: {}", e);
+                None
+            }
+        }
+    } else {
+        info!("ℹ️  Strategy overrides: Disabled (Brain sizes, dynamic slippage)");
+        None
+    };
+    let landing_tracker = Arc::new(RwLock::new(LandingTracker::new(LANDING_WINDOW, LANDING_MIN_SAMPLES)));
     
     // Initialize UDP socket for sending ExecutionConfirmations to Brain
     let confirmation_socket = Arc::new(
@@ -106,12 +181,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     
     // Start Advice Bus listener (receives TradeDecisions from Brain)
     let recent_trades_clone = recent_trades.clone();
+    let seen_decisions_clone = seen_decisions.clone();
     let trading_clone = trading.clone();
     let db_clone = db.clone();
     let config_clone = config.clone();
     let telemetry_clone = telemetry.clone();
     let confirmation_socket_clone = confirmation_socket.clone();
     let brain_addr_clone = brain_confirmation_addr.to_string();
+    let strategy_store_clone = strategy_store.clone();
+    let strategy_cfg_clone = strategy_cfg.clone();
+    let landing_tracker_clone = landing_tracker.clone();
+    let landing_telemetry_clone = landing_telemetry.clone();
+    let circuit_breaker_clone = circuit_breaker.clone();
     
     info!("📋 Starting Advice Bus Listener on port {}", config.advice_bus_port);
     
@@ -122,20 +203,42 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
                 
                 loop {
                     tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+                    health::listener_tick();
                     
                     // UNIFIED MESSAGE RECEIVING: Read once, route by size
                     if let Some(message) = listener.try_recv_any() {
                         match message {
-                            advice_bus::MessageType::TradeDecision(decision) => {
+                            advice_bus::MessageType::TradeDecision(mut decision) => {
                                 let timestamp_received = telemetry::now_ns();
                                 let mint_str = bs58::encode(decision.mint).into_string();
-                                let decision_id = uuid::Uuid::new_v4().to_string();
                                 
-                                info!("📥 RECEIVED TradeDecision: {} {} | mint: {} | conf: {}",
+                                // Never trust inbound slippage beyond MAX_SLIPPAGE_BPS
+                                decision.slippage_bps = slippage::clamp_slippage_bps(
+                                    decision.slippage_bps, config_clone.max_slippage_bps, &mint_str[..12]);
+                                let decision_id = decision.decision_id_str()
+                                    .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+                                let trace_id = decision.trace_id_str().unwrap_or_else(|| decision_id.clone());
+                                let trace = decision.trace_tag();
+                                
+                                // Same decision ID already handled: Brain retransmit, drop it
+                                if decision.decision_id_str().is_some() {
+                                    let mut seen = seen_decisions_clone.write().await;
+                                    if seen.contains(&decision.decision_id) {
+                                        warn!("⏭️  Skipping duplicate decision {} for {}", decision_id, &mint_str[..12]);
+                                        continue;
+                                    }
+                                    seen.push_back(decision.decision_id);
+                                    if seen.len() > MAX_SEEN_DECISIONS {
+                                        seen.pop_front();
+                                    }
+                                }
+                                
+                                info!("📥 RECEIVED TradeDecision: {} {} | mint: {} | conf: {} | trace={}",
                                       if decision.is_buy() { "BUY" } else { "SELL" },
                                       decision.size_lamports as f64 / 1e9,
                                       &mint_str[..12], 
-                                      decision.confidence);
+                                      decision.confidence,
+                                      trace);
                         
                         if decision.is_buy() {
                             // Check deduplication (prevent double-buy within 5s)
@@ -167,12 +270,61 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
                             
                             info!("✅ BUY accepted (deduplication check passed)");
                             
+                            // Circuit breaker open: don't attempt, tell Brain to pause entries
+                            let circuit_remaining = circuit_breaker_clone.write().await.open_remaining(Instant::now());
+                            if let Some(remaining) = circuit_remaining {
+                                let cooldown_secs = remaining.as_secs().clamp(1, u16::MAX as u64) as u16;
+                                warn!("🔌 Circuit open: skipping BUY for {} ({}s cooldown left)",
+                                      &mint_str[..12], cooldown_secs);
+                                let confirmation = ExecutionConfirmation::new_circuit_open(decision.mint, 0, cooldown_secs)
+                                    .with_trace_id(decision.trace_id);
+                                if let Err(e) = confirmation_socket_clone.send_to(
+                                    &confirmation.to_bytes(),
+                                    &brain_addr_clone
+                                ).await {
+                                    error!("❌ Failed to send circuit-open confirmation: {}", e);
+                                }
+                                continue;
+                            }
+                            
+                            // Strategy override: scale Brain's size, replace dynamic slippage
+                            let strategy_override = match &strategy_store_clone {
+                                Some(store) => {
+                                    let hour_utc = (std::time::SystemTime::now()
+                                        .duration_since(std::time::UNIX_EPOCH)
+                                        .map(|d| d.as_secs())
+                                        .unwrap_or(0) / 3600 % 24) as u8;
+                                    let ctx = LiveContext {
+                                        hour_utc,
+                                        landing_rate: landing_tracker_clone.read().await.rate(),
+                                        ..LiveContext::default()
+                                    };
+                                    let active = store.read().await;
+                                    let picked = strategy_loader::pick_strategy(&active.strategies, &ctx)
+                                        .map(|s| (s.id.clone(), s.execution_override(&ctx, &strategy_cfg_clone)));
+                                    picked
+                                }
+                                None => None,
+                            };
+                            let size_lamports = match &strategy_override {
+                                Some((id, ov)) => {
+                                    info!("🎛️  Strategy {} override: size x{:.2}, slippage {} bps",
+                                          id, ov.size_multiplier, ov.strategy_slippage_bps);
+                                    ov.apply_size(decision.size_lamports)
+                                }
+                                None => decision.size_lamports,
+                            };
+                            let slippage_override = strategy_override.as_ref().map(|(_, ov)| {
+                                slippage::clamp_slippage_bps(ov.strategy_slippage_bps, config_clone.max_slippage_bps, &mint_str[..12])
+                            });
+                            
                             // Execute BUY
-                            let position_size_sol = decision.size_lamports as f64 / 1_000_000_000.0;
+                            let position_size_sol = size_lamports as f64 / 1_000_000_000.0;
                             let position_size_usd = position_size_sol * 200.0; // Rough estimate
                             
                             info!("🔨 Building BUY transaction: {} SOL with {}% slippage",
-                                  position_size_sol, decision.slippage_bps as f64 / 100.0);
+                                  position_size_sol,
+                                  slippage_override.unwrap_or(decision.slippage_bps) as f64 / 100.0);
                             
                             // Get warmed blockhash from cache
                             let cached_blockhash = Some(trading::get_cached_blockhash().await);
@@ -185,17 +337,74 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
                                 1, // estimated_position
                                 0.0, // mempool_volume
                                 0, // pending_buys
-                                Some(decision_id.clone()), // trace_id
+                                Some(trace_id.clone()),
                                 cached_blockhash,
                                 decision.entry_type,
+                                slippage_override,
                             ).await {
                                 Ok(result) => {
+                                    circuit_breaker_clone.write().await.record_success();
                                     info!("✅ BUY executed successfully!");
                                     info!("   📝 Signature: {}", result.signature);
                                     info!("   💰 Tokens bought: {:.2}", result.token_amount);
                                     info!("   💵 SOL spent: {:.4}", result.position_size / 200.0);
                                     info!("   📊 Price: {:.10} SOL/token", result.price);
                                     
+                                    // Landing: wait for the tx to confirm, then record route / CU price
+                                    // outcome and write the performance log line
+                                    {
+                                        let trading = trading_clone.clone();
+                                        let landing_telemetry = landing_telemetry_clone.clone();
+                                        let landing_tracker = landing_tracker_clone.clone();
+                                        let landing_window = std::time::Duration::from_secs(config_clone.landing_window_secs);
+                                        let decision_id = decision_id.clone();
+                                        let mint_str = mint_str.clone();
+                                        let result = result.clone();
+                                        tokio::spawn(async move {
+                                            let t_send = result.t_send.unwrap_or_else(Instant::now);
+                                            let ts_sent = performance_log::now_ns()
+                                                .saturating_sub(t_send.elapsed().as_nanos() as u64);
+                                            let route = result.submission_path.clone().unwrap_or_else(|| "UNKNOWN".to_string());
+                                            let bucket = telemetry::cu_price_bucket(result.priority_fee_micro_lamports);
+                                            
+                                            let log = performance_log::PerformanceLogBuilder::new(
+                                                decision_id,
+                                                mint_str.clone(),
+                                                "BUY".to_string(),
+                                                timestamp_received,
+                                                ts_sent,
+                                                ts_sent,
+                                            )
+                                            .signature(result.signature.clone())
+                                            .position_size(result.position_size)
+                                            .priority_fee(result.priority_fee_micro_lamports.unwrap_or(0))
+                                            .cu_price_bucket(bucket)
+                                            .jito_bundle(route.starts_with("JITO"))
+                                            .route(result.submission_path.clone());
+                                            
+                                            let log = if result.dry_run {
+                                                landing_tracker.write().await.record(true);
+                                                log.status("DRY_RUN".to_string())
+                                            } else {
+                                                let (status, confirm_time) = trading
+                                                    .wait_for_landing(&result.signature, t_send, landing_window)
+                                                    .await;
+                                                let confirm_ms = confirm_time.map(|d| d.as_secs_f64() * 1000.0);
+                                                landing_telemetry.record(&route, result.priority_fee_micro_lamports, status, confirm_ms);
+                                                landing_tracker.write().await.record(status == telemetry::LandingStatus::Landed);
+                                                debug!("🛬 BUY {} via {} (cu={}): {} {}",
+                                                       &mint_str[..12], route, bucket, status.as_str(),
+                                                       confirm_ms.map(|ms| format!("in {:.0}ms", ms)).unwrap_or_default());
+                                                log.landing(
+                                                    status == telemetry::LandingStatus::Landed,
+                                                    confirm_time.map(|d| ts_sent + d.as_nanos() as u64),
+                                                )
+                                                .status(status.as_str().to_string())
+                                            };
+                                            log.build().log(performance_log::PERFORMANCE_LOG_PATH);
+                                        });
+                                    }
+                                    
                                     // Send ExecutionConfirmation to Brain (Brain handles rest)
                                     let tx_sig_bytes: [u8; 32] = bs58::decode(&result.signature)
                                         .into_vec()
@@ -207,10 +416,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
                                     let confirmation = ExecutionConfirmation::new_success(
                                         decision.mint,
                                         0, // BUY side
-                                        decision.size_lamports,
+                                        size_lamports,
                                         result.price,
                                         tx_sig_bytes,
-                                    );
+                                    )
+                                    .with_dry_run(result.dry_run)
+                                    .with_quote(result.quoted_tokens_raw, result.effective_price_sol)
+                                    .with_trace_id(decision.trace_id);
                                     
                                     if let Err(e) = confirmation_socket_clone.send_to(
                                         &confirmation.to_bytes(),
@@ -218,14 +430,25 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
                                     ).await {
                                         error!("❌ Failed to send BUY confirmation to Brain: {}", e);
                                     } else {
-                                        info!("📡 Sent BUY confirmation to Brain: {}", &mint_str[..12]);
+                                        info!("📡 Sent BUY confirmation to Brain: {} | trace={}", &mint_str[..12], trace);
                                     }
                                 }
                                 Err(e) => {
+                                    landing_tracker_clone.write().await.record(false);
                                     error!("❌ BUY failed for {}: {}", &mint_str[..12], e);
                                     
-                                    // Send failure confirmation to Brain
-                                    let confirmation = ExecutionConfirmation::new_failure(decision.mint, 0);
+                                    // Send failure confirmation to Brain ("circuit open" if this tripped the breaker)
+                                    let confirmation = if circuit_breaker_clone.write().await.record_failure(Instant::now()) {
+                                        error!("🔌 Circuit breaker OPEN: {} consecutive failures, pausing BUYs for {}s",
+                                               config_clone.circuit_breaker_failures, config_clone.circuit_breaker_cooldown_secs);
+                                        ExecutionConfirmation::new_circuit_open(
+                                            decision.mint, 0,
+                                            config_clone.circuit_breaker_cooldown_secs.min(u16::MAX as u64) as u16,
+                                        )
+                                    } else {
+                                        ExecutionConfirmation::new_failure(decision.mint, 0)
+                                    }
+                                    .with_trace_id(decision.trace_id);
                                     if let Err(send_err) = confirmation_socket_clone.send_to(
                                         &confirmation.to_bytes(),
                                         &brain_addr_clone
@@ -283,13 +506,31 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
                                 &mint_str,
                                 decision.size_lamports,
                                 decision.slippage_bps,
+                                decision.min_sol_out_lamports,
+                                Some(trace_id.clone()),
                                 cached_blockhash,
                             ).await {
                                 Ok(result) => {
+                                    circuit_breaker_clone.write().await.record_success();
                                     info!("✅ SELL executed successfully!");
                                     info!("   📝 Signature: {}", result.signature);
                                     info!("   💰 Exit price: {:.10} SOL/token", result.exit_price);
                                     
+                                    let ts_sent = performance_log::now_ns();
+                                    performance_log::PerformanceLogBuilder::new(
+                                        decision_id.clone(),
+                                        mint_str.clone(),
+                                        "SELL".to_string(),
+                                        timestamp_received,
+                                        ts_sent,
+                                        ts_sent, // Confirmation tracked separately by signature
+                                    )
+                                    .signature(result.signature.clone())
+                                    .priority_fee(result.priority_fee_micro_lamports.unwrap_or(0))
+                                    .status(if result.dry_run { "DRY_RUN" } else { "SUBMITTED" }.to_string())
+                                    .build()
+                                    .log(performance_log::PERFORMANCE_LOG_PATH);
+                                    
                                     // Send ExecutionConfirmation to Brain
                                     let tx_sig_bytes: [u8; 32] = bs58::decode(&result.signature)
                                         .into_vec()
@@ -304,7 +545,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
                                         decision.size_lamports,
                                         result.exit_price,
                                         tx_sig_bytes,
-                                    );
+                                    )
+                                    .with_dry_run(result.dry_run)
+                                    .with_trace_id(decision.trace_id);
                                     
                                     if let Err(e) = confirmation_socket_clone.send_to(
                                         &confirmation.to_bytes(),
@@ -312,14 +555,24 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
                                     ).await {
                                         error!("❌ Failed to send SELL confirmation to Brain: {}", e);
                                     } else {
-                                        info!("📡 Sent SELL confirmation to Brain: {}", &mint_str[..12]);
+                                        info!("📡 Sent SELL confirmation to Brain: {} | trace={}", &mint_str[..12], trace);
                                     }
                                 }
                                 Err(e) => {
                                     error!("❌ SELL failed for {}: {}", &mint_str[..12], e);
                                     
-                                    // Send failure confirmation to Brain
-                                    let confirmation = ExecutionConfirmation::new_failure(decision.mint, 1);
+                                    // Send failure confirmation to Brain ("circuit open" if this tripped the breaker)
+                                    let confirmation = if circuit_breaker_clone.write().await.record_failure(Instant::now()) {
+                                        error!("🔌 Circuit breaker OPEN: {} consecutive failures, pausing BUYs for {}s",
+                                               config_clone.circuit_breaker_failures, config_clone.circuit_breaker_cooldown_secs);
+                                        ExecutionConfirmation::new_circuit_open(
+                                            decision.mint, 1,
+                                            config_clone.circuit_breaker_cooldown_secs.min(u16::MAX as u64) as u16,
+                                        )
+                                    } else {
+                                        ExecutionConfirmation::new_failure(decision.mint, 1)
+                                    }
+                                    .with_trace_id(decision.trace_id);
                                     if let Err(send_err) = confirmation_socket_clone.send_to(
                                         &confirmation.to_bytes(),
                                         &brain_addr_clone
@@ -346,8 +599,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
                                         info!("📊 RECEIVED SolPriceUpdate: ${:.2} from {} (ts: {})", 
                                             price_usd, source_name, timestamp_secs);
                                         
-                                        // Update the cache used by trading engine
-                                        trading::update_sol_price_cache(price_usd).await;
+                                        // Update the cache used by trading engine (drops stale/out-of-order prices)
+                                        trading::update_sol_price_cache(
+                                            price_usd,
+                                            timestamp_secs as u64,
+                                            std::time::Duration::from_secs(config_clone.sol_price_max_age_secs),
+                                        ).await;
                                     }
                                     _ => {
                                         debug!("Received other advisory type: {:?}", advisory);
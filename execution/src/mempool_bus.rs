@@ -16,6 +16,7 @@ pub struct HotSignalMessage {
     pub amount_sol: f64,
     pub action: String,
     pub urgency: u8,  // 0-100
+    pub whale_tier: u8,  // 0=none, 1=whale, 2=mega-whale
     pub timestamp: u64,
 }
 
@@ -83,6 +83,10 @@ pub struct BrainMetrics {
     pub udp_packets_received: IntCounter,
     pub udp_packets_sent: IntCounter,
     pub udp_parse_errors: IntCounter,
+    
+    // Blockhash warm-up cache staleness
+    pub blockhash_retries: IntCounter,
+    pub blockhash_retry_failures: IntCounter,
 }
 
 impl BrainMetrics {
@@ -241,6 +245,17 @@ impl BrainMetrics {
         ).unwrap();
         registry.register(Box::new(udp_parse_errors.clone())).unwrap();
         
+        // Blockhash metrics
+        let blockhash_retries = IntCounter::with_opts(
+            Opts::new("executor_blockhash_retries", "Submits retried with a fresh blockhash after a blockhash error")
+        ).unwrap();
+        registry.register(Box::new(blockhash_retries.clone())).unwrap();
+        
+        let blockhash_retry_failures = IntCounter::with_opts(
+            Opts::new("executor_blockhash_retry_failures", "Fresh-blockhash retries that still failed")
+        ).unwrap();
+        registry.register(Box::new(blockhash_retry_failures.clone())).unwrap();
+        
         Self {
             registry,
             decisions_total,
@@ -271,6 +286,8 @@ impl BrainMetrics {
             udp_packets_received,
             udp_packets_sent,
             udp_parse_errors,
+            blockhash_retries,
+            blockhash_retry_failures,
         }
     }
     
@@ -300,13 +317,15 @@ pub async fn start_metrics_server(port: u16) -> anyhow::Result<()> {
     
     let app = Router::new()
         .route("/metrics", get(metrics_handler))
-        .route("/health", get(health_handler));
+        .route("/health", get(crate::health::health_handler))
+        .route("/ready", get(crate::health::ready_handler));
     
     let listener = TcpListener::bind(&addr).await?;
     
     info!("✓ Metrics server listening on http://{}", addr);
     info!("  • Metrics endpoint: http://{}/metrics", addr);
     info!("  • Health endpoint: http://{}/health", addr);
+    info!("  • Ready endpoint: http://{}/ready", addr);
     
     axum::serve(listener, app).await?;
     
@@ -336,15 +355,6 @@ async fn metrics_handler() -> Response {
     }
 }
 
-/// Health check endpoint
-async fn health_handler() -> Response {
-    (
-        StatusCode::OK,
-        [("content-type", "application/json")],
-        r#"{"status":"healthy","service":"brain"}"#,
-    ).into_response()
-}
-
 // ============================================================================
 // Helper Functions for Recording Metrics
 // ============================================================================
@@ -465,6 +475,18 @@ pub fn record_db_error() {
     metrics().db_errors.inc();
 }
 
+/// Record a submit retried with a fresh blockhash; returns the running total
+pub fn record_blockhash_retry() -> u64 {
+    let m = metrics();
+    m.blockhash_retries.inc();
+    m.blockhash_retries.get()
+}
+
+/// Record a fresh-blockhash retry that still failed
+pub fn record_blockhash_retry_failed() {
+    metrics().blockhash_retry_failures.inc();
+}
+
 /// Timer for measuring decision latency
 pub struct DecisionTimer {
     start: std::time::Instant,
@@ -542,5 +564,10 @@ mod tests {
         record_cache_access(CacheType::Mint, true);
         update_sol_price(195.50);
         update_active_positions(3);
+        
+        let before = metrics().blockhash_retries.get();
+        assert_eq!(record_blockhash_retry(), before + 1);
+        record_blockhash_retry_failed();
+        assert!(metrics().blockhash_retry_failures.get() > 0);
     }
 }
@@ -12,6 +12,9 @@ use std::io::Write;
 use std::path::Path;
 use log::error;
 
+/// Default JSONL output path (relative to the executor's working directory)
+pub const PERFORMANCE_LOG_PATH: &str = "logs/performance.jsonl";
+
 /// Complete performance metrics for a single trade execution
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TradePerformanceLog {
@@ -59,7 +62,14 @@ pub struct TradePerformanceLog {
     // Context
     pub tier: String,              // "t1", "t2", "t3", "t4"
     pub jito_bundle: bool,
+    pub route: Option<String>,     // Route that landed the tx: "RPC", "TPU", "JITO", "TPU-FALLBACK", ...
     pub resubmitted: bool,
+    
+    // Landing (did the tx confirm within the landing window?)
+    #[serde(default)]
+    pub landed: Option<bool>,
+    #[serde(default)]
+    pub cu_price_bucket: Option<String>,  // See telemetry::cu_price_bucket
 }
 
 impl TradePerformanceLog {
@@ -147,7 +157,10 @@ impl PerformanceLogBuilder {
                 error_message: None,
                 tier: "unknown".to_string(),
                 jito_bundle: false,
+                route: None,
                 resubmitted: false,
+                landed: None,
+                cu_price_bucket: None,
             }
         }
     }
@@ -186,6 +199,27 @@ impl PerformanceLogBuilder {
         self
     }
     
+    pub fn cu_price_bucket(mut self, bucket: &str) -> Self {
+        self.log.cu_price_bucket = Some(bucket.to_string());
+        self
+    }
+    
+    /// Record whether the tx landed; a landed tx also sets the confirmation time
+    pub fn landing(mut self, landed: bool, ts_confirmation_ns: Option<u64>) -> Self {
+        self.log.landed = Some(landed);
+        if let Some(ts_confirmation_ns) = ts_confirmation_ns {
+            self.log.ts_confirmation_ns = ts_confirmation_ns;
+            self.log.latency_execution_to_confirmation_ms =
+                (ts_confirmation_ns.saturating_sub(self.log.ts_execution_ns)) as f64 / 1_000_000.0;
+            if let Some(ts_decision) = self.log.ts_decision_ns {
+                self.log.latency_total_ms = Some(
+                    (ts_confirmation_ns.saturating_sub(ts_decision)) as f64 / 1_000_000.0
+                );
+            }
+        }
+        self
+    }
+    
     pub fn compute_units(mut self, cu: u64) -> Self {
         self.log.compute_units_used = Some(cu);
         self
@@ -230,6 +264,11 @@ impl PerformanceLogBuilder {
         self
     }
     
+    pub fn route(mut self, route: Option<String>) -> Self {
+        self.log.route = route;
+        self
+    }
+    
     pub fn resubmitted(mut self, resubmitted: bool) -> Self {
         self.log.resubmitted = resubmitted;
         self
@@ -297,4 +336,37 @@ mod tests {
         assert_eq!(log.latency_execution_to_confirmation_ms, 0.15);
         assert_eq!(log.latency_total_ms, Some(0.2));
     }
+    
+    #[test]
+    fn test_landing_sets_confirmation_latency() {
+        let ts_submit = 1_000_000_000u64;
+        let log = PerformanceLogBuilder::new(
+            "test".to_string(),
+            "mint".to_string(),
+            "BUY".to_string(),
+            ts_submit - 2_000_000,
+            ts_submit,
+            ts_submit,
+        )
+        .route(Some("TPU".to_string()))
+        .cu_price_bucket("10k-100k")
+        .landing(true, Some(ts_submit + 850_000_000))
+        .status("LANDED".to_string())
+        .build();
+        
+        assert_eq!(log.landed, Some(true));
+        assert_eq!(log.latency_execution_to_confirmation_ms, 850.0);
+        
+        let json = serde_json::to_string(&log).unwrap();
+        assert!(json.contains("\"cu_price_bucket\":\"10k-100k\""));
+        
+        let dropped = PerformanceLogBuilder::new(
+            "test".to_string(), "mint".to_string(), "BUY".to_string(),
+            ts_submit, ts_submit, ts_submit,
+        )
+        .landing(false, None)
+        .build();
+        assert_eq!(dropped.landed, Some(false));
+        assert_eq!(dropped.latency_execution_to_confirmation_ms, 0.0);
+    }
 }
@@ -102,7 +102,7 @@ impl BondingCurveState {
     }
     
     /// Calculate how many tokens you get for a given SOL amount
-    /// Uses constant product formula: x * y = k
+    /// Uses constant product formula: x * y = k (see `quote_buy`)
     pub fn calculate_buy_tokens(&self, sol_amount: f64) -> f64 {
         if self.complete {
             return 0.0;
@@ -110,17 +110,9 @@ impl BondingCurveState {
         
         let sol_lamports = (sol_amount * LAMPORTS_PER_SOL as f64) as u64;
         
-        // Constant product: k = virtual_sol_reserves * virtual_token_reserves
-        let k = (self.virtual_sol_reserves as u128) * (self.virtual_token_reserves as u128);
-        
-        // New SOL reserves after buy
-        let new_sol_reserves = self.virtual_sol_reserves + sol_lamports;
-        
-        // Calculate new token reserves: new_token_reserves = k / new_sol_reserves
-        let new_token_reserves = (k / new_sol_reserves as u128) as u64;
-        
-        // Tokens to receive
-        let tokens_base_units = self.virtual_token_reserves.saturating_sub(new_token_reserves);
+        // Can't buy more than the curve still holds
+        let tokens_base_units = quote_buy(self.virtual_sol_reserves, self.virtual_token_reserves, sol_lamports)
+            .min(self.real_token_reserves);
         
         // Convert to human-readable
         tokens_base_units as f64 / 10_f64.powi(PUMP_CURVE_TOKEN_DECIMALS as i32)
@@ -161,6 +153,22 @@ impl BondingCurveState {
 }
 
 /// Derive bonding curve address from token mint
+/// Exact tokens out (base units) for `sol_in_lamports` on the constant product curve
+/// 
+/// Integer math as the pump.fun program does it: the new token reserves are
+/// rounded up, so the quote never exceeds what the program will deliver.
+pub fn quote_buy(virtual_sol_reserves: u64, virtual_token_reserves: u64, sol_in_lamports: u64) -> u64 {
+    if virtual_sol_reserves == 0 || virtual_token_reserves == 0 || sol_in_lamports == 0 {
+        return 0;
+    }
+    
+    let k = virtual_sol_reserves as u128 * virtual_token_reserves as u128;
+    let new_sol_reserves = virtual_sol_reserves as u128 + sol_in_lamports as u128;
+    let new_token_reserves = k / new_sol_reserves + 1;
+    
+    (virtual_token_reserves as u128).saturating_sub(new_token_reserves) as u64
+}
+
 pub fn find_bonding_curve_address(token_mint: &Pubkey) -> Result<Pubkey, Box<dyn std::error::Error + Send + Sync>> {
     let program_id = Pubkey::from_str(PUMP_PROGRAM_ID)?;
     
@@ -397,4 +405,21 @@ mod tests {
         // Should get around 35M tokens
         assert!(tokens > 30_000_000.0 && tokens < 40_000_000.0, "Tokens: {}", tokens);
     }
+    
+    #[test]
+    fn test_quote_buy_exact() {
+        // 1 SOL into 30 SOL / 1.073B token virtual reserves
+        let tokens = quote_buy(30_000_000_000, 1_073_000_000_000_000, 1_000_000_000);
+        
+        // k / 31 SOL = 1_038_387_096_774_193.5..., rounded up
+        assert_eq!(tokens, 1_073_000_000_000_000 - 1_038_387_096_774_194);
+        
+        // Never more than the curve's product invariant allows
+        let k = 30_000_000_000u128 * 1_073_000_000_000_000u128;
+        let remaining = 1_073_000_000_000_000u128 - tokens as u128;
+        assert!(remaining * 31_000_000_000u128 >= k);
+        
+        assert_eq!(quote_buy(30_000_000_000, 1_073_000_000_000_000, 0), 0);
+        assert_eq!(quote_buy(0, 1_073_000_000_000_000, 1_000_000_000), 0);
+    }
 }
\ No newline at end of file
@@ -5,7 +5,8 @@ use solana_sdk::{
     system_program,
 };
 use std::str::FromStr;
-use log::debug;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use log::{debug, warn};
 
 // Manually define SPL Token and Associated Token Account IDs to avoid dependency issues
 const SPL_TOKEN_PROGRAM_ID: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
@@ -17,6 +18,66 @@ pub const PUMP_GLOBAL_STATE: &str = "4wTV1YmiEkRvAtNtsSGPtUrqRYQMe5SKy2uB4Jjaxnj
 pub const PUMP_FEE_RECIPIENT: &str = "CebN5WGQ4jvEPvsVU4EoHEpgzq1VV7AbicfhtW4xC9iM";
 pub const PUMP_EVENT_AUTHORITY: &str = "Ce6TQqeHC9p8KetsN6JsjHK7UTZk7nasjjnr7XxXp9F1";
 
+/// Fee recipients listed in the pump.fun global account; the program accepts
+/// any of them, so spreading trades across them avoids write-lock contention
+/// on a single account during bursts
+pub const PUMP_FEE_RECIPIENTS: &[&str] = &[
+    PUMP_FEE_RECIPIENT,
+    "62qc2CNXwrYqQScmEdiZFFAnJR262PxWEuNQtxfafNgV",
+    "7VtfL8fvgNfhz17qKRMjzQEXgbdpnHHHQRh54R9jP2RJ",
+    "7hTckgnGnLQR6sdH7YkqFTAA7VwTfYFaZ6EhEsU3saCX",
+    "9rPYyANsfQZw3DnDmKE3YCQF5E8oD89UXoHn9JFEhJUz",
+    "AVmoTthdrX6tKt4nDjco2D775W2YK3sDhxPcMmzUAmTY",
+    "FWsW1xNtWscwNmKv6wVsU1iTzRN6wmmk3MjxRP5tT7hz",
+    "G5UZAVbAf46s7cKWoyKu8kYTip9DGTpbLZ2qa9Aq69dP",
+];
+
+/// Whether `key` is one of the known pump.fun fee recipients
+pub fn is_known_fee_recipient(key: &str) -> bool {
+    PUMP_FEE_RECIPIENTS.contains(&key.trim())
+}
+
+/// Round-robin fee recipient per transaction (PUMP_FEE_RECIPIENTS config)
+pub struct FeeRecipientRotation {
+    recipients: Vec<Pubkey>,
+    next: AtomicUsize,
+}
+
+impl FeeRecipientRotation {
+    /// Keep the configured recipients that are known-valid (unknown ones are
+    /// dropped with a warning); an empty result falls back to the canonical one
+    pub fn new(configured: &[String]) -> Self {
+        let mut recipients: Vec<Pubkey> = Vec::new();
+        for key in configured {
+            if !is_known_fee_recipient(key) {
+                warn!("⚠️  Ignoring unknown pump.fun fee recipient: {}", key);
+                continue;
+            }
+            if let Ok(pubkey) = Pubkey::from_str(key.trim()) {
+                if !recipients.contains(&pubkey) {
+                    recipients.push(pubkey);
+                }
+            }
+        }
+        if recipients.is_empty() {
+            recipients.push(Pubkey::from_str(PUMP_FEE_RECIPIENT).unwrap());
+        }
+        
+        Self { recipients, next: AtomicUsize::new(0) }
+    }
+    
+    /// Fee recipient for the next transaction
+    pub fn next(&self) -> Pubkey {
+        let i = self.next.fetch_add(1, Ordering::Relaxed);
+        self.recipients[i % self.recipients.len()]
+    }
+    
+    /// Recipients in rotation order
+    pub fn recipients(&self) -> &[Pubkey] {
+        &self.recipients
+    }
+}
+
 const BONDING_CURVE_SEED: &[u8] = b"bonding-curve";
 
 /// Calculate buy instruction discriminator
@@ -69,12 +130,14 @@ pub fn get_associated_token_address(wallet: &Pubkey, mint: &Pubkey) -> Pubkey {
 /// * `token_amount` - Amount of tokens to buy (in base units, with decimals)
 /// * `max_sol_cost` - Maximum SOL to spend (slippage protection, in lamports)
 /// * `bonding_curve_creator` - Creator pubkey from bonding curve state (needed for creator_vault PDA)
+/// * `fee_recipient` - One of `PUMP_FEE_RECIPIENTS` (see `FeeRecipientRotation`)
 pub fn create_buy_instruction(
     buyer: &Pubkey,
     mint: &Pubkey,
     token_amount: u64,
     max_sol_cost: u64,
     bonding_curve_creator: &Pubkey,
+    fee_recipient: &Pubkey,
 ) -> Result<Instruction> {
     debug!("🔨 Building Pump.fun BUY instruction (OFFICIAL 16-account structure)");
     debug!("   Token amount: {}", token_amount);
@@ -83,7 +146,6 @@ pub fn create_buy_instruction(
     // Parse program IDs
     let program_id = Pubkey::from_str(PUMP_PROGRAM_ID)?;
     let global_state = Pubkey::from_str(PUMP_GLOBAL_STATE)?;
-    let fee_recipient = Pubkey::from_str(PUMP_FEE_RECIPIENT)?;
     let event_authority = Pubkey::from_str(PUMP_EVENT_AUTHORITY)?;
     let token_program = Pubkey::from_str(SPL_TOKEN_PROGRAM_ID)?;
     
@@ -138,7 +200,7 @@ pub fn create_buy_instruction(
     // Build accounts (OFFICIAL IDL ORDER - 16 accounts total!)
     let accounts = vec![
         AccountMeta::new_readonly(global_state, false),           // 0: global
-        AccountMeta::new(fee_recipient, false),                   // 1: fee_recipient (writable)
+        AccountMeta::new(*fee_recipient, false),                  // 1: fee_recipient (writable)
         AccountMeta::new_readonly(*mint, false),                  // 2: mint
         AccountMeta::new(bonding_curve, false),                   // 3: bonding_curve (writable)
         AccountMeta::new(associated_bonding_curve, false),        // 4: associated_bonding_curve (writable)
@@ -170,12 +232,14 @@ pub fn create_buy_instruction(
 /// * `token_amount` - Amount of tokens to sell (in base units, with decimals)
 /// * `min_sol_output` - Minimum SOL to receive (slippage protection, in lamports)
 /// * `bonding_curve_creator` - Creator pubkey from bonding curve state (needed for creator_vault PDA)
+/// * `fee_recipient` - One of `PUMP_FEE_RECIPIENTS` (see `FeeRecipientRotation`)
 pub fn create_sell_instruction(
     seller: &Pubkey,
     mint: &Pubkey,
     token_amount: u64,
     min_sol_output: u64,
     bonding_curve_creator: &Pubkey,
+    fee_recipient: &Pubkey,
 ) -> Result<Instruction> {
     debug!("🔨 Building Pump.fun SELL instruction (OFFICIAL 14-account structure)");
     debug!("   Token amount: {}", token_amount);
@@ -184,7 +248,6 @@ pub fn create_sell_instruction(
     // Parse program IDs
     let program_id = Pubkey::from_str(PUMP_PROGRAM_ID)?;
     let global_state = Pubkey::from_str(PUMP_GLOBAL_STATE)?;
-    let fee_recipient = Pubkey::from_str(PUMP_FEE_RECIPIENT)?;
     let event_authority = Pubkey::from_str(PUMP_EVENT_AUTHORITY)?;
     let token_program = Pubkey::from_str(SPL_TOKEN_PROGRAM_ID)?;
     
@@ -224,7 +287,7 @@ pub fn create_sell_instruction(
     // Build accounts (OFFICIAL IDL ORDER - 14 accounts total!)
     let accounts = vec![
         AccountMeta::new_readonly(global_state, false),           // 0: global
-        AccountMeta::new(fee_recipient, false),                   // 1: fee_recipient (writable)
+        AccountMeta::new(*fee_recipient, false),                  // 1: fee_recipient (writable)
         AccountMeta::new_readonly(*mint, false),                  // 2: mint
         AccountMeta::new(bonding_curve, false),                   // 3: bonding_curve (writable)
         AccountMeta::new(associated_bonding_curve, false),        // 4: associated_bonding_curve (writable)
@@ -273,16 +336,21 @@ mod tests {
         let mint = Pubkey::from_str("GBX4a3zACNfp8MdTvYMoLwjf5gKUmZxHyYQgaeJqXkMD").unwrap();
         let creator = Pubkey::default();  // Dummy creator for test
         
+        let fee_recipient = Pubkey::from_str(PUMP_FEE_RECIPIENTS[3]).unwrap();
+        
         let ix = create_buy_instruction(
             &buyer,
             &mint,
             1_000_000, // 1 token (6 decimals)
             10_000_000, // 0.01 SOL
             &creator,  // NEW - creator pubkey
+            &fee_recipient,
         ).unwrap();
         
         // Should have correct program ID
         assert_eq!(ix.program_id.to_string(), PUMP_PROGRAM_ID);
+        assert_eq!(ix.accounts[1].pubkey, fee_recipient);
+        assert!(ix.accounts[1].is_writable);
         
         // Should have 16 accounts (OFFICIAL IDL STRUCTURE!)
         assert_eq!(ix.accounts.len(), 16);
@@ -290,4 +358,27 @@ mod tests {
         // Data should be 24 bytes (8 discriminator + 8 amount + 8 maxSolCost)
         assert_eq!(ix.data.len(), 24);
     }
+    
+    #[test]
+    fn test_fee_recipient_rotation() {
+        let configured: Vec<String> = vec![
+            PUMP_FEE_RECIPIENTS[1].to_string(),
+            "11111111111111111111111111111111".to_string(), // not a fee recipient
+            PUMP_FEE_RECIPIENTS[2].to_string(),
+            PUMP_FEE_RECIPIENTS[1].to_string(),              // duplicate
+        ];
+        let rotation = FeeRecipientRotation::new(&configured);
+        assert_eq!(rotation.recipients().len(), 2);
+        
+        let first = rotation.next();
+        let second = rotation.next();
+        assert_eq!(first.to_string(), PUMP_FEE_RECIPIENTS[1]);
+        assert_eq!(second.to_string(), PUMP_FEE_RECIPIENTS[2]);
+        assert_eq!(rotation.next(), first);
+        
+        // Nothing usable: canonical recipient only
+        let fallback = FeeRecipientRotation::new(&["bogus".to_string()]);
+        assert_eq!(fallback.next().to_string(), PUMP_FEE_RECIPIENT);
+        assert_eq!(FeeRecipientRotation::new(&[]).next().to_string(), PUMP_FEE_RECIPIENT);
+    }
 }
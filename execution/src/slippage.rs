@@ -222,6 +222,18 @@ pub async fn calculate_buy_slippage(
     Ok(SlippageResult::new(expected_tokens, actual_tokens))
 }
 
+/// Cap inbound slippage at `max_bps` (executor-side last line of defense)
+/// 
+/// Brain's value is trusted up to the cap; anything above is clamped and logged.
+pub fn clamp_slippage_bps(requested_bps: u16, max_bps: u16, mint: &str) -> u16 {
+    if requested_bps <= max_bps {
+        return requested_bps;
+    }
+    warn!("🛑 Clamping slippage for {}: {} bps → {} bps (MAX_SLIPPAGE_BPS)",
+          mint, requested_bps, max_bps);
+    max_bps
+}
+
 /// Calculate sell slippage (SOL amount perspective)
 /// 
 /// Simulates expected SOL from bonding curve, then compares with actual SOL received.
@@ -239,6 +251,14 @@ pub async fn calculate_sell_slippage(
 mod tests {
     use super::*;
     
+    #[test]
+    fn test_clamp_slippage_bps() {
+        // 100% slippage from a bad decision is capped to the configured max
+        assert_eq!(clamp_slippage_bps(10_000, 2_000, "mint"), 2_000);
+        assert_eq!(clamp_slippage_bps(2_000, 2_000, "mint"), 2_000);
+        assert_eq!(clamp_slippage_bps(300, 2_000, "mint"), 300);
+    }
+    
     #[test]
     fn test_slippage_calculation() {
         // Test case 1: Normal slippage loss
@@ -2,9 +2,12 @@
 // Lightweight UDP telemetry for latency tracking and performance analysis
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::net::UdpSocket;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use anyhow::{Context, Result};
-use log::{warn, debug};
+use log::{info, warn, debug};
 
 /// Telemetry message sent from Executor → Brain
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -182,6 +185,140 @@ impl TelemetrySender {
     }
 }
 
+/// Landing outcome of a submitted transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LandingStatus {
+    Landed,   // Confirmed within the landing window
+    Failed,   // Landed within the window but errored on-chain
+    Dropped,  // Not seen within the landing window
+}
+
+impl LandingStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LandingStatus::Landed => "LANDED",
+            LandingStatus::Failed => "FAILED",
+            LandingStatus::Dropped => "DROPPED",
+        }
+    }
+}
+
+/// Compute-unit price bucket used to group landing stats
+pub fn cu_price_bucket(priority_fee_micro_lamports: Option<u64>) -> &'static str {
+    match priority_fee_micro_lamports {
+        None => "tip",  // Jito paths pay a tip instead of a CU price
+        Some(0) => "0",
+        Some(p) if p < 10_000 => "<10k",
+        Some(p) if p < 100_000 => "10k-100k",
+        Some(p) if p < 1_000_000 => "100k-1M",
+        Some(_) => ">=1M",
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct LandingCounts {
+    landed: u64,
+    failed: u64,
+    dropped: u64,
+    total_confirm_ms: f64,  // Sum over landed txs (submit → confirmed)
+}
+
+/// Landing stats for one (route, CU price bucket) over a rollup window
+#[derive(Debug, Clone, PartialEq)]
+pub struct LandingRollup {
+    pub route: String,
+    pub cu_price_bucket: &'static str,
+    pub submitted: u64,
+    pub landed: u64,
+    pub failed: u64,
+    pub dropped: u64,
+    pub landing_rate: f64,          // landed / submitted
+    pub avg_confirm_ms: Option<f64>,
+}
+
+/// Per-route / CU-price landing counters, drained by the periodic rollup
+#[derive(Debug, Default)]
+pub struct LandingTelemetry {
+    counts: Mutex<HashMap<(String, &'static str), LandingCounts>>,
+}
+
+impl LandingTelemetry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the outcome of one submitted transaction
+    pub fn record(
+        &self,
+        route: &str,
+        priority_fee_micro_lamports: Option<u64>,
+        status: LandingStatus,
+        confirm_ms: Option<f64>,
+    ) {
+        let key = (route.to_string(), cu_price_bucket(priority_fee_micro_lamports));
+        let mut counts = self.counts.lock().unwrap_or_else(|e| e.into_inner());
+        let entry = counts.entry(key).or_default();
+        match status {
+            LandingStatus::Landed => {
+                entry.landed += 1;
+                entry.total_confirm_ms += confirm_ms.unwrap_or(0.0);
+            }
+            LandingStatus::Failed => entry.failed += 1,
+            LandingStatus::Dropped => entry.dropped += 1,
+        }
+    }
+
+    /// Drain the counters into per-(route, bucket) rollups, sorted by route then bucket
+    pub fn rollup(&self) -> Vec<LandingRollup> {
+        let drained = std::mem::take(&mut *self.counts.lock().unwrap_or_else(|e| e.into_inner()));
+        let mut rollups: Vec<LandingRollup> = drained
+            .into_iter()
+            .map(|((route, bucket), c)| {
+                let submitted = c.landed + c.failed + c.dropped;
+                LandingRollup {
+                    route,
+                    cu_price_bucket: bucket,
+                    submitted,
+                    landed: c.landed,
+                    failed: c.failed,
+                    dropped: c.dropped,
+                    landing_rate: if submitted > 0 { c.landed as f64 / submitted as f64 } else { 0.0 },
+                    avg_confirm_ms: (c.landed > 0).then(|| c.total_confirm_ms / c.landed as f64),
+                }
+            })
+            .collect();
+        rollups.sort_by(|a, b| a.route.cmp(&b.route).then(a.cu_price_bucket.cmp(b.cu_price_bucket)));
+        rollups
+    }
+
+    /// Log and reset the landing stats collected since the previous rollup
+    pub fn log_rollup(&self, window_secs: u64) {
+        let rollups = self.rollup();
+        if rollups.is_empty() {
+            return;
+        }
+        info!("🛬 Landing rollup (last {}s):", window_secs);
+        for r in rollups {
+            info!("   {:<12} cu={:<9} sent={:<3} landed={:<3} failed={:<3} dropped={:<3} rate={:.0}% avg_confirm={}",
+                  r.route, r.cu_price_bucket, r.submitted, r.landed, r.failed, r.dropped,
+                  r.landing_rate * 100.0,
+                  r.avg_confirm_ms.map(|ms| format!("{:.0}ms", ms)).unwrap_or_else(|| "-".to_string()));
+        }
+    }
+
+    /// Spawn the periodic rollup logger
+    pub fn spawn_rollup_logger(self: Arc<Self>, interval_secs: u64) {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+            interval.tick().await;  // First tick fires immediately
+            loop {
+                interval.tick().await;
+                self.log_rollup(interval_secs);
+            }
+        });
+    }
+}
+
 /// Get current time in nanoseconds (for timestamp tracking)
 pub fn now_ns() -> u64 {
     std::time::SystemTime::now()
@@ -189,3 +326,45 @@ pub fn now_ns() -> u64 {
         .unwrap()
         .as_nanos() as u64
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cu_price_buckets() {
+        assert_eq!(cu_price_bucket(None), "tip");
+        assert_eq!(cu_price_bucket(Some(0)), "0");
+        assert_eq!(cu_price_bucket(Some(5_000)), "<10k");
+        assert_eq!(cu_price_bucket(Some(50_000)), "10k-100k");
+        assert_eq!(cu_price_bucket(Some(100_000)), "100k-1M");
+        assert_eq!(cu_price_bucket(Some(2_000_000)), ">=1M");
+    }
+
+    #[test]
+    fn test_landing_rollup_groups_and_drains() {
+        let telemetry = LandingTelemetry::new();
+        telemetry.record("TPU", Some(50_000), LandingStatus::Landed, Some(400.0));
+        telemetry.record("TPU", Some(60_000), LandingStatus::Landed, Some(600.0));
+        telemetry.record("TPU", Some(70_000), LandingStatus::Dropped, None);
+        telemetry.record("TPU", Some(70_000), LandingStatus::Failed, None);
+        telemetry.record("JITO", None, LandingStatus::Dropped, None);
+
+        let rollups = telemetry.rollup();
+        assert_eq!(rollups.len(), 2);
+
+        assert_eq!(rollups[0].route, "JITO");
+        assert_eq!(rollups[0].cu_price_bucket, "tip");
+        assert_eq!(rollups[0].landing_rate, 0.0);
+        assert_eq!(rollups[0].avg_confirm_ms, None);
+
+        let tpu = &rollups[1];
+        assert_eq!(tpu.cu_price_bucket, "10k-100k");
+        assert_eq!((tpu.submitted, tpu.landed, tpu.failed, tpu.dropped), (4, 2, 1, 1));
+        assert_eq!(tpu.landing_rate, 0.5);
+        assert_eq!(tpu.avg_confirm_ms, Some(500.0));
+
+        // Counters reset after each rollup
+        assert!(telemetry.rollup().is_empty());
+    }
+}
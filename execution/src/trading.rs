@@ -1,12 +1,15 @@
-use crate::config::Config;
+use crate::config::{Config, ExecutionRoute};
 use crate::pump_bonding_curve;
 use crate::pump_instructions;
 use crate::jito::JitoClient;
 use crate::tpu_client::FastTpuClient;
 use crate::grpc_client::PriorityFeeTracker;
 use crate::database::Database;  // TIER 5: For confirmation tracking
+use crate::telemetry::LandingStatus;
 use solana_client::rpc_client::RpcClient;
-use solana_client::rpc_config::RpcSendTransactionConfig;
+use solana_client::rpc_config::{
+    RpcSendTransactionConfig, RpcSimulateTransactionAccountsConfig, RpcSimulateTransactionConfig,
+};
 use solana_sdk::{
     commitment_config::CommitmentConfig,
     pubkey::Pubkey,
@@ -32,8 +35,9 @@ use std::sync::OnceLock;
 // Price doesn't change much within a few seconds, so we can cache it
 struct SolPriceCache {
     price: f64,
-    cached_at: Instant,
+    cached_at: Instant,  // When the price was broadcast (not received)
     ttl: Duration,
+    broadcast_secs: u64, // Sender timestamp of the cached price
 }
 
 static SOL_PRICE_CACHE: OnceLock<RwLock<SolPriceCache>> = OnceLock::new();
@@ -45,6 +49,7 @@ fn get_sol_price_cache() -> &'static RwLock<SolPriceCache> {
             price: 0.0, // Invalid price forces first fetch
             cached_at: Instant::now(), // Current time
             ttl: Duration::from_secs(0), // 0 TTL for first call (force fetch)
+            broadcast_secs: 0,
         })
     })
 }
@@ -86,6 +91,14 @@ pub async fn get_cached_blockhash() -> Hash {
     cached.hash
 }
 
+/// Whether a submit error means the cluster didn't accept our (stale) blockhash
+pub fn is_blockhash_error(err: &str) -> bool {
+    let err = err.to_ascii_lowercase();
+    err.contains("blockhash not found")
+        || err.contains("blockhashnotfound")
+        || err.contains("block height exceeded")
+}
+
 /// Start background blockhash warm-up task
 /// Refreshes blockhash every 300ms to keep it hot
 pub fn start_blockhash_warmup_task(rpc_client: Arc<RpcClient>) {
@@ -135,13 +148,35 @@ pub fn start_blockhash_warmup_task(rpc_client: Arc<RpcClient>) {
 
 /// Update SOL price cache from external source (e.g., copytrader bot broadcast)
 /// This allows avoiding API failures during critical trades
-pub async fn update_sol_price_cache(price: f64) {
+///
+/// Broadcasts older than `max_age` (SOL_PRICE_MAX_AGE_SECS) or older than the
+/// cached price are ignored; returns whether the cache was updated.
+pub async fn update_sol_price_cache(price: f64, broadcast_secs: u64, max_age: Duration) -> bool {
+    let now_secs = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let age = Duration::from_secs(now_secs.saturating_sub(broadcast_secs));
+    
     let cache = get_sol_price_cache();
     let mut cached = cache.write().await;
+    if broadcast_secs < cached.broadcast_secs {
+        debug!("⏭️  Ignoring out-of-order SOL price ${:.2} (ts {} < cached {})",
+            price, broadcast_secs, cached.broadcast_secs);
+        return false;
+    }
+    if age > max_age {
+        warn!("⏰ Ignoring stale SOL price broadcast: ${:.2} is {}s old (max {}s)",
+            price, age.as_secs(), max_age.as_secs());
+        return false;
+    }
     cached.price = price;
-    cached.cached_at = Instant::now();
-    cached.ttl = Duration::from_secs(30); // 30s TTL
-    info!("✅ SOL price cache UPDATED from broadcast: ${:.2} (TTL: 30s)", price);
+    cached.cached_at = Instant::now().checked_sub(age).unwrap_or_else(Instant::now);
+    cached.ttl = max_age;
+    cached.broadcast_secs = broadcast_secs;
+    info!("✅ SOL price cache UPDATED from broadcast: ${:.2} (age: {}s, TTL: {}s)",
+        price, age.as_secs(), max_age.as_secs());
+    true
 }
 
 /// Get SOL/USD price from cache (populated by UDP broadcast from Brain)
@@ -164,7 +199,7 @@ async fn fetch_sol_price() -> Result<f64, Box<dyn std::error::Error + Send + Syn
                 age.as_secs_f64(), cached.ttl.as_secs_f64());
             
             // Return stale price rather than failing
-            // Brain UDP should refresh this every 30s
+            // The oracle heartbeat should refresh this well within the TTL
             return Ok(cached.price);
         }
     }
@@ -185,6 +220,7 @@ pub struct TradingEngine {
     fee_tracker: PriorityFeeTracker,  // TIER 2: Dynamic priority fee tracking
     curve_cache: Arc<pump_bonding_curve::BondingCurveCache>,  // OPTIMIZATION #12: Curve caching
     brain_socket: Option<std::net::UdpSocket>,  // For sending trade status to brain
+    fee_recipients: pump_instructions::FeeRecipientRotation,  // Per-tx pump.fun fee recipient
 }
 
 #[derive(Debug, Clone)]
@@ -209,7 +245,11 @@ pub struct BuyResult {
     pub t_build: Option<std::time::Instant>,  // When tx was built
     pub t_send: Option<std::time::Instant>,   // When tx was sent
     pub submission_path: Option<String>,      // How tx was submitted: "TPU", "JITO", "JITO-RACE", "RPC"
-    pub entry_type: u8,                       // Entry strategy: 0=Rank, 1=Momentum, 2=CopyTrade, 3=LateOpportunity
+    pub entry_type: u8,                       // Entry strategy: 0=Rank, 1=Momentum, 2=CopyTrade, 3=LateOpportunity, 4=Hotlist
+    pub dry_run: bool,                        // Simulated only (EXECUTOR_DRY_RUN) - signature is synthetic
+    pub priority_fee_micro_lamports: Option<u64>,  // Compute-unit price used (None on Jito paths: CU price = tip)
+    pub quoted_tokens_raw: u64,               // Tokens out quoted from the bonding curve before sending (base units)
+    pub effective_price_sol: f64,             // SOL in / quoted tokens (includes curve price impact)
 }
 
 /// Execution status for tracking transaction lifecycle
@@ -240,6 +280,8 @@ pub struct ExitResult {
     pub t_build: Option<std::time::Instant>,  // When tx was built
     pub t_send: Option<std::time::Instant>,   // When tx was sent
     pub submission_path: Option<String>,      // How tx was submitted: "TPU", "JITO", "JITO-RACE", "RPC"
+    pub dry_run: bool,                        // Simulated only (EXECUTOR_DRY_RUN) - signature is synthetic
+    pub priority_fee_micro_lamports: Option<u64>,  // Compute-unit price used (None on Jito paths: CU price = tip)
 }
 
 #[derive(Debug, Clone)]
@@ -270,8 +312,8 @@ impl TradingEngine {
         
         info!("Trading wallet: {}", keypair.pubkey());
         
-        // Initialize Jito client ONLY if enabled
-        let jito_client = if config.use_jito {
+        // Initialize Jito client ONLY if enabled (or routing BUYs through Jito)
+        let jito_client = if config.use_jito || config.execution_route == ExecutionRoute::Jito {
             match JitoClient::new(
                 &config.jito_block_engine_url,
                 None, // UUID is optional
@@ -290,8 +332,8 @@ impl TradingEngine {
             None
         };
         
-        // Initialize TPU client if enabled
-        let tpu_client = if config.use_tpu {
+        // Initialize TPU client if enabled (also the fallback for the Jito route)
+        let tpu_client = if config.use_tpu || config.execution_route != ExecutionRoute::Rpc {
             match FastTpuClient::new(&config.rpc_endpoint, &config.websocket_endpoint) {
                 Ok(client) => {
                     info!("✅ TPU client initialized for direct validator submission");
@@ -327,6 +369,9 @@ impl TradingEngine {
             }
         };
         
+        let fee_recipients = pump_instructions::FeeRecipientRotation::new(&config.pump_fee_recipients);
+        info!("✅ Rotating {} pump.fun fee recipient(s)", fee_recipients.recipients().len());
+        
         Ok(TradingEngine {
             rpc_client,
             keypair,
@@ -336,6 +381,7 @@ impl TradingEngine {
             fee_tracker: PriorityFeeTracker::new(),  // Initialize fee tracker
             curve_cache,  // Add curve cache
             brain_socket,  // Add brain socket
+            fee_recipients,
         })
     }
     
@@ -429,42 +475,48 @@ impl TradingEngine {
     }
     
     /// TIER 2: Calculate dynamic priority fee based on recent successful Pump.fun transactions
-    /// Uses p95 (95th percentile) + 10% buffer to stay competitive without overpaying
+    /// Uses the configured percentile (PRIORITY_FEE_PERCENTILE) + 10% buffer, clamped to MAX_PRIORITY_FEE_MICROLAMPORTS
     /// Falls back to conservative defaults if analysis fails
     fn get_dynamic_priority_fee(&self) -> u64 {
-        // Try to get p95 from recent transactions
-        if let Some(p95) = self.fee_tracker.get_p95() {
+        let percentile = self.config.priority_fee_percentile;
+        let max_fee = self.config.max_priority_fee_microlamports;
+        
+        // Try to get the configured percentile (e.g. p75) from recent transactions
+        if let Some(market_fee) = self.fee_tracker.get_percentile(percentile) {
             // Add 10% buffer to stay competitive
-            let boosted = (p95 as f64 * 1.10) as u64;
+            let boosted = (market_fee as f64 * 1.10) as u64;
             
-            // Clamp to reasonable bounds: 5k floor, 50k ceiling
-            let fee = boosted.clamp(5_000, 50_000);
+            // Clamp to reasonable bounds: 5k floor, configured ceiling
+            let fee = boosted.clamp(5_000, max_fee.max(5_000));
             
             // Log for diagnostics (samples available)
             let samples = self.fee_tracker.sample_count();
-            debug!("📊 Dynamic fee (buy): {} µL/CU (p95: {}, samples: {})", fee, p95, samples);
+            debug!("📊 Dynamic fee (buy): {} µL/CU (p{:.0}: {}, samples: {})", fee, percentile, market_fee, samples);
             
             fee
         } else {
             // Insufficient data - use conservative default
-            debug!("📊 Dynamic fee (buy): 10000 µL/CU (fallback - insufficient samples)");
-            10_000  // 10k micro-lamports = balanced priority
+            let fee = 10_000.min(max_fee);  // 10k micro-lamports = balanced priority
+            debug!("📊 Dynamic fee (buy): {} µL/CU (fallback - insufficient samples)", fee);
+            fee
         }
     }
     
     /// TIER 2: Get dynamic priority fee for sells (higher priority than buys)
     fn get_dynamic_priority_fee_sell(&self) -> u64 {
-        // Try to get p95 from recent transactions
-        if let Some(p95) = self.fee_tracker.get_p95() {
+        let percentile = self.config.priority_fee_percentile;
+        
+        // Try to get the configured percentile from recent transactions
+        if let Some(market_fee) = self.fee_tracker.get_percentile(percentile) {
             // Add 25% buffer for sells (more urgent than buys)
-            let boosted = (p95 as f64 * 1.25) as u64;
+            let boosted = (market_fee as f64 * 1.25) as u64;
             
             // Clamp to reasonable bounds: 10k floor, 75k ceiling (higher than buys)
             let fee = boosted.clamp(10_000, 75_000);
             
             // Log for diagnostics
             let samples = self.fee_tracker.sample_count();
-            debug!("📊 Dynamic fee (sell): {} µL/CU (p95: {}, samples: {})", fee, p95, samples);
+            debug!("📊 Dynamic fee (sell): {} µL/CU (p{:.0}: {}, samples: {})", fee, percentile, market_fee, samples);
             
             fee
         } else {
@@ -494,6 +546,7 @@ impl TradingEngine {
         token_address: &str,
         token_amount_raw: u64,
         max_sol_cost: u64,
+        priority_fee: u64,
         trace_id: Option<String>,
         cached_blockhash: Option<solana_sdk::hash::Hash>,
     ) -> Result<(String, Instant, Instant, String), Box<dyn std::error::Error + Send + Sync>> {
@@ -510,6 +563,7 @@ impl TradingEngine {
             &token_address_owned,
             token_amount_raw,
             max_sol_cost,
+            priority_fee,
             trace_id.clone(),
             cached_blockhash,
         );
@@ -569,6 +623,7 @@ impl TradingEngine {
                             &token_address_owned,
                             token_amount_raw,
                             max_sol_cost,
+                            priority_fee,
                             None,
                             cached_blockhash,
                         ).await {
@@ -599,6 +654,7 @@ impl TradingEngine {
         trace_id: Option<String>,  // NEW: For latency tracking
         cached_blockhash: Option<solana_sdk::hash::Hash>,  // NEW: Pre-warmed blockhash
         entry_type: u8,     // NEW: Entry strategy type for tracking
+        slippage_bps_override: Option<u16>,  // Strategy slippage (replaces dynamic slippage)
     ) -> Result<BuyResult, Box<dyn std::error::Error + Send + Sync>> {
         let t_buy_start = std::time::Instant::now();
         info!("⚡ Executing BUY for {} (${} position)", token_address, position_size_usd);
@@ -667,9 +723,14 @@ impl TradingEngine {
         // Step 5: Execute the buy via Jito with DYNAMIC SLIPPAGE (TIER 2)
         let sol_amount_lamports = (sol_amount * 1_000_000_000.0) as u64;
         let token_amount_raw = (token_amount * 1_000_000.0) as u64; // 6 decimals
+        let effective_price_sol = if token_amount > 0.0 { sol_amount / token_amount } else { entry_price };
         
         // TIER 2: Get dynamic slippage based on BOTH position AND queue depth
-        let slippage_multiplier = self.get_dynamic_slippage_with_queue(estimated_position, pending_buys);
+        // (a strategy slippage override takes precedence)
+        let slippage_multiplier = match slippage_bps_override {
+            Some(bps) => 1.0 + bps as f64 / 10_000.0,
+            None => self.get_dynamic_slippage_with_queue(estimated_position, pending_buys),
+        };
         let max_sol_cost = (sol_amount_lamports as f64 * slippage_multiplier) as u64;
         let slippage_percent = (slippage_multiplier - 1.0) * 100.0;
         
@@ -680,12 +741,19 @@ impl TradingEngine {
         info!("   Token amount (raw): {}", token_amount_raw);
         info!("   Estimated position: #{}", estimated_position);
         info!("   Pending buys in queue: {}", pending_buys);
-        info!("   Dynamic slippage: {:.1}% (base: {:.0}%, queue adj: {:+.1}%)", 
-            slippage_percent, 
-            (base_slippage - 1.0) * 100.0,
-            queue_adjustment * 100.0
-        );
-        info!("   Dynamic priority fee: {} micro-lamports/CU (TIER 2)", self.get_dynamic_priority_fee());
+        if slippage_bps_override.is_some() {
+            info!("   Strategy slippage: {:.1}% (overrides dynamic)", slippage_percent);
+        } else {
+            info!("   Dynamic slippage: {:.1}% (base: {:.0}%, queue adj: {:+.1}%)", 
+                slippage_percent, 
+                (base_slippage - 1.0) * 100.0,
+                queue_adjustment * 100.0
+            );
+        }
+        // TIER 2: One priority fee per transaction, from recent network percentiles
+        let priority_fee = self.get_dynamic_priority_fee();
+        info!("   Dynamic priority fee: {} micro-lamports/CU (p{:.0}, max {})",
+            priority_fee, self.config.priority_fee_percentile, self.config.max_priority_fee_microlamports);
         info!("   Max SOL cost: {} lamports ({} SOL)", max_sol_cost, max_sol_cost as f64 / 1e9);
         
         let calc_time = t_calc_start.elapsed().as_millis();
@@ -694,50 +762,55 @@ impl TradingEngine {
         // 🕐 Capture timing: transaction will be built inside execute functions
         let t_before_build = std::time::Instant::now();
         
-        // Execute buy with priority: RACE > TPU > Jito > Direct RPC
+        // Execute buy with priority: DRY-RUN > RACE > configured EXECUTION_ROUTE (Jito → TPU fallback)
         let t_exec_start = std::time::Instant::now();
-        let (signature, t_build, t_send, winner_path) = if self.config.use_jito_race && self.tpu_client.is_some() {
-            info!("🏁 Executing in RACE MODE (TPU vs Jito)...");
-            let (sig, tb, ts, path) = self.execute_race_buy(
+        let mut simulated_price = None;
+        let (signature, t_build, t_send, winner_path) = if self.config.dry_run {
+            info!("🧪 DRY-RUN: simulating BUY (EXECUTOR_DRY_RUN=true, not submitting)...");
+            let transaction = self.build_buy_transaction(
                 token_address,
                 token_amount_raw,
                 max_sol_cost,
-                trace_id.clone(),
+                priority_fee,
                 cached_blockhash,
             ).await?;
-            (sig, Some(tb), Some(ts), Some(path))
-        } else if self.config.use_tpu && self.tpu_client.is_some() {
-            info!("⚡ Executing via TPU (direct validator submission)...");
-            let (sig, tb, ts) = self.execute_tpu_buy_with_timing(
-                token_address,
-                token_amount_raw,
-                max_sol_cost,
-                trace_id.clone(),  // Pass trace_id for monitoring
-                cached_blockhash,  // Use warmed blockhash
-            ).await?;
-            (sig, Some(tb), Some(ts), Some("TPU".to_string()))
-        } else if self.config.use_jito {
-            info!("⚡ Executing Jito bundle submission...");
-            let (sig, tb, ts) = self.execute_jito_buy_with_timing(
-                token_address, 
-                token_amount_raw,
-                max_sol_cost,
-                trace_id.clone(),  // Pass trace_id for monitoring
-                cached_blockhash,  // Use warmed blockhash
-            ).await?;
-            (sig, Some(tb), Some(ts), Some("JITO".to_string()))
+            let t_sim = std::time::Instant::now();
+            let (sig, price) = self.simulate_dry_run(&transaction, &token_pubkey)?;
+            simulated_price = price;
+            (sig, Some(t_before_build), Some(t_sim), Some("DRY-RUN".to_string()))
         } else {
-            info!("⚡ Executing direct RPC transaction (fallback)...");
-            let sig = self.execute_direct_rpc_buy(
-                token_address, 
+            let submitted = self.submit_buy(
+                token_address,
                 token_amount_raw,
                 max_sol_cost,
-                cached_blockhash,  // Use warmed blockhash
-            ).await?;
-            // For direct RPC, we don't have fine-grained timing
-            (sig, Some(t_before_build), Some(std::time::Instant::now()), Some("RPC".to_string()))
+                priority_fee,
+                trace_id.clone(),
+                cached_blockhash,
+            ).await;
+            match submitted {
+                // Warm-up cache handed us a blockhash the cluster no longer knows:
+                // retry once with one fetched straight from RPC
+                Err(e) if is_blockhash_error(&e.to_string()) => {
+                    let retries = crate::metrics::record_blockhash_retry();
+                    warn!("🔁 BUY hit a stale blockhash ({}), retrying once with a fresh one (#{} since start)", e, retries);
+                    let fresh_blockhash = self.fetch_fresh_blockhash().await?;
+                    self.submit_buy(
+                        token_address,
+                        token_amount_raw,
+                        max_sol_cost,
+                        priority_fee,
+                        trace_id.clone(),
+                        Some(fresh_blockhash),
+                    ).await.inspect_err(|_| crate::metrics::record_blockhash_retry_failed())?
+                }
+                other => other?,
+            }
         };
         
+        if let Some(ref path) = winner_path {
+            info!("🛣️  Route landed: {} (configured: {})", path, self.config.execution_route.as_str());
+        }
+        
         let exec_time = t_exec_start.elapsed().as_millis();
         if let Some(ref path) = winner_path {
             println!("⏱️  Execution via {} : {}ms", path, exec_time);
@@ -745,13 +818,17 @@ impl TradingEngine {
             println!("⏱️  Execution time: {}ms", exec_time);
         }
         
+        // Jito paths price compute units from the tip, not the fee tracker
+        let landed_via_jito = winner_path.as_deref().is_some_and(|path| path.starts_with("JITO"));
+        let priority_fee_micro_lamports = if landed_via_jito { None } else { Some(priority_fee) };
+        
         let total_buy_time = t_buy_start.elapsed().as_millis();
         println!("⏱️ TOTAL buy() function time: {}ms", total_buy_time);
         
         // Step 6: Calculate fees
         // Note: Slippage is already reflected in the execution price (tokens received),
         // so we only count explicit fees: Jito tip + gas
-        let jito_tip = if self.config.use_jito {
+        let jito_tip = if landed_via_jito {
             self.config.jito_tip_amount as f64 / 1_000_000_000.0 * sol_price
         } else {
             0.0 // No Jito tip when using direct RPC
@@ -772,7 +849,13 @@ impl TradingEngine {
         // Calculate total cost including fees
         let total_cost_usd = position_size_usd + entry_fees.total;
         
-        info!("✅ BUY EXECUTED!");
+        let entry_price = simulated_price.unwrap_or(entry_price);
+        
+        if self.config.dry_run {
+            info!("✅ BUY SIMULATED (dry-run, nothing submitted)");
+        } else {
+            info!("✅ BUY EXECUTED!");
+        }
         info!("   Estimated Position: #{}", estimated_position);
         info!("   ACTUAL Position: #{} 🎯", actual_position);
         info!("   Entry Price: ${:.8}", entry_price);
@@ -806,9 +889,96 @@ impl TradingEngine {
             t_send,                  // ✅ Send timestamp
             submission_path: winner_path,  // ✅ How tx was submitted (TPU/JITO/RPC)
             entry_type,              // ✅ Entry strategy type
+            dry_run: self.config.dry_run,
+            priority_fee_micro_lamports,
+            quoted_tokens_raw: token_amount_raw,
+            effective_price_sol,
         })
     }
     
+    /// Submit a BUY over the configured route: RACE > EXECUTION_ROUTE (Jito → TPU fallback) > RPC
+    async fn submit_buy(
+        &self,
+        token_address: &str,
+        token_amount_raw: u64,
+        max_sol_cost: u64,
+        priority_fee: u64,
+        trace_id: Option<String>,
+        cached_blockhash: Option<solana_sdk::hash::Hash>,
+    ) -> Result<(String, Option<Instant>, Option<Instant>, Option<String>), Box<dyn std::error::Error + Send + Sync>> {
+        let t_before_build = std::time::Instant::now();
+        let submitted = if self.config.use_jito_race && self.tpu_client.is_some() {
+            info!("🏁 Executing in RACE MODE (TPU vs Jito)...");
+            let (sig, tb, ts, path) = self.execute_race_buy(
+                token_address,
+                token_amount_raw,
+                max_sol_cost,
+                priority_fee,
+                trace_id.clone(),
+                cached_blockhash,
+            ).await?;
+            (sig, Some(tb), Some(ts), Some(path))
+        } else if self.config.execution_route == ExecutionRoute::Jito && self.jito_client.is_some() {
+            info!("⚡ Executing Jito bundle submission (EXECUTION_ROUTE=jito)...");
+            match self.execute_jito_buy_with_timing(
+                token_address, 
+                token_amount_raw,
+                max_sol_cost,
+                trace_id.clone(),  // Pass trace_id for monitoring
+                cached_blockhash,  // Use warmed blockhash
+            ).await {
+                Ok((sig, tb, ts)) => (sig, Some(tb), Some(ts), Some("JITO".to_string())),
+                Err(e) if self.tpu_client.is_some() => {
+                    warn!("❌ Jito bundle rejected: {}, falling back to TPU...", e);
+                    let (sig, tb, ts) = self.execute_tpu_buy_with_timing(
+                        token_address,
+                        token_amount_raw,
+                        max_sol_cost,
+                        priority_fee,
+                        trace_id.clone(),
+                        cached_blockhash,
+                    ).await?;
+                    (sig, Some(tb), Some(ts), Some("TPU-FALLBACK".to_string()))
+                }
+                Err(e) => return Err(e),
+            }
+        } else if self.config.execution_route != ExecutionRoute::Rpc && self.tpu_client.is_some() {
+            info!("⚡ Executing via TPU (direct validator submission)...");
+            let (sig, tb, ts) = self.execute_tpu_buy_with_timing(
+                token_address,
+                token_amount_raw,
+                max_sol_cost,
+                priority_fee,
+                trace_id.clone(),  // Pass trace_id for monitoring
+                cached_blockhash,  // Use warmed blockhash
+            ).await?;
+            (sig, Some(tb), Some(ts), Some("TPU".to_string()))
+        } else {
+            info!("⚡ Executing direct RPC transaction...");
+            let sig = self.execute_direct_rpc_buy(
+                token_address, 
+                token_amount_raw,
+                max_sol_cost,
+                priority_fee,
+                cached_blockhash,  // Use warmed blockhash
+            ).await?;
+            // For direct RPC, we don't have fine-grained timing
+            (sig, Some(t_before_build), Some(std::time::Instant::now()), Some("RPC".to_string()))
+        };
+        Ok(submitted)
+    }
+    
+    /// Fetch a blockhash straight from RPC (bypassing the warm-up cache) and
+    /// refresh the cache with it
+    async fn fetch_fresh_blockhash(&self) -> Result<solana_sdk::hash::Hash, Box<dyn std::error::Error + Send + Sync>> {
+        let rpc_client = self.rpc_client.clone();
+        let hash = tokio::task::spawn_blocking(move || rpc_client.get_latest_blockhash()).await??;
+        let mut cached = get_blockhash_cache().write().await;
+        cached.hash = hash;
+        cached.cached_at = Instant::now();
+        Ok(hash)
+    }
+    
     /// TIER 3 Task 2: Transaction resubmission engine with automatic retry
     /// Wraps buy() with intelligent retry logic for network reliability
     pub async fn buy_with_retry(
@@ -839,6 +1009,7 @@ impl TradingEngine {
                 trace_id.clone(),
                 cached_blockhash,
                 entry_type,          // Pass entry_type through
+                None,                // Dynamic slippage
             ).await {
                 Ok(result) => {
                     if attempt > 1 {
@@ -954,6 +1125,7 @@ impl TradingEngine {
             Some(trace_id.clone()),
             Some(fresh_blockhash),
             0,                       // entry_type=0 (default to Rank for resubmit)
+            None,                    // Dynamic slippage
         ).await {
             Ok(result) => {
                 info!("✅ RESUBMIT SUCCESS: New signature {}", &result.signature[..12]);
@@ -1216,6 +1388,8 @@ impl TradingEngine {
             t_build: Some(t_build),
             t_send: Some(t_send),
             submission_path: Some(submission_path),
+            dry_run: false,
+            priority_fee_micro_lamports: None,
         })
     }
     
@@ -1264,6 +1438,8 @@ impl TradingEngine {
         token_address: &str,
         _size_lamports: u64,
         _slippage_bps: u16,
+        min_sol_out_lamports: u64,
+        trace_id: Option<String>,  // For latency tracking
         cached_blockhash: Option<solana_sdk::hash::Hash>,
     ) -> Result<ExitResult, anyhow::Error> {
         info!("⚡ Executing simplified SELL for {}", token_address);
@@ -1287,11 +1463,45 @@ impl TradingEngine {
             }
         };
         
+        // Dry-run BUYs never landed, so there is nothing on-chain to simulate selling:
+        // quote the exit from the bonding curve instead
+        if token_balance == 0 && self.config.dry_run {
+            let exit_price = fresh_curve.calculate_price();
+            info!("🧪 DRY-RUN: no token balance (simulated entry) - quoting exit from curve: {:.10} SOL/token", exit_price);
+            return Ok(ExitResult {
+                trade_id: trade_id.to_string(),
+                status: ExecutionStatus::Pending,
+                signature: Signature::new_unique().to_string(),
+                exit_price,
+                gross_profit: 0.0, // Brain calculates
+                exit_fees: FeeBreakdown {
+                    jito_tip: 0.0,
+                    gas_fee: 0.0,
+                    slippage: 0.0,
+                    total: 0.0,
+                },
+                net_profit: 0.0, // Brain calculates
+                net_profit_sol: 0.0, // Brain calculates
+                tier: "".to_string(),
+                holding_time: 0, // Brain calculates
+                actual_sol_received: None,
+                slippage_bps: None,
+                t_build: None,
+                t_send: None,
+                submission_path: Some("DRY-RUN".to_string()),
+                dry_run: true,
+                priority_fee_micro_lamports: None,
+            });
+        }
+        
         if token_balance == 0 {
             anyhow::bail!("No tokens to sell - balance is 0");
         }
         
         info!("   Token balance: {} (raw)", token_balance);
+        if min_sol_out_lamports > 0 {
+            info!("   Min SOL out: {} lamports ({:.6} SOL)", min_sol_out_lamports, min_sol_out_lamports as f64 / 1e9);
+        }
         
         // Build SELL transaction
         let t_build = std::time::Instant::now();
@@ -1306,41 +1516,70 @@ impl TradingEngine {
             &self.keypair.pubkey(),
             &token_mint,
             token_balance,
-            0, // min_sol_output - set to 0 for max speed, rely on slippage
+            min_sol_out_lamports, // Brain's floor from market price - exit slippage (0 = none)
             &fresh_curve.creator,
+            &self.fee_recipients.next(),
         )?;
         
-        let message = solana_sdk::message::Message::new_with_blockhash(
-            &[sell_ix],
-            Some(&self.keypair.pubkey()),
-            &recent_blockhash,
-        );
+        // TIER 2: Dynamic compute-unit price (sells bid above buys)
+        let priority_fee = self.get_dynamic_priority_fee_sell();
+        let compute_limit_ix = ComputeBudgetInstruction::set_compute_unit_limit(200_000);
+        let compute_budget_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_fee);
+        info!("   Priority fee: {} µLamports/CU", priority_fee);
         
-        let transaction = solana_sdk::transaction::Transaction::new(
-            &[&self.keypair],
-            message,
-            recent_blockhash,
-        );
+        let mut instructions = vec![compute_limit_ix, compute_budget_ix, sell_ix];
+        
+        // Add memo with trace_id for gRPC monitoring (kept on a blockhash retry)
+        if let Some(ref trace_id) = trace_id {
+            instructions.push(build_memo(trace_id.as_bytes(), &[&self.keypair.pubkey()]));
+        }
+        
+        let build_transaction = |blockhash: solana_sdk::hash::Hash| {
+            let message = solana_sdk::message::Message::new_with_blockhash(
+                &instructions,
+                Some(&self.keypair.pubkey()),
+                &blockhash,
+            );
+            solana_sdk::transaction::Transaction::new(&[&self.keypair], message, blockhash)
+        };
+        let transaction = build_transaction(recent_blockhash);
         
         info!("   Transaction built in {:?}", t_build.elapsed());
         
-        // Send SELL transaction using existing method
+        // Send SELL transaction using existing method (or simulate it in dry-run mode)
         let t_send = std::time::Instant::now();
-        let signature = self.rpc_client.send_and_confirm_transaction(&transaction)?;
-        
-        let submission_path = "RPC"; // Simplified executor always uses RPC
+        let (signature, simulated_price, submission_path) = if self.config.dry_run {
+            let (sig, price) = self.simulate_dry_run(&transaction, &token_mint)
+                .map_err(|e| anyhow::anyhow!("{}", e))?;
+            (sig, price, "DRY-RUN")
+        } else {
+            let sig = match self.rpc_client.send_and_confirm_transaction(&transaction) {
+                // Stale warm-up blockhash: re-sign with a fresh one and send once more
+                Err(e) if is_blockhash_error(&e.to_string()) => {
+                    let retries = crate::metrics::record_blockhash_retry();
+                    warn!("🔁 SELL hit a stale blockhash ({}), retrying once with a fresh one (#{} since start, trace={})",
+                          e, retries, trace_id.as_deref().unwrap_or("-"));
+                    let fresh_blockhash = self.fetch_fresh_blockhash().await
+                        .map_err(|e| anyhow::anyhow!("{}", e))?;
+                    self.rpc_client.send_and_confirm_transaction(&build_transaction(fresh_blockhash))
+                        .inspect_err(|_| crate::metrics::record_blockhash_retry_failed())?
+                }
+                other => other?,
+            };
+            (sig.to_string(), None, "RPC") // Simplified executor always uses RPC
+        };
         
         info!("   Transaction sent via {} in {:?}", submission_path, t_send.elapsed());
         info!("   Signature: {}", signature);
         
-        // Calculate exit price from bonding curve
-        let exit_price = fresh_curve.calculate_price();
+        // Calculate exit price from bonding curve (post-simulation curve in dry-run mode)
+        let exit_price = simulated_price.unwrap_or_else(|| fresh_curve.calculate_price());
         
         // Return minimal ExitResult (Brain calculates profit/fees)
         Ok(ExitResult {
             trade_id: trade_id.to_string(),
             status: ExecutionStatus::Pending,
-            signature: signature.to_string(),
+            signature,
             exit_price,
             gross_profit: 0.0, // Brain calculates
             exit_fees: FeeBreakdown { 
@@ -1358,6 +1597,8 @@ impl TradingEngine {
             t_build: Some(t_build),
             t_send: Some(t_send),
             submission_path: Some(submission_path.to_string()),
+            dry_run: self.config.dry_run,
+            priority_fee_micro_lamports: Some(priority_fee),
         })
     }
     
@@ -1471,6 +1712,7 @@ impl TradingEngine {
             token_amount_raw,
             max_sol_cost,
             &curve_state.creator,
+            &self.fee_recipients.next(),
         )?;
         
         // Add compute budget for BUY
@@ -1503,6 +1745,7 @@ impl TradingEngine {
             token_amount_raw,
             min_sol_output,
             &curve_state.creator,
+            &self.fee_recipients.next(),
         )?;
         
         // Add compute budget for SELL
@@ -1629,6 +1872,7 @@ impl TradingEngine {
             token_amount,
             max_sol_cost,
             &curve_state.creator,  // NEW - needed for creator_vault PDA
+            &self.fee_recipients.next(),
         )?;
         
         let s3 = Instant::now(); // After instruction build
@@ -1768,6 +2012,7 @@ impl TradingEngine {
             token_amount,
             min_sol_output,
             &curve_state.creator,  // NEW - needed for creator_vault PDA
+            &self.fee_recipients.next(),
         )?;
         
         // 2. Determine tip amount (dynamic or fixed)
@@ -1841,11 +2086,11 @@ impl TradingEngine {
         token: &str,
         token_amount: u64,
         max_sol_cost: u64,
+        priority_fee: u64,
         cached_blockhash: Option<solana_sdk::hash::Hash>,
     ) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
         info!("🔄 Executing direct RPC buy (no Jito)...");
         
-        let token_pubkey = Pubkey::from_str(token)?;
         let wallet_pubkey = self.keypair.pubkey();
         
         // Check wallet balance (non-blocking)
@@ -1867,6 +2112,34 @@ impl TradingEngine {
             return Err(format!("Insufficient balance: {:.4} SOL (need at least 0.01 SOL)", balance_sol).into());
         }
         
+        let transaction = self.build_buy_transaction(token, token_amount, max_sol_cost, priority_fee, cached_blockhash).await?;
+        
+        info!("📡 Submitting transaction to RPC (skip_preflight=true)...");
+        
+        // Send transaction with skip_preflight to eliminate 50-200ms simulation delay
+        let config = RpcSendTransactionConfig {
+            skip_preflight: true,
+            ..Default::default()
+        };
+        let signature = self.rpc_client.send_transaction_with_config(&transaction, config)?;
+        
+        info!("✅ Transaction confirmed! Signature: {}", signature);
+        Ok(signature.to_string())
+    }
+    
+    /// Build and sign a Pump.fun BUY transaction (ATA creation + compute budget + buy)
+    /// Shared by the direct RPC path and dry-run simulation
+    async fn build_buy_transaction(
+        &self,
+        token: &str,
+        token_amount: u64,
+        max_sol_cost: u64,
+        priority_fee: u64,
+        cached_blockhash: Option<solana_sdk::hash::Hash>,
+    ) -> Result<Transaction, Box<dyn std::error::Error + Send + Sync>> {
+        let token_pubkey = Pubkey::from_str(token)?;
+        let wallet_pubkey = self.keypair.pubkey();
+        
         // Fetch bonding curve state to get creator pubkey
         let curve_state = self.curve_cache.get_or_fetch(
             &self.rpc_client,
@@ -1915,13 +2188,13 @@ impl TradingEngine {
             token_amount,
             max_sol_cost,
             &curve_state.creator,  // NEW - needed for creator_vault PDA
+            &self.fee_recipients.next(),
         )?;
         
         // Add compute budget instructions
         let compute_limit = 200_000; // Conservative limit for Pump.fun buys (TPU path)
         let compute_limit_ix = ComputeBudgetInstruction::set_compute_unit_limit(compute_limit);
         // TIER 2: Dynamic priority fee (was: 5000 static)
-        let priority_fee = self.get_dynamic_priority_fee();
         let compute_budget_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_fee);
         
         info!("⚙️ Compute limit: {} CU, price: {} µLamports/CU", compute_limit, priority_fee);
@@ -1939,17 +2212,56 @@ impl TradingEngine {
         );
         transaction.sign(&[&self.keypair], recent_blockhash);
         
-        info!("📡 Submitting transaction to RPC (skip_preflight=true)...");
-        
-        // Send transaction with skip_preflight to eliminate 50-200ms simulation delay
-        let config = RpcSendTransactionConfig {
-            skip_preflight: true,
+        Ok(transaction)
+    }
+    
+    /// 🧪 DRY-RUN: simulate a signed transaction via simulateTransaction instead of submitting it
+    ///
+    /// Returns a synthetic signature (never lands on-chain) and the bonding curve price
+    /// after the simulated trade, when the simulation returns the curve account.
+    fn simulate_dry_run(
+        &self,
+        transaction: &Transaction,
+        token_mint: &Pubkey,
+    ) -> Result<(String, Option<f64>), Box<dyn std::error::Error + Send + Sync>> {
+        let bonding_curve = pump_bonding_curve::find_bonding_curve_address(token_mint)?;
+        
+        let config = RpcSimulateTransactionConfig {
+            sig_verify: false,
+            replace_recent_blockhash: true,  // Cached blockhash may be stale by now
+            commitment: Some(CommitmentConfig::processed()),
+            accounts: Some(RpcSimulateTransactionAccountsConfig {
+                encoding: None,  // RPC defaults to base64
+                addresses: vec![bonding_curve.to_string()],
+            }),
             ..Default::default()
         };
-        let signature = self.rpc_client.send_transaction_with_config(&transaction, config)?;
         
-        info!("✅ Transaction confirmed! Signature: {}", signature);
-        Ok(signature.to_string())
+        let simulation = self.rpc_client.simulate_transaction_with_config(transaction, config)?.value;
+        
+        if let Some(err) = simulation.err {
+            for log_line in simulation.logs.unwrap_or_default().iter().rev().take(5) {
+                warn!("   🧪 {}", log_line);
+            }
+            return Err(format!("Dry-run simulation failed: {:?}", err).into());
+        }
+        
+        // Post-trade curve state → simulated price
+        let simulated_price = simulation.accounts
+            .and_then(|accounts| accounts.into_iter().next().flatten())
+            .and_then(|account| account.decode::<solana_sdk::account::Account>())
+            .and_then(|account| pump_bonding_curve::BondingCurveState::from_account_data(&account.data).ok())
+            .map(|curve| curve.calculate_price());
+        
+        let signature = Signature::new_unique().to_string();
+        
+        info!("🧪 DRY-RUN simulated OK ({} CU) - NOT submitted", simulation.units_consumed.unwrap_or(0));
+        info!("   Synthetic signature: {}", signature);
+        if let Some(price) = simulated_price {
+            info!("   Simulated price: {:.10} SOL/token", price);
+        }
+        
+        Ok((signature, simulated_price))
     }
     
     /// Execute buy using TPU (direct validator submission - fastest!)
@@ -1959,7 +2271,8 @@ impl TradingEngine {
         token_amount: u64,
         max_sol_cost: u64,
     ) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
-        let (sig, _, _) = self.execute_tpu_buy_with_timing(token, token_amount, max_sol_cost, None, None).await?;
+        let priority_fee = self.get_dynamic_priority_fee();
+        let (sig, _, _) = self.execute_tpu_buy_with_timing(token, token_amount, max_sol_cost, priority_fee, None, None).await?;
         Ok(sig)
     }
     
@@ -1969,6 +2282,7 @@ impl TradingEngine {
         token: &str,
         token_amount: u64,
         max_sol_cost: u64,
+        priority_fee: u64,  // µL/CU chosen by buy() from PriorityFeeTracker
         trace_id: Option<String>,  // NEW: For latency tracking
         cached_blockhash: Option<solana_sdk::hash::Hash>,
     ) -> Result<(String, std::time::Instant, std::time::Instant), Box<dyn std::error::Error + Send + Sync>> {
@@ -2023,12 +2337,12 @@ impl TradingEngine {
             token_amount,
             max_sol_cost,
             &curve_state.creator,  // NEW - needed for creator_vault PDA
+            &self.fee_recipients.next(),
         )?;
         
         // Add compute budget instructions
         let compute_limit = 200_000; // Conservative limit for Pump.fun buys
         let compute_limit_ix = ComputeBudgetInstruction::set_compute_unit_limit(compute_limit);
-        let priority_fee = 10000; // 10000 micro-lamports per CU (high priority for TPU)
         let compute_budget_ix = ComputeBudgetInstruction::set_compute_unit_price(priority_fee);
         
         info!("⚙️ Compute limit: {} CU, price: {} µLamports/CU", compute_limit, priority_fee);
@@ -2164,6 +2478,7 @@ impl TradingEngine {
             token_amount,
             min_sol_output,
             &curve_state.creator,  // NEW - needed for creator_vault PDA
+            &self.fee_recipients.next(),
         )?;
         
         // Add compute budget instructions
@@ -2236,6 +2551,7 @@ impl TradingEngine {
             token_amount,
             min_sol_output,
             &curve_state.creator,  // NEW - needed for creator_vault PDA
+            &self.fee_recipients.next(),
         )?;
         
         // Add compute budget instructions
@@ -2292,6 +2608,49 @@ impl TradingEngine {
     pub fn get_rpc_client(&self) -> &RpcClient {
         &self.rpc_client
     }
+    /// Poll signature status until the tx lands (confirmed) or `window` passes
+    /// Returns the landing status and submit → confirmation time when seen
+    pub async fn wait_for_landing(
+        &self,
+        signature: &str,
+        t_send: Instant,
+        window: Duration,
+    ) -> (LandingStatus, Option<Duration>) {
+        let signature = match Signature::from_str(signature) {
+            Ok(sig) => sig,
+            Err(e) => {
+                warn!("⚠️  Landing check skipped, bad signature {}: {}", signature, e);
+                return (LandingStatus::Dropped, None);
+            }
+        };
+        let poll_interval = Duration::from_millis(250);
+        
+        loop {
+            match self.rpc_client.get_signature_statuses(&[signature]) {
+                Ok(response) => {
+                    // Processed isn't landed yet; wait for confirmed (or finalized)
+                    if let Some(Some(status)) = response.value.first() {
+                        if status.satisfies_commitment(CommitmentConfig::confirmed()) {
+                            let elapsed = t_send.elapsed();
+                            if status.err.is_some() {
+                                return (LandingStatus::Failed, Some(elapsed));
+                            }
+                            return (LandingStatus::Landed, Some(elapsed));
+                        }
+                    }
+                }
+                Err(e) => {
+                    debug!("Landing status check failed for {}: {}", signature, e);
+                }
+            }
+            
+            if t_send.elapsed() >= window {
+                return (LandingStatus::Dropped, None);
+            }
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+    
     /// TIER 5: Monitor transaction confirmation and update t5 timing
     /// Polls signature status until finalized commitment is reached
     pub async fn monitor_confirmation(
@@ -2514,4 +2873,18 @@ fn load_keypair_from_string(private_key: &str) -> Result<Keypair, Box<dyn std::e
     }
     
     Err("Invalid private key format".into())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_is_blockhash_error() {
+        assert!(is_blockhash_error("RPC response error -32002: Transaction simulation failed: Blockhash not found"));
+        assert!(is_blockhash_error("TransactionError(BlockhashNotFound)"));
+        assert!(is_blockhash_error("unable to confirm transaction. This can happen in situations such as transaction expiration and insufficient fee-payer funds: block height exceeded"));
+        assert!(!is_blockhash_error("custom program error: 0x1772"));
+        assert!(!is_blockhash_error("insufficient funds for rent"));
+    }
+}
@@ -14,7 +14,7 @@ pub struct TxConfirmed {
     pub msg_type: u8,
     pub signature: [u8; 64],
     pub mint: [u8; 32],
-    pub trade_id: [u8; 16],
+    pub trace_id: [u8; 16],  // Brain's trace UUID, echoed from WatchSignature
     pub side: u8,  // 0=BUY, 1=SELL
     pub status: u8,  // 0=SUCCESS, 1=FAILED
     pub timestamp_ns: u64,
@@ -44,8 +44,8 @@ impl TxConfirmed {
         let mut mint = [0u8; 32];
         mint.copy_from_slice(&data[65..97]);
         
-        let mut trade_id = [0u8; 16];
-        trade_id.copy_from_slice(&data[97..113]);
+        let mut trace_id = [0u8; 16];
+        trace_id.copy_from_slice(&data[97..113]);
         
         let side = data[113];
         let status = data[114];
@@ -58,7 +58,7 @@ impl TxConfirmed {
             msg_type,
             signature,
             mint,
-            trade_id,
+            trace_id,
             side,
             status,
             timestamp_ns,
@@ -75,14 +75,14 @@ impl TxConfirmed {
         bs58::encode(&self.mint).into_string()
     }
     
-    /// Get trade_id as hex string (first 16 bytes of UUID)
-    pub fn trade_id_hex(&self) -> String {
-        hex::encode(&self.trade_id)
+    /// Get trace_id as hex string
+    pub fn trace_id_hex(&self) -> String {
+        hex::encode(&self.trace_id)
     }
     
-    /// Get trade_id as u128 (for deduplication)
-    pub fn trade_id(&self) -> u128 {
-        u128::from_le_bytes(self.trade_id)
+    /// Get trace_id as u128 (for deduplication)
+    pub fn trace_key(&self) -> u128 {
+        u128::from_le_bytes(self.trace_id)
     }
     
     /// Get status as string